@@ -0,0 +1,47 @@
+//! Accessors compiled by the codegen regression test in `src/test/codegen.rs`, which asserts that
+//! reading a field through composed lenses generates the same code as accessing it directly.
+use core::hint::black_box;
+use optics::{HasTotalGetter, field_lens};
+
+#[derive(Clone)]
+pub struct Server {
+    pub database: Database,
+}
+
+#[derive(Clone)]
+pub struct Database {
+    pub endpoint: Endpoint,
+}
+
+#[derive(Clone)]
+pub struct Endpoint {
+    pub port: u16,
+}
+
+#[unsafe(no_mangle)]
+#[inline(never)]
+pub fn direct_get(server: &Server) -> u16 {
+    server.database.endpoint.port
+}
+
+#[unsafe(no_mangle)]
+#[inline(never)]
+pub fn composed_lens_get(server: &Server) -> u16 {
+    field_lens!(Server, database)
+        .compose_with_lens(field_lens!(Database, endpoint))
+        .compose_with_lens(field_lens!(Endpoint, port))
+        .get(server)
+}
+
+fn main() {
+    let server = Server {
+        database: Database {
+            endpoint: Endpoint { port: 5432 },
+        },
+    };
+
+    assert_eq!(
+        direct_get(black_box(&server)),
+        composed_lens_get(black_box(&server))
+    );
+}
@@ -2,7 +2,7 @@ use crate::some_prism::some_prism;
 use optics::{HasSetter, mapped_lens};
 
 mod some_prism {
-    use optics::{HasGetter, HasSetter, Prism, PrismImpl};
+    use optics::{HasGetter, HasModify, HasSetter, Prism, PrismImpl};
 
     struct WasNone;
     struct SomePrism<A>(std::marker::PhantomData<A>);
@@ -25,6 +25,14 @@ mod some_prism {
         }
     }
 
+    impl<A: Clone> HasModify<Option<A>, A> for SomePrism<A> {
+        fn modify<F: FnOnce(&mut A)>(&self, source: &mut Option<A>, f: F) {
+            if let Some(value) = source {
+                f(value);
+            }
+        }
+    }
+
     pub fn some_prism<A: Clone>() -> PrismImpl<Option<A>, A, impl Prism<Option<A>, A>> {
         SomePrism(std::marker::PhantomData).into()
     }
@@ -1,4 +1,4 @@
-use optics::{HasGetter, HasSetter, mapped_fallible_iso, mapped_lens, mapped_prism};
+use optics::{HasGetter, HasSetter, OpticsError, mapped_fallible_iso, mapped_lens, mapped_prism};
 
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
@@ -22,7 +22,7 @@ fn main() {
     );
 
     let bind_address_prism = mapped_prism(
-        |http: &HttpConfig| http.bind_address.clone().ok_or(()),
+        |http: &HttpConfig| http.bind_address.clone().ok_or(OpticsError::NoFocus),
         |http, addr| http.bind_address = Some(addr),
     );
 
@@ -33,20 +33,21 @@ fn main() {
         |addr: &String| {
             addr.rsplit(':')
                 .next()
-                .and_then(|port| port.parse::<u16>().ok())
-                .ok_or(())
+                .ok_or(OpticsError::NoFocus)?
+                .parse::<u16>()
+                .map_err(OpticsError::from)
         },
         move |port: &u16| {
             (*port > minimum_port)
                 .then_some(format!("0.0.0.0:{}", port))
-                .ok_or(())
+                .ok_or(OpticsError::OutOfRange)
         },
     );
 
     // Compose lens and fallible iso into a ComposedFallibleIso
     let http_bind_address_prism = http_lens.compose_with_prism(bind_address_prism);
     let http_bind_address_port_prism =
-        http_bind_address_prism.compose_with_fallible_iso::<(), _, _>(port_fallible_iso);
+        http_bind_address_prism.compose_with_fallible_iso::<OpticsError, _, _>(port_fallible_iso);
 
     let mut config = AppConfig {
         http: HttpConfig {
@@ -0,0 +1,74 @@
+use crate::Either;
+use crate::async_optic::{AsyncGetter, AsyncSetter};
+use core::marker::PhantomData;
+
+mod private {
+    use core::marker::PhantomData;
+
+    #[derive(Clone, Copy, Debug)]
+    pub struct ComposedAsyncOptic<O1, O2, I> {
+        pub(super) optic1: O1,
+        pub(super) optic2: O2,
+        pub(super) _phantom: PhantomData<fn() -> I>,
+    }
+}
+
+/// An async optic composed from two async optics `O1: <S, I>` and `O2: <I, A>` applied one after
+/// another, created by [`AsyncGetter::compose_with_async`].
+///
+/// # Type Parameters
+/// - `O1`: The first optic, focusing from `S` to `I`.
+/// - `O2`: The second optic, focusing from `I` to `A`.
+/// - `I`: The intermediate type.
+pub type ComposedAsyncOptic<O1, O2, I> = private::ComposedAsyncOptic<O1, O2, I>;
+
+impl<S, I, A, O1, O2> AsyncGetter<S, A> for ComposedAsyncOptic<O1, O2, I>
+where
+    O1: AsyncGetter<S, I> + Sync,
+    O2: AsyncGetter<I, A> + Sync,
+    S: Sync,
+    I: Send + Sync,
+{
+    type GetterError = Either<O1::GetterError, O2::GetterError>;
+
+    async fn try_get(&self, source: &S) -> Result<A, Self::GetterError> {
+        let i = self.optic1.try_get(source).await.map_err(Either::Left)?;
+        self.optic2.try_get(&i).await.map_err(Either::Right)
+    }
+}
+
+impl<S, I, A, O1, O2> AsyncSetter<S, A> for ComposedAsyncOptic<O1, O2, I>
+where
+    O1: AsyncGetter<S, I> + AsyncSetter<S, I> + Sync,
+    O2: AsyncSetter<I, A> + Sync,
+    S: Send + Sync,
+    I: Send,
+    A: Send,
+{
+    type SetterError =
+        Either<Either<<O1 as AsyncGetter<S, I>>::GetterError, O1::SetterError>, O2::SetterError>;
+
+    async fn try_set(&self, source: &mut S, value: A) -> Result<(), Self::SetterError> {
+        let mut i = self
+            .optic1
+            .try_get(source)
+            .await
+            .map_err(|e| Either::Left(Either::Left(e)))?;
+        self.optic2
+            .try_set(&mut i, value)
+            .await
+            .map_err(Either::Right)?;
+        self.optic1
+            .try_set(source, i)
+            .await
+            .map_err(|e| Either::Left(Either::Right(e)))
+    }
+}
+
+pub(crate) fn new<O1, O2, I>(optic1: O1, optic2: O2) -> ComposedAsyncOptic<O1, O2, I> {
+    ComposedAsyncOptic {
+        optic1,
+        optic2,
+        _phantom: PhantomData,
+    }
+}
@@ -0,0 +1,43 @@
+use crate::async_optic::{AsyncGetter, AsyncSetter};
+use crate::{HasGetter, HasSetter};
+use core::convert::Infallible;
+
+/// A synchronous optic lifted into an async one by [`lift_async`].
+///
+/// Reading and writing complete immediately, through the getter and setter of the wrapped optic.
+#[derive(Clone, Copy, Debug)]
+pub struct LiftedAsyncOptic<O>(O);
+
+impl<S: Sync, A, O: HasGetter<S, A> + Sync> AsyncGetter<S, A> for LiftedAsyncOptic<O> {
+    type GetterError = O::GetterError;
+
+    async fn try_get(&self, source: &S) -> Result<A, Self::GetterError> {
+        self.0.try_get(source)
+    }
+}
+
+impl<S: Send, A: Send, O: HasSetter<S, A> + Sync> AsyncSetter<S, A> for LiftedAsyncOptic<O> {
+    type SetterError = Infallible;
+
+    async fn try_set(&self, source: &mut S, value: A) -> Result<(), Self::SetterError> {
+        self.0.set(source, value);
+        Ok(())
+    }
+}
+
+/// Lifts a synchronous optic into an async one, so it can be composed with async optics.
+///
+/// The lifted optic is an [`AsyncGetter`] if `optic` can be read, and an [`AsyncSetter`] if it can
+/// be written.
+///
+/// # Parameters
+///
+/// - `optic`: The synchronous optic to lift.
+///
+/// # Returns
+///
+/// A new `LiftedAsyncOptic` wrapping `optic`.
+#[must_use]
+pub const fn lift_async<O>(optic: O) -> LiftedAsyncOptic<O> {
+    LiftedAsyncOptic(optic)
+}
@@ -0,0 +1,97 @@
+mod composed;
+mod lifted;
+
+pub use composed::ComposedAsyncOptic;
+pub use lifted::{LiftedAsyncOptic, lift_async};
+
+/// An optic that reads its focus asynchronously, e.g. by fetching a database row or a REST
+/// resource identified by the source.
+///
+/// Synchronous optics are lifted into async ones by [`lift_async`], and async optics are chained
+/// with [`compose_with_async`](AsyncGetter::compose_with_async), so a lens into a struct can be
+/// applied to the struct returned by an async fetch.
+///
+/// The returned futures are `Send`, so reads can be spawned on multi-threaded executors.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{AsyncGetter, field_lens, lift_async};
+/// use std::collections::HashMap;
+///
+/// #[derive(Clone)]
+/// struct User {
+///     name: String,
+/// }
+///
+/// struct Users;
+///
+/// impl AsyncGetter<HashMap<u32, User>, User> for Users {
+///     type GetterError = ();
+///
+///     async fn try_get(&self, db: &HashMap<u32, User>) -> Result<User, ()> {
+///         db.get(&1).cloned().ok_or(())
+///     }
+/// }
+///
+/// let name = Users.compose_with_async(lift_async(field_lens!(User, name)));
+/// ```
+pub trait AsyncGetter<S, A> {
+    /// The type of the error returned when the focus could not be read.
+    type GetterError;
+
+    /// Tries to read the focused value of type `A` from the source of type `S`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Self::GetterError` if the focus could not be read.
+    fn try_get(&self, source: &S) -> impl Future<Output = Result<A, Self::GetterError>> + Send;
+
+    /// Composes this async optic with another one focusing on a part of its target, resulting in
+    /// an async optic from `S` to the target of `other`.
+    ///
+    /// Reading through the composition reads the focus of `self` and then the focus of `other`
+    /// within it. When `self` is also an [`AsyncSetter`] and `other` can be written, writing
+    /// through the composition reads the focus of `self`, writes the value into it through
+    /// `other`, and stores the updated focus back through `self`.
+    ///
+    /// The error of the composition is an [`Either`](crate::Either), identifying which of the
+    /// optics failed.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: The async optic to compose with. Synchronous optics can be passed through
+    ///   [`lift_async`].
+    ///
+    /// # Returns
+    ///
+    /// A new `ComposedAsyncOptic` that represents the composition of `self` and `other`.
+    #[must_use]
+    fn compose_with_async<O2>(self, other: O2) -> ComposedAsyncOptic<Self, O2, A>
+    where
+        Self: Sized,
+    {
+        composed::new(self, other)
+    }
+}
+
+/// An optic that writes its focus asynchronously, e.g. by storing a database row or a REST
+/// resource identified by the source.
+///
+/// The returned futures are `Send`, so writes can be spawned on multi-threaded executors. See
+/// [`AsyncGetter`] for lifting synchronous optics and composing async ones.
+pub trait AsyncSetter<S, A> {
+    /// The type of the error returned when the value could not be written.
+    type SetterError;
+
+    /// Tries to write a value of type `A` as the focus of the source of type `S`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Self::SetterError` if the value could not be written.
+    fn try_set(
+        &self,
+        source: &mut S,
+        value: A,
+    ) -> impl Future<Output = Result<(), Self::SetterError>> + Send;
+}
@@ -0,0 +1,27 @@
+/// A base trait for optics that provides a setter operation that can reject the value being set.
+///
+/// This trait defines the ability to set a value of type `A` into a mutable source of type `S`,
+/// failing with a domain error of type `Self::SetterError` instead of writing when the value is not
+/// acceptable (e.g. a port below 1024). It is distinct from the error of the getter, which reports
+/// that the focus could not be reached at all.
+///
+/// # Implementors
+///
+///   - [`FallibleSetter`] — a concrete optic that allows only fallible set operations.
+pub trait HasFallibleSetter<S, A> {
+    /// The type of the error returned when the value is rejected.
+    type SetterError;
+
+    /// Tries to set a value of type `A` the optic focuses on in a mutable source of type `S`.
+    ///
+    /// # Parameters
+    ///
+    /// - `source`: A mutable reference to the source of type `S` into which the value is to be set.
+    /// - `value`: The value of type `A` to be set into the source.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Self::SetterError` if the value was rejected. In that case the source is left
+    /// unchanged.
+    fn try_set(&self, source: &mut S, value: A) -> Result<(), Self::SetterError>;
+}
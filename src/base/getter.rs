@@ -1,3 +1,8 @@
+/// The labels of the optics an optic is composed after, passed down by compositions to
+/// [`HasGetter::try_get_within`]. Calling it with a callback feeds the callback each label,
+/// outermost first.
+pub type OuterLabels<'a> = &'a dyn Fn(&mut dyn FnMut(&'static str));
+
 /// A base trait for optics that provides a partial getter operation.
 ///
 /// This trait defines the ability to retrieve a value of type `A` from a source of type `S`,
@@ -42,4 +47,33 @@ pub trait HasGetter<S, A> {
     ///
     /// Returns a `Result<A, Self::GetterError>`, of the value the optic focuses on.
     fn try_get(&self, source: &S) -> Result<A, Self::GetterError>;
+
+    /// Attempts to retrieve a value like [`try_get`](Self::try_get), knowing the labels of the
+    /// optics this one is composed after.
+    ///
+    /// Compositions call this on their second optic, passing down the labels of their first one,
+    /// so optics labelled with a `context` method can report the full path of labels in their
+    /// [`ContextError`](crate::ContextError). The default implementation ignores `outer` and
+    /// calls [`try_get`](Self::try_get).
+    ///
+    /// # Parameters
+    ///
+    /// - `source`: A reference to the source of type `S` from which the value is to be retrieved.
+    /// - `outer`: Calls its argument with each label of the enclosing optics, outermost first.
+    ///
+    /// # Errors
+    ///
+    /// It returns an error specified by the implementing optic if the focus fails.
+    #[inline]
+    fn try_get_within(&self, source: &S, outer: OuterLabels<'_>) -> Result<A, Self::GetterError> {
+        let _ = outer;
+        self.try_get(source)
+    }
+
+    /// Calls `f` with each label given to this optic or the optics it is composed of with a
+    /// `context` method, outermost first. The default implementation has no labels.
+    #[inline]
+    fn for_each_label(&self, f: &mut dyn FnMut(&'static str)) {
+        let _ = f;
+    }
 }
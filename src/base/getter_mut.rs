@@ -0,0 +1,36 @@
+use crate::HasGetter;
+
+/// A base trait for optics that can retrieve their focus by mutable reference.
+///
+/// This trait extends [`HasGetter`] with the ability to mutably borrow the focus of type `A`
+/// directly from a source of type `S`. This allows modifying the focus in place, avoiding the
+/// clone-modify-set cycle of [`HasGetter::try_get`] and [`HasSetter::set`](crate::HasSetter::set).
+///
+/// Retrieving the focus by mutable reference fails with the same `GetterError` as
+/// [`HasGetter::try_get`].
+///
+/// # Implementors
+///
+/// Only optics whose focus is stored as-is within the source can implement this trait, such as
+/// lenses created by [`field_lens`](crate::field_lens) or [`mapped_ref_lens`](crate::mapped_ref_lens),
+/// and compositions of these.
+pub trait HasGetterMut<S, A>: HasGetter<S, A> {
+    /// Attempts to retrieve a mutable reference to the value of type `A` within a source of type `S`.
+    ///
+    /// # Parameters
+    ///
+    /// - `source`: A mutable reference to the source of type `S` from which the value is to be
+    ///   borrowed.
+    ///
+    /// # Errors
+    ///
+    /// It returns an error specified by the implementing optic if the focus fails.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result<&mut A, Self::GetterError>`, of a mutable reference to the value the optic
+    /// focuses on.
+    fn try_get_mut<'a>(&self, source: &'a mut S) -> Result<&'a mut A, Self::GetterError>
+    where
+        Self: 'a;
+}
@@ -0,0 +1,33 @@
+use crate::HasGetter;
+
+/// A base trait for optics that can retrieve their focus by reference.
+///
+/// This trait extends [`HasGetter`] with the ability to borrow the focus of type `A` directly from
+/// a source of type `S`, instead of returning an owned copy. This allows inspecting large foci,
+/// such as `String`s or `Vec`s, without cloning them.
+///
+/// Retrieving the focus by reference fails with the same `GetterError` as [`HasGetter::try_get`].
+///
+/// # Implementors
+///
+/// Only optics whose focus is stored as-is within the source can implement this trait, such as
+/// lenses created by [`field_lens`](crate::field_lens), [`borrowed_lens`](crate::borrowed_lens) or
+/// [`mapped_ref_lens`](crate::mapped_ref_lens), and compositions of these.
+pub trait HasGetterRef<S, A>: HasGetter<S, A> {
+    /// Attempts to retrieve a reference to the value of type `A` within a source of type `S`.
+    ///
+    /// # Parameters
+    ///
+    /// - `source`: A reference to the source of type `S` from which the value is to be borrowed.
+    ///
+    /// # Errors
+    ///
+    /// It returns an error specified by the implementing optic if the focus fails.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result<&A, Self::GetterError>`, of a reference to the value the optic focuses on.
+    fn try_get_ref<'a>(&self, source: &'a S) -> Result<&'a A, Self::GetterError>
+    where
+        Self: 'a;
+}
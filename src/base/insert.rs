@@ -0,0 +1,25 @@
+use crate::HasSetter;
+
+/// A base trait for optics that can construct their focus when it is not present in the source.
+///
+/// This trait extends [`HasSetter`] with an operation that always results in the focus being
+/// present: where [`HasSetter::set`] of a prism may only replace a focus that is already there,
+/// [`HasInsert::insert`] creates it if needed, e.g. by inserting a missing entry into a map.
+///
+/// # Implementors
+///
+/// Optics that know how to construct their focus implement this trait, such as
+/// [`map::value`](crate::map::value), [`btree_map::value`](crate::btree_map::value),
+/// [`vec::get`](crate::vec::get), [`deque::front`](crate::deque::front), prisms given an insert
+/// function with [`PrismImpl::with_insert`](crate::PrismImpl::with_insert), and compositions of a
+/// prism with one of these.
+pub trait HasInsert<S, A>: HasSetter<S, A> {
+    /// Sets the value of type `A` the optic focuses on in a mutable source of type `S`,
+    /// constructing the focus if it is not present.
+    ///
+    /// # Parameters
+    ///
+    /// - `source`: A mutable reference to the source of type `S` into which the value is inserted.
+    /// - `value`: The value of type `A` to be inserted into the source.
+    fn insert(&self, source: &mut S, value: A);
+}
@@ -1,7 +1,19 @@
+mod fallible_setter;
 mod getter;
+mod getter_mut;
+mod getter_ref;
+mod insert;
+mod modify;
 mod reversible;
 mod setter;
+mod traversal;
 
-pub use getter::HasGetter;
+pub use fallible_setter::HasFallibleSetter;
+pub use getter::{HasGetter, OuterLabels};
+pub use getter_mut::HasGetterMut;
+pub use getter_ref::HasGetterRef;
+pub use insert::HasInsert;
+pub use modify::HasModify;
 pub use reversible::HasReverseGet;
 pub use setter::HasSetter;
+pub use traversal::HasTraversal;
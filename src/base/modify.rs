@@ -0,0 +1,35 @@
+use crate::{HasGetter, HasSetter};
+
+/// A base trait for optics that can modify their focus in place.
+///
+/// This trait defines the ability to apply a function to the value of type `A` the optic focuses
+/// on within a mutable source of type `S`. If the focus cannot be retrieved, the source is left
+/// unchanged.
+///
+/// The default implementation retrieves the focus, applies the function and sets it back. Optics
+/// that can do better override it: composed optics modify their focus level by level, instead of
+/// retrieving and setting back the whole intermediate value, and optics implementing
+/// [`HasGetterMut`](crate::HasGetterMut) modify the focus without cloning it at all.
+///
+/// # Implementors
+///
+/// All optics that allow both reading and writing implement this trait:
+///
+///   - [`Prism`] — optic that allows for fallible retrieval of values.
+///   - [`Lens`] — a total optic that allows for setting values.
+///   - [`FallibleIso`] — reversible optic that can allows for fallible conversion of values in both directions.
+///   - [`Iso`] — a reversible optic that allows for setting values in both directions.
+pub trait HasModify<S, A>: HasGetter<S, A> + HasSetter<S, A> {
+    /// Modifies the value of type `A` the optic focuses on in a mutable source of type `S` in place.
+    ///
+    /// # Parameters
+    ///
+    /// - `source`: A mutable reference to the source of type `S` in which the focus is modified.
+    /// - `f`: The function modifying the focus.
+    fn modify<F: FnOnce(&mut A)>(&self, source: &mut S, f: F) {
+        if let Ok(mut value) = self.try_get(source) {
+            f(&mut value);
+            self.set(source, value);
+        }
+    }
+}
@@ -0,0 +1,38 @@
+/// A base trait for optics that focus on any number of values at once.
+///
+/// This trait defines the ability to visit every value of type `A` focused on inside a source of
+/// type `S`, and to replace each of them with a new value computed from the old one. A source may
+/// contain zero, one or many such values, such as the elements of a collection.
+///
+/// The callbacks are passed as trait objects, so that the trait remains object safe and can be
+/// implemented by compositions without the need of generic methods.
+///
+/// # Notes
+/// - Currently, you will likely need to Clone or Copy the focused values in order to pass them to
+///   the callback.
+///
+/// # Implementors
+///
+/// Types that implement `HasTraversal` can be used to define optics that allow for
+/// reading and updating multiple values at once.
+///
+///   - [`Traversal`] — optic that focuses on any number of values.
+///
+pub trait HasTraversal<S, A> {
+    /// Calls `f` with every value of type `A` focused on inside a source of type `S`.
+    ///
+    /// # Parameters
+    ///
+    /// - `source`: A reference to the source of type `S` from which the values are to be retrieved.
+    /// - `f`: The function to call with each focused value.
+    fn for_each(&self, source: &S, f: &mut dyn FnMut(A));
+
+    /// Replaces every value of type `A` focused on inside a mutable source of type `S` with the
+    /// result of calling `f` with the current value.
+    ///
+    /// # Parameters
+    ///
+    /// - `source`: A mutable reference to the source of type `S` in which the values are to be updated.
+    /// - `f`: The function computing the new value from the old one.
+    fn over_all(&self, source: &mut S, f: &mut dyn FnMut(A) -> A);
+}
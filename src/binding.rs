@@ -0,0 +1,521 @@
+use crate::{
+    Either, ErasedError, HasFallibleSetter, HasGetter, HasSetter, OpticsError, Patch, PatchError,
+    PatchRegistry,
+};
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::rc::Rc;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::{Debug, Display, Formatter};
+use core::marker::PhantomData;
+use core::str::FromStr;
+
+/// A value edited by a user interface widget, remembering whether it was edited since it was last
+/// loaded.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::Binding;
+///
+/// let mut name = Binding::new("Alice".to_string());
+/// assert!(!name.is_dirty());
+///
+/// name.set("Bob".to_string());
+/// assert_eq!(name.get(), "Bob");
+/// assert!(name.is_dirty());
+///
+/// name.reset("Carol".to_string());
+/// assert!(!name.is_dirty());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Binding<A> {
+    value: A,
+    dirty: bool,
+}
+
+impl<A> Binding<A> {
+    /// Creates a new, clean binding holding `value`.
+    #[must_use]
+    pub fn new(value: A) -> Self {
+        Binding {
+            value,
+            dirty: false,
+        }
+    }
+
+    /// Returns the current value of the binding.
+    #[must_use]
+    pub fn get(&self) -> &A {
+        &self.value
+    }
+
+    /// Replaces the value of the binding with `value` edited by the user, marking it dirty.
+    ///
+    /// # Parameters
+    ///
+    /// - `value`: The edited value.
+    pub fn set(&mut self, value: A) {
+        self.value = value;
+        self.dirty = true;
+    }
+
+    /// Replaces the value of the binding with `value` loaded from the bound data, marking it clean.
+    ///
+    /// # Parameters
+    ///
+    /// - `value`: The loaded value.
+    pub fn reset(&mut self, value: A) {
+        self.value = value;
+        self.dirty = false;
+    }
+
+    /// Returns `true` if the value was edited since it was last loaded.
+    #[must_use]
+    pub fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    /// Consumes the binding, returning its value.
+    #[must_use]
+    pub fn into_value(self) -> A {
+        self.value
+    }
+}
+
+/// A [`Binding`] carrying the optic it is bound through, loading its value from and committing it
+/// to a value of type `S`.
+///
+/// The binding is filled from the focus of the optic with [`load`](OpticBinding::load), edited
+/// with [`set`](OpticBinding::set), and written back with [`commit`](OpticBinding::commit), or
+/// [`try_commit`](OpticBinding::try_commit) for optics that may reject the values written through
+/// them.
+///
+/// Writing through a prism that has no focus in the target is dropped by the prism, so committing
+/// reads the focus back after writing it, and fails with the error of the optic if it is still
+/// absent, leaving the binding dirty.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{OpticBinding, option};
+///
+/// let mut port = OpticBinding::new(option::some::<u16>(), &Some(8080)).unwrap();
+/// assert_eq!(*port.get(), 8080);
+///
+/// port.set(9090);
+/// assert!(port.is_dirty());
+///
+/// let mut configured = None;
+/// port.commit(&mut configured).unwrap();
+/// assert_eq!(configured, Some(9090));
+/// assert!(!port.is_dirty());
+///
+/// assert!(port.load(&None).is_err());
+/// assert_eq!(*port.get(), 9090);
+/// ```
+pub struct OpticBinding<S, A, O> {
+    binding: Binding<A>,
+    optic: O,
+    _phantom: PhantomData<fn(&S)>,
+}
+
+impl<S, A, O: HasGetter<S, A>> OpticBinding<S, A, O> {
+    /// Creates a new, clean binding holding the focus of `optic` in `source`.
+    ///
+    /// # Parameters
+    ///
+    /// - `optic`: The optic focusing on the part of the value edited through the binding.
+    /// - `source`: The value to read the binding from.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the optic if it has no focus in `source`.
+    pub fn new(optic: O, source: &S) -> Result<Self, O::GetterError> {
+        let value = optic.try_get(source)?;
+        Ok(OpticBinding {
+            binding: Binding::new(value),
+            optic,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Fills the binding with the focus of its optic in `source`, marking it clean.
+    ///
+    /// # Parameters
+    ///
+    /// - `source`: The value to read the binding from.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the optic if it has no focus in `source`, leaving the binding
+    /// unchanged.
+    pub fn load(&mut self, source: &S) -> Result<(), O::GetterError> {
+        self.binding.reset(self.optic.try_get(source)?);
+        Ok(())
+    }
+
+    /// Writes the binding to `target` if it was edited, marking it clean.
+    ///
+    /// # Parameters
+    ///
+    /// - `target`: The value to write the binding to.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the optic if it has no focus in `target` after writing, leaving the
+    /// binding dirty.
+    pub fn commit(&mut self, target: &mut S) -> Result<(), O::GetterError>
+    where
+        O: HasSetter<S, A>,
+        A: Clone,
+    {
+        if self.binding.is_dirty() {
+            self.optic.set(target, self.binding.get().clone());
+            self.optic.try_get(target)?;
+            self.binding.dirty = false;
+        }
+        Ok(())
+    }
+
+    /// Writes the binding to `target` through an optic that may reject it if it was edited,
+    /// marking it clean.
+    ///
+    /// # Parameters
+    ///
+    /// - `target`: The value to write the binding to.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Either::Left`] with the error of the optic if it rejects the value, or
+    /// [`Either::Right`] with the error of the optic if it has no focus in `target` after writing,
+    /// leaving the binding dirty.
+    pub fn try_commit(
+        &mut self,
+        target: &mut S,
+    ) -> Result<(), Either<O::SetterError, O::GetterError>>
+    where
+        O: HasFallibleSetter<S, A>,
+        A: Clone,
+    {
+        if self.binding.is_dirty() {
+            self.optic
+                .try_set(target, self.binding.get().clone())
+                .map_err(Either::Left)?;
+            self.optic.try_get(target).map_err(Either::Right)?;
+            self.binding.dirty = false;
+        }
+        Ok(())
+    }
+}
+
+impl<S, A, O> OpticBinding<S, A, O> {
+    /// Returns the current value of the binding.
+    #[must_use]
+    pub fn get(&self) -> &A {
+        self.binding.get()
+    }
+
+    /// Replaces the value of the binding with `value` edited by the user, marking it dirty.
+    ///
+    /// # Parameters
+    ///
+    /// - `value`: The edited value.
+    pub fn set(&mut self, value: A) {
+        self.binding.set(value);
+    }
+
+    /// Returns `true` if the value was edited since it was last loaded or committed.
+    #[must_use]
+    pub fn is_dirty(&self) -> bool {
+        self.binding.is_dirty()
+    }
+
+    /// Returns the binding holding the value.
+    #[must_use]
+    pub fn binding(&self) -> &Binding<A> {
+        &self.binding
+    }
+
+    /// Returns the optic the binding is bound through.
+    #[must_use]
+    pub fn optic(&self) -> &O {
+        &self.optic
+    }
+}
+
+impl<S, A: Debug, O> Debug for OpticBinding<S, A, O> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("OpticBinding")
+            .field("binding", &self.binding)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A set of [`Binding`]s holding the text of the inputs of a form, each bound to a part of a value
+/// of type `S` through an optic, and identified by the id of its input.
+///
+/// The bindings are filled from the value with [`load`](FormBinder::load), edited by the inputs
+/// through [`binding_mut`](FormBinder::binding_mut), and the edited ones are parsed and written back
+/// to the value with [`commit`](FormBinder::commit), which applies them as a [`Patch`] keyed by the
+/// ids of the inputs.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{FormBinder, field_lens};
+///
+/// struct Server {
+///     host: String,
+///     port: u16,
+/// }
+///
+/// let mut server = Server { host: "localhost".to_string(), port: 8080 };
+///
+/// let mut form = FormBinder::new();
+/// form.bind("host", field_lens!(Server, host))
+///     .bind("port", field_lens!(Server, port));
+/// form.load(&server);
+/// assert_eq!(form.binding("port").unwrap().get(), "8080");
+///
+/// form.binding_mut("port").unwrap().set("80a".to_string());
+/// let errors = form.commit(&mut server).unwrap_err();
+/// assert_eq!(errors[0].to_string(), "port: invalid digit found in string");
+///
+/// form.binding_mut("port").unwrap().set("9090".to_string());
+/// form.commit(&mut server).unwrap();
+/// assert_eq!(server.port, 9090);
+/// assert!(!form.is_dirty());
+/// ```
+pub struct FormBinder<'a, S> {
+    fields: BTreeMap<String, Field<'a, S>>,
+    writers: PatchRegistry<'a, S>,
+}
+
+#[allow(clippy::type_complexity)]
+struct Field<'a, S> {
+    binding: Binding<String>,
+    read: Box<dyn Fn(&S) -> Option<String> + 'a>,
+}
+
+impl<'a, S> FormBinder<'a, S> {
+    /// Creates a new form without any bindings.
+    #[must_use]
+    pub fn new() -> Self {
+        FormBinder {
+            fields: BTreeMap::new(),
+            writers: PatchRegistry::new(),
+        }
+    }
+
+    /// Binds the input `id` to the focus of `optic`, replacing any binding of the same input.
+    ///
+    /// The binding is empty until the form is [loaded](FormBinder::load).
+    ///
+    /// # Parameters
+    ///
+    /// - `id`: The id of the input.
+    /// - `optic`: The optic focusing on the part of the value edited by the input.
+    pub fn bind<A, O>(&mut self, id: impl Into<String>, optic: O) -> &mut Self
+    where
+        O: HasGetter<S, A> + HasSetter<S, A> + 'a,
+        A: FromStr + Display,
+        A::Err: Into<ErasedError>,
+    {
+        let optic = Rc::new(optic);
+        let setter = optic.clone();
+        let id = id.into();
+        self.writers.register_as(id.clone(), move |target, value| {
+            setter.set(target, value);
+            written::<S, A, O>(&*setter, target)
+        });
+        self.insert(id, optic)
+    }
+
+    /// Binds the input `id` to the focus of `optic`, which may reject the values written through
+    /// it, replacing any binding of the same input.
+    ///
+    /// The binding is empty until the form is [loaded](FormBinder::load).
+    ///
+    /// # Parameters
+    ///
+    /// - `id`: The id of the input.
+    /// - `optic`: The optic focusing on the part of the value edited by the input, such as a
+    ///   validated optic.
+    pub fn bind_fallible<A, O>(&mut self, id: impl Into<String>, optic: O) -> &mut Self
+    where
+        O: HasGetter<S, A> + HasFallibleSetter<S, A> + 'a,
+        O::SetterError: Into<ErasedError>,
+        A: FromStr + Display,
+        A::Err: Into<ErasedError>,
+    {
+        let optic = Rc::new(optic);
+        let setter = optic.clone();
+        let id = id.into();
+        self.writers.register_as(id.clone(), move |target, value| {
+            setter.try_set(target, value).map_err(Into::into)?;
+            written::<S, A, O>(&*setter, target)
+        });
+        self.insert(id, optic)
+    }
+
+    /// Returns the binding of the input `id`, if it is bound.
+    #[must_use]
+    pub fn binding(&self, id: &str) -> Option<&Binding<String>> {
+        self.fields.get(id).map(|field| &field.binding)
+    }
+
+    /// Returns the binding of the input `id` for editing, if it is bound.
+    #[must_use]
+    pub fn binding_mut(&mut self, id: &str) -> Option<&mut Binding<String>> {
+        self.fields.get_mut(id).map(|field| &mut field.binding)
+    }
+
+    /// Returns the ids of the bound inputs, in lexicographic order.
+    pub fn ids(&self) -> impl Iterator<Item = &str> {
+        self.fields.keys().map(String::as_str)
+    }
+
+    /// Returns `true` if any of the bindings was edited since it was last loaded or committed.
+    #[must_use]
+    pub fn is_dirty(&self) -> bool {
+        self.fields.values().any(|field| field.binding.is_dirty())
+    }
+
+    /// Fills every binding with the focus of its optic in `source`, marking them clean.
+    ///
+    /// Bindings whose optic has no focus in `source` are emptied.
+    ///
+    /// # Parameters
+    ///
+    /// - `source`: The value to read the bindings from.
+    pub fn load(&mut self, source: &S) {
+        for field in self.fields.values_mut() {
+            field
+                .binding
+                .reset((field.read)(source).unwrap_or_default());
+        }
+    }
+
+    /// Parses the edited bindings and writes them to `target`, marking the written ones clean.
+    ///
+    /// Bindings that fail to be written don't stop the others from being written, and stay dirty.
+    /// Bindings whose optic has no focus in `target` after writing them, such as prisms dropping
+    /// the write, fail with [`OpticsError::NoFocus`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`BindingError`] for each binding that could not be parsed, was rejected by its
+    /// optic or was not written, in the order of their ids.
+    pub fn commit(&mut self, target: &mut S) -> Result<(), Vec<BindingError>> {
+        let patch: Patch = self
+            .fields
+            .iter()
+            .filter(|(_, field)| field.binding.is_dirty())
+            .map(|(id, field)| (id.as_str(), field.binding.get().as_str()))
+            .collect();
+
+        let errors = patch.apply(target, &self.writers).err().unwrap_or_default();
+        for (id, field) in &mut self.fields {
+            if !errors.iter().any(|error| error.id() == id) {
+                field.binding.dirty = false;
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors.into_iter().map(BindingError::from).collect())
+        }
+    }
+
+    fn insert<A, O>(&mut self, id: String, optic: Rc<O>) -> &mut Self
+    where
+        O: HasGetter<S, A> + 'a,
+        A: Display,
+    {
+        self.fields.insert(
+            id,
+            Field {
+                binding: Binding::default(),
+                read: Box::new(move |source| {
+                    optic.try_get(source).ok().map(|value| value.to_string())
+                }),
+            },
+        );
+        self
+    }
+}
+
+fn written<S, A, O: HasGetter<S, A>>(optic: &O, target: &S) -> Result<(), ErasedError> {
+    optic
+        .try_get(target)
+        .map(drop)
+        .map_err(|_| OpticsError::NoFocus.into())
+}
+
+impl<S> Default for FormBinder<'_, S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S> Debug for FormBinder<'_, S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_map()
+            .entries(self.fields.iter().map(|(id, field)| (id, &field.binding)))
+            .finish()
+    }
+}
+
+/// The error of a binding of a [`FormBinder`] that could not be committed.
+#[derive(Debug)]
+pub struct BindingError {
+    id: String,
+    error: ErasedError,
+}
+
+impl BindingError {
+    /// Returns the id of the input whose binding could not be committed.
+    #[must_use]
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Returns a reference to the error of parsing or writing the binding.
+    #[must_use]
+    pub fn error(&self) -> &ErasedError {
+        &self.error
+    }
+
+    /// Consumes this `BindingError`, returning the error of parsing or writing the binding.
+    #[must_use]
+    pub fn into_error(self) -> ErasedError {
+        self.error
+    }
+}
+
+impl From<PatchError> for BindingError {
+    fn from(error: PatchError) -> Self {
+        match error {
+            PatchError::Rejected { id, error } => BindingError { id, error },
+            PatchError::UnknownPath { ref id } => BindingError {
+                id: id.clone(),
+                error: Box::new(error),
+            },
+        }
+    }
+}
+
+impl Display for BindingError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}: {}", self.id, self.error)
+    }
+}
+
+impl core::error::Error for BindingError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(&*self.error)
+    }
+}
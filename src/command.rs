@@ -0,0 +1,212 @@
+use crate::{HasGetter, HasSetter};
+use core::fmt::{Debug, Formatter};
+use core::marker::PhantomData;
+
+/// A write to a value of type `S` that can be undone, for building undo stacks of edits.
+///
+/// [`SetCommand`] and [`OverCommand`] implement it for writes through optics, capturing the
+/// focus they overwrite when executed, so undoing them restores it.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{Command, OverCommand, SetCommand, field_lens};
+///
+/// struct Document {
+///     title: String,
+///     revision: u32,
+/// }
+///
+/// let mut document = Document { title: "Draft".to_string(), revision: 1 };
+/// let mut history: Vec<Box<dyn Command<Document>>> = vec![
+///     Box::new(SetCommand::new(field_lens!(Document, title), "Final".to_string())),
+///     Box::new(OverCommand::new(field_lens!(Document, revision), |revision| revision + 1)),
+/// ];
+///
+/// let executed: Vec<bool> = history
+///     .iter_mut()
+///     .map(|command| command.execute(&mut document))
+///     .collect();
+/// assert_eq!(executed, [true, true]);
+/// assert_eq!((document.title.as_str(), document.revision), ("Final", 2));
+///
+/// history.iter_mut().rev().for_each(|command| command.undo(&mut document));
+/// assert_eq!((document.title.as_str(), document.revision), ("Draft", 1));
+/// ```
+pub trait Command<S> {
+    /// Applies the write to `target`, returning `true` if it was applied.
+    ///
+    /// Commands writing through an optic that has no focus in `target`, such as a prism that does
+    /// not match, leave it unchanged and return `false`.
+    ///
+    /// # Parameters
+    ///
+    /// - `target`: The value to write to.
+    fn execute(&mut self, target: &mut S) -> bool;
+
+    /// Reverts the last execution of the write on `target`.
+    ///
+    /// Does nothing if the command was not executed since it was last undone, or if its last
+    /// execution found no focus to write to.
+    ///
+    /// # Parameters
+    ///
+    /// - `target`: The value the command was executed on.
+    fn undo(&mut self, target: &mut S);
+}
+
+#[cfg(feature = "alloc")]
+impl<S, C: Command<S> + ?Sized> Command<S> for alloc::boxed::Box<C> {
+    fn execute(&mut self, target: &mut S) -> bool {
+        (**self).execute(target)
+    }
+
+    fn undo(&mut self, target: &mut S) {
+        (**self).undo(target);
+    }
+}
+
+/// A [`Command`] setting the focus of an optic to a value.
+///
+/// Executing the command does nothing and returns `false` if the optic has no focus in the target,
+/// so undoing it does nothing either.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{Command, SetCommand, option};
+///
+/// let mut port = Some(8080);
+/// let mut command = SetCommand::new(option::some(), 9090);
+///
+/// assert!(command.execute(&mut port));
+/// assert_eq!(port, Some(9090));
+///
+/// let mut missing = None;
+/// assert!(!command.execute(&mut missing));
+/// assert_eq!(missing, None);
+/// ```
+pub struct SetCommand<S, A, O> {
+    optic: O,
+    value: A,
+    prior: Option<A>,
+    _phantom: PhantomData<fn(&mut S)>,
+}
+
+impl<S, A, O> SetCommand<S, A, O>
+where
+    O: HasGetter<S, A> + HasSetter<S, A>,
+    A: Clone,
+{
+    /// Creates a new command setting the focus of `optic` to `value`.
+    ///
+    /// # Parameters
+    ///
+    /// - `optic`: The optic to set the focus of.
+    /// - `value`: The value to set the focus to.
+    #[must_use]
+    pub fn new(optic: O, value: A) -> Self {
+        SetCommand {
+            optic,
+            value,
+            prior: None,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<S, A, O> Command<S> for SetCommand<S, A, O>
+where
+    O: HasGetter<S, A> + HasSetter<S, A>,
+    A: Clone,
+{
+    fn execute(&mut self, target: &mut S) -> bool {
+        let Ok(prior) = self.optic.try_get(target) else {
+            self.prior = None;
+            return false;
+        };
+        self.optic.set(target, self.value.clone());
+        self.prior = Some(prior);
+        true
+    }
+
+    fn undo(&mut self, target: &mut S) {
+        if let Some(prior) = self.prior.take() {
+            self.optic.set(target, prior);
+        }
+    }
+}
+
+impl<S, A: Debug, O> Debug for SetCommand<S, A, O> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SetCommand")
+            .field("value", &self.value)
+            .field("prior", &self.prior)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A [`Command`] replacing the focus of an optic with the result of a function applied to it.
+///
+/// Executing the command does nothing and returns `false` if the optic has no focus in the target,
+/// so undoing it does nothing either.
+pub struct OverCommand<S, A, O, F> {
+    optic: O,
+    f: F,
+    prior: Option<A>,
+    _phantom: PhantomData<fn(&mut S)>,
+}
+
+impl<S, A, O, F> OverCommand<S, A, O, F>
+where
+    O: HasGetter<S, A> + HasSetter<S, A>,
+    A: Clone,
+    F: Fn(A) -> A,
+{
+    /// Creates a new command replacing the focus of `optic` with the result of `f` applied to it.
+    ///
+    /// # Parameters
+    ///
+    /// - `optic`: The optic to transform the focus of.
+    /// - `f`: The function computing the new focus from the current one.
+    #[must_use]
+    pub fn new(optic: O, f: F) -> Self {
+        OverCommand {
+            optic,
+            f,
+            prior: None,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<S, A, O, F> Command<S> for OverCommand<S, A, O, F>
+where
+    O: HasGetter<S, A> + HasSetter<S, A>,
+    A: Clone,
+    F: Fn(A) -> A,
+{
+    fn execute(&mut self, target: &mut S) -> bool {
+        let Ok(prior) = self.optic.try_get(target) else {
+            self.prior = None;
+            return false;
+        };
+        self.optic.set(target, (self.f)(prior.clone()));
+        self.prior = Some(prior);
+        true
+    }
+
+    fn undo(&mut self, target: &mut S) {
+        if let Some(prior) = self.prior.take() {
+            self.optic.set(target, prior);
+        }
+    }
+}
+
+impl<S, A: Debug, O, F> Debug for OverCommand<S, A, O, F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("OverCommand")
+            .field("prior", &self.prior)
+            .finish_non_exhaustive()
+    }
+}
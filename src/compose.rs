@@ -0,0 +1,253 @@
+/// Composes a chain of optics, applying the appropriate `compose_with_XXX` method at every step.
+///
+/// Long chains of prisms and fallible isos need the common error type to be spelled out at every
+/// step where it can not be inferred, such as `compose_with_fallible_iso::<(), _, _>(...)`.
+/// This macro takes the error type once, and threads it through all the compositions that require
+/// it. As macros can not inspect the types of their arguments, every optic is prefixed with its
+/// kind, and the macro keeps track of the kind of the resulting optic after each step according to
+/// the composition table.
+///
+/// # Syntax
+///
+/// ```ignore
+/// compose!(kind1 optic1, kind2 optic2, ...)
+/// compose!(err = ErrorType; kind1 optic1, kind2 optic2, ...)
+/// compose!(err = ErrorType, reverse_err = ReverseErrorType; kind1 optic1, kind2 optic2, ...)
+/// ```
+///
+/// - `kind`: One of `partial_getter`, `getter`, `setter`, `fallible_setter`, `prism`, `lens`, `iso`,
+///   `fallible_iso` or `traversal`, the kind of the optic that follows it.
+/// - `optic`: An expression evaluating to the `Impl` wrapper of the given kind.
+/// - `ErrorType`: The common getter error type used wherever two fallible optics are composed.
+///   When omitted, it is inferred.
+/// - `ReverseErrorType`: The common reverse error type used when two fallible isos are composed.
+///   Defaults to `ErrorType`.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{compose, mapped_fallible_iso, mapped_lens, mapped_prism, HasGetter, HasSetter};
+///
+/// #[derive(Debug, Clone)]
+/// struct HttpConfig {
+///     bind_address: Option<String>,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// struct AppConfig {
+///     http: HttpConfig,
+/// }
+///
+/// let http_lens = mapped_lens(|app: &AppConfig| app.http.clone(), |app, http| app.http = http);
+/// let bind_address_prism = mapped_prism(
+///     |http: &HttpConfig| http.bind_address.clone().ok_or(()),
+///     |http, addr| http.bind_address = Some(addr),
+/// );
+/// let port_fallible_iso = mapped_fallible_iso(
+///     |addr: &String| addr.rsplit(':').next().and_then(|port| port.parse::<u16>().ok()).ok_or(()),
+///     |port: &u16| Ok::<_, ()>(format!("0.0.0.0:{port}")),
+/// );
+///
+/// let port_prism = compose!(err = ();
+///     lens http_lens,
+///     prism bind_address_prism,
+///     fallible_iso port_fallible_iso,
+/// );
+///
+/// let mut config = AppConfig { http: HttpConfig { bind_address: Some("127.0.0.1:8080".to_string()) } };
+///
+/// assert_eq!(port_prism.try_get(&config), Ok(8080));
+/// port_prism.set(&mut config, 8081);
+/// assert_eq!(config.http.bind_address, Some("0.0.0.0:8081".to_string()));
+/// ```
+///
+/// # Notes
+///
+/// - A single optic is returned unchanged.
+/// - Compositions that are not possible (e.g. a `partial_getter` followed by a `setter`) fail to
+///   match any rule of the macro.
+#[macro_export]
+macro_rules! compose {
+    (@chain [$err:ty] [$rev:ty] $kind:ident ($acc:expr)) => {
+        $acc
+    };
+
+    (@chain [$err:ty] [$rev:ty] partial_getter ($acc:expr) partial_getter ($next:expr) $($rest:tt)*) => {
+        $crate::compose!(@chain [$err] [$rev] partial_getter ($acc.compose_with_partial_getter::<$err, _, _>($next)) $($rest)*)
+    };
+    (@chain [$err:ty] [$rev:ty] partial_getter ($acc:expr) getter ($next:expr) $($rest:tt)*) => {
+        $crate::compose!(@chain [$err] [$rev] partial_getter ($acc.compose_with_getter($next)) $($rest)*)
+    };
+    (@chain [$err:ty] [$rev:ty] partial_getter ($acc:expr) prism ($next:expr) $($rest:tt)*) => {
+        $crate::compose!(@chain [$err] [$rev] partial_getter ($acc.compose_with_prism::<$err, _, _>($next)) $($rest)*)
+    };
+    (@chain [$err:ty] [$rev:ty] partial_getter ($acc:expr) lens ($next:expr) $($rest:tt)*) => {
+        $crate::compose!(@chain [$err] [$rev] partial_getter ($acc.compose_with_lens($next)) $($rest)*)
+    };
+    (@chain [$err:ty] [$rev:ty] partial_getter ($acc:expr) iso ($next:expr) $($rest:tt)*) => {
+        $crate::compose!(@chain [$err] [$rev] partial_getter ($acc.compose_with_iso($next)) $($rest)*)
+    };
+    (@chain [$err:ty] [$rev:ty] partial_getter ($acc:expr) fallible_iso ($next:expr) $($rest:tt)*) => {
+        $crate::compose!(@chain [$err] [$rev] partial_getter ($acc.compose_with_fallible_iso::<$err, _, _>($next)) $($rest)*)
+    };
+    (@chain [$err:ty] [$rev:ty] getter ($acc:expr) partial_getter ($next:expr) $($rest:tt)*) => {
+        $crate::compose!(@chain [$err] [$rev] partial_getter ($acc.compose_with_partial_getter($next)) $($rest)*)
+    };
+    (@chain [$err:ty] [$rev:ty] getter ($acc:expr) getter ($next:expr) $($rest:tt)*) => {
+        $crate::compose!(@chain [$err] [$rev] getter ($acc.compose_with_getter($next)) $($rest)*)
+    };
+    (@chain [$err:ty] [$rev:ty] getter ($acc:expr) prism ($next:expr) $($rest:tt)*) => {
+        $crate::compose!(@chain [$err] [$rev] partial_getter ($acc.compose_with_prism($next)) $($rest)*)
+    };
+    (@chain [$err:ty] [$rev:ty] getter ($acc:expr) lens ($next:expr) $($rest:tt)*) => {
+        $crate::compose!(@chain [$err] [$rev] getter ($acc.compose_with_lens($next)) $($rest)*)
+    };
+    (@chain [$err:ty] [$rev:ty] getter ($acc:expr) iso ($next:expr) $($rest:tt)*) => {
+        $crate::compose!(@chain [$err] [$rev] getter ($acc.compose_with_iso($next)) $($rest)*)
+    };
+    (@chain [$err:ty] [$rev:ty] getter ($acc:expr) fallible_iso ($next:expr) $($rest:tt)*) => {
+        $crate::compose!(@chain [$err] [$rev] partial_getter ($acc.compose_with_fallible_iso($next)) $($rest)*)
+    };
+    (@chain [$err:ty] [$rev:ty] prism ($acc:expr) partial_getter ($next:expr) $($rest:tt)*) => {
+        $crate::compose!(@chain [$err] [$rev] partial_getter ($acc.compose_with_partial_getter::<$err, _, _>($next)) $($rest)*)
+    };
+    (@chain [$err:ty] [$rev:ty] prism ($acc:expr) getter ($next:expr) $($rest:tt)*) => {
+        $crate::compose!(@chain [$err] [$rev] partial_getter ($acc.compose_with_getter($next)) $($rest)*)
+    };
+    (@chain [$err:ty] [$rev:ty] prism ($acc:expr) setter ($next:expr) $($rest:tt)*) => {
+        $crate::compose!(@chain [$err] [$rev] setter ($acc.compose_with_setter($next)) $($rest)*)
+    };
+    (@chain [$err:ty] [$rev:ty] prism ($acc:expr) fallible_setter ($next:expr) $($rest:tt)*) => {
+        $crate::compose!(@chain [$err] [$rev] fallible_setter ($acc.compose_with_fallible_setter::<$err, _, _>($next)) $($rest)*)
+    };
+    (@chain [$err:ty] [$rev:ty] prism ($acc:expr) prism ($next:expr) $($rest:tt)*) => {
+        $crate::compose!(@chain [$err] [$rev] prism ($acc.compose_with_prism::<$err, _, _>($next)) $($rest)*)
+    };
+    (@chain [$err:ty] [$rev:ty] prism ($acc:expr) lens ($next:expr) $($rest:tt)*) => {
+        $crate::compose!(@chain [$err] [$rev] prism ($acc.compose_with_lens($next)) $($rest)*)
+    };
+    (@chain [$err:ty] [$rev:ty] prism ($acc:expr) iso ($next:expr) $($rest:tt)*) => {
+        $crate::compose!(@chain [$err] [$rev] prism ($acc.compose_with_iso($next)) $($rest)*)
+    };
+    (@chain [$err:ty] [$rev:ty] prism ($acc:expr) fallible_iso ($next:expr) $($rest:tt)*) => {
+        $crate::compose!(@chain [$err] [$rev] prism ($acc.compose_with_fallible_iso::<$err, _, _>($next)) $($rest)*)
+    };
+    (@chain [$err:ty] [$rev:ty] lens ($acc:expr) partial_getter ($next:expr) $($rest:tt)*) => {
+        $crate::compose!(@chain [$err] [$rev] partial_getter ($acc.compose_with_partial_getter($next)) $($rest)*)
+    };
+    (@chain [$err:ty] [$rev:ty] lens ($acc:expr) getter ($next:expr) $($rest:tt)*) => {
+        $crate::compose!(@chain [$err] [$rev] getter ($acc.compose_with_getter($next)) $($rest)*)
+    };
+    (@chain [$err:ty] [$rev:ty] lens ($acc:expr) setter ($next:expr) $($rest:tt)*) => {
+        $crate::compose!(@chain [$err] [$rev] setter ($acc.compose_with_setter($next)) $($rest)*)
+    };
+    (@chain [$err:ty] [$rev:ty] lens ($acc:expr) fallible_setter ($next:expr) $($rest:tt)*) => {
+        $crate::compose!(@chain [$err] [$rev] fallible_setter ($acc.compose_with_fallible_setter($next)) $($rest)*)
+    };
+    (@chain [$err:ty] [$rev:ty] lens ($acc:expr) prism ($next:expr) $($rest:tt)*) => {
+        $crate::compose!(@chain [$err] [$rev] prism ($acc.compose_with_prism($next)) $($rest)*)
+    };
+    (@chain [$err:ty] [$rev:ty] lens ($acc:expr) lens ($next:expr) $($rest:tt)*) => {
+        $crate::compose!(@chain [$err] [$rev] lens ($acc.compose_with_lens($next)) $($rest)*)
+    };
+    (@chain [$err:ty] [$rev:ty] lens ($acc:expr) iso ($next:expr) $($rest:tt)*) => {
+        $crate::compose!(@chain [$err] [$rev] lens ($acc.compose_with_iso($next)) $($rest)*)
+    };
+    (@chain [$err:ty] [$rev:ty] lens ($acc:expr) fallible_iso ($next:expr) $($rest:tt)*) => {
+        $crate::compose!(@chain [$err] [$rev] prism ($acc.compose_with_fallible_iso($next)) $($rest)*)
+    };
+    (@chain [$err:ty] [$rev:ty] iso ($acc:expr) partial_getter ($next:expr) $($rest:tt)*) => {
+        $crate::compose!(@chain [$err] [$rev] partial_getter ($acc.compose_with_partial_getter($next)) $($rest)*)
+    };
+    (@chain [$err:ty] [$rev:ty] iso ($acc:expr) getter ($next:expr) $($rest:tt)*) => {
+        $crate::compose!(@chain [$err] [$rev] getter ($acc.compose_with_getter($next)) $($rest)*)
+    };
+    (@chain [$err:ty] [$rev:ty] iso ($acc:expr) setter ($next:expr) $($rest:tt)*) => {
+        $crate::compose!(@chain [$err] [$rev] setter ($acc.compose_with_setter($next)) $($rest)*)
+    };
+    (@chain [$err:ty] [$rev:ty] iso ($acc:expr) fallible_setter ($next:expr) $($rest:tt)*) => {
+        $crate::compose!(@chain [$err] [$rev] fallible_setter ($acc.compose_with_fallible_setter($next)) $($rest)*)
+    };
+    (@chain [$err:ty] [$rev:ty] iso ($acc:expr) prism ($next:expr) $($rest:tt)*) => {
+        $crate::compose!(@chain [$err] [$rev] prism ($acc.compose_with_prism($next)) $($rest)*)
+    };
+    (@chain [$err:ty] [$rev:ty] iso ($acc:expr) lens ($next:expr) $($rest:tt)*) => {
+        $crate::compose!(@chain [$err] [$rev] lens ($acc.compose_with_lens($next)) $($rest)*)
+    };
+    (@chain [$err:ty] [$rev:ty] iso ($acc:expr) iso ($next:expr) $($rest:tt)*) => {
+        $crate::compose!(@chain [$err] [$rev] iso ($acc.compose_with_iso($next)) $($rest)*)
+    };
+    (@chain [$err:ty] [$rev:ty] iso ($acc:expr) fallible_iso ($next:expr) $($rest:tt)*) => {
+        $crate::compose!(@chain [$err] [$rev] fallible_iso ($acc.compose_with_fallible_iso($next)) $($rest)*)
+    };
+    (@chain [$err:ty] [$rev:ty] fallible_iso ($acc:expr) partial_getter ($next:expr) $($rest:tt)*) => {
+        $crate::compose!(@chain [$err] [$rev] partial_getter ($acc.compose_with_partial_getter::<$err, _, _>($next)) $($rest)*)
+    };
+    (@chain [$err:ty] [$rev:ty] fallible_iso ($acc:expr) getter ($next:expr) $($rest:tt)*) => {
+        $crate::compose!(@chain [$err] [$rev] partial_getter ($acc.compose_with_getter($next)) $($rest)*)
+    };
+    (@chain [$err:ty] [$rev:ty] fallible_iso ($acc:expr) setter ($next:expr) $($rest:tt)*) => {
+        $crate::compose!(@chain [$err] [$rev] setter ($acc.compose_with_setter($next)) $($rest)*)
+    };
+    (@chain [$err:ty] [$rev:ty] fallible_iso ($acc:expr) fallible_setter ($next:expr) $($rest:tt)*) => {
+        $crate::compose!(@chain [$err] [$rev] fallible_setter ($acc.compose_with_fallible_setter::<$err, _, _>($next)) $($rest)*)
+    };
+    (@chain [$err:ty] [$rev:ty] fallible_iso ($acc:expr) prism ($next:expr) $($rest:tt)*) => {
+        $crate::compose!(@chain [$err] [$rev] prism ($acc.compose_with_prism::<$err, _, _>($next)) $($rest)*)
+    };
+    (@chain [$err:ty] [$rev:ty] fallible_iso ($acc:expr) lens ($next:expr) $($rest:tt)*) => {
+        $crate::compose!(@chain [$err] [$rev] prism ($acc.compose_with_lens($next)) $($rest)*)
+    };
+    (@chain [$err:ty] [$rev:ty] fallible_iso ($acc:expr) iso ($next:expr) $($rest:tt)*) => {
+        $crate::compose!(@chain [$err] [$rev] fallible_iso ($acc.compose_with_iso($next)) $($rest)*)
+    };
+    (@chain [$err:ty] [$rev:ty] fallible_iso ($acc:expr) fallible_iso ($next:expr) $($rest:tt)*) => {
+        $crate::compose!(@chain [$err] [$rev] fallible_iso ($acc.compose_with_fallible_iso::<$err, $rev, _, _>($next)) $($rest)*)
+    };
+
+    (@chain [$err:ty] [$rev:ty] prism ($acc:expr) traversal ($next:expr) $($rest:tt)*) => {
+        $crate::compose!(@chain [$err] [$rev] traversal ($acc.compose_with_traversal($next)) $($rest)*)
+    };
+    (@chain [$err:ty] [$rev:ty] lens ($acc:expr) traversal ($next:expr) $($rest:tt)*) => {
+        $crate::compose!(@chain [$err] [$rev] traversal ($acc.compose_with_traversal($next)) $($rest)*)
+    };
+    (@chain [$err:ty] [$rev:ty] iso ($acc:expr) traversal ($next:expr) $($rest:tt)*) => {
+        $crate::compose!(@chain [$err] [$rev] traversal ($acc.compose_with_traversal($next)) $($rest)*)
+    };
+    (@chain [$err:ty] [$rev:ty] fallible_iso ($acc:expr) traversal ($next:expr) $($rest:tt)*) => {
+        $crate::compose!(@chain [$err] [$rev] traversal ($acc.compose_with_traversal($next)) $($rest)*)
+    };
+    (@chain [$err:ty] [$rev:ty] traversal ($acc:expr) setter ($next:expr) $($rest:tt)*) => {
+        $crate::compose!(@chain [$err] [$rev] setter ($acc.compose_with_setter($next)) $($rest)*)
+    };
+    (@chain [$err:ty] [$rev:ty] traversal ($acc:expr) fallible_setter ($next:expr) $($rest:tt)*) => {
+        $crate::compose!(@chain [$err] [$rev] fallible_setter ($acc.compose_with_fallible_setter($next)) $($rest)*)
+    };
+    (@chain [$err:ty] [$rev:ty] traversal ($acc:expr) prism ($next:expr) $($rest:tt)*) => {
+        $crate::compose!(@chain [$err] [$rev] traversal ($acc.compose_with_prism($next)) $($rest)*)
+    };
+    (@chain [$err:ty] [$rev:ty] traversal ($acc:expr) lens ($next:expr) $($rest:tt)*) => {
+        $crate::compose!(@chain [$err] [$rev] traversal ($acc.compose_with_lens($next)) $($rest)*)
+    };
+    (@chain [$err:ty] [$rev:ty] traversal ($acc:expr) iso ($next:expr) $($rest:tt)*) => {
+        $crate::compose!(@chain [$err] [$rev] traversal ($acc.compose_with_iso($next)) $($rest)*)
+    };
+    (@chain [$err:ty] [$rev:ty] traversal ($acc:expr) fallible_iso ($next:expr) $($rest:tt)*) => {
+        $crate::compose!(@chain [$err] [$rev] traversal ($acc.compose_with_fallible_iso($next)) $($rest)*)
+    };
+    (@chain [$err:ty] [$rev:ty] traversal ($acc:expr) traversal ($next:expr) $($rest:tt)*) => {
+        $crate::compose!(@chain [$err] [$rev] traversal ($acc.compose_with_traversal($next)) $($rest)*)
+    };
+
+    (err = $err:ty, reverse_err = $rev:ty; $($kind:ident $optic:expr),+ $(,)?) => {
+        $crate::compose!(@chain [$err] [$rev] $($kind ($optic))+)
+    };
+
+    (err = $err:ty; $($kind:ident $optic:expr),+ $(,)?) => {
+        $crate::compose!(@chain [$err] [$err] $($kind ($optic))+)
+    };
+
+    ($($kind:ident $optic:expr),+ $(,)?) => {
+        $crate::compose!(@chain [_] [_] $($kind ($optic))+)
+    };
+}
@@ -0,0 +1,91 @@
+use crate::OuterLabels;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::{Display, Formatter};
+
+/// The error of an optic labelled with a `context` method, carrying the path of labels leading to
+/// the optic that failed.
+///
+/// The path ends with the label given to the optic that failed, preceded by the labels of the
+/// optics it is composed after, which compositions pass down automatically. It can be extended
+/// further towards the root with [`within`](Self::within), e.g. in the error mappers of a
+/// `compose_with_XXX_with_mappers` method. The path is displayed joined by dots, followed by the
+/// original error.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{ContextError, HasGetter, NoneError, option};
+///
+/// let timeout = option::some::<Option<u32>>()
+///     .context("http")
+///     .compose_with_prism::<ContextError<NoneError>, _, _>(option::some::<u32>().context("timeout"));
+/// let error = timeout.try_get(&Some(None)).unwrap_err();
+///
+/// assert_eq!(error.path(), ["http", "timeout"]);
+/// assert_eq!(error.error(), &NoneError);
+/// assert_eq!(error.within("client").path(), ["client", "http", "timeout"]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ContextError<E> {
+    path: Vec<&'static str>,
+    error: E,
+}
+
+impl<E> ContextError<E> {
+    /// Creates a new `ContextError` wrapping `error`, with a path consisting of `label` only.
+    #[must_use]
+    pub fn new(label: &'static str, error: E) -> Self {
+        ContextError {
+            path: vec![label],
+            error,
+        }
+    }
+
+    /// Creates a new `ContextError` wrapping `error`, with a path consisting of the labels passed
+    /// by `outer` followed by `label`.
+    pub(crate) fn within_path(outer: OuterLabels<'_>, label: &'static str, error: E) -> Self {
+        let mut path = Vec::new();
+        outer(&mut |label| path.push(label));
+        path.push(label);
+        ContextError { path, error }
+    }
+
+    /// Prepends `label` to the path of this error, marking that the optic which failed is nested
+    /// within an optic labelled `label`.
+    #[must_use]
+    pub fn within(mut self, label: &'static str) -> Self {
+        self.path.insert(0, label);
+        self
+    }
+
+    /// Returns the path of labels leading to the optic that failed, starting from the outermost one.
+    #[must_use]
+    pub fn path(&self) -> &[&'static str] {
+        &self.path
+    }
+
+    /// Returns a reference to the original error of the optic that failed.
+    #[must_use]
+    pub fn error(&self) -> &E {
+        &self.error
+    }
+
+    /// Consumes this `ContextError`, returning the original error of the optic that failed.
+    #[must_use]
+    pub fn into_error(self) -> E {
+        self.error
+    }
+}
+
+impl<E: Display> Display for ContextError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}: {}", self.path.join("."), self.error)
+    }
+}
+
+impl<E: core::error::Error + 'static> core::error::Error for ContextError<E> {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
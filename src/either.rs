@@ -0,0 +1,67 @@
+use core::fmt::{Display, Formatter};
+
+/// The error of an optic composed from two optics that can both fail, identifying which of them
+/// failed.
+///
+/// This is the error type used by [`ComposeWith::then`](crate::ComposeWith::then) and the
+/// `compose_with_XXX_tracking` methods when both optics are fallible, so optics with different
+/// error types can be composed without spelling out a common error type. In a deep chain of
+/// optics, nesting `Either`s tells the caller exactly which optic failed. When both errors are the
+/// same type, [`Either::into_inner`] unifies them.
+///
+/// It displays as the error of the optic that failed, and forwards its source.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{ComposeWith, Either, HasGetter, NoneError, PrefixMissing, option, string};
+///
+/// let name = option::some().then(string::strip_prefix("user:"));
+///
+/// assert_eq!(name.try_get(&Some("user:root".to_string())), Ok("root".to_string()));
+/// assert_eq!(name.try_get(&None), Err(Either::Left(NoneError)));
+/// assert_eq!(name.try_get(&Some("root".to_string())), Err(Either::Right(PrefixMissing)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Either<L, R> {
+    /// The first optic failed.
+    Left(L),
+    /// The second optic failed.
+    Right(R),
+}
+
+impl<T> Either<T, T> {
+    /// Unifies the two sides of an `Either` with the same type on both sides.
+    #[must_use]
+    pub fn into_inner(self) -> T {
+        match self {
+            Either::Left(t) | Either::Right(t) => t,
+        }
+    }
+}
+
+impl<L: Display, R: Display> Display for Either<L, R> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Either::Left(l) => l.fmt(f),
+            Either::Right(r) => r.fmt(f),
+        }
+    }
+}
+
+impl<L, R> core::error::Error for Either<L, R>
+where
+    L: core::error::Error,
+    R: core::error::Error,
+{
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Either::Left(l) => l.source(),
+            Either::Right(r) => r.source(),
+        }
+    }
+}
+
+/// The error of an optic composed with a `compose_with_XXX_tracking` method, an alias of
+/// [`Either`].
+pub type ComposedError<E1, E2> = Either<E1, E2>;
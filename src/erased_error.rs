@@ -0,0 +1,25 @@
+use alloc::boxed::Box;
+
+/// The error of an optic whose errors were erased with an `erase_err` method.
+///
+/// Any error implementing [`core::error::Error`], as well as `&str` and `String` messages, can be
+/// converted into it, so deep chains of compositions mixing many error types can report their
+/// failures with a single type, still displaying the original error.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{ComposeWith, ErasedError, HasGetter, mapped_fallible_iso, option};
+/// use core::num::ParseIntError;
+///
+/// let parse = mapped_fallible_iso(
+///     |s: &String| s.parse::<u16>(),
+///     |port: &u16| Ok::<_, ParseIntError>(port.to_string()),
+/// );
+/// let port = option::some().then(parse).erase_err();
+///
+/// let error: ErasedError = port.try_get(&None).unwrap_err();
+/// assert_eq!(error.to_string(), "the value is None");
+/// assert_eq!(port.try_get(&Some("8080".to_string())).ok(), Some(8080));
+/// ```
+pub type ErasedError = Box<dyn core::error::Error + Send + Sync>;
@@ -0,0 +1,668 @@
+#[cfg(any(feature = "getter", feature = "setter"))]
+use crate::Setter;
+#[cfg(feature = "setter")]
+use crate::SetterImpl;
+#[cfg(any(feature = "getter", feature = "prism", feature = "setter"))]
+use crate::optics::fallible_setter::ComposedFallibleSetter;
+use crate::optics::fallible_setter::NestedFallibleSetter;
+#[cfg(any(feature = "iso", feature = "fallible-iso"))]
+use crate::optics::fallible_setter::ReversedFallibleSetter;
+use crate::optics::partial_getter::ComposedPartialGetter;
+#[cfg(all(feature = "setter", any(feature = "getter", feature = "prism")))]
+use crate::optics::setter::ComposedSetter;
+use crate::optics::setter::NestedSetter;
+#[cfg(any(feature = "iso", feature = "fallible-iso"))]
+use crate::optics::setter::ReversedSetter;
+use crate::optics::traversal::{
+    ComposedTraversal, EachFallibleSetter, OverAll, each_fallible_setter, over_all,
+};
+#[cfg(feature = "setter")]
+use crate::optics::traversal::{EachSetter, each_setter};
+#[cfg(any(feature = "lens", feature = "prism"))]
+use crate::optics::traversal::{SingleTraversal, single_traversal};
+use crate::{
+    Either, FallibleSetter, FallibleSetterImpl, HasModify, PartialGetter, PartialGetterImpl,
+    Traversal, TraversalImpl,
+};
+use core::ops::Shr;
+#[cfg(feature = "fallible-iso")]
+use {
+    crate::optics::fallible_iso::ComposedFallibleIso,
+    crate::{FallibleIso, FallibleIsoImpl},
+};
+#[cfg(feature = "getter")]
+use {
+    crate::optics::getter::ComposedGetter,
+    crate::{Getter, GetterImpl, infallible},
+    core::convert::identity,
+};
+#[cfg(feature = "iso")]
+use {
+    crate::optics::iso::ComposedIso,
+    crate::{Iso, IsoImpl},
+};
+#[cfg(feature = "lens")]
+use {
+    crate::optics::lens::ComposedLens,
+    crate::{Lens, LensImpl},
+};
+#[cfg(feature = "prism")]
+use {
+    crate::optics::prism::ComposedPrism,
+    crate::{Prism, PrismImpl},
+};
+
+/// Composes an optic with another one, resulting in the most specific optic kind allowed by the
+/// composition table.
+///
+/// This trait is implemented for every valid pair of optic wrappers, and dispatches to the same
+/// composition as the corresponding `compose_with_XXX` method, so chains of optics can be built
+/// without keeping track of the kind of each intermediate result:
+/// `lens.then(prism).then(iso)` is a `PrismImpl`.
+///
+/// When both optics can fail, the error of the composed optic is an [`Either`] of the errors of the
+/// two optics, so optics with different error types can be composed without any annotations. When
+/// only one of them can fail, its error is used as is. Use the `compose_with_XXX` methods to unify
+/// the errors into a common type instead.
+///
+/// Compositions that are not possible, such as a `Getter` followed by a `Traversal`, do not
+/// implement this trait, and fail to compile.
+///
+/// The optic wrappers also implement [`Shr`] for every optic they can be composed with, so
+/// `a.then(b).then(c)` can also be written as `a >> b >> c`.
+///
+/// Composition consumes the optics, use [`ComposeWith::compose_ref`] to compose clones of optics
+/// that are reused in several chains.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{ComposeWith, HasGetter, HasSetter, mapped_iso, mapped_lens, mapped_prism};
+///
+/// #[derive(Debug, Clone)]
+/// struct HttpConfig {
+///     port: Option<u16>,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// struct AppConfig {
+///     http: HttpConfig,
+/// }
+///
+/// let http_lens = mapped_lens(|app: &AppConfig| app.http.clone(), |app, http| app.http = http);
+/// let port_prism = mapped_prism(
+///     |http: &HttpConfig| http.port.ok_or(()),
+///     |http, port| http.port = Some(port),
+/// );
+/// let offset_iso = mapped_iso(|port: &u16| u32::from(*port) + 1, |n: &u32| (*n - 1) as u16);
+///
+/// let next_port = http_lens.then(port_prism).then(offset_iso);
+///
+/// let mut config = AppConfig { http: HttpConfig { port: Some(8080) } };
+///
+/// assert_eq!(next_port.try_get(&config), Ok(8081));
+/// next_port.set(&mut config, 9001);
+/// assert_eq!(config.http.port, Some(9000));
+/// ```
+///
+/// The same chain using the `>>` operator:
+///
+/// ```rust
+/// use optics::{HasGetter, mapped_iso, mapped_lens, mapped_prism};
+///
+/// let first_lens = mapped_lens(|pair: &(Option<u16>, bool)| pair.0, |pair, first| pair.0 = first);
+/// let some_prism = mapped_prism(|port: &Option<u16>| port.ok_or(()), |port, p| *port = Some(p));
+/// let offset_iso = mapped_iso(|port: &u16| u32::from(*port) + 1, |n: &u32| (*n - 1) as u16);
+///
+/// let next_port = first_lens >> some_prism >> offset_iso;
+///
+/// assert_eq!(next_port.try_get(&(Some(8080), true)), Ok(8081));
+/// assert_eq!(next_port.try_get(&(None, true)), Err(()));
+/// ```
+pub trait ComposeWith<O2> {
+    /// The optic resulting from the composition.
+    type Output;
+
+    /// Composes this optic with `other`, focusing through both optics sequentially.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: The optic to compose with.
+    ///
+    /// # Returns
+    ///
+    /// The composed optic, of the most specific kind the pair of optics allows.
+    #[must_use]
+    fn then(self, other: O2) -> Self::Output;
+
+    /// Composes clones of this optic and `other`, leaving both of them usable.
+    ///
+    /// This allows a set of shared base optics to be composed into several chains without
+    /// rebuilding them. Optics built from closures are cloneable when their closures are.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: The optic to compose with.
+    ///
+    /// # Returns
+    ///
+    /// The composed optic, of the most specific kind the pair of optics allows.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use optics::{ComposeWith, HasTotalGetter, mapped_lens};
+    ///
+    /// let outer = mapped_lens(|p: &((u8, u8), u8)| p.0, |p, v| p.0 = v);
+    /// let first = mapped_lens(|p: &(u8, u8)| p.0, |p, v| p.0 = v);
+    /// let second = mapped_lens(|p: &(u8, u8)| p.1, |p, v| p.1 = v);
+    ///
+    /// let outer_first = outer.compose_ref(&first);
+    /// let outer_second = outer.compose_ref(&second);
+    ///
+    /// assert_eq!(outer_first.get(&((1, 2), 3)), 1);
+    /// assert_eq!(outer_second.get(&((1, 2), 3)), 2);
+    /// ```
+    #[must_use]
+    fn compose_ref(&self, other: &O2) -> Self::Output
+    where
+        Self: Clone,
+        O2: Clone,
+    {
+        self.clone().then(other.clone())
+    }
+}
+
+/// Composes two optics, resulting in the most specific optic kind allowed by the composition table.
+///
+/// This is the free function form of [`ComposeWith::then`], for generic code that would otherwise
+/// need to name the composition trait in a method call. Optics that are not wrapped in an `Impl`
+/// struct can be wrapped with `.into()`, such as `LensImpl::from(lens)`.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{compose, HasGetter, Lens, LensImpl, Prism, PrismImpl};
+///
+/// fn first_port<L, P>(servers: L, port: P) -> Result<u16, ()>
+/// where
+///     L: Lens<Vec<Option<u16>>, Option<u16>>,
+///     P: Prism<Option<u16>, u16, GetterError = ()>,
+/// {
+///     let servers_port = compose(LensImpl::from(servers), PrismImpl::from(port));
+///     servers_port.try_get(&vec![Some(80), None])
+/// }
+///
+/// let first = optics::mapped_lens(|v: &Vec<Option<u16>>| v[0], |v, port| v[0] = port);
+/// let some = optics::mapped_prism(|port: &Option<u16>| port.ok_or(()), |port, p| *port = Some(p));
+///
+/// assert_eq!(first_port(first.0, some.0), Ok(80));
+/// ```
+#[must_use]
+pub fn compose<O1, O2>(o1: O1, o2: O2) -> O1::Output
+where
+    O1: ComposeWith<O2>,
+{
+    o1.then(o2)
+}
+
+/// Composes three optics, resulting in the most specific optic kind allowed by the composition
+/// table.
+///
+/// Equivalent to `o1.then(o2).then(o3)`.
+#[must_use]
+pub fn compose3<O1, O2, O3>(o1: O1, o2: O2, o3: O3) -> <O1::Output as ComposeWith<O3>>::Output
+where
+    O1: ComposeWith<O2>,
+    O1::Output: ComposeWith<O3>,
+{
+    o1.then(o2).then(o3)
+}
+
+/// Composes four optics, resulting in the most specific optic kind allowed by the composition
+/// table.
+///
+/// Equivalent to `o1.then(o2).then(o3).then(o4)`.
+#[must_use]
+pub fn compose4<O1, O2, O3, O4>(
+    o1: O1,
+    o2: O2,
+    o3: O3,
+    o4: O4,
+) -> <<O1::Output as ComposeWith<O3>>::Output as ComposeWith<O4>>::Output
+where
+    O1: ComposeWith<O2>,
+    O1::Output: ComposeWith<O3>,
+    <O1::Output as ComposeWith<O3>>::Output: ComposeWith<O4>,
+{
+    o1.then(o2).then(o3).then(o4)
+}
+
+macro_rules! compose_with {
+    ($(
+        $(#[$attr:meta])*
+        $first:ident<$kind1:ident> then $second:ident<$kind2:ident> => $output:ty
+            $(where [$($bound:tt)*])?,
+        |$o1:ident, $o2:ident| $compose:expr;
+    )+) => {
+        $(
+            $(#[$attr])*
+            impl<S, I, A, O1, O2> ComposeWith<$second<I, A, O2>> for $first<S, I, O1>
+            where
+                O1: $kind1<S, I>,
+                O2: $kind2<I, A>,
+                $($($bound)*)?
+            {
+                type Output = $output;
+
+                fn then(self, other: $second<I, A, O2>) -> Self::Output {
+                    let ($o1, $o2) = (self.0, other.0);
+                    $compose.into()
+                }
+            }
+        )+
+    };
+}
+
+compose_with! {
+    PartialGetterImpl<PartialGetter> then PartialGetterImpl<PartialGetter>
+        => PartialGetterImpl<
+            S,
+            A,
+            ComposedPartialGetter<O1, O2, Either<O1::GetterError, O2::GetterError>, S, I, A>,
+        >,
+        |o1, o2| ComposedPartialGetter::new(o1, o2, Either::Left, Either::Right);
+    #[cfg(feature = "getter")]
+    PartialGetterImpl<PartialGetter> then GetterImpl<Getter>
+        => PartialGetterImpl<S, A, ComposedPartialGetter<O1, O2, O1::GetterError, S, I, A>>,
+        |o1, o2| ComposedPartialGetter::new(o1, o2, identity, infallible);
+    #[cfg(feature = "prism")]
+    PartialGetterImpl<PartialGetter> then PrismImpl<Prism>
+        => PartialGetterImpl<
+            S,
+            A,
+            ComposedPartialGetter<O1, O2, Either<O1::GetterError, O2::GetterError>, S, I, A>,
+        >,
+        |o1, o2| ComposedPartialGetter::new(o1, o2, Either::Left, Either::Right);
+    #[cfg(feature = "lens")]
+    PartialGetterImpl<PartialGetter> then LensImpl<Lens>
+        => PartialGetterImpl<S, A, ComposedPartialGetter<O1, O2, O1::GetterError, S, I, A>>,
+        |o1, o2| ComposedPartialGetter::new(o1, o2, identity, infallible);
+    #[cfg(feature = "iso")]
+    PartialGetterImpl<PartialGetter> then IsoImpl<Iso>
+        => PartialGetterImpl<S, A, ComposedPartialGetter<O1, O2, O1::GetterError, S, I, A>>,
+        |o1, o2| ComposedPartialGetter::new(o1, o2, identity, infallible);
+    #[cfg(feature = "fallible-iso")]
+    PartialGetterImpl<PartialGetter> then FallibleIsoImpl<FallibleIso>
+        => PartialGetterImpl<
+            S,
+            A,
+            ComposedPartialGetter<O1, O2, Either<O1::GetterError, O2::GetterError>, S, I, A>,
+        >,
+        |o1, o2| ComposedPartialGetter::new(o1, o2, Either::Left, Either::Right);
+
+    #[cfg(feature = "getter")]
+    GetterImpl<Getter> then PartialGetterImpl<PartialGetter>
+        => PartialGetterImpl<S, A, ComposedPartialGetter<O1, O2, O2::GetterError, S, I, A>>,
+        |o1, o2| ComposedPartialGetter::new(o1, o2, infallible, identity);
+    #[cfg(feature = "getter")]
+    GetterImpl<Getter> then GetterImpl<Getter>
+        => GetterImpl<S, A, ComposedGetter<O1, O2, S, I, A>>,
+        |o1, o2| ComposedGetter::new(o1, o2);
+    #[cfg(all(feature = "getter", feature = "setter"))]
+    GetterImpl<Getter> then SetterImpl<Setter>
+        => SetterImpl<S, A, ComposedSetter<O1, O2, S, I, A>>
+        where [O1: Setter<S, I>],
+        |o1, o2| ComposedSetter::new(o1, o2);
+    #[cfg(feature = "getter")]
+    GetterImpl<Getter> then FallibleSetterImpl<FallibleSetter>
+        => FallibleSetterImpl<S, A, ComposedFallibleSetter<O1, O2, O2::SetterError, S, I, A>>
+        where [O1: Setter<S, I>],
+        |o1, o2| ComposedFallibleSetter::new(o1, o2, infallible, identity);
+    #[cfg(all(feature = "getter", feature = "prism"))]
+    GetterImpl<Getter> then PrismImpl<Prism>
+        => PartialGetterImpl<S, A, ComposedPartialGetter<O1, O2, O2::GetterError, S, I, A>>,
+        |o1, o2| ComposedPartialGetter::new(o1, o2, infallible, identity);
+    #[cfg(feature = "lens")]
+    GetterImpl<Getter> then LensImpl<Lens>
+        => GetterImpl<S, A, ComposedGetter<O1, O2, S, I, A>>,
+        |o1, o2| ComposedGetter::new(o1, o2);
+    #[cfg(feature = "iso")]
+    GetterImpl<Getter> then IsoImpl<Iso>
+        => GetterImpl<S, A, ComposedGetter<O1, O2, S, I, A>>,
+        |o1, o2| ComposedGetter::new(o1, o2);
+    #[cfg(all(feature = "fallible-iso", feature = "getter"))]
+    GetterImpl<Getter> then FallibleIsoImpl<FallibleIso>
+        => PartialGetterImpl<S, A, ComposedPartialGetter<O1, O2, O2::GetterError, S, I, A>>,
+        |o1, o2| ComposedPartialGetter::new(o1, o2, infallible, identity);
+
+    #[cfg(feature = "prism")]
+    PrismImpl<Prism> then PartialGetterImpl<PartialGetter>
+        => PartialGetterImpl<
+            S,
+            A,
+            ComposedPartialGetter<O1, O2, Either<O1::GetterError, O2::GetterError>, S, I, A>,
+        >,
+        |o1, o2| ComposedPartialGetter::new(o1, o2, Either::Left, Either::Right);
+    #[cfg(all(feature = "getter", feature = "prism"))]
+    PrismImpl<Prism> then GetterImpl<Getter>
+        => PartialGetterImpl<S, A, ComposedPartialGetter<O1, O2, O1::GetterError, S, I, A>>,
+        |o1, o2| ComposedPartialGetter::new(o1, o2, identity, infallible);
+    #[cfg(feature = "prism")]
+    PrismImpl<Prism> then SetterImpl<Setter>
+        => SetterImpl<S, A, ComposedSetter<O1, O2, S, I, A>>,
+        |o1, o2| ComposedSetter::new(o1, o2);
+    #[cfg(feature = "prism")]
+    PrismImpl<Prism> then FallibleSetterImpl<FallibleSetter>
+        => FallibleSetterImpl<
+            S,
+            A,
+            ComposedFallibleSetter<O1, O2, Either<O1::GetterError, O2::SetterError>, S, I, A>,
+        >,
+        |o1, o2| ComposedFallibleSetter::new(o1, o2, Either::Left, Either::Right);
+    #[cfg(feature = "prism")]
+    PrismImpl<Prism> then PrismImpl<Prism>
+        => PrismImpl<S, A, ComposedPrism<O1, O2, Either<O1::GetterError, O2::GetterError>, S, I, A>>,
+        |o1, o2| ComposedPrism::new(o1, o2, Either::Left, Either::Right);
+    #[cfg(all(feature = "lens", feature = "prism"))]
+    PrismImpl<Prism> then LensImpl<Lens>
+        => PrismImpl<S, A, ComposedPrism<O1, O2, O1::GetterError, S, I, A>>,
+        |o1, o2| ComposedPrism::new(o1, o2, identity, infallible);
+    #[cfg(all(feature = "iso", feature = "prism"))]
+    PrismImpl<Prism> then IsoImpl<Iso>
+        => PrismImpl<S, A, ComposedPrism<O1, O2, O1::GetterError, S, I, A>>,
+        |o1, o2| ComposedPrism::new(o1, o2, identity, infallible);
+    #[cfg(feature = "fallible-iso")]
+    PrismImpl<Prism> then FallibleIsoImpl<FallibleIso>
+        => PrismImpl<S, A, ComposedPrism<O1, O2, Either<O1::GetterError, O2::GetterError>, S, I, A>>,
+        |o1, o2| ComposedPrism::new(o1, o2, Either::Left, Either::Right);
+    #[cfg(feature = "prism")]
+    PrismImpl<Prism> then TraversalImpl<Traversal>
+        => TraversalImpl<S, A, ComposedTraversal<SingleTraversal<S, I, O1>, O2, S, I, A>>,
+        |o1, o2| ComposedTraversal::new(single_traversal(o1), o2);
+
+    #[cfg(feature = "lens")]
+    LensImpl<Lens> then PartialGetterImpl<PartialGetter>
+        => PartialGetterImpl<S, A, ComposedPartialGetter<O1, O2, O2::GetterError, S, I, A>>,
+        |o1, o2| ComposedPartialGetter::new(o1, o2, infallible, identity);
+    #[cfg(feature = "lens")]
+    LensImpl<Lens> then GetterImpl<Getter>
+        => GetterImpl<S, A, ComposedGetter<O1, O2, S, I, A>>,
+        |o1, o2| ComposedGetter::new(o1, o2);
+    #[cfg(feature = "lens")]
+    LensImpl<Lens> then SetterImpl<Setter>
+        => SetterImpl<S, A, ComposedSetter<O1, O2, S, I, A>>,
+        |o1, o2| ComposedSetter::new(o1, o2);
+    #[cfg(feature = "lens")]
+    LensImpl<Lens> then FallibleSetterImpl<FallibleSetter>
+        => FallibleSetterImpl<S, A, ComposedFallibleSetter<O1, O2, O2::SetterError, S, I, A>>,
+        |o1, o2| ComposedFallibleSetter::new(o1, o2, infallible, identity);
+    #[cfg(all(feature = "lens", feature = "prism"))]
+    LensImpl<Lens> then PrismImpl<Prism>
+        => PrismImpl<S, A, ComposedPrism<O1, O2, O2::GetterError, S, I, A>>,
+        |o1, o2| ComposedPrism::new(o1, o2, infallible, identity);
+    #[cfg(feature = "lens")]
+    LensImpl<Lens> then LensImpl<Lens>
+        => LensImpl<S, A, ComposedLens<O1, O2, S, I, A>>,
+        |o1, o2| ComposedLens::new(o1, o2);
+    #[cfg(feature = "iso")]
+    LensImpl<Lens> then IsoImpl<Iso>
+        => LensImpl<S, A, ComposedLens<O1, O2, S, I, A>>,
+        |o1, o2| ComposedLens::new(o1, o2);
+    #[cfg(all(feature = "fallible-iso", feature = "lens"))]
+    LensImpl<Lens> then FallibleIsoImpl<FallibleIso>
+        => PrismImpl<S, A, ComposedPrism<O1, FallibleIsoImpl<I, A, O2>, O2::GetterError, S, I, A>>,
+        |o1, o2| ComposedPrism::new(o1, FallibleIsoImpl::from(o2), infallible, identity);
+    #[cfg(feature = "lens")]
+    LensImpl<Lens> then TraversalImpl<Traversal>
+        => TraversalImpl<S, A, ComposedTraversal<SingleTraversal<S, I, O1>, O2, S, I, A>>,
+        |o1, o2| ComposedTraversal::new(single_traversal(o1), o2);
+
+    #[cfg(feature = "iso")]
+    IsoImpl<Iso> then PartialGetterImpl<PartialGetter>
+        => PartialGetterImpl<S, A, ComposedPartialGetter<O1, O2, O2::GetterError, S, I, A>>,
+        |o1, o2| ComposedPartialGetter::new(o1, o2, infallible, identity);
+    #[cfg(feature = "iso")]
+    IsoImpl<Iso> then GetterImpl<Getter>
+        => GetterImpl<S, A, ComposedGetter<O1, O2, S, I, A>>,
+        |o1, o2| ComposedGetter::new(o1, o2);
+    #[cfg(feature = "iso")]
+    IsoImpl<Iso> then SetterImpl<Setter>
+        => SetterImpl<S, A, ComposedSetter<O1, O2, S, I, A>>,
+        |o1, o2| ComposedSetter::new(o1, o2);
+    #[cfg(feature = "iso")]
+    IsoImpl<Iso> then FallibleSetterImpl<FallibleSetter>
+        => FallibleSetterImpl<S, A, ComposedFallibleSetter<O1, O2, O2::SetterError, S, I, A>>,
+        |o1, o2| ComposedFallibleSetter::new(o1, o2, infallible, identity);
+    #[cfg(all(feature = "iso", feature = "prism"))]
+    IsoImpl<Iso> then PrismImpl<Prism>
+        => PrismImpl<S, A, ComposedPrism<O1, O2, O2::GetterError, S, I, A>>,
+        |o1, o2| ComposedPrism::new(o1, o2, infallible, identity);
+    #[cfg(feature = "iso")]
+    IsoImpl<Iso> then LensImpl<Lens>
+        => LensImpl<S, A, ComposedLens<O1, O2, S, I, A>>,
+        |o1, o2| ComposedLens::new(o1, o2);
+    #[cfg(feature = "iso")]
+    IsoImpl<Iso> then IsoImpl<Iso>
+        => IsoImpl<S, A, ComposedIso<O1, O2, S, I, A>>,
+        |o1, o2| ComposedIso::new(o1, o2);
+    #[cfg(all(feature = "fallible-iso", feature = "iso"))]
+    IsoImpl<Iso> then FallibleIsoImpl<FallibleIso>
+        => FallibleIsoImpl<
+            S,
+            A,
+            ComposedFallibleIso<S, I, A, O2::GetterError, O2::ReverseError, O1, O2>,
+        >,
+        |o1, o2| ComposedFallibleIso::new(o1, o2, infallible, identity, infallible, identity);
+    #[cfg(feature = "iso")]
+    IsoImpl<Iso> then TraversalImpl<Traversal>
+        => TraversalImpl<S, A, ComposedTraversal<SingleTraversal<S, I, O1>, O2, S, I, A>>,
+        |o1, o2| ComposedTraversal::new(single_traversal(o1), o2);
+
+    #[cfg(feature = "fallible-iso")]
+    FallibleIsoImpl<FallibleIso> then PartialGetterImpl<PartialGetter>
+        => PartialGetterImpl<
+            S,
+            A,
+            ComposedPartialGetter<O1, O2, Either<O1::GetterError, O2::GetterError>, S, I, A>,
+        >,
+        |o1, o2| ComposedPartialGetter::new(o1, o2, Either::Left, Either::Right);
+    #[cfg(all(feature = "fallible-iso", feature = "getter"))]
+    FallibleIsoImpl<FallibleIso> then GetterImpl<Getter>
+        => PartialGetterImpl<S, A, ComposedPartialGetter<O1, O2, O1::GetterError, S, I, A>>,
+        |o1, o2| ComposedPartialGetter::new(o1, o2, identity, infallible);
+    #[cfg(feature = "fallible-iso")]
+    FallibleIsoImpl<FallibleIso> then SetterImpl<Setter>
+        => SetterImpl<S, A, ComposedSetter<O1, O2, S, I, A>>,
+        |o1, o2| ComposedSetter::new(o1, o2);
+    #[cfg(feature = "fallible-iso")]
+    FallibleIsoImpl<FallibleIso> then FallibleSetterImpl<FallibleSetter>
+        => FallibleSetterImpl<
+            S,
+            A,
+            ComposedFallibleSetter<O1, O2, Either<O1::GetterError, O2::SetterError>, S, I, A>,
+        >,
+        |o1, o2| ComposedFallibleSetter::new(o1, o2, Either::Left, Either::Right);
+    #[cfg(feature = "fallible-iso")]
+    FallibleIsoImpl<FallibleIso> then PrismImpl<Prism>
+        => PrismImpl<S, A, ComposedPrism<O1, O2, Either<O1::GetterError, O2::GetterError>, S, I, A>>,
+        |o1, o2| ComposedPrism::new(o1, o2, Either::Left, Either::Right);
+    #[cfg(all(feature = "fallible-iso", feature = "lens"))]
+    FallibleIsoImpl<FallibleIso> then LensImpl<Lens>
+        => PrismImpl<S, A, ComposedPrism<O1, O2, O1::GetterError, S, I, A>>,
+        |o1, o2| ComposedPrism::new(o1, o2, identity, infallible);
+    #[cfg(all(feature = "fallible-iso", feature = "iso"))]
+    FallibleIsoImpl<FallibleIso> then IsoImpl<Iso>
+        => FallibleIsoImpl<
+            S,
+            A,
+            ComposedFallibleIso<S, I, A, O1::GetterError, O1::ReverseError, O1, O2>,
+        >,
+        |o1, o2| ComposedFallibleIso::new(o1, o2, identity, infallible, identity, infallible);
+    #[cfg(feature = "fallible-iso")]
+    FallibleIsoImpl<FallibleIso> then FallibleIsoImpl<FallibleIso>
+        => FallibleIsoImpl<
+            S,
+            A,
+            ComposedFallibleIso<
+                S,
+                I,
+                A,
+                Either<O1::GetterError, O2::GetterError>,
+                Either<O1::ReverseError, O2::ReverseError>,
+                O1,
+                O2,
+            >,
+        >,
+        |o1, o2| {
+            ComposedFallibleIso::new(o1, o2, Either::Left, Either::Right, Either::Left, Either::Right)
+        };
+    #[cfg(feature = "fallible-iso")]
+    FallibleIsoImpl<FallibleIso> then TraversalImpl<Traversal>
+        => TraversalImpl<S, A, ComposedTraversal<SingleTraversal<S, I, O1>, O2, S, I, A>>,
+        |o1, o2| ComposedTraversal::new(single_traversal(o1), o2);
+
+    #[cfg(feature = "setter")]
+    TraversalImpl<Traversal> then SetterImpl<Setter>
+        => SetterImpl<S, A, EachSetter<O1, O2, S, I, A>>
+        where [A: Clone],
+        |o1, o2| each_setter(o1, o2);
+    TraversalImpl<Traversal> then FallibleSetterImpl<FallibleSetter>
+        => FallibleSetterImpl<S, A, EachFallibleSetter<O1, O2, S, I, A>>
+        where [A: Clone],
+        |o1, o2| each_fallible_setter(o1, o2);
+    #[cfg(feature = "prism")]
+    TraversalImpl<Traversal> then PrismImpl<Prism>
+        => TraversalImpl<S, A, ComposedTraversal<O1, SingleTraversal<I, A, O2>, S, I, A>>,
+        |o1, o2| ComposedTraversal::new(o1, single_traversal(o2));
+    #[cfg(feature = "lens")]
+    TraversalImpl<Traversal> then LensImpl<Lens>
+        => TraversalImpl<S, A, ComposedTraversal<O1, SingleTraversal<I, A, O2>, S, I, A>>,
+        |o1, o2| ComposedTraversal::new(o1, single_traversal(o2));
+    #[cfg(feature = "iso")]
+    TraversalImpl<Traversal> then IsoImpl<Iso>
+        => TraversalImpl<S, A, ComposedTraversal<O1, SingleTraversal<I, A, O2>, S, I, A>>,
+        |o1, o2| ComposedTraversal::new(o1, single_traversal(o2));
+    #[cfg(feature = "fallible-iso")]
+    TraversalImpl<Traversal> then FallibleIsoImpl<FallibleIso>
+        => TraversalImpl<S, A, ComposedTraversal<O1, SingleTraversal<I, A, O2>, S, I, A>>,
+        |o1, o2| ComposedTraversal::new(o1, single_traversal(o2));
+    TraversalImpl<Traversal> then TraversalImpl<Traversal>
+        => TraversalImpl<S, A, ComposedTraversal<O1, O2, S, I, A>>,
+        |o1, o2| ComposedTraversal::new(o1, o2);
+
+    #[cfg(feature = "setter")]
+    SetterImpl<Setter> then SetterImpl<Setter>
+        => SetterImpl<S, A, NestedSetter<O1, O2, S, I, A>>
+        where [O1: HasModify<S, I>],
+        |o1, o2| NestedSetter::new(o1, o2);
+    #[cfg(feature = "setter")]
+    SetterImpl<Setter> then FallibleSetterImpl<FallibleSetter>
+        => FallibleSetterImpl<
+            S,
+            A,
+            ComposedFallibleSetter<O1, O2, Either<O1::GetterError, O2::SetterError>, S, I, A>,
+        >
+        where [O1: HasModify<S, I>],
+        |o1, o2| ComposedFallibleSetter::new(o1, o2, Either::Left, Either::Right);
+    #[cfg(all(feature = "setter", feature = "prism"))]
+    SetterImpl<Setter> then PrismImpl<Prism>
+        => SetterImpl<S, A, NestedSetter<O1, O2, S, I, A>>
+        where [O1: HasModify<S, I>],
+        |o1, o2| NestedSetter::new(o1, o2);
+    #[cfg(feature = "lens")]
+    SetterImpl<Setter> then LensImpl<Lens>
+        => SetterImpl<S, A, NestedSetter<O1, O2, S, I, A>>
+        where [O1: HasModify<S, I>],
+        |o1, o2| NestedSetter::new(o1, o2);
+    #[cfg(feature = "iso")]
+    SetterImpl<Setter> then IsoImpl<Iso>
+        => SetterImpl<S, A, ReversedSetter<O1, O2, S, I, A>>,
+        |o1, o2| ReversedSetter::new(o1, o2);
+    #[cfg(feature = "fallible-iso")]
+    SetterImpl<Setter> then FallibleIsoImpl<FallibleIso>
+        => FallibleSetterImpl<S, A, ReversedSetter<O1, O2, S, I, A>>,
+        |o1, o2| ReversedSetter::new(o1, o2);
+    #[cfg(feature = "setter")]
+    SetterImpl<Setter> then TraversalImpl<Traversal>
+        => SetterImpl<S, A, NestedSetter<O1, OverAll<I, A, O2>, S, I, A>>
+        where [O1: HasModify<S, I>, A: Clone],
+        |o1, o2| NestedSetter::new(o1, over_all(o2));
+
+    #[cfg(feature = "setter")]
+    FallibleSetterImpl<FallibleSetter> then SetterImpl<Setter>
+        => FallibleSetterImpl<S, A, NestedSetter<O1, O2, S, I, A>>
+        where [O1: HasModify<S, I>],
+        |o1, o2| NestedSetter::new(o1, o2);
+    FallibleSetterImpl<FallibleSetter> then FallibleSetterImpl<FallibleSetter>
+        => FallibleSetterImpl<
+            S,
+            A,
+            NestedFallibleSetter<O1, O2, Either<O1::SetterError, O2::SetterError>, S, I, A>,
+        >
+        where [O1: HasModify<S, I>],
+        |o1, o2| NestedFallibleSetter::new(o1, o2, Either::Left, Either::Right);
+    #[cfg(feature = "prism")]
+    FallibleSetterImpl<FallibleSetter> then PrismImpl<Prism>
+        => FallibleSetterImpl<S, A, NestedSetter<O1, O2, S, I, A>>
+        where [O1: HasModify<S, I>],
+        |o1, o2| NestedSetter::new(o1, o2);
+    #[cfg(feature = "lens")]
+    FallibleSetterImpl<FallibleSetter> then LensImpl<Lens>
+        => FallibleSetterImpl<S, A, NestedSetter<O1, O2, S, I, A>>
+        where [O1: HasModify<S, I>],
+        |o1, o2| NestedSetter::new(o1, o2);
+    #[cfg(feature = "iso")]
+    FallibleSetterImpl<FallibleSetter> then IsoImpl<Iso>
+        => FallibleSetterImpl<S, A, ReversedFallibleSetter<O1, O2, O1::SetterError, S, I, A>>,
+        |o1, o2| ReversedFallibleSetter::new(o1, o2, identity, infallible);
+    #[cfg(feature = "fallible-iso")]
+    FallibleSetterImpl<FallibleSetter> then FallibleIsoImpl<FallibleIso>
+        => FallibleSetterImpl<
+            S,
+            A,
+            ReversedFallibleSetter<O1, O2, Either<O1::SetterError, O2::ReverseError>, S, I, A>,
+        >,
+        |o1, o2| ReversedFallibleSetter::new(o1, o2, Either::Left, Either::Right);
+    FallibleSetterImpl<FallibleSetter> then TraversalImpl<Traversal>
+        => FallibleSetterImpl<S, A, NestedSetter<O1, OverAll<I, A, O2>, S, I, A>>
+        where [O1: HasModify<S, I>, A: Clone],
+        |o1, o2| NestedSetter::new(o1, over_all(o2));
+}
+
+macro_rules! shr_compose {
+    ($($(#[$attr:meta])* $wrapper:ident<$kind:ident>),+) => {
+        $(
+            $(#[$attr])*
+            impl<S, I, O1, O2> Shr<O2> for $wrapper<S, I, O1>
+            where
+                O1: $kind<S, I>,
+                Self: ComposeWith<O2>,
+            {
+                type Output = <Self as ComposeWith<O2>>::Output;
+
+                fn shr(self, other: O2) -> Self::Output {
+                    self.then(other)
+                }
+            }
+        )+
+    };
+}
+
+shr_compose!(
+    PartialGetterImpl<PartialGetter>,
+    #[cfg(feature = "getter")]
+    GetterImpl<Getter>,
+    #[cfg(feature = "prism")]
+    PrismImpl<Prism>,
+    #[cfg(feature = "lens")]
+    LensImpl<Lens>,
+    #[cfg(feature = "iso")]
+    IsoImpl<Iso>,
+    #[cfg(feature = "fallible-iso")]
+    FallibleIsoImpl<FallibleIso>,
+    TraversalImpl<Traversal>,
+    #[cfg(feature = "setter")]
+    SetterImpl<Setter>,
+    FallibleSetterImpl<FallibleSetter>
+);
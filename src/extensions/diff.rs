@@ -0,0 +1,162 @@
+#[cfg(feature = "fallible-iso")]
+use crate::{FallibleIso, FallibleIsoImpl};
+#[cfg(feature = "getter")]
+use crate::{Getter, GetterImpl};
+use crate::{HasGetter, HasPath, PartialGetter, PartialGetterImpl};
+#[cfg(feature = "iso")]
+use crate::{Iso, IsoImpl};
+#[cfg(feature = "lens")]
+use crate::{Lens, LensImpl};
+#[cfg(feature = "prism")]
+use crate::{Prism, PrismImpl};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{Debug, Display, Formatter};
+
+/// A difference in the focus of an optic between two values, found by [`diff`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Change {
+    path: String,
+    before: Option<String>,
+    after: Option<String>,
+}
+
+impl Change {
+    /// Returns the path of the optic whose focus differs, its segments joined with a `.`.
+    #[must_use]
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Returns the `Debug` representation of the focus in the old value, or `None` if the optic
+    /// had no focus in it.
+    #[must_use]
+    pub fn before(&self) -> Option<&str> {
+        self.before.as_deref()
+    }
+
+    /// Returns the `Debug` representation of the focus in the new value, or `None` if the optic
+    /// has no focus in it.
+    #[must_use]
+    pub fn after(&self) -> Option<&str> {
+        self.after.as_deref()
+    }
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let absent = "(absent)";
+        write!(
+            f,
+            "{}: {} -> {}",
+            self.path,
+            self.before.as_deref().unwrap_or(absent),
+            self.after.as_deref().unwrap_or(absent)
+        )
+    }
+}
+
+/// An optic that can compare its focus in two values, so it can be passed to [`diff`].
+///
+/// This trait is implemented by the wrappers of all optics that can read their focus, when the
+/// optic has a path, and the focus can be compared and formatted with `Debug`.
+pub trait HasDiff<S> {
+    /// Compares the focus of this optic in `old` and `new`.
+    ///
+    /// # Returns
+    ///
+    /// A [`Change`] if the focus differs, or is only present in one of the values.
+    fn diff(&self, old: &S, new: &S) -> Option<Change>;
+}
+
+/// Compares two values through a set of optics, listing the foci that differ.
+///
+/// This turns the optics already defined for a type into a human-readable diff of its values,
+/// such as the changes made to a configuration.
+///
+/// # Parameters
+///
+/// - `old`: The value before the changes.
+/// - `new`: The value after the changes.
+/// - `optics`: The optics to compare the values through.
+///
+/// # Returns
+///
+/// The changes found, in the order of `optics`.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{diff, field_lens};
+///
+/// #[derive(Clone)]
+/// struct Server {
+///     host: String,
+///     port: u16,
+/// }
+///
+/// let old = Server { host: "localhost".to_string(), port: 8080 };
+/// let new = Server { port: 9090, ..old.clone() };
+///
+/// let changes = diff(&old, &new, &[&field_lens!(Server, host), &field_lens!(Server, port)]);
+///
+/// assert_eq!(changes.len(), 1);
+/// assert_eq!(changes[0].to_string(), "port: 8080 -> 9090");
+/// ```
+#[must_use]
+pub fn diff<S>(old: &S, new: &S, optics: &[&dyn HasDiff<S>]) -> Vec<Change> {
+    optics
+        .iter()
+        .filter_map(|optic| optic.diff(old, new))
+        .collect()
+}
+
+fn diff_focus<S, A, O>(optic: &O, old: &S, new: &S) -> Option<Change>
+where
+    O: HasGetter<S, A> + HasPath,
+    A: PartialEq + Debug,
+{
+    let old = optic.try_get(old).ok();
+    let new = optic.try_get(new).ok();
+    if old == new {
+        return None;
+    }
+
+    Some(Change {
+        path: optic.joined_path(),
+        before: old.map(|focus| format!("{focus:?}")),
+        after: new.map(|focus| format!("{focus:?}")),
+    })
+}
+
+macro_rules! has_diff {
+    ($($(#[$attr:meta])* $wrapper:ident<$kind:ident>;)+) => {
+        $(
+            $(#[$attr])*
+            impl<S, A, O> HasDiff<S> for $wrapper<S, A, O>
+            where
+                O: $kind<S, A> + HasPath,
+                A: PartialEq + Debug,
+            {
+                fn diff(&self, old: &S, new: &S) -> Option<Change> {
+                    diff_focus(self, old, new)
+                }
+            }
+        )+
+    };
+}
+
+has_diff! {
+    PartialGetterImpl<PartialGetter>;
+    #[cfg(feature = "getter")]
+    GetterImpl<Getter>;
+    #[cfg(feature = "lens")]
+    LensImpl<Lens>;
+    #[cfg(feature = "prism")]
+    PrismImpl<Prism>;
+    #[cfg(feature = "iso")]
+    IsoImpl<Iso>;
+    #[cfg(feature = "fallible-iso")]
+    FallibleIsoImpl<FallibleIso>;
+}
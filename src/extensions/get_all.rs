@@ -0,0 +1,43 @@
+use crate::HasTraversal;
+use alloc::vec::Vec;
+
+/// Provides a convenient interface for collecting all the values a traversal focuses on.
+///
+/// This trait is automatically implemented for any optic that implements [`HasTraversal`].
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{array_each, HasGetAll};
+///
+/// let each = array_each::<u32, 3>();
+///
+/// assert_eq!(each.get_all(&[1, 2, 3]), vec![1, 2, 3]);
+/// ```
+///
+/// # See also:
+///
+/// [`HasTraversal`]: base trait for optics that focus on any number of values.
+pub trait HasGetAll<S, A> {
+    /// Retrieves all values of type `A` focused on inside a source of type `S`.
+    ///
+    /// # Parameters
+    ///
+    /// - `source`: A reference to the source of type `S` from which the values are to be retrieved.
+    ///
+    /// # Returns
+    ///
+    /// Returns the values the optic focuses on, in the order they are visited.
+    fn get_all(&self, source: &S) -> Vec<A>;
+}
+
+impl<S, A, T> HasGetAll<S, A> for T
+where
+    T: HasTraversal<S, A>,
+{
+    fn get_all(&self, source: &S) -> Vec<A> {
+        let mut values = Vec::new();
+        self.for_each(source, &mut |a| values.push(a));
+        values
+    }
+}
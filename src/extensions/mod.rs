@@ -1,7 +1,31 @@
+mod compose_with;
+#[cfg(feature = "alloc")]
+mod diff;
+#[cfg(feature = "alloc")]
+mod get_all;
 mod over;
+#[cfg(feature = "parallel")]
+mod par_over;
+#[cfg(feature = "serde")]
+mod serde_focus;
 mod total_getter;
 mod total_reverse_get;
+mod update;
+#[cfg(feature = "tokio")]
+mod watch;
 
+pub use compose_with::{ComposeWith, compose, compose3, compose4};
+#[cfg(feature = "alloc")]
+pub use diff::{Change, HasDiff, diff};
+#[cfg(feature = "alloc")]
+pub use get_all::HasGetAll;
 pub use over::HasOver;
+#[cfg(feature = "parallel")]
+pub use par_over::HasParOver;
+#[cfg(feature = "serde")]
+pub use serde_focus::{DeserializeFocus, SerdeFocus, SerializeFocus, serde_focus};
 pub use total_getter::HasTotalGetter;
 pub use total_reverse_get::HasTotalReverseGet;
+pub use update::HasUpdate;
+#[cfg(feature = "tokio")]
+pub use watch::{WatchMap, send_through, watch_map};
@@ -0,0 +1,97 @@
+use crate::{HasGetAll, HasTraversal};
+use alloc::vec::Vec;
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+/// Provides parallel counterparts of [`HasTraversal::over_all`] and folding over the values a
+/// traversal focuses on, spreading the work over the available cores.
+///
+/// This trait is automatically implemented for any optic that implements [`HasTraversal`], and is
+/// available with the `parallel` feature.
+///
+/// The focused values are collected and processed on the global `rayon` thread pool, which splits
+/// the work between its threads and balances it by work stealing. Collecting the foci and writing
+/// them back has a cost, so this is only worth it for traversals with many foci or an expensive
+/// per-value function.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{HasParOver, mapped_traversal};
+///
+/// let each = mapped_traversal(
+///     |v: &Vec<u64>, f: &mut dyn FnMut(u64)| v.iter().copied().for_each(f),
+///     |v: &mut Vec<u64>, f: &mut dyn FnMut(u64) -> u64| v.iter_mut().for_each(|x| *x = f(*x)),
+/// );
+///
+/// let mut values: Vec<u64> = (1..=10_000).collect();
+/// each.par_over(&mut values, |x| x * 2);
+///
+/// assert_eq!(each.par_fold(&values, || 0, |acc, x| acc + x, |a, b| a + b), 100_010_000);
+/// ```
+///
+/// # See also:
+///
+/// [`HasTraversal`]: base trait for optics that focus on any number of values.
+pub trait HasParOver<S, A> {
+    /// Replaces every value of type `A` focused on inside a mutable source of type `S` with the
+    /// result of calling `f` with the current value, calling `f` from several threads at once.
+    ///
+    /// # Parameters
+    ///
+    /// - `source`: A mutable reference to the source of type `S` in which the values are to be updated.
+    /// - `f`: The function computing the new value from the old one.
+    fn par_over<F>(&self, source: &mut S, f: F)
+    where
+        F: Fn(A) -> A + Sync + Send;
+
+    /// Folds the values of type `A` focused on inside a source of type `S` into a single value,
+    /// folding separate runs of the values on several threads at once.
+    ///
+    /// # Parameters
+    ///
+    /// - `source`: A reference to the source of type `S` from which the values are to be retrieved.
+    /// - `init`: The function creating the initial accumulator of each run.
+    /// - `fold`: The function adding a value to an accumulator.
+    /// - `combine`: The function merging the accumulators of two runs, in visiting order.
+    ///
+    /// # Returns
+    ///
+    /// Returns the combined accumulator, or the result of `init` if the traversal focuses on no
+    /// values.
+    fn par_fold<B, I, F, C>(&self, source: &S, init: I, fold: F, combine: C) -> B
+    where
+        B: Send,
+        I: Fn() -> B + Sync + Send,
+        F: Fn(B, A) -> B + Sync + Send,
+        C: Fn(B, B) -> B + Sync + Send;
+}
+
+impl<S, A, T> HasParOver<S, A> for T
+where
+    T: HasTraversal<S, A>,
+    A: Send,
+{
+    fn par_over<F>(&self, source: &mut S, f: F)
+    where
+        F: Fn(A) -> A + Sync + Send,
+    {
+        let updated: Vec<A> = self.get_all(source).into_par_iter().map(f).collect();
+
+        let mut values = updated.into_iter();
+        self.over_all(source, &mut |a| values.next().unwrap_or(a));
+    }
+
+    fn par_fold<B, I, F, C>(&self, source: &S, init: I, fold: F, combine: C) -> B
+    where
+        B: Send,
+        I: Fn() -> B + Sync + Send,
+        F: Fn(B, A) -> B + Sync + Send,
+        C: Fn(B, B) -> B + Sync + Send,
+    {
+        self.get_all(source)
+            .into_par_iter()
+            .fold(&init, fold)
+            .reduce_with(combine)
+            .unwrap_or_else(init)
+    }
+}
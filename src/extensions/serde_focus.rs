@@ -0,0 +1,147 @@
+use crate::{HasGetter, HasSetter};
+use core::fmt::{Display, Formatter};
+use core::marker::PhantomData;
+use serde::Deserialize;
+use serde::de::{DeserializeSeed, Deserializer};
+use serde::ser::{Error, Serialize, Serializer};
+
+/// Views the focus of an optic as a standalone serde value, created by [`serde_focus`].
+///
+/// [`serialize`](SerdeFocus::serialize) serializes only the focused part of a source, and
+/// [`deserialize_into`](SerdeFocus::deserialize_into) deserializes a value and writes it into the
+/// focus of a source, so partial payloads such as the bodies of PATCH requests can be read and
+/// written through existing optics, without defining transfer structs for them.
+pub struct SerdeFocus<O, S, A> {
+    optic: O,
+    _phantom: PhantomData<fn(&S) -> A>,
+}
+
+/// Serializes the focus of an optic in a source, created by [`SerdeFocus::serialize`].
+///
+/// Serializing fails with a custom error of the serializer if the optic has no focus in the
+/// source.
+pub struct SerializeFocus<'a, O, S, A> {
+    optic: &'a O,
+    source: &'a S,
+    _phantom: PhantomData<fn(&S) -> A>,
+}
+
+/// Deserializes a value and writes it into the focus of an optic in a source, created by
+/// [`SerdeFocus::deserialize_into`].
+pub struct DeserializeFocus<'a, O, S, A> {
+    optic: &'a O,
+    target: &'a mut S,
+    _phantom: PhantomData<fn(&S) -> A>,
+}
+
+/// Creates a [`SerdeFocus`] viewing the focus of `optic` as a standalone serde value.
+///
+/// # Arguments
+///
+/// - `optic` — The optic focusing on the part of the source to serialize or deserialize.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{field_lens, serde_focus};
+/// use serde::de::DeserializeSeed;
+///
+/// #[derive(Clone)]
+/// struct Server {
+///     port: u16,
+///     name: String,
+/// }
+///
+/// let mut server = Server { port: 80, name: "web".to_string() };
+/// let port = serde_focus(field_lens!(Server, port));
+///
+/// assert_eq!(serde_json::to_string(&port.serialize(&server)).unwrap(), "80");
+///
+/// let mut body = serde_json::Deserializer::from_str("8080");
+/// port.deserialize_into(&mut server).deserialize(&mut body).unwrap();
+/// assert_eq!(server.port, 8080);
+/// assert_eq!(server.name, "web");
+/// ```
+#[must_use]
+pub fn serde_focus<O, S, A>(optic: O) -> SerdeFocus<O, S, A> {
+    SerdeFocus {
+        optic,
+        _phantom: PhantomData,
+    }
+}
+
+impl<O, S, A> SerdeFocus<O, S, A> {
+    /// Returns an adapter serializing the focus of the optic in `source`.
+    ///
+    /// # Arguments
+    ///
+    /// - `source` — The source whose focus is serialized.
+    #[must_use]
+    pub fn serialize<'a>(&'a self, source: &'a S) -> SerializeFocus<'a, O, S, A> {
+        SerializeFocus {
+            optic: &self.optic,
+            source,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns a `DeserializeSeed` deserializing a value and writing it into the focus of the
+    /// optic in `target`.
+    ///
+    /// The value is written with [`HasSetter::set`], so writing through a prism into a target
+    /// without a focus behaves as setting through the prism does.
+    ///
+    /// # Arguments
+    ///
+    /// - `target` — The source the deserialized value is written into.
+    #[must_use]
+    pub fn deserialize_into<'a>(&'a self, target: &'a mut S) -> DeserializeFocus<'a, O, S, A> {
+        DeserializeFocus {
+            optic: &self.optic,
+            target,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Consumes the adapter, returning the optic it views through.
+    #[must_use]
+    pub fn into_inner(self) -> O {
+        self.optic
+    }
+}
+
+struct FocusError<E>(E);
+
+impl<E: Display> Display for FocusError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "the optic has no focus in the source: {}", self.0)
+    }
+}
+
+impl<O, S, A> Serialize for SerializeFocus<'_, O, S, A>
+where
+    O: HasGetter<S, A>,
+    O::GetterError: Display,
+    A: Serialize,
+{
+    fn serialize<Ser: Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        self.optic
+            .try_get(self.source)
+            .map_err(|error| Ser::Error::custom(FocusError(error)))?
+            .serialize(serializer)
+    }
+}
+
+impl<'de, O, S, A> DeserializeSeed<'de> for DeserializeFocus<'_, O, S, A>
+where
+    O: HasSetter<S, A>,
+    A: Deserialize<'de>,
+{
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        let value = A::deserialize(deserializer)?;
+        self.optic.set(self.target, value);
+        Ok(())
+    }
+}
@@ -0,0 +1,83 @@
+use crate::{HasModify, HasSetter};
+
+/// Provides owned, functional updates of a source, producing a new source instead of mutating it
+/// in place.
+///
+/// This is convenient in immutable-style code, such as state reducers, where a new state is
+/// produced from the previous one without requiring `&mut` access at the call site.
+///
+/// This trait is automatically implemented for any optic that implements [`HasSetter`].
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{HasUpdate, mapped_lens};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Counter {
+///     count: u32,
+/// }
+///
+/// let count = mapped_lens(|c: &Counter| c.count, |c: &mut Counter, v| c.count = v);
+///
+/// let state = Counter { count: 0 };
+/// let state = count.update(state, 10);
+/// let state = count.with(state, |c| *c += 1);
+/// assert_eq!(state, Counter { count: 11 });
+/// ```
+///
+/// # See also:
+///
+/// [`HasSetter`]: base trait for optics that can set their focus.
+/// [`HasModify`]: base trait for optics that can modify their focus in place.
+pub trait HasUpdate<S, A> {
+    /// Sets the focus of the optic in an owned source of type `S` to `value`, returning the updated
+    /// source.
+    ///
+    /// # Parameters
+    ///
+    /// - `source`: The source of type `S` to update.
+    /// - `value`: The value of type `A` to set the focus to.
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated source.
+    #[must_use]
+    fn update(&self, source: S, value: A) -> S;
+
+    /// Modifies the focus of the optic in an owned source of type `S` with `f`, returning the
+    /// updated source.
+    ///
+    /// If the focus cannot be retrieved, the source is returned unchanged.
+    ///
+    /// # Parameters
+    ///
+    /// - `source`: The source of type `S` to update.
+    /// - `f`: The function modifying the focus.
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated source.
+    #[must_use]
+    fn with<F: FnOnce(&mut A)>(&self, source: S, f: F) -> S
+    where
+        Self: HasModify<S, A>;
+}
+
+impl<S, A, T> HasUpdate<S, A> for T
+where
+    T: HasSetter<S, A>,
+{
+    fn update(&self, mut source: S, value: A) -> S {
+        self.set(&mut source, value);
+        source
+    }
+
+    fn with<F: FnOnce(&mut A)>(&self, mut source: S, f: F) -> S
+    where
+        Self: HasModify<S, A>,
+    {
+        self.modify(&mut source, f);
+        source
+    }
+}
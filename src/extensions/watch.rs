@@ -0,0 +1,178 @@
+use crate::{Getter, HasTotalGetter, Lens};
+use core::fmt::{Debug, Formatter};
+use core::marker::PhantomData;
+use tokio::sync::watch::error::RecvError;
+use tokio::sync::watch::{Receiver, Sender};
+
+/// A receiver of the part of the value of a `tokio::sync::watch` channel that a getter focuses
+/// on, created by [`watch_map`].
+///
+/// The receiver only reports a change when the focused value differs from the last one it has
+/// seen, so writes to other parts of the watched value do not wake it up.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{field_lens, watch_map};
+/// use tokio::sync::watch;
+///
+/// #[derive(Clone)]
+/// struct Config {
+///     port: u16,
+///     name: String,
+/// }
+///
+/// let (tx, rx) = watch::channel(Config { port: 80, name: "web".to_string() });
+/// let mut port = watch_map(rx, field_lens!(Config, port));
+///
+/// tx.send_modify(|config| config.name = "api".to_string());
+/// assert!(!port.has_changed().unwrap());
+///
+/// tx.send_modify(|config| config.port = 8080);
+/// assert!(port.has_changed().unwrap());
+/// assert_eq!(port.get(), 8080);
+/// ```
+pub struct WatchMap<S, A, G> {
+    receiver: Receiver<S>,
+    getter: G,
+    seen: A,
+    _phantom: PhantomData<fn(&S) -> A>,
+}
+
+impl<S, A, G> WatchMap<S, A, G>
+where
+    G: Getter<S, A>,
+    A: PartialEq,
+{
+    /// Returns the focus of the most recent value of the channel, without marking it as seen.
+    #[must_use]
+    pub fn get(&self) -> A {
+        self.getter.get(&self.receiver.borrow())
+    }
+
+    /// Returns whether the focus changed since it was last seen, marking the current value of
+    /// the channel as seen.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RecvError`] if the sender of the channel has been dropped.
+    pub fn has_changed(&mut self) -> Result<bool, RecvError> {
+        if !self.receiver.has_changed()? {
+            return Ok(false);
+        }
+
+        Ok(self.mark_seen())
+    }
+
+    /// Waits until the focus differs from the one last seen, marking the new value of the
+    /// channel as seen.
+    ///
+    /// Values of the channel whose focus equals the one last seen are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RecvError`] if the sender of the channel has been dropped.
+    pub async fn changed(&mut self) -> Result<(), RecvError> {
+        loop {
+            self.receiver.changed().await?;
+
+            if self.mark_seen() {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Consumes the adapter, returning the receiver of the whole value.
+    #[must_use]
+    pub fn into_inner(self) -> Receiver<S> {
+        self.receiver
+    }
+
+    fn mark_seen(&mut self) -> bool {
+        let focus = self.getter.get(&self.receiver.borrow_and_update());
+
+        if focus == self.seen {
+            false
+        } else {
+            self.seen = focus;
+            true
+        }
+    }
+}
+
+impl<S, A: Debug, G> Debug for WatchMap<S, A, G> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("WatchMap")
+            .field("seen", &self.seen)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Projects the receiver of a `tokio::sync::watch` channel through `getter`, returning a
+/// [`WatchMap`] that only reports changes of the focused value.
+///
+/// The current value of the channel is marked as seen.
+///
+/// # Arguments
+///
+/// - `receiver` — The receiver of the whole value.
+/// - `getter` — The getter focusing on the part of the value to watch.
+#[must_use]
+pub fn watch_map<S, A, G>(mut receiver: Receiver<S>, getter: G) -> WatchMap<S, A, G>
+where
+    G: Getter<S, A>,
+    A: PartialEq,
+{
+    let seen = getter.get(&receiver.borrow_and_update());
+
+    WatchMap {
+        receiver,
+        getter,
+        seen,
+        _phantom: PhantomData,
+    }
+}
+
+/// Sets the focus of `lens` in the value of a `tokio::sync::watch` channel, notifying the
+/// receivers only if the focus changed.
+///
+/// Returns whether the value was modified.
+///
+/// # Arguments
+///
+/// - `sender` — The sender of the channel.
+/// - `lens` — The lens focusing on the part of the value to set.
+/// - `value` — The new value of the focus.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{field_lens, send_through};
+/// use tokio::sync::watch;
+///
+/// #[derive(Clone)]
+/// struct Config {
+///     port: u16,
+/// }
+///
+/// let (tx, mut rx) = watch::channel(Config { port: 80 });
+///
+/// assert!(!send_through(&tx, &field_lens!(Config, port), 80));
+/// assert!(!rx.has_changed().unwrap());
+/// assert!(send_through(&tx, &field_lens!(Config, port), 8080));
+/// assert_eq!(rx.borrow_and_update().port, 8080);
+/// ```
+pub fn send_through<S, A, L>(sender: &Sender<S>, lens: &L, value: A) -> bool
+where
+    L: Lens<S, A>,
+    A: PartialEq,
+{
+    sender.send_if_modified(|source| {
+        if lens.get(source) == value {
+            false
+        } else {
+            lens.set(source, value);
+            true
+        }
+    })
+}
@@ -0,0 +1,392 @@
+use crate::registry::ErasedOptic;
+use crate::{ErasedError, HasGetter, HasModify, HasSetter, OpticRegistry, PrismImpl, SharedPrism};
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::any::{Any, TypeId, type_name};
+use core::fmt::{Debug, Display, Formatter};
+use core::marker::PhantomData;
+
+/// A set of optics into the fields, elements and optional values of types, used to parse key paths
+/// such as `"aux[1].port?"` into optics at runtime.
+///
+/// A key path is a sequence of
+///
+/// - `.field` segments, focusing on a field registered with [`field`](KeyPathRegistry::field),
+///   the `.` omitted when the key path starts with one,
+/// - `[index]` segments, focusing on an element of a type registered with
+///   [`index`](KeyPathRegistry::index),
+/// - `?` segments, focusing on the value of an optional type registered with
+///   [`optional`](KeyPathRegistry::optional).
+///
+/// Each segment is resolved against the optics registered for the type focused on by the previous
+/// segments, and [`parse`](KeyPathRegistry::parse) checks that the last one focuses on the
+/// requested type. This allows tools that receive paths from their users, such as debuggers or
+/// administration interfaces, to read and write the parts of a structure those paths name.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{HasGetter, HasSetter, KeyPathRegistry, field_lens, option, vec};
+///
+/// #[derive(Clone)]
+/// struct Server {
+///     port: Option<u16>,
+/// }
+///
+/// struct Cluster {
+///     servers: Vec<Server>,
+/// }
+///
+/// let mut registry = KeyPathRegistry::new();
+/// registry
+///     .field("servers", field_lens!(Cluster, servers))
+///     .field("port", field_lens!(Server, port))
+///     .index(vec::get::<Server>)
+///     .optional(option::some::<u16>());
+///
+/// let port = registry.parse::<Cluster, u16>("servers[1].port?").unwrap();
+///
+/// let mut cluster = Cluster { servers: vec![Server { port: None }, Server { port: Some(80) }] };
+/// port.set(&mut cluster, 8080);
+/// assert_eq!(port.try_get(&cluster).ok(), Some(8080));
+/// assert!(registry.parse::<Cluster, u16>("servers[1].port").is_err());
+/// ```
+///
+/// The fields of each type are stored in an [`OpticRegistry`] of that type, under their names.
+#[derive(Default)]
+pub struct KeyPathRegistry {
+    fields: BTreeMap<TypeId, Box<dyn Fields>>,
+    indices: BTreeMap<TypeId, Index>,
+    optionals: BTreeMap<TypeId, ErasedOptic>,
+}
+
+struct Index(Box<dyn Fn(usize) -> ErasedOptic + Send + Sync>);
+
+/// The registry of the fields of a type, with the type erased.
+trait Fields: Any + Send + Sync {
+    fn field(&self, name: &str) -> Option<ErasedOptic>;
+
+    fn names(&self) -> Vec<&str>;
+
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<S: 'static> Fields for OpticRegistry<S> {
+    fn field(&self, name: &str) -> Option<ErasedOptic> {
+        self.erased(name).cloned()
+    }
+
+    fn names(&self) -> Vec<&str> {
+        OpticRegistry::names(self).collect()
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+impl KeyPathRegistry {
+    /// Creates a new, empty registry.
+    #[must_use]
+    pub fn new() -> Self {
+        KeyPathRegistry {
+            fields: BTreeMap::new(),
+            indices: BTreeMap::new(),
+            optionals: BTreeMap::new(),
+        }
+    }
+
+    /// Registers `optic` as the field `name` of values of type `S`, resolving `.name` segments
+    /// following a segment focusing on an `S`.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The name of the field.
+    /// - `optic`: The optic focusing on the field, usually a lens.
+    pub fn field<S, A, O>(&mut self, name: impl Into<String>, optic: O) -> &mut Self
+    where
+        S: 'static,
+        A: 'static,
+        O: HasModify<S, A> + Send + Sync + 'static,
+        O::GetterError: Into<ErasedError>,
+    {
+        if let Some(fields) = self
+            .fields
+            .entry(TypeId::of::<S>())
+            .or_insert_with(|| Box::new(OpticRegistry::<S>::new()))
+            .as_any_mut()
+            .downcast_mut::<OpticRegistry<S>>()
+        {
+            fields.register_prism(name, PrismImpl::from(optic));
+        }
+        self
+    }
+
+    /// Registers `index` as the way to focus on the elements of values of type `S`, resolving
+    /// `[index]` segments following a segment focusing on an `S`.
+    ///
+    /// # Parameters
+    ///
+    /// - `index`: The function creating the optic focusing on the element at an index, such as
+    ///   [`vec::get`](crate::vec::get).
+    pub fn index<S, A, O>(
+        &mut self,
+        index: impl Fn(usize) -> O + Send + Sync + 'static,
+    ) -> &mut Self
+    where
+        S: 'static,
+        A: 'static,
+        O: HasModify<S, A> + Send + Sync + 'static,
+        O::GetterError: Into<ErasedError>,
+    {
+        self.indices.insert(
+            TypeId::of::<S>(),
+            Index(Box::new(move |position| ErasedOptic::of(index(position)))),
+        );
+        self
+    }
+
+    /// Registers `optic` as the way to focus on the value of optional values of type `S`, resolving
+    /// `?` segments following a segment focusing on an `S`.
+    ///
+    /// # Parameters
+    ///
+    /// - `optic`: The optic focusing on the value, such as [`option::some`](crate::option::some).
+    pub fn optional<S, A, O>(&mut self, optic: O) -> &mut Self
+    where
+        S: 'static,
+        A: 'static,
+        O: HasModify<S, A> + Send + Sync + 'static,
+        O::GetterError: Into<ErasedError>,
+    {
+        self.optionals
+            .insert(TypeId::of::<S>(), ErasedOptic::of(optic));
+        self
+    }
+
+    /// Parses `path` into a prism focusing on the value of type `A` it names in a value of type `S`.
+    ///
+    /// The prism fails to read its focus if any of the optics of the segments does, reporting the
+    /// error of that optic.
+    ///
+    /// Reading through the prism gets the focus of each segment with [`HasGetter::try_get`], so
+    /// every intermediate value is cloned on the way to the focus, e.g. the whole element for
+    /// `"servers[1].port"`. Writing modifies each intermediate in place instead. Key paths reading
+    /// large intermediates on hot paths are better written as compositions of the typed optics,
+    /// which can borrow them with [`HasGetterRef`](crate::HasGetterRef).
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`KeyPathError`] if `path` is malformed, if one of its segments is not registered
+    /// for the type it follows, or if it doesn't focus on a value of type `A`.
+    pub fn parse<S: 'static, A: 'static>(
+        &self,
+        path: &str,
+    ) -> Result<SharedPrism<S, A, ErasedError>, KeyPathError> {
+        let mut steps: Vec<ErasedOptic> = Vec::new();
+        let mut focus = (TypeId::of::<S>(), type_name::<S>());
+        let mut rest = path;
+
+        while !rest.is_empty() || steps.is_empty() {
+            let position = path.len() - rest.len();
+            let step = if let Some(after) = rest.strip_prefix('?') {
+                rest = after;
+                self.optionals
+                    .get(&focus.0)
+                    .cloned()
+                    .ok_or(KeyPathError::NotOptional { ty: focus.1 })?
+            } else if let Some(after) = rest.strip_prefix('[') {
+                let len = after
+                    .find(|c: char| !c.is_ascii_digit())
+                    .unwrap_or(after.len());
+                let index = after[..len].parse().map_err(|_| KeyPathError::Syntax {
+                    position: position + 1,
+                    expected: "an index",
+                })?;
+                rest = after[len..].strip_prefix(']').ok_or(KeyPathError::Syntax {
+                    position: position + 1 + len,
+                    expected: "`]`",
+                })?;
+                let Index(index_step) = self
+                    .indices
+                    .get(&focus.0)
+                    .ok_or(KeyPathError::NotIndexable { ty: focus.1 })?;
+                index_step(index)
+            } else {
+                let after = if steps.is_empty() {
+                    rest
+                } else {
+                    rest.strip_prefix('.').ok_or(KeyPathError::Syntax {
+                        position,
+                        expected: "`.`, `[` or `?`",
+                    })?
+                };
+                let len = after
+                    .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                    .unwrap_or(after.len());
+                if len == 0 {
+                    return Err(KeyPathError::Syntax {
+                        position: path.len() - after.len(),
+                        expected: "a field name",
+                    });
+                }
+                rest = &after[len..];
+                self.fields
+                    .get(&focus.0)
+                    .and_then(|fields| fields.field(&after[..len]))
+                    .ok_or_else(|| KeyPathError::UnknownField {
+                        ty: focus.1,
+                        field: after[..len].to_string(),
+                    })?
+            };
+
+            focus = (step.focus, step.focus_name);
+            steps.push(step);
+        }
+
+        if focus.0 != TypeId::of::<A>() {
+            return Err(KeyPathError::TypeMismatch {
+                expected: type_name::<A>(),
+                found: focus.1,
+            });
+        }
+
+        Ok(PrismImpl::from(KeyPath {
+            steps,
+            _phantom: PhantomData,
+        })
+        .shared())
+    }
+}
+
+impl Debug for KeyPathRegistry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("KeyPathRegistry")
+            .field(
+                "fields",
+                &self
+                    .fields
+                    .values()
+                    .flat_map(|fields| fields.names())
+                    .collect::<Vec<_>>(),
+            )
+            .finish_non_exhaustive()
+    }
+}
+
+struct KeyPath<S, A> {
+    steps: Vec<ErasedOptic>,
+    _phantom: PhantomData<fn(&mut S) -> A>,
+}
+
+impl<S: 'static, A: 'static> HasGetter<S, A> for KeyPath<S, A> {
+    type GetterError = ErasedError;
+
+    fn try_get(&self, source: &S) -> Result<A, Self::GetterError> {
+        let (first, steps) = self.steps.split_first().ok_or(KeyPathError::Syntax {
+            position: 0,
+            expected: "a field name",
+        })?;
+
+        // The registered optics only get owned foci, so each intermediate is cloned out of the
+        // previous one.
+        let mut value = (first.get)(source)?;
+        for step in steps {
+            value = (step.get)(&*value)?;
+        }
+
+        value.downcast::<A>().map(|value| *value).map_err(|_| {
+            KeyPathError::TypeMismatch {
+                expected: type_name::<A>(),
+                found: "another type",
+            }
+            .into()
+        })
+    }
+}
+
+impl<S: 'static, A: 'static> HasSetter<S, A> for KeyPath<S, A> {
+    fn set(&self, source: &mut S, value: A) {
+        let Some((last, steps)) = self.steps.split_last() else {
+            return;
+        };
+
+        let mut value = Some(value);
+        modify_through(steps, source, &mut |parent| (last.set)(parent, &mut value));
+    }
+}
+
+impl<S: 'static, A: 'static> HasModify<S, A> for KeyPath<S, A> {
+    fn modify<F: FnOnce(&mut A)>(&self, source: &mut S, f: F) {
+        let mut f = Some(f);
+        modify_through(&self.steps, source, &mut |focus| {
+            if let (Some(focus), Some(f)) = (focus.downcast_mut::<A>(), f.take()) {
+                f(focus);
+            }
+        });
+    }
+}
+
+/// Calls `f` with the focus of `steps` in `source`, modifying every intermediate in place.
+fn modify_through(steps: &[ErasedOptic], source: &mut dyn Any, f: &mut dyn FnMut(&mut dyn Any)) {
+    match steps.split_first() {
+        Some((step, rest)) => (step.modify)(source, &mut |focus| modify_through(rest, focus, f)),
+        None => f(source),
+    }
+}
+
+/// The error of parsing a key path with a [`KeyPathRegistry`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeyPathError {
+    /// The key path is malformed.
+    Syntax {
+        /// The byte offset in the key path where the error was found.
+        position: usize,
+        /// A description of what was expected at the position.
+        expected: &'static str,
+    },
+    /// A `.field` segment names a field that is not registered for the type it follows.
+    UnknownField {
+        /// The type the segment follows.
+        ty: &'static str,
+        /// The name of the field.
+        field: String,
+    },
+    /// An `[index]` segment follows a type with no registered index optic.
+    NotIndexable {
+        /// The type the segment follows.
+        ty: &'static str,
+    },
+    /// A `?` segment follows a type with no registered optional optic.
+    NotOptional {
+        /// The type the segment follows.
+        ty: &'static str,
+    },
+    /// The key path focuses on a value of another type than the requested one.
+    TypeMismatch {
+        /// The requested type.
+        expected: &'static str,
+        /// The type the key path focuses on.
+        found: &'static str,
+    },
+}
+
+impl Display for KeyPathError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            KeyPathError::Syntax { position, expected } => {
+                write!(f, "expected {expected} at position {position}")
+            }
+            KeyPathError::UnknownField { ty, field } => write!(f, "{ty} has no field `{field}`"),
+            KeyPathError::NotIndexable { ty } => write!(f, "{ty} can't be indexed"),
+            KeyPathError::NotOptional { ty } => write!(f, "{ty} is not optional"),
+            KeyPathError::TypeMismatch { expected, found } => {
+                write!(f, "expected a path to {expected}, found one to {found}")
+            }
+        }
+    }
+}
+
+impl core::error::Error for KeyPathError {}
@@ -0,0 +1,9 @@
+//! Utilities checking that hand-written optics obey the laws expected of their kind.
+//!
+//! The laws are what makes optics composable: a lens has to give back what was set through it,
+//! and setting what it gives must not change the source. Optics built from closures, such as the
+//! ones created by [`mapped_lens`](crate::mapped_lens), can easily break them, e.g. by
+//! normalizing the written value, so downstream crates can run these checks in their test suites.
+
+#[cfg(feature = "proptest")]
+pub mod proptest;
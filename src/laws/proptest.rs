@@ -0,0 +1,210 @@
+//! Property based checks of the optic laws, running on the `proptest` test runner.
+//!
+//! [`lens_laws`], [`prism_laws`] and [`iso_laws`] generate sources and focused values from the
+//! given strategies, and check the laws of the optic against them, shrinking the failing cases
+//! to a minimal counterexample. They return the `TestError` of the runner, so they can be
+//! unwrapped in a plain `#[test]`.
+//!
+//! These checks require the `proptest` feature.
+//!
+//! # Example
+//!
+//! ```rust
+//! use optics::laws::proptest::lens_laws;
+//! use optics::mapped_lens;
+//! use proptest::prelude::*;
+//!
+//! let port = mapped_lens(|s: &(String, u16)| s.1, |s, port| s.1 = port);
+//! lens_laws((".*", any::<u16>()), any::<u16>(), &port).unwrap();
+//!
+//! let lowercase = mapped_lens(
+//!     |s: &String| s.clone(),
+//!     |s, value: String| *s = value.to_lowercase(),
+//! );
+//! assert!(lens_laws(".*", "[A-Z]+", &lowercase).is_err());
+//! ```
+#[cfg(feature = "prism")]
+use crate::Prism;
+#[cfg(feature = "lens")]
+use crate::{HasTotalGetter, Lens};
+#[cfg(feature = "iso")]
+use crate::{HasTotalReverseGet, Iso};
+#[cfg(any(feature = "lens", feature = "prism", feature = "iso"))]
+use {
+    ::proptest::prop_assert_eq,
+    ::proptest::strategy::Strategy,
+    ::proptest::test_runner::{Config, TestError, TestRunner},
+    core::fmt::Debug,
+};
+
+/// The default configuration of the runner, which can be overridden by the `PROPTEST_*`
+/// environment variables, without persisting the failing cases into the sources of the caller.
+#[cfg(any(feature = "lens", feature = "prism", feature = "iso"))]
+fn config() -> Config {
+    Config {
+        failure_persistence: None,
+        ..Config::default()
+    }
+}
+
+/// Checks that `lens` obeys the lens laws, for sources generated by `sources` and values
+/// generated by `values`.
+///
+/// The laws checked are:
+///
+/// - get-set: setting the focus of a source to the value it already holds leaves the source
+///   unchanged.
+/// - set-get: the focus of a source after setting it to a value is that value.
+/// - set-set: setting the focus twice is the same as only setting it the second time.
+///
+/// # Errors
+///
+/// Returns the `TestError` of the runner with the minimal source and values breaking a law.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::laws::proptest::lens_laws;
+/// use optics::mapped_lens;
+/// use proptest::prelude::*;
+///
+/// let first = mapped_lens(|s: &(u8, u8)| s.0, |s, v| s.0 = v);
+///
+/// assert!(lens_laws(any::<(u8, u8)>(), any::<u8>(), &first).is_ok());
+/// ```
+#[cfg(feature = "lens")]
+pub fn lens_laws<S, A, L>(
+    sources: impl Strategy<Value = S>,
+    values: impl Strategy<Value = A>,
+    lens: &L,
+) -> Result<(), TestError<(S, A, A)>>
+where
+    S: Clone + Debug + PartialEq,
+    A: Clone + Debug + PartialEq,
+    L: Lens<S, A>,
+{
+    TestRunner::new(config()).run(&(sources, &values, &values), |(source, a, b)| {
+        let mut unchanged = source.clone();
+        lens.set(&mut unchanged, lens.get(&source));
+        prop_assert_eq!(&unchanged, &source, "get-set");
+
+        let mut set = source.clone();
+        lens.set(&mut set, a.clone());
+        prop_assert_eq!(lens.get(&set), a, "set-get");
+
+        let mut set_twice = set;
+        lens.set(&mut set_twice, b.clone());
+        let mut set_once = source;
+        lens.set(&mut set_once, b);
+        prop_assert_eq!(set_twice, set_once, "set-set");
+
+        Ok(())
+    })
+}
+
+/// Checks that `prism` obeys the prism laws, for sources generated by `sources` and values
+/// generated by `values`.
+///
+/// The laws checked are:
+///
+/// - get-set: setting the focus of a source to the value it already holds leaves the source
+///   unchanged.
+/// - set-get: after setting the focus of a source that has one, the focus is the value set. A
+///   source without a focus may either be left without one, or get the value set as its focus.
+/// - set-set: setting the focus twice is the same as only setting it the second time.
+///
+/// Strategies generating both sources with and without a focus check all of them.
+///
+/// # Errors
+///
+/// Returns the `TestError` of the runner with the minimal source and values breaking a law.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::laws::proptest::prism_laws;
+/// use optics::option;
+/// use proptest::prelude::*;
+///
+/// assert!(prism_laws(any::<Option<u8>>(), any::<u8>(), &option::some()).is_ok());
+/// ```
+#[cfg(feature = "prism")]
+pub fn prism_laws<S, A, P>(
+    sources: impl Strategy<Value = S>,
+    values: impl Strategy<Value = A>,
+    prism: &P,
+) -> Result<(), TestError<(S, A, A)>>
+where
+    S: Clone + Debug + PartialEq,
+    A: Clone + Debug + PartialEq,
+    P: Prism<S, A>,
+{
+    TestRunner::new(config()).run(&(sources, &values, &values), |(source, a, b)| {
+        let focus = prism.try_get(&source).ok();
+
+        if let Some(focus) = focus.clone() {
+            let mut unchanged = source.clone();
+            prism.set(&mut unchanged, focus);
+            prop_assert_eq!(&unchanged, &source, "get-set");
+        }
+
+        let mut set = source.clone();
+        prism.set(&mut set, a.clone());
+        match prism.try_get(&set).ok() {
+            None => ::proptest::prop_assert!(focus.is_none(), "set-get"),
+            Some(got) => prop_assert_eq!(got, a, "set-get"),
+        }
+
+        let mut set_twice = set;
+        prism.set(&mut set_twice, b.clone());
+        let mut set_once = source;
+        prism.set(&mut set_once, b);
+        prop_assert_eq!(set_twice, set_once, "set-set");
+
+        Ok(())
+    })
+}
+
+/// Checks that `iso` obeys the iso laws, for sources generated by `sources` and values generated
+/// by `values`.
+///
+/// The laws checked are that converting a source to its focus and back gives the source, and
+/// converting a value back to a source and to its focus again gives the value.
+///
+/// # Errors
+///
+/// Returns the `TestError` of the runner with the minimal source and value breaking a law.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::laws::proptest::iso_laws;
+/// use optics::mapped_iso;
+/// use proptest::prelude::*;
+///
+/// let swap = mapped_iso(|p: &(u8, i8)| (p.1, p.0), |p: &(i8, u8)| (p.1, p.0));
+///
+/// assert!(iso_laws(any::<(u8, i8)>(), any::<(i8, u8)>(), &swap).is_ok());
+/// ```
+#[cfg(feature = "iso")]
+pub fn iso_laws<S, A, I>(
+    sources: impl Strategy<Value = S>,
+    values: impl Strategy<Value = A>,
+    iso: &I,
+) -> Result<(), TestError<(S, A)>>
+where
+    S: Clone + Debug + PartialEq,
+    A: Clone + Debug + PartialEq,
+    I: Iso<S, A>,
+{
+    TestRunner::new(config()).run(&(sources, values), |(source, value)| {
+        prop_assert_eq!(
+            iso.reverse_get(&iso.get(&source)),
+            source,
+            "get-reverse_get"
+        );
+        prop_assert_eq!(iso.get(&iso.reverse_get(&value)), value, "reverse_get-get");
+
+        Ok(())
+    })
+}
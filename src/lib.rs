@@ -6,7 +6,10 @@
 #![allow(clippy::module_name_repetitions)]
 #![forbid(unsafe_code)]
 
+#[cfg(feature = "alloc")]
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
 use core::convert::Infallible;
 
@@ -14,25 +17,217 @@ fn infallible<E>(e: Infallible) -> E {
     match e {}
 }
 
+#[cfg(feature = "async")]
+mod async_optic;
 mod base;
+#[cfg(feature = "alloc")]
+mod binding;
+mod command;
+mod compose;
+#[cfg(feature = "alloc")]
+mod context_error;
+mod either;
+#[cfg(feature = "alloc")]
+mod erased_error;
 mod extensions;
+#[cfg(all(feature = "alloc", feature = "prism"))]
+mod keypath;
+#[cfg(feature = "proptest")]
+pub mod laws;
+#[cfg(feature = "alloc")]
+mod migrations;
+mod named;
 mod optics;
+mod optics_error;
+#[cfg(feature = "alloc")]
+mod patch;
+#[cfg(feature = "alloc")]
+mod registry;
+mod std_optics;
+#[cfg(feature = "store")]
+mod store;
+#[cfg(feature = "tracing")]
+mod traced;
+#[cfg(feature = "alloc")]
+mod transaction;
 
 #[cfg(test)]
 mod test;
 
-pub use base::{HasGetter, HasReverseGet, HasSetter};
-pub use extensions::{HasOver, HasTotalGetter, HasTotalReverseGet};
+#[cfg(feature = "async")]
+pub use async_optic::{AsyncGetter, AsyncSetter, ComposedAsyncOptic, LiftedAsyncOptic, lift_async};
+pub use base::{
+    HasFallibleSetter, HasGetter, HasGetterMut, HasGetterRef, HasInsert, HasModify, HasReverseGet,
+    HasSetter, HasTraversal, OuterLabels,
+};
+#[cfg(feature = "alloc")]
+pub use binding::{Binding, BindingError, FormBinder, OpticBinding};
+pub use command::{Command, OverCommand, SetCommand};
+#[cfg(feature = "alloc")]
+pub use context_error::ContextError;
+pub use either::{ComposedError, Either};
+#[cfg(feature = "alloc")]
+pub use erased_error::ErasedError;
+#[cfg(feature = "parallel")]
+pub use extensions::HasParOver;
+#[cfg(feature = "alloc")]
+pub use extensions::{Change, HasDiff, HasGetAll, diff};
+pub use extensions::{
+    ComposeWith, HasOver, HasTotalGetter, HasTotalReverseGet, HasUpdate, compose, compose3,
+    compose4,
+};
+#[cfg(feature = "serde")]
+pub use extensions::{DeserializeFocus, SerdeFocus, SerializeFocus, serde_focus};
+#[cfg(feature = "tokio")]
+pub use extensions::{WatchMap, send_through, watch_map};
+#[cfg(all(feature = "alloc", feature = "prism"))]
+pub use keypath::{KeyPathError, KeyPathRegistry};
+#[cfg(feature = "alloc")]
+pub use migrations::{MigrationChain, MigrationError};
+pub use named::HasPath;
 
+pub use optics::fallible_iso::FallibleIso;
+#[cfg(all(feature = "alloc", feature = "fallible-iso"))]
+pub use optics::fallible_iso::{BoxedFallibleIso, DynFallibleIso, SharedFallibleIso};
+#[cfg(feature = "fallible-iso")]
 pub use optics::fallible_iso::{
-    FallibleIso, FallibleIsoImpl, composed_fallible_iso, identity_fallible_iso, mapped_fallible_iso,
+    FallibleIsoImpl, FnFallibleIso, composed_fallible_iso, identity_fallible_iso,
+    mapped_fallible_iso,
+};
+#[cfg(feature = "alloc")]
+pub use optics::fallible_setter::{BoxedFallibleSetter, DynFallibleSetter, SharedFallibleSetter};
+pub use optics::fallible_setter::{
+    FallibleSetter, FallibleSetterImpl, FnFallibleSetter, composed_fallible_setter,
+    identity_fallible_setter, mapped_fallible_setter,
+};
+pub use optics::getter::Getter;
+#[cfg(all(feature = "alloc", feature = "getter"))]
+pub use optics::getter::{BoxedGetter, DynGetter, SharedGetter};
+#[cfg(feature = "getter")]
+pub use optics::getter::{FnGetter, GetterImpl, composed_getter, identity_getter, mapped_getter};
+pub use optics::iso::Iso;
+#[cfg(all(feature = "alloc", feature = "iso"))]
+pub use optics::iso::{BoxedIso, DynIso, SharedIso};
+#[cfg(feature = "iso")]
+pub use optics::iso::{FnIso, IsoImpl, composed_iso, identity_iso, mapped_iso};
+pub use optics::lens::Lens;
+#[cfg(all(feature = "alloc", feature = "lens"))]
+pub use optics::lens::{BoxedLens, DynLens, SharedLens};
+#[cfg(feature = "lens")]
+pub use optics::lens::{
+    FnLens, LensImpl, borrowed_lens, composed_lens, identity_lens, mapped_lens, mapped_ref_lens,
 };
-pub use optics::getter::{Getter, GetterImpl, composed_getter, identity_getter, mapped_getter};
-pub use optics::iso::{Iso, IsoImpl, composed_iso, identity_iso, mapped_iso};
-pub use optics::lens::{Lens, LensImpl, composed_lens, identity_lens, mapped_lens};
+#[cfg(feature = "alloc")]
+pub use optics::partial_getter::{BoxedPartialGetter, DynPartialGetter, SharedPartialGetter};
 pub use optics::partial_getter::{
-    PartialGetter, PartialGetterImpl, composed_partial_getter, identity_partial_getter,
-    mapped_partial_getter,
+    FnPartialGetter, PartialGetter, PartialGetterImpl, composed_partial_getter,
+    identity_partial_getter, mapped_partial_getter,
+};
+pub use optics::prism::Prism;
+#[cfg(all(feature = "alloc", feature = "prism"))]
+pub use optics::prism::{BoxedPrism, DynPrism, SharedPrism};
+#[cfg(feature = "prism")]
+pub use optics::prism::{
+    FnPrism, PredicateFailed, PrismImpl, composed_prism, identity_prism, mapped_prism,
+};
+pub use optics::setter::Setter;
+#[cfg(all(feature = "alloc", feature = "setter"))]
+pub use optics::setter::{BoxedSetter, DynSetter, SharedSetter};
+#[cfg(feature = "setter")]
+pub use optics::setter::{FnSetter, SetterImpl, composed_setter, identity_setter, mapped_setter};
+#[cfg(feature = "alloc")]
+pub use optics::traversal::{BoxedTraversal, DynTraversal, SharedTraversal};
+pub use optics::traversal::{
+    Traversal, TraversalImpl, composed_traversal, identity_traversal, mapped_traversal,
+};
+
+pub use optics_error::OpticsError;
+#[cfg(feature = "alloc")]
+pub use patch::{Patch, PatchError, PatchRegistry};
+#[cfg(feature = "alloc")]
+pub use registry::{OpticRegistry, OpticRegistryError};
+pub use std_optics::array::array_each;
+#[cfg(feature = "lens")]
+pub use std_optics::array::array_lens;
+#[cfg(feature = "lens")]
+pub use std_optics::bits::{
+    bit_lens, bit_lens_u8, bit_lens_u16, bit_lens_u64, bits_lens, bits_lens_u8, bits_lens_u16,
+    bits_lens_u64,
 };
-pub use optics::prism::{Prism, PrismImpl, composed_prism, identity_prism, mapped_prism};
-pub use optics::setter::{Setter, SetterImpl, composed_setter, identity_setter, mapped_setter};
+#[cfg(feature = "alloc")]
+pub use std_optics::btree_map::{self, MapEmpty};
+#[cfg(feature = "lens")]
+pub use std_optics::cell::cell_lens;
+#[cfg(feature = "prism")]
+pub use std_optics::cell::ref_cell_lens;
+#[cfg(feature = "chrono")]
+pub use std_optics::chrono;
+#[cfg(feature = "config")]
+pub use std_optics::config;
+#[cfg(feature = "iso")]
+pub use std_optics::convert::from_iso;
+#[cfg(all(feature = "alloc", feature = "fallible-iso"))]
+pub use std_optics::convert::parse_iso;
+#[cfg(feature = "fallible-iso")]
+pub use std_optics::convert::{try_from_both_iso, try_from_iso};
+#[cfg(feature = "alloc")]
+pub use std_optics::cow;
+#[cfg(feature = "alloc")]
+pub use std_optics::deque;
+#[cfg(any(feature = "json", feature = "toml", feature = "yaml"))]
+pub use std_optics::document::DocumentError;
+#[cfg(feature = "encoding")]
+pub use std_optics::encoding::DecodeError;
+#[cfg(all(feature = "encoding", feature = "fallible-iso"))]
+pub use std_optics::encoding::{base64_iso, hex_iso};
+#[cfg(feature = "figment")]
+pub use std_optics::figment;
+#[cfg(feature = "im")]
+pub use std_optics::im;
+#[cfg(all(feature = "alloc", feature = "prism"))]
+pub use std_optics::index::index_prism;
+pub use std_optics::index::{IndexError, slice_index_getter};
+#[cfg(feature = "json")]
+pub use std_optics::json;
+pub use std_optics::map::{self, KeyMissing};
+pub use std_optics::num::{self, NumericRangeError, ZeroError};
+pub use std_optics::option::{self, NoneError, SomeError};
+#[cfg(all(feature = "alloc", feature = "lens"))]
+pub use std_optics::pointer::{arc_make_mut, boxed, rc_make_mut};
+#[cfg(feature = "bevy_reflect")]
+pub use std_optics::reflect::ReflectError;
+#[cfg(all(feature = "bevy_reflect", feature = "prism"))]
+pub use std_optics::reflect::reflect_lens;
+pub use std_optics::result::{self, UnexpectedOk};
+pub use std_optics::socket_addr;
+pub use std_optics::std_isos;
+#[cfg(feature = "alloc")]
+pub use std_optics::string::{self, PrefixMissing, SuffixMissing};
+pub use std_optics::sync::LockPoisoned;
+#[cfg(all(feature = "std", feature = "prism"))]
+pub use std_optics::sync::{mutex_lens, rwlock_lens};
+#[cfg(feature = "fallible-iso")]
+pub use std_optics::time::duration_millis_u32;
+#[cfg(feature = "lens")]
+pub use std_optics::time::duration_secs;
+#[cfg(feature = "time")]
+pub use std_optics::time_crate as time;
+#[cfg(feature = "toml")]
+pub use std_optics::toml;
+pub use std_optics::tuple;
+#[cfg(feature = "url")]
+pub use std_optics::url;
+#[cfg(all(feature = "uuid", feature = "iso"))]
+pub use std_optics::uuid::uuid_bytes_iso;
+#[cfg(all(feature = "uuid", feature = "alloc", feature = "fallible-iso"))]
+pub use std_optics::uuid::uuid_str_iso;
+#[cfg(feature = "validator")]
+pub use std_optics::validator;
+#[cfg(feature = "alloc")]
+pub use std_optics::vec;
+#[cfg(feature = "yaml")]
+pub use std_optics::yaml;
+#[cfg(feature = "store")]
+pub use store::{Selector, Store, SubscriptionId};
+#[cfg(feature = "alloc")]
+pub use transaction::{Transaction, TransactionError};
@@ -0,0 +1,280 @@
+use crate::{ErasedError, HasGetter, HasReverseGet};
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+use alloc::vec::Vec;
+use core::any::{Any, TypeId, type_name};
+use core::fmt::{Debug, Display, Formatter};
+use core::marker::PhantomData;
+
+/// A sequence of migrations between the versions of a persisted data format, upgrading values of
+/// the oldest version `S` to the current version `A`.
+///
+/// Each migration is a fallible iso from one version to the next, appended with
+/// [`then`](MigrationChain::then). Values are upgraded with [`migrate`](MigrationChain::migrate)
+/// or, when they were persisted by an intermediate version, with
+/// [`migrate_from`](MigrationChain::migrate_from), and downgraded by the reverse conversions with
+/// [`downgrade`](MigrationChain::downgrade). A failed migration reports the versions of the step
+/// that failed.
+///
+/// Versions are told apart by their types, so each version of the chain must be a distinct type.
+/// Migrations are only described by fallible isos between whole versions: moving a field from one
+/// version to the next is written in the iso, for example by reading the field with a lens of the
+/// old version and writing it with a lens of the new one.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{MigrationChain, MigrationError, mapped_fallible_iso};
+///
+/// struct ConfigV1 {
+///     port: String,
+/// }
+///
+/// #[derive(Debug, PartialEq)]
+/// struct ConfigV2 {
+///     port: u16,
+/// }
+///
+/// let chain = MigrationChain::<ConfigV1, ConfigV1>::new().then(mapped_fallible_iso(
+///     |v1: &ConfigV1| v1.port.parse().map(|port| ConfigV2 { port }),
+///     |v2: &ConfigV2| Ok::<_, core::convert::Infallible>(ConfigV1 { port: v2.port.to_string() }),
+/// ));
+///
+/// let v2 = chain.migrate(ConfigV1 { port: "8080".to_string() }).unwrap();
+/// assert_eq!(v2, ConfigV2 { port: 8080 });
+///
+/// let error = chain.migrate(ConfigV1 { port: "http".to_string() }).unwrap_err();
+/// assert!(matches!(error, MigrationError::Failed { step: 0, .. }));
+/// ```
+pub struct MigrationChain<S, A> {
+    steps: Vec<Step>,
+    _phantom: PhantomData<fn(S) -> A>,
+}
+
+#[allow(clippy::type_complexity)]
+struct Step {
+    from_name: &'static str,
+    to: TypeId,
+    to_name: &'static str,
+    upgrade: Box<dyn Fn(&dyn Any) -> Result<Box<dyn Any>, ErasedError>>,
+    downgrade: Box<dyn Fn(&dyn Any) -> Result<Box<dyn Any>, ErasedError>>,
+}
+
+impl<V: 'static> MigrationChain<V, V> {
+    /// Creates a new chain without any migrations, its oldest and current versions both being `V`.
+    #[must_use]
+    pub fn new() -> Self {
+        MigrationChain {
+            steps: Vec::new(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<V: 'static> Default for MigrationChain<V, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: 'static, A: 'static> MigrationChain<S, A> {
+    /// Appends the migration `iso` from the current version `A` to the next version `B`, making `B`
+    /// the current version.
+    ///
+    /// # Parameters
+    ///
+    /// - `iso`: The fallible iso converting values of version `A` to version `B`, and back.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `B` is already a version of this chain, as values of that version could not be
+    /// told apart when migrating them.
+    #[must_use]
+    pub fn then<B, I>(self, iso: I) -> MigrationChain<S, B>
+    where
+        B: 'static,
+        I: HasGetter<A, B> + HasReverseGet<A, B> + 'static,
+        I::GetterError: Into<ErasedError>,
+        I::ReverseError: Into<ErasedError>,
+    {
+        assert!(
+            TypeId::of::<B>() != TypeId::of::<S>()
+                && self.steps.iter().all(|step| step.to != TypeId::of::<B>()),
+            "{} is already a version of the migration chain",
+            type_name::<B>()
+        );
+
+        let iso = Rc::new(iso);
+        let reverse = iso.clone();
+        let mut steps = self.steps;
+        steps.push(Step {
+            from_name: type_name::<A>(),
+            to: TypeId::of::<B>(),
+            to_name: type_name::<B>(),
+            upgrade: Box::new(move |value| {
+                let value = value
+                    .downcast_ref::<A>()
+                    .ok_or(MigrationError::UnknownVersion {
+                        version: type_name::<A>(),
+                    })?;
+                iso.try_get(value)
+                    .map(|value| Box::new(value) as Box<dyn Any>)
+                    .map_err(Into::into)
+            }),
+            downgrade: Box::new(move |value| {
+                let value = value
+                    .downcast_ref::<B>()
+                    .ok_or(MigrationError::UnknownVersion {
+                        version: type_name::<B>(),
+                    })?;
+                reverse
+                    .try_reverse_get(value)
+                    .map(|value| Box::new(value) as Box<dyn Any>)
+                    .map_err(Into::into)
+            }),
+        });
+
+        MigrationChain {
+            steps,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns the number of migrations in this chain.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// Returns `true` if this chain has no migrations.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Upgrades `value` of the oldest version to the current version, applying every migration of
+    /// the chain in order.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`MigrationError::Failed`] identifying the first migration that failed.
+    pub fn migrate(&self, value: S) -> Result<A, MigrationError> {
+        self.migrate_from(value)
+    }
+
+    /// Upgrades `value` of the version `V` to the current version, applying the migrations of the
+    /// chain following the first version `V`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`MigrationError::UnknownVersion`] if `V` is not a version of this chain, and a
+    /// [`MigrationError::Failed`] identifying the first migration that failed.
+    pub fn migrate_from<V: 'static>(&self, value: V) -> Result<A, MigrationError> {
+        let start = if TypeId::of::<V>() == TypeId::of::<S>() {
+            0
+        } else {
+            self.steps
+                .iter()
+                .position(|step| step.to == TypeId::of::<V>())
+                .ok_or(MigrationError::UnknownVersion {
+                    version: type_name::<V>(),
+                })?
+                + 1
+        };
+
+        let mut value: Box<dyn Any> = Box::new(value);
+        for (index, step) in self.steps.iter().enumerate().skip(start) {
+            value = (step.upgrade)(&*value).map_err(|error| MigrationError::Failed {
+                step: index,
+                from: step.from_name,
+                to: step.to_name,
+                error,
+            })?;
+        }
+
+        downcast(value)
+    }
+
+    /// Downgrades `value` of the current version to the oldest version, applying the reverse of
+    /// every migration of the chain in reverse order.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`MigrationError::Failed`] identifying the first migration whose reverse failed.
+    pub fn downgrade(&self, value: A) -> Result<S, MigrationError> {
+        let mut value: Box<dyn Any> = Box::new(value);
+        for (index, step) in self.steps.iter().enumerate().rev() {
+            value = (step.downgrade)(&*value).map_err(|error| MigrationError::Failed {
+                step: index,
+                from: step.to_name,
+                to: step.from_name,
+                error,
+            })?;
+        }
+
+        downcast(value)
+    }
+}
+
+fn downcast<T: 'static>(value: Box<dyn Any>) -> Result<T, MigrationError> {
+    value
+        .downcast::<T>()
+        .map(|value| *value)
+        .map_err(|_| MigrationError::UnknownVersion {
+            version: type_name::<T>(),
+        })
+}
+
+impl<S, A> Debug for MigrationChain<S, A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_list()
+            .entries(self.steps.iter().map(|step| (step.from_name, step.to_name)))
+            .finish()
+    }
+}
+
+/// The error of migrating a value with a [`MigrationChain`].
+#[derive(Debug)]
+pub enum MigrationError {
+    /// The version of the value to migrate is not a version of the chain.
+    UnknownVersion {
+        /// The name of the type of the value.
+        version: &'static str,
+    },
+    /// A migration of the chain failed.
+    Failed {
+        /// The position of the migration among the migrations of the chain.
+        step: usize,
+        /// The name of the version the migration converted from.
+        from: &'static str,
+        /// The name of the version the migration converted to.
+        to: &'static str,
+        /// The error of the migration.
+        error: ErasedError,
+    },
+}
+
+impl Display for MigrationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            MigrationError::UnknownVersion { version } => {
+                write!(f, "{version} is not a version of the migration chain")
+            }
+            MigrationError::Failed {
+                step,
+                from,
+                to,
+                error,
+            } => write!(f, "migration {step} from {from} to {to} failed: {error}"),
+        }
+    }
+}
+
+impl core::error::Error for MigrationError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            MigrationError::UnknownVersion { .. } => None,
+            MigrationError::Failed { error, .. } => Some(&**error),
+        }
+    }
+}
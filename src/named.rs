@@ -0,0 +1,150 @@
+use crate::{
+    HasFallibleSetter, HasGetter, HasGetterMut, HasGetterRef, HasInsert, HasModify, HasReverseGet,
+    HasSetter, HasTraversal,
+};
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::fmt::{Display, Formatter};
+
+/// An optic that can list the names of the fields and variants it focuses through.
+///
+/// Optics created by the [`field_lens!`](crate::field_lens) and
+/// [`enum_prism!`](crate::enum_prism) macros, or labelled with the `named` method of the optic
+/// wrappers have a single segment, and composed optics have the segments of their parts, so the
+/// path of any such optic can be printed for diagnostics with the `path` method of the wrappers.
+pub trait HasPath {
+    /// Calls `f` with each segment of the path of this optic, from the outermost one.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: The function to call with each segment.
+    fn for_each_segment<'a>(&'a self, f: &mut dyn FnMut(&'a str));
+
+    /// Returns the segments of the path of this optic joined with a `.`, such as `"main.port"`.
+    #[cfg(feature = "alloc")]
+    fn joined_path(&self) -> String {
+        let mut path = String::new();
+        self.for_each_segment(&mut |segment| {
+            if !path.is_empty() {
+                path.push('.');
+            }
+            path.push_str(segment);
+        });
+        path
+    }
+}
+
+/// An optic labelled with a static name, created by the `named` method of the optic wrappers.
+///
+/// It behaves exactly like the optic it wraps, implementing the same base traits, and displays as
+/// its name. Composed optics display as the names of their parts joined with a `.`, so a chain of
+/// named optics displays as a path such as `main.port`.
+#[derive(Debug, Clone, Copy)]
+pub struct Named<O> {
+    optic: O,
+    name: &'static str,
+}
+
+impl<O> Named<O> {
+    pub(crate) fn new(optic: O, name: &'static str) -> Self {
+        Named { optic, name }
+    }
+
+    /// Returns the name of the optic.
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+impl<O> Display for Named<O> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.name)
+    }
+}
+
+impl<O> HasPath for Named<O> {
+    fn for_each_segment<'a>(&'a self, f: &mut dyn FnMut(&'a str)) {
+        f(self.name);
+    }
+}
+
+impl<S, A, O: HasGetter<S, A>> HasGetter<S, A> for Named<O> {
+    type GetterError = O::GetterError;
+
+    #[inline]
+    fn try_get(&self, source: &S) -> Result<A, Self::GetterError> {
+        self.optic.try_get(source)
+    }
+}
+
+impl<S, A, O: HasGetterRef<S, A>> HasGetterRef<S, A> for Named<O> {
+    #[inline]
+    fn try_get_ref<'a>(&self, source: &'a S) -> Result<&'a A, Self::GetterError>
+    where
+        Self: 'a,
+    {
+        self.optic.try_get_ref(source)
+    }
+}
+
+impl<S, A, O: HasGetterMut<S, A>> HasGetterMut<S, A> for Named<O> {
+    #[inline]
+    fn try_get_mut<'a>(&self, source: &'a mut S) -> Result<&'a mut A, Self::GetterError>
+    where
+        Self: 'a,
+    {
+        self.optic.try_get_mut(source)
+    }
+}
+
+impl<S, A, O: HasSetter<S, A>> HasSetter<S, A> for Named<O> {
+    #[inline]
+    fn set(&self, source: &mut S, value: A) {
+        self.optic.set(source, value);
+    }
+}
+
+impl<S, A, O: HasInsert<S, A>> HasInsert<S, A> for Named<O> {
+    #[inline]
+    fn insert(&self, source: &mut S, value: A) {
+        self.optic.insert(source, value);
+    }
+}
+
+impl<S, A, O: HasModify<S, A>> HasModify<S, A> for Named<O> {
+    #[inline]
+    fn modify<F: FnOnce(&mut A)>(&self, source: &mut S, f: F) {
+        self.optic.modify(source, f);
+    }
+}
+
+impl<S, A, O: HasFallibleSetter<S, A>> HasFallibleSetter<S, A> for Named<O> {
+    type SetterError = O::SetterError;
+
+    #[inline]
+    fn try_set(&self, source: &mut S, value: A) -> Result<(), Self::SetterError> {
+        self.optic.try_set(source, value)
+    }
+}
+
+impl<S, A, O: HasReverseGet<S, A>> HasReverseGet<S, A> for Named<O> {
+    type ReverseError = O::ReverseError;
+
+    #[inline]
+    fn try_reverse_get(&self, value: &A) -> Result<S, Self::ReverseError> {
+        self.optic.try_reverse_get(value)
+    }
+}
+
+impl<S, A, O: HasTraversal<S, A>> HasTraversal<S, A> for Named<O> {
+    #[inline]
+    fn for_each(&self, source: &S, f: &mut dyn FnMut(A)) {
+        self.optic.for_each(source, f);
+    }
+
+    #[inline]
+    fn over_all(&self, source: &mut S, f: &mut dyn FnMut(A) -> A) {
+        self.optic.over_all(source, f);
+    }
+}
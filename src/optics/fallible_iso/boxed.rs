@@ -0,0 +1,51 @@
+use crate::{FallibleIsoImpl, HasGetter, HasModify, HasReverseGet, HasSetter};
+use alloc::boxed::Box;
+
+/// An object safe version of [`FallibleIso`](crate::FallibleIso), implemented by every fallible
+/// iso.
+///
+/// It can be used as a trait object, such as in a [`BoxedFallibleIso`], to store fallible isos of
+/// different types in the same collection. The methods of the [`HasModify`](crate::HasModify) base
+/// trait are not object safe, so they are not part of it, but they are implemented by the boxed
+/// fallible iso.
+pub trait DynFallibleIso<S, A, GE, RE>:
+    HasGetter<S, A, GetterError = GE> + HasSetter<S, A> + HasReverseGet<S, A, ReverseError = RE>
+{
+}
+
+impl<S, A, GE, RE, O> DynFallibleIso<S, A, GE, RE> for O where
+    O: HasGetter<S, A, GetterError = GE> + HasSetter<S, A> + HasReverseGet<S, A, ReverseError = RE>
+{
+}
+
+/// A boxed fallible iso of any type, focusing on a value of type `A` in a source of type `S`.
+///
+/// Unlike the opaque fallible isos returned by the constructors of this crate, boxed fallible isos
+/// of different types have the same type, so they can be stored in collections or struct fields,
+/// and composed like any other fallible iso. They are created with [`FallibleIsoImpl::boxed`].
+pub type BoxedFallibleIso<S, A, GE, RE> =
+    FallibleIsoImpl<S, A, Box<dyn DynFallibleIso<S, A, GE, RE> + Send + Sync>>;
+
+impl<S, A, GE, RE> HasGetter<S, A> for Box<dyn DynFallibleIso<S, A, GE, RE> + Send + Sync> {
+    type GetterError = GE;
+
+    fn try_get(&self, source: &S) -> Result<A, Self::GetterError> {
+        (**self).try_get(source)
+    }
+}
+
+impl<S, A, GE, RE> HasSetter<S, A> for Box<dyn DynFallibleIso<S, A, GE, RE> + Send + Sync> {
+    fn set(&self, source: &mut S, value: A) {
+        (**self).set(source, value);
+    }
+}
+
+impl<S, A, GE, RE> HasReverseGet<S, A> for Box<dyn DynFallibleIso<S, A, GE, RE> + Send + Sync> {
+    type ReverseError = RE;
+
+    fn try_reverse_get(&self, value: &A) -> Result<S, Self::ReverseError> {
+        (**self).try_reverse_get(value)
+    }
+}
+
+impl<S, A, GE, RE> HasModify<S, A> for Box<dyn DynFallibleIso<S, A, GE, RE> + Send + Sync> {}
@@ -1,17 +1,123 @@
+use crate::HasPath;
 use crate::HasReverseGet;
+use crate::OuterLabels;
 use crate::optics::fallible_iso::FallibleIso;
 use crate::optics::fallible_iso::wrapper::FallibleIsoImpl;
-use crate::{HasGetter, HasSetter};
+use crate::{HasGetter, HasModify, HasSetter};
+use core::fmt::{Debug, Display, Formatter};
 use core::marker::PhantomData;
 
-struct ComposedFallibleIso<S, I, A, GE, RE, FI1: FallibleIso<S, I>, FI2: FallibleIso<I, A>> {
-    optic1: FI1,
-    optic2: FI2,
-    getter_error_fn_1: fn(FI1::GetterError) -> GE,
-    getter_error_fn_2: fn(FI2::GetterError) -> GE,
-    reverse_error_fn_1: fn(FI1::ReverseError) -> RE,
-    reverse_error_fn_2: fn(FI2::ReverseError) -> RE,
-    _phantom: PhantomData<(S, I, A, GE, RE)>,
+mod private {
+    use crate::{FallibleIso, HasGetter, HasReverseGet};
+    use core::marker::PhantomData;
+
+    pub struct ComposedFallibleIso<
+        S,
+        I,
+        A,
+        GE,
+        RE,
+        FI1: FallibleIso<S, I>,
+        FI2: FallibleIso<I, A>,
+        GF1 = fn(<FI1 as HasGetter<S, I>>::GetterError) -> GE,
+        GF2 = fn(<FI2 as HasGetter<I, A>>::GetterError) -> GE,
+        RF1 = fn(<FI1 as HasReverseGet<S, I>>::ReverseError) -> RE,
+        RF2 = fn(<FI2 as HasReverseGet<I, A>>::ReverseError) -> RE,
+    > {
+        pub(super) optic1: FI1,
+        pub(super) optic2: FI2,
+        pub(super) getter_error_fn_1: GF1,
+        pub(super) getter_error_fn_2: GF2,
+        pub(super) reverse_error_fn_1: RF1,
+        pub(super) reverse_error_fn_2: RF2,
+        pub(super) _phantom: PhantomData<(S, I, A, GE, RE)>,
+    }
+}
+
+pub type ComposedFallibleIso<
+    S,
+    I,
+    A,
+    GE,
+    RE,
+    FI1,
+    FI2,
+    GF1 = fn(<FI1 as HasGetter<S, I>>::GetterError) -> GE,
+    GF2 = fn(<FI2 as HasGetter<I, A>>::GetterError) -> GE,
+    RF1 = fn(<FI1 as HasReverseGet<S, I>>::ReverseError) -> RE,
+    RF2 = fn(<FI2 as HasReverseGet<I, A>>::ReverseError) -> RE,
+> = private::ComposedFallibleIso<S, I, A, GE, RE, FI1, FI2, GF1, GF2, RF1, RF2>;
+
+impl<S, I, A, GE, RE, FI1, FI2, GF1, GF2, RF1, RF2> Clone
+    for ComposedFallibleIso<S, I, A, GE, RE, FI1, FI2, GF1, GF2, RF1, RF2>
+where
+    FI1: FallibleIso<S, I> + Clone,
+    FI2: FallibleIso<I, A> + Clone,
+    GF1: Clone,
+    GF2: Clone,
+    RF1: Clone,
+    RF2: Clone,
+{
+    fn clone(&self) -> Self {
+        ComposedFallibleIso {
+            optic1: self.optic1.clone(),
+            optic2: self.optic2.clone(),
+            getter_error_fn_1: self.getter_error_fn_1.clone(),
+            getter_error_fn_2: self.getter_error_fn_2.clone(),
+            reverse_error_fn_1: self.reverse_error_fn_1.clone(),
+            reverse_error_fn_2: self.reverse_error_fn_2.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<S, I, A, GE, RE, FI1, FI2, GF1, GF2, RF1, RF2> Copy
+    for ComposedFallibleIso<S, I, A, GE, RE, FI1, FI2, GF1, GF2, RF1, RF2>
+where
+    FI1: FallibleIso<S, I> + Copy,
+    FI2: FallibleIso<I, A> + Copy,
+    GF1: Copy,
+    GF2: Copy,
+    RF1: Copy,
+    RF2: Copy,
+{
+}
+
+impl<S, I, A, GE, RE, FI1, FI2, GF1, GF2, RF1, RF2> Debug
+    for ComposedFallibleIso<S, I, A, GE, RE, FI1, FI2, GF1, GF2, RF1, RF2>
+where
+    FI1: FallibleIso<S, I> + Debug,
+    FI2: FallibleIso<I, A> + Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ComposedFallibleIso")
+            .field("optic1", &self.optic1)
+            .field("optic2", &self.optic2)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<S, I, A, GE, RE, FI1, FI2, GF1, GF2, RF1, RF2> Display
+    for ComposedFallibleIso<S, I, A, GE, RE, FI1, FI2, GF1, GF2, RF1, RF2>
+where
+    FI1: FallibleIso<S, I> + Display,
+    FI2: FallibleIso<I, A> + Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}.{}", self.optic1, self.optic2)
+    }
+}
+
+impl<S, I, A, GE, RE, FI1, FI2, GF1, GF2, RF1, RF2> HasPath
+    for ComposedFallibleIso<S, I, A, GE, RE, FI1, FI2, GF1, GF2, RF1, RF2>
+where
+    FI1: FallibleIso<S, I> + HasPath,
+    FI2: FallibleIso<I, A> + HasPath,
+{
+    fn for_each_segment<'a>(&'a self, f: &mut dyn FnMut(&'a str)) {
+        self.optic1.for_each_segment(f);
+        self.optic2.for_each_segment(f);
+    }
 }
 
 impl<S, I, A, GE, RE, FI1: FallibleIso<S, I>, FI2: FallibleIso<I, A>>
@@ -37,44 +143,114 @@ impl<S, I, A, GE, RE, FI1: FallibleIso<S, I>, FI2: FallibleIso<I, A>>
     }
 }
 
-impl<S, I, A, GE, RE, FI1: FallibleIso<S, I>, FI2: FallibleIso<I, A>> HasGetter<S, A>
-    for ComposedFallibleIso<S, I, A, GE, RE, FI1, FI2>
+impl<S, I, A, GE, RE, FI1, FI2, GF1, GF2, RF1, RF2>
+    ComposedFallibleIso<S, I, A, GE, RE, FI1, FI2, GF1, GF2, RF1, RF2>
+where
+    FI1: FallibleIso<S, I>,
+    FI2: FallibleIso<I, A>,
+    GF1: Fn(FI1::GetterError) -> GE,
+    GF2: Fn(FI2::GetterError) -> GE,
+    RF1: Fn(FI1::ReverseError) -> RE,
+    RF2: Fn(FI2::ReverseError) -> RE,
+{
+    pub(crate) fn with_mappers(
+        optic1: FI1,
+        optic2: FI2,
+        getter_error_fn_1: GF1,
+        getter_error_fn_2: GF2,
+        reverse_error_fn_1: RF1,
+        reverse_error_fn_2: RF2,
+    ) -> Self {
+        ComposedFallibleIso {
+            optic1,
+            optic2,
+            getter_error_fn_1,
+            getter_error_fn_2,
+            reverse_error_fn_1,
+            reverse_error_fn_2,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<S, I, A, GE, RE, FI1: FallibleIso<S, I>, FI2: FallibleIso<I, A>, GF1, GF2, RF1, RF2>
+    HasGetter<S, A> for ComposedFallibleIso<S, I, A, GE, RE, FI1, FI2, GF1, GF2, RF1, RF2>
+where
+    GF1: Fn(FI1::GetterError) -> GE,
+    GF2: Fn(FI2::GetterError) -> GE,
 {
     type GetterError = GE;
 
+    #[inline]
     fn try_get(&self, source: &S) -> Result<A, Self::GetterError> {
+        self.try_get_within(source, &|_| {})
+    }
+
+    #[inline]
+    fn try_get_within(&self, source: &S, outer: OuterLabels<'_>) -> Result<A, Self::GetterError> {
         let i = self
             .optic1
-            .try_get(source)
-            .map_err(self.getter_error_fn_1)?;
-        self.optic2.try_get(&i).map_err(self.getter_error_fn_2)
+            .try_get_within(source, outer)
+            .map_err(&self.getter_error_fn_1)?;
+        self.optic2
+            .try_get_within(&i, &|f| {
+                outer(f);
+                self.optic1.for_each_label(f);
+            })
+            .map_err(&self.getter_error_fn_2)
+    }
+
+    fn for_each_label(&self, f: &mut dyn FnMut(&'static str)) {
+        self.optic1.for_each_label(f);
+        self.optic2.for_each_label(f);
     }
 }
 
-impl<S, I, A, GE, RE, FI1: FallibleIso<S, I>, FI2: FallibleIso<I, A>> HasReverseGet<S, A>
-    for ComposedFallibleIso<S, I, A, GE, RE, FI1, FI2>
+impl<S, I, A, GE, RE, FI1: FallibleIso<S, I>, FI2: FallibleIso<I, A>, GF1, GF2, RF1, RF2>
+    HasReverseGet<S, A> for ComposedFallibleIso<S, I, A, GE, RE, FI1, FI2, GF1, GF2, RF1, RF2>
+where
+    RF1: Fn(FI1::ReverseError) -> RE,
+    RF2: Fn(FI2::ReverseError) -> RE,
 {
     type ReverseError = RE;
 
+    #[inline]
     fn try_reverse_get(&self, value: &A) -> Result<S, Self::ReverseError> {
         let i = self
             .optic2
             .try_reverse_get(value)
-            .map_err(self.reverse_error_fn_2)?;
+            .map_err(&self.reverse_error_fn_2)?;
         self.optic1
             .try_reverse_get(&i)
-            .map_err(self.reverse_error_fn_1)
+            .map_err(&self.reverse_error_fn_1)
     }
 }
 
-impl<S, I, A, GE, RE, FI1: FallibleIso<S, I>, FI2: FallibleIso<I, A>> HasSetter<S, A>
-    for ComposedFallibleIso<S, I, A, GE, RE, FI1, FI2>
+impl<S, I, A, GE, RE, FI1: FallibleIso<S, I>, FI2: FallibleIso<I, A>, GF1, GF2, RF1, RF2>
+    HasSetter<S, A> for ComposedFallibleIso<S, I, A, GE, RE, FI1, FI2, GF1, GF2, RF1, RF2>
+where
+    GF1: Fn(FI1::GetterError) -> GE,
+    GF2: Fn(FI2::GetterError) -> GE,
+    RF1: Fn(FI1::ReverseError) -> RE,
+    RF2: Fn(FI2::ReverseError) -> RE,
 {
+    #[inline]
     fn set(&self, source: &mut S, value: A) {
-        if let Ok(mut i) = self.optic1.try_get(source).map_err(self.getter_error_fn_1) {
-            self.optic2.set(&mut i, value);
-            self.optic1.set(source, i);
-        }
+        self.optic1.modify(source, |i| self.optic2.set(i, value));
+    }
+}
+
+impl<S, I, A, GE, RE, FI1: FallibleIso<S, I>, FI2: FallibleIso<I, A>, GF1, GF2, RF1, RF2>
+    HasModify<S, A> for ComposedFallibleIso<S, I, A, GE, RE, FI1, FI2, GF1, GF2, RF1, RF2>
+where
+    GF1: Fn(FI1::GetterError) -> GE,
+    GF2: Fn(FI2::GetterError) -> GE,
+    RF1: Fn(FI1::ReverseError) -> RE,
+    RF2: Fn(FI2::ReverseError) -> RE,
+{
+    #[inline]
+    fn modify<F: FnOnce(&mut A)>(&self, source: &mut S, f: F) {
+        self.optic1.modify(source, |i| self.optic2.modify(i, f));
     }
 }
 
@@ -108,6 +284,7 @@ impl<S, I, A, GE, RE, FI1: FallibleIso<S, I>, FI2: FallibleIso<I, A>> HasSetter<
 ///
 /// - [`FallibleIso`] — the optic type that `ComposedFallibleIso` is based on
 #[must_use]
+#[allow(clippy::type_complexity)]
 pub fn new<S, A, I, GE, RE, FI1: FallibleIso<S, I>, FI2: FallibleIso<I, A>>(
     f1: FI1,
     f2: FI2,
@@ -115,9 +292,7 @@ pub fn new<S, A, I, GE, RE, FI1: FallibleIso<S, I>, FI2: FallibleIso<I, A>>(
     getter_error_fn_2: fn(FI2::GetterError) -> GE,
     reverse_error_fn_1: fn(FI1::ReverseError) -> RE,
     reverse_error_fn_2: fn(FI2::ReverseError) -> RE,
-) -> FallibleIsoImpl<S, A, impl FallibleIso<S, A, GetterError = GE, ReverseError = RE>>
-where
-{
+) -> FallibleIsoImpl<S, A, ComposedFallibleIso<S, I, A, GE, RE, FI1, FI2>> {
     ComposedFallibleIso::new(
         f1,
         f2,
@@ -0,0 +1,85 @@
+use crate::OuterLabels;
+use crate::optics::fallible_iso::wrapper::FallibleIsoImpl;
+use crate::{ContextError, FallibleIso, HasGetter, HasModify, HasReverseGet, HasSetter};
+use core::marker::PhantomData;
+
+struct ContextFallibleIso<FI: FallibleIso<S, A>, S, A> {
+    optic: FI,
+    label: &'static str,
+    _phantom: PhantomData<(S, A)>,
+}
+
+impl<FI, S, A> HasGetter<S, A> for ContextFallibleIso<FI, S, A>
+where
+    FI: FallibleIso<S, A>,
+{
+    type GetterError = ContextError<FI::GetterError>;
+
+    fn try_get(&self, source: &S) -> Result<A, Self::GetterError> {
+        self.try_get_within(source, &|_| {})
+    }
+
+    fn try_get_within(&self, source: &S, outer: OuterLabels<'_>) -> Result<A, Self::GetterError> {
+        self.optic
+            .try_get(source)
+            .map_err(|e| ContextError::within_path(outer, self.label, e))
+    }
+
+    fn for_each_label(&self, f: &mut dyn FnMut(&'static str)) {
+        f(self.label);
+    }
+}
+
+impl<FI, S, A> HasSetter<S, A> for ContextFallibleIso<FI, S, A>
+where
+    FI: FallibleIso<S, A>,
+{
+    fn set(&self, source: &mut S, value: A) {
+        self.optic.set(source, value);
+    }
+}
+
+impl<FI, S, A> HasModify<S, A> for ContextFallibleIso<FI, S, A>
+where
+    FI: FallibleIso<S, A>,
+{
+    fn modify<F: FnOnce(&mut A)>(&self, source: &mut S, f: F) {
+        self.optic.modify(source, f);
+    }
+}
+
+impl<FI, S, A> HasReverseGet<S, A> for ContextFallibleIso<FI, S, A>
+where
+    FI: FallibleIso<S, A>,
+{
+    type ReverseError = ContextError<FI::ReverseError>;
+
+    fn try_reverse_get(&self, value: &A) -> Result<S, Self::ReverseError> {
+        self.optic
+            .try_reverse_get(value)
+            .map_err(|e| ContextError::new(self.label, e))
+    }
+}
+
+/// Creates a `FallibleIso<S,A>` that converts through `iso`, labelling its errors with `label`.
+#[allow(clippy::type_complexity)]
+pub(crate) fn new<S, A, FI: FallibleIso<S, A>>(
+    iso: FI,
+    label: &'static str,
+) -> FallibleIsoImpl<
+    S,
+    A,
+    impl FallibleIso<
+        S,
+        A,
+        GetterError = ContextError<FI::GetterError>,
+        ReverseError = ContextError<FI::ReverseError>,
+    >,
+> {
+    ContextFallibleIso {
+        optic: iso,
+        label,
+        _phantom: PhantomData,
+    }
+    .into()
+}
@@ -0,0 +1,71 @@
+use crate::optics::fallible_iso::wrapper::FallibleIsoImpl;
+use crate::{ErasedError, FallibleIso, HasGetter, HasModify, HasReverseGet, HasSetter};
+use core::marker::PhantomData;
+
+struct ErasedFallibleIso<FI: FallibleIso<S, A>, S, A> {
+    optic: FI,
+    _phantom: PhantomData<(S, A)>,
+}
+
+impl<FI, S, A> HasGetter<S, A> for ErasedFallibleIso<FI, S, A>
+where
+    FI: FallibleIso<S, A>,
+    FI::GetterError: Into<ErasedError>,
+{
+    type GetterError = ErasedError;
+
+    fn try_get(&self, source: &S) -> Result<A, Self::GetterError> {
+        self.optic.try_get(source).map_err(Into::into)
+    }
+}
+
+impl<FI, S, A> HasSetter<S, A> for ErasedFallibleIso<FI, S, A>
+where
+    FI: FallibleIso<S, A>,
+{
+    fn set(&self, source: &mut S, value: A) {
+        self.optic.set(source, value);
+    }
+}
+
+impl<FI, S, A> HasModify<S, A> for ErasedFallibleIso<FI, S, A>
+where
+    FI: FallibleIso<S, A>,
+    FI::GetterError: Into<ErasedError>,
+{
+    fn modify<F: FnOnce(&mut A)>(&self, source: &mut S, f: F) {
+        self.optic.modify(source, f);
+    }
+}
+
+impl<FI, S, A> HasReverseGet<S, A> for ErasedFallibleIso<FI, S, A>
+where
+    FI: FallibleIso<S, A>,
+    FI::ReverseError: Into<ErasedError>,
+{
+    type ReverseError = ErasedError;
+
+    fn try_reverse_get(&self, value: &A) -> Result<S, Self::ReverseError> {
+        self.optic.try_reverse_get(value).map_err(Into::into)
+    }
+}
+
+/// Creates a `FallibleIso<S,A>` that converts through `iso`, boxing its errors into an
+/// [`ErasedError`].
+pub(crate) fn new<S, A, FI: FallibleIso<S, A>>(
+    iso: FI,
+) -> FallibleIsoImpl<
+    S,
+    A,
+    impl FallibleIso<S, A, GetterError = ErasedError, ReverseError = ErasedError>,
+>
+where
+    FI::GetterError: Into<ErasedError>,
+    FI::ReverseError: Into<ErasedError>,
+{
+    ErasedFallibleIso {
+        optic: iso,
+        _phantom: PhantomData,
+    }
+    .into()
+}
@@ -0,0 +1,60 @@
+use crate::optics::fallible_iso::wrapper::FallibleIsoImpl;
+use crate::{FallibleIso, HasGetter, HasModify, HasReverseGet, HasSetter};
+use core::marker::PhantomData;
+
+struct InvertedFallibleIso<FI: FallibleIso<S, A>, S, A> {
+    optic: FI,
+    _phantom: PhantomData<(S, A)>,
+}
+
+impl<FI, S, A> HasGetter<A, S> for InvertedFallibleIso<FI, S, A>
+where
+    FI: FallibleIso<S, A>,
+{
+    type GetterError = FI::ReverseError;
+
+    fn try_get(&self, source: &A) -> Result<S, Self::GetterError> {
+        self.optic.try_reverse_get(source)
+    }
+}
+
+impl<FI, S, A> HasSetter<A, S> for InvertedFallibleIso<FI, S, A>
+where
+    FI: FallibleIso<S, A>,
+{
+    fn set(&self, source: &mut A, value: S) {
+        self.optic
+            .try_get(&value)
+            .into_iter()
+            .for_each(|a| *source = a);
+    }
+}
+
+impl<FI, S, A> HasModify<A, S> for InvertedFallibleIso<FI, S, A> where FI: FallibleIso<S, A> {}
+
+impl<FI, S, A> HasReverseGet<A, S> for InvertedFallibleIso<FI, S, A>
+where
+    FI: FallibleIso<S, A>,
+{
+    type ReverseError = FI::GetterError;
+
+    fn try_reverse_get(&self, value: &S) -> Result<A, Self::ReverseError> {
+        self.optic.try_get(value)
+    }
+}
+
+/// Creates a `FallibleIso<A,S>` that converts in the opposite direction of `iso`, swapping its
+/// getter and reverse errors.
+pub(crate) fn new<S, A, FI: FallibleIso<S, A>>(
+    iso: FI,
+) -> FallibleIsoImpl<
+    A,
+    S,
+    impl FallibleIso<A, S, GetterError = FI::ReverseError, ReverseError = FI::GetterError>,
+> {
+    InvertedFallibleIso {
+        optic: iso,
+        _phantom: PhantomData,
+    }
+    .into()
+}
@@ -1,16 +1,68 @@
+use crate::HasReverseGet;
 use crate::optics::fallible_iso::wrapper::FallibleIsoImpl;
-use crate::{FallibleIso, HasReverseGet};
-use crate::{HasGetter, HasSetter};
+use crate::{HasGetter, HasModify, HasSetter};
+use core::fmt::{Debug, Formatter};
 use core::marker::PhantomData;
 
-struct MappedFallibleIso<S, A, GE, RE, GET = fn(&S) -> Result<A, GE>, REV = fn(&A) -> Result<S, RE>>
+mod private {
+    use core::marker::PhantomData;
+
+    pub struct MappedFallibleIso<
+        S,
+        A,
+        GE,
+        RE,
+        GET = fn(&S) -> Result<A, GE>,
+        REV = fn(&A) -> Result<S, RE>,
+    >
+    where
+        GET: Fn(&S) -> Result<A, GE>,
+        REV: Fn(&A) -> Result<S, RE>,
+    {
+        pub(super) get_fn: GET,
+        pub(super) rev_fn: REV,
+        pub(super) phantom: PhantomData<(S, A, GE, RE)>,
+    }
+}
+
+pub type MappedFallibleIso<
+    S,
+    A,
+    GE,
+    RE,
+    GET = fn(&S) -> Result<A, GE>,
+    REV = fn(&A) -> Result<S, RE>,
+> = private::MappedFallibleIso<S, A, GE, RE, GET, REV>;
+
+impl<S, A, GE, RE, GET, REV> Clone for MappedFallibleIso<S, A, GE, RE, GET, REV>
+where
+    GET: Fn(&S) -> Result<A, GE> + Clone,
+    REV: Fn(&A) -> Result<S, RE> + Clone,
+{
+    fn clone(&self) -> Self {
+        MappedFallibleIso {
+            get_fn: self.get_fn.clone(),
+            rev_fn: self.rev_fn.clone(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<S, A, GE, RE, GET, REV> Copy for MappedFallibleIso<S, A, GE, RE, GET, REV>
+where
+    GET: Fn(&S) -> Result<A, GE> + Copy,
+    REV: Fn(&A) -> Result<S, RE> + Copy,
+{
+}
+
+impl<S, A, GE, RE, GET, REV> Debug for MappedFallibleIso<S, A, GE, RE, GET, REV>
 where
     GET: Fn(&S) -> Result<A, GE>,
     REV: Fn(&A) -> Result<S, RE>,
 {
-    get_fn: GET,
-    rev_fn: REV,
-    phantom: PhantomData<(S, A, GE, RE)>,
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("MappedFallibleIso").finish_non_exhaustive()
+    }
 }
 
 impl<S, A, GE, RE, GET, REV> MappedFallibleIso<S, A, GE, RE, GET, REV>
@@ -18,7 +70,7 @@ where
     GET: Fn(&S) -> Result<A, GE>,
     REV: Fn(&A) -> Result<S, RE>,
 {
-    fn new(get_fn: GET, rev_fn: REV) -> Self {
+    const fn new(get_fn: GET, rev_fn: REV) -> Self {
         MappedFallibleIso {
             get_fn,
             rev_fn,
@@ -51,6 +103,13 @@ where
     }
 }
 
+impl<S, A, GE, RE, GET, REV> HasModify<S, A> for MappedFallibleIso<S, A, GE, RE, GET, REV>
+where
+    GET: Fn(&S) -> Result<A, GE>,
+    REV: Fn(&A) -> Result<S, RE>,
+{
+}
+
 impl<S, A, GE, RE, GET, REV> HasReverseGet<S, A> for MappedFallibleIso<S, A, GE, RE, GET, REV>
 where
     GET: Fn(&S) -> Result<A, GE>,
@@ -109,13 +168,13 @@ where
 /// assert_eq!(s, "8082".to_string());
 /// ```
 #[must_use]
-pub fn new<S, A, GE, RE, GET, REV>(
+pub const fn new<S, A, GE, RE, GET, REV>(
     get_fn: GET,
     rev_fn: REV,
-) -> FallibleIsoImpl<S, A, impl FallibleIso<S, A, GetterError = GE, ReverseError = RE>>
+) -> FallibleIsoImpl<S, A, MappedFallibleIso<S, A, GE, RE, GET, REV>>
 where
     GET: Fn(&S) -> Result<A, GE>,
     REV: Fn(&A) -> Result<S, RE>,
 {
-    MappedFallibleIso::new(get_fn, rev_fn).into()
+    FallibleIsoImpl::new(MappedFallibleIso::new(get_fn, rev_fn))
 }
@@ -1,13 +1,42 @@
-use crate::{HasGetter, HasSetter};
+use crate::{HasGetter, HasModify, HasSetter};
+#[cfg(all(feature = "alloc", feature = "fallible-iso"))]
+mod boxed;
+#[cfg(feature = "fallible-iso")]
 pub(crate) mod composed;
+#[cfg(all(feature = "alloc", feature = "fallible-iso"))]
+mod context;
+#[cfg(all(feature = "alloc", feature = "fallible-iso"))]
+mod erased;
+#[cfg(feature = "fallible-iso")]
+mod inverted;
+#[cfg(feature = "fallible-iso")]
 pub(crate) mod mapped;
+#[cfg(all(feature = "alloc", feature = "fallible-iso"))]
+mod shared;
+#[cfg(feature = "fallible-iso")]
 mod wrapper;
 
 use crate::HasReverseGet;
+#[cfg(all(feature = "alloc", feature = "fallible-iso"))]
+pub use boxed::{BoxedFallibleIso, DynFallibleIso};
+#[cfg(feature = "fallible-iso")]
+pub(crate) use composed::ComposedFallibleIso;
+#[cfg(feature = "fallible-iso")]
 pub use composed::new as composed_fallible_iso;
+#[cfg(feature = "fallible-iso")]
 pub use mapped::new as mapped_fallible_iso;
+#[cfg(all(feature = "alloc", feature = "fallible-iso"))]
+pub use shared::SharedFallibleIso;
+#[cfg(feature = "fallible-iso")]
 pub use wrapper::FallibleIsoImpl;
 
+/// A [`FallibleIsoImpl`] built by [`mapped_fallible_iso`] from plain functions or non-capturing closures.
+///
+/// As [`mapped_fallible_iso`] is a `const fn`, this type can be used to declare the optic as a `static`.
+#[cfg(feature = "fallible-iso")]
+pub type FnFallibleIso<S, A, GE, RE> =
+    FallibleIsoImpl<S, A, mapped::MappedFallibleIso<S, A, GE, RE>>;
+
 /// A `FallibleIso` defines a reversible, but potentially failing conversion between two types.
 ///
 /// It provides:
@@ -29,9 +58,15 @@ pub use wrapper::FallibleIsoImpl;
 /// # See Also
 /// - [`Iso`] — a variant of `FallibleIso` where the mapping cannot fail.
 /// - [`FallibleIsoImpl`] — the wrapper of opaque struct that implement the `FallibleIso` trait
-pub trait FallibleIso<S, A>: HasGetter<S, A> + HasSetter<S, A> + HasReverseGet<S, A> {}
+pub trait FallibleIso<S, A>:
+    HasGetter<S, A> + HasSetter<S, A> + HasModify<S, A> + HasReverseGet<S, A>
+{
+}
 
-impl<S, A, FI: HasGetter<S, A> + HasSetter<S, A> + HasReverseGet<S, A>> FallibleIso<S, A> for FI {}
+impl<S, A, FI: HasGetter<S, A> + HasSetter<S, A> + HasModify<S, A> + HasReverseGet<S, A>>
+    FallibleIso<S, A> for FI
+{
+}
 
 /// Creates a `FallibleIso` that maps an input to itself. This is actually an `Iso`.
 ///
@@ -65,6 +100,7 @@ impl<S, A, FI: HasGetter<S, A> + HasSetter<S, A> + HasReverseGet<S, A>> Fallible
 /// # See Also
 ///
 /// - [`mapped_fallible_iso`] for constructing custom `FallibleIso`s from arbitrary mapping functions.
+#[cfg(feature = "fallible-iso")]
 #[must_use]
 pub fn identity_fallible_iso<S: Clone, GE, RE>()
 -> FallibleIsoImpl<S, S, impl FallibleIso<S, S, GetterError = GE, ReverseError = RE>> {
@@ -0,0 +1,35 @@
+use crate::{DynFallibleIso, FallibleIsoImpl, HasGetter, HasModify, HasReverseGet, HasSetter};
+use alloc::sync::Arc;
+
+/// A shared fallible iso of any type, focusing on a value of type `A` in a source of type `S`.
+///
+/// Like [boxed fallible isos](crate::BoxedFallibleIso), shared fallible isos of different types
+/// have the same type, but they are reference counted, so they can be cloned cheaply, stored in
+/// registries and sent to other threads. They are created with
+/// [`FallibleIsoImpl::shared`](crate::FallibleIsoImpl::shared).
+pub type SharedFallibleIso<S, A, GE, RE> =
+    FallibleIsoImpl<S, A, Arc<dyn DynFallibleIso<S, A, GE, RE> + Send + Sync>>;
+
+impl<S, A, GE, RE> HasGetter<S, A> for Arc<dyn DynFallibleIso<S, A, GE, RE> + Send + Sync> {
+    type GetterError = GE;
+
+    fn try_get(&self, source: &S) -> Result<A, Self::GetterError> {
+        (**self).try_get(source)
+    }
+}
+
+impl<S, A, GE, RE> HasSetter<S, A> for Arc<dyn DynFallibleIso<S, A, GE, RE> + Send + Sync> {
+    fn set(&self, source: &mut S, value: A) {
+        (**self).set(source, value);
+    }
+}
+
+impl<S, A, GE, RE> HasReverseGet<S, A> for Arc<dyn DynFallibleIso<S, A, GE, RE> + Send + Sync> {
+    type ReverseError = RE;
+
+    fn try_reverse_get(&self, value: &A) -> Result<S, Self::ReverseError> {
+        (**self).try_reverse_get(value)
+    }
+}
+
+impl<S, A, GE, RE> HasModify<S, A> for Arc<dyn DynFallibleIso<S, A, GE, RE> + Send + Sync> {}
@@ -1,10 +1,42 @@
+#[cfg(feature = "getter")]
+use crate::Getter;
+use crate::HasPath;
+#[cfg(feature = "iso")]
+use crate::Iso;
+#[cfg(feature = "lens")]
+use crate::Lens;
+use crate::OuterLabels;
+use crate::named::Named;
+use crate::optics::fallible_iso::ComposedFallibleIso;
+use crate::optics::fallible_iso::inverted::new as invert;
+use crate::optics::fallible_setter::ComposedFallibleSetter;
+use crate::optics::partial_getter::{ComposedPartialGetter, ReadOnly};
+use crate::optics::prism::ComposedPrism;
+use crate::optics::setter::{ComposedSetter, WriteOnly};
+use crate::optics::traversal::{ComposedTraversal, SingleTraversal, single_traversal};
+#[cfg(feature = "tracing")]
+use crate::traced::Traced;
 use crate::{
-    FallibleIso, Getter, GetterImpl, HasGetter, HasReverseGet, HasSetter, Iso, IsoImpl, Lens,
-    LensImpl, PartialGetter, PartialGetterImpl, Prism, PrismImpl, Setter, SetterImpl,
-    composed_fallible_iso, composed_partial_getter, composed_prism, composed_setter, infallible,
+    Either, FallibleIso, FallibleSetter, FallibleSetterImpl, HasFallibleSetter, HasGetter,
+    HasModify, HasReverseGet, HasSetter, PartialGetter, PartialGetterImpl, Prism, PrismImpl,
+    Setter, SetterImpl, Traversal, TraversalImpl, composed_fallible_iso, composed_fallible_setter,
+    composed_partial_getter, composed_prism, composed_setter, composed_traversal, infallible,
+    mapped_fallible_iso,
 };
-use core::convert::identity;
+use core::convert::{Infallible, identity};
+use core::fmt::{Debug, Display, Formatter};
 use core::marker::PhantomData;
+#[cfg(feature = "alloc")]
+use {
+    crate::optics::fallible_iso::context::new as context,
+    crate::optics::fallible_iso::erased::new as erase_err,
+    crate::optics::fallible_iso::{BoxedFallibleIso, DynFallibleIso, SharedFallibleIso},
+    crate::{ContextError, ErasedError},
+    alloc::boxed::Box,
+    alloc::string::{String, ToString},
+    alloc::sync::Arc,
+    alloc::vec::Vec,
+};
 
 /// A wrapper of the [`FallibleIso`] optic implementations, encapsulating a potentially failing,
 /// reversible bijective conversion.
@@ -36,7 +68,7 @@ use core::marker::PhantomData;
 pub struct FallibleIsoImpl<S, A, FI: FallibleIso<S, A>>(pub FI, PhantomData<(S, A)>);
 
 impl<S, A, FI: FallibleIso<S, A>> FallibleIsoImpl<S, A, FI> {
-    fn new(l: FI) -> Self {
+    pub(crate) const fn new(l: FI) -> Self {
         //TODO: Verify not to nest an Impl inside an Impl - currently seems to be impossible at compile time.
         FallibleIsoImpl(l, PhantomData)
     }
@@ -48,28 +80,176 @@ impl<S, A, FI: FallibleIso<S, A>> From<FI> for FallibleIsoImpl<S, A, FI> {
     }
 }
 
+impl<S, A, FI: FallibleIso<S, A> + Clone> Clone for FallibleIsoImpl<S, A, FI> {
+    fn clone(&self) -> Self {
+        Self::new(self.0.clone())
+    }
+}
+
+impl<S, A, FI: FallibleIso<S, A> + Copy> Copy for FallibleIsoImpl<S, A, FI> {}
+
+impl<S, A, FI: FallibleIso<S, A> + Debug> Debug for FallibleIsoImpl<S, A, FI> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("FallibleIsoImpl").field(&self.0).finish()
+    }
+}
+
+impl<S, A, FI: FallibleIso<S, A> + Display> Display for FallibleIsoImpl<S, A, FI> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl<S, A, FI: FallibleIso<S, A> + HasPath> HasPath for FallibleIsoImpl<S, A, FI> {
+    fn for_each_segment<'a>(&'a self, f: &mut dyn FnMut(&'a str)) {
+        self.0.for_each_segment(f);
+    }
+}
+
+impl<S, A, FI: FallibleIso<S, A>> FallibleIsoImpl<S, A, FI> {
+    /// Labels this optic with a static `name`, used by its [`Display`] implementation.
+    ///
+    /// Composing named optics results in an optic displaying the names of its parts joined with a
+    /// `.`, which helps to identify a misbehaving optic in logs and error messages.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The name of the optic.
+    ///
+    /// # Returns
+    ///
+    /// A new `FallibleIsoImpl` behaving exactly like `self`, displayed as `name`.
+    #[must_use]
+    pub fn named(self, name: &'static str) -> FallibleIsoImpl<S, A, Named<FI>> {
+        Named::new(self.0, name).into()
+    }
+
+    /// Wraps this optic to emit a `tracing` event for every operation going through it, with
+    /// `name` identifying the optic, whether the operation succeeded and the time it took.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The name of the optic in the events, such as `"config.port"`.
+    ///
+    /// # Returns
+    ///
+    /// A new `FallibleIsoImpl` behaving exactly like `self`, tracing its operations.
+    #[cfg(feature = "tracing")]
+    #[must_use]
+    pub fn traced(self, name: &'static str) -> FallibleIsoImpl<S, A, Traced<FI>> {
+        Traced::new(self.0, name).into()
+    }
+
+    /// Returns the name of the optic, the names of the optics it is composed of joined with a `.`.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn name(&self) -> String
+    where
+        FI: Display,
+    {
+        self.to_string()
+    }
+
+    /// Returns the names of the fields and variants this optic focuses through, from the outermost
+    /// one, such as the field names of the [`field_lens!`](crate::field_lens) lenses it is
+    /// composed of.
+    #[cfg(feature = "alloc")]
+    pub fn path(&self) -> impl Iterator<Item = &str>
+    where
+        FI: HasPath,
+    {
+        let mut path = Vec::new();
+        self.0.for_each_segment(&mut |segment| path.push(segment));
+        path.into_iter()
+    }
+
+    /// Boxes this fallible iso, erasing its type, so it can be stored alongside fallible isos of
+    /// other types.
+    ///
+    /// # Returns
+    ///
+    /// A [`BoxedFallibleIso`] behaving exactly like `self`.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn boxed(self) -> BoxedFallibleIso<S, A, FI::GetterError, FI::ReverseError>
+    where
+        FI: Send + Sync + 'static,
+    {
+        let optic: Box<dyn DynFallibleIso<S, A, FI::GetterError, FI::ReverseError> + Send + Sync> =
+            Box::new(self.0);
+        optic.into()
+    }
+
+    /// Wraps this fallible iso in an [`Arc`], erasing its type, so it can be cloned cheaply and
+    /// shared between threads.
+    ///
+    /// # Returns
+    ///
+    /// A [`SharedFallibleIso`] behaving exactly like `self`.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn shared(self) -> SharedFallibleIso<S, A, FI::GetterError, FI::ReverseError>
+    where
+        FI: Send + Sync + 'static,
+    {
+        let optic: Arc<dyn DynFallibleIso<S, A, FI::GetterError, FI::ReverseError> + Send + Sync> =
+            Arc::new(self.0);
+        optic.into()
+    }
+}
+
 impl<S, A, FI: FallibleIso<S, A>> HasGetter<S, A> for FallibleIsoImpl<S, A, FI> {
     type GetterError = FI::GetterError;
 
+    #[inline]
     fn try_get(&self, source: &S) -> Result<A, Self::GetterError> {
         self.0.try_get(source)
     }
+
+    #[inline]
+    fn try_get_within(&self, source: &S, outer: OuterLabels<'_>) -> Result<A, Self::GetterError> {
+        self.0.try_get_within(source, outer)
+    }
+
+    #[inline]
+    fn for_each_label(&self, f: &mut dyn FnMut(&'static str)) {
+        self.0.for_each_label(f);
+    }
 }
 
 impl<S, A, FI: FallibleIso<S, A>> HasSetter<S, A> for FallibleIsoImpl<S, A, FI> {
+    #[inline]
     fn set(&self, source: &mut S, value: A) {
         self.0.set(source, value);
     }
 }
 
+impl<S, A, FI: FallibleIso<S, A>> HasModify<S, A> for FallibleIsoImpl<S, A, FI> {
+    #[inline]
+    fn modify<F: FnOnce(&mut A)>(&self, source: &mut S, f: F) {
+        self.0.modify(source, f);
+    }
+}
+
 impl<S, A, FI: FallibleIso<S, A>> HasReverseGet<S, A> for FallibleIsoImpl<S, A, FI> {
     type ReverseError = FI::ReverseError;
 
+    #[inline]
     fn try_reverse_get(&self, value: &A) -> Result<S, Self::ReverseError> {
         self.0.try_reverse_get(value)
     }
 }
 
+impl<S, A, FI: FallibleIso<S, A>> HasFallibleSetter<S, A> for FallibleIsoImpl<S, A, FI> {
+    type SetterError = FI::ReverseError;
+
+    #[inline]
+    fn try_set(&self, source: &mut S, value: A) -> Result<(), Self::SetterError> {
+        *source = self.0.try_reverse_get(&value)?;
+        Ok(())
+    }
+}
+
 impl<S, I, FI1: FallibleIso<S, I>> FallibleIsoImpl<S, I, FI1> {
     /// Composes this `FallibleIsoImpl<S,I>` with a `PartialGetter<I,A>`, resulting in a new `PartialGetter<S, A>`
     /// that focuses through both optics sequentially.
@@ -99,13 +279,13 @@ impl<S, I, FI1: FallibleIso<S, I>> FallibleIsoImpl<S, I, FI1> {
     /// [`compose_with_partial_getter_with_mappers`](Self::compose_with_partial_getter_with_mappers).
     pub fn compose_with_partial_getter<E, A, PG2: PartialGetter<I, A>>(
         self,
-        other: PartialGetterImpl<I, A, PG2>,
-    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A, GetterError = E>>
+        other: PG2,
+    ) -> PartialGetterImpl<S, A, ComposedPartialGetter<FI1, PG2, E, S, I, A>>
     where
         FI1::GetterError: Into<E>,
         PG2::GetterError: Into<E>,
     {
-        composed_partial_getter(self.0, other.0, Into::into, Into::into)
+        composed_partial_getter(self.0, other, Into::into, Into::into)
     }
 
     /// Composes this `FallibleIsoImpl<S,I>` with a `PartialGetter<I,A>`, resulting in a new `PartialGetter<S, A>`
@@ -135,13 +315,45 @@ impl<S, I, FI1: FallibleIso<S, I>> FallibleIsoImpl<S, I, FI1> {
     /// This method is similar to [`compose_with_partial_getter`](Self::compose_with_partial_getter), but
     /// provides the ability to specify custom functions to map the errors from each
     /// optic into a common error type.
-    pub fn compose_with_partial_getter_with_mappers<E, A, PG2: PartialGetter<I, A>>(
+    #[allow(clippy::type_complexity)]
+    pub fn compose_with_partial_getter_with_mappers<E, A, PG2: PartialGetter<I, A>, F1, F2>(
+        self,
+        other: PG2,
+        error_mapper_1: F1,
+        error_mapper_2: F2,
+    ) -> PartialGetterImpl<S, A, ComposedPartialGetter<FI1, PG2, E, S, I, A, F1, F2>>
+    where
+        F1: Fn(FI1::GetterError) -> E,
+        F2: Fn(PG2::GetterError) -> E,
+    {
+        ComposedPartialGetter::with_mappers(self.0, other, error_mapper_1, error_mapper_2).into()
+    }
+
+    /// Composes this `FallibleIsoImpl<S,I>` with a `PartialGetterImpl<I,A>`, resulting in a new `PartialGetterImpl<S, A>`
+    /// whose error identifies which of the two optics failed.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `A`: The target type of the composed optic.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: The partial getter to compose with.
+    ///
+    /// # Returns
+    ///
+    /// A new `PartialGetterImpl` failing with a [`Either::Left`] if `self` fails, or a
+    /// [`Either::Right`] if `other` fails.
+    #[allow(clippy::type_complexity)]
+    pub fn compose_with_partial_getter_tracking<A, PG2: PartialGetter<I, A>>(
         self,
-        other: PartialGetterImpl<I, A, PG2>,
-        error_mapper_1: fn(FI1::GetterError) -> E,
-        error_mapper_2: fn(PG2::GetterError) -> E,
-    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A, GetterError = E>> {
-        composed_partial_getter(self.0, other.0, error_mapper_1, error_mapper_2)
+        other: PG2,
+    ) -> PartialGetterImpl<
+        S,
+        A,
+        ComposedPartialGetter<FI1, PG2, Either<FI1::GetterError, PG2::GetterError>, S, I, A>,
+    > {
+        composed_partial_getter(self.0, other, Either::Left, Either::Right)
     }
 
     /// Composes this `FallibleIsoImpl<S,I>` with a `Getter<I,A>`, resulting in a new `PartialGetter<S, A>`
@@ -163,11 +375,13 @@ impl<S, I, FI1: FallibleIso<S, I>> FallibleIsoImpl<S, I, FI1> {
     ///
     /// A new `PartialGetterImpl` that represents the composition of `self` and `other`.
     ///
+    #[cfg(feature = "getter")]
+    #[allow(clippy::type_complexity)]
     pub fn compose_with_getter<A, G2: Getter<I, A>>(
         self,
-        other: GetterImpl<I, A, G2>,
-    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A, GetterError = FI1::GetterError>> {
-        composed_partial_getter(self.0, other.0, identity, infallible)
+        other: G2,
+    ) -> PartialGetterImpl<S, A, ComposedPartialGetter<FI1, G2, FI1::GetterError, S, I, A>> {
+        composed_partial_getter(self.0, other, identity, infallible)
     }
 
     /// Composes this `FallibleIsoImpl<S,I>` with a `Setter<I,A>`, resulting in a new `Setter<S, A>`
@@ -191,9 +405,75 @@ impl<S, I, FI1: FallibleIso<S, I>> FallibleIsoImpl<S, I, FI1> {
     ///
     pub fn compose_with_setter<A, S2: Setter<I, A>>(
         self,
-        other: SetterImpl<I, A, S2>,
-    ) -> SetterImpl<S, A, impl Setter<S, A>> {
-        composed_setter(self.0, other.0)
+        other: S2,
+    ) -> SetterImpl<S, A, ComposedSetter<FI1, S2, S, I, A>> {
+        composed_setter(self.0, other)
+    }
+
+    /// Composes this `FallibleIsoImpl<S,I>` with a `FallibleSetter<I,A>`, resulting in a new `FallibleSetter<S, A>`
+    /// that focuses through both optics sequentially.
+    ///
+    /// The resulting `FallibleSetterImpl` will set the value by first applying `self` and then
+    /// `other`. Unlike the composition with a `Setter`, the write is not silently dropped when
+    /// `self` fails to focus: the composition fails if either `self` fails to focus, or `other`
+    /// rejects the value.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `E`: The common error type for the composed optic.
+    /// - `A`: The target type of the composed optic.
+    /// - `FS2`: The type of the fallible setter to compose with.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: The fallible setter to compose with.
+    ///
+    /// # Returns
+    ///
+    /// A new `FallibleSetterImpl` that represents the composition of `self` and `other`.
+    ///
+    pub fn compose_with_fallible_setter<E, A, FS2: FallibleSetter<I, A>>(
+        self,
+        other: FS2,
+    ) -> FallibleSetterImpl<S, A, ComposedFallibleSetter<FI1, FS2, E, S, I, A>>
+    where
+        FI1::GetterError: Into<E>,
+        FS2::SetterError: Into<E>,
+    {
+        composed_fallible_setter(self.0, other, Into::into, Into::into)
+    }
+
+    /// Composes this `FallibleIsoImpl<S,I>` with a `FallibleSetter<I,A>`, resulting in a new `FallibleSetter<S, A>`
+    /// that focuses through both optics sequentially, with explicit error mappers.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `E`: The common error type for the composed optic.
+    /// - `A`: The target type of the composed optic.
+    /// - `FS2`: The type of the fallible setter to compose with.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: The fallible setter to compose with.
+    /// - `error_mapper_1`: A function to map `FI1::GetterError` into `E`.
+    /// - `error_mapper_2`: A function to map `FS2::SetterError` into `E`.
+    ///
+    /// # Returns
+    ///
+    /// A new `FallibleSetterImpl` that represents the composition of `self` and `other`.
+    ///
+    #[allow(clippy::type_complexity)]
+    pub fn compose_with_fallible_setter_with_mappers<E, A, FS2: FallibleSetter<I, A>, F1, F2>(
+        self,
+        other: FS2,
+        error_mapper_1: F1,
+        error_mapper_2: F2,
+    ) -> FallibleSetterImpl<S, A, ComposedFallibleSetter<FI1, FS2, E, S, I, A, F1, F2>>
+    where
+        F1: Fn(FI1::GetterError) -> E,
+        F2: Fn(FS2::SetterError) -> E,
+    {
+        ComposedFallibleSetter::with_mappers(self.0, other, error_mapper_1, error_mapper_2).into()
     }
 
     /// Composes this `FallibleIsoImpl<S,I>` with another `Prism<I,A>`, resulting in a new `PrismImpl<S, A>`
@@ -224,12 +504,12 @@ impl<S, I, FI1: FallibleIso<S, I>> FallibleIsoImpl<S, I, FI1> {
     /// [`compose_with_prism_with_mappers`](Self::compose_with_prism_with_mappers).
     pub fn compose_with_prism<E, A, P2: Prism<I, A>>(
         self,
-        other: PrismImpl<I, A, P2>,
-    ) -> PrismImpl<S, A, impl Prism<S, A, GetterError = E>>
+        other: P2,
+    ) -> PrismImpl<S, A, ComposedPrism<FI1, P2, E, S, I, A>>
     where
         E: From<FI1::GetterError> + From<P2::GetterError>,
     {
-        composed_prism(self.0, other.0, Into::into, Into::into)
+        composed_prism(self.0, other, Into::into, Into::into)
     }
 
     /// Composes this `FallibleIsoImpl<S,I>` with another `PrismImpl<I,A>`, resulting in a new `PrismImpl<S, A>`
@@ -259,13 +539,42 @@ impl<S, I, FI1: FallibleIso<S, I>> FallibleIsoImpl<S, I, FI1> {
     /// This method is similar to [`compose_with_prism`](Self::compose_with_prism), but
     /// provides the ability to specify custom functions to map the errors from each
     /// prism into a common error type.
-    pub fn compose_with_prism_with_mappers<E, A, P2: Prism<I, A>>(
+    #[allow(clippy::type_complexity)]
+    pub fn compose_with_prism_with_mappers<E, A, P2: Prism<I, A>, F1, F2>(
         self,
-        other: PrismImpl<I, A, P2>,
-        error_mapper_1: fn(FI1::GetterError) -> E,
-        error_mapper_2: fn(P2::GetterError) -> E,
-    ) -> PrismImpl<S, A, impl Prism<S, A, GetterError = E>> {
-        composed_prism(self.0, other.0, error_mapper_1, error_mapper_2)
+        other: P2,
+        error_mapper_1: F1,
+        error_mapper_2: F2,
+    ) -> PrismImpl<S, A, ComposedPrism<FI1, P2, E, S, I, A, F1, F2>>
+    where
+        F1: Fn(FI1::GetterError) -> E,
+        F2: Fn(P2::GetterError) -> E,
+    {
+        ComposedPrism::with_mappers(self.0, other, error_mapper_1, error_mapper_2).into()
+    }
+
+    /// Composes this `FallibleIsoImpl<S,I>` with a `PrismImpl<I,A>`, resulting in a new `PrismImpl<S, A>`
+    /// whose error identifies which of the two optics failed.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `A`: The target type of the composed optic.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: The prism to compose with.
+    ///
+    /// # Returns
+    ///
+    /// A new `PrismImpl` failing with a [`Either::Left`] if `self` fails, or a
+    /// [`Either::Right`] if `other` fails.
+    #[allow(clippy::type_complexity)]
+    pub fn compose_with_prism_tracking<A, P2: Prism<I, A>>(
+        self,
+        other: P2,
+    ) -> PrismImpl<S, A, ComposedPrism<FI1, P2, Either<FI1::GetterError, P2::GetterError>, S, I, A>>
+    {
+        composed_prism(self.0, other, Either::Left, Either::Right)
     }
 
     /// Composes this `FallibleIsoImpl<S,I>` with a `Lens<I,A>`, resulting in a new `Prism<S, A>`
@@ -286,11 +595,13 @@ impl<S, I, FI1: FallibleIso<S, I>> FallibleIsoImpl<S, I, FI1> {
     /// # Returns
     ///
     /// A new `PrismImpl` that represents the composition of `self` and `other`
+    #[cfg(feature = "lens")]
+    #[allow(clippy::type_complexity)]
     pub fn compose_with_lens<A, L2: Lens<I, A>>(
         self,
-        other: LensImpl<I, A, L2>,
-    ) -> PrismImpl<S, A, impl Prism<S, A, GetterError = FI1::GetterError>> {
-        composed_prism(self.0, other.0, identity, infallible)
+        other: L2,
+    ) -> PrismImpl<S, A, ComposedPrism<FI1, L2, FI1::GetterError, S, I, A>> {
+        composed_prism(self.0, other, identity, infallible)
     }
 
     /// Composes this `FallibleIsoImpl<S,I>` with a `FallibleIsoImpl<I,A>`, resulting in a new `FallibleIsoImpl<S, A>`
@@ -319,17 +630,18 @@ impl<S, I, FI1: FallibleIso<S, I>> FallibleIsoImpl<S, I, FI1> {
     /// This method uses `Into::into` to convert the errors from both prisms into the
     /// common error type `E`. If you need custom error mapping, consider using
     /// [`compose_with_fallible_iso_with_mappers`](Self::compose_with_fallible_iso_with_mappers).
+    #[allow(clippy::type_complexity)]
     pub fn compose_with_fallible_iso<GE, RE, A, FI2: FallibleIso<I, A>>(
         self,
-        other: FallibleIsoImpl<I, A, FI2>,
-    ) -> FallibleIsoImpl<S, A, impl FallibleIso<S, A, GetterError = GE, ReverseError = RE>>
+        other: FI2,
+    ) -> FallibleIsoImpl<S, A, ComposedFallibleIso<S, I, A, GE, RE, FI1, FI2>>
     where
         GE: From<FI1::GetterError> + From<FI2::GetterError>,
         RE: From<FI1::ReverseError> + From<FI2::ReverseError>,
     {
         composed_fallible_iso(
             self.0,
-            other.0,
+            other,
             Into::into,
             Into::into,
             Into::into,
@@ -367,22 +679,81 @@ impl<S, I, FI1: FallibleIso<S, I>> FallibleIsoImpl<S, I, FI1> {
     /// This method is similar to [`compose_with_fallible_iso`](Self::compose_with_fallible_iso), but
     /// provides the ability to specify custom functions to map the errors from each
     /// prism into a common error type.
-    pub fn compose_with_fallible_iso_with_mappers<GE, RE, A, FI2: FallibleIso<I, A>>(
+    #[allow(clippy::type_complexity)]
+    pub fn compose_with_fallible_iso_with_mappers<
+        GE,
+        RE,
+        A,
+        FI2: FallibleIso<I, A>,
+        GF1,
+        GF2,
+        RF1,
+        RF2,
+    >(
         self,
-        other: FallibleIsoImpl<I, A, FI2>,
-        getter_error_mapper_1: fn(FI1::GetterError) -> GE,
-        getter_error_mapper_2: fn(FI2::GetterError) -> GE,
-        reverse_error_mapper_1: fn(FI1::ReverseError) -> RE,
-        reverse_error_mapper_2: fn(FI2::ReverseError) -> RE,
-    ) -> FallibleIsoImpl<S, A, impl FallibleIso<S, A, GetterError = GE, ReverseError = RE>> {
-        composed_fallible_iso(
+        other: FI2,
+        getter_error_mapper_1: GF1,
+        getter_error_mapper_2: GF2,
+        reverse_error_mapper_1: RF1,
+        reverse_error_mapper_2: RF2,
+    ) -> FallibleIsoImpl<S, A, ComposedFallibleIso<S, I, A, GE, RE, FI1, FI2, GF1, GF2, RF1, RF2>>
+    where
+        GF1: Fn(FI1::GetterError) -> GE,
+        GF2: Fn(FI2::GetterError) -> GE,
+        RF1: Fn(FI1::ReverseError) -> RE,
+        RF2: Fn(FI2::ReverseError) -> RE,
+    {
+        ComposedFallibleIso::with_mappers(
             self.0,
-            other.0,
+            other,
             getter_error_mapper_1,
             getter_error_mapper_2,
             reverse_error_mapper_1,
             reverse_error_mapper_2,
         )
+        .into()
+    }
+
+    /// Composes this `FallibleIsoImpl<S,I>` with a `FallibleIsoImpl<I,A>`, resulting in a new `FallibleIsoImpl<S, A>`
+    /// whose error identifies which of the two optics failed.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `A`: The target type of the composed optic.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: The fallible iso to compose with.
+    ///
+    /// # Returns
+    ///
+    /// A new `FallibleIsoImpl` failing with a [`Either::Left`] if `self` fails, or a
+    /// [`Either::Right`] if `other` fails.
+    #[allow(clippy::type_complexity)]
+    pub fn compose_with_fallible_iso_tracking<A, FI2: FallibleIso<I, A>>(
+        self,
+        other: FI2,
+    ) -> FallibleIsoImpl<
+        S,
+        A,
+        ComposedFallibleIso<
+            S,
+            I,
+            A,
+            Either<FI1::GetterError, FI2::GetterError>,
+            Either<FI1::ReverseError, FI2::ReverseError>,
+            FI1,
+            FI2,
+        >,
+    > {
+        composed_fallible_iso(
+            self.0,
+            other,
+            Either::Left,
+            Either::Right,
+            Either::Left,
+            Either::Right,
+        )
     }
 
     /// Composes this `FallibleIsoImpl<S,I>` with an `Iso<I,A>`, resulting in a new `FallibleIsoImpl<S, A>`
@@ -403,10 +774,280 @@ impl<S, I, FI1: FallibleIso<S, I>> FallibleIsoImpl<S, I, FI1> {
     /// # Returns
     ///
     /// A new `FallibleIsoImpl` that represents the composition of `self` and `other`
+    #[cfg(feature = "iso")]
+    #[allow(clippy::type_complexity)]
     pub fn compose_with_iso<A, ISO2: Iso<I, A>>(
         self,
-        other: IsoImpl<I, A, ISO2>,
-    ) -> FallibleIsoImpl<S, A, impl FallibleIso<S, A>> {
-        composed_fallible_iso(self.0, other.0, identity, infallible, identity, infallible)
+        other: ISO2,
+    ) -> FallibleIsoImpl<
+        S,
+        A,
+        ComposedFallibleIso<S, I, A, FI1::GetterError, FI1::ReverseError, FI1, ISO2>,
+    > {
+        composed_fallible_iso(self.0, other, identity, infallible, identity, infallible)
+    }
+
+    /// Composes this `FallibleIsoImpl<S,I>` with a `Traversal<I,A>`, resulting in a new `TraversalImpl<S, A>`
+    /// that focuses through both optics sequentially.
+    ///
+    /// The resulting `TraversalImpl` will visit every value focused on by `other` in the value
+    /// focused on by `self`. If the fallible iso fails to focus, no
+    /// values are visited.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `A`: The target type of the composed traversal.
+    /// - `T2`: The type of the traversal to compose with.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: The traversal to compose with.
+    ///
+    /// # Returns
+    ///
+    /// A new `TraversalImpl` that represents the composition of `self` and `other`.
+    #[allow(clippy::type_complexity)]
+    pub fn compose_with_traversal<A, T2: Traversal<I, A>>(
+        self,
+        other: T2,
+    ) -> TraversalImpl<S, A, ComposedTraversal<SingleTraversal<S, I, FI1>, T2, S, I, A>> {
+        composed_traversal(single_traversal(self.0), other)
+    }
+}
+
+impl<S, A, FI1: FallibleIso<S, A>> FallibleIsoImpl<S, A, FI1> {
+    /// Adapts both sides of this `FallibleIsoImpl<S,A>` with plain functions, resulting in a new
+    /// `FallibleIsoImpl<T,B>`.
+    ///
+    /// `pre` and `pre_reverse` convert between the new source type `T` and the source type `S`
+    /// of `self`, while `post` and `post_reverse` convert between the focus type `A` of `self` and
+    /// the new focus type `B`. Both pairs need to be inverses of each other.
+    ///
+    /// The errors of `self` are kept as they are.
+    ///
+    /// # Parameters
+    ///
+    /// - `pre`: Converts the new source type into the source type of `self`.
+    /// - `pre_reverse`: Converts the source type of `self` back into the new source type.
+    /// - `post`: Converts the focus of `self` into the new focus type.
+    /// - `post_reverse`: Converts the new focus type back into the focus of `self`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use optics::{HasGetter, HasReverseGet, mapped_fallible_iso};
+    /// use core::num::ParseIntError;
+    ///
+    /// let parse = mapped_fallible_iso(
+    ///     |s: &String| s.parse::<u16>(),
+    ///     |port: &u16| Ok::<_, ParseIntError>(port.to_string()),
+    /// );
+    /// let port = parse.dimap(
+    ///     |s: &&str| s.to_string(),
+    ///     |s: &String| if s.is_empty() { "" } else { "set" },
+    ///     |port: &u16| u32::from(*port),
+    ///     |port: &u32| *port as u16,
+    /// );
+    ///
+    /// assert_eq!(port.try_get(&"8080"), Ok(8080u32));
+    /// assert_eq!(port.try_reverse_get(&8080), Ok("set"));
+    /// ```
+    pub fn dimap<T, B, PRE, PREREV, POST, POSTREV>(
+        self,
+        pre: PRE,
+        pre_reverse: PREREV,
+        post: POST,
+        post_reverse: POSTREV,
+    ) -> FallibleIsoImpl<
+        T,
+        B,
+        impl FallibleIso<T, B, GetterError = FI1::GetterError, ReverseError = FI1::ReverseError>,
+    >
+    where
+        PRE: Fn(&T) -> S,
+        PREREV: Fn(&S) -> T,
+        POST: Fn(&A) -> B,
+        POSTREV: Fn(&B) -> A,
+    {
+        let pre = mapped_fallible_iso(
+            move |t: &T| Ok::<_, Infallible>(pre(t)),
+            move |s: &S| Ok::<_, Infallible>(pre_reverse(s)),
+        );
+        let post = mapped_fallible_iso(
+            move |a: &A| Ok::<_, Infallible>(post(a)),
+            move |b: &B| Ok::<_, Infallible>(post_reverse(b)),
+        );
+        composed_fallible_iso(
+            composed_fallible_iso(pre.0, self.0, infallible, identity, infallible, identity).0,
+            post.0,
+            identity,
+            infallible,
+            identity,
+            infallible,
+        )
+    }
+
+    /// Inverts this `FallibleIsoImpl<S,A>`, resulting in a new `FallibleIsoImpl<A,S>` that
+    /// converts in the opposite direction.
+    ///
+    /// The `try_get` of the resulting iso is the `try_reverse_get` of `self`, and vice versa,
+    /// so the getter and reverse errors swap places.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use optics::{HasGetter, HasReverseGet, mapped_fallible_iso};
+    /// use core::num::ParseIntError;
+    ///
+    /// let parse = mapped_fallible_iso(
+    ///     |s: &String| s.parse::<u16>(),
+    ///     |port: &u16| Ok::<_, ParseIntError>(port.to_string()),
+    /// );
+    /// let format = parse.invert();
+    ///
+    /// assert_eq!(format.try_get(&8080), Ok("8080".to_string()));
+    /// assert_eq!(format.try_reverse_get(&"443".to_string()), Ok(443));
+    /// assert!(format.try_reverse_get(&"port".to_string()).is_err());
+    /// ```
+    pub fn invert(
+        self,
+    ) -> FallibleIsoImpl<
+        A,
+        S,
+        impl FallibleIso<A, S, GetterError = FI1::ReverseError, ReverseError = FI1::GetterError>,
+    > {
+        invert(self.0)
+    }
+
+    /// Labels the errors of this `FallibleIsoImpl<S,A>` with `label`, resulting in a new `FallibleIsoImpl<S,A>`
+    /// whose getter and reverse errors are both wrapped in a [`ContextError`].
+    ///
+    /// The label is the last segment of the path carried by the error. When this optic is composed
+    /// after other labelled optics, their labels are prepended to the path automatically, so a
+    /// failure deep in a chain of compositions reports where it happened.
+    ///
+    /// # Parameters
+    ///
+    /// - `label`: A human-readable name of the part of the structure this optic focuses on.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use optics::{HasGetter, mapped_fallible_iso};
+    /// use core::num::ParseIntError;
+    ///
+    /// let port = mapped_fallible_iso(
+    ///     |s: &String| s.parse::<u16>(),
+    ///     |port: &u16| Ok::<_, ParseIntError>(port.to_string()),
+    /// )
+    /// .context("port");
+    /// let error = port.try_get(&"http".to_string()).unwrap_err();
+    ///
+    /// assert_eq!(error.path(), ["port"]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[allow(clippy::type_complexity)]
+    pub fn context(
+        self,
+        label: &'static str,
+    ) -> FallibleIsoImpl<
+        S,
+        A,
+        impl FallibleIso<
+            S,
+            A,
+            GetterError = ContextError<FI1::GetterError>,
+            ReverseError = ContextError<FI1::ReverseError>,
+        >,
+    > {
+        context(self.0, label)
+    }
+
+    /// Boxes the errors of this `FallibleIsoImpl<S,A>` into an [`ErasedError`], resulting in a new
+    /// `FallibleIsoImpl<S,A>` that can be composed with optics of any other error type without mapping
+    /// the errors into a common type.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use optics::{HasGetter, HasReverseGet, mapped_fallible_iso};
+    ///
+    /// let port = mapped_fallible_iso(
+    ///     |s: &String| s.parse::<u16>(),
+    ///     |port: &u16| if *port == 0 { Err("port 0 is reserved") } else { Ok(port.to_string()) },
+    /// )
+    /// .erase_err();
+    ///
+    /// assert_eq!(port.try_get(&"http".to_string()).unwrap_err().to_string(), "invalid digit found in string");
+    /// assert_eq!(port.try_reverse_get(&0).unwrap_err().to_string(), "port 0 is reserved");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn erase_err(
+        self,
+    ) -> FallibleIsoImpl<
+        S,
+        A,
+        impl FallibleIso<S, A, GetterError = ErasedError, ReverseError = ErasedError>,
+    >
+    where
+        FI1::GetterError: Into<ErasedError>,
+        FI1::ReverseError: Into<ErasedError>,
+    {
+        erase_err(self.0)
+    }
+}
+
+#[allow(clippy::wrong_self_convention)]
+impl<S, A, FI: FallibleIso<S, A>> FallibleIsoImpl<S, A, FI> {
+    /// Downgrades this `FallibleIsoImpl<S,A>` to a read-only `PartialGetterImpl<S,A>`, exposing only the
+    /// capabilities of a [`PartialGetter`].
+    ///
+    /// The underlying optic is wrapped so that only its read operations can be reached, not even
+    /// through the `.0` field of the result, so APIs can accept exactly the access level they need.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use optics::{HasGetter, mapped_fallible_iso};
+    /// use core::num::ParseIntError;
+    ///
+    /// let port = mapped_fallible_iso(
+    ///     |s: &String| s.parse::<u16>(),
+    ///     |port: &u16| Ok::<_, ParseIntError>(port.to_string()),
+    /// )
+    /// .as_partial_getter();
+    ///
+    /// assert_eq!(port.try_get(&"8080".to_string()), Ok(8080));
+    /// ```
+    #[must_use]
+    pub fn as_partial_getter(self) -> PartialGetterImpl<S, A, ReadOnly<FI>> {
+        ReadOnly::new(self.0).into()
+    }
+
+    /// Downgrades this `FallibleIsoImpl<S,A>` to a write-only `SetterImpl<S,A>`, exposing only the
+    /// capabilities of a [`Setter`].
+    ///
+    /// The underlying optic is wrapped so that only its write operations can be reached, not even
+    /// through the `.0` field of the result, so APIs can accept exactly the access level they need.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use optics::{HasSetter, mapped_fallible_iso};
+    /// use core::num::ParseIntError;
+    ///
+    /// let port = mapped_fallible_iso(
+    ///     |s: &String| s.parse::<u16>(),
+    ///     |port: &u16| Ok::<_, ParseIntError>(port.to_string()),
+    /// )
+    /// .as_setter();
+    /// let mut configured = String::new();
+    ///
+    /// port.set(&mut configured, 443);
+    /// assert_eq!(configured, "443");
+    /// ```
+    #[must_use]
+    pub fn as_setter(self) -> SetterImpl<S, A, WriteOnly<FI>> {
+        WriteOnly::new(self.0).into()
     }
 }
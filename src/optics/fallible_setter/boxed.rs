@@ -0,0 +1,28 @@
+use crate::{FallibleSetterImpl, HasFallibleSetter};
+use alloc::boxed::Box;
+
+/// An object safe version of [`FallibleSetter`](crate::FallibleSetter), implemented by every
+/// fallible setter.
+///
+/// It can be used as a trait object, such as in a [`BoxedFallibleSetter`], to store fallible
+/// setters of different types in the same collection.
+pub trait DynFallibleSetter<S, A, E>: HasFallibleSetter<S, A, SetterError = E> {}
+
+impl<S, A, E, O: HasFallibleSetter<S, A, SetterError = E>> DynFallibleSetter<S, A, E> for O {}
+
+/// A boxed fallible setter of any type, focusing on a value of type `A` in a source of type `S`.
+///
+/// Unlike the opaque fallible setters returned by the constructors of this crate, boxed fallible
+/// setters of different types have the same type, so they can be stored in collections or struct
+/// fields, and composed like any other fallible setter. They are created with
+/// [`FallibleSetterImpl::boxed`].
+pub type BoxedFallibleSetter<S, A, E> =
+    FallibleSetterImpl<S, A, Box<dyn DynFallibleSetter<S, A, E> + Send + Sync>>;
+
+impl<S, A, E> HasFallibleSetter<S, A> for Box<dyn DynFallibleSetter<S, A, E> + Send + Sync> {
+    type SetterError = E;
+
+    fn try_set(&self, source: &mut S, value: A) -> Result<(), Self::SetterError> {
+        (**self).try_set(source, value)
+    }
+}
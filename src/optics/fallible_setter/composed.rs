@@ -0,0 +1,231 @@
+use crate::HasPath;
+use crate::optics::fallible_setter::FallibleSetter;
+use crate::optics::fallible_setter::wrapper::FallibleSetterImpl;
+use crate::{HasFallibleSetter, HasGetter, HasSetter};
+use core::fmt::{Debug, Display, Formatter};
+use core::marker::PhantomData;
+
+mod private {
+    use crate::{FallibleSetter, HasFallibleSetter, HasGetter, HasSetter};
+    use core::marker::PhantomData;
+
+    pub struct ComposedFallibleSetter<
+        O1,
+        FS2,
+        E,
+        S,
+        I,
+        A,
+        F1 = fn(<O1 as HasGetter<S, I>>::GetterError) -> E,
+        F2 = fn(<FS2 as HasFallibleSetter<I, A>>::SetterError) -> E,
+    >
+    where
+        O1: HasGetter<S, I> + HasSetter<S, I>,
+        FS2: FallibleSetter<I, A>,
+    {
+        pub(super) optic1: O1,
+        pub(super) optic2: FS2,
+        pub(super) error_fn_1: F1,
+        pub(super) error_fn_2: F2,
+        pub(super) _phantom: PhantomData<(S, I, A, E)>,
+    }
+}
+
+/// A `ComposedFallibleSetter` represents the composition of an optic able to read and write its
+/// focus with a `FallibleSetter`, resulting in a `FallibleSetter` that focuses from a source type
+/// `S` to a target type `A` through an intermediate type `I`.
+///
+/// Unlike a `ComposedSetter`, it does not silently drop the write when the first optic fails to
+/// focus: both the getter error of the first optic and the setter error of the second one are
+/// mapped into the unified error type `E` and returned.
+///
+/// # Type Parameters
+/// - `O1`: The first optic, focusing from `S` to `I`.
+/// - `FS2`: The second optic, fallibly setting `A` in `I`.
+/// - `E`: The unified error type of the composed setter.
+/// - `S`: The source type.
+/// - `I`: The intermediate type.
+/// - `A`: The target type.
+/// - `F1`: The function mapping `O1`'s getter error, a function pointer by default.
+/// - `F2`: The function mapping `FS2`'s setter error, a function pointer by default.
+///
+/// # Fields
+/// - `optic1`: The first optic instance.
+/// - `optic2`: The second optic instance.
+/// - `error_fn_1`: A function to map `O1`'s getter error to the unified error type `E`.
+/// - `error_fn_2`: A function to map `FS2`'s setter error to the unified error type `E`.
+pub type ComposedFallibleSetter<
+    O1,
+    FS2,
+    E,
+    S,
+    I,
+    A,
+    F1 = fn(<O1 as HasGetter<S, I>>::GetterError) -> E,
+    F2 = fn(<FS2 as HasFallibleSetter<I, A>>::SetterError) -> E,
+> = private::ComposedFallibleSetter<O1, FS2, E, S, I, A, F1, F2>;
+
+impl<O1, FS2, E, S, I, A, F1, F2> Clone for ComposedFallibleSetter<O1, FS2, E, S, I, A, F1, F2>
+where
+    O1: HasGetter<S, I> + HasSetter<S, I> + Clone,
+    FS2: FallibleSetter<I, A> + Clone,
+    F1: Clone,
+    F2: Clone,
+{
+    fn clone(&self) -> Self {
+        ComposedFallibleSetter {
+            optic1: self.optic1.clone(),
+            optic2: self.optic2.clone(),
+            error_fn_1: self.error_fn_1.clone(),
+            error_fn_2: self.error_fn_2.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<O1, FS2, E, S, I, A, F1, F2> Copy for ComposedFallibleSetter<O1, FS2, E, S, I, A, F1, F2>
+where
+    O1: HasGetter<S, I> + HasSetter<S, I> + Copy,
+    FS2: FallibleSetter<I, A> + Copy,
+    F1: Copy,
+    F2: Copy,
+{
+}
+
+impl<O1, FS2, E, S, I, A, F1, F2> Debug for ComposedFallibleSetter<O1, FS2, E, S, I, A, F1, F2>
+where
+    O1: HasGetter<S, I> + HasSetter<S, I> + Debug,
+    FS2: FallibleSetter<I, A> + Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ComposedFallibleSetter")
+            .field("optic1", &self.optic1)
+            .field("optic2", &self.optic2)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<O1, FS2, E, S, I, A, F1, F2> Display for ComposedFallibleSetter<O1, FS2, E, S, I, A, F1, F2>
+where
+    O1: HasGetter<S, I> + HasSetter<S, I> + Display,
+    FS2: FallibleSetter<I, A> + Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}.{}", self.optic1, self.optic2)
+    }
+}
+
+impl<O1, FS2, E, S, I, A, F1, F2> HasPath for ComposedFallibleSetter<O1, FS2, E, S, I, A, F1, F2>
+where
+    O1: HasGetter<S, I> + HasSetter<S, I> + HasPath,
+    FS2: FallibleSetter<I, A> + HasPath,
+{
+    fn for_each_segment<'a>(&'a self, f: &mut dyn FnMut(&'a str)) {
+        self.optic1.for_each_segment(f);
+        self.optic2.for_each_segment(f);
+    }
+}
+
+impl<O1, FS2, E, S, I, A> ComposedFallibleSetter<O1, FS2, E, S, I, A>
+where
+    O1: HasGetter<S, I> + HasSetter<S, I>,
+    FS2: FallibleSetter<I, A>,
+{
+    pub(crate) fn new(
+        optic1: O1,
+        optic2: FS2,
+        error_fn_1: fn(O1::GetterError) -> E,
+        error_fn_2: fn(FS2::SetterError) -> E,
+    ) -> Self {
+        ComposedFallibleSetter {
+            optic1,
+            optic2,
+            error_fn_1,
+            error_fn_2,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<O1, FS2, E, S, I, A, F1, F2> ComposedFallibleSetter<O1, FS2, E, S, I, A, F1, F2>
+where
+    O1: HasGetter<S, I> + HasSetter<S, I>,
+    FS2: FallibleSetter<I, A>,
+    F1: Fn(O1::GetterError) -> E,
+    F2: Fn(FS2::SetterError) -> E,
+{
+    #[cfg(any(feature = "prism", feature = "setter"))]
+    pub(crate) fn with_mappers(optic1: O1, optic2: FS2, error_fn_1: F1, error_fn_2: F2) -> Self {
+        ComposedFallibleSetter {
+            optic1,
+            optic2,
+            error_fn_1,
+            error_fn_2,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<O1, FS2, E, S, I, A, F1, F2> HasFallibleSetter<S, A>
+    for ComposedFallibleSetter<O1, FS2, E, S, I, A, F1, F2>
+where
+    O1: HasGetter<S, I> + HasSetter<S, I>,
+    FS2: FallibleSetter<I, A>,
+    F1: Fn(O1::GetterError) -> E,
+    F2: Fn(FS2::SetterError) -> E,
+{
+    type SetterError = E;
+
+    #[inline]
+    fn try_set(&self, source: &mut S, value: A) -> Result<(), Self::SetterError> {
+        let mut i = self.optic1.try_get(source).map_err(&self.error_fn_1)?;
+        self.optic2
+            .try_set(&mut i, value)
+            .map_err(&self.error_fn_2)?;
+        self.optic1.set(source, i);
+        Ok(())
+    }
+}
+
+/// Creates a `FallibleSetter<S,A>` combined from two optics <S, I>, <I, A> applied one after another.
+///
+/// This struct is automatically created by composing two existing optics, and is **not** intended
+/// to be directly constructed outside the crate. Instead, it is generated through composition of
+/// two optics via the corresponding `composable_with_XXX` methods.
+///
+/// The first optic has to be able to read the intermediate value so that it can change its focused
+/// value, so it has to be at least a `Prism`. If it fails to focus, or the second optic rejects the
+/// value, the source is left unchanged and the error is returned.
+///
+/// # Type Parameters
+/// - `S`: The source type of the first optic
+/// - `A`: The target type of the second optic
+/// - `I`: The intermediate type: the target type of the first optic and the source type of the second optic
+/// - `E`: The error type for the resulting optic
+///
+/// # Arguments
+/// - `o1`: The first optic of type `Prism<S, I>`
+/// - `fs2`: The second optic of type `FallibleSetter<I, A>`
+/// - `error_fn_1`: A function that maps the getter error of the first optic to a resulting error type `E`
+/// - `error_fn_2`: A function that maps the setter error of the second optic to a resulting error type `E`
+///
+/// This struct **should not** be manually constructed by users. Instead, it is created via
+/// composition of two optics using the appropriate `compose_with_XXX` methods on each optic impl.
+/// The `ComposedFallibleSetter` structure is provided internally by the crate after you compose valid optics.
+///
+/// # See Also
+///
+/// - [`FallibleSetter`] — the optic type that `ComposedFallibleSetter` is based on
+#[must_use]
+pub fn new<S, A, I, E, O1, FS2>(
+    o1: O1,
+    fs2: FS2,
+    error_fn_1: fn(O1::GetterError) -> E,
+    error_fn_2: fn(FS2::SetterError) -> E,
+) -> FallibleSetterImpl<S, A, ComposedFallibleSetter<O1, FS2, E, S, I, A>>
+where
+    O1: HasGetter<S, I> + HasSetter<S, I>,
+    FS2: FallibleSetter<I, A>,
+{
+    ComposedFallibleSetter::new(o1, fs2, error_fn_1, error_fn_2).into()
+}
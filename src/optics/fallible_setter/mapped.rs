@@ -0,0 +1,116 @@
+use crate::FallibleSetterImpl;
+use crate::HasFallibleSetter;
+use core::fmt::{Debug, Formatter};
+use core::marker::PhantomData;
+
+mod private {
+    use core::marker::PhantomData;
+
+    pub struct MappedFallibleSetter<S, A, E, SET = fn(&mut S, A) -> Result<(), E>>
+    where
+        SET: Fn(&mut S, A) -> Result<(), E>,
+    {
+        pub(super) set_fn: SET,
+        pub(super) phantom: PhantomData<(S, A, E)>,
+    }
+}
+
+pub type MappedFallibleSetter<S, A, E, SET = fn(&mut S, A) -> Result<(), E>> =
+    private::MappedFallibleSetter<S, A, E, SET>;
+
+impl<S, A, E, SET> Clone for MappedFallibleSetter<S, A, E, SET>
+where
+    SET: Fn(&mut S, A) -> Result<(), E> + Clone,
+{
+    fn clone(&self) -> Self {
+        MappedFallibleSetter {
+            set_fn: self.set_fn.clone(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<S, A, E, SET> Copy for MappedFallibleSetter<S, A, E, SET> where
+    SET: Fn(&mut S, A) -> Result<(), E> + Copy
+{
+}
+
+impl<S, A, E, SET> Debug for MappedFallibleSetter<S, A, E, SET>
+where
+    SET: Fn(&mut S, A) -> Result<(), E>,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("MappedFallibleSetter")
+            .finish_non_exhaustive()
+    }
+}
+
+impl<S, A, E, SET> MappedFallibleSetter<S, A, E, SET>
+where
+    SET: Fn(&mut S, A) -> Result<(), E>,
+{
+    const fn new(set_fn: SET) -> Self {
+        MappedFallibleSetter {
+            set_fn,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<S, A, E, SET> HasFallibleSetter<S, A> for MappedFallibleSetter<S, A, E, SET>
+where
+    SET: Fn(&mut S, A) -> Result<(), E>,
+{
+    type SetterError = E;
+
+    fn try_set(&self, source: &mut S, value: A) -> Result<(), Self::SetterError> {
+        (self.set_fn)(source, value)
+    }
+}
+
+/// Creates a new `FallibleSetter` with the provided setter function.
+///
+/// # Type Parameters
+/// - `S`: The source type of the optic
+/// - `A`: The target type of the optic
+/// - `E`: The error type returned when a value is rejected
+///
+/// # Arguments
+///
+/// - `set_fn` — A function that sets the focus value `A` in the source `S`, or rejects it with an
+///   error `E`. It should leave the source unchanged when rejecting the value.
+///
+/// # Returns
+///
+/// A new `FallibleSetterImpl` instance that can be used as a `FallibleSetter<S, A>`.
+///
+/// # Examples
+///
+/// ```
+/// use optics::{mapped_fallible_setter, HasFallibleSetter};
+///
+/// struct Server { port: u16 };
+///
+/// let mut s = Server { port: 8080 };
+/// let port_setter = mapped_fallible_setter(|s: &mut Server, port: u16| {
+///     if port < 1024 {
+///         return Err("port below 1024");
+///     }
+///     s.port = port;
+///     Ok(())
+/// });
+///
+/// assert_eq!(port_setter.try_set(&mut s, 80), Err("port below 1024"));
+/// assert_eq!(s.port, 8080);
+/// assert_eq!(port_setter.try_set(&mut s, 8081), Ok(()));
+/// assert_eq!(s.port, 8081);
+/// ```
+#[must_use]
+pub const fn new<S, A, E, SET>(
+    set_fn: SET,
+) -> FallibleSetterImpl<S, A, MappedFallibleSetter<S, A, E, SET>>
+where
+    SET: Fn(&mut S, A) -> Result<(), E>,
+{
+    FallibleSetterImpl::new(MappedFallibleSetter::new(set_fn))
+}
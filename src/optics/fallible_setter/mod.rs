@@ -0,0 +1,94 @@
+#[cfg(feature = "alloc")]
+mod boxed;
+mod composed;
+mod mapped;
+mod nested;
+#[cfg(any(feature = "iso", feature = "fallible-iso"))]
+mod reversed;
+#[cfg(feature = "alloc")]
+mod shared;
+mod wrapper;
+
+use crate::HasFallibleSetter;
+
+#[cfg(feature = "alloc")]
+pub use boxed::{BoxedFallibleSetter, DynFallibleSetter};
+#[cfg(any(feature = "getter", feature = "prism", feature = "setter"))]
+pub(crate) use composed::ComposedFallibleSetter;
+pub use composed::new as composed_fallible_setter;
+pub use mapped::new as mapped_fallible_setter;
+pub(crate) use nested::NestedFallibleSetter;
+#[cfg(any(feature = "iso", feature = "fallible-iso"))]
+pub(crate) use reversed::ReversedFallibleSetter;
+#[cfg(feature = "alloc")]
+pub use shared::SharedFallibleSetter;
+pub use wrapper::FallibleSetterImpl;
+
+/// A [`FallibleSetterImpl`] built by [`mapped_fallible_setter`] from plain functions or non-capturing closures.
+///
+/// As [`mapped_fallible_setter`] is a `const fn`, this type can be used to declare the optic as a `static`.
+pub type FnFallibleSetter<S, A, E> =
+    FallibleSetterImpl<S, A, mapped::MappedFallibleSetter<S, A, E>>;
+
+/// A `FallibleSetter` is an optic that can change its focused value, providing only a write
+/// operation that can reject the value being written.
+///
+/// It provides:
+/// - `try_set` to set the focused value inside a larger type, or fail with a domain error
+///
+/// This is useful when the values a part of a larger type can hold are more restricted than its
+/// type, such as a port that must not be a privileged one, and callers writing to it need to know
+/// if their write was rejected.
+///
+/// Type Arguments
+///   - `S`: The data type the optic operates on
+///   - `A`: The data type the optic focuses on
+///
+/// # Note
+///
+/// This is a marker trait that is blanket implemented for all structs that satisfy the requirements.
+///
+/// # See Also
+/// - [`Setter`] — an optic that can change its focused value, without being able to reject it
+/// - [`Prism`] — an optic that focuses on a potentially missing value in a product type (ex. optional struct field) or a sum type vairant
+/// - [`Lens`] — an optic that focuses on an always-present value in a product type (e.g., a required struct field)
+pub trait FallibleSetter<S, A>: HasFallibleSetter<S, A> {}
+
+impl<S, A, FS: HasFallibleSetter<S, A>> FallibleSetter<S, A> for FS {}
+
+/// Creates a `FallibleSetter` that focuses on the entire input, and never rejects a value.
+///
+/// It can be useful as the neutral element when building fallible setters through composition.
+///
+/// # Type Parameters
+///
+/// - `S`: The type of the input and output value.
+/// - `E`: The error type of the setter, which is never returned.
+///
+/// # Returns
+///
+/// A `FallibleSetterImpl` instance that implements `FallibleSetter<S, S>`
+///
+/// # Example
+///
+///```rust
+/// use optics::{identity_fallible_setter, HasFallibleSetter};
+///
+/// let mut s = 142;
+///
+/// identity_fallible_setter::<_, ()>().try_set(&mut s, 42).unwrap();
+///
+/// assert_eq!(s, 42);
+/// ```
+///
+/// # See Also
+///
+/// - [`mapped_fallible_setter`] for constructing custom `FallibleSetter`s from arbitrary mapping functions.
+#[must_use]
+pub fn identity_fallible_setter<S, E>()
+-> FallibleSetterImpl<S, S, impl FallibleSetter<S, S, SetterError = E>> {
+    mapped_fallible_setter(|s, v| {
+        *s = v;
+        Ok(())
+    })
+}
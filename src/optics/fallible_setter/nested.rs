@@ -0,0 +1,182 @@
+use crate::HasPath;
+use crate::optics::fallible_setter::FallibleSetter;
+use crate::{HasFallibleSetter, HasModify};
+use core::fmt::{Debug, Display, Formatter};
+use core::marker::PhantomData;
+
+mod private {
+    use crate::{FallibleSetter, HasFallibleSetter};
+    use core::marker::PhantomData;
+
+    pub struct NestedFallibleSetter<
+        FS1,
+        FS2,
+        E,
+        S,
+        I,
+        A,
+        F1 = fn(<FS1 as HasFallibleSetter<S, I>>::SetterError) -> E,
+        F2 = fn(<FS2 as HasFallibleSetter<I, A>>::SetterError) -> E,
+    >
+    where
+        FS1: FallibleSetter<S, I>,
+        FS2: FallibleSetter<I, A>,
+    {
+        pub(super) optic1: FS1,
+        pub(super) optic2: FS2,
+        pub(super) error_fn_1: F1,
+        pub(super) error_fn_2: F2,
+        pub(super) _phantom: PhantomData<(S, I, A, E)>,
+    }
+}
+
+/// A `NestedFallibleSetter` represents the composition of a fallible setter able to modify its
+/// focus of type `I` in place, such as one wrapping a prism with validation, with a
+/// `FallibleSetter<I, A>`, resulting in a `FallibleSetter` writing values of type `A` into a
+/// source of type `S`.
+///
+/// The focus of the first optic is read, written through the second one and written back through
+/// the first one, so both can reject the value. Their setter errors are mapped into the unified
+/// error type `E`. If the first optic has no focus in the source, the write is dropped.
+///
+/// # Type Parameters
+/// - `FS1`: The first optic, modifying `I` in place inside `S`.
+/// - `FS2`: The second optic, fallibly setting `A` in `I`.
+/// - `E`: The unified error type of the composed setter.
+/// - `S`: The source type.
+/// - `I`: The intermediate type.
+/// - `A`: The target type.
+/// - `F1`: The function mapping `FS1`'s setter error, a function pointer by default.
+/// - `F2`: The function mapping `FS2`'s setter error, a function pointer by default.
+pub type NestedFallibleSetter<
+    FS1,
+    FS2,
+    E,
+    S,
+    I,
+    A,
+    F1 = fn(<FS1 as HasFallibleSetter<S, I>>::SetterError) -> E,
+    F2 = fn(<FS2 as HasFallibleSetter<I, A>>::SetterError) -> E,
+> = private::NestedFallibleSetter<FS1, FS2, E, S, I, A, F1, F2>;
+
+impl<FS1, FS2, E, S, I, A, F1, F2> Clone for NestedFallibleSetter<FS1, FS2, E, S, I, A, F1, F2>
+where
+    FS1: FallibleSetter<S, I> + Clone,
+    FS2: FallibleSetter<I, A> + Clone,
+    F1: Clone,
+    F2: Clone,
+{
+    fn clone(&self) -> Self {
+        NestedFallibleSetter {
+            optic1: self.optic1.clone(),
+            optic2: self.optic2.clone(),
+            error_fn_1: self.error_fn_1.clone(),
+            error_fn_2: self.error_fn_2.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<FS1, FS2, E, S, I, A, F1, F2> Copy for NestedFallibleSetter<FS1, FS2, E, S, I, A, F1, F2>
+where
+    FS1: FallibleSetter<S, I> + Copy,
+    FS2: FallibleSetter<I, A> + Copy,
+    F1: Copy,
+    F2: Copy,
+{
+}
+
+impl<FS1, FS2, E, S, I, A, F1, F2> Debug for NestedFallibleSetter<FS1, FS2, E, S, I, A, F1, F2>
+where
+    FS1: FallibleSetter<S, I> + Debug,
+    FS2: FallibleSetter<I, A> + Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("NestedFallibleSetter")
+            .field("optic1", &self.optic1)
+            .field("optic2", &self.optic2)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<FS1, FS2, E, S, I, A, F1, F2> Display for NestedFallibleSetter<FS1, FS2, E, S, I, A, F1, F2>
+where
+    FS1: FallibleSetter<S, I> + Display,
+    FS2: FallibleSetter<I, A> + Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}.{}", self.optic1, self.optic2)
+    }
+}
+
+impl<FS1, FS2, E, S, I, A, F1, F2> HasPath for NestedFallibleSetter<FS1, FS2, E, S, I, A, F1, F2>
+where
+    FS1: FallibleSetter<S, I> + HasPath,
+    FS2: FallibleSetter<I, A> + HasPath,
+{
+    fn for_each_segment<'a>(&'a self, f: &mut dyn FnMut(&'a str)) {
+        self.optic1.for_each_segment(f);
+        self.optic2.for_each_segment(f);
+    }
+}
+
+impl<FS1, FS2, E, S, I, A> NestedFallibleSetter<FS1, FS2, E, S, I, A>
+where
+    FS1: FallibleSetter<S, I>,
+    FS2: FallibleSetter<I, A>,
+{
+    pub(crate) fn new(
+        optic1: FS1,
+        optic2: FS2,
+        error_fn_1: fn(FS1::SetterError) -> E,
+        error_fn_2: fn(FS2::SetterError) -> E,
+    ) -> Self {
+        NestedFallibleSetter {
+            optic1,
+            optic2,
+            error_fn_1,
+            error_fn_2,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<FS1, FS2, E, S, I, A, F1, F2> NestedFallibleSetter<FS1, FS2, E, S, I, A, F1, F2>
+where
+    FS1: FallibleSetter<S, I>,
+    FS2: FallibleSetter<I, A>,
+    F1: Fn(FS1::SetterError) -> E,
+    F2: Fn(FS2::SetterError) -> E,
+{
+    pub(crate) fn with_mappers(optic1: FS1, optic2: FS2, error_fn_1: F1, error_fn_2: F2) -> Self {
+        NestedFallibleSetter {
+            optic1,
+            optic2,
+            error_fn_1,
+            error_fn_2,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<FS1, FS2, E, S, I, A, F1, F2> HasFallibleSetter<S, A>
+    for NestedFallibleSetter<FS1, FS2, E, S, I, A, F1, F2>
+where
+    FS1: HasModify<S, I> + FallibleSetter<S, I>,
+    FS2: FallibleSetter<I, A>,
+    F1: Fn(FS1::SetterError) -> E,
+    F2: Fn(FS2::SetterError) -> E,
+{
+    type SetterError = E;
+
+    #[inline]
+    fn try_set(&self, source: &mut S, value: A) -> Result<(), Self::SetterError> {
+        let Ok(mut i) = self.optic1.try_get(source) else {
+            return Ok(());
+        };
+        self.optic2
+            .try_set(&mut i, value)
+            .map_err(&self.error_fn_2)?;
+        self.optic1.try_set(source, i).map_err(&self.error_fn_1)
+    }
+}
@@ -0,0 +1,182 @@
+use crate::HasPath;
+use crate::optics::fallible_setter::FallibleSetter;
+use crate::{HasFallibleSetter, HasReverseGet};
+use core::fmt::{Debug, Display, Formatter};
+use core::marker::PhantomData;
+
+mod private {
+    use crate::{FallibleSetter, HasFallibleSetter, HasReverseGet};
+    use core::marker::PhantomData;
+
+    pub struct ReversedFallibleSetter<
+        FS1,
+        R2,
+        E,
+        S,
+        I,
+        A,
+        F1 = fn(<FS1 as HasFallibleSetter<S, I>>::SetterError) -> E,
+        F2 = fn(<R2 as HasReverseGet<I, A>>::ReverseError) -> E,
+    >
+    where
+        FS1: FallibleSetter<S, I>,
+        R2: HasReverseGet<I, A>,
+    {
+        pub(super) optic1: FS1,
+        pub(super) optic2: R2,
+        pub(super) error_fn_1: F1,
+        pub(super) error_fn_2: F2,
+        pub(super) _phantom: PhantomData<(S, I, A, E)>,
+    }
+}
+
+/// A `ReversedFallibleSetter` represents the composition of a `FallibleSetter<S, I>` with an optic
+/// able to convert a value of type `A` back into an `I`, such as an `Iso<I, A>` or a
+/// `FallibleIso<I, A>`, resulting in a fallible setter writing values of type `A` into a source of
+/// type `S`.
+///
+/// A setter can not read its focus, so the value being written is converted into the focus of the
+/// setter with the reverse direction of the second optic, and written as a whole. Both the setter
+/// error of the first optic and the reverse error of the second one are mapped into the unified
+/// error type `E` and returned.
+///
+/// # Type Parameters
+/// - `FS1`: The fallible setter, writing `I` into `S`.
+/// - `R2`: The optic converting `A` back into `I`.
+/// - `E`: The unified error type of the composed setter.
+/// - `S`: The source type.
+/// - `I`: The intermediate type.
+/// - `A`: The target type.
+/// - `F1`: The function mapping `FS1`'s setter error, a function pointer by default.
+/// - `F2`: The function mapping `R2`'s reverse error, a function pointer by default.
+pub type ReversedFallibleSetter<
+    FS1,
+    R2,
+    E,
+    S,
+    I,
+    A,
+    F1 = fn(<FS1 as HasFallibleSetter<S, I>>::SetterError) -> E,
+    F2 = fn(<R2 as HasReverseGet<I, A>>::ReverseError) -> E,
+> = private::ReversedFallibleSetter<FS1, R2, E, S, I, A, F1, F2>;
+
+impl<FS1, R2, E, S, I, A, F1, F2> Clone for ReversedFallibleSetter<FS1, R2, E, S, I, A, F1, F2>
+where
+    FS1: FallibleSetter<S, I> + Clone,
+    R2: HasReverseGet<I, A> + Clone,
+    F1: Clone,
+    F2: Clone,
+{
+    fn clone(&self) -> Self {
+        ReversedFallibleSetter {
+            optic1: self.optic1.clone(),
+            optic2: self.optic2.clone(),
+            error_fn_1: self.error_fn_1.clone(),
+            error_fn_2: self.error_fn_2.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<FS1, R2, E, S, I, A, F1, F2> Copy for ReversedFallibleSetter<FS1, R2, E, S, I, A, F1, F2>
+where
+    FS1: FallibleSetter<S, I> + Copy,
+    R2: HasReverseGet<I, A> + Copy,
+    F1: Copy,
+    F2: Copy,
+{
+}
+
+impl<FS1, R2, E, S, I, A, F1, F2> Debug for ReversedFallibleSetter<FS1, R2, E, S, I, A, F1, F2>
+where
+    FS1: FallibleSetter<S, I> + Debug,
+    R2: HasReverseGet<I, A> + Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ReversedFallibleSetter")
+            .field("optic1", &self.optic1)
+            .field("optic2", &self.optic2)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<FS1, R2, E, S, I, A, F1, F2> Display for ReversedFallibleSetter<FS1, R2, E, S, I, A, F1, F2>
+where
+    FS1: FallibleSetter<S, I> + Display,
+    R2: HasReverseGet<I, A> + Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}.{}", self.optic1, self.optic2)
+    }
+}
+
+impl<FS1, R2, E, S, I, A, F1, F2> HasPath for ReversedFallibleSetter<FS1, R2, E, S, I, A, F1, F2>
+where
+    FS1: FallibleSetter<S, I> + HasPath,
+    R2: HasReverseGet<I, A> + HasPath,
+{
+    fn for_each_segment<'a>(&'a self, f: &mut dyn FnMut(&'a str)) {
+        self.optic1.for_each_segment(f);
+        self.optic2.for_each_segment(f);
+    }
+}
+
+impl<FS1, R2, E, S, I, A> ReversedFallibleSetter<FS1, R2, E, S, I, A>
+where
+    FS1: FallibleSetter<S, I>,
+    R2: HasReverseGet<I, A>,
+{
+    pub(crate) fn new(
+        optic1: FS1,
+        optic2: R2,
+        error_fn_1: fn(FS1::SetterError) -> E,
+        error_fn_2: fn(R2::ReverseError) -> E,
+    ) -> Self {
+        ReversedFallibleSetter {
+            optic1,
+            optic2,
+            error_fn_1,
+            error_fn_2,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<FS1, R2, E, S, I, A, F1, F2> ReversedFallibleSetter<FS1, R2, E, S, I, A, F1, F2>
+where
+    FS1: FallibleSetter<S, I>,
+    R2: HasReverseGet<I, A>,
+    F1: Fn(FS1::SetterError) -> E,
+    F2: Fn(R2::ReverseError) -> E,
+{
+    #[cfg(feature = "fallible-iso")]
+    pub(crate) fn with_mappers(optic1: FS1, optic2: R2, error_fn_1: F1, error_fn_2: F2) -> Self {
+        ReversedFallibleSetter {
+            optic1,
+            optic2,
+            error_fn_1,
+            error_fn_2,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<FS1, R2, E, S, I, A, F1, F2> HasFallibleSetter<S, A>
+    for ReversedFallibleSetter<FS1, R2, E, S, I, A, F1, F2>
+where
+    FS1: FallibleSetter<S, I>,
+    R2: HasReverseGet<I, A>,
+    F1: Fn(FS1::SetterError) -> E,
+    F2: Fn(R2::ReverseError) -> E,
+{
+    type SetterError = E;
+
+    #[inline]
+    fn try_set(&self, source: &mut S, value: A) -> Result<(), Self::SetterError> {
+        let i = self
+            .optic2
+            .try_reverse_get(&value)
+            .map_err(&self.error_fn_2)?;
+        self.optic1.try_set(source, i).map_err(&self.error_fn_1)
+    }
+}
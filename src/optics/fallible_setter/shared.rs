@@ -0,0 +1,19 @@
+use crate::{DynFallibleSetter, FallibleSetterImpl, HasFallibleSetter};
+use alloc::sync::Arc;
+
+/// A shared fallible setter of any type, focusing on a value of type `A` in a source of type `S`.
+///
+/// Like [boxed fallible setters](crate::BoxedFallibleSetter), shared fallible setters of different
+/// types have the same type, but they are reference counted, so they can be cloned cheaply, stored
+/// in registries and sent to other threads. They are created with
+/// [`FallibleSetterImpl::shared`](crate::FallibleSetterImpl::shared).
+pub type SharedFallibleSetter<S, A, E> =
+    FallibleSetterImpl<S, A, Arc<dyn DynFallibleSetter<S, A, E> + Send + Sync>>;
+
+impl<S, A, E> HasFallibleSetter<S, A> for Arc<dyn DynFallibleSetter<S, A, E> + Send + Sync> {
+    type SetterError = E;
+
+    fn try_set(&self, source: &mut S, value: A) -> Result<(), Self::SetterError> {
+        (**self).try_set(source, value)
+    }
+}
@@ -0,0 +1,583 @@
+use crate::HasPath;
+#[cfg(feature = "lens")]
+use crate::Lens;
+#[cfg(feature = "prism")]
+use crate::Prism;
+#[cfg(feature = "setter")]
+use crate::Setter;
+use crate::named::Named;
+use crate::optics::fallible_setter::NestedFallibleSetter;
+#[cfg(any(feature = "iso", feature = "fallible-iso"))]
+use crate::optics::fallible_setter::ReversedFallibleSetter;
+use crate::optics::setter::NestedSetter;
+use crate::optics::traversal::{OverAll, over_all};
+#[cfg(feature = "tracing")]
+use crate::traced::Traced;
+#[cfg(feature = "fallible-iso")]
+use crate::{Either, FallibleIso};
+use crate::{FallibleSetter, HasFallibleSetter, HasModify, Traversal, mapped_fallible_setter};
+#[cfg(feature = "iso")]
+use crate::{Iso, infallible};
+#[cfg(feature = "iso")]
+use core::convert::identity;
+use core::fmt::{Debug, Display, Formatter};
+use core::marker::PhantomData;
+#[cfg(feature = "alloc")]
+use {
+    crate::optics::fallible_setter::{
+        BoxedFallibleSetter, DynFallibleSetter, SharedFallibleSetter,
+    },
+    alloc::boxed::Box,
+    alloc::string::{String, ToString},
+    alloc::sync::Arc,
+    alloc::vec::Vec,
+};
+
+/// A wrapper of the [`FallibleSetter`] optic implementations, encapsulating a fallible setter function.
+///
+/// `FallibleSetterImpl` provides a way to define fallible setters - optics that are able to write
+/// to a focused value of type `A` in a source of type `S`, but may reject the value being written.
+///
+/// # Note
+///
+/// This struct is not intended to be created by users directly, but it implements a `From<FallibleSetter<S,A>>` so
+/// that implementors of new optic types can wrap their concrete implementation of a `FallibleSetter` optic.
+///
+/// # Type Parameters
+///
+/// - `S`: The source type into which the value is to be written.
+/// - `A`: The target type of the value to be written.
+///
+/// # See Also
+///
+/// - [`FallibleSetter`] trait for defining custom fallible setters.
+/// - [`mapped_fallible_setter`] function for creating `FallibleSetterImpl` instances from mapping functions.
+pub struct FallibleSetterImpl<S, A, FS: FallibleSetter<S, A>>(pub FS, PhantomData<(S, A)>);
+
+impl<S, A, FS: FallibleSetter<S, A>> FallibleSetterImpl<S, A, FS> {
+    pub(crate) const fn new(l: FS) -> Self {
+        //TODO: Verify not to nest an Impl inside an Impl - currently seems to be impossible at compile time.
+        FallibleSetterImpl(l, PhantomData)
+    }
+}
+
+impl<S, A, FS: FallibleSetter<S, A>> From<FS> for FallibleSetterImpl<S, A, FS> {
+    fn from(value: FS) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<S, A, FS: FallibleSetter<S, A> + Clone> Clone for FallibleSetterImpl<S, A, FS> {
+    fn clone(&self) -> Self {
+        Self::new(self.0.clone())
+    }
+}
+
+impl<S, A, FS: FallibleSetter<S, A> + Copy> Copy for FallibleSetterImpl<S, A, FS> {}
+
+impl<S, A, FS: FallibleSetter<S, A> + Debug> Debug for FallibleSetterImpl<S, A, FS> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("FallibleSetterImpl").field(&self.0).finish()
+    }
+}
+
+impl<S, A, FS: FallibleSetter<S, A> + Display> Display for FallibleSetterImpl<S, A, FS> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl<S, A, FS: FallibleSetter<S, A> + HasPath> HasPath for FallibleSetterImpl<S, A, FS> {
+    fn for_each_segment<'a>(&'a self, f: &mut dyn FnMut(&'a str)) {
+        self.0.for_each_segment(f);
+    }
+}
+
+impl<S, A, FS: FallibleSetter<S, A>> FallibleSetterImpl<S, A, FS> {
+    /// Labels this optic with a static `name`, used by its [`Display`] implementation.
+    ///
+    /// Composing named optics results in an optic displaying the names of its parts joined with a
+    /// `.`, which helps to identify a misbehaving optic in logs and error messages.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The name of the optic.
+    ///
+    /// # Returns
+    ///
+    /// A new `FallibleSetterImpl` behaving exactly like `self`, displayed as `name`.
+    #[must_use]
+    pub fn named(self, name: &'static str) -> FallibleSetterImpl<S, A, Named<FS>> {
+        Named::new(self.0, name).into()
+    }
+
+    /// Wraps this optic to emit a `tracing` event for every operation going through it, with
+    /// `name` identifying the optic, whether the operation succeeded and the time it took.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The name of the optic in the events, such as `"config.port"`.
+    ///
+    /// # Returns
+    ///
+    /// A new `FallibleSetterImpl` behaving exactly like `self`, tracing its operations.
+    #[cfg(feature = "tracing")]
+    #[must_use]
+    pub fn traced(self, name: &'static str) -> FallibleSetterImpl<S, A, Traced<FS>> {
+        Traced::new(self.0, name).into()
+    }
+
+    /// Returns the name of the optic, the names of the optics it is composed of joined with a `.`.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn name(&self) -> String
+    where
+        FS: Display,
+    {
+        self.to_string()
+    }
+
+    /// Returns the names of the fields and variants this optic focuses through, from the outermost
+    /// one, such as the field names of the [`field_lens!`](crate::field_lens) lenses it is
+    /// composed of.
+    #[cfg(feature = "alloc")]
+    pub fn path(&self) -> impl Iterator<Item = &str>
+    where
+        FS: HasPath,
+    {
+        let mut path = Vec::new();
+        self.0.for_each_segment(&mut |segment| path.push(segment));
+        path.into_iter()
+    }
+
+    /// Boxes this fallible setter, erasing its type, so it can be stored alongside fallible setters
+    /// of other types.
+    ///
+    /// # Returns
+    ///
+    /// A [`BoxedFallibleSetter`] behaving exactly like `self`.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn boxed(self) -> BoxedFallibleSetter<S, A, FS::SetterError>
+    where
+        FS: Send + Sync + 'static,
+    {
+        let optic: Box<dyn DynFallibleSetter<S, A, FS::SetterError> + Send + Sync> =
+            Box::new(self.0);
+        optic.into()
+    }
+
+    /// Wraps this fallible setter in an [`Arc`], erasing its type, so it can be cloned cheaply and
+    /// shared between threads.
+    ///
+    /// # Returns
+    ///
+    /// A [`SharedFallibleSetter`] behaving exactly like `self`.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn shared(self) -> SharedFallibleSetter<S, A, FS::SetterError>
+    where
+        FS: Send + Sync + 'static,
+    {
+        let optic: Arc<dyn DynFallibleSetter<S, A, FS::SetterError> + Send + Sync> =
+            Arc::new(self.0);
+        optic.into()
+    }
+}
+
+impl<S, A, FS: FallibleSetter<S, A>> HasFallibleSetter<S, A> for FallibleSetterImpl<S, A, FS> {
+    type SetterError = FS::SetterError;
+
+    #[inline]
+    fn try_set(&self, source: &mut S, value: A) -> Result<(), Self::SetterError> {
+        self.0.try_set(source, value)
+    }
+}
+
+impl<S, A, FS: FallibleSetter<S, A>> FallibleSetterImpl<S, A, FS> {
+    /// Adapts this `FallibleSetterImpl<S,A>` into a `FallibleSetterImpl<S,B>` by mapping the
+    /// incoming value with `f` before writing it.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: The function converting the value being set into the focus of `self`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use optics::{HasFallibleSetter, mapped_fallible_setter};
+    ///
+    /// let timeout_millis = mapped_fallible_setter(|timeout: &mut u64, millis: u64| {
+    ///     if millis == 0 {
+    ///         return Err("timeout must not be zero");
+    ///     }
+    ///     *timeout = millis;
+    ///     Ok(())
+    /// });
+    /// let timeout_secs = timeout_millis.premap(|secs: u32| u64::from(secs) * 1_000);
+    ///
+    /// let mut timeout = 1;
+    /// assert_eq!(timeout_secs.try_set(&mut timeout, 0), Err("timeout must not be zero"));
+    /// assert_eq!(timeout_secs.try_set(&mut timeout, 30), Ok(()));
+    /// assert_eq!(timeout, 30_000);
+    /// ```
+    pub fn premap<B, F: Fn(B) -> A>(
+        self,
+        f: F,
+    ) -> FallibleSetterImpl<S, B, impl FallibleSetter<S, B, SetterError = FS::SetterError>> {
+        mapped_fallible_setter(move |source: &mut S, value: B| self.0.try_set(source, f(value)))
+    }
+}
+
+impl<S, I, FS1: FallibleSetter<S, I>> FallibleSetterImpl<S, I, FS1> {
+    /// Composes this `FallibleSetterImpl<S,I>` with a `Setter<I,A>`, resulting in a new
+    /// `FallibleSetterImpl<S, A>` writing values of type `A`.
+    ///
+    /// The value is written by modifying the focus of `self` with `other` and writing it back
+    /// through `self`, failing if `self` rejects it. `self` must be able to read its focus, such
+    /// as a fallible setter wrapping a prism with validation. If `self` has no focus in the
+    /// source, the write is dropped.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `A`: The target type of the composed optic.
+    /// - `S2`: The type of the setter to compose with.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: The setter to compose with.
+    ///
+    /// # Returns
+    ///
+    /// A new `FallibleSetterImpl` that represents the composition of `self` and `other`.
+    #[cfg(feature = "setter")]
+    pub fn compose_with_setter<A, S2: Setter<I, A>>(
+        self,
+        other: S2,
+    ) -> FallibleSetterImpl<S, A, NestedSetter<FS1, S2, S, I, A>>
+    where
+        FS1: HasModify<S, I>,
+    {
+        NestedSetter::new(self.0, other).into()
+    }
+
+    /// Composes this `FallibleSetterImpl<S,I>` with another `FallibleSetter<I,A>`, resulting in a
+    /// new `FallibleSetterImpl<S, A>` writing values of type `A`.
+    ///
+    /// The focus of `self` is read, written through `other` and written back through `self`, so
+    /// both can reject the value. `self` must be able to read its focus, such as a fallible setter
+    /// wrapping a prism with validation. If `self` has no focus in the source, the write is
+    /// dropped.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `E`: The error type of the composed setter, that the setter errors of both `self` and
+    ///   `other` can be converted into.
+    /// - `A`: The target type of the composed optic.
+    /// - `FS2`: The type of the fallible setter to compose with.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: The fallible setter to compose with.
+    ///
+    /// # Returns
+    ///
+    /// A new `FallibleSetterImpl` that represents the composition of `self` and `other`.
+    pub fn compose_with_fallible_setter<E, A, FS2: FallibleSetter<I, A>>(
+        self,
+        other: FS2,
+    ) -> FallibleSetterImpl<S, A, NestedFallibleSetter<FS1, FS2, E, S, I, A>>
+    where
+        FS1: HasModify<S, I>,
+        FS1::SetterError: Into<E>,
+        FS2::SetterError: Into<E>,
+    {
+        NestedFallibleSetter::new(self.0, other, Into::into, Into::into).into()
+    }
+
+    /// Composes this `FallibleSetterImpl<S,I>` with another `FallibleSetter<I,A>`, resulting in a
+    /// new `FallibleSetterImpl<S, A>` writing values of type `A`, using the provided mappers to
+    /// convert the setter errors of both optics into a common error type.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `E`: The error type of the composed setter.
+    /// - `A`: The target type of the composed optic.
+    /// - `FS2`: The type of the fallible setter to compose with.
+    /// - `F1`: The type of the mapper of the setter error of `self`.
+    /// - `F2`: The type of the mapper of the setter error of `other`.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: The fallible setter to compose with.
+    /// - `error_mapper_1`: Converts the setter error of `self` into `E`.
+    /// - `error_mapper_2`: Converts the setter error of `other` into `E`.
+    ///
+    /// # Returns
+    ///
+    /// A new `FallibleSetterImpl` that represents the composition of `self` and `other`.
+    #[allow(clippy::type_complexity)]
+    pub fn compose_with_fallible_setter_with_mappers<E, A, FS2: FallibleSetter<I, A>, F1, F2>(
+        self,
+        other: FS2,
+        error_mapper_1: F1,
+        error_mapper_2: F2,
+    ) -> FallibleSetterImpl<S, A, NestedFallibleSetter<FS1, FS2, E, S, I, A, F1, F2>>
+    where
+        FS1: HasModify<S, I>,
+        F1: Fn(FS1::SetterError) -> E,
+        F2: Fn(FS2::SetterError) -> E,
+    {
+        NestedFallibleSetter::with_mappers(self.0, other, error_mapper_1, error_mapper_2).into()
+    }
+
+    /// Composes this `FallibleSetterImpl<S,I>` with a `Prism<I,A>`, resulting in a new
+    /// `FallibleSetterImpl<S, A>` writing values of type `A`.
+    ///
+    /// The value is written by modifying the focus of `self` with `other` and writing it back
+    /// through `self`, failing if `self` rejects it. `self` must be able to read its focus, such
+    /// as a fallible setter wrapping a prism with validation. If `self` has no focus in the
+    /// source, the write is dropped.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `A`: The target type of the composed optic.
+    /// - `P2`: The type of the prism to compose with.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: The prism to compose with.
+    ///
+    /// # Returns
+    ///
+    /// A new `FallibleSetterImpl` that represents the composition of `self` and `other`.
+    #[cfg(feature = "prism")]
+    pub fn compose_with_prism<A, P2: Prism<I, A>>(
+        self,
+        other: P2,
+    ) -> FallibleSetterImpl<S, A, NestedSetter<FS1, P2, S, I, A>>
+    where
+        FS1: HasModify<S, I>,
+    {
+        NestedSetter::new(self.0, other).into()
+    }
+
+    /// Composes this `FallibleSetterImpl<S,I>` with a `Lens<I,A>`, resulting in a new
+    /// `FallibleSetterImpl<S, A>` writing values of type `A`.
+    ///
+    /// The value is written by modifying the focus of `self` with `other` and writing it back
+    /// through `self`, failing if `self` rejects it. `self` must be able to read its focus, such
+    /// as a fallible setter wrapping a prism with validation. If `self` has no focus in the
+    /// source, the write is dropped.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `A`: The target type of the composed optic.
+    /// - `L2`: The type of the lens to compose with.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: The lens to compose with.
+    ///
+    /// # Returns
+    ///
+    /// A new `FallibleSetterImpl` that represents the composition of `self` and `other`.
+    #[cfg(feature = "lens")]
+    pub fn compose_with_lens<A, L2: Lens<I, A>>(
+        self,
+        other: L2,
+    ) -> FallibleSetterImpl<S, A, NestedSetter<FS1, L2, S, I, A>>
+    where
+        FS1: HasModify<S, I>,
+    {
+        NestedSetter::new(self.0, other).into()
+    }
+
+    /// Composes this `FallibleSetterImpl<S,I>` with an `Iso<I,A>`, resulting in a new
+    /// `FallibleSetterImpl<S, A>` writing values of type `A`.
+    ///
+    /// A setter can not read its focus, so the value being written is converted into the focus of
+    /// `self` with the reverse direction of `other`, and written through `self`, failing if
+    /// `self` rejects it.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `A`: The target type of the composed optic.
+    /// - `ISO2`: The type of the iso to compose with.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: The iso to compose with.
+    ///
+    /// # Returns
+    ///
+    /// A new `FallibleSetterImpl` that represents the composition of `self` and `other`.
+    #[cfg(feature = "iso")]
+    #[allow(clippy::type_complexity)]
+    pub fn compose_with_iso<A, ISO2: Iso<I, A>>(
+        self,
+        other: ISO2,
+    ) -> FallibleSetterImpl<S, A, ReversedFallibleSetter<FS1, ISO2, FS1::SetterError, S, I, A>>
+    {
+        ReversedFallibleSetter::new(self.0, other, identity, infallible).into()
+    }
+
+    /// Composes this `FallibleSetterImpl<S,I>` with a `FallibleIso<I,A>`, resulting in a new
+    /// `FallibleSetterImpl<S, A>` writing values of type `A`.
+    ///
+    /// A setter can not read its focus, so the value being written is converted into the focus of
+    /// `self` with the reverse direction of `other`, and written through `self`. If the
+    /// conversion fails or `self` rejects the value, the error is returned.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `E`: The error type for the composed setter, which must be able to be constructed from
+    ///   both `FS1::SetterError` and `FI2::ReverseError` through `Into::into`.
+    /// - `A`: The target type of the composed optic.
+    /// - `FI2`: The type of the fallible iso to compose with.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: The fallible iso to compose with.
+    ///
+    /// # Returns
+    ///
+    /// A new `FallibleSetterImpl` that represents the composition of `self` and `other`.
+    ///
+    /// # Note
+    ///
+    /// This method uses `Into::into` to convert the errors of both optics into the common error
+    /// type `E`. If you need custom error mapping, consider using
+    /// [`compose_with_fallible_iso_with_mappers`](Self::compose_with_fallible_iso_with_mappers).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use optics::{HasFallibleSetter, OpticsError, mapped_fallible_setter, parse_iso};
+    ///
+    /// let port = mapped_fallible_setter(|port: &mut String, value: String| {
+    ///     if value == "0" {
+    ///         return Err(OpticsError::OutOfRange);
+    ///     }
+    ///     *port = value;
+    ///     Ok(())
+    /// });
+    /// let port_number = port.compose_with_fallible_iso::<OpticsError, _, _>(parse_iso::<u16>());
+    ///
+    /// let mut config = "80".to_string();
+    /// assert_eq!(port_number.try_set(&mut config, 0), Err(OpticsError::OutOfRange));
+    /// assert_eq!(port_number.try_set(&mut config, 8080), Ok(()));
+    /// assert_eq!(config, "8080");
+    /// ```
+    #[cfg(feature = "fallible-iso")]
+    #[allow(clippy::type_complexity)]
+    pub fn compose_with_fallible_iso<E, A, FI2: FallibleIso<I, A>>(
+        self,
+        other: FI2,
+    ) -> FallibleSetterImpl<S, A, ReversedFallibleSetter<FS1, FI2, E, S, I, A>>
+    where
+        E: From<FS1::SetterError> + From<FI2::ReverseError>,
+    {
+        ReversedFallibleSetter::new(self.0, other, Into::<E>::into, Into::<E>::into).into()
+    }
+
+    /// Composes this `FallibleSetterImpl<S,I>` with a `FallibleIso<I,A>`, resulting in a new
+    /// `FallibleSetterImpl<S, A>` writing values of type `A`, mapping the errors of both optics
+    /// with custom functions.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `E`: The common error type for the composed setter.
+    /// - `A`: The target type of the composed optic.
+    /// - `FI2`: The type of the fallible iso to compose with.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: The fallible iso to compose with.
+    /// - `setter_error_mapper`: A function to map `FS1::SetterError` into `E`.
+    /// - `reverse_error_mapper`: A function to map `FI2::ReverseError` into `E`.
+    ///
+    /// # Returns
+    ///
+    /// A new `FallibleSetterImpl` that represents the composition of `self` and `other` with
+    /// custom error mapping.
+    #[cfg(feature = "fallible-iso")]
+    #[allow(clippy::type_complexity)]
+    pub fn compose_with_fallible_iso_with_mappers<E, A, FI2: FallibleIso<I, A>, F1, F2>(
+        self,
+        other: FI2,
+        setter_error_mapper: F1,
+        reverse_error_mapper: F2,
+    ) -> FallibleSetterImpl<S, A, ReversedFallibleSetter<FS1, FI2, E, S, I, A, F1, F2>>
+    where
+        F1: Fn(FS1::SetterError) -> E,
+        F2: Fn(FI2::ReverseError) -> E,
+    {
+        ReversedFallibleSetter::with_mappers(
+            self.0,
+            other,
+            setter_error_mapper,
+            reverse_error_mapper,
+        )
+        .into()
+    }
+
+    /// Composes this `FallibleSetterImpl<S,I>` with a `FallibleIsoImpl<I,A>`, resulting in a new
+    /// `FallibleSetterImpl<S, A>` whose error identifies which of the two optics failed.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `A`: The target type of the composed optic.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: The fallible iso to compose with.
+    ///
+    /// # Returns
+    ///
+    /// A new `FallibleSetterImpl` failing with a [`Either::Left`] if `self` rejects the value, or
+    /// a [`Either::Right`] if `other` fails to convert it.
+    #[cfg(feature = "fallible-iso")]
+    #[allow(clippy::type_complexity)]
+    pub fn compose_with_fallible_iso_tracking<A, FI2: FallibleIso<I, A>>(
+        self,
+        other: FI2,
+    ) -> FallibleSetterImpl<
+        S,
+        A,
+        ReversedFallibleSetter<FS1, FI2, Either<FS1::SetterError, FI2::ReverseError>, S, I, A>,
+    > {
+        ReversedFallibleSetter::new(self.0, other, Either::Left, Either::Right).into()
+    }
+
+    /// Composes this `FallibleSetterImpl<S,I>` with a `Traversal<I,A>`, resulting in a new
+    /// `FallibleSetterImpl<S, A>` writing a value of type `A` into every focus of `other`.
+    ///
+    /// The value is written by modifying the focus of `self` with `other` and writing it back
+    /// through `self`, failing if `self` rejects it. `self` must be able to read its focus, such
+    /// as a fallible setter wrapping a prism with validation. If `self` has no focus in the
+    /// source, the write is dropped.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `A`: The target type of the composed optic.
+    /// - `T2`: The type of the traversal to compose with.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: The traversal to compose with.
+    ///
+    /// # Returns
+    ///
+    /// A new `FallibleSetterImpl` that represents the composition of `self` and `other`.
+    #[allow(clippy::type_complexity)]
+    pub fn compose_with_traversal<A: Clone, T2: Traversal<I, A>>(
+        self,
+        other: T2,
+    ) -> FallibleSetterImpl<S, A, NestedSetter<FS1, OverAll<I, A, T2>, S, I, A>>
+    where
+        FS1: HasModify<S, I>,
+    {
+        NestedSetter::new(self.0, over_all(other)).into()
+    }
+}
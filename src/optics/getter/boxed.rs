@@ -0,0 +1,26 @@
+use crate::{GetterImpl, HasGetter};
+use alloc::boxed::Box;
+use core::convert::Infallible;
+
+/// An object safe version of [`Getter`](crate::Getter), implemented by every getter.
+///
+/// It can be used as a trait object, such as in a [`BoxedGetter`], to store getters of different
+/// types in the same collection.
+pub trait DynGetter<S, A>: HasGetter<S, A, GetterError = Infallible> {}
+
+impl<S, A, O: HasGetter<S, A, GetterError = Infallible>> DynGetter<S, A> for O {}
+
+/// A boxed getter of any type, focusing on a value of type `A` in a source of type `S`.
+///
+/// Unlike the opaque getters returned by the constructors of this crate, boxed getters of different
+/// types have the same type, so they can be stored in collections or struct fields, and composed
+/// like any other getter. They are created with [`GetterImpl::boxed`].
+pub type BoxedGetter<S, A> = GetterImpl<S, A, Box<dyn DynGetter<S, A> + Send + Sync>>;
+
+impl<S, A> HasGetter<S, A> for Box<dyn DynGetter<S, A> + Send + Sync> {
+    type GetterError = Infallible;
+
+    fn try_get(&self, source: &S) -> Result<A, Self::GetterError> {
+        (**self).try_get(source)
+    }
+}
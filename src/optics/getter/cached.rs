@@ -0,0 +1,103 @@
+use crate::optics::getter::wrapper::GetterImpl;
+use crate::{Getter, HasGetter, HasTotalGetter};
+use core::cell::RefCell;
+use core::convert::Infallible;
+use core::marker::PhantomData;
+
+struct CachedGetter<G: Getter<S, A>, KF: Fn(&S) -> K, S, A, K> {
+    optic: G,
+    key_fn: KF,
+    last: RefCell<Option<(K, A)>>,
+    _phantom: PhantomData<S>,
+}
+
+impl<G, KF, S, A, K> HasGetter<S, A> for CachedGetter<G, KF, S, A, K>
+where
+    G: Getter<S, A>,
+    KF: Fn(&S) -> K,
+    A: Clone,
+    K: PartialEq,
+{
+    type GetterError = Infallible;
+
+    fn try_get(&self, source: &S) -> Result<A, Self::GetterError> {
+        let key = (self.key_fn)(source);
+
+        if let Some(focus) = self
+            .last
+            .borrow()
+            .as_ref()
+            .filter(|(last_key, _)| *last_key == key)
+            .map(|(_, focus)| focus.clone())
+        {
+            return Ok(focus);
+        }
+
+        let focus = self.optic.get(source);
+        *self.last.borrow_mut() = Some((key, focus.clone()));
+        Ok(focus)
+    }
+}
+
+/// Creates a `Getter<S,A>` that only evaluates `g` when the key computed by `key_fn` differs from
+/// the key of the previous read.
+pub(crate) fn new_by<S, A, K, G, KF>(g: G, key_fn: KF) -> GetterImpl<S, A, impl Getter<S, A>>
+where
+    G: Getter<S, A>,
+    KF: Fn(&S) -> K,
+    A: Clone,
+    K: PartialEq,
+{
+    CachedGetter {
+        optic: g,
+        key_fn,
+        last: RefCell::new(None),
+        _phantom: PhantomData,
+    }
+    .into()
+}
+
+struct CachedBySourceGetter<G: Getter<S, A>, S, A> {
+    optic: G,
+    last: RefCell<Option<(S, A)>>,
+}
+
+impl<G, S, A> HasGetter<S, A> for CachedBySourceGetter<G, S, A>
+where
+    G: Getter<S, A>,
+    S: PartialEq + Clone,
+    A: Clone,
+{
+    type GetterError = Infallible;
+
+    fn try_get(&self, source: &S) -> Result<A, Self::GetterError> {
+        if let Some(focus) = self
+            .last
+            .borrow()
+            .as_ref()
+            .filter(|(last_source, _)| last_source == source)
+            .map(|(_, focus)| focus.clone())
+        {
+            return Ok(focus);
+        }
+
+        let focus = self.optic.get(source);
+        *self.last.borrow_mut() = Some((source.clone(), focus.clone()));
+        Ok(focus)
+    }
+}
+
+/// Creates a `Getter<S,A>` that only evaluates `g` when the source differs from the source of the
+/// previous read, cloning the source only when `g` is evaluated.
+pub(crate) fn new<S, A, G>(g: G) -> GetterImpl<S, A, impl Getter<S, A>>
+where
+    G: Getter<S, A>,
+    S: PartialEq + Clone,
+    A: Clone,
+{
+    CachedBySourceGetter {
+        optic: g,
+        last: RefCell::new(None),
+    }
+    .into()
+}
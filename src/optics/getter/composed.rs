@@ -1,12 +1,76 @@
+use crate::HasPath;
 use crate::optics::getter::wrapper::GetterImpl;
 use crate::{Getter, HasGetter, HasTotalGetter};
 use core::convert::Infallible;
+use core::fmt::{Debug, Display, Formatter};
 use core::marker::PhantomData;
 
-struct ComposedGetter<G1: Getter<S, I>, G2: Getter<I, A>, S, I, A> {
-    optic1: G1,
-    optic2: G2,
-    _phantom: PhantomData<(S, I, A)>,
+mod private {
+    use crate::Getter;
+    use core::marker::PhantomData;
+
+    pub struct ComposedGetter<G1: Getter<S, I>, G2: Getter<I, A>, S, I, A> {
+        pub(super) optic1: G1,
+        pub(super) optic2: G2,
+        pub(super) _phantom: PhantomData<(S, I, A)>,
+    }
+}
+
+pub type ComposedGetter<G1, G2, S, I, A> = private::ComposedGetter<G1, G2, S, I, A>;
+
+impl<G1, G2, S, I, A> Clone for ComposedGetter<G1, G2, S, I, A>
+where
+    G1: Getter<S, I> + Clone,
+    G2: Getter<I, A> + Clone,
+{
+    fn clone(&self) -> Self {
+        ComposedGetter {
+            optic1: self.optic1.clone(),
+            optic2: self.optic2.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<G1, G2, S, I, A> Copy for ComposedGetter<G1, G2, S, I, A>
+where
+    G1: Getter<S, I> + Copy,
+    G2: Getter<I, A> + Copy,
+{
+}
+
+impl<G1, G2, S, I, A> Debug for ComposedGetter<G1, G2, S, I, A>
+where
+    G1: Getter<S, I> + Debug,
+    G2: Getter<I, A> + Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ComposedGetter")
+            .field("optic1", &self.optic1)
+            .field("optic2", &self.optic2)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<G1, G2, S, I, A> Display for ComposedGetter<G1, G2, S, I, A>
+where
+    G1: Getter<S, I> + Display,
+    G2: Getter<I, A> + Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}.{}", self.optic1, self.optic2)
+    }
+}
+
+impl<G1, G2, S, I, A> HasPath for ComposedGetter<G1, G2, S, I, A>
+where
+    G1: Getter<S, I> + HasPath,
+    G2: Getter<I, A> + HasPath,
+{
+    fn for_each_segment<'a>(&'a self, f: &mut dyn FnMut(&'a str)) {
+        self.optic1.for_each_segment(f);
+        self.optic2.for_each_segment(f);
+    }
 }
 
 impl<G1, G2, S, I, A> ComposedGetter<G1, G2, S, I, A>
@@ -30,6 +94,7 @@ where
 {
     type GetterError = Infallible;
 
+    #[inline]
     fn try_get(&self, source: &S) -> Result<A, Self::GetterError> {
         Ok(self.optic2.get(&self.optic1.get(source)))
     }
@@ -62,6 +127,6 @@ where
 pub fn new<S, A, I, G1: Getter<S, I>, G2: Getter<I, A>>(
     l1: G1,
     l2: G2,
-) -> GetterImpl<S, A, impl Getter<S, A>> {
+) -> GetterImpl<S, A, ComposedGetter<G1, G2, S, I, A>> {
     ComposedGetter::new(l1, l2).into()
 }
@@ -1,8 +1,21 @@
+use crate::HasGetter;
 use crate::optics::getter::wrapper::GetterImpl;
-use crate::{Getter, HasGetter};
 use core::convert::Infallible;
+use core::fmt::{Debug, Formatter};
 use core::marker::PhantomData;
 
+mod private {
+    use core::marker::PhantomData;
+
+    pub struct MappedGetter<S, A, GET = fn(&S) -> A>
+    where
+        GET: Fn(&S) -> A,
+    {
+        pub(super) get_fn: GET,
+        pub(super) phantom: PhantomData<(S, A)>,
+    }
+}
+
 /// Creates a new `Getter` with the provided getter function.
 ///
 /// # Type Parameters
@@ -30,12 +43,29 @@ use core::marker::PhantomData;
 ///
 /// assert_eq!(x_partial_getter.get(&point), 10);
 /// ```
-struct MappedGetter<S, A, GET = fn(&S) -> A>
+pub type MappedGetter<S, A, GET = fn(&S) -> A> = private::MappedGetter<S, A, GET>;
+
+impl<S, A, GET> Clone for MappedGetter<S, A, GET>
+where
+    GET: Fn(&S) -> A + Clone,
+{
+    fn clone(&self) -> Self {
+        MappedGetter {
+            get_fn: self.get_fn.clone(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<S, A, GET> Copy for MappedGetter<S, A, GET> where GET: Fn(&S) -> A + Copy {}
+
+impl<S, A, GET> Debug for MappedGetter<S, A, GET>
 where
     GET: Fn(&S) -> A,
 {
-    get_fn: GET,
-    phantom: PhantomData<(S, A)>,
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("MappedGetter").finish_non_exhaustive()
+    }
 }
 
 impl<S, A, GET> MappedGetter<S, A, GET>
@@ -87,7 +117,7 @@ where
     /// let `x_value` = `x_lens.get(&point)`; // retrieves 10 * 2 = 20
     /// `x_lens.set(&mut` point, 60); // sets x to 60 / 2 = 30
     // ```
-    fn new(get_fn: GET) -> Self {
+    const fn new(get_fn: GET) -> Self {
         MappedGetter {
             get_fn,
             phantom: PhantomData,
@@ -133,9 +163,9 @@ where
 /// assert_eq!(x_lens.get(&p), 10);
 /// ```
 #[must_use]
-pub fn new<S, A, GET>(get_fn: GET) -> GetterImpl<S, A, impl Getter<S, A>>
+pub const fn new<S, A, GET>(get_fn: GET) -> GetterImpl<S, A, MappedGetter<S, A, GET>>
 where
     GET: Fn(&S) -> A,
 {
-    MappedGetter::new(get_fn).into()
+    GetterImpl::new(MappedGetter::new(get_fn))
 }
@@ -1,13 +1,39 @@
+#[cfg(all(feature = "alloc", feature = "getter"))]
+mod boxed;
+#[cfg(feature = "getter")]
+mod cached;
+#[cfg(feature = "getter")]
 mod composed;
+#[cfg(feature = "getter")]
 mod mapped;
+#[cfg(all(feature = "alloc", feature = "getter"))]
+mod shared;
+#[cfg(feature = "getter")]
 mod wrapper;
+#[cfg(feature = "getter")]
+mod zip;
 
 use crate::HasGetter;
+#[cfg(all(feature = "alloc", feature = "getter"))]
+pub use boxed::{BoxedGetter, DynGetter};
+#[cfg(feature = "getter")]
+pub(crate) use composed::ComposedGetter;
+#[cfg(feature = "getter")]
 pub use composed::new as composed_getter;
 use core::convert::Infallible;
+#[cfg(feature = "getter")]
 pub use mapped::new as mapped_getter;
+#[cfg(all(feature = "alloc", feature = "getter"))]
+pub use shared::SharedGetter;
+#[cfg(feature = "getter")]
 pub use wrapper::GetterImpl;
 
+/// A [`GetterImpl`] built by [`mapped_getter`] from plain functions or non-capturing closures.
+///
+/// As [`mapped_getter`] is a `const fn`, this type can be used to declare the optic as a `static`.
+#[cfg(feature = "getter")]
+pub type FnGetter<S, A> = GetterImpl<S, A, mapped::MappedGetter<S, A>>;
+
 /// A `Getter` is an optic that focuses on a value inside a product type.
 ///
 /// It provides:
@@ -59,6 +85,7 @@ impl<S, A, G: HasGetter<S, A, GetterError = Infallible>> Getter<S, A> for G {}
 /// - [`mapped_getter`] for constructing custom `Getter`s
 ///   from an arbitrary mapping function.
 ///
+#[cfg(feature = "getter")]
 #[must_use]
 pub fn identity_getter<S: Clone>() -> GetterImpl<S, S, impl Getter<S, S>> {
     mapped_getter(|x: &S| x.clone())
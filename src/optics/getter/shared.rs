@@ -0,0 +1,18 @@
+use crate::{DynGetter, GetterImpl, HasGetter};
+use alloc::sync::Arc;
+use core::convert::Infallible;
+
+/// A shared getter of any type, focusing on a value of type `A` in a source of type `S`.
+///
+/// Like [boxed getters](crate::BoxedGetter), shared getters of different types have the same type,
+/// but they are reference counted, so they can be cloned cheaply, stored in registries and sent to
+/// other threads. They are created with [`GetterImpl::shared`](crate::GetterImpl::shared).
+pub type SharedGetter<S, A> = GetterImpl<S, A, Arc<dyn DynGetter<S, A> + Send + Sync>>;
+
+impl<S, A> HasGetter<S, A> for Arc<dyn DynGetter<S, A> + Send + Sync> {
+    type GetterError = Infallible;
+
+    fn try_get(&self, source: &S) -> Result<A, Self::GetterError> {
+        (**self).try_get(source)
+    }
+}
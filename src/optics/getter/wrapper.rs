@@ -1,11 +1,44 @@
+#[cfg(feature = "fallible-iso")]
+use crate::FallibleIso;
+use crate::HasPath;
+#[cfg(feature = "iso")]
+use crate::Iso;
+#[cfg(feature = "prism")]
+use crate::Prism;
+use crate::named::Named;
+use crate::optics::fallible_setter::ComposedFallibleSetter;
+use crate::optics::getter::ComposedGetter;
+use crate::optics::getter::cached::{new as cached, new_by as cached_by};
 use crate::optics::getter::composed::new as composed_getter;
+use crate::optics::getter::zip::new as zip;
+use crate::optics::partial_getter::ComposedPartialGetter;
+#[cfg(feature = "lens")]
+use crate::optics::partial_getter::with_setter::new_lens as with_setter;
+#[cfg(feature = "tracing")]
+use crate::traced::Traced;
 use crate::{
-    FallibleIso, FallibleIsoImpl, Getter, HasGetter, HasTotalGetter, Iso, IsoImpl, Lens, LensImpl,
-    PartialGetter, PartialGetterImpl, Prism, PrismImpl, Setter, SetterImpl,
-    composed_partial_getter, composed_setter, infallible,
+    FallibleSetter, FallibleSetterImpl, Getter, HasGetter, HasTotalGetter, PartialGetter,
+    PartialGetterImpl, Setter, composed_fallible_setter, composed_partial_getter, infallible,
+    mapped_getter,
 };
+#[cfg(feature = "lens")]
+use crate::{Lens, LensImpl};
 use core::convert::{Infallible, identity};
+use core::fmt::{Debug, Display, Formatter};
 use core::marker::PhantomData;
+#[cfg(feature = "alloc")]
+use {
+    crate::optics::getter::{BoxedGetter, DynGetter, SharedGetter},
+    alloc::boxed::Box,
+    alloc::string::{String, ToString},
+    alloc::sync::Arc,
+    alloc::vec::Vec,
+};
+#[cfg(feature = "setter")]
+use {
+    crate::optics::setter::ComposedSetter,
+    crate::{SetterImpl, composed_setter},
+};
 
 /// A wrapper of the [`Getter`] optic implementations, encapsulating a total getter function.
 ///
@@ -36,8 +69,123 @@ impl<S, A, G: Getter<S, A>> From<G> for GetterImpl<S, A, G> {
     }
 }
 
+impl<S, A, G: Getter<S, A> + Clone> Clone for GetterImpl<S, A, G> {
+    fn clone(&self) -> Self {
+        Self::new(self.0.clone())
+    }
+}
+
+impl<S, A, G: Getter<S, A> + Copy> Copy for GetterImpl<S, A, G> {}
+
+impl<S, A, G: Getter<S, A> + Debug> Debug for GetterImpl<S, A, G> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("GetterImpl").field(&self.0).finish()
+    }
+}
+
+impl<S, A, G: Getter<S, A> + Display> Display for GetterImpl<S, A, G> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl<S, A, G: Getter<S, A> + HasPath> HasPath for GetterImpl<S, A, G> {
+    fn for_each_segment<'a>(&'a self, f: &mut dyn FnMut(&'a str)) {
+        self.0.for_each_segment(f);
+    }
+}
+
+impl<S, A, G: Getter<S, A>> GetterImpl<S, A, G> {
+    /// Labels this optic with a static `name`, used by its [`Display`] implementation.
+    ///
+    /// Composing named optics results in an optic displaying the names of its parts joined with a
+    /// `.`, which helps to identify a misbehaving optic in logs and error messages.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The name of the optic.
+    ///
+    /// # Returns
+    ///
+    /// A new `GetterImpl` behaving exactly like `self`, displayed as `name`.
+    #[must_use]
+    pub fn named(self, name: &'static str) -> GetterImpl<S, A, Named<G>> {
+        Named::new(self.0, name).into()
+    }
+
+    /// Wraps this optic to emit a `tracing` event for every operation going through it, with
+    /// `name` identifying the optic, whether the operation succeeded and the time it took.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The name of the optic in the events, such as `"config.port"`.
+    ///
+    /// # Returns
+    ///
+    /// A new `GetterImpl` behaving exactly like `self`, tracing its operations.
+    #[cfg(feature = "tracing")]
+    #[must_use]
+    pub fn traced(self, name: &'static str) -> GetterImpl<S, A, Traced<G>> {
+        Traced::new(self.0, name).into()
+    }
+
+    /// Returns the name of the optic, the names of the optics it is composed of joined with a `.`.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn name(&self) -> String
+    where
+        G: Display,
+    {
+        self.to_string()
+    }
+
+    /// Returns the names of the fields and variants this optic focuses through, from the outermost
+    /// one, such as the field names of the [`field_lens!`](crate::field_lens) lenses it is
+    /// composed of.
+    #[cfg(feature = "alloc")]
+    pub fn path(&self) -> impl Iterator<Item = &str>
+    where
+        G: HasPath,
+    {
+        let mut path = Vec::new();
+        self.0.for_each_segment(&mut |segment| path.push(segment));
+        path.into_iter()
+    }
+
+    /// Boxes this getter, erasing its type, so it can be stored alongside getters of other types.
+    ///
+    /// # Returns
+    ///
+    /// A [`BoxedGetter`] behaving exactly like `self`.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn boxed(self) -> BoxedGetter<S, A>
+    where
+        G: Send + Sync + 'static,
+    {
+        let optic: Box<dyn DynGetter<S, A> + Send + Sync> = Box::new(self.0);
+        optic.into()
+    }
+
+    /// Wraps this getter in an [`Arc`], erasing its type, so it can be cloned cheaply and shared
+    /// between threads.
+    ///
+    /// # Returns
+    ///
+    /// A [`SharedGetter`] behaving exactly like `self`.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn shared(self) -> SharedGetter<S, A>
+    where
+        G: Send + Sync + 'static,
+    {
+        let optic: Arc<dyn DynGetter<S, A> + Send + Sync> = Arc::new(self.0);
+        optic.into()
+    }
+}
+
 impl<S, A, G: Getter<S, A>> GetterImpl<S, A, G> {
-    fn new(prism: G) -> Self {
+    pub(crate) const fn new(prism: G) -> Self {
         //TODO: Verify not to nest an Impl inside an Impl - currently seems to be impossible at compile time.
         GetterImpl(prism, PhantomData)
     }
@@ -46,6 +194,7 @@ impl<S, A, G: Getter<S, A>> GetterImpl<S, A, G> {
 impl<S, A, G: Getter<S, A>> HasGetter<S, A> for GetterImpl<S, A, G> {
     type GetterError = Infallible;
 
+    #[inline]
     fn try_get(&self, source: &S) -> Result<A, Self::GetterError> {
         Ok(self.0.get(source))
     }
@@ -71,11 +220,12 @@ impl<S, I, G1: Getter<S, I>> GetterImpl<S, I, G1> {
     ///
     /// A new `PartialGetterImpl` that represents the composition of `self` and `other`.
     ///
+    #[allow(clippy::type_complexity)]
     pub fn compose_with_partial_getter<A, PG2: PartialGetter<I, A>>(
         self,
-        other: PartialGetterImpl<I, A, PG2>,
-    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A, GetterError = PG2::GetterError>> {
-        composed_partial_getter(self.0, other.0, infallible, identity)
+        other: PG2,
+    ) -> PartialGetterImpl<S, A, ComposedPartialGetter<G1, PG2, PG2::GetterError, S, I, A>> {
+        composed_partial_getter(self.0, other, infallible, identity)
     }
 
     /// Composes this `GetterImpl<S,I>` with a `GetterImpl<I,A>`, resulting in a new `GetterImpl<S, A>`
@@ -99,9 +249,9 @@ impl<S, I, G1: Getter<S, I>> GetterImpl<S, I, G1> {
     ///
     pub fn compose_with_getter<A, G2: Getter<I, A>>(
         self,
-        other: GetterImpl<I, A, G2>,
-    ) -> GetterImpl<S, A, impl Getter<S, A>> {
-        composed_getter(self.0, other.0)
+        other: G2,
+    ) -> GetterImpl<S, A, ComposedGetter<G1, G2, S, I, A>> {
+        composed_getter(self.0, other)
     }
 
     /// Composes this `GetterImpl<S,I>` with a `Setter<I,A>`, resulting in a new `Setter<S, A>`
@@ -123,14 +273,45 @@ impl<S, I, G1: Getter<S, I>> GetterImpl<S, I, G1> {
     ///
     /// A new `SetterImpl` that represents the composition of `self` and `other`.
     ///
+    #[cfg(feature = "setter")]
     pub fn compose_with_setter<A, S2: Setter<I, A>>(
         self,
-        other: SetterImpl<I, A, S2>,
-    ) -> SetterImpl<S, A, impl Setter<S, A>>
+        other: S2,
+    ) -> SetterImpl<S, A, ComposedSetter<G1, S2, S, I, A>>
     where
         G1: Setter<S, I>,
     {
-        composed_setter(self.0, other.0)
+        composed_setter(self.0, other)
+    }
+
+    /// Composes this `GetterImpl<S,I>` with a `FallibleSetter<I,A>`, resulting in a new `FallibleSetter<S, A>`
+    /// that focuses through both optics sequentially.
+    ///
+    /// The resulting `FallibleSetterImpl` will set the value by first applying `self` and then
+    /// `other`, failing if `other` rejects the value.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `A`: The target type of the composed optic.
+    /// - `FS2`: The type of the fallible setter to compose with.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: The fallible setter to compose with.
+    ///
+    /// # Returns
+    ///
+    /// A new `FallibleSetterImpl` that represents the composition of `self` and `other`.
+    ///
+    #[allow(clippy::type_complexity)]
+    pub fn compose_with_fallible_setter<A, FS2: FallibleSetter<I, A>>(
+        self,
+        other: FS2,
+    ) -> FallibleSetterImpl<S, A, ComposedFallibleSetter<G1, FS2, FS2::SetterError, S, I, A>>
+    where
+        G1: Setter<S, I>,
+    {
+        composed_fallible_setter(self.0, other, infallible, identity)
     }
 
     /// Composes this `GetterImpl<S,I>` with a `Prism<I,A>`, resulting in a new `PartialGetterImpl<S, A>`
@@ -151,11 +332,13 @@ impl<S, I, G1: Getter<S, I>> GetterImpl<S, I, G1> {
     /// # Returns
     ///
     /// A new `PartialGetterImpl` that represents the composition of `self` and `other`.
+    #[cfg(feature = "prism")]
+    #[allow(clippy::type_complexity)]
     pub fn compose_with_prism<A, P2: Prism<I, A>>(
         self,
-        other: PrismImpl<I, A, P2>,
-    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A, GetterError = P2::GetterError>> {
-        composed_partial_getter(self.0, other.0, infallible, identity)
+        other: P2,
+    ) -> PartialGetterImpl<S, A, ComposedPartialGetter<G1, P2, P2::GetterError, S, I, A>> {
+        composed_partial_getter(self.0, other, infallible, identity)
     }
 
     /// Composes this `GetterImpl<S,I>` with a `LensImpl<I,A>`, resulting in a new `GetterImpl<S, A>`
@@ -176,11 +359,12 @@ impl<S, I, G1: Getter<S, I>> GetterImpl<S, I, G1> {
     /// # Returns
     ///
     /// A new `GetterImpl` that represents the composition of `self` and `other`
+    #[cfg(feature = "lens")]
     pub fn compose_with_lens<A, L2: Lens<I, A>>(
         self,
-        other: LensImpl<I, A, L2>,
-    ) -> GetterImpl<S, A, impl Getter<S, A>> {
-        composed_getter(self.0, other.0)
+        other: L2,
+    ) -> GetterImpl<S, A, ComposedGetter<G1, L2, S, I, A>> {
+        composed_getter(self.0, other)
     }
 
     /// Composes this `GetterImpl<S,I>` with a `FallibleIso<I,A>`, resulting in a new `PartialGetterImpl<S, A>`
@@ -201,11 +385,13 @@ impl<S, I, G1: Getter<S, I>> GetterImpl<S, I, G1> {
     /// # Returns
     ///
     /// A new `PartialGetterImpl` that represents the composition of `self` and `other`.
+    #[cfg(feature = "fallible-iso")]
+    #[allow(clippy::type_complexity)]
     pub fn compose_with_fallible_iso<A, FI2: FallibleIso<I, A>>(
         self,
-        other: FallibleIsoImpl<I, A, FI2>,
-    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A, GetterError = FI2::GetterError>> {
-        composed_partial_getter(self.0, other.0, infallible, identity)
+        other: FI2,
+    ) -> PartialGetterImpl<S, A, ComposedPartialGetter<G1, FI2, FI2::GetterError, S, I, A>> {
+        composed_partial_getter(self.0, other, infallible, identity)
     }
 
     /// Composes this `GetterImpl<S,I>` with an `IsoImpl<I,A>`, resulting in a new `GetterImpl<S, A>`
@@ -226,10 +412,164 @@ impl<S, I, G1: Getter<S, I>> GetterImpl<S, I, G1> {
     /// # Returns
     ///
     /// A new `GetterImpl` that represents the composition of `self` and `other`
+    #[cfg(feature = "iso")]
     pub fn compose_with_iso<A, ISO2: Iso<I, A>>(
         self,
-        other: IsoImpl<I, A, ISO2>,
-    ) -> GetterImpl<S, A, impl Getter<S, A>> {
-        composed_getter(self.0, other.0)
+        other: ISO2,
+    ) -> GetterImpl<S, A, ComposedGetter<G1, ISO2, S, I, A>> {
+        composed_getter(self.0, other)
+    }
+}
+
+impl<S, A, G1: Getter<S, A>> GetterImpl<S, A, G1> {
+    /// Combines this `GetterImpl<S,A>` with another `GetterImpl<S,B>` over the same source, resulting in a
+    /// new `GetterImpl<S, (A, B)>` focusing on both values as a tuple.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: The getter focusing on the second element of the tuple.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use optics::{HasTotalGetter, mapped_getter};
+    ///
+    /// struct Server {
+    ///     host: String,
+    ///     port: u16,
+    /// }
+    ///
+    /// let host = mapped_getter(|s: &Server| s.host.clone());
+    /// let port = mapped_getter(|s: &Server| s.port);
+    /// let endpoint = host.zip(port);
+    ///
+    /// let server = Server { host: "localhost".to_string(), port: 80 };
+    /// assert_eq!(endpoint.get(&server), ("localhost".to_string(), 80));
+    /// ```
+    pub fn zip<B, G2: Getter<S, B>>(
+        self,
+        other: GetterImpl<S, B, G2>,
+    ) -> GetterImpl<S, (A, B), impl Getter<S, (A, B)>> {
+        zip(self.0, other)
+    }
+
+    /// Maps the focus of this `GetterImpl<S,A>` with `f`, resulting in a read-only
+    /// `GetterImpl<S,B>` focusing on a value derived from the focus.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: The function deriving the new focus from the focus of `self`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use optics::{HasTotalGetter, mapped_getter};
+    ///
+    /// struct Server {
+    ///     host: String,
+    /// }
+    ///
+    /// let host = mapped_getter(|s: &Server| s.host.clone());
+    /// let host_len = host.map(String::len);
+    /// let server = Server { host: "localhost".to_string() };
+    ///
+    /// assert_eq!(host_len.get(&server), 9);
+    /// ```
+    pub fn map<B, F: Fn(&A) -> B>(self, f: F) -> GetterImpl<S, B, impl Getter<S, B>> {
+        composed_getter(self.0, mapped_getter(f).0)
+    }
+
+    /// Caches the focus of this `GetterImpl<S,A>`, so that reading it again from an equal source
+    /// returns a clone of the previous focus instead of evaluating `self` again.
+    ///
+    /// Only the focus of the last read is cached. This is useful for expensive derived getters,
+    /// such as ones parsing or aggregating the source, which are read repeatedly from a source
+    /// that rarely changes. Use [`GetterImpl::cached_by`] if comparing the sources is expensive.
+    ///
+    /// The cache is kept in a [`RefCell`](core::cell::RefCell), so the resulting getter is not
+    /// `Sync`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use optics::{HasTotalGetter, mapped_getter};
+    ///
+    /// let word_count = mapped_getter(|text: &String| text.split_whitespace().count()).cached();
+    ///
+    /// let text = "the quick brown fox".to_string();
+    /// assert_eq!(word_count.get(&text), 4);
+    /// assert_eq!(word_count.get(&text), 4); // not counted again
+    /// ```
+    pub fn cached(self) -> GetterImpl<S, A, impl Getter<S, A>>
+    where
+        S: PartialEq + Clone,
+        A: Clone,
+    {
+        cached(self.0)
+    }
+
+    /// Caches the focus of this `GetterImpl<S,A>` keyed by `key_fn`, so that reading it again from
+    /// a source with an equal key returns a clone of the previous focus instead of evaluating
+    /// `self` again.
+    ///
+    /// Only the focus of the last read is cached. The key is typically a version number or hash
+    /// of the source that is cheaper to compare than the source itself.
+    ///
+    /// # Parameters
+    ///
+    /// - `key_fn`: The function computing the key of a source.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use optics::{HasTotalGetter, mapped_getter};
+    ///
+    /// struct Document {
+    ///     revision: u64,
+    ///     text: String,
+    /// }
+    ///
+    /// let word_count = mapped_getter(|doc: &Document| doc.text.split_whitespace().count())
+    ///     .cached_by(|doc: &Document| doc.revision);
+    ///
+    /// let doc = Document { revision: 1, text: "the quick brown fox".to_string() };
+    /// assert_eq!(word_count.get(&doc), 4);
+    /// ```
+    pub fn cached_by<K: PartialEq, F: Fn(&S) -> K>(
+        self,
+        key_fn: F,
+    ) -> GetterImpl<S, A, impl Getter<S, A>>
+    where
+        A: Clone,
+    {
+        cached_by(self.0, key_fn)
+    }
+
+    /// Upgrades this `GetterImpl<S,A>` to a `LensImpl<S,A>` by attaching `set_fn` as the way to
+    /// write the focus, so the read half of a lens can be derived first and the write half added
+    /// later.
+    ///
+    /// # Parameters
+    ///
+    /// - `set_fn`: The function writing a new focus into the source.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use optics::{HasSetter, HasTotalGetter, mapped_getter};
+    ///
+    /// struct Server {
+    ///     port: u16,
+    /// }
+    ///
+    /// let port = mapped_getter(|s: &Server| s.port).with_setter(|s, port| s.port = port);
+    /// let mut server = Server { port: 80 };
+    ///
+    /// port.set(&mut server, 8080);
+    /// assert_eq!(port.get(&server), 8080);
+    /// ```
+    #[cfg(feature = "lens")]
+    pub fn with_setter<F: Fn(&mut S, A)>(self, set_fn: F) -> LensImpl<S, A, impl Lens<S, A>> {
+        with_setter(self.0, set_fn)
     }
 }
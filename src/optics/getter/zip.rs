@@ -0,0 +1,35 @@
+use crate::optics::getter::wrapper::GetterImpl;
+use crate::{Getter, HasGetter};
+use core::convert::Infallible;
+use core::marker::PhantomData;
+
+struct ZippedGetter<G1: Getter<S, A>, G2: Getter<S, B>, S, A, B> {
+    optic1: G1,
+    optic2: G2,
+    _phantom: PhantomData<(S, A, B)>,
+}
+
+impl<G1, G2, S, A, B> HasGetter<S, (A, B)> for ZippedGetter<G1, G2, S, A, B>
+where
+    G1: Getter<S, A>,
+    G2: Getter<S, B>,
+{
+    type GetterError = Infallible;
+
+    fn try_get(&self, source: &S) -> Result<(A, B), Self::GetterError> {
+        Ok((self.optic1.try_get(source)?, self.optic2.try_get(source)?))
+    }
+}
+
+/// Creates a `Getter<S,(A,B)>` focusing on the foci of both `g1` and `g2` as a tuple.
+pub(crate) fn new<S, A, B, G1: Getter<S, A>, G2: Getter<S, B>>(
+    g1: G1,
+    g2: G2,
+) -> GetterImpl<S, (A, B), impl Getter<S, (A, B)>> {
+    ZippedGetter {
+        optic1: g1,
+        optic2: g2,
+        _phantom: PhantomData,
+    }
+    .into()
+}
@@ -0,0 +1,53 @@
+use crate::{HasGetter, HasModify, HasReverseGet, HasSetter, IsoImpl};
+use alloc::boxed::Box;
+use core::convert::Infallible;
+
+/// An object safe version of [`Iso`](crate::Iso), implemented by every iso.
+///
+/// It can be used as a trait object, such as in a [`BoxedIso`], to store isos of different types in
+/// the same collection. The methods of the [`HasModify`](crate::HasModify) base trait are not
+/// object safe, so they are not part of it, but they are implemented by the boxed iso.
+pub trait DynIso<S, A>:
+    HasGetter<S, A, GetterError = Infallible>
+    + HasSetter<S, A>
+    + HasReverseGet<S, A, ReverseError = Infallible>
+{
+}
+
+impl<S, A, O> DynIso<S, A> for O where
+    O: HasGetter<S, A, GetterError = Infallible>
+        + HasSetter<S, A>
+        + HasReverseGet<S, A, ReverseError = Infallible>
+{
+}
+
+/// A boxed iso of any type, focusing on a value of type `A` in a source of type `S`.
+///
+/// Unlike the opaque isos returned by the constructors of this crate, boxed isos of different types
+/// have the same type, so they can be stored in collections or struct fields, and composed like any
+/// other iso. They are created with [`IsoImpl::boxed`].
+pub type BoxedIso<S, A> = IsoImpl<S, A, Box<dyn DynIso<S, A> + Send + Sync>>;
+
+impl<S, A> HasGetter<S, A> for Box<dyn DynIso<S, A> + Send + Sync> {
+    type GetterError = Infallible;
+
+    fn try_get(&self, source: &S) -> Result<A, Self::GetterError> {
+        (**self).try_get(source)
+    }
+}
+
+impl<S, A> HasSetter<S, A> for Box<dyn DynIso<S, A> + Send + Sync> {
+    fn set(&self, source: &mut S, value: A) {
+        (**self).set(source, value);
+    }
+}
+
+impl<S, A> HasReverseGet<S, A> for Box<dyn DynIso<S, A> + Send + Sync> {
+    type ReverseError = Infallible;
+
+    fn try_reverse_get(&self, value: &A) -> Result<S, Self::ReverseError> {
+        (**self).try_reverse_get(value)
+    }
+}
+
+impl<S, A> HasModify<S, A> for Box<dyn DynIso<S, A> + Send + Sync> {}
@@ -1,17 +1,81 @@
+use crate::HasPath;
 use crate::optics::iso::Iso;
 use crate::optics::iso::wrapper::IsoImpl;
-use crate::{HasGetter, HasReverseGet, HasSetter, HasTotalGetter, HasTotalReverseGet};
+use crate::{HasGetter, HasGetterRef, HasModify, HasReverseGet, HasSetter};
 use core::convert::Infallible;
+use core::fmt::{Debug, Display, Formatter};
 use core::marker::PhantomData;
 
-struct ComposedIso<ISO1, ISO2, S, I, A>
+mod private {
+    use crate::Iso;
+    use core::marker::PhantomData;
+
+    pub struct ComposedIso<ISO1, ISO2, S, I, A>
+    where
+        ISO1: Iso<S, I>,
+        ISO2: Iso<I, A>,
+    {
+        pub(super) optic1: ISO1,
+        pub(super) optic2: ISO2,
+        pub(super) _phantom: PhantomData<(S, I, A)>,
+    }
+}
+
+pub type ComposedIso<ISO1, ISO2, S, I, A> = private::ComposedIso<ISO1, ISO2, S, I, A>;
+
+impl<ISO1, ISO2, S, I, A> Clone for ComposedIso<ISO1, ISO2, S, I, A>
 where
-    ISO1: Iso<S, I>,
-    ISO2: Iso<I, A>,
+    ISO1: Iso<S, I> + Clone,
+    ISO2: Iso<I, A> + Clone,
+{
+    fn clone(&self) -> Self {
+        ComposedIso {
+            optic1: self.optic1.clone(),
+            optic2: self.optic2.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<ISO1, ISO2, S, I, A> Copy for ComposedIso<ISO1, ISO2, S, I, A>
+where
+    ISO1: Iso<S, I> + Copy,
+    ISO2: Iso<I, A> + Copy,
+{
+}
+
+impl<ISO1, ISO2, S, I, A> Debug for ComposedIso<ISO1, ISO2, S, I, A>
+where
+    ISO1: Iso<S, I> + Debug,
+    ISO2: Iso<I, A> + Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ComposedIso")
+            .field("optic1", &self.optic1)
+            .field("optic2", &self.optic2)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<ISO1, ISO2, S, I, A> Display for ComposedIso<ISO1, ISO2, S, I, A>
+where
+    ISO1: Iso<S, I> + Display,
+    ISO2: Iso<I, A> + Display,
 {
-    optic1: ISO1,
-    optic2: ISO2,
-    _phantom: PhantomData<(S, I, A)>,
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}.{}", self.optic1, self.optic2)
+    }
+}
+
+impl<ISO1, ISO2, S, I, A> HasPath for ComposedIso<ISO1, ISO2, S, I, A>
+where
+    ISO1: Iso<S, I> + HasPath,
+    ISO2: Iso<I, A> + HasPath,
+{
+    fn for_each_segment<'a>(&'a self, f: &mut dyn FnMut(&'a str)) {
+        self.optic1.for_each_segment(f);
+        self.optic2.for_each_segment(f);
+    }
 }
 
 impl<ISO1, ISO2, S, I, A> ComposedIso<ISO1, ISO2, S, I, A>
@@ -35,21 +99,47 @@ where
 {
     type GetterError = Infallible;
 
+    #[inline]
     fn try_get(&self, source: &S) -> Result<A, Self::GetterError> {
         let i = self.optic1.try_get(source)?;
         self.optic2.try_get(&i)
     }
 }
 
+impl<ISO1, ISO2, S, I, A> HasGetterRef<S, A> for ComposedIso<ISO1, ISO2, S, I, A>
+where
+    ISO1: Iso<S, I> + HasGetterRef<S, I>,
+    ISO2: Iso<I, A> + HasGetterRef<I, A>,
+{
+    #[inline]
+    fn try_get_ref<'a>(&self, source: &'a S) -> Result<&'a A, Self::GetterError>
+    where
+        Self: 'a,
+    {
+        let i = self.optic1.try_get_ref(source)?;
+        self.optic2.try_get_ref(i)
+    }
+}
+
 impl<ISO1, ISO2, S, I, A> HasSetter<S, A> for ComposedIso<ISO1, ISO2, S, I, A>
 where
     ISO1: Iso<S, I>,
     ISO2: Iso<I, A>,
 {
+    #[inline]
     fn set(&self, source: &mut S, value: A) {
-        let mut i = self.optic1.get(source);
-        self.optic2.set(&mut i, value);
-        self.optic1.set(source, i);
+        self.optic1.modify(source, |i| self.optic2.set(i, value));
+    }
+}
+
+impl<ISO1, ISO2, S, I, A> HasModify<S, A> for ComposedIso<ISO1, ISO2, S, I, A>
+where
+    ISO1: Iso<S, I>,
+    ISO2: Iso<I, A>,
+{
+    #[inline]
+    fn modify<F: FnOnce(&mut A)>(&self, source: &mut S, f: F) {
+        self.optic1.modify(source, |i| self.optic2.modify(i, f));
     }
 }
 
@@ -60,8 +150,10 @@ where
 {
     type ReverseError = Infallible;
 
+    #[inline]
     fn try_reverse_get(&self, value: &A) -> Result<S, Self::ReverseError> {
-        Ok(self.reverse_get(value))
+        let i = self.optic2.try_reverse_get(value)?;
+        self.optic1.try_reverse_get(&i)
     }
 }
 
@@ -92,8 +184,6 @@ where
 pub fn new<S, A, I, ISO1: Iso<S, I>, ISO2: Iso<I, A>>(
     i1: ISO1,
     i2: ISO2,
-) -> IsoImpl<S, A, impl Iso<S, A>>
-where
-{
+) -> IsoImpl<S, A, ComposedIso<ISO1, ISO2, S, I, A>> {
     ComposedIso::new(i1, i2).into()
 }
@@ -0,0 +1,53 @@
+use crate::optics::iso::wrapper::IsoImpl;
+use crate::{
+    HasGetter, HasModify, HasReverseGet, HasSetter, HasTotalGetter, HasTotalReverseGet, Iso,
+};
+use core::convert::Infallible;
+use core::marker::PhantomData;
+
+struct InvertedIso<ISO: Iso<S, A>, S, A> {
+    optic: ISO,
+    _phantom: PhantomData<(S, A)>,
+}
+
+impl<ISO, S, A> HasGetter<A, S> for InvertedIso<ISO, S, A>
+where
+    ISO: Iso<S, A>,
+{
+    type GetterError = Infallible;
+
+    fn try_get(&self, source: &A) -> Result<S, Self::GetterError> {
+        Ok(self.optic.reverse_get(source))
+    }
+}
+
+impl<ISO, S, A> HasSetter<A, S> for InvertedIso<ISO, S, A>
+where
+    ISO: Iso<S, A>,
+{
+    fn set(&self, source: &mut A, value: S) {
+        *source = self.optic.get(&value);
+    }
+}
+
+impl<ISO, S, A> HasModify<A, S> for InvertedIso<ISO, S, A> where ISO: Iso<S, A> {}
+
+impl<ISO, S, A> HasReverseGet<A, S> for InvertedIso<ISO, S, A>
+where
+    ISO: Iso<S, A>,
+{
+    type ReverseError = Infallible;
+
+    fn try_reverse_get(&self, value: &S) -> Result<A, Self::ReverseError> {
+        Ok(self.optic.get(value))
+    }
+}
+
+/// Creates an `Iso<A,S>` that converts in the opposite direction of `iso`.
+pub(crate) fn new<S, A, ISO: Iso<S, A>>(iso: ISO) -> IsoImpl<A, S, impl Iso<A, S>> {
+    InvertedIso {
+        optic: iso,
+        _phantom: PhantomData,
+    }
+    .into()
+}
@@ -1,17 +1,54 @@
-use crate::optics::iso::Iso;
 use crate::optics::iso::wrapper::IsoImpl;
-use crate::{HasGetter, HasReverseGet, HasSetter, HasTotalReverseGet};
+use crate::{HasGetter, HasModify, HasReverseGet, HasSetter, HasTotalReverseGet};
 use core::convert::Infallible;
+use core::fmt::{Debug, Formatter};
 use core::marker::PhantomData;
 
-struct MappedIso<S, A, GET = fn(&S) -> A, REV = fn(&A) -> S>
+mod private {
+    use core::marker::PhantomData;
+
+    pub struct MappedIso<S, A, GET = fn(&S) -> A, REV = fn(&A) -> S>
+    where
+        GET: Fn(&S) -> A,
+        REV: Fn(&A) -> S,
+    {
+        pub(super) get_fn: GET,
+        pub(super) rev_fn: REV,
+        pub(super) phantom: PhantomData<(S, A)>,
+    }
+}
+
+pub type MappedIso<S, A, GET = fn(&S) -> A, REV = fn(&A) -> S> = private::MappedIso<S, A, GET, REV>;
+
+impl<S, A, GET, REV> Clone for MappedIso<S, A, GET, REV>
+where
+    GET: Fn(&S) -> A + Clone,
+    REV: Fn(&A) -> S + Clone,
+{
+    fn clone(&self) -> Self {
+        MappedIso {
+            get_fn: self.get_fn.clone(),
+            rev_fn: self.rev_fn.clone(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<S, A, GET, REV> Copy for MappedIso<S, A, GET, REV>
+where
+    GET: Fn(&S) -> A + Copy,
+    REV: Fn(&A) -> S + Copy,
+{
+}
+
+impl<S, A, GET, REV> Debug for MappedIso<S, A, GET, REV>
 where
     GET: Fn(&S) -> A,
     REV: Fn(&A) -> S,
 {
-    get_fn: GET,
-    rev_fn: REV,
-    phantom: PhantomData<(S, A)>,
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("MappedIso").finish_non_exhaustive()
+    }
 }
 
 impl<S, A, GET, REV> MappedIso<S, A, GET, REV>
@@ -19,7 +56,7 @@ where
     GET: Fn(&S) -> A,
     REV: Fn(&A) -> S,
 {
-    fn new(get_fn: GET, rev_fn: REV) -> Self {
+    const fn new(get_fn: GET, rev_fn: REV) -> Self {
         MappedIso {
             get_fn,
             rev_fn,
@@ -50,6 +87,13 @@ where
     }
 }
 
+impl<S, A, GET, REV> HasModify<S, A> for MappedIso<S, A, GET, REV>
+where
+    GET: Fn(&S) -> A,
+    REV: Fn(&A) -> S,
+{
+}
+
 impl<S, A, GET, REV> HasReverseGet<S, A> for MappedIso<S, A, GET, REV>
 where
     GET: Fn(&S) -> A,
@@ -121,10 +165,13 @@ where
 /// assert_eq!(cartesan, Cartesan { x: 6.0, y: 8.0 });
 /// ```
 #[must_use]
-pub fn new<S, A, GET, REV>(get_fn: GET, rev_fn: REV) -> IsoImpl<S, A, impl Iso<S, A>>
+pub const fn new<S, A, GET, REV>(
+    get_fn: GET,
+    rev_fn: REV,
+) -> IsoImpl<S, A, MappedIso<S, A, GET, REV>>
 where
     GET: Fn(&S) -> A,
     REV: Fn(&A) -> S,
 {
-    MappedIso::new(get_fn, rev_fn).into()
+    IsoImpl::new(MappedIso::new(get_fn, rev_fn))
 }
@@ -1,14 +1,38 @@
-use crate::{HasGetter, HasReverseGet, HasSetter};
+use crate::{HasGetter, HasModify, HasReverseGet, HasSetter};
 use core::convert::Infallible;
 
+#[cfg(all(feature = "alloc", feature = "iso"))]
+mod boxed;
+#[cfg(feature = "iso")]
 mod composed;
+#[cfg(feature = "iso")]
+mod inverted;
+#[cfg(feature = "iso")]
 mod mapped;
+#[cfg(all(feature = "alloc", feature = "iso"))]
+mod shared;
+#[cfg(feature = "iso")]
 mod wrapper;
 
+#[cfg(all(feature = "alloc", feature = "iso"))]
+pub use boxed::{BoxedIso, DynIso};
+#[cfg(feature = "iso")]
+pub(crate) use composed::ComposedIso;
+#[cfg(feature = "iso")]
 pub use composed::new as composed_iso;
+#[cfg(feature = "iso")]
 pub use mapped::new as mapped_iso;
+#[cfg(all(feature = "alloc", feature = "iso"))]
+pub use shared::SharedIso;
+#[cfg(feature = "iso")]
 pub use wrapper::IsoImpl;
 
+/// An [`IsoImpl`] built by [`mapped_iso`] from plain functions or non-capturing closures.
+///
+/// As [`mapped_iso`] is a `const fn`, this type can be used to declare the optic as a `static`.
+#[cfg(feature = "iso")]
+pub type FnIso<S, A> = IsoImpl<S, A, mapped::MappedIso<S, A>>;
+
 /// An `Iso` defines an isomorphism between two type, which is a bijective, reversible conversion between the members of two types.
 ///
 /// It provides:
@@ -33,6 +57,7 @@ pub use wrapper::IsoImpl;
 pub trait Iso<S, A>:
     HasGetter<S, A, GetterError = Infallible>
     + HasSetter<S, A>
+    + HasModify<S, A>
     + HasReverseGet<S, A, ReverseError = Infallible>
 {
 }
@@ -42,6 +67,7 @@ impl<
     A,
     ISO: HasGetter<S, A, GetterError = Infallible>
         + HasSetter<S, A>
+        + HasModify<S, A>
         + HasReverseGet<S, A, ReverseError = Infallible>,
 > Iso<S, A> for ISO
 {
@@ -78,7 +104,103 @@ impl<
 /// # See Also
 ///
 /// - [`mapped_iso`] for constructing custom `Iso`s from arbitrary mapping functions.
+#[cfg(feature = "iso")]
 #[must_use]
 pub fn identity_iso<S: Clone>() -> IsoImpl<S, S, impl Iso<S, S>> {
     mapped_iso(|x: &S| x.clone(), |x: &S| x.clone())
 }
+
+/// Generates an iso between a newtype wrapper and the type it wraps.
+///
+/// This macro expands to a call to `mapped_iso` with:
+/// - a getter closure that clones the wrapped value out of the newtype,
+/// - a reverse closure that wraps a clone of the value into the newtype.
+///
+/// # Syntax
+///
+/// ```ignore
+/// newtype_iso!(WrapperType, InnerType)
+/// newtype_iso!(WrapperType, InnerType, from)
+/// ```
+///
+/// - `WrapperType`: A single-field tuple struct, such as `struct Port(u16)`.
+/// - `InnerType`: The type of its only field.
+/// - `from`: Instead of accessing the field directly, convert between the two types using their
+///   `From` implementations. This is useful when the field of the wrapper is not accessible, but
+///   it implements `From<InnerType>` and `InnerType` implements `From<WrapperType>`.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{newtype_iso, HasSetter, HasTotalGetter, HasTotalReverseGet};
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// struct Port(u16);
+///
+/// let port_iso = newtype_iso!(Port, u16);
+///
+/// let mut port = Port(8080);
+/// assert_eq!(port_iso.get(&port), 8080);
+/// assert_eq!(port_iso.reverse_get(&443), Port(443));
+///
+/// port_iso.set(&mut port, 8081);
+/// assert_eq!(port, Port(8081));
+/// ```
+///
+/// Using the `From` implementations:
+///
+/// ```rust
+/// use optics::{newtype_iso, HasTotalGetter, HasTotalReverseGet};
+///
+/// mod opaque {
+///     #[derive(Debug, Clone, PartialEq)]
+///     pub struct Port(u16);
+///
+///     impl From<u16> for Port {
+///         fn from(value: u16) -> Self { Port(value) }
+///     }
+///
+///     impl From<Port> for u16 {
+///         fn from(value: Port) -> Self { value.0 }
+///     }
+/// }
+///
+/// let port_iso = newtype_iso!(opaque::Port, u16, from);
+/// assert_eq!(port_iso.get(&opaque::Port::from(8080)), 8080);
+/// assert_eq!(port_iso.reverse_get(&443), opaque::Port::from(443));
+/// ```
+///
+/// # Notes
+///
+/// - Values are cloned in both directions; therefore, both types must implement `Clone`.
+/// - Without `from`, the field of the wrapper must be accessible (e.g., public or within the same module).
+#[cfg(feature = "iso")]
+#[macro_export]
+macro_rules! newtype_iso {
+    ($wrapper:ty, $inner:ty $(,)?) => {
+        $crate::mapped_iso::<$wrapper, $inner, _, _>(
+            |input: &$wrapper| ::core::clone::Clone::clone(&input.0),
+            |value: &$inner| {
+                type Wrapper = $wrapper;
+                Wrapper {
+                    0: ::core::clone::Clone::clone(value),
+                }
+            },
+        )
+    };
+
+    ($wrapper:ty, $inner:ty, from $(,)?) => {
+        $crate::mapped_iso::<$wrapper, $inner, _, _>(
+            |input: &$wrapper| {
+                <$inner as ::core::convert::From<$wrapper>>::from(::core::clone::Clone::clone(
+                    input,
+                ))
+            },
+            |value: &$inner| {
+                <$wrapper as ::core::convert::From<$inner>>::from(::core::clone::Clone::clone(
+                    value,
+                ))
+            },
+        )
+    };
+}
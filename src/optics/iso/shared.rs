@@ -0,0 +1,34 @@
+use crate::{DynIso, HasGetter, HasModify, HasReverseGet, HasSetter, IsoImpl};
+use alloc::sync::Arc;
+use core::convert::Infallible;
+
+/// A shared iso of any type, focusing on a value of type `A` in a source of type `S`.
+///
+/// Like [boxed isos](crate::BoxedIso), shared isos of different types have the same type, but they
+/// are reference counted, so they can be cloned cheaply, stored in registries and sent to other
+/// threads. They are created with [`IsoImpl::shared`](crate::IsoImpl::shared).
+pub type SharedIso<S, A> = IsoImpl<S, A, Arc<dyn DynIso<S, A> + Send + Sync>>;
+
+impl<S, A> HasGetter<S, A> for Arc<dyn DynIso<S, A> + Send + Sync> {
+    type GetterError = Infallible;
+
+    fn try_get(&self, source: &S) -> Result<A, Self::GetterError> {
+        (**self).try_get(source)
+    }
+}
+
+impl<S, A> HasSetter<S, A> for Arc<dyn DynIso<S, A> + Send + Sync> {
+    fn set(&self, source: &mut S, value: A) {
+        (**self).set(source, value);
+    }
+}
+
+impl<S, A> HasReverseGet<S, A> for Arc<dyn DynIso<S, A> + Send + Sync> {
+    type ReverseError = Infallible;
+
+    fn try_reverse_get(&self, value: &A) -> Result<S, Self::ReverseError> {
+        (**self).try_reverse_get(value)
+    }
+}
+
+impl<S, A> HasModify<S, A> for Arc<dyn DynIso<S, A> + Send + Sync> {}
@@ -1,11 +1,43 @@
+use crate::HasPath;
+use crate::named::Named;
+use crate::optics::fallible_setter::ComposedFallibleSetter;
+use crate::optics::getter::ComposedGetter;
+use crate::optics::iso::ComposedIso;
+use crate::optics::iso::inverted::new as invert;
+use crate::optics::lens::ComposedLens;
+use crate::optics::partial_getter::{ComposedPartialGetter, ReadOnly};
+use crate::optics::setter::{ComposedSetter, WriteOnly};
+use crate::optics::traversal::{ComposedTraversal, SingleTraversal, single_traversal};
+#[cfg(feature = "tracing")]
+use crate::traced::Traced;
 use crate::{
-    FallibleIso, FallibleIsoImpl, Getter, GetterImpl, HasGetter, HasReverseGet, HasSetter,
-    HasTotalGetter, HasTotalReverseGet, Iso, Lens, LensImpl, PartialGetter, PartialGetterImpl,
-    Prism, PrismImpl, Setter, SetterImpl, composed_fallible_iso, composed_getter, composed_iso,
-    composed_lens, composed_partial_getter, composed_prism, composed_setter, infallible,
+    FallibleSetter, FallibleSetterImpl, Getter, GetterImpl, HasGetter, HasGetterRef, HasModify,
+    HasReverseGet, HasSetter, HasTotalGetter, HasTotalReverseGet, Iso, Lens, LensImpl,
+    PartialGetter, PartialGetterImpl, Setter, SetterImpl, Traversal, TraversalImpl,
+    composed_fallible_setter, composed_getter, composed_iso, composed_partial_getter,
+    composed_setter, composed_traversal, infallible, mapped_iso,
 };
 use core::convert::{Infallible, identity};
+use core::fmt::{Debug, Display, Formatter};
 use core::marker::PhantomData;
+#[cfg(feature = "fallible-iso")]
+use {
+    crate::optics::fallible_iso::ComposedFallibleIso,
+    crate::{FallibleIso, FallibleIsoImpl, composed_fallible_iso},
+};
+#[cfg(feature = "alloc")]
+use {
+    crate::optics::iso::{BoxedIso, DynIso, SharedIso},
+    alloc::boxed::Box,
+    alloc::string::{String, ToString},
+    alloc::sync::Arc,
+    alloc::vec::Vec,
+};
+#[cfg(feature = "prism")]
+use {
+    crate::optics::prism::ComposedPrism,
+    crate::{Prism, PrismImpl, composed_prism},
+};
 
 /// A wrapper of the [`Iso`] optic implementations, encapsulating a reversible bijective conversion.
 ///
@@ -31,7 +63,7 @@ use core::marker::PhantomData;
 pub struct IsoImpl<S, A, ISO: Iso<S, A>>(pub ISO, PhantomData<(S, A)>);
 
 impl<S, A, ISO: Iso<S, A>> IsoImpl<S, A, ISO> {
-    fn new(i: ISO) -> Self {
+    pub(crate) const fn new(i: ISO) -> Self {
         //TODO: Verify not to nest an Impl inside an Impl - currently seems to be impossible at compile time.
         IsoImpl(i, PhantomData)
     }
@@ -43,23 +75,158 @@ impl<S, A, ISO: Iso<S, A>> From<ISO> for IsoImpl<S, A, ISO> {
     }
 }
 
+impl<S, A, ISO: Iso<S, A> + Clone> Clone for IsoImpl<S, A, ISO> {
+    fn clone(&self) -> Self {
+        Self::new(self.0.clone())
+    }
+}
+
+impl<S, A, ISO: Iso<S, A> + Copy> Copy for IsoImpl<S, A, ISO> {}
+
+impl<S, A, ISO: Iso<S, A> + Debug> Debug for IsoImpl<S, A, ISO> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("IsoImpl").field(&self.0).finish()
+    }
+}
+
+impl<S, A, ISO: Iso<S, A> + Display> Display for IsoImpl<S, A, ISO> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl<S, A, ISO: Iso<S, A> + HasPath> HasPath for IsoImpl<S, A, ISO> {
+    fn for_each_segment<'a>(&'a self, f: &mut dyn FnMut(&'a str)) {
+        self.0.for_each_segment(f);
+    }
+}
+
+impl<S, A, ISO: Iso<S, A>> IsoImpl<S, A, ISO> {
+    /// Labels this optic with a static `name`, used by its [`Display`] implementation.
+    ///
+    /// Composing named optics results in an optic displaying the names of its parts joined with a
+    /// `.`, which helps to identify a misbehaving optic in logs and error messages.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The name of the optic.
+    ///
+    /// # Returns
+    ///
+    /// A new `IsoImpl` behaving exactly like `self`, displayed as `name`.
+    #[must_use]
+    pub fn named(self, name: &'static str) -> IsoImpl<S, A, Named<ISO>> {
+        Named::new(self.0, name).into()
+    }
+
+    /// Wraps this optic to emit a `tracing` event for every operation going through it, with
+    /// `name` identifying the optic, whether the operation succeeded and the time it took.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The name of the optic in the events, such as `"config.port"`.
+    ///
+    /// # Returns
+    ///
+    /// A new `IsoImpl` behaving exactly like `self`, tracing its operations.
+    #[cfg(feature = "tracing")]
+    #[must_use]
+    pub fn traced(self, name: &'static str) -> IsoImpl<S, A, Traced<ISO>> {
+        Traced::new(self.0, name).into()
+    }
+
+    /// Returns the name of the optic, the names of the optics it is composed of joined with a `.`.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn name(&self) -> String
+    where
+        ISO: Display,
+    {
+        self.to_string()
+    }
+
+    /// Returns the names of the fields and variants this optic focuses through, from the outermost
+    /// one, such as the field names of the [`field_lens!`](crate::field_lens) lenses it is
+    /// composed of.
+    #[cfg(feature = "alloc")]
+    pub fn path(&self) -> impl Iterator<Item = &str>
+    where
+        ISO: HasPath,
+    {
+        let mut path = Vec::new();
+        self.0.for_each_segment(&mut |segment| path.push(segment));
+        path.into_iter()
+    }
+
+    /// Boxes this iso, erasing its type, so it can be stored alongside isos of other types.
+    ///
+    /// # Returns
+    ///
+    /// A [`BoxedIso`] behaving exactly like `self`.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn boxed(self) -> BoxedIso<S, A>
+    where
+        ISO: Send + Sync + 'static,
+    {
+        let optic: Box<dyn DynIso<S, A> + Send + Sync> = Box::new(self.0);
+        optic.into()
+    }
+
+    /// Wraps this iso in an [`Arc`], erasing its type, so it can be cloned cheaply and shared
+    /// between threads.
+    ///
+    /// # Returns
+    ///
+    /// A [`SharedIso`] behaving exactly like `self`.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn shared(self) -> SharedIso<S, A>
+    where
+        ISO: Send + Sync + 'static,
+    {
+        let optic: Arc<dyn DynIso<S, A> + Send + Sync> = Arc::new(self.0);
+        optic.into()
+    }
+}
+
 impl<S, A, ISO: Iso<S, A>> HasGetter<S, A> for IsoImpl<S, A, ISO> {
     type GetterError = Infallible;
 
+    #[inline]
     fn try_get(&self, source: &S) -> Result<A, Self::GetterError> {
         Ok(self.0.get(source))
     }
 }
 
+impl<S, A, ISO: Iso<S, A> + HasGetterRef<S, A>> HasGetterRef<S, A> for IsoImpl<S, A, ISO> {
+    #[inline]
+    fn try_get_ref<'a>(&self, source: &'a S) -> Result<&'a A, Self::GetterError>
+    where
+        Self: 'a,
+    {
+        self.0.try_get_ref(source)
+    }
+}
+
 impl<S, A, ISO: Iso<S, A>> HasSetter<S, A> for IsoImpl<S, A, ISO> {
+    #[inline]
     fn set(&self, source: &mut S, value: A) {
         self.0.set(source, value);
     }
 }
 
+impl<S, A, ISO: Iso<S, A>> HasModify<S, A> for IsoImpl<S, A, ISO> {
+    #[inline]
+    fn modify<F: FnOnce(&mut A)>(&self, source: &mut S, f: F) {
+        self.0.modify(source, f);
+    }
+}
+
 impl<S, A, ISO: Iso<S, A>> HasReverseGet<S, A> for IsoImpl<S, A, ISO> {
     type ReverseError = Infallible;
 
+    #[inline]
     fn try_reverse_get(&self, value: &A) -> Result<S, Self::ReverseError> {
         Ok(self.0.reverse_get(value))
     }
@@ -85,11 +252,12 @@ impl<S, I, ISO1: Iso<S, I>> IsoImpl<S, I, ISO1> {
     ///
     /// A new `PartialGetterImpl` that represents the composition of `self` and `other`.
     ///
+    #[allow(clippy::type_complexity)]
     pub fn compose_with_partial_getter<A, PG2: PartialGetter<I, A>>(
         self,
-        other: PartialGetterImpl<I, A, PG2>,
-    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A, GetterError = PG2::GetterError>> {
-        composed_partial_getter(self.0, other.0, infallible, identity)
+        other: PG2,
+    ) -> PartialGetterImpl<S, A, ComposedPartialGetter<ISO1, PG2, PG2::GetterError, S, I, A>> {
+        composed_partial_getter(self.0, other, infallible, identity)
     }
 
     /// Composes this `IsoImpl<S,I>` with a `GetterImpl<I,A>`, resulting in a new `GetterImpl<S, A>`
@@ -113,9 +281,9 @@ impl<S, I, ISO1: Iso<S, I>> IsoImpl<S, I, ISO1> {
     ///
     pub fn compose_with_getter<A, G2: Getter<I, A>>(
         self,
-        other: GetterImpl<I, A, G2>,
-    ) -> GetterImpl<S, A, impl Getter<S, A>> {
-        composed_getter(self.0, other.0)
+        other: G2,
+    ) -> GetterImpl<S, A, ComposedGetter<ISO1, G2, S, I, A>> {
+        composed_getter(self.0, other)
     }
 
     /// Composes this `IsoImpl<S,I>` with a `Setter<I,A>`, resulting in a new `Setter<S, A>`
@@ -139,9 +307,37 @@ impl<S, I, ISO1: Iso<S, I>> IsoImpl<S, I, ISO1> {
     ///
     pub fn compose_with_setter<A, S2: Setter<I, A>>(
         self,
-        other: SetterImpl<I, A, S2>,
-    ) -> SetterImpl<S, A, impl Setter<S, A>> {
-        composed_setter(self.0, other.0)
+        other: S2,
+    ) -> SetterImpl<S, A, ComposedSetter<ISO1, S2, S, I, A>> {
+        composed_setter(self.0, other)
+    }
+
+    /// Composes this `IsoImpl<S,I>` with a `FallibleSetter<I,A>`, resulting in a new `FallibleSetter<S, A>`
+    /// that focuses through both optics sequentially.
+    ///
+    /// The resulting `FallibleSetterImpl` will set the value by first applying `self` and then
+    /// `other`, failing if `other` rejects the value.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `A`: The target type of the composed optic.
+    /// - `FS2`: The type of the fallible setter to compose with.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: The fallible setter to compose with.
+    ///
+    /// # Returns
+    ///
+    /// A new `FallibleSetterImpl` that represents the composition of `self` and `other`.
+    ///
+    #[allow(clippy::type_complexity)]
+    pub fn compose_with_fallible_setter<A, FS2: FallibleSetter<I, A>>(
+        self,
+        other: FS2,
+    ) -> FallibleSetterImpl<S, A, ComposedFallibleSetter<ISO1, FS2, FS2::SetterError, S, I, A>>
+    {
+        composed_fallible_setter(self.0, other, infallible, identity)
     }
 
     /// Composes this `IsoImpl<S,I>` with a `LensImpl<I,A>`, resulting in a new `LensImpl<S, A>`
@@ -164,9 +360,9 @@ impl<S, I, ISO1: Iso<S, I>> IsoImpl<S, I, ISO1> {
     /// A new `LensImpl` that represents the composition of `self` and `other`
     pub fn compose_with_lens<A, L2: Lens<I, A>>(
         self,
-        other: LensImpl<I, A, L2>,
-    ) -> LensImpl<S, A, impl Lens<S, A>> {
-        composed_lens(self.0, other.0)
+        other: L2,
+    ) -> LensImpl<S, A, ComposedLens<ISO1, L2, S, I, A>> {
+        ComposedLens::new(self.0, other).into()
     }
 
     /// Composes this `IsoImpl<S,I>` with a `Prism<I,A>`, resulting in a new `PrismImpl<S, A>`
@@ -187,11 +383,13 @@ impl<S, I, ISO1: Iso<S, I>> IsoImpl<S, I, ISO1> {
     /// # Returns
     ///
     /// A new `PrismImpl` that represents the composition of `self` and `other`.
+    #[cfg(feature = "prism")]
+    #[allow(clippy::type_complexity)]
     pub fn compose_with_prism<A, P2: Prism<I, A>>(
         self,
-        other: PrismImpl<I, A, P2>,
-    ) -> PrismImpl<S, A, impl Prism<S, A, GetterError = P2::GetterError>> {
-        composed_prism(self.0, other.0, infallible, identity)
+        other: P2,
+    ) -> PrismImpl<S, A, ComposedPrism<ISO1, P2, P2::GetterError, S, I, A>> {
+        composed_prism(self.0, other, infallible, identity)
     }
 
     /// Composes this `IsoImpl<S,I>` with a `FallibleIso<I,A>`, resulting in a new `FallibleIsoImpl<S, A>`
@@ -212,15 +410,17 @@ impl<S, I, ISO1: Iso<S, I>> IsoImpl<S, I, ISO1> {
     /// # Returns
     ///
     /// A new `FallibleIsoImpl` that represents the composition of `self` and `other`.
+    #[cfg(feature = "fallible-iso")]
+    #[allow(clippy::type_complexity)]
     pub fn compose_with_fallible_iso<A, FI2: FallibleIso<I, A>>(
         self,
-        other: FallibleIsoImpl<I, A, FI2>,
+        other: FI2,
     ) -> FallibleIsoImpl<
         S,
         A,
-        impl FallibleIso<S, A, GetterError = FI2::GetterError, ReverseError = FI2::ReverseError>,
+        ComposedFallibleIso<S, I, A, FI2::GetterError, FI2::ReverseError, ISO1, FI2>,
     > {
-        composed_fallible_iso(self.0, other.0, infallible, identity, infallible, identity)
+        composed_fallible_iso(self.0, other, infallible, identity, infallible, identity)
     }
 
     /// Composes this `IsoImpl<S,I>` with an `IsoImpl<I,A>`, resulting in a new `IsoImpl<S, A>`
@@ -243,8 +443,173 @@ impl<S, I, ISO1: Iso<S, I>> IsoImpl<S, I, ISO1> {
     /// A new `IsoImpl` that represents the composition of `self` and `other`
     pub fn compose_with_iso<A, ISO2: Iso<I, A>>(
         self,
-        other: IsoImpl<I, A, ISO2>,
-    ) -> IsoImpl<S, A, impl Iso<S, A>> {
-        composed_iso(self.0, other.0)
+        other: ISO2,
+    ) -> IsoImpl<S, A, ComposedIso<ISO1, ISO2, S, I, A>> {
+        ComposedIso::new(self.0, other).into()
+    }
+
+    /// Composes this `IsoImpl<S,I>` with a `Traversal<I,A>`, resulting in a new `TraversalImpl<S, A>`
+    /// that focuses through both optics sequentially.
+    ///
+    /// The resulting `TraversalImpl` will visit every value focused on by `other` in the value
+    /// focused on by `self`.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `A`: The target type of the composed traversal.
+    /// - `T2`: The type of the traversal to compose with.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: The traversal to compose with.
+    ///
+    /// # Returns
+    ///
+    /// A new `TraversalImpl` that represents the composition of `self` and `other`.
+    #[allow(clippy::type_complexity)]
+    pub fn compose_with_traversal<A, T2: Traversal<I, A>>(
+        self,
+        other: T2,
+    ) -> TraversalImpl<S, A, ComposedTraversal<SingleTraversal<S, I, ISO1>, T2, S, I, A>> {
+        composed_traversal(single_traversal(self.0), other)
+    }
+}
+
+impl<S, A, ISO1: Iso<S, A>> IsoImpl<S, A, ISO1> {
+    /// Adapts both sides of this `IsoImpl<S,A>` with plain functions, resulting in a new
+    /// `IsoImpl<T,B>`.
+    ///
+    /// `pre` and `pre_reverse` convert between the new source type `T` and the source type `S`
+    /// of `self`, while `post` and `post_reverse` convert between the focus type `A` of `self` and
+    /// the new focus type `B`. Both pairs need to be inverses of each other.
+    ///
+    /// # Parameters
+    ///
+    /// - `pre`: Converts the new source type into the source type of `self`.
+    /// - `pre_reverse`: Converts the source type of `self` back into the new source type.
+    /// - `post`: Converts the focus of `self` into the new focus type.
+    /// - `post_reverse`: Converts the new focus type back into the focus of `self`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use optics::{HasTotalGetter, HasTotalReverseGet, mapped_iso};
+    ///
+    /// let celsius_fahrenheit = mapped_iso(|c: &f64| c * 1.8 + 32.0, |f: &f64| (f - 32.0) / 1.8);
+    /// let tenths = celsius_fahrenheit.dimap(
+    ///     |tenths: &i32| f64::from(*tenths) / 10.0,
+    ///     |c: &f64| (c * 10.0).round() as i32,
+    ///     |f: &f64| f.round() as i64,
+    ///     |f: &i64| *f as f64,
+    /// );
+    ///
+    /// assert_eq!(tenths.get(&370), 99);
+    /// assert_eq!(tenths.reverse_get(&212), 1000);
+    /// ```
+    pub fn dimap<T, B, PRE, PREREV, POST, POSTREV>(
+        self,
+        pre: PRE,
+        pre_reverse: PREREV,
+        post: POST,
+        post_reverse: POSTREV,
+    ) -> IsoImpl<T, B, impl Iso<T, B>>
+    where
+        PRE: Fn(&T) -> S,
+        PREREV: Fn(&S) -> T,
+        POST: Fn(&A) -> B,
+        POSTREV: Fn(&B) -> A,
+    {
+        composed_iso(
+            composed_iso(mapped_iso(pre, pre_reverse).0, self.0),
+            mapped_iso(post, post_reverse).0,
+        )
+    }
+
+    /// Inverts this `IsoImpl<S,A>`, resulting in a new `IsoImpl<A,S>` that converts in the
+    /// opposite direction.
+    ///
+    /// The `get` of the resulting iso is the `reverse_get` of `self`, and vice versa.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use optics::{HasTotalGetter, HasTotalReverseGet, mapped_iso};
+    ///
+    /// let celsius_to_fahrenheit = mapped_iso(|c: &f64| c * 1.8 + 32.0, |f: &f64| (f - 32.0) / 1.8);
+    /// let fahrenheit_to_celsius = celsius_to_fahrenheit.invert();
+    ///
+    /// assert_eq!(fahrenheit_to_celsius.get(&212.0), 100.0);
+    /// assert_eq!(fahrenheit_to_celsius.reverse_get(&0.0), 32.0);
+    /// ```
+    pub fn invert(self) -> IsoImpl<A, S, impl Iso<A, S>> {
+        invert(self.0)
+    }
+}
+
+#[allow(clippy::wrong_self_convention)]
+impl<S, A, ISO: Iso<S, A>> IsoImpl<S, A, ISO> {
+    /// Downgrades this `IsoImpl<S,A>` to a read-only `GetterImpl<S,A>`, exposing only the
+    /// capabilities of a [`Getter`].
+    ///
+    /// The underlying optic is wrapped so that only its read operations can be reached, not even
+    /// through the `.0` field of the result, so APIs can accept exactly the access level they need.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use optics::{HasTotalGetter, mapped_iso};
+    ///
+    /// let fahrenheit = mapped_iso(|c: &f64| c * 1.8 + 32.0, |f: &f64| (f - 32.0) / 1.8).as_getter();
+    ///
+    /// assert_eq!(fahrenheit.get(&100.0), 212.0);
+    /// ```
+    #[must_use]
+    pub fn as_getter(self) -> GetterImpl<S, A, ReadOnly<ISO>> {
+        ReadOnly::new(self.0).into()
+    }
+
+    /// Downgrades this `IsoImpl<S,A>` to a read-only `PartialGetterImpl<S,A>`, exposing only the
+    /// capabilities of a [`PartialGetter`].
+    ///
+    /// The underlying optic is wrapped so that only its read operations can be reached, not even
+    /// through the `.0` field of the result, so APIs can accept exactly the access level they need.
+    ///
+    /// Retrieving the focus never fails, the error type of the resulting optic is [`Infallible`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use optics::{HasGetter, mapped_iso};
+    ///
+    /// let fahrenheit =
+    ///     mapped_iso(|c: &f64| c * 1.8 + 32.0, |f: &f64| (f - 32.0) / 1.8).as_partial_getter();
+    ///
+    /// assert_eq!(fahrenheit.try_get(&100.0), Ok(212.0));
+    /// ```
+    #[must_use]
+    pub fn as_partial_getter(self) -> PartialGetterImpl<S, A, ReadOnly<ISO>> {
+        ReadOnly::new(self.0).into()
+    }
+
+    /// Downgrades this `IsoImpl<S,A>` to a write-only `SetterImpl<S,A>`, exposing only the
+    /// capabilities of a [`Setter`].
+    ///
+    /// The underlying optic is wrapped so that only its write operations can be reached, not even
+    /// through the `.0` field of the result, so APIs can accept exactly the access level they need.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use optics::{HasSetter, mapped_iso};
+    ///
+    /// let fahrenheit = mapped_iso(|c: &f64| c * 1.8 + 32.0, |f: &f64| (f - 32.0) / 1.8).as_setter();
+    /// let mut celsius = 0.0;
+    ///
+    /// fahrenheit.set(&mut celsius, 212.0);
+    /// assert_eq!(celsius, 100.0);
+    /// ```
+    #[must_use]
+    pub fn as_setter(self) -> SetterImpl<S, A, WriteOnly<ISO>> {
+        WriteOnly::new(self.0).into()
     }
 }
@@ -0,0 +1,106 @@
+use crate::optics::lens::Lens;
+use crate::optics::lens::wrapper::LensImpl;
+use crate::{HasGetter, HasGetterRef, HasModify, HasSetter};
+use core::convert::Infallible;
+use core::marker::PhantomData;
+
+struct BorrowedLens<S, A, GET, SET>
+where
+    GET: Fn(&S) -> &A,
+    SET: Fn(&mut S, A),
+{
+    get_ref_fn: GET,
+    set_fn: SET,
+    phantom: PhantomData<(S, A)>,
+}
+
+impl<S, A: Clone, GET, SET> HasGetter<S, A> for BorrowedLens<S, A, GET, SET>
+where
+    GET: Fn(&S) -> &A,
+    SET: Fn(&mut S, A),
+{
+    type GetterError = Infallible;
+
+    fn try_get(&self, source: &S) -> Result<A, Self::GetterError> {
+        Ok((self.get_ref_fn)(source).clone())
+    }
+}
+
+impl<S, A: Clone, GET, SET> HasGetterRef<S, A> for BorrowedLens<S, A, GET, SET>
+where
+    GET: Fn(&S) -> &A,
+    SET: Fn(&mut S, A),
+{
+    fn try_get_ref<'a>(&self, source: &'a S) -> Result<&'a A, Self::GetterError>
+    where
+        Self: 'a,
+    {
+        Ok((self.get_ref_fn)(source))
+    }
+}
+
+impl<S, A, GET, SET> HasSetter<S, A> for BorrowedLens<S, A, GET, SET>
+where
+    GET: Fn(&S) -> &A,
+    SET: Fn(&mut S, A),
+{
+    fn set(&self, source: &mut S, value: A) {
+        (self.set_fn)(source, value);
+    }
+}
+
+impl<S, A: Clone, GET, SET> HasModify<S, A> for BorrowedLens<S, A, GET, SET>
+where
+    GET: Fn(&S) -> &A,
+    SET: Fn(&mut S, A),
+{
+}
+
+/// Creates a new `Lens` whose getter borrows the focus from the source.
+///
+/// Besides the usual lens operations, the resulting lens implements [`HasGetterRef`], so its focus
+/// can be inspected without cloning it. Retrieving an owned focus clones the borrowed value.
+///
+/// # Type Parameters
+/// - `S`: The source type of the optic
+/// - `A`: The target type of the optic. Must implement `Clone`.
+///
+/// # Arguments
+///
+/// - `get_ref_fn` — A function that borrows the focus value `A` from the source `S`.
+/// - `set_fn` — A function that sets the focused value `A` in the source `S`.
+///
+/// # Returns
+///
+/// A new `LensImpl` instance that can be used as a `Lens<S, A>` and a `HasGetterRef<S, A>`.
+///
+/// # Examples
+///
+/// ```
+/// use optics::{borrowed_lens, HasGetterRef, HasSetter, HasTotalGetter};
+///
+/// struct Document { title: String, body: String };
+/// let body_lens = borrowed_lens(|d: &Document| &d.body, |d, v| d.body = v);
+///
+/// let mut doc = Document { title: "optics".to_string(), body: "lorem ipsum".to_string() };
+///
+/// assert_eq!(body_lens.try_get_ref(&doc).map(String::len), Ok(11));
+/// body_lens.set(&mut doc, "dolor".to_string());
+/// assert_eq!(body_lens.get(&doc), "dolor");
+/// ```
+#[must_use]
+pub fn new<S, A: Clone, GET, SET>(
+    get_ref_fn: GET,
+    set_fn: SET,
+) -> LensImpl<S, A, impl Lens<S, A> + HasGetterRef<S, A>>
+where
+    GET: Fn(&S) -> &A,
+    SET: Fn(&mut S, A),
+{
+    BorrowedLens {
+        get_ref_fn,
+        set_fn,
+        phantom: PhantomData,
+    }
+    .into()
+}
@@ -0,0 +1,35 @@
+use crate::{HasGetter, HasModify, HasSetter, LensImpl};
+use alloc::boxed::Box;
+use core::convert::Infallible;
+
+/// An object safe version of [`Lens`](crate::Lens), implemented by every lens.
+///
+/// It can be used as a trait object, such as in a [`BoxedLens`], to store lenses of different types
+/// in the same collection. The methods of the [`HasModify`](crate::HasModify) base trait are not
+/// object safe, so they are not part of it, but they are implemented by the boxed lens.
+pub trait DynLens<S, A>: HasGetter<S, A, GetterError = Infallible> + HasSetter<S, A> {}
+
+impl<S, A, O: HasGetter<S, A, GetterError = Infallible> + HasSetter<S, A>> DynLens<S, A> for O {}
+
+/// A boxed lens of any type, focusing on a value of type `A` in a source of type `S`.
+///
+/// Unlike the opaque lenses returned by the constructors of this crate, boxed lenses of different
+/// types have the same type, so they can be stored in collections or struct fields, and composed
+/// like any other lens. They are created with [`LensImpl::boxed`].
+pub type BoxedLens<S, A> = LensImpl<S, A, Box<dyn DynLens<S, A> + Send + Sync>>;
+
+impl<S, A> HasGetter<S, A> for Box<dyn DynLens<S, A> + Send + Sync> {
+    type GetterError = Infallible;
+
+    fn try_get(&self, source: &S) -> Result<A, Self::GetterError> {
+        (**self).try_get(source)
+    }
+}
+
+impl<S, A> HasSetter<S, A> for Box<dyn DynLens<S, A> + Send + Sync> {
+    fn set(&self, source: &mut S, value: A) {
+        (**self).set(source, value);
+    }
+}
+
+impl<S, A> HasModify<S, A> for Box<dyn DynLens<S, A> + Send + Sync> {}
@@ -0,0 +1,69 @@
+use crate::{HasFallibleSetter, HasGetter, HasModify, HasSetter, Lens, LensImpl};
+use core::convert::Infallible;
+use core::marker::PhantomData;
+
+struct CheckedLens<GET, SET, E, S, A>
+where
+    GET: Fn(&S) -> A,
+    SET: Fn(&mut S, A) -> Result<(), E>,
+{
+    get_fn: GET,
+    set_fn: SET,
+    _phantom: PhantomData<(S, A, E)>,
+}
+
+impl<GET, SET, E, S, A> HasGetter<S, A> for CheckedLens<GET, SET, E, S, A>
+where
+    GET: Fn(&S) -> A,
+    SET: Fn(&mut S, A) -> Result<(), E>,
+{
+    type GetterError = Infallible;
+
+    fn try_get(&self, source: &S) -> Result<A, Self::GetterError> {
+        Ok((self.get_fn)(source))
+    }
+}
+
+impl<GET, SET, E, S, A> HasSetter<S, A> for CheckedLens<GET, SET, E, S, A>
+where
+    GET: Fn(&S) -> A,
+    SET: Fn(&mut S, A) -> Result<(), E>,
+{
+    fn set(&self, source: &mut S, value: A) {
+        let _ = self.try_set(source, value);
+    }
+}
+
+impl<GET, SET, E, S, A> HasFallibleSetter<S, A> for CheckedLens<GET, SET, E, S, A>
+where
+    GET: Fn(&S) -> A,
+    SET: Fn(&mut S, A) -> Result<(), E>,
+{
+    type SetterError = E;
+
+    fn try_set(&self, source: &mut S, value: A) -> Result<(), Self::SetterError> {
+        (self.set_fn)(source, value)
+    }
+}
+
+impl<GET, SET, E, S, A> HasModify<S, A> for CheckedLens<GET, SET, E, S, A>
+where
+    GET: Fn(&S) -> A,
+    SET: Fn(&mut S, A) -> Result<(), E>,
+{
+}
+
+/// Creates a `Lens<S,A>` whose writes go through `set_fn`, which may reject a value depending on
+/// the source it is written into, as the validated mutators of other crates do. Rejections are
+/// reported through `HasFallibleSetter`, and leave the source unchanged on `HasSetter::set`.
+pub(crate) fn new<S, A, E>(
+    get_fn: impl Fn(&S) -> A,
+    set_fn: impl Fn(&mut S, A) -> Result<(), E>,
+) -> LensImpl<S, A, impl Lens<S, A> + HasFallibleSetter<S, A, SetterError = E>> {
+    CheckedLens {
+        get_fn,
+        set_fn,
+        _phantom: PhantomData,
+    }
+    .into()
+}
@@ -1,13 +1,77 @@
+use crate::HasPath;
 use crate::HasSetter;
 use crate::optics::lens::Lens;
-use crate::{HasGetter, HasTotalGetter, LensImpl};
+use crate::{HasFallibleSetter, HasGetter, HasGetterMut, HasGetterRef, HasModify, LensImpl};
 use core::convert::Infallible;
+use core::fmt::{Debug, Display, Formatter};
 use core::marker::PhantomData;
 
-struct ComposedLens<L1: Lens<S, I>, L2: Lens<I, A>, S, I, A> {
-    optic1: L1,
-    optic2: L2,
-    _phantom: PhantomData<(S, I, A)>,
+mod private {
+    use crate::Lens;
+    use core::marker::PhantomData;
+
+    pub struct ComposedLens<L1: Lens<S, I>, L2: Lens<I, A>, S, I, A> {
+        pub(super) optic1: L1,
+        pub(super) optic2: L2,
+        pub(super) _phantom: PhantomData<(S, I, A)>,
+    }
+}
+
+pub type ComposedLens<L1, L2, S, I, A> = private::ComposedLens<L1, L2, S, I, A>;
+
+impl<L1, L2, S, I, A> Clone for ComposedLens<L1, L2, S, I, A>
+where
+    L1: Lens<S, I> + Clone,
+    L2: Lens<I, A> + Clone,
+{
+    fn clone(&self) -> Self {
+        ComposedLens {
+            optic1: self.optic1.clone(),
+            optic2: self.optic2.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<L1, L2, S, I, A> Copy for ComposedLens<L1, L2, S, I, A>
+where
+    L1: Lens<S, I> + Copy,
+    L2: Lens<I, A> + Copy,
+{
+}
+
+impl<L1, L2, S, I, A> Debug for ComposedLens<L1, L2, S, I, A>
+where
+    L1: Lens<S, I> + Debug,
+    L2: Lens<I, A> + Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ComposedLens")
+            .field("optic1", &self.optic1)
+            .field("optic2", &self.optic2)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<L1, L2, S, I, A> Display for ComposedLens<L1, L2, S, I, A>
+where
+    L1: Lens<S, I> + Display,
+    L2: Lens<I, A> + Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}.{}", self.optic1, self.optic2)
+    }
+}
+
+impl<L1, L2, S, I, A> HasPath for ComposedLens<L1, L2, S, I, A>
+where
+    L1: Lens<S, I> + HasPath,
+    L2: Lens<I, A> + HasPath,
+{
+    fn for_each_segment<'a>(&'a self, f: &mut dyn FnMut(&'a str)) {
+        self.optic1.for_each_segment(f);
+        self.optic2.for_each_segment(f);
+    }
 }
 
 impl<L1, L2, S, I, A> ComposedLens<L1, L2, S, I, A>
@@ -15,7 +79,7 @@ where
     L1: Lens<S, I>,
     L2: Lens<I, A>,
 {
-    fn new(optic1: L1, optic2: L2) -> Self {
+    pub(crate) fn new(optic1: L1, optic2: L2) -> Self {
         ComposedLens {
             optic1,
             optic2,
@@ -31,21 +95,78 @@ where
 {
     type GetterError = Infallible;
 
+    #[inline]
     fn try_get(&self, source: &S) -> Result<A, Self::GetterError> {
         let i = self.optic1.try_get(source)?;
         self.optic2.try_get(&i)
     }
 }
 
+impl<S, I, A, L1, L2> HasGetterRef<S, A> for ComposedLens<L1, L2, S, I, A>
+where
+    L1: Lens<S, I> + HasGetterRef<S, I>,
+    L2: Lens<I, A> + HasGetterRef<I, A>,
+{
+    #[inline]
+    fn try_get_ref<'a>(&self, source: &'a S) -> Result<&'a A, Self::GetterError>
+    where
+        Self: 'a,
+    {
+        let i = self.optic1.try_get_ref(source)?;
+        self.optic2.try_get_ref(i)
+    }
+}
+
+impl<S, I, A, L1, L2> HasGetterMut<S, A> for ComposedLens<L1, L2, S, I, A>
+where
+    L1: Lens<S, I> + HasGetterMut<S, I>,
+    L2: Lens<I, A> + HasGetterMut<I, A>,
+{
+    #[inline]
+    fn try_get_mut<'a>(&self, source: &'a mut S) -> Result<&'a mut A, Self::GetterError>
+    where
+        Self: 'a,
+    {
+        let i = self.optic1.try_get_mut(source)?;
+        self.optic2.try_get_mut(i)
+    }
+}
+
 impl<S, I, A, L1, L2> HasSetter<S, A> for ComposedLens<L1, L2, S, I, A>
 where
     L1: Lens<S, I>,
     L2: Lens<I, A>,
 {
+    #[inline]
     fn set(&self, source: &mut S, value: A) {
-        let mut i = self.optic1.get(source);
-        self.optic2.set(&mut i, value);
-        self.optic1.set(source, i);
+        self.optic1.modify(source, |i| self.optic2.set(i, value));
+    }
+}
+
+impl<S, I, A, L1, L2> HasFallibleSetter<S, A> for ComposedLens<L1, L2, S, I, A>
+where
+    L1: Lens<S, I>,
+    L2: Lens<I, A> + HasFallibleSetter<I, A>,
+{
+    type SetterError = L2::SetterError;
+
+    #[inline]
+    fn try_set(&self, source: &mut S, value: A) -> Result<(), Self::SetterError> {
+        let mut result = Ok(());
+        self.optic1
+            .modify(source, |i| result = self.optic2.try_set(i, value));
+        result
+    }
+}
+
+impl<S, I, A, L1, L2> HasModify<S, A> for ComposedLens<L1, L2, S, I, A>
+where
+    L1: Lens<S, I>,
+    L2: Lens<I, A>,
+{
+    #[inline]
+    fn modify<F: FnOnce(&mut A)>(&self, source: &mut S, f: F) {
+        self.optic1.modify(source, |i| self.optic2.modify(i, f));
     }
 }
 
@@ -76,6 +197,6 @@ where
 pub fn new<S, A, I, L1: Lens<S, I>, L2: Lens<I, A>>(
     l1: L1,
     l2: L2,
-) -> LensImpl<S, A, impl Lens<S, A>> {
+) -> LensImpl<S, A, ComposedLens<L1, L2, S, I, A>> {
     ComposedLens::new(l1, l2).into()
 }
@@ -1,18 +1,57 @@
 use crate::HasGetter;
+use crate::HasModify;
 use crate::HasSetter;
-use crate::optics::lens::Lens;
 use crate::optics::lens::wrapper::LensImpl;
 use core::convert::Infallible;
+use core::fmt::{Debug, Formatter};
 use core::marker::PhantomData;
 
-struct MappedLens<S, A, GET = fn(&S) -> A, SET = fn(&mut S, A)>
+mod private {
+    use core::marker::PhantomData;
+
+    pub struct MappedLens<S, A, GET = fn(&S) -> A, SET = fn(&mut S, A)>
+    where
+        GET: Fn(&S) -> A,
+        SET: Fn(&mut S, A),
+    {
+        pub(super) get_fn: GET,
+        pub(super) set_fn: SET,
+        pub(super) phantom: PhantomData<(S, A)>,
+    }
+}
+
+pub type MappedLens<S, A, GET = fn(&S) -> A, SET = fn(&mut S, A)> =
+    private::MappedLens<S, A, GET, SET>;
+
+impl<S, A, GET, SET> Clone for MappedLens<S, A, GET, SET>
+where
+    GET: Fn(&S) -> A + Clone,
+    SET: Fn(&mut S, A) + Clone,
+{
+    fn clone(&self) -> Self {
+        MappedLens {
+            get_fn: self.get_fn.clone(),
+            set_fn: self.set_fn.clone(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<S, A, GET, SET> Copy for MappedLens<S, A, GET, SET>
+where
+    GET: Fn(&S) -> A + Copy,
+    SET: Fn(&mut S, A) + Copy,
+{
+}
+
+impl<S, A, GET, SET> Debug for MappedLens<S, A, GET, SET>
 where
     GET: Fn(&S) -> A,
     SET: Fn(&mut S, A),
 {
-    get_fn: GET,
-    set_fn: SET,
-    phantom: PhantomData<(S, A)>,
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("MappedLens").finish_non_exhaustive()
+    }
 }
 
 impl<S, A, GET, SET> MappedLens<S, A, GET, SET>
@@ -20,7 +59,7 @@ where
     GET: Fn(&S) -> A,
     SET: Fn(&mut S, A),
 {
-    pub(crate) fn new(get_fn: GET, set_fn: SET) -> Self {
+    pub(crate) const fn new(get_fn: GET, set_fn: SET) -> Self {
         MappedLens {
             get_fn,
             set_fn,
@@ -51,6 +90,13 @@ where
     }
 }
 
+impl<S, A, GET, SET> HasModify<S, A> for MappedLens<S, A, GET, SET>
+where
+    GET: Fn(&S) -> A,
+    SET: Fn(&mut S, A),
+{
+}
+
 /// Creates a new `Lens` with the provided getter and setter function.
 ///
 /// # Type Parameters
@@ -83,11 +129,25 @@ where
 /// x_lens.set(&mut p, 42);
 /// assert_eq!(x_lens.get(&p), 42);
 /// ```
+///
+/// Being a `const fn`, lenses built from non-capturing closures can be declared as statics:
+///
+/// ```
+/// use optics::{mapped_lens, FnLens, HasTotalGetter};
+///
+/// struct Point { x: u32, y: u32 };
+/// static X_LENS: FnLens<Point, u32> = mapped_lens(|s: &Point| s.x, |s, v| s.x = v);
+///
+/// assert_eq!(X_LENS.get(&Point { x: 10, y: 20 }), 10);
+/// ```
 #[must_use]
-pub fn new<S, A, GET, SET>(get_fn: GET, set_fn: SET) -> LensImpl<S, A, impl Lens<S, A>>
+pub const fn new<S, A, GET, SET>(
+    get_fn: GET,
+    set_fn: SET,
+) -> LensImpl<S, A, MappedLens<S, A, GET, SET>>
 where
     GET: Fn(&S) -> A,
     SET: Fn(&mut S, A),
 {
-    MappedLens::new(get_fn, set_fn).into()
+    LensImpl::new(MappedLens::new(get_fn, set_fn))
 }
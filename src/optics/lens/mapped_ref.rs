@@ -0,0 +1,162 @@
+use crate::optics::lens::wrapper::LensImpl;
+use crate::{HasGetter, HasGetterMut, HasGetterRef, HasModify, HasSetter};
+use core::convert::Infallible;
+use core::fmt::{Debug, Formatter};
+use core::marker::PhantomData;
+
+mod private {
+    use core::marker::PhantomData;
+
+    pub struct MappedRefLens<S, A, GET, GETMUT>
+    where
+        GET: Fn(&S) -> &A,
+        GETMUT: Fn(&mut S) -> &mut A,
+    {
+        pub(super) get_ref_fn: GET,
+        pub(super) get_mut_fn: GETMUT,
+        pub(super) phantom: PhantomData<(S, A)>,
+    }
+}
+
+pub type MappedRefLens<S, A, GET, GETMUT> = private::MappedRefLens<S, A, GET, GETMUT>;
+
+impl<S, A, GET, GETMUT> Clone for MappedRefLens<S, A, GET, GETMUT>
+where
+    GET: Fn(&S) -> &A + Clone,
+    GETMUT: Fn(&mut S) -> &mut A + Clone,
+{
+    fn clone(&self) -> Self {
+        MappedRefLens {
+            get_ref_fn: self.get_ref_fn.clone(),
+            get_mut_fn: self.get_mut_fn.clone(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<S, A, GET, GETMUT> Copy for MappedRefLens<S, A, GET, GETMUT>
+where
+    GET: Fn(&S) -> &A + Copy,
+    GETMUT: Fn(&mut S) -> &mut A + Copy,
+{
+}
+
+impl<S, A, GET, GETMUT> Debug for MappedRefLens<S, A, GET, GETMUT>
+where
+    GET: Fn(&S) -> &A,
+    GETMUT: Fn(&mut S) -> &mut A,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("MappedRefLens").finish_non_exhaustive()
+    }
+}
+
+impl<S, A: Clone, GET, GETMUT> HasGetter<S, A> for MappedRefLens<S, A, GET, GETMUT>
+where
+    GET: Fn(&S) -> &A,
+    GETMUT: Fn(&mut S) -> &mut A,
+{
+    type GetterError = Infallible;
+
+    fn try_get(&self, source: &S) -> Result<A, Self::GetterError> {
+        Ok((self.get_ref_fn)(source).clone())
+    }
+}
+
+impl<S, A: Clone, GET, GETMUT> HasGetterRef<S, A> for MappedRefLens<S, A, GET, GETMUT>
+where
+    GET: Fn(&S) -> &A,
+    GETMUT: Fn(&mut S) -> &mut A,
+{
+    fn try_get_ref<'a>(&self, source: &'a S) -> Result<&'a A, Self::GetterError>
+    where
+        Self: 'a,
+    {
+        Ok((self.get_ref_fn)(source))
+    }
+}
+
+impl<S, A: Clone, GET, GETMUT> HasGetterMut<S, A> for MappedRefLens<S, A, GET, GETMUT>
+where
+    GET: Fn(&S) -> &A,
+    GETMUT: Fn(&mut S) -> &mut A,
+{
+    fn try_get_mut<'a>(&self, source: &'a mut S) -> Result<&'a mut A, Self::GetterError>
+    where
+        Self: 'a,
+    {
+        Ok((self.get_mut_fn)(source))
+    }
+}
+
+impl<S, A, GET, GETMUT> HasSetter<S, A> for MappedRefLens<S, A, GET, GETMUT>
+where
+    GET: Fn(&S) -> &A,
+    GETMUT: Fn(&mut S) -> &mut A,
+{
+    fn set(&self, source: &mut S, value: A) {
+        *(self.get_mut_fn)(source) = value;
+    }
+}
+
+impl<S, A: Clone, GET, GETMUT> HasModify<S, A> for MappedRefLens<S, A, GET, GETMUT>
+where
+    GET: Fn(&S) -> &A,
+    GETMUT: Fn(&mut S) -> &mut A,
+{
+    fn modify<F: FnOnce(&mut A)>(&self, source: &mut S, f: F) {
+        f((self.get_mut_fn)(source));
+    }
+}
+
+/// Creates a new `Lens` that borrows its focus from the source, both immutably and mutably.
+///
+/// Besides the usual lens operations, the resulting lens implements [`HasGetterRef`] and
+/// [`HasGetterMut`], so its focus can be inspected and modified in place without cloning it.
+/// Retrieving an owned focus clones the borrowed value, and setting the focus assigns it through
+/// the mutable reference.
+///
+/// # Type Parameters
+/// - `S`: The source type of the optic
+/// - `A`: The target type of the optic. Must implement `Clone`.
+///
+/// # Arguments
+///
+/// - `get_ref_fn` — A function that borrows the focus value `A` from the source `S`.
+/// - `get_mut_fn` — A function that mutably borrows the focus value `A` from the source `S`.
+///
+/// # Returns
+///
+/// A new `LensImpl` instance that can be used as a `Lens<S, A>`, a `HasGetterRef<S, A>` and a
+/// `HasGetterMut<S, A>`.
+///
+/// # Examples
+///
+/// ```
+/// use optics::{mapped_ref_lens, HasGetterMut, HasTotalGetter};
+///
+/// struct Document { title: String, body: String };
+/// let body_lens = mapped_ref_lens(|d: &Document| &d.body, |d: &mut Document| &mut d.body);
+///
+/// let mut doc = Document { title: "optics".to_string(), body: "lorem".to_string() };
+///
+/// let Ok(body) = body_lens.try_get_mut(&mut doc);
+/// body.push_str(" ipsum");
+/// assert_eq!(body_lens.get(&doc), "lorem ipsum");
+/// ```
+#[must_use]
+pub fn new<S, A: Clone, GET, GETMUT>(
+    get_ref_fn: GET,
+    get_mut_fn: GETMUT,
+) -> LensImpl<S, A, MappedRefLens<S, A, GET, GETMUT>>
+where
+    GET: Fn(&S) -> &A,
+    GETMUT: Fn(&mut S) -> &mut A,
+{
+    MappedRefLens {
+        get_ref_fn,
+        get_mut_fn,
+        phantom: PhantomData,
+    }
+    .into()
+}
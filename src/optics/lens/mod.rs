@@ -1,15 +1,60 @@
 use crate::HasGetter;
+use crate::HasModify;
 use crate::HasSetter;
 use core::convert::Infallible;
 
+#[cfg(feature = "lens")]
+mod borrowed;
+#[cfg(all(feature = "alloc", feature = "lens"))]
+mod boxed;
+#[cfg(all(
+    feature = "lens",
+    any(feature = "url", feature = "chrono", feature = "time")
+))]
+mod checked;
+#[cfg(feature = "lens")]
 mod composed;
+#[cfg(feature = "lens")]
 mod mapped;
+#[cfg(feature = "lens")]
+mod mapped_ref;
+#[cfg(all(feature = "alloc", feature = "lens"))]
+mod shared;
+#[cfg(feature = "lens")]
+mod validated;
+#[cfg(feature = "lens")]
 mod wrapper;
+#[cfg(feature = "lens")]
+mod zip;
 
+#[cfg(feature = "lens")]
+pub use borrowed::new as borrowed_lens;
+#[cfg(all(feature = "alloc", feature = "lens"))]
+pub use boxed::{BoxedLens, DynLens};
+#[cfg(all(
+    feature = "lens",
+    any(feature = "url", feature = "chrono", feature = "time")
+))]
+pub(crate) use checked::new as checked_lens;
+#[cfg(feature = "lens")]
+pub(crate) use composed::ComposedLens;
+#[cfg(feature = "lens")]
 pub use composed::new as composed_lens;
+#[cfg(feature = "lens")]
 pub use mapped::new as mapped_lens;
+#[cfg(feature = "lens")]
+pub use mapped_ref::new as mapped_ref_lens;
+#[cfg(all(feature = "alloc", feature = "lens"))]
+pub use shared::SharedLens;
+#[cfg(feature = "lens")]
 pub use wrapper::LensImpl;
 
+/// A [`LensImpl`] built by [`mapped_lens`] from plain functions or non-capturing closures.
+///
+/// As [`mapped_lens`] is a `const fn`, this type can be used to declare the optic as a `static`.
+#[cfg(feature = "lens")]
+pub type FnLens<S, A> = LensImpl<S, A, mapped::MappedLens<S, A>>;
+
 /// An optic for focusing on a value that is guaranteed to exist within a larger structure.
 ///
 /// A `Lens` is appropriate for product types (e.g., structs) where the focus is always present.
@@ -47,9 +92,15 @@ pub use wrapper::LensImpl;
 /// - [`Getter`] — an optic that focuses on value that is guaranteed to exist in a larger type
 /// - [`Setter`] — an optic that can change its focused value
 /// - [`Iso`] — an isomorphism optic representing a reversible bijective conversion between two types
-pub trait Lens<S, A>: HasGetter<S, A, GetterError = Infallible> + HasSetter<S, A> {}
+pub trait Lens<S, A>:
+    HasGetter<S, A, GetterError = Infallible> + HasSetter<S, A> + HasModify<S, A>
+{
+}
 
-impl<S, A, L: HasGetter<S, A, GetterError = Infallible> + HasSetter<S, A>> Lens<S, A> for L {}
+impl<S, A, L: HasGetter<S, A, GetterError = Infallible> + HasSetter<S, A> + HasModify<S, A>>
+    Lens<S, A> for L
+{
+}
 
 /// Creates a `Lens` that focuses on the entire input.
 ///
@@ -82,6 +133,7 @@ impl<S, A, L: HasGetter<S, A, GetterError = Infallible> + HasSetter<S, A>> Lens<
 /// - [`mapped_partial_getter`] for constructing custom `PartialGetter`s
 ///   from arbitrary mapping functions.
 ///
+#[cfg(feature = "lens")]
 #[must_use]
 pub fn identity_lens<S: Clone>() -> LensImpl<S, S, impl Lens<S, S>> {
     mapped_lens(|x: &S| x.clone(), |s, v| *s = v)
@@ -125,15 +177,22 @@ pub fn identity_lens<S: Clone>() -> LensImpl<S, S, impl Lens<S, S>> {
 ///
 /// # Notes
 ///
-/// - The getter returns a reference to the field.
-/// - The setter assigns the new value to the field.
+/// - The getter returns a reference to the field, so the lens implements [`HasGetterRef`](crate::HasGetterRef)
+///   and [`HasGetterMut`](crate::HasGetterMut). Retrieving an owned focus clones the field.
+/// - The setter assigns the new value to the field. Composed field lenses `set` and
+///   [`modify`](crate::HasModify::modify) their focus in place, without cloning the structs along
+///   the path.
 /// - The field must be accessible (e.g., public or within the same module).
+/// - The lens is [`named`](crate::LensImpl::named) after the field, so it shows up in the
+///   [`path`](crate::LensImpl::path) of the optics it is composed into.
+#[cfg(feature = "lens")]
 #[macro_export]
 macro_rules! field_lens {
     ($type:ty, $field:ident) => {
-        $crate::mapped_lens::<$type, _, _, _>(
-            |input: &$type| input.$field.clone(),
-            |input: &mut $type, value| input.$field = value,
+        $crate::mapped_ref_lens::<$type, _, _, _>(
+            |input: &$type| &input.$field,
+            |input: &mut $type| &mut input.$field,
         )
+        .named(stringify!($field))
     };
 }
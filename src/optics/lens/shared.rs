@@ -0,0 +1,26 @@
+use crate::{DynLens, HasGetter, HasModify, HasSetter, LensImpl};
+use alloc::sync::Arc;
+use core::convert::Infallible;
+
+/// A shared lens of any type, focusing on a value of type `A` in a source of type `S`.
+///
+/// Like [boxed lenses](crate::BoxedLens), shared lenses of different types have the same type, but
+/// they are reference counted, so they can be cloned cheaply, stored in registries and sent to
+/// other threads. They are created with [`LensImpl::shared`](crate::LensImpl::shared).
+pub type SharedLens<S, A> = LensImpl<S, A, Arc<dyn DynLens<S, A> + Send + Sync>>;
+
+impl<S, A> HasGetter<S, A> for Arc<dyn DynLens<S, A> + Send + Sync> {
+    type GetterError = Infallible;
+
+    fn try_get(&self, source: &S) -> Result<A, Self::GetterError> {
+        (**self).try_get(source)
+    }
+}
+
+impl<S, A> HasSetter<S, A> for Arc<dyn DynLens<S, A> + Send + Sync> {
+    fn set(&self, source: &mut S, value: A) {
+        (**self).set(source, value);
+    }
+}
+
+impl<S, A> HasModify<S, A> for Arc<dyn DynLens<S, A> + Send + Sync> {}
@@ -1,11 +1,42 @@
+#[cfg(feature = "fallible-iso")]
+use crate::FallibleIso;
+use crate::HasPath;
+#[cfg(feature = "iso")]
+use crate::Iso;
+use crate::named::Named;
+use crate::optics::fallible_setter::ComposedFallibleSetter;
+use crate::optics::getter::ComposedGetter;
+use crate::optics::lens::ComposedLens;
+use crate::optics::lens::validated::new as with_validation;
+use crate::optics::lens::zip::new as zip;
+use crate::optics::partial_getter::{ComposedPartialGetter, ReadOnly};
+use crate::optics::setter::{ComposedSetter, WriteOnly};
+use crate::optics::traversal::{ComposedTraversal, SingleTraversal, single_traversal};
+#[cfg(feature = "tracing")]
+use crate::traced::Traced;
 use crate::{
-    FallibleIso, FallibleIsoImpl, Getter, GetterImpl, HasGetter, HasSetter, HasTotalGetter, Iso,
-    IsoImpl, Lens, PartialGetter, PartialGetterImpl, Prism, PrismImpl, Setter, SetterImpl,
-    composed_getter, composed_lens, composed_partial_getter, composed_prism, composed_setter,
-    infallible,
+    FallibleSetter, FallibleSetterImpl, Getter, GetterImpl, HasFallibleSetter, HasGetter,
+    HasGetterMut, HasGetterRef, HasModify, HasSetter, HasTotalGetter, Lens, PartialGetter,
+    PartialGetterImpl, Setter, SetterImpl, Traversal, TraversalImpl, composed_fallible_setter,
+    composed_getter, composed_partial_getter, composed_setter, composed_traversal, infallible,
+    mapped_getter,
 };
 use core::convert::{Infallible, identity};
+use core::fmt::{Debug, Display, Formatter};
 use core::marker::PhantomData;
+#[cfg(feature = "alloc")]
+use {
+    crate::optics::lens::{BoxedLens, DynLens, SharedLens},
+    alloc::boxed::Box,
+    alloc::string::{String, ToString},
+    alloc::sync::Arc,
+    alloc::vec::Vec,
+};
+#[cfg(feature = "prism")]
+use {
+    crate::optics::prism::ComposedPrism,
+    crate::{Prism, PrismImpl, composed_prism},
+};
 
 /// A wrapper of the [`Lens`] optic implementations, encapsulating a getter and setter function.
 ///
@@ -30,7 +61,7 @@ use core::marker::PhantomData;
 pub struct LensImpl<S, A, L: Lens<S, A>>(pub L, PhantomData<(S, A)>);
 
 impl<S, A, L: Lens<S, A>> LensImpl<S, A, L> {
-    fn new(l: L) -> Self {
+    pub(crate) const fn new(l: L) -> Self {
         //TODO: Verify not to nest an Impl inside an Impl - currently seems to be impossible at compile time.
         LensImpl(l, PhantomData)
     }
@@ -42,20 +73,173 @@ impl<S, A, L: Lens<S, A>> From<L> for LensImpl<S, A, L> {
     }
 }
 
+impl<S, A, L: Lens<S, A> + Clone> Clone for LensImpl<S, A, L> {
+    fn clone(&self) -> Self {
+        Self::new(self.0.clone())
+    }
+}
+
+impl<S, A, L: Lens<S, A> + Copy> Copy for LensImpl<S, A, L> {}
+
+impl<S, A, L: Lens<S, A> + Debug> Debug for LensImpl<S, A, L> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("LensImpl").field(&self.0).finish()
+    }
+}
+
+impl<S, A, L: Lens<S, A> + Display> Display for LensImpl<S, A, L> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl<S, A, L: Lens<S, A> + HasPath> HasPath for LensImpl<S, A, L> {
+    fn for_each_segment<'a>(&'a self, f: &mut dyn FnMut(&'a str)) {
+        self.0.for_each_segment(f);
+    }
+}
+
+impl<S, A, L: Lens<S, A>> LensImpl<S, A, L> {
+    /// Labels this optic with a static `name`, used by its [`Display`] implementation.
+    ///
+    /// Composing named optics results in an optic displaying the names of its parts joined with a
+    /// `.`, which helps to identify a misbehaving optic in logs and error messages.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The name of the optic.
+    ///
+    /// # Returns
+    ///
+    /// A new `LensImpl` behaving exactly like `self`, displayed as `name`.
+    #[must_use]
+    pub fn named(self, name: &'static str) -> LensImpl<S, A, Named<L>> {
+        Named::new(self.0, name).into()
+    }
+
+    /// Wraps this optic to emit a `tracing` event for every operation going through it, with
+    /// `name` identifying the optic, whether the operation succeeded and the time it took.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The name of the optic in the events, such as `"config.port"`.
+    ///
+    /// # Returns
+    ///
+    /// A new `LensImpl` behaving exactly like `self`, tracing its operations.
+    #[cfg(feature = "tracing")]
+    #[must_use]
+    pub fn traced(self, name: &'static str) -> LensImpl<S, A, Traced<L>> {
+        Traced::new(self.0, name).into()
+    }
+
+    /// Returns the name of the optic, the names of the optics it is composed of joined with a `.`.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn name(&self) -> String
+    where
+        L: Display,
+    {
+        self.to_string()
+    }
+
+    /// Returns the names of the fields and variants this optic focuses through, from the outermost
+    /// one, such as the field names of the [`field_lens!`](crate::field_lens) lenses it is
+    /// composed of.
+    #[cfg(feature = "alloc")]
+    pub fn path(&self) -> impl Iterator<Item = &str>
+    where
+        L: HasPath,
+    {
+        let mut path = Vec::new();
+        self.0.for_each_segment(&mut |segment| path.push(segment));
+        path.into_iter()
+    }
+
+    /// Boxes this lens, erasing its type, so it can be stored alongside lenses of other types.
+    ///
+    /// # Returns
+    ///
+    /// A [`BoxedLens`] behaving exactly like `self`.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn boxed(self) -> BoxedLens<S, A>
+    where
+        L: Send + Sync + 'static,
+    {
+        let optic: Box<dyn DynLens<S, A> + Send + Sync> = Box::new(self.0);
+        optic.into()
+    }
+
+    /// Wraps this lens in an [`Arc`], erasing its type, so it can be cloned cheaply and shared
+    /// between threads.
+    ///
+    /// # Returns
+    ///
+    /// A [`SharedLens`] behaving exactly like `self`.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn shared(self) -> SharedLens<S, A>
+    where
+        L: Send + Sync + 'static,
+    {
+        let optic: Arc<dyn DynLens<S, A> + Send + Sync> = Arc::new(self.0);
+        optic.into()
+    }
+}
+
 impl<S, A, L: Lens<S, A>> HasGetter<S, A> for LensImpl<S, A, L> {
     type GetterError = Infallible;
 
+    #[inline]
     fn try_get(&self, source: &S) -> Result<A, Self::GetterError> {
         Ok(self.0.get(source))
     }
 }
 
+impl<S, A, L: Lens<S, A> + HasGetterRef<S, A>> HasGetterRef<S, A> for LensImpl<S, A, L> {
+    #[inline]
+    fn try_get_ref<'a>(&self, source: &'a S) -> Result<&'a A, Self::GetterError>
+    where
+        Self: 'a,
+    {
+        self.0.try_get_ref(source)
+    }
+}
+
+impl<S, A, L: Lens<S, A> + HasGetterMut<S, A>> HasGetterMut<S, A> for LensImpl<S, A, L> {
+    #[inline]
+    fn try_get_mut<'a>(&self, source: &'a mut S) -> Result<&'a mut A, Self::GetterError>
+    where
+        Self: 'a,
+    {
+        self.0.try_get_mut(source)
+    }
+}
+
 impl<S, A, L: Lens<S, A>> HasSetter<S, A> for LensImpl<S, A, L> {
+    #[inline]
     fn set(&self, source: &mut S, value: A) {
         self.0.set(source, value);
     }
 }
 
+impl<S, A, L: Lens<S, A>> HasModify<S, A> for LensImpl<S, A, L> {
+    #[inline]
+    fn modify<F: FnOnce(&mut A)>(&self, source: &mut S, f: F) {
+        self.0.modify(source, f);
+    }
+}
+
+impl<S, A, L: Lens<S, A> + HasFallibleSetter<S, A>> HasFallibleSetter<S, A> for LensImpl<S, A, L> {
+    type SetterError = L::SetterError;
+
+    #[inline]
+    fn try_set(&self, source: &mut S, value: A) -> Result<(), Self::SetterError> {
+        self.0.try_set(source, value)
+    }
+}
+
 impl<S, I, L: Lens<S, I>> LensImpl<S, I, L> {
     /// Composes this `LensImpl<S,I>` with a `PartialGetter<I,A>`, resulting in a new `PartialGetter<S, A>`
     /// that focuses through both optics sequentially.
@@ -76,11 +260,12 @@ impl<S, I, L: Lens<S, I>> LensImpl<S, I, L> {
     ///
     /// A new `PartialGetterImpl` that represents the composition of `self` and `other`.
     ///
+    #[allow(clippy::type_complexity)]
     pub fn compose_with_partial_getter<A, PG2: PartialGetter<I, A>>(
         self,
-        other: PartialGetterImpl<I, A, PG2>,
-    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A, GetterError = PG2::GetterError>> {
-        composed_partial_getter(self.0, other.0, infallible, identity)
+        other: PG2,
+    ) -> PartialGetterImpl<S, A, ComposedPartialGetter<L, PG2, PG2::GetterError, S, I, A>> {
+        composed_partial_getter(self.0, other, infallible, identity)
     }
 
     /// Composes this `LensImpl<S,I>` with a `GetterImpl<I,A>`, resulting in a new `GetterImpl<S, A>`
@@ -104,9 +289,9 @@ impl<S, I, L: Lens<S, I>> LensImpl<S, I, L> {
     ///
     pub fn compose_with_getter<A, G2: Getter<I, A>>(
         self,
-        other: GetterImpl<I, A, G2>,
-    ) -> GetterImpl<S, A, impl Getter<S, A>> {
-        composed_getter(self.0, other.0)
+        other: G2,
+    ) -> GetterImpl<S, A, ComposedGetter<L, G2, S, I, A>> {
+        composed_getter(self.0, other)
     }
 
     /// Composes this `LensImpl<S,I>` with a `Setter<I,A>`, resulting in a new `Setter<S, A>`
@@ -130,9 +315,36 @@ impl<S, I, L: Lens<S, I>> LensImpl<S, I, L> {
     ///
     pub fn compose_with_setter<A, S2: Setter<I, A>>(
         self,
-        other: SetterImpl<I, A, S2>,
-    ) -> SetterImpl<S, A, impl Setter<S, A>> {
-        composed_setter(self.0, other.0)
+        other: S2,
+    ) -> SetterImpl<S, A, ComposedSetter<L, S2, S, I, A>> {
+        composed_setter(self.0, other)
+    }
+
+    /// Composes this `LensImpl<S,I>` with a `FallibleSetter<I,A>`, resulting in a new `FallibleSetter<S, A>`
+    /// that focuses through both optics sequentially.
+    ///
+    /// The resulting `FallibleSetterImpl` will set the value by first applying `self` and then
+    /// `other`, failing if `other` rejects the value.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `A`: The target type of the composed optic.
+    /// - `FS2`: The type of the fallible setter to compose with.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: The fallible setter to compose with.
+    ///
+    /// # Returns
+    ///
+    /// A new `FallibleSetterImpl` that represents the composition of `self` and `other`.
+    ///
+    #[allow(clippy::type_complexity)]
+    pub fn compose_with_fallible_setter<A, FS2: FallibleSetter<I, A>>(
+        self,
+        other: FS2,
+    ) -> FallibleSetterImpl<S, A, ComposedFallibleSetter<L, FS2, FS2::SetterError, S, I, A>> {
+        composed_fallible_setter(self.0, other, infallible, identity)
     }
 
     /// Composes this `LensImpl<S,I>` with a `Prism<I,A>`, resulting in a new `PrismImpl<S, A>`
@@ -153,11 +365,13 @@ impl<S, I, L: Lens<S, I>> LensImpl<S, I, L> {
     /// # Returns
     ///
     /// A new `PrismImpl` that represents the composition of `self` and `other`.
+    #[cfg(feature = "prism")]
+    #[allow(clippy::type_complexity)]
     pub fn compose_with_prism<A, P: Prism<I, A>>(
         self,
-        other: PrismImpl<I, A, P>,
-    ) -> PrismImpl<S, A, impl Prism<S, A, GetterError = P::GetterError>> {
-        composed_prism(self.0, other.0, infallible, identity)
+        other: P,
+    ) -> PrismImpl<S, A, ComposedPrism<L, P, P::GetterError, S, I, A>> {
+        composed_prism(self.0, other, infallible, identity)
     }
 
     /// Composes this `LensImpl<S,I>` with a `LensImpl<I,A>`, resulting in a new `LensImpl<S, A>`
@@ -180,9 +394,46 @@ impl<S, I, L: Lens<S, I>> LensImpl<S, I, L> {
     /// A new `LensImpl` that represents the composition of `self` and `other`
     pub fn compose_with_lens<A, L2: Lens<I, A>>(
         self,
-        other: LensImpl<I, A, L2>,
-    ) -> LensImpl<S, A, impl Lens<S, A>> {
-        composed_lens(self.0, other.0)
+        other: L2,
+    ) -> LensImpl<S, A, ComposedLens<L, L2, S, I, A>> {
+        ComposedLens::new(self.0, other).into()
+    }
+
+    /// Composes this `LensImpl<S,I>` with a `LensImpl<I,A>` like
+    /// [`compose_with_lens`](Self::compose_with_lens), but boxes both lenses and the result.
+    ///
+    /// The resulting [`BoxedLens`] has the same type whatever lenses it is composed of, so the types
+    /// of deep chains built with `compose_dyn` do not nest into each other. This trades a dynamic
+    /// call per stage for less generated code and shorter compile times.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use optics::{BoxedLens, HasTotalGetter, field_lens};
+    ///
+    /// #[derive(Clone)]
+    /// struct Server { database: Database }
+    /// #[derive(Clone)]
+    /// struct Database { port: u16 }
+    ///
+    /// let port: BoxedLens<Server, u16> =
+    ///     field_lens!(Server, database).compose_dyn(field_lens!(Database, port));
+    ///
+    /// let server = Server { database: Database { port: 5432 } };
+    /// assert_eq!(port.get(&server), 5432);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn compose_dyn<A, L2>(self, other: L2) -> BoxedLens<S, A>
+    where
+        L: Send + Sync + 'static,
+        L2: Lens<I, A> + Send + Sync + 'static,
+        S: Send + Sync + 'static,
+        I: Send + Sync + 'static,
+        A: Send + Sync + 'static,
+    {
+        self.boxed()
+            .compose_with_lens(LensImpl::<I, A, L2>::from(other).boxed())
+            .boxed()
     }
 
     /// Composes this `LensImpl<S,I>` with a `FallibleIso<I,A>`, resulting in a new `PrismImpl<S, A>`
@@ -191,6 +442,11 @@ impl<S, I, L: Lens<S, I>> LensImpl<S, I, L> {
     /// The resulting `PrismImpl` will attempt to extract a value by first applying `self` and then
     /// `other`. If the fallible iso fails to focus, the composition will fail to focus.
     ///
+    /// The reverse direction of `other` is kept: besides the infallible `set`, which leaves the
+    /// source unchanged when the value cannot be mapped back, the resulting prism implements
+    /// [`HasFallibleSetter`](crate::HasFallibleSetter), whose `try_set` reports the reverse error
+    /// of `other`.
+    ///
     /// # Type Parameters
     ///
     /// - `A`: The target type of the composed prism.
@@ -203,11 +459,13 @@ impl<S, I, L: Lens<S, I>> LensImpl<S, I, L> {
     /// # Returns
     ///
     /// A new `PrismImpl` that represents the composition of `self` and `other`.
+    #[cfg(feature = "fallible-iso")]
+    #[allow(clippy::type_complexity)]
     pub fn compose_with_fallible_iso<A, FI2: FallibleIso<I, A>>(
         self,
-        other: FallibleIsoImpl<I, A, FI2>,
-    ) -> PrismImpl<S, A, impl Prism<S, A, GetterError = FI2::GetterError>> {
-        composed_prism(self.0, other.0, infallible, identity)
+        other: FI2,
+    ) -> PrismImpl<S, A, ComposedPrism<L, FI2, FI2::GetterError, S, I, A>> {
+        composed_prism(self.0, other, infallible, identity)
     }
 
     /// Composes this `LensImpl<S,I>` with an `IsoImpl<I,A>`, resulting in a new `LensImpl<S, A>`
@@ -228,10 +486,289 @@ impl<S, I, L: Lens<S, I>> LensImpl<S, I, L> {
     /// # Returns
     ///
     /// A new `LensImpl` that represents the composition of `self` and `other`
+    #[cfg(feature = "iso")]
     pub fn compose_with_iso<A, ISO2: Iso<I, A>>(
         self,
-        other: IsoImpl<I, A, ISO2>,
-    ) -> LensImpl<S, A, impl Lens<S, A>> {
-        composed_lens(self.0, other.0)
+        other: ISO2,
+    ) -> LensImpl<S, A, ComposedLens<L, ISO2, S, I, A>> {
+        ComposedLens::new(self.0, other).into()
+    }
+
+    /// Composes this `LensImpl<S,I>` with a `Traversal<I,A>`, resulting in a new `TraversalImpl<S, A>`
+    /// that focuses through both optics sequentially.
+    ///
+    /// The resulting `TraversalImpl` will visit every value focused on by `other` in the value
+    /// focused on by `self`.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `A`: The target type of the composed traversal.
+    /// - `T2`: The type of the traversal to compose with.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: The traversal to compose with.
+    ///
+    /// # Returns
+    ///
+    /// A new `TraversalImpl` that represents the composition of `self` and `other`.
+    #[allow(clippy::type_complexity)]
+    pub fn compose_with_traversal<A, T2: Traversal<I, A>>(
+        self,
+        other: T2,
+    ) -> TraversalImpl<S, A, ComposedTraversal<SingleTraversal<S, I, L>, T2, S, I, A>> {
+        composed_traversal(single_traversal(self.0), other)
+    }
+}
+
+impl<S, A, L1: Lens<S, A>> LensImpl<S, A, L1> {
+    /// Combines this `LensImpl<S,A>` with another `LensImpl<S,B>` over the same source, resulting in a
+    /// new `LensImpl<S, (A, B)>` focusing on both values as a tuple.
+    ///
+    /// # Note
+    ///
+    /// The two lenses must focus on disjoint parts of the source, otherwise setting the tuple
+    /// would write the same part twice, and the value set through `self` would be overwritten by
+    /// the one set through `other`, breaking the lens laws.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: The lens focusing on the second element of the tuple.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use optics::{HasSetter, HasTotalGetter, mapped_lens};
+    ///
+    /// struct Server {
+    ///     host: String,
+    ///     port: u16,
+    /// }
+    ///
+    /// let host = mapped_lens(|s: &Server| s.host.clone(), |s, host| s.host = host);
+    /// let port = mapped_lens(|s: &Server| s.port, |s, port| s.port = port);
+    /// let endpoint = host.zip(port);
+    ///
+    /// let mut server = Server { host: "localhost".to_string(), port: 80 };
+    /// assert_eq!(endpoint.get(&server), ("localhost".to_string(), 80));
+    ///
+    /// endpoint.set(&mut server, ("example.com".to_string(), 443));
+    /// assert_eq!(server.host, "example.com");
+    /// assert_eq!(server.port, 443);
+    /// ```
+    pub fn zip<B, L2: Lens<S, B>>(
+        self,
+        other: LensImpl<S, B, L2>,
+    ) -> LensImpl<S, (A, B), impl Lens<S, (A, B)>> {
+        zip(self.0, other)
+    }
+
+    /// Maps the focus of this `LensImpl<S,A>` with `f`, resulting in a read-only
+    /// `GetterImpl<S,B>` focusing on a value derived from the focus.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: The function deriving the new focus from the focus of `self`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use optics::{HasTotalGetter, mapped_lens};
+    ///
+    /// struct Server {
+    ///     host: String,
+    /// }
+    ///
+    /// let host = mapped_lens(|s: &Server| s.host.clone(), |s, host| s.host = host);
+    /// let host_len = host.map(String::len);
+    /// let server = Server { host: "localhost".to_string() };
+    ///
+    /// assert_eq!(host_len.get(&server), 9);
+    /// ```
+    pub fn map<B, F: Fn(&A) -> B>(self, f: F) -> GetterImpl<S, B, impl Getter<S, B>> {
+        composed_getter(self.0, mapped_getter(f).0)
+    }
+
+    /// Restricts the values that can be written through this `LensImpl<S,A>` to the ones accepted
+    /// by `validate_fn`, resulting in a new `LensImpl<S,A>` that also implements
+    /// [`HasFallibleSetter`].
+    ///
+    /// Rejected values are reported by [`HasFallibleSetter::try_set`] with the error returned by
+    /// `validate_fn`, while [`HasSetter::set`] ignores them, so the focus always holds a valid
+    /// value. Reading the focus is not affected. To surface rejections through compositions, compose
+    /// the optic with [`LensImpl::as_fallible_setter`].
+    ///
+    /// # Parameters
+    ///
+    /// - `validate_fn`: The function checking a value before it is written.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use optics::{HasFallibleSetter, HasSetter, HasTotalGetter, mapped_lens};
+    ///
+    /// struct Server {
+    ///     port: u16,
+    /// }
+    ///
+    /// let port = mapped_lens(|s: &Server| s.port, |s, port| s.port = port)
+    ///     .with_validation(|port| if *port < 1024 { Err("port below 1024") } else { Ok(()) });
+    ///
+    /// let mut server = Server { port: 8080 };
+    /// assert_eq!(port.try_set(&mut server, 80), Err("port below 1024"));
+    /// port.set(&mut server, 80);
+    /// assert_eq!(port.get(&server), 8080);
+    ///
+    /// assert_eq!(port.try_set(&mut server, 8081), Ok(()));
+    /// assert_eq!(port.get(&server), 8081);
+    /// ```
+    pub fn with_validation<E, V: Fn(&A) -> Result<(), E>>(
+        self,
+        validate_fn: V,
+    ) -> LensImpl<S, A, impl Lens<S, A> + HasFallibleSetter<S, A, SetterError = E>> {
+        with_validation(self.0, validate_fn)
+    }
+}
+
+impl<S, A, L: Lens<S, Option<A>>> LensImpl<S, Option<A>, L> {
+    /// Removes the value this `LensImpl<S,Option<A>>` focuses on, leaving `None` in its place,
+    /// and returns the removed value.
+    ///
+    /// # Parameters
+    ///
+    /// - `source`: A mutable reference to the source from which the focus is removed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use optics::{HasTotalGetter, map};
+    /// use std::collections::HashMap;
+    ///
+    /// let mut env = HashMap::from([("HOME".to_string(), "/root".to_string())]);
+    /// let home = map::at("HOME".to_string());
+    ///
+    /// assert_eq!(home.take(&mut env), Some("/root".to_string()));
+    /// assert_eq!(home.get(&env), None);
+    /// assert_eq!(home.take(&mut env), None);
+    /// ```
+    pub fn take(&self, source: &mut S) -> Option<A> {
+        let mut taken = None;
+        self.0.modify(source, |value| taken = value.take());
+        taken
+    }
+}
+
+#[allow(clippy::wrong_self_convention)]
+impl<S, A, L: Lens<S, A>> LensImpl<S, A, L> {
+    /// Downgrades this `LensImpl<S,A>` to a read-only `GetterImpl<S,A>`, exposing only the
+    /// capabilities of a [`Getter`].
+    ///
+    /// The underlying optic is wrapped so that only its read operations can be reached, not even
+    /// through the `.0` field of the result, so APIs can accept exactly the access level they need.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use optics::{HasTotalGetter, mapped_lens};
+    ///
+    /// let port = mapped_lens(|p: &(String, u16)| p.1, |p, v| p.1 = v).as_getter();
+    ///
+    /// assert_eq!(port.get(&("localhost".to_string(), 80)), 80);
+    /// ```
+    ///
+    /// The lens can not be written through the getter:
+    ///
+    /// ```compile_fail
+    /// use optics::{HasSetter, mapped_lens};
+    ///
+    /// let port = mapped_lens(|p: &(String, u16)| p.1, |p, v| p.1 = v).as_getter();
+    ///
+    /// port.0.set(&mut ("localhost".to_string(), 80), 443);
+    /// ```
+    #[must_use]
+    pub fn as_getter(self) -> GetterImpl<S, A, ReadOnly<L>> {
+        ReadOnly::new(self.0).into()
+    }
+
+    /// Downgrades this `LensImpl<S,A>` to a read-only `PartialGetterImpl<S,A>`, exposing only the
+    /// capabilities of a [`PartialGetter`].
+    ///
+    /// The underlying optic is wrapped so that only its read operations can be reached, not even
+    /// through the `.0` field of the result, so APIs can accept exactly the access level they need.
+    ///
+    /// Retrieving the focus never fails, the error type of the resulting optic is [`Infallible`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use optics::{HasGetter, mapped_lens};
+    ///
+    /// let port = mapped_lens(|p: &(String, u16)| p.1, |p, v| p.1 = v).as_partial_getter();
+    ///
+    /// assert_eq!(port.try_get(&("localhost".to_string(), 80)), Ok(80));
+    /// ```
+    #[must_use]
+    pub fn as_partial_getter(self) -> PartialGetterImpl<S, A, ReadOnly<L>> {
+        ReadOnly::new(self.0).into()
+    }
+
+    /// Downgrades this `LensImpl<S,A>` to a write-only `SetterImpl<S,A>`, exposing only the
+    /// capabilities of a [`Setter`].
+    ///
+    /// The underlying optic is wrapped so that only its write operations can be reached, not even
+    /// through the `.0` field of the result, so APIs can accept exactly the access level they need.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use optics::{HasSetter, mapped_lens};
+    ///
+    /// let port = mapped_lens(|p: &(String, u16)| p.1, |p, v| p.1 = v).as_setter();
+    /// let mut address = ("localhost".to_string(), 80);
+    ///
+    /// port.set(&mut address, 443);
+    /// assert_eq!(address.1, 443);
+    /// ```
+    ///
+    /// The lens can not be read through the setter:
+    ///
+    /// ```compile_fail
+    /// use optics::{HasGetter, mapped_lens};
+    ///
+    /// let port = mapped_lens(|p: &(String, u16)| p.1, |p, v| p.1 = v).as_setter();
+    ///
+    /// port.0.try_get(&("localhost".to_string(), 80));
+    /// ```
+    #[must_use]
+    pub fn as_setter(self) -> SetterImpl<S, A, WriteOnly<L>> {
+        WriteOnly::new(self.0).into()
+    }
+
+    /// Downgrades this `LensImpl<S,A>` to a write-only `FallibleSetterImpl<S,A>`, exposing only
+    /// the capabilities of a [`FallibleSetter`].
+    ///
+    /// This is only available for optics that can reject the written values, such as the ones
+    /// created by [`LensImpl::with_validation`]. The resulting optic can be composed with other
+    /// optics to surface the rejections.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use optics::{ComposeWith, Either, HasFallibleSetter, NoneError, mapped_lens, option};
+    ///
+    /// let port = mapped_lens(|p: &u16| *p, |p, v| *p = v)
+    ///     .with_validation(|port| if *port < 1024 { Err("port below 1024") } else { Ok(()) });
+    /// let configured_port = option::some::<u16>().then(port.as_fallible_setter());
+    ///
+    /// assert_eq!(configured_port.try_set(&mut None, 8080), Err(Either::Left(NoneError)));
+    /// assert_eq!(configured_port.try_set(&mut Some(8080), 80), Err(Either::Right("port below 1024")));
+    /// assert_eq!(configured_port.try_set(&mut Some(8080), 8081), Ok(()));
+    /// ```
+    #[must_use]
+    pub fn as_fallible_setter(self) -> FallibleSetterImpl<S, A, WriteOnly<L>>
+    where
+        L: HasFallibleSetter<S, A>,
+    {
+        WriteOnly::new(self.0).into()
     }
 }
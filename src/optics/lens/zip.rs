@@ -0,0 +1,53 @@
+use crate::optics::lens::wrapper::LensImpl;
+use crate::{HasGetter, HasModify, HasSetter, Lens};
+use core::convert::Infallible;
+use core::marker::PhantomData;
+
+struct ZippedLens<L1: Lens<S, A>, L2: Lens<S, B>, S, A, B> {
+    optic1: L1,
+    optic2: L2,
+    _phantom: PhantomData<(S, A, B)>,
+}
+
+impl<L1, L2, S, A, B> HasGetter<S, (A, B)> for ZippedLens<L1, L2, S, A, B>
+where
+    L1: Lens<S, A>,
+    L2: Lens<S, B>,
+{
+    type GetterError = Infallible;
+
+    fn try_get(&self, source: &S) -> Result<(A, B), Self::GetterError> {
+        Ok((self.optic1.try_get(source)?, self.optic2.try_get(source)?))
+    }
+}
+
+impl<L1, L2, S, A, B> HasSetter<S, (A, B)> for ZippedLens<L1, L2, S, A, B>
+where
+    L1: Lens<S, A>,
+    L2: Lens<S, B>,
+{
+    fn set(&self, source: &mut S, value: (A, B)) {
+        self.optic1.set(source, value.0);
+        self.optic2.set(source, value.1);
+    }
+}
+
+impl<L1, L2, S, A, B> HasModify<S, (A, B)> for ZippedLens<L1, L2, S, A, B>
+where
+    L1: Lens<S, A>,
+    L2: Lens<S, B>,
+{
+}
+
+/// Creates a `Lens<S,(A,B)>` focusing on the foci of both `l1` and `l2` as a tuple.
+pub(crate) fn new<S, A, B, L1: Lens<S, A>, L2: Lens<S, B>>(
+    l1: L1,
+    l2: L2,
+) -> LensImpl<S, (A, B), impl Lens<S, (A, B)>> {
+    ZippedLens {
+        optic1: l1,
+        optic2: l2,
+        _phantom: PhantomData,
+    }
+    .into()
+}
@@ -1,9 +1,9 @@
 pub mod fallible_iso;
+pub mod fallible_setter;
 pub mod getter;
 pub mod iso;
 pub mod lens;
 pub mod partial_getter;
 pub mod prism;
 pub mod setter;
-
-// pub mod traversal;
+pub mod traversal;
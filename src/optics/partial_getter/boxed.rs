@@ -0,0 +1,28 @@
+use crate::{HasGetter, PartialGetterImpl};
+use alloc::boxed::Box;
+
+/// An object safe version of [`PartialGetter`](crate::PartialGetter), implemented by every partial
+/// getter.
+///
+/// It can be used as a trait object, such as in a [`BoxedPartialGetter`], to store partial getters
+/// of different types in the same collection.
+pub trait DynPartialGetter<S, A, E>: HasGetter<S, A, GetterError = E> {}
+
+impl<S, A, E, O: HasGetter<S, A, GetterError = E>> DynPartialGetter<S, A, E> for O {}
+
+/// A boxed partial getter of any type, focusing on a value of type `A` in a source of type `S`.
+///
+/// Unlike the opaque partial getters returned by the constructors of this crate, boxed partial
+/// getters of different types have the same type, so they can be stored in collections or struct
+/// fields, and composed like any other partial getter. They are created with
+/// [`PartialGetterImpl::boxed`].
+pub type BoxedPartialGetter<S, A, E> =
+    PartialGetterImpl<S, A, Box<dyn DynPartialGetter<S, A, E> + Send + Sync>>;
+
+impl<S, A, E> HasGetter<S, A> for Box<dyn DynPartialGetter<S, A, E> + Send + Sync> {
+    type GetterError = E;
+
+    fn try_get(&self, source: &S) -> Result<A, Self::GetterError> {
+        (**self).try_get(source)
+    }
+}
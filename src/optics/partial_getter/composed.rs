@@ -1,13 +1,102 @@
+use crate::HasPath;
+use crate::OuterLabels;
 use crate::optics::partial_getter::wrapper::PartialGetterImpl;
 use crate::{HasGetter, PartialGetter};
+use core::fmt::{Debug, Display, Formatter};
 use core::marker::PhantomData;
 
-struct ComposedPartialGetter<PG1: PartialGetter<S, I>, PG2: PartialGetter<I, A>, E, S, I, A> {
-    optic1: PG1,
-    optic2: PG2,
-    error_fn_1: fn(PG1::GetterError) -> E,
-    error_fn_2: fn(PG2::GetterError) -> E,
-    _phantom: PhantomData<(S, I, A, E)>,
+mod private {
+    use crate::{HasGetter, PartialGetter};
+    use core::marker::PhantomData;
+
+    pub struct ComposedPartialGetter<
+        PG1: PartialGetter<S, I>,
+        PG2: PartialGetter<I, A>,
+        E,
+        S,
+        I,
+        A,
+        F1 = fn(<PG1 as HasGetter<S, I>>::GetterError) -> E,
+        F2 = fn(<PG2 as HasGetter<I, A>>::GetterError) -> E,
+    > {
+        pub(super) optic1: PG1,
+        pub(super) optic2: PG2,
+        pub(super) error_fn_1: F1,
+        pub(super) error_fn_2: F2,
+        pub(super) _phantom: PhantomData<(S, I, A, E)>,
+    }
+}
+
+pub type ComposedPartialGetter<
+    PG1,
+    PG2,
+    E,
+    S,
+    I,
+    A,
+    F1 = fn(<PG1 as HasGetter<S, I>>::GetterError) -> E,
+    F2 = fn(<PG2 as HasGetter<I, A>>::GetterError) -> E,
+> = private::ComposedPartialGetter<PG1, PG2, E, S, I, A, F1, F2>;
+
+impl<PG1, PG2, E, S, I, A, F1, F2> Clone for ComposedPartialGetter<PG1, PG2, E, S, I, A, F1, F2>
+where
+    PG1: PartialGetter<S, I> + Clone,
+    PG2: PartialGetter<I, A> + Clone,
+    F1: Clone,
+    F2: Clone,
+{
+    fn clone(&self) -> Self {
+        ComposedPartialGetter {
+            optic1: self.optic1.clone(),
+            optic2: self.optic2.clone(),
+            error_fn_1: self.error_fn_1.clone(),
+            error_fn_2: self.error_fn_2.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<PG1, PG2, E, S, I, A, F1, F2> Copy for ComposedPartialGetter<PG1, PG2, E, S, I, A, F1, F2>
+where
+    PG1: PartialGetter<S, I> + Copy,
+    PG2: PartialGetter<I, A> + Copy,
+    F1: Copy,
+    F2: Copy,
+{
+}
+
+impl<PG1, PG2, E, S, I, A, F1, F2> Debug for ComposedPartialGetter<PG1, PG2, E, S, I, A, F1, F2>
+where
+    PG1: PartialGetter<S, I> + Debug,
+    PG2: PartialGetter<I, A> + Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ComposedPartialGetter")
+            .field("optic1", &self.optic1)
+            .field("optic2", &self.optic2)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<PG1, PG2, E, S, I, A, F1, F2> Display for ComposedPartialGetter<PG1, PG2, E, S, I, A, F1, F2>
+where
+    PG1: PartialGetter<S, I> + Display,
+    PG2: PartialGetter<I, A> + Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}.{}", self.optic1, self.optic2)
+    }
+}
+
+impl<PG1, PG2, E, S, I, A, F1, F2> HasPath for ComposedPartialGetter<PG1, PG2, E, S, I, A, F1, F2>
+where
+    PG1: PartialGetter<S, I> + HasPath,
+    PG2: PartialGetter<I, A> + HasPath,
+{
+    fn for_each_segment<'a>(&'a self, f: &mut dyn FnMut(&'a str)) {
+        self.optic1.for_each_segment(f);
+        self.optic2.for_each_segment(f);
+    }
 }
 
 impl<PG1, PG2, E, S, I, A> ComposedPartialGetter<PG1, PG2, E, S, I, A>
@@ -31,16 +120,56 @@ where
     }
 }
 
-impl<PG1, PG2, E, S, I, A> HasGetter<S, A> for ComposedPartialGetter<PG1, PG2, E, S, I, A>
+impl<PG1, PG2, E, S, I, A, F1, F2> ComposedPartialGetter<PG1, PG2, E, S, I, A, F1, F2>
 where
     PG1: PartialGetter<S, I>,
     PG2: PartialGetter<I, A>,
+    F1: Fn(PG1::GetterError) -> E,
+    F2: Fn(PG2::GetterError) -> E,
+{
+    pub(crate) fn with_mappers(optic1: PG1, optic2: PG2, error_fn_1: F1, error_fn_2: F2) -> Self {
+        ComposedPartialGetter {
+            optic1,
+            optic2,
+            error_fn_1,
+            error_fn_2,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<PG1, PG2, E, S, I, A, F1, F2> HasGetter<S, A>
+    for ComposedPartialGetter<PG1, PG2, E, S, I, A, F1, F2>
+where
+    PG1: PartialGetter<S, I>,
+    PG2: PartialGetter<I, A>,
+    F1: Fn(PG1::GetterError) -> E,
+    F2: Fn(PG2::GetterError) -> E,
 {
     type GetterError = E;
 
+    #[inline]
     fn try_get(&self, source: &S) -> Result<A, Self::GetterError> {
-        let i = self.optic1.try_get(source).map_err(self.error_fn_1)?;
-        self.optic2.try_get(&i).map_err(self.error_fn_2)
+        self.try_get_within(source, &|_| {})
+    }
+
+    #[inline]
+    fn try_get_within(&self, source: &S, outer: OuterLabels<'_>) -> Result<A, Self::GetterError> {
+        let i = self
+            .optic1
+            .try_get_within(source, outer)
+            .map_err(&self.error_fn_1)?;
+        self.optic2
+            .try_get_within(&i, &|f| {
+                outer(f);
+                self.optic1.for_each_label(f);
+            })
+            .map_err(&self.error_fn_2)
+    }
+
+    fn for_each_label(&self, f: &mut dyn FnMut(&'static str)) {
+        self.optic1.for_each_label(f);
+        self.optic2.for_each_label(f);
     }
 }
 
@@ -76,6 +205,6 @@ pub fn new<S, A, I, E, PG1: PartialGetter<S, I>, PG2: PartialGetter<I, A>>(
     pg2: PG2,
     error_fn_1: fn(PG1::GetterError) -> E,
     error_fn_2: fn(PG2::GetterError) -> E,
-) -> PartialGetterImpl<S, A, impl PartialGetter<S, A, GetterError = E>> {
+) -> PartialGetterImpl<S, A, ComposedPartialGetter<PG1, PG2, E, S, I, A>> {
     ComposedPartialGetter::new(pg1, pg2, error_fn_1, error_fn_2).into()
 }
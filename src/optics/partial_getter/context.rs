@@ -0,0 +1,45 @@
+use crate::OuterLabels;
+use crate::optics::partial_getter::wrapper::PartialGetterImpl;
+use crate::{ContextError, HasGetter, PartialGetter};
+use core::marker::PhantomData;
+
+struct ContextPartialGetter<PG: PartialGetter<S, A>, S, A> {
+    optic: PG,
+    label: &'static str,
+    _phantom: PhantomData<(S, A)>,
+}
+
+impl<PG, S, A> HasGetter<S, A> for ContextPartialGetter<PG, S, A>
+where
+    PG: PartialGetter<S, A>,
+{
+    type GetterError = ContextError<PG::GetterError>;
+
+    fn try_get(&self, source: &S) -> Result<A, Self::GetterError> {
+        self.try_get_within(source, &|_| {})
+    }
+
+    fn try_get_within(&self, source: &S, outer: OuterLabels<'_>) -> Result<A, Self::GetterError> {
+        self.optic
+            .try_get(source)
+            .map_err(|e| ContextError::within_path(outer, self.label, e))
+    }
+
+    fn for_each_label(&self, f: &mut dyn FnMut(&'static str)) {
+        f(self.label);
+    }
+}
+
+/// Creates a `PartialGetter<S,A>` that focuses through `pg`, labelling its errors with `label`.
+pub(crate) fn new<S, A, PG: PartialGetter<S, A>>(
+    pg: PG,
+    label: &'static str,
+) -> PartialGetterImpl<S, A, impl PartialGetter<S, A, GetterError = ContextError<PG::GetterError>>>
+{
+    ContextPartialGetter {
+        optic: pg,
+        label,
+        _phantom: PhantomData,
+    }
+    .into()
+}
@@ -0,0 +1,35 @@
+use crate::optics::partial_getter::wrapper::PartialGetterImpl;
+use crate::{ErasedError, HasGetter, PartialGetter};
+use core::marker::PhantomData;
+
+struct ErasedPartialGetter<PG: PartialGetter<S, A>, S, A> {
+    optic: PG,
+    _phantom: PhantomData<fn(&S) -> A>,
+}
+
+impl<PG, S, A> HasGetter<S, A> for ErasedPartialGetter<PG, S, A>
+where
+    PG: PartialGetter<S, A>,
+    PG::GetterError: Into<ErasedError>,
+{
+    type GetterError = ErasedError;
+
+    fn try_get(&self, source: &S) -> Result<A, Self::GetterError> {
+        self.optic.try_get(source).map_err(Into::into)
+    }
+}
+
+/// Creates a `PartialGetter<S,A>` that focuses through `pg`, boxing its errors into an
+/// [`ErasedError`].
+pub(crate) fn new<S, A, PG: PartialGetter<S, A>>(
+    pg: PG,
+) -> PartialGetterImpl<S, A, impl PartialGetter<S, A, GetterError = ErasedError>>
+where
+    PG::GetterError: Into<ErasedError>,
+{
+    ErasedPartialGetter {
+        optic: pg,
+        _phantom: PhantomData,
+    }
+    .into()
+}
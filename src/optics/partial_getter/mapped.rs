@@ -1,8 +1,21 @@
+use crate::HasGetter;
 use crate::optics::partial_getter::wrapper::PartialGetterImpl;
-use crate::{HasGetter, PartialGetter};
+use core::fmt::{Debug, Formatter};
 use core::marker::PhantomData;
 
-/// A concrete implementation of the [`PartialGetter`] trait.
+mod private {
+    use core::marker::PhantomData;
+
+    pub struct MappedPartialGetter<S, A, E, GET = fn(&S) -> Result<A, E>>
+    where
+        GET: Fn(&S) -> Result<A, E>,
+    {
+        pub(super) get_fn: GET,
+        pub(super) phantom: PhantomData<(S, A)>,
+    }
+}
+
+/// A concrete implementation of the [`PartialGetter`](crate::PartialGetter) trait.
 ///
 /// This struct allows you to create a `PartialGetter` by providing custom getter and setter functions.
 /// It is the primary way to create a `PartialGetter` manually, and is flexible enough to support any
@@ -24,21 +37,43 @@ use core::marker::PhantomData;
 ///
 /// # See Also
 ///
-/// - [`Lens`] — a more restrictive optic type for focus values
+/// - [`Lens`](crate::Lens) — a more restrictive optic type for focus values
 /// - [`Optic`] — base trait that all optics implement
-struct MappedPartialGetter<S, A, E, GET = fn(&S) -> Result<A, E>>
+pub type MappedPartialGetter<S, A, E, GET = fn(&S) -> Result<A, E>> =
+    private::MappedPartialGetter<S, A, E, GET>;
+
+impl<S, A, E, GET> Clone for MappedPartialGetter<S, A, E, GET>
+where
+    GET: Fn(&S) -> Result<A, E> + Clone,
+{
+    fn clone(&self) -> Self {
+        MappedPartialGetter {
+            get_fn: self.get_fn.clone(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<S, A, E, GET> Copy for MappedPartialGetter<S, A, E, GET> where
+    GET: Fn(&S) -> Result<A, E> + Copy
+{
+}
+
+impl<S, A, E, GET> Debug for MappedPartialGetter<S, A, E, GET>
 where
     GET: Fn(&S) -> Result<A, E>,
 {
-    get_fn: GET,
-    phantom: PhantomData<(S, A)>,
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("MappedPartialGetter")
+            .finish_non_exhaustive()
+    }
 }
 
 impl<S, A, E, GET> MappedPartialGetter<S, A, E, GET>
 where
     GET: Fn(&S) -> Result<A, E>,
 {
-    fn new(get_fn: GET) -> Self {
+    const fn new(get_fn: GET) -> Self {
         MappedPartialGetter {
             get_fn,
             phantom: PhantomData,
@@ -86,11 +121,11 @@ where
 /// assert_eq!(ipv4_partial_getter.try_get(&addr), Ok("8.8.4.4".to_string()));
 /// ```
 #[must_use]
-pub fn new<S, A, E, GET>(
+pub const fn new<S, A, E, GET>(
     get_fn: GET,
-) -> PartialGetterImpl<S, A, impl PartialGetter<S, A, GetterError = E>>
+) -> PartialGetterImpl<S, A, MappedPartialGetter<S, A, E, GET>>
 where
     GET: Fn(&S) -> Result<A, E>,
 {
-    MappedPartialGetter::new(get_fn).into()
+    PartialGetterImpl::new(MappedPartialGetter::new(get_fn))
 }
@@ -1,14 +1,39 @@
 use crate::HasGetter;
 use core::convert::Infallible;
 
+#[cfg(feature = "alloc")]
+mod boxed;
 mod composed;
+#[cfg(feature = "alloc")]
+mod context;
+#[cfg(feature = "alloc")]
+mod erased;
 mod mapped;
+mod or_else;
+#[cfg(any(feature = "lens", feature = "prism"))]
+mod read_only;
+#[cfg(feature = "alloc")]
+mod shared;
+#[cfg(any(feature = "lens", feature = "prism"))]
+pub(crate) mod with_setter;
 mod wrapper;
 
+#[cfg(feature = "alloc")]
+pub use boxed::{BoxedPartialGetter, DynPartialGetter};
+pub(crate) use composed::ComposedPartialGetter;
 pub use composed::new as composed_partial_getter;
 pub use mapped::new as mapped_partial_getter;
+#[cfg(any(feature = "lens", feature = "prism"))]
+pub(crate) use read_only::ReadOnly;
+#[cfg(feature = "alloc")]
+pub use shared::SharedPartialGetter;
 pub use wrapper::PartialGetterImpl;
 
+/// A [`PartialGetterImpl`] built by [`mapped_partial_getter`] from plain functions or non-capturing closures.
+///
+/// As [`mapped_partial_getter`] is a `const fn`, this type can be used to declare the optic as a `static`.
+pub type FnPartialGetter<S, A, E> = PartialGetterImpl<S, A, mapped::MappedPartialGetter<S, A, E>>;
+
 /// A `PartialGetter` is an optic that focuses on a potentially missing value, providing
 /// only a read operations
 ///
@@ -0,0 +1,36 @@
+use crate::optics::partial_getter::wrapper::PartialGetterImpl;
+use crate::{HasGetter, PartialGetter};
+use core::marker::PhantomData;
+
+struct OrElsePartialGetter<PG1: PartialGetter<S, A>, PG2: PartialGetter<S, A>, S, A> {
+    optic1: PG1,
+    optic2: PG2,
+    _phantom: PhantomData<(S, A)>,
+}
+
+impl<PG1, PG2, S, A> HasGetter<S, A> for OrElsePartialGetter<PG1, PG2, S, A>
+where
+    PG1: PartialGetter<S, A>,
+    PG2: PartialGetter<S, A>,
+{
+    type GetterError = PG2::GetterError;
+
+    fn try_get(&self, source: &S) -> Result<A, Self::GetterError> {
+        self.optic1
+            .try_get(source)
+            .or_else(|_| self.optic2.try_get(source))
+    }
+}
+
+/// Creates a `PartialGetter<S,A>` that focuses through `pg1`, falling back to `pg2` if `pg1` fails.
+pub(crate) fn new<S, A, PG1: PartialGetter<S, A>, PG2: PartialGetter<S, A>>(
+    pg1: PG1,
+    pg2: PG2,
+) -> PartialGetterImpl<S, A, impl PartialGetter<S, A, GetterError = PG2::GetterError>> {
+    OrElsePartialGetter {
+        optic1: pg1,
+        optic2: pg2,
+        _phantom: PhantomData,
+    }
+    .into()
+}
@@ -0,0 +1,50 @@
+use crate::{HasGetter, HasPath};
+use core::fmt::{Debug, Display, Formatter};
+
+mod private {
+    #[derive(Clone, Copy)]
+    pub struct ReadOnly<O>(pub(super) O);
+}
+
+/// A `ReadOnly` wraps an optic, exposing only its [`HasGetter`] operation.
+///
+/// It is the optic handed out by the `as_getter` and `as_partial_getter` downgrades, so the
+/// write operations of the downgraded optic can not be reached through the resulting
+/// `GetterImpl` or `PartialGetterImpl`.
+///
+/// # Type Parameters
+/// - `O`: The wrapped optic.
+pub type ReadOnly<O> = private::ReadOnly<O>;
+
+impl<O: Debug> Debug for ReadOnly<O> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("ReadOnly").field(&self.0).finish()
+    }
+}
+
+impl<O: Display> Display for ReadOnly<O> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl<O: HasPath> HasPath for ReadOnly<O> {
+    fn for_each_segment<'a>(&'a self, f: &mut dyn FnMut(&'a str)) {
+        self.0.for_each_segment(f);
+    }
+}
+
+impl<O> ReadOnly<O> {
+    pub(crate) fn new(optic: O) -> Self {
+        private::ReadOnly(optic)
+    }
+}
+
+impl<S, A, O: HasGetter<S, A>> HasGetter<S, A> for ReadOnly<O> {
+    type GetterError = O::GetterError;
+
+    #[inline]
+    fn try_get(&self, source: &S) -> Result<A, Self::GetterError> {
+        self.0.try_get(source)
+    }
+}
@@ -0,0 +1,19 @@
+use crate::{DynPartialGetter, HasGetter, PartialGetterImpl};
+use alloc::sync::Arc;
+
+/// A shared partial getter of any type, focusing on a value of type `A` in a source of type `S`.
+///
+/// Like [boxed partial getters](crate::BoxedPartialGetter), shared partial getters of different
+/// types have the same type, but they are reference counted, so they can be cloned cheaply, stored
+/// in registries and sent to other threads. They are created with
+/// [`PartialGetterImpl::shared`](crate::PartialGetterImpl::shared).
+pub type SharedPartialGetter<S, A, E> =
+    PartialGetterImpl<S, A, Arc<dyn DynPartialGetter<S, A, E> + Send + Sync>>;
+
+impl<S, A, E> HasGetter<S, A> for Arc<dyn DynPartialGetter<S, A, E> + Send + Sync> {
+    type GetterError = E;
+
+    fn try_get(&self, source: &S) -> Result<A, Self::GetterError> {
+        (**self).try_get(source)
+    }
+}
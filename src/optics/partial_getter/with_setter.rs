@@ -0,0 +1,70 @@
+#[cfg(feature = "lens")]
+use crate::{Getter, Lens, LensImpl};
+use crate::{HasGetter, HasModify, HasSetter};
+#[cfg(feature = "prism")]
+use crate::{PartialGetter, Prism, PrismImpl};
+use core::marker::PhantomData;
+
+struct WithSetter<G: HasGetter<S, A>, F: Fn(&mut S, A), S, A> {
+    optic: G,
+    set_fn: F,
+    _phantom: PhantomData<(S, A)>,
+}
+
+impl<G, F, S, A> HasGetter<S, A> for WithSetter<G, F, S, A>
+where
+    G: HasGetter<S, A>,
+    F: Fn(&mut S, A),
+{
+    type GetterError = G::GetterError;
+
+    fn try_get(&self, source: &S) -> Result<A, Self::GetterError> {
+        self.optic.try_get(source)
+    }
+}
+
+impl<G, F, S, A> HasSetter<S, A> for WithSetter<G, F, S, A>
+where
+    G: HasGetter<S, A>,
+    F: Fn(&mut S, A),
+{
+    fn set(&self, source: &mut S, value: A) {
+        (self.set_fn)(source, value);
+    }
+}
+
+impl<G, F, S, A> HasModify<S, A> for WithSetter<G, F, S, A>
+where
+    G: HasGetter<S, A>,
+    F: Fn(&mut S, A),
+{
+}
+
+/// Creates a `Prism<S,A>` that reads its focus through `partial_getter` and writes it with
+/// `set_fn`.
+#[cfg(feature = "prism")]
+pub(crate) fn new<S, A, PG: PartialGetter<S, A>, F: Fn(&mut S, A)>(
+    partial_getter: PG,
+    set_fn: F,
+) -> PrismImpl<S, A, impl Prism<S, A, GetterError = PG::GetterError>> {
+    WithSetter {
+        optic: partial_getter,
+        set_fn,
+        _phantom: PhantomData,
+    }
+    .into()
+}
+
+/// Creates a `Lens<S,A>` that reads its focus through `getter` and writes it with `set_fn`.
+#[cfg(feature = "lens")]
+pub(crate) fn new_lens<S, A, G: Getter<S, A>, F: Fn(&mut S, A)>(
+    getter: G,
+    set_fn: F,
+) -> LensImpl<S, A, impl Lens<S, A>> {
+    WithSetter {
+        optic: getter,
+        set_fn,
+        _phantom: PhantomData,
+    }
+    .into()
+}
@@ -1,10 +1,42 @@
+#[cfg(feature = "fallible-iso")]
+use crate::FallibleIso;
+#[cfg(feature = "getter")]
+use crate::Getter;
+use crate::HasPath;
+#[cfg(feature = "iso")]
+use crate::Iso;
+#[cfg(feature = "lens")]
+use crate::Lens;
+use crate::OuterLabels;
+#[cfg(any(feature = "prism", feature = "setter"))]
+use crate::Prism;
+#[cfg(feature = "prism")]
+use crate::PrismImpl;
+#[cfg(feature = "setter")]
+use crate::Setter;
+use crate::named::Named;
+use crate::optics::partial_getter::ComposedPartialGetter;
 use crate::optics::partial_getter::composed::new as composed_partial_getter;
-use crate::{
-    FallibleIso, FallibleIsoImpl, Getter, GetterImpl, HasGetter, Iso, IsoImpl, Lens, LensImpl,
-    PartialGetter, Prism, PrismImpl, Setter, SetterImpl, infallible,
-};
-use core::convert::identity;
+use crate::optics::partial_getter::or_else::new as or_else;
+#[cfg(feature = "prism")]
+use crate::optics::partial_getter::with_setter::new as with_setter;
+#[cfg(feature = "tracing")]
+use crate::traced::Traced;
+use crate::{Either, HasGetter, PartialGetter, infallible, mapped_partial_getter};
+use core::convert::{Infallible, identity};
+use core::fmt::{Debug, Display, Formatter};
 use core::marker::PhantomData;
+#[cfg(feature = "alloc")]
+use {
+    crate::optics::partial_getter::context::new as context,
+    crate::optics::partial_getter::erased::new as erase_err,
+    crate::optics::partial_getter::{BoxedPartialGetter, DynPartialGetter, SharedPartialGetter},
+    crate::{ContextError, ErasedError},
+    alloc::boxed::Box,
+    alloc::string::{String, ToString},
+    alloc::sync::Arc,
+    alloc::vec::Vec,
+};
 
 /// A wrapper of the [`PartialGetter`] optic implementations, encapsulating a partial getter function.
 ///
@@ -30,7 +62,7 @@ use core::marker::PhantomData;
 pub struct PartialGetterImpl<S, A, PG: PartialGetter<S, A>>(pub PG, PhantomData<(S, A)>);
 
 impl<S, A, PG: PartialGetter<S, A>> PartialGetterImpl<S, A, PG> {
-    fn new(prism: PG) -> Self {
+    pub(crate) const fn new(prism: PG) -> Self {
         //TODO: Verify not to nest an Impl inside an Impl - currently seems to be impossible at compile time.
         PartialGetterImpl(prism, PhantomData)
     }
@@ -42,12 +74,141 @@ impl<S, A, PG: PartialGetter<S, A>> From<PG> for PartialGetterImpl<S, A, PG> {
     }
 }
 
+impl<S, A, PG: PartialGetter<S, A> + Clone> Clone for PartialGetterImpl<S, A, PG> {
+    fn clone(&self) -> Self {
+        Self::new(self.0.clone())
+    }
+}
+
+impl<S, A, PG: PartialGetter<S, A> + Copy> Copy for PartialGetterImpl<S, A, PG> {}
+
+impl<S, A, PG: PartialGetter<S, A> + Debug> Debug for PartialGetterImpl<S, A, PG> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("PartialGetterImpl").field(&self.0).finish()
+    }
+}
+
+impl<S, A, PG: PartialGetter<S, A> + Display> Display for PartialGetterImpl<S, A, PG> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl<S, A, PG: PartialGetter<S, A> + HasPath> HasPath for PartialGetterImpl<S, A, PG> {
+    fn for_each_segment<'a>(&'a self, f: &mut dyn FnMut(&'a str)) {
+        self.0.for_each_segment(f);
+    }
+}
+
+impl<S, A, PG: PartialGetter<S, A>> PartialGetterImpl<S, A, PG> {
+    /// Labels this optic with a static `name`, used by its [`Display`] implementation.
+    ///
+    /// Composing named optics results in an optic displaying the names of its parts joined with a
+    /// `.`, which helps to identify a misbehaving optic in logs and error messages.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The name of the optic.
+    ///
+    /// # Returns
+    ///
+    /// A new `PartialGetterImpl` behaving exactly like `self`, displayed as `name`.
+    #[must_use]
+    pub fn named(self, name: &'static str) -> PartialGetterImpl<S, A, Named<PG>> {
+        Named::new(self.0, name).into()
+    }
+
+    /// Wraps this optic to emit a `tracing` event for every operation going through it, with
+    /// `name` identifying the optic, whether the operation succeeded and the time it took.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The name of the optic in the events, such as `"config.port"`.
+    ///
+    /// # Returns
+    ///
+    /// A new `PartialGetterImpl` behaving exactly like `self`, tracing its operations.
+    #[cfg(feature = "tracing")]
+    #[must_use]
+    pub fn traced(self, name: &'static str) -> PartialGetterImpl<S, A, Traced<PG>> {
+        Traced::new(self.0, name).into()
+    }
+
+    /// Returns the name of the optic, the names of the optics it is composed of joined with a `.`.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn name(&self) -> String
+    where
+        PG: Display,
+    {
+        self.to_string()
+    }
+
+    /// Returns the names of the fields and variants this optic focuses through, from the outermost
+    /// one, such as the field names of the [`field_lens!`](crate::field_lens) lenses it is
+    /// composed of.
+    #[cfg(feature = "alloc")]
+    pub fn path(&self) -> impl Iterator<Item = &str>
+    where
+        PG: HasPath,
+    {
+        let mut path = Vec::new();
+        self.0.for_each_segment(&mut |segment| path.push(segment));
+        path.into_iter()
+    }
+
+    /// Boxes this partial getter, erasing its type, so it can be stored alongside partial getters
+    /// of other types.
+    ///
+    /// # Returns
+    ///
+    /// A [`BoxedPartialGetter`] behaving exactly like `self`.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn boxed(self) -> BoxedPartialGetter<S, A, PG::GetterError>
+    where
+        PG: Send + Sync + 'static,
+    {
+        let optic: Box<dyn DynPartialGetter<S, A, PG::GetterError> + Send + Sync> =
+            Box::new(self.0);
+        optic.into()
+    }
+
+    /// Wraps this partial getter in an [`Arc`], erasing its type, so it can be cloned cheaply and
+    /// shared between threads.
+    ///
+    /// # Returns
+    ///
+    /// A [`SharedPartialGetter`] behaving exactly like `self`.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn shared(self) -> SharedPartialGetter<S, A, PG::GetterError>
+    where
+        PG: Send + Sync + 'static,
+    {
+        let optic: Arc<dyn DynPartialGetter<S, A, PG::GetterError> + Send + Sync> =
+            Arc::new(self.0);
+        optic.into()
+    }
+}
+
 impl<S, A, PG: PartialGetter<S, A>> HasGetter<S, A> for PartialGetterImpl<S, A, PG> {
     type GetterError = PG::GetterError;
 
+    #[inline]
     fn try_get(&self, source: &S) -> Result<A, Self::GetterError> {
         self.0.try_get(source)
     }
+
+    #[inline]
+    fn try_get_within(&self, source: &S, outer: OuterLabels<'_>) -> Result<A, Self::GetterError> {
+        self.0.try_get_within(source, outer)
+    }
+
+    #[inline]
+    fn for_each_label(&self, f: &mut dyn FnMut(&'static str)) {
+        self.0.for_each_label(f);
+    }
 }
 
 impl<S, I, PG1: PartialGetter<S, I>> PartialGetterImpl<S, I, PG1> {
@@ -79,13 +240,13 @@ impl<S, I, PG1: PartialGetter<S, I>> PartialGetterImpl<S, I, PG1> {
     /// [`compose_with_partial_getter_with_mappers`](Self::compose_with_partial_getter_with_mappers).
     pub fn compose_with_partial_getter<E, A, PG2: PartialGetter<I, A>>(
         self,
-        other: PartialGetterImpl<I, A, PG2>,
-    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A, GetterError = E>>
+        other: PG2,
+    ) -> PartialGetterImpl<S, A, ComposedPartialGetter<PG1, PG2, E, S, I, A>>
     where
         PG1::GetterError: Into<E>,
         PG2::GetterError: Into<E>,
     {
-        composed_partial_getter(self.0, other.0, Into::into, Into::into)
+        composed_partial_getter(self.0, other, Into::into, Into::into)
     }
 
     /// Composes this `PartialGetterImpl<S,I>` with a `PartialGetter<I,A>`, resulting in a new `PartialGetter<S, A>`
@@ -115,13 +276,45 @@ impl<S, I, PG1: PartialGetter<S, I>> PartialGetterImpl<S, I, PG1> {
     /// This method is similar to [`compose_with_partial_getter`](Self::compose_with_partial_getter), but
     /// provides the ability to specify custom functions to map the errors from each
     /// optic into a common error type.
-    pub fn compose_with_partial_getter_with_mappers<E, A, PG2: PartialGetter<I, A>>(
+    #[allow(clippy::type_complexity)]
+    pub fn compose_with_partial_getter_with_mappers<E, A, PG2: PartialGetter<I, A>, F1, F2>(
+        self,
+        other: PG2,
+        error_mapper_1: F1,
+        error_mapper_2: F2,
+    ) -> PartialGetterImpl<S, A, ComposedPartialGetter<PG1, PG2, E, S, I, A, F1, F2>>
+    where
+        F1: Fn(PG1::GetterError) -> E,
+        F2: Fn(PG2::GetterError) -> E,
+    {
+        ComposedPartialGetter::with_mappers(self.0, other, error_mapper_1, error_mapper_2).into()
+    }
+
+    /// Composes this `PartialGetterImpl<S,I>` with a `PartialGetterImpl<I,A>`, resulting in a new `PartialGetterImpl<S, A>`
+    /// whose error identifies which of the two optics failed.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `A`: The target type of the composed optic.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: The partial getter to compose with.
+    ///
+    /// # Returns
+    ///
+    /// A new `PartialGetterImpl` failing with a [`Either::Left`] if `self` fails, or a
+    /// [`Either::Right`] if `other` fails.
+    #[allow(clippy::type_complexity)]
+    pub fn compose_with_partial_getter_tracking<A, PG2: PartialGetter<I, A>>(
         self,
-        other: PartialGetterImpl<I, A, PG2>,
-        error_mapper_1: fn(PG1::GetterError) -> E,
-        error_mapper_2: fn(PG2::GetterError) -> E,
-    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A>> {
-        composed_partial_getter(self.0, other.0, error_mapper_1, error_mapper_2)
+        other: PG2,
+    ) -> PartialGetterImpl<
+        S,
+        A,
+        ComposedPartialGetter<PG1, PG2, Either<PG1::GetterError, PG2::GetterError>, S, I, A>,
+    > {
+        composed_partial_getter(self.0, other, Either::Left, Either::Right)
     }
 
     /// Composes this `PartialGetterImpl<S,I>` with a `GetterImpl<I,A>`, resulting in a new `PartialGetterImpl<S, A>`
@@ -143,17 +336,20 @@ impl<S, I, PG1: PartialGetter<S, I>> PartialGetterImpl<S, I, PG1> {
     ///
     /// A new `PartialGetterImpl` that represents the composition of `self` and `other`.
     ///
+    #[cfg(feature = "getter")]
+    #[allow(clippy::type_complexity)]
     pub fn compose_with_getter<A, G2: Getter<I, A>>(
         self,
-        other: GetterImpl<I, A, G2>,
-    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A>> {
-        composed_partial_getter(self.0, other.0, identity, infallible)
+        other: G2,
+    ) -> PartialGetterImpl<S, A, ComposedPartialGetter<PG1, G2, PG1::GetterError, S, I, A>> {
+        composed_partial_getter(self.0, other, identity, infallible)
     }
 
     /// Impossible to combine
     /// # Panics
     /// always
-    pub fn compose_with_setter<A, S2: Setter<I, A>>(self, _other: SetterImpl<I, A, S2>) -> !
+    #[cfg(feature = "setter")]
+    pub fn compose_with_setter<A, S2: Setter<I, A>>(self, _other: S2) -> !
     where
         PG1: Prism<S, I>,
     {
@@ -186,15 +382,16 @@ impl<S, I, PG1: PartialGetter<S, I>> PartialGetterImpl<S, I, PG1> {
     /// This method uses `Into::into` to convert the errors from both prisms into the
     /// common error type `E`. If you need custom error mapping, consider using
     /// [`compose_with_prism_with_mappers`](Self::compose_with_prism_with_mappers).
+    #[cfg(feature = "prism")]
     pub fn compose_with_prism<E, A, P2: Prism<I, A>>(
         self,
-        other: PrismImpl<I, A, P2>,
-    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A>>
+        other: P2,
+    ) -> PartialGetterImpl<S, A, ComposedPartialGetter<PG1, P2, E, S, I, A>>
     where
         PG1::GetterError: Into<E>,
         P2::GetterError: Into<E>,
     {
-        composed_partial_getter(self.0, other.0, Into::into, Into::into)
+        composed_partial_getter(self.0, other, Into::into, Into::into)
     }
 
     /// Composes this `PartialGetterImpl<S,I>` with a `PrismImpl<I,A>`, resulting in a new `PartialGetterImpl<S, A>`
@@ -224,13 +421,47 @@ impl<S, I, PG1: PartialGetter<S, I>> PartialGetterImpl<S, I, PG1> {
     /// This method is similar to [`compose_with_prism`](Self::compose_with_prism), but
     /// provides the ability to specify custom functions to map the errors from each
     /// prism into a common error type.
-    pub fn compose_with_prism_with_mappers<E, A, P2: Prism<I, A>>(
+    #[cfg(feature = "prism")]
+    #[allow(clippy::type_complexity)]
+    pub fn compose_with_prism_with_mappers<E, A, P2: Prism<I, A>, F1, F2>(
+        self,
+        other: P2,
+        error_mapper_1: F1,
+        error_mapper_2: F2,
+    ) -> PartialGetterImpl<S, A, ComposedPartialGetter<PG1, P2, E, S, I, A, F1, F2>>
+    where
+        F1: Fn(PG1::GetterError) -> E,
+        F2: Fn(P2::GetterError) -> E,
+    {
+        ComposedPartialGetter::with_mappers(self.0, other, error_mapper_1, error_mapper_2).into()
+    }
+
+    /// Composes this `PartialGetterImpl<S,I>` with a `PrismImpl<I,A>`, resulting in a new `PartialGetterImpl<S, A>`
+    /// whose error identifies which of the two optics failed.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `A`: The target type of the composed optic.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: The prism to compose with.
+    ///
+    /// # Returns
+    ///
+    /// A new `PartialGetterImpl` failing with a [`Either::Left`] if `self` fails, or a
+    /// [`Either::Right`] if `other` fails.
+    #[cfg(feature = "prism")]
+    #[allow(clippy::type_complexity)]
+    pub fn compose_with_prism_tracking<A, P2: Prism<I, A>>(
         self,
-        other: PrismImpl<I, A, P2>,
-        error_mapper_1: fn(PG1::GetterError) -> E,
-        error_mapper_2: fn(P2::GetterError) -> E,
-    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A>> {
-        composed_partial_getter(self.0, other.0, error_mapper_1, error_mapper_2)
+        other: P2,
+    ) -> PartialGetterImpl<
+        S,
+        A,
+        ComposedPartialGetter<PG1, P2, Either<PG1::GetterError, P2::GetterError>, S, I, A>,
+    > {
+        composed_partial_getter(self.0, other, Either::Left, Either::Right)
     }
 
     /// Composes this `PartialGetterImpl<S,I>` with a `Lens<I,A>`, resulting in a new `PartialGetterImpl<S, A>`
@@ -251,11 +482,13 @@ impl<S, I, PG1: PartialGetter<S, I>> PartialGetterImpl<S, I, PG1> {
     /// # Returns
     ///
     /// A new `PartialGetterImpl` that represents the composition of `self` and `other`
+    #[cfg(feature = "lens")]
+    #[allow(clippy::type_complexity)]
     pub fn compose_with_lens<A, L2: Lens<I, A>>(
         self,
-        other: LensImpl<I, A, L2>,
-    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A>> {
-        composed_partial_getter(self.0, other.0, identity, infallible)
+        other: L2,
+    ) -> PartialGetterImpl<S, A, ComposedPartialGetter<PG1, L2, PG1::GetterError, S, I, A>> {
+        composed_partial_getter(self.0, other, identity, infallible)
     }
 
     /// Composes this `PartialGetterImpl<S,I>` with a `FallibleIso<I,A>`, resulting in a new `PartialGetterImpl<S, A>`
@@ -284,14 +517,15 @@ impl<S, I, PG1: PartialGetter<S, I>> PartialGetterImpl<S, I, PG1> {
     /// This method uses `Into::into` to convert the errors from both prisms into the
     /// common error type `E`. If you need custom error mapping, consider using
     /// [`compose_with_fallible_iso_with_mappers`](Self::compose_with_fallible_iso_with_mappers).
+    #[cfg(feature = "fallible-iso")]
     pub fn compose_with_fallible_iso<E, A, FI2: FallibleIso<I, A>>(
         self,
-        other: FallibleIsoImpl<I, A, FI2>,
-    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A>>
+        other: FI2,
+    ) -> PartialGetterImpl<S, A, ComposedPartialGetter<PG1, FI2, E, S, I, A>>
     where
         E: From<FI2::GetterError> + From<PG1::GetterError>,
     {
-        composed_partial_getter(self.0, other.0, Into::<E>::into, Into::<E>::into)
+        composed_partial_getter(self.0, other, Into::<E>::into, Into::<E>::into)
     }
 
     /// Composes this `PartialGetterImpl<S,I>` with a `FallibleIso<I,A>`, resulting in a new `PartialGetterImpl<S, A>`
@@ -322,18 +556,53 @@ impl<S, I, PG1: PartialGetter<S, I>> PartialGetterImpl<S, I, PG1> {
     /// This method is similar to [`compose_with_fallible_iso`](Self::compose_with_fallible_iso), but
     /// provides the ability to specify custom functions to map the errors from each
     /// prism into a common error type.
-    pub fn compose_with_fallible_iso_with_mappers<E, A, FI2: FallibleIso<I, A>>(
+    #[cfg(feature = "fallible-iso")]
+    #[allow(clippy::type_complexity)]
+    pub fn compose_with_fallible_iso_with_mappers<E, A, FI2: FallibleIso<I, A>, F1, F2>(
         self,
-        other: FallibleIsoImpl<I, A, FI2>,
-        getter_error_mapper_1: fn(PG1::GetterError) -> E,
-        getter_error_mapper_2: fn(FI2::GetterError) -> E,
-    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A>> {
-        composed_partial_getter(
+        other: FI2,
+        getter_error_mapper_1: F1,
+        getter_error_mapper_2: F2,
+    ) -> PartialGetterImpl<S, A, ComposedPartialGetter<PG1, FI2, E, S, I, A, F1, F2>>
+    where
+        F1: Fn(PG1::GetterError) -> E,
+        F2: Fn(FI2::GetterError) -> E,
+    {
+        ComposedPartialGetter::with_mappers(
             self.0,
-            other.0,
+            other,
             getter_error_mapper_1,
             getter_error_mapper_2,
         )
+        .into()
+    }
+
+    /// Composes this `PartialGetterImpl<S,I>` with a `FallibleIsoImpl<I,A>`, resulting in a new `PartialGetterImpl<S, A>`
+    /// whose error identifies which of the two optics failed.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `A`: The target type of the composed optic.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: The fallible iso to compose with.
+    ///
+    /// # Returns
+    ///
+    /// A new `PartialGetterImpl` failing with a [`Either::Left`] if `self` fails, or a
+    /// [`Either::Right`] if `other` fails.
+    #[cfg(feature = "fallible-iso")]
+    #[allow(clippy::type_complexity)]
+    pub fn compose_with_fallible_iso_tracking<A, FI2: FallibleIso<I, A>>(
+        self,
+        other: FI2,
+    ) -> PartialGetterImpl<
+        S,
+        A,
+        ComposedPartialGetter<PG1, FI2, Either<PG1::GetterError, FI2::GetterError>, S, I, A>,
+    > {
+        composed_partial_getter(self.0, other, Either::Left, Either::Right)
     }
 
     /// Composes this `PartialGetterImpl<S,I>` with an `Iso<I,A>`, resulting in a new `PartialGetterImpl<S, A>`
@@ -354,10 +623,173 @@ impl<S, I, PG1: PartialGetter<S, I>> PartialGetterImpl<S, I, PG1> {
     /// # Returns
     ///
     /// A new `PartialGetterImpl` that represents the composition of `self` and `other`
+    #[cfg(feature = "iso")]
+    #[allow(clippy::type_complexity)]
     pub fn compose_with_iso<A, ISO2: Iso<I, A>>(
         self,
-        other: IsoImpl<I, A, ISO2>,
-    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A, GetterError = PG1::GetterError>> {
-        composed_partial_getter(self.0, other.0, identity, infallible)
+        other: ISO2,
+    ) -> PartialGetterImpl<S, A, ComposedPartialGetter<PG1, ISO2, PG1::GetterError, S, I, A>> {
+        composed_partial_getter(self.0, other, identity, infallible)
+    }
+}
+
+impl<S, A, PG1: PartialGetter<S, A>> PartialGetterImpl<S, A, PG1> {
+    /// Combines this `PartialGetterImpl<S,A>` with another `PartialGetterImpl<S,A>` focusing on the same type, resulting in
+    /// a new `PartialGetterImpl<S, A>` that falls back to `other` if `self` fails to retrieve its focus.
+    ///
+    /// This is useful for reading a value that may be found in one of several locations.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: The fallback partial getter.
+    ///
+    /// # Returns
+    ///
+    /// A new `PartialGetterImpl` that fails with the error of `other` only if both optics fail.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use optics::{HasGetter, mapped_partial_getter};
+    ///
+    /// struct Config {
+    ///     port: Option<u16>,
+    ///     legacy_port: Option<u16>,
+    /// }
+    ///
+    /// let port = mapped_partial_getter(|c: &Config| c.port.ok_or(()));
+    /// let legacy_port = mapped_partial_getter(|c: &Config| c.legacy_port.ok_or(()));
+    ///
+    /// let any_port = port.or_else(legacy_port);
+    ///
+    /// assert_eq!(any_port.try_get(&Config { port: Some(80), legacy_port: Some(8080) }), Ok(80));
+    /// assert_eq!(any_port.try_get(&Config { port: None, legacy_port: Some(8080) }), Ok(8080));
+    /// assert_eq!(any_port.try_get(&Config { port: None, legacy_port: None }), Err(()));
+    /// ```
+    pub fn or_else<PG2: PartialGetter<S, A>>(
+        self,
+        other: PartialGetterImpl<S, A, PG2>,
+    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A, GetterError = PG2::GetterError>> {
+        or_else(self.0, other)
+    }
+
+    /// Maps the focus of this `PartialGetterImpl<S,A>` with `f`, resulting in a read-only
+    /// `PartialGetterImpl<S,B>` focusing on a value derived from the focus.
+    ///
+    /// Retrieving the focus fails with the error of `self`.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: The function deriving the new focus from the focus of `self`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use optics::{HasGetter, mapped_partial_getter};
+    ///
+    /// struct Server {
+    ///     host: String,
+    /// }
+    ///
+    /// let host = mapped_partial_getter(|s: &Server| {
+    ///     s.host.strip_prefix("local").map(str::to_string).ok_or(())
+    /// });
+    /// let host_len = host.map(String::len);
+    /// let server = Server { host: "localhost".to_string() };
+    ///
+    /// assert_eq!(host_len.try_get(&server), Ok(4));
+    /// ```
+    pub fn map<B, F: Fn(&A) -> B>(
+        self,
+        f: F,
+    ) -> PartialGetterImpl<S, B, impl PartialGetter<S, B, GetterError = PG1::GetterError>> {
+        let mapper = mapped_partial_getter(move |a: &A| Ok::<_, Infallible>(f(a)));
+        composed_partial_getter(self.0, mapper.0, identity, infallible)
+    }
+
+    /// Labels the errors of this `PartialGetterImpl<S,A>` with `label`, resulting in a new `PartialGetterImpl<S,A>`
+    /// whose errors are wrapped in a [`ContextError`].
+    ///
+    /// The label is the last segment of the path carried by the error. When this optic is composed
+    /// after other labelled optics, their labels are prepended to the path automatically, so a
+    /// failure deep in a chain of compositions reports where it happened.
+    ///
+    /// # Parameters
+    ///
+    /// - `label`: A human-readable name of the part of the structure this optic focuses on.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use optics::{HasGetter, mapped_partial_getter};
+    ///
+    /// let port = mapped_partial_getter(|s: &String| s.parse::<u16>()).context("port");
+    /// let error = port.try_get(&"http".to_string()).unwrap_err();
+    ///
+    /// assert_eq!(error.path(), ["port"]);
+    /// assert_eq!(error.to_string(), "port: invalid digit found in string");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn context(
+        self,
+        label: &'static str,
+    ) -> PartialGetterImpl<
+        S,
+        A,
+        impl PartialGetter<S, A, GetterError = ContextError<PG1::GetterError>>,
+    > {
+        context(self.0, label)
+    }
+
+    /// Boxes the errors of this `PartialGetterImpl<S,A>` into an [`ErasedError`], resulting in a new
+    /// `PartialGetterImpl<S,A>` that can be composed with optics of any other error type without mapping
+    /// the errors into a common type.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use optics::{HasGetter, mapped_partial_getter};
+    ///
+    /// let port = mapped_partial_getter(|s: &String| s.parse::<u16>()).erase_err();
+    /// let error = port.try_get(&"http".to_string()).unwrap_err();
+    ///
+    /// assert_eq!(error.to_string(), "invalid digit found in string");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn erase_err(
+        self,
+    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A, GetterError = ErasedError>>
+    where
+        PG1::GetterError: Into<ErasedError>,
+    {
+        erase_err(self.0)
+    }
+
+    /// Upgrades this `PartialGetterImpl<S,A>` to a `PrismImpl<S,A>` by attaching `set_fn` as the
+    /// way to write the focus, so the read half of a prism can be derived first and the write half
+    /// added later.
+    ///
+    /// # Parameters
+    ///
+    /// - `set_fn`: The function writing a new focus into the source.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use optics::{HasGetter, HasSetter, mapped_partial_getter};
+    ///
+    /// let port = mapped_partial_getter(|s: &String| s.parse::<u16>())
+    ///     .with_setter(|s, port: u16| *s = port.to_string());
+    /// let mut text = "http".to_string();
+    ///
+    /// port.set(&mut text, 8080);
+    /// assert_eq!(port.try_get(&text), Ok(8080));
+    /// ```
+    #[cfg(feature = "prism")]
+    pub fn with_setter<F: Fn(&mut S, A)>(
+        self,
+        set_fn: F,
+    ) -> PrismImpl<S, A, impl Prism<S, A, GetterError = PG1::GetterError>> {
+        with_setter(self.0, set_fn)
     }
 }
@@ -0,0 +1,34 @@
+use crate::{HasGetter, HasModify, HasSetter, PrismImpl};
+use alloc::boxed::Box;
+
+/// An object safe version of [`Prism`](crate::Prism), implemented by every prism.
+///
+/// It can be used as a trait object, such as in a [`BoxedPrism`], to store prisms of different
+/// types in the same collection. The methods of the [`HasModify`](crate::HasModify) base trait are
+/// not object safe, so they are not part of it, but they are implemented by the boxed prism.
+pub trait DynPrism<S, A, E>: HasGetter<S, A, GetterError = E> + HasSetter<S, A> {}
+
+impl<S, A, E, O: HasGetter<S, A, GetterError = E> + HasSetter<S, A>> DynPrism<S, A, E> for O {}
+
+/// A boxed prism of any type, focusing on a value of type `A` in a source of type `S`.
+///
+/// Unlike the opaque prisms returned by the constructors of this crate, boxed prisms of different
+/// types have the same type, so they can be stored in collections or struct fields, and composed
+/// like any other prism. They are created with [`PrismImpl::boxed`].
+pub type BoxedPrism<S, A, E> = PrismImpl<S, A, Box<dyn DynPrism<S, A, E> + Send + Sync>>;
+
+impl<S, A, E> HasGetter<S, A> for Box<dyn DynPrism<S, A, E> + Send + Sync> {
+    type GetterError = E;
+
+    fn try_get(&self, source: &S) -> Result<A, Self::GetterError> {
+        (**self).try_get(source)
+    }
+}
+
+impl<S, A, E> HasSetter<S, A> for Box<dyn DynPrism<S, A, E> + Send + Sync> {
+    fn set(&self, source: &mut S, value: A) {
+        (**self).set(source, value);
+    }
+}
+
+impl<S, A, E> HasModify<S, A> for Box<dyn DynPrism<S, A, E> + Send + Sync> {}
@@ -1,9 +1,37 @@
+use crate::HasFallibleSetter;
 use crate::HasGetter;
+use crate::HasInsert;
+use crate::HasModify;
+use crate::HasPath;
 use crate::HasSetter;
+use crate::OuterLabels;
 use crate::optics::prism::Prism;
 use crate::optics::prism::wrapper::PrismImpl;
+use core::fmt::{Debug, Display, Formatter};
 use core::marker::PhantomData;
 
+mod private {
+    use crate::{HasGetter, Prism};
+    use core::marker::PhantomData;
+
+    pub struct ComposedPrism<
+        P1: Prism<S, I>,
+        P2: Prism<I, A>,
+        E,
+        S,
+        I,
+        A,
+        F1 = fn(<P1 as HasGetter<S, I>>::GetterError) -> E,
+        F2 = fn(<P2 as HasGetter<I, A>>::GetterError) -> E,
+    > {
+        pub(super) optic1: P1,
+        pub(super) optic2: P2,
+        pub(super) error_fn_1: F1,
+        pub(super) error_fn_2: F2,
+        pub(super) _phantom: PhantomData<(S, I, A, E)>,
+    }
+}
+
 /// A `ComposedPrism` represents the composition of two optics, resulting in a `Prism` that focuses
 /// from a source type `S` to a target type `A` through an intermediate type `I`.
 ///
@@ -22,18 +50,84 @@ use core::marker::PhantomData;
 /// - `S`: The source type.
 /// - `I`: The intermediate type.
 /// - `A`: The target type.
+/// - `F1`: The function mapping `O1`'s getter error, a function pointer by default.
+/// - `F2`: The function mapping `O2`'s getter error, a function pointer by default.
 ///
 /// # Fields
 /// - `optic1`: The first optic instance.
 /// - `optic2`: The second optic instance.
 /// - `error_fn_1`: A function to map `O1`'s getter error to the unified error type `E`.
 /// - `error_fn_2`: A function to map `O2`'s getter error to the unified error type `E`.
-struct ComposedPrism<P1: Prism<S, I>, P2: Prism<I, A>, E, S, I, A> {
-    optic1: P1,
-    optic2: P2,
-    error_fn_1: fn(P1::GetterError) -> E,
-    error_fn_2: fn(P2::GetterError) -> E,
-    _phantom: PhantomData<(S, I, A, E)>,
+pub type ComposedPrism<
+    P1,
+    P2,
+    E,
+    S,
+    I,
+    A,
+    F1 = fn(<P1 as HasGetter<S, I>>::GetterError) -> E,
+    F2 = fn(<P2 as HasGetter<I, A>>::GetterError) -> E,
+> = private::ComposedPrism<P1, P2, E, S, I, A, F1, F2>;
+
+impl<P1, P2, E, S, I, A, F1, F2> Clone for ComposedPrism<P1, P2, E, S, I, A, F1, F2>
+where
+    P1: Prism<S, I> + Clone,
+    P2: Prism<I, A> + Clone,
+    F1: Clone,
+    F2: Clone,
+{
+    fn clone(&self) -> Self {
+        ComposedPrism {
+            optic1: self.optic1.clone(),
+            optic2: self.optic2.clone(),
+            error_fn_1: self.error_fn_1.clone(),
+            error_fn_2: self.error_fn_2.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<P1, P2, E, S, I, A, F1, F2> Copy for ComposedPrism<P1, P2, E, S, I, A, F1, F2>
+where
+    P1: Prism<S, I> + Copy,
+    P2: Prism<I, A> + Copy,
+    F1: Copy,
+    F2: Copy,
+{
+}
+
+impl<P1, P2, E, S, I, A, F1, F2> Debug for ComposedPrism<P1, P2, E, S, I, A, F1, F2>
+where
+    P1: Prism<S, I> + Debug,
+    P2: Prism<I, A> + Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ComposedPrism")
+            .field("optic1", &self.optic1)
+            .field("optic2", &self.optic2)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<P1, P2, E, S, I, A, F1, F2> Display for ComposedPrism<P1, P2, E, S, I, A, F1, F2>
+where
+    P1: Prism<S, I> + Display,
+    P2: Prism<I, A> + Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}.{}", self.optic1, self.optic2)
+    }
+}
+
+impl<P1, P2, E, S, I, A, F1, F2> HasPath for ComposedPrism<P1, P2, E, S, I, A, F1, F2>
+where
+    P1: Prism<S, I> + HasPath,
+    P2: Prism<I, A> + HasPath,
+{
+    fn for_each_segment<'a>(&'a self, f: &mut dyn FnMut(&'a str)) {
+        self.optic1.for_each_segment(f);
+        self.optic2.for_each_segment(f);
+    }
 }
 
 impl<P1, P2, E, S, I, A> ComposedPrism<P1, P2, E, S, I, A>
@@ -41,7 +135,7 @@ where
     P1: Prism<S, I>,
     P2: Prism<I, A>,
 {
-    fn new(
+    pub(crate) fn new(
         optic1: P1,
         optic2: P2,
         error_fn_1: fn(P1::GetterError) -> E,
@@ -57,29 +151,113 @@ where
     }
 }
 
-impl<P1, P2, E, S, I, A> HasGetter<S, A> for ComposedPrism<P1, P2, E, S, I, A>
+impl<P1, P2, E, S, I, A, F1, F2> ComposedPrism<P1, P2, E, S, I, A, F1, F2>
 where
     P1: Prism<S, I>,
     P2: Prism<I, A>,
+    F1: Fn(P1::GetterError) -> E,
+    F2: Fn(P2::GetterError) -> E,
+{
+    pub(crate) fn with_mappers(optic1: P1, optic2: P2, error_fn_1: F1, error_fn_2: F2) -> Self {
+        ComposedPrism {
+            optic1,
+            optic2,
+            error_fn_1,
+            error_fn_2,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<P1, P2, E, S, I, A, F1, F2> HasGetter<S, A> for ComposedPrism<P1, P2, E, S, I, A, F1, F2>
+where
+    P1: Prism<S, I>,
+    P2: Prism<I, A>,
+    F1: Fn(P1::GetterError) -> E,
+    F2: Fn(P2::GetterError) -> E,
 {
     type GetterError = E;
 
+    #[inline]
     fn try_get(&self, source: &S) -> Result<A, Self::GetterError> {
-        let i = self.optic1.try_get(source).map_err(self.error_fn_1)?;
-        self.optic2.try_get(&i).map_err(self.error_fn_2)
+        self.try_get_within(source, &|_| {})
+    }
+
+    #[inline]
+    fn try_get_within(&self, source: &S, outer: OuterLabels<'_>) -> Result<A, Self::GetterError> {
+        let i = self
+            .optic1
+            .try_get_within(source, outer)
+            .map_err(&self.error_fn_1)?;
+        self.optic2
+            .try_get_within(&i, &|f| {
+                outer(f);
+                self.optic1.for_each_label(f);
+            })
+            .map_err(&self.error_fn_2)
+    }
+
+    fn for_each_label(&self, f: &mut dyn FnMut(&'static str)) {
+        self.optic1.for_each_label(f);
+        self.optic2.for_each_label(f);
     }
 }
 
-impl<P1, P2, E, S, I, A> HasSetter<S, A> for ComposedPrism<P1, P2, E, S, I, A>
+impl<P1, P2, E, S, I, A, F1, F2> HasSetter<S, A> for ComposedPrism<P1, P2, E, S, I, A, F1, F2>
 where
     P1: Prism<S, I>,
     P2: Prism<I, A>,
+    F1: Fn(P1::GetterError) -> E,
+    F2: Fn(P2::GetterError) -> E,
 {
+    #[inline]
     fn set(&self, source: &mut S, value: A) {
-        if let Ok(mut i) = self.optic1.try_get(source).map_err(self.error_fn_1) {
-            self.optic2.set(&mut i, value);
-            self.optic1.set(source, i);
-        }
+        self.optic1.modify(source, |i| self.optic2.set(i, value));
+    }
+}
+
+impl<P1, P2, E, S, I, A, F1, F2> HasFallibleSetter<S, A>
+    for ComposedPrism<P1, P2, E, S, I, A, F1, F2>
+where
+    P1: Prism<S, I>,
+    P2: Prism<I, A> + HasFallibleSetter<I, A>,
+    F1: Fn(P1::GetterError) -> E,
+    F2: Fn(P2::GetterError) -> E,
+{
+    type SetterError = P2::SetterError;
+
+    #[inline]
+    fn try_set(&self, source: &mut S, value: A) -> Result<(), Self::SetterError> {
+        let mut result = Ok(());
+        self.optic1
+            .modify(source, |i| result = self.optic2.try_set(i, value));
+        result
+    }
+}
+
+impl<P1, P2, E, S, I, A, F1, F2> HasInsert<S, A> for ComposedPrism<P1, P2, E, S, I, A, F1, F2>
+where
+    P1: Prism<S, I>,
+    P2: Prism<I, A> + HasInsert<I, A>,
+    F1: Fn(P1::GetterError) -> E,
+    F2: Fn(P2::GetterError) -> E,
+{
+    #[inline]
+    fn insert(&self, source: &mut S, value: A) {
+        self.optic1.modify(source, |i| self.optic2.insert(i, value));
+    }
+}
+
+impl<P1, P2, E, S, I, A, F1, F2> HasModify<S, A> for ComposedPrism<P1, P2, E, S, I, A, F1, F2>
+where
+    P1: Prism<S, I>,
+    P2: Prism<I, A>,
+    F1: Fn(P1::GetterError) -> E,
+    F2: Fn(P2::GetterError) -> E,
+{
+    #[inline]
+    fn modify<F: FnOnce(&mut A)>(&self, source: &mut S, f: F) {
+        self.optic1.modify(source, |i| self.optic2.modify(i, f));
     }
 }
 
@@ -115,6 +293,6 @@ pub fn new<S, A, I, E, P1: Prism<S, I>, P2: Prism<I, A>>(
     p2: P2,
     error_fn_1: fn(P1::GetterError) -> E,
     error_fn_2: fn(P2::GetterError) -> E,
-) -> PrismImpl<S, A, impl Prism<S, A, GetterError = E>> {
+) -> PrismImpl<S, A, ComposedPrism<P1, P2, E, S, I, A>> {
     ComposedPrism::new(p1, p2, error_fn_1, error_fn_2).into()
 }
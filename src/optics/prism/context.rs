@@ -0,0 +1,62 @@
+use crate::OuterLabels;
+use crate::optics::prism::wrapper::PrismImpl;
+use crate::{ContextError, HasGetter, HasModify, HasSetter, Prism};
+use core::marker::PhantomData;
+
+struct ContextPrism<P: Prism<S, A>, S, A> {
+    optic: P,
+    label: &'static str,
+    _phantom: PhantomData<(S, A)>,
+}
+
+impl<P, S, A> HasGetter<S, A> for ContextPrism<P, S, A>
+where
+    P: Prism<S, A>,
+{
+    type GetterError = ContextError<P::GetterError>;
+
+    fn try_get(&self, source: &S) -> Result<A, Self::GetterError> {
+        self.try_get_within(source, &|_| {})
+    }
+
+    fn try_get_within(&self, source: &S, outer: OuterLabels<'_>) -> Result<A, Self::GetterError> {
+        self.optic
+            .try_get(source)
+            .map_err(|e| ContextError::within_path(outer, self.label, e))
+    }
+
+    fn for_each_label(&self, f: &mut dyn FnMut(&'static str)) {
+        f(self.label);
+    }
+}
+
+impl<P, S, A> HasSetter<S, A> for ContextPrism<P, S, A>
+where
+    P: Prism<S, A>,
+{
+    fn set(&self, source: &mut S, value: A) {
+        self.optic.set(source, value);
+    }
+}
+
+impl<P, S, A> HasModify<S, A> for ContextPrism<P, S, A>
+where
+    P: Prism<S, A>,
+{
+    fn modify<F: FnOnce(&mut A)>(&self, source: &mut S, f: F) {
+        self.optic.modify(source, f);
+    }
+}
+
+/// Creates a `Prism<S,A>` that focuses through `prism`, labelling its errors with `label`.
+pub(crate) fn new<S, A, P: Prism<S, A>>(
+    prism: P,
+    label: &'static str,
+) -> PrismImpl<S, A, impl Prism<S, A, GetterError = ContextError<P::GetterError>>> {
+    ContextPrism {
+        optic: prism,
+        label,
+        _phantom: PhantomData,
+    }
+    .into()
+}
@@ -1,135 +1,344 @@
-use crate::mapped_prism;
-
 /// Generates a prism (getter and setter pair) for a specific enum variant.
 ///
 /// This macro expands to a call to `mapped_prism`, creating:
 /// - a getter closure that attempts to extract the fields of a specific variant
 /// - a setter closure that replaces the entire enum value with a new instance of that variant
 ///
-/// The macro handles **tuple-like**, **struct-like**, and **unit** enum variants.
+/// The kind of the variant is taken from the shape it is written in: `Variant` for a unit
+/// variant, `Variant(_, _)` for a tuple-like variant and `Variant { a, b }` for a struct-like
+/// variant, mirroring the enum definition, so there is no need to spell out the kind with a
+/// `tuple`, `struct` or `unit` token. A macro can not see the definition of the enum, so the shape
+/// still has to be written; a shape that does not match the definition fails to compile.
 ///
 /// For variants with:
-/// - **No fields** (unit variant), the getter returns `Option<()>`
-/// - **One field**, the getter returns `Option<FieldType>` directly (not wrapped in a tuple)
-/// - **Multiple fields**, the getter returns `Option<(FieldType1, FieldType2, ...)>` as a tuple
+/// - **No fields** (unit variant), the getter returns `Result<(), OpticsError>`
+/// - **One field**, the getter returns `Result<FieldType, OpticsError>` directly (not wrapped in a
+///   tuple)
+/// - **Multiple fields**, the getter returns `Result<(FieldType1, FieldType2, ...), OpticsError>`
+///   as a tuple
+///
+/// When the source is a different variant, the getter fails with
+/// [`OpticsError::VariantMismatch`](crate::OpticsError::VariantMismatch).
 ///
 /// # Syntax
 ///
 /// ```ignore
-/// enum_prism!(TypeName, VariantName, variant_kind, (arg1, arg2, ...))
+/// enum_prism!(TypeName, UnitVariant)
+/// enum_prism!(TypeName, TupleVariant(_, _, ...))
+/// enum_prism!(TypeName, StructVariant { field1, field2, ... })
 /// ```
 ///
-/// - `TypeName`: The name of the enum type
-/// - `VariantName`: The name of the variant to target
-/// - `variant_kind`: One of `tuple`, `struct`, or `unit`
-/// - `(arg1, arg2, ...)`: A list of identifiers representing the fields of the variant;
-///   for `unit` variants, use `()`
+/// - `TypeName`: The enum type
+/// - `UnitVariant`: A variant without fields
+/// - `TupleVariant(_, ...)`: A tuple-like variant, with one `_` per field (up to 12 fields)
+/// - `StructVariant { ... }`: A struct-like variant, listing the fields to focus on. The fields
+///   are returned in the order they are listed in.
+///
+/// The explicit form `enum_prism!(TypeName, VariantName, tuple|struct|unit, (arg1, arg2, ...))`
+/// is still accepted for backwards compatibility.
 ///
 /// # Example
 ///
 /// ```rust
-/// # use optics::enum_prism;
-/// # fn mapped_prism<GET, SET, T, F>(getter: GET, setter: SET) -> (GET, SET)
-/// # where GET: Fn(&T) -> Option<F>, SET: Fn(&mut T, F), { (getter, setter) }
-/// #[derive(Debug, Clone)]
+/// use optics::{enum_prism, HasGetter, HasSetter, OpticsError};
+///
+/// #[derive(Debug, Clone, PartialEq)]
 /// enum Message {
 ///     Quit,
 ///     Move { x: i32, y: i32 },
 ///     Echo(String),
+///     Resize(u32, u32),
 /// }
 ///
 /// // Struct-like variant with multiple fields returns tuple
-/// let move_prism = enum_prism!(Message, Move, struct, (x, y));
+/// let move_prism = enum_prism!(Message, Move { x, y });
 /// let m = Message::Move { x: 10, y: 20 };
-/// assert_eq!(move_prism.0(&m), Some((10, 20)));
+/// assert_eq!(move_prism.try_get(&m), Ok((10, 20)));
 ///
 /// // Tuple-like variant with single field returns field directly
-/// let echo_prism = enum_prism!(Message, Echo, tuple, (msg));
-/// let e = Message::Echo("Hello".into());
-/// assert_eq!(echo_prism.0(&e), Some("Hello".to_string()));
+/// let echo_prism = enum_prism!(Message, Echo(_));
+/// let mut e = Message::Echo("Hello".into());
+/// assert_eq!(echo_prism.try_get(&e), Ok("Hello".to_string()));
+/// echo_prism.set(&mut e, "World".into());
+/// assert_eq!(e, Message::Echo("World".into()));
+///
+/// // Tuple-like variant with multiple fields returns tuple
+/// let resize_prism = enum_prism!(Message, Resize(_, _));
+/// assert_eq!(resize_prism.try_get(&Message::Resize(640, 480)), Ok((640, 480)));
+/// assert_eq!(resize_prism.try_get(&Message::Quit), Err(OpticsError::VariantMismatch));
 ///
 /// // Unit variant returns ()
-/// let quit_prism = enum_prism!(Message, Quit, unit, ());
+/// let quit_prism = enum_prism!(Message, Quit);
 /// let q = Message::Quit;
-/// assert_eq!(quit_prism.0(&q), Some(()));
+/// assert_eq!(quit_prism.try_get(&q), Ok(()));
+/// ```
+///
+/// A tuple-like variant written without its fields is taken for a unit variant, and does not
+/// compile:
+///
+/// ```compile_fail
+/// use optics::enum_prism;
+///
+/// #[derive(Clone)]
+/// enum Message {
+///     Echo(String),
+/// }
+///
+/// let _ = enum_prism!(Message, Echo);
 /// ```
 ///
 /// # Notes
 ///
-/// - The getter returns an `Option` of the variant’s fields with the following rules:
-///   - Unit variants return `Option<()>`
-///   - Single-field variants return the field type directly inside `Option`
-///   - Multi-field variants return a tuple of fields inside `Option`
+/// - The getter returns a `Result` of the variant’s fields with the following rules:
+///   - Unit variants return `Result<(), OpticsError>`
+///   - Single-field variants return the field type directly inside the `Result`
+///   - Multi-field variants return a tuple of fields inside the `Result`
 /// - The setter replaces the enum with a new instance of the variant.
 /// - Fields are cloned in the getter; therefore, field types must implement `Clone`.
+/// - Struct-like variants must list all of their fields, as the setter needs to construct the
+///   variant from them.
+/// - The enum type can not refer to generic parameters of the enclosing item.
+/// - The prism is [`named`](crate::PrismImpl::named) after the variant, so it shows up in the
+///   [`path`](crate::PrismImpl::path) of the optics it is composed into.
 ///
 /// # See Also
 ///
-/// - [`mapped_prism`] for the expected function signature this macro generates.
+/// - [`mapped_prism`](crate::mapped_prism) for the expected function signature this macro generates.
 #[macro_export]
 macro_rules! enum_prism {
-    // Unit variant (no args)
-    ($type:path, $variant:ident, unit, ()) => {
-        crate::mapped_prism(
-            |input: &$type| match input {
-                &<$type>::$variant => Ok(()),
-                _ => Err(()),
+    // Assigns a binding name to every field of a tuple-like variant
+    (@tuple $type:ty, $variant:ident, [$($bound:ident)*], [$_field:tt $($fields:tt)*], [$name:ident $($names:ident)*]) => {
+        $crate::enum_prism!(@tuple $type, $variant, [$($bound)* $name], [$($fields)*], [$($names)*])
+    };
+
+    (@tuple $type:ty, $variant:ident, [$($bound:ident)*], [$($fields:tt)+], []) => {
+        compile_error!("enum_prism! supports tuple-like variants with at most 12 fields")
+    };
+
+    (@tuple $type:ty, $variant:ident, [$arg:ident], [], [$($names:ident)*]) => {{
+        type __EnumPrismType = $type;
+        $crate::mapped_prism(
+            |input: &__EnumPrismType| match input {
+                __EnumPrismType::$variant($arg) => Ok($arg.clone()),
+                #[allow(unreachable_patterns)]
+                _ => Err($crate::OpticsError::VariantMismatch),
             },
-            |input: &mut $type, ()| {
-                *input = <$type>::$variant;
+            |input: &mut __EnumPrismType, value| {
+                *input = __EnumPrismType::$variant(value);
             },
         )
-    };
+    }};
 
-    // Single field tuple-like variant
-    ($type:path, $variant:ident, tuple, ($arg:ident)) => {
+    (@tuple $type:ty, $variant:ident, [$($arg:ident)+], [], [$($names:ident)*]) => {{
+        type __EnumPrismType = $type;
         $crate::mapped_prism(
-            |input: &$type| match input {
-                &$type::$variant(ref $arg) => Ok($arg.clone()),
-                _ => Err(()),
+            |input: &__EnumPrismType| match input {
+                __EnumPrismType::$variant($($arg),+) => Ok(($($arg.clone()),+)),
+                #[allow(unreachable_patterns)]
+                _ => Err($crate::OpticsError::VariantMismatch),
             },
-            |input: &mut $type, value| {
-                *input = $type::$variant(value);
+            |input: &mut __EnumPrismType, ($($arg),+)| {
+                *input = __EnumPrismType::$variant($($arg),+);
             },
         )
-    };
+    }};
 
-    // Multiple fields tuple-like variant
-    ($type:path, $variant:ident, tuple, ($first:ident, $($rest:ident),+)) => {
+    (@struct $type:ty, $variant:ident, $arg:ident) => {{
+        type __EnumPrismType = $type;
         $crate::mapped_prism(
-            |input: &$type| match input {
-                <$type>::$variant(ref $first, $(ref $rest),+) => Ok(($first.clone(), $($rest.clone()),+)),
-                _ => Err(()),
+            |input: &__EnumPrismType| match input {
+                __EnumPrismType::$variant { $arg } => Ok($arg.clone()),
+                #[allow(unreachable_patterns)]
+                _ => Err($crate::OpticsError::VariantMismatch),
             },
-            |input: &mut $type, ($first, $($rest),+)| {
-                *input = <$type>::$variant($first, $($rest),+);
+            |input: &mut __EnumPrismType, value| {
+                *input = __EnumPrismType::$variant { $arg: value };
             },
         )
-    };
+    }};
 
-    // Single field struct-like variant
-    ($type:path, $variant:ident, struct, ($arg:ident)) => {
+    (@struct $type:ty, $variant:ident, $($arg:ident),+) => {{
+        type __EnumPrismType = $type;
         $crate::mapped_prism(
-            |input: &$type| match input {
-                <$type>::$variant { ref $arg } => Ok($arg.clone()),
-                _ => Err(()),
+            |input: &__EnumPrismType| match input {
+                __EnumPrismType::$variant { $($arg),+ } => Ok(($($arg.clone()),+)),
+                #[allow(unreachable_patterns)]
+                _ => Err($crate::OpticsError::VariantMismatch),
             },
-            |input: &mut $type, value| {
-                *input = <$type>::$variant { $arg: value };
+            |input: &mut __EnumPrismType, ($($arg),+)| {
+                *input = __EnumPrismType::$variant { $($arg),+ };
             },
         )
-    };
+    }};
 
-    // Multiple fields struct-like variant
-    ($type:path, $variant:ident, struct, ($first:ident, $($rest:ident),+)) => {
+    // Unit variant
+    ($type:ty, $variant:ident $(,)?) => {{
+        type __EnumPrismType = $type;
         $crate::mapped_prism(
-            |input: &$type| match input {
-                <$type>::$variant { ref $first, $(ref $rest),+ } => Ok(($first.clone(), $($rest.clone()),+)),
-                _ => Err(()),
+            |input: &__EnumPrismType| match input {
+                __EnumPrismType::$variant => Ok(()),
+                #[allow(unreachable_patterns)]
+                _ => Err($crate::OpticsError::VariantMismatch),
             },
-            |input: &mut $type, ($first, $($rest),+)| {
-                *input = <$type>::$variant { $first, $($rest),+ };
+            |input: &mut __EnumPrismType, ()| {
+                *input = __EnumPrismType::$variant;
             },
         )
+        .named(stringify!($variant))
+    }};
+
+    // Tuple-like variant, one `_` per field
+    ($type:ty, $variant:ident ($($field:tt),+) $(,)?) => {
+        $crate::enum_prism!(@tuple $type, $variant, [], [$($field)+], [f0 f1 f2 f3 f4 f5 f6 f7 f8 f9 f10 f11])
+            .named(stringify!($variant))
+    };
+
+    // Struct-like variant
+    ($type:ty, $variant:ident { $($field:ident),+ $(,)? } $(,)?) => {
+        $crate::enum_prism!(@struct $type, $variant, $($field),+).named(stringify!($variant))
+    };
+
+    // Explicit variant kinds, kept for backwards compatibility
+    ($type:ty, $variant:ident, unit, ()) => {
+        $crate::enum_prism!($type, $variant)
+    };
+
+    ($type:ty, $variant:ident, tuple, ($($arg:ident),+)) => {
+        $crate::enum_prism!(@tuple $type, $variant, [], [$($arg)+], [f0 f1 f2 f3 f4 f5 f6 f7 f8 f9 f10 f11])
+            .named(stringify!($variant))
+    };
+
+    ($type:ty, $variant:ident, struct, ($($arg:ident),+)) => {
+        $crate::enum_prism!(@struct $type, $variant, $($arg),+).named(stringify!($variant))
+    };
+}
+
+/// Generates a module containing a prism for every variant of an enum.
+///
+/// Writing a separate [`enum_prism!`](crate::enum_prism) invocation for every variant of a large
+/// enum is tedious. This macro takes the list of variants - written the same way as they are in the
+/// enum definition, including the field types - and emits a module with one constructor function
+/// per variant, named after the variant. Each function returns the same prism `enum_prism!` would.
+///
+/// # Syntax
+///
+/// ```ignore
+/// enum_prisms!(visibility mod module_name for TypeName {
+///     UnitVariant,
+///     TupleVariant(Type1, Type2, ...),
+///     StructVariant { field1: Type1, field2: Type2, ... },
+/// });
+/// ```
+///
+/// The enum type and the field types are resolved relative to the module the macro is invoked in.
+/// Attributes of the variants, such as doc comments or `#[cfg(...)]` conditions, are forwarded to
+/// their constructor functions.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{enum_prisms, HasGetter, HasSetter, OpticsError};
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// enum Message {
+///     Quit,
+///     Move { x: i32, y: i32 },
+///     Echo(String),
+///     Resize(u32, u32),
+///     #[cfg(feature = "colors")]
+///     Recolor(u8, u8, u8),
+/// }
+///
+/// enum_prisms!(mod message for Message {
+///     Quit,
+///     Move { x: i32, y: i32 },
+///     /// Echoes the message back.
+///     Echo(String),
+///     Resize(u32, u32),
+///     #[cfg(feature = "colors")]
+///     Recolor(u8, u8, u8),
+/// });
+///
+/// fn main() {
+///     let mut m = Message::Quit;
+///     assert_eq!(message::Quit().try_get(&m), Ok(()));
+///     assert_eq!(message::Echo().try_get(&m), Err(OpticsError::VariantMismatch));
+///
+///     message::Move().set(&mut m, (1, 2));
+///     assert_eq!(m, Message::Move { x: 1, y: 2 });
+///     assert_eq!(message::Resize().try_get(&Message::Resize(640, 480)), Ok((640, 480)));
+/// }
+/// ```
+///
+/// # See Also
+///
+/// - [`enum_prism!`](crate::enum_prism) for generating the prism of a single variant.
+#[macro_export]
+macro_rules! enum_prisms {
+    (@variants $type:ty;) => {};
+
+    (@variants $type:ty; $(#[$meta:meta])* $variant:ident $(, $($rest:tt)*)?) => {
+        /// A prism focusing on the unit variant of the same name.
+        $(#[$meta])*
+        #[must_use]
+        pub fn $variant() -> $crate::PrismImpl<$type, (), impl $crate::Prism<$type, (), GetterError = $crate::OpticsError> + $crate::HasPath> {
+            $crate::enum_prism!($type, $variant)
+        }
+
+        $crate::enum_prisms!(@variants $type; $($($rest)*)?);
+    };
+
+    (@variants $type:ty; $(#[$meta:meta])* $variant:ident ($field:ty $(,)?) $(, $($rest:tt)*)?) => {
+        /// A prism focusing on the field of the tuple-like variant of the same name.
+        $(#[$meta])*
+        #[must_use]
+        pub fn $variant() -> $crate::PrismImpl<$type, $field, impl $crate::Prism<$type, $field, GetterError = $crate::OpticsError> + $crate::HasPath> {
+            $crate::enum_prism!($type, $variant(_))
+        }
+
+        $crate::enum_prisms!(@variants $type; $($($rest)*)?);
+    };
+
+    (@variants $type:ty; $(#[$meta:meta])* $variant:ident ($($field:ty),+ $(,)?) $(, $($rest:tt)*)?) => {
+        /// A prism focusing on the fields of the tuple-like variant of the same name.
+        $(#[$meta])*
+        #[must_use]
+        pub fn $variant() -> $crate::PrismImpl<$type, ($($field),+), impl $crate::Prism<$type, ($($field),+), GetterError = $crate::OpticsError> + $crate::HasPath> {
+            $crate::enum_prism!($type, $variant($($field),+))
+        }
+
+        $crate::enum_prisms!(@variants $type; $($($rest)*)?);
+    };
+
+    (@variants $type:ty; $(#[$meta:meta])* $variant:ident { $name:ident : $field:ty $(,)? } $(, $($rest:tt)*)?) => {
+        /// A prism focusing on the field of the struct-like variant of the same name.
+        $(#[$meta])*
+        #[must_use]
+        pub fn $variant() -> $crate::PrismImpl<$type, $field, impl $crate::Prism<$type, $field, GetterError = $crate::OpticsError> + $crate::HasPath> {
+            $crate::enum_prism!($type, $variant { $name })
+        }
+
+        $crate::enum_prisms!(@variants $type; $($($rest)*)?);
+    };
+
+    (@variants $type:ty; $(#[$meta:meta])* $variant:ident { $($name:ident : $field:ty),+ $(,)? } $(, $($rest:tt)*)?) => {
+        /// A prism focusing on the fields of the struct-like variant of the same name.
+        $(#[$meta])*
+        #[must_use]
+        pub fn $variant() -> $crate::PrismImpl<$type, ($($field),+), impl $crate::Prism<$type, ($($field),+), GetterError = $crate::OpticsError> + $crate::HasPath> {
+            $crate::enum_prism!($type, $variant { $($name),+ })
+        }
+
+        $crate::enum_prisms!(@variants $type; $($($rest)*)?);
+    };
+
+    ($vis:vis mod $module:ident for $type:ty { $($variants:tt)* }) => {
+        #[allow(non_snake_case)]
+        $vis mod $module {
+            #[allow(unused_imports)]
+            use super::*;
+
+            $crate::enum_prisms!(@variants $type; $($variants)*);
+        }
     };
 }
@@ -0,0 +1,54 @@
+use crate::optics::prism::wrapper::PrismImpl;
+use crate::{ErasedError, HasGetter, HasModify, HasSetter, Prism};
+use core::marker::PhantomData;
+
+struct ErasedPrism<P: Prism<S, A>, S, A> {
+    optic: P,
+    _phantom: PhantomData<fn(&mut S) -> A>,
+}
+
+impl<P, S, A> HasGetter<S, A> for ErasedPrism<P, S, A>
+where
+    P: Prism<S, A>,
+    P::GetterError: Into<ErasedError>,
+{
+    type GetterError = ErasedError;
+
+    fn try_get(&self, source: &S) -> Result<A, Self::GetterError> {
+        self.optic.try_get(source).map_err(Into::into)
+    }
+}
+
+impl<P, S, A> HasSetter<S, A> for ErasedPrism<P, S, A>
+where
+    P: Prism<S, A>,
+{
+    fn set(&self, source: &mut S, value: A) {
+        self.optic.set(source, value);
+    }
+}
+
+impl<P, S, A> HasModify<S, A> for ErasedPrism<P, S, A>
+where
+    P: Prism<S, A>,
+    P::GetterError: Into<ErasedError>,
+{
+    fn modify<F: FnOnce(&mut A)>(&self, source: &mut S, f: F) {
+        self.optic.modify(source, f);
+    }
+}
+
+/// Creates a `Prism<S,A>` that focuses through `prism`, boxing its errors into an
+/// [`ErasedError`].
+pub(crate) fn new<S, A, P: Prism<S, A>>(
+    prism: P,
+) -> PrismImpl<S, A, impl Prism<S, A, GetterError = ErasedError>>
+where
+    P::GetterError: Into<ErasedError>,
+{
+    ErasedPrism {
+        optic: prism,
+        _phantom: PhantomData,
+    }
+    .into()
+}
@@ -0,0 +1,128 @@
+use crate::optics::partial_getter::PartialGetterImpl;
+use crate::optics::prism::wrapper::PrismImpl;
+use crate::{Either, HasGetter, HasModify, HasSetter, OpticsError, PartialGetter, Prism};
+use core::fmt::{Display, Formatter};
+use core::marker::PhantomData;
+
+/// The error returned when the focus of a filtered optic does not satisfy its predicate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PredicateFailed;
+
+impl Display for PredicateFailed {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "the focused value does not satisfy the predicate")
+    }
+}
+
+impl core::error::Error for PredicateFailed {}
+
+impl From<PredicateFailed> for () {
+    fn from(_: PredicateFailed) -> Self {}
+}
+
+impl From<PredicateFailed> for OpticsError {
+    fn from(_: PredicateFailed) -> Self {
+        OpticsError::NoFocus
+    }
+}
+
+struct FilteredPrism<P: Prism<S, A>, F: Fn(&A) -> bool, S, A> {
+    optic: P,
+    predicate: F,
+    _phantom: PhantomData<(S, A)>,
+}
+
+impl<P, F, S, A> HasGetter<S, A> for FilteredPrism<P, F, S, A>
+where
+    P: Prism<S, A>,
+    F: Fn(&A) -> bool,
+{
+    type GetterError = Either<P::GetterError, PredicateFailed>;
+
+    fn try_get(&self, source: &S) -> Result<A, Self::GetterError> {
+        let a = self.optic.try_get(source).map_err(Either::Left)?;
+
+        if (self.predicate)(&a) {
+            Ok(a)
+        } else {
+            Err(Either::Right(PredicateFailed))
+        }
+    }
+}
+
+impl<P, F, S, A> HasSetter<S, A> for FilteredPrism<P, F, S, A>
+where
+    P: Prism<S, A>,
+    F: Fn(&A) -> bool,
+{
+    fn set(&self, source: &mut S, value: A) {
+        if (self.predicate)(&value) {
+            self.optic.set(source, value);
+        }
+    }
+}
+
+impl<P, F, S, A> HasModify<S, A> for FilteredPrism<P, F, S, A>
+where
+    P: Prism<S, A>,
+    F: Fn(&A) -> bool,
+{
+}
+
+struct FilterMappedPartialGetter<PG: PartialGetter<S, A>, F: Fn(A) -> Option<B>, S, A, B> {
+    optic: PG,
+    f: F,
+    _phantom: PhantomData<(S, A, B)>,
+}
+
+impl<PG, F, S, A, B> HasGetter<S, B> for FilterMappedPartialGetter<PG, F, S, A, B>
+where
+    PG: PartialGetter<S, A>,
+    F: Fn(A) -> Option<B>,
+{
+    type GetterError = Either<PG::GetterError, PredicateFailed>;
+
+    fn try_get(&self, source: &S) -> Result<B, Self::GetterError> {
+        let a = self.optic.try_get(source).map_err(Either::Left)?;
+        (self.f)(a).ok_or(Either::Right(PredicateFailed))
+    }
+}
+
+/// Creates a `Prism<S,A>` that only matches when the focus of `p` satisfies `predicate`.
+pub(crate) fn new<S, A, P, F>(
+    p: P,
+    predicate: F,
+) -> PrismImpl<S, A, impl Prism<S, A, GetterError = Either<P::GetterError, PredicateFailed>>>
+where
+    P: Prism<S, A>,
+    F: Fn(&A) -> bool,
+{
+    FilteredPrism {
+        optic: p,
+        predicate,
+        _phantom: PhantomData,
+    }
+    .into()
+}
+
+/// Creates a `PartialGetter<S,B>` that maps the focus of `pg` with `f`, and fails if `f` returns
+/// `None`.
+pub(crate) fn new_filter_map<S, A, B, PG, F>(
+    pg: PG,
+    f: F,
+) -> PartialGetterImpl<
+    S,
+    B,
+    impl PartialGetter<S, B, GetterError = Either<PG::GetterError, PredicateFailed>>,
+>
+where
+    PG: PartialGetter<S, A>,
+    F: Fn(A) -> Option<B>,
+{
+    FilterMappedPartialGetter {
+        optic: pg,
+        f,
+        _phantom: PhantomData,
+    }
+    .into()
+}
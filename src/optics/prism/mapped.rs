@@ -1,17 +1,56 @@
 use crate::HasGetter;
+use crate::HasModify;
 use crate::HasSetter;
-use crate::optics::prism::Prism;
 use crate::optics::prism::wrapper::PrismImpl;
+use core::fmt::{Debug, Formatter};
 use core::marker::PhantomData;
 
-struct MappedPrism<S, A, E, GET = fn(&S) -> Result<A, E>, SET = fn(&mut S, A)>
+mod private {
+    use core::marker::PhantomData;
+
+    pub struct MappedPrism<S, A, E, GET = fn(&S) -> Result<A, E>, SET = fn(&mut S, A)>
+    where
+        GET: Fn(&S) -> Result<A, E>,
+        SET: Fn(&mut S, A),
+    {
+        pub(super) get_fn: GET,
+        pub(super) set_fn: SET,
+        pub(super) phantom: PhantomData<(S, A)>,
+    }
+}
+
+pub type MappedPrism<S, A, E, GET = fn(&S) -> Result<A, E>, SET = fn(&mut S, A)> =
+    private::MappedPrism<S, A, E, GET, SET>;
+
+impl<S, A, E, GET, SET> Clone for MappedPrism<S, A, E, GET, SET>
+where
+    GET: Fn(&S) -> Result<A, E> + Clone,
+    SET: Fn(&mut S, A) + Clone,
+{
+    fn clone(&self) -> Self {
+        MappedPrism {
+            get_fn: self.get_fn.clone(),
+            set_fn: self.set_fn.clone(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<S, A, E, GET, SET> Copy for MappedPrism<S, A, E, GET, SET>
+where
+    GET: Fn(&S) -> Result<A, E> + Copy,
+    SET: Fn(&mut S, A) + Copy,
+{
+}
+
+impl<S, A, E, GET, SET> Debug for MappedPrism<S, A, E, GET, SET>
 where
     GET: Fn(&S) -> Result<A, E>,
     SET: Fn(&mut S, A),
 {
-    get_fn: GET,
-    set_fn: SET,
-    phantom: PhantomData<(S, A)>,
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("MappedPrism").finish_non_exhaustive()
+    }
 }
 
 impl<S, A, E, GET, SET> MappedPrism<S, A, E, GET, SET>
@@ -19,7 +58,7 @@ where
     GET: Fn(&S) -> Result<A, E>,
     SET: Fn(&mut S, A),
 {
-    pub(crate) fn new(get_fn: GET, set_fn: SET) -> Self {
+    pub(crate) const fn new(get_fn: GET, set_fn: SET) -> Self {
         MappedPrism {
             get_fn,
             set_fn,
@@ -50,6 +89,13 @@ where
     }
 }
 
+impl<S, A, E, GET, SET> HasModify<S, A> for MappedPrism<S, A, E, GET, SET>
+where
+    GET: Fn(&S) -> Result<A, E>,
+    SET: Fn(&mut S, A),
+{
+}
+
 /// Creates a new `Prism` with the provided getter and setter function.
 ///
 /// # Type Parameters
@@ -59,7 +105,9 @@ where
 /// # Arguments
 ///
 /// - `get_fn` — A function that faillibly retrieves the focus value `A` from the source `S`.
-/// - `set_fn` — A function that sets the focused value `A` in the source `S`.
+/// - `set_fn` — A function that sets the focused value `A` in the source `S`. It should construct
+///   the focus if it is not present (e.g. `*s = Some(v)`), so that the value set can be read back.
+///   Use [`PrismImpl::set_if_present`] to only write a focus that is already present.
 ///
 /// # Examples
 ///
@@ -80,13 +128,13 @@ where
 /// assert_eq!(addr, IpAddress::Ipv4("1.1.2.2".to_string()));
 /// ```
 #[must_use]
-pub fn new<S, A, E, GET, SET>(
+pub const fn new<S, A, E, GET, SET>(
     get_fn: GET,
     set_fn: SET,
-) -> PrismImpl<S, A, impl Prism<S, A, GetterError = E>>
+) -> PrismImpl<S, A, MappedPrism<S, A, E, GET, SET>>
 where
     GET: Fn(&S) -> Result<A, E>,
     SET: Fn(&mut S, A),
 {
-    MappedPrism::new(get_fn, set_fn).into()
+    PrismImpl::new(MappedPrism::new(get_fn, set_fn))
 }
@@ -1,16 +1,57 @@
 use crate::HasGetter;
+use crate::HasModify;
 use crate::HasSetter;
+#[cfg(feature = "prism")]
 use core::convert::Infallible;
 
+#[cfg(all(feature = "alloc", feature = "prism"))]
+mod boxed;
+#[cfg(feature = "prism")]
 mod composed;
+#[cfg(all(feature = "alloc", feature = "prism"))]
+mod context;
+#[cfg(feature = "prism")]
+mod enum_prism;
+#[cfg(all(feature = "alloc", feature = "prism"))]
+mod erased;
+#[cfg(feature = "prism")]
+mod filter;
+#[cfg(feature = "prism")]
 mod mapped;
+#[cfg(feature = "prism")]
+mod or_else;
+#[cfg(all(feature = "alloc", feature = "prism"))]
+mod shared;
+#[cfg(feature = "prism")]
+mod validated;
+#[cfg(all(feature = "prism", feature = "lens"))]
+mod with_default;
+#[cfg(feature = "prism")]
+mod with_insert;
+#[cfg(feature = "prism")]
 mod wrapper;
-// mod enum_prism; // Needs #![feature(more_qualified_paths)] stabilized https://github.com/rust-lang/rust/issues/86935
 
+#[cfg(all(feature = "alloc", feature = "prism"))]
+pub use boxed::{BoxedPrism, DynPrism};
+#[cfg(feature = "prism")]
+pub(crate) use composed::ComposedPrism;
+#[cfg(feature = "prism")]
 pub use composed::new as composed_prism;
+#[cfg(feature = "prism")]
+pub use filter::PredicateFailed;
+#[cfg(feature = "prism")]
 pub use mapped::new as mapped_prism;
+#[cfg(all(feature = "alloc", feature = "prism"))]
+pub use shared::SharedPrism;
+#[cfg(feature = "prism")]
 pub use wrapper::PrismImpl;
 
+/// A [`PrismImpl`] built by [`mapped_prism`] from plain functions or non-capturing closures.
+///
+/// As [`mapped_prism`] is a `const fn`, this type can be used to declare the optic as a `static`.
+#[cfg(feature = "prism")]
+pub type FnPrism<S, A, E> = PrismImpl<S, A, mapped::MappedPrism<S, A, E>>;
+
 /// A `Prism` is an optic that focuses on a potentially missing value, such as a variant of a
 /// sum type (enum).
 ///
@@ -35,9 +76,9 @@ pub use wrapper::PrismImpl;
 /// - [`Lens`] — an optic that focuses on an always-present value in a product type (e.g., a required struct field)
 /// - [`FallibleIso`] — a variant of `Iso` where the mapping might fail, returning an error
 /// - [`Iso`] — an isomorphism optic representing a reversible bijective conversion between two types
-pub trait Prism<S, A>: HasGetter<S, A> + HasSetter<S, A> {}
+pub trait Prism<S, A>: HasGetter<S, A> + HasSetter<S, A> + HasModify<S, A> {}
 
-impl<S, A, P: HasGetter<S, A> + HasSetter<S, A>> Prism<S, A> for P {}
+impl<S, A, P: HasGetter<S, A> + HasSetter<S, A> + HasModify<S, A>> Prism<S, A> for P {}
 
 /// Creates a `Prism` that focuses on the entire input. Note that this is actually a lens in disguise.
 ///
@@ -68,6 +109,7 @@ impl<S, A, P: HasGetter<S, A> + HasSetter<S, A>> Prism<S, A> for P {}
 /// # See Also
 ///
 /// - [`mapped_prism`] for constructing custom `Prism`s from arbitrary mapping functions.
+#[cfg(feature = "prism")]
 #[must_use]
 pub fn identity_prism<S: Clone>() -> PrismImpl<S, S, impl Prism<S, S, GetterError = Infallible>> {
     mapped_prism(|s: &S| Ok::<_, Infallible>(s.clone()), |s, v| *s = v)
@@ -0,0 +1,60 @@
+use crate::optics::prism::wrapper::PrismImpl;
+use crate::{HasGetter, HasModify, HasSetter, Prism};
+use core::marker::PhantomData;
+
+struct OrElsePrism<P1: Prism<S, A>, P2: Prism<S, A>, S, A> {
+    optic1: P1,
+    optic2: P2,
+    _phantom: PhantomData<(S, A)>,
+}
+
+impl<P1, P2, S, A> HasGetter<S, A> for OrElsePrism<P1, P2, S, A>
+where
+    P1: Prism<S, A>,
+    P2: Prism<S, A>,
+{
+    type GetterError = P2::GetterError;
+
+    fn try_get(&self, source: &S) -> Result<A, Self::GetterError> {
+        self.optic1
+            .try_get(source)
+            .or_else(|_| self.optic2.try_get(source))
+    }
+}
+
+impl<P1, P2, S, A> HasSetter<S, A> for OrElsePrism<P1, P2, S, A>
+where
+    P1: Prism<S, A>,
+    P2: Prism<S, A>,
+{
+    fn set(&self, source: &mut S, value: A) {
+        if self.optic1.try_get(source).is_err() && self.optic2.try_get(source).is_ok() {
+            self.optic2.set(source, value);
+        } else {
+            self.optic1.set(source, value);
+        }
+    }
+}
+
+impl<P1, P2, S, A> HasModify<S, A> for OrElsePrism<P1, P2, S, A>
+where
+    P1: Prism<S, A>,
+    P2: Prism<S, A>,
+{
+}
+
+/// Creates a `Prism<S,A>` that focuses through `p1`, falling back to `p2` if `p1` fails.
+///
+/// The value is set through the optic that currently matches, preferring `p1`. If neither of
+/// them matches, the value is set through `p1`.
+pub(crate) fn new<S, A, P1: Prism<S, A>, P2: Prism<S, A>>(
+    p1: P1,
+    p2: P2,
+) -> PrismImpl<S, A, impl Prism<S, A, GetterError = P2::GetterError>> {
+    OrElsePrism {
+        optic1: p1,
+        optic2: p2,
+        _phantom: PhantomData,
+    }
+    .into()
+}
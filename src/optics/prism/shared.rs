@@ -0,0 +1,25 @@
+use crate::{DynPrism, HasGetter, HasModify, HasSetter, PrismImpl};
+use alloc::sync::Arc;
+
+/// A shared prism of any type, focusing on a value of type `A` in a source of type `S`.
+///
+/// Like [boxed prisms](crate::BoxedPrism), shared prisms of different types have the same type, but
+/// they are reference counted, so they can be cloned cheaply, stored in registries and sent to
+/// other threads. They are created with [`PrismImpl::shared`](crate::PrismImpl::shared).
+pub type SharedPrism<S, A, E> = PrismImpl<S, A, Arc<dyn DynPrism<S, A, E> + Send + Sync>>;
+
+impl<S, A, E> HasGetter<S, A> for Arc<dyn DynPrism<S, A, E> + Send + Sync> {
+    type GetterError = E;
+
+    fn try_get(&self, source: &S) -> Result<A, Self::GetterError> {
+        (**self).try_get(source)
+    }
+}
+
+impl<S, A, E> HasSetter<S, A> for Arc<dyn DynPrism<S, A, E> + Send + Sync> {
+    fn set(&self, source: &mut S, value: A) {
+        (**self).set(source, value);
+    }
+}
+
+impl<S, A, E> HasModify<S, A> for Arc<dyn DynPrism<S, A, E> + Send + Sync> {}
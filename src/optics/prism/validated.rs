@@ -0,0 +1,69 @@
+use crate::{HasFallibleSetter, HasGetter, HasModify, HasSetter, Prism, PrismImpl};
+use core::marker::PhantomData;
+
+struct ValidatedPrism<O: Prism<S, A>, V: Fn(&A) -> Result<(), E>, E, S, A> {
+    optic: O,
+    validate_fn: V,
+    _phantom: PhantomData<(S, A, E)>,
+}
+
+impl<O, V, E, S, A> HasGetter<S, A> for ValidatedPrism<O, V, E, S, A>
+where
+    O: Prism<S, A>,
+    V: Fn(&A) -> Result<(), E>,
+{
+    type GetterError = O::GetterError;
+
+    fn try_get(&self, source: &S) -> Result<A, Self::GetterError> {
+        self.optic.try_get(source)
+    }
+}
+
+impl<O, V, E, S, A> HasSetter<S, A> for ValidatedPrism<O, V, E, S, A>
+where
+    O: Prism<S, A>,
+    V: Fn(&A) -> Result<(), E>,
+{
+    fn set(&self, source: &mut S, value: A) {
+        let _ = self.try_set(source, value);
+    }
+}
+
+impl<O, V, E, S, A> HasFallibleSetter<S, A> for ValidatedPrism<O, V, E, S, A>
+where
+    O: Prism<S, A>,
+    V: Fn(&A) -> Result<(), E>,
+{
+    type SetterError = E;
+
+    fn try_set(&self, source: &mut S, value: A) -> Result<(), Self::SetterError> {
+        (self.validate_fn)(&value)?;
+        self.optic.set(source, value);
+        Ok(())
+    }
+}
+
+impl<O, V, E, S, A> HasModify<S, A> for ValidatedPrism<O, V, E, S, A>
+where
+    O: Prism<S, A>,
+    V: Fn(&A) -> Result<(), E>,
+{
+}
+
+/// Creates a `Prism<S,A>` that focuses through `optic`, only writing the values accepted by
+/// `validate_fn`, and reporting the rejections through `HasFallibleSetter`.
+pub(crate) fn new<S, A, E, O: Prism<S, A>, V: Fn(&A) -> Result<(), E>>(
+    optic: O,
+    validate_fn: V,
+) -> PrismImpl<
+    S,
+    A,
+    impl Prism<S, A, GetterError = O::GetterError> + HasFallibleSetter<S, A, SetterError = E>,
+> {
+    ValidatedPrism {
+        optic,
+        validate_fn,
+        _phantom: PhantomData,
+    }
+    .into()
+}
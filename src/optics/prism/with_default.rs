@@ -0,0 +1,55 @@
+use crate::{HasGetter, HasModify, HasSetter, Lens, LensImpl, Prism};
+use core::convert::Infallible;
+use core::marker::PhantomData;
+
+struct DefaultedPrism<P: Prism<S, A>, D: Fn() -> A, S, A> {
+    optic: P,
+    default_fn: D,
+    _phantom: PhantomData<(S, A)>,
+}
+
+impl<P, D, S, A> HasGetter<S, A> for DefaultedPrism<P, D, S, A>
+where
+    P: Prism<S, A>,
+    D: Fn() -> A,
+{
+    type GetterError = Infallible;
+
+    fn try_get(&self, source: &S) -> Result<A, Self::GetterError> {
+        Ok(self
+            .optic
+            .try_get(source)
+            .unwrap_or_else(|_| (self.default_fn)()))
+    }
+}
+
+impl<P, D, S, A> HasSetter<S, A> for DefaultedPrism<P, D, S, A>
+where
+    P: Prism<S, A>,
+    D: Fn() -> A,
+{
+    fn set(&self, source: &mut S, value: A) {
+        self.optic.set(source, value);
+    }
+}
+
+impl<P, D, S, A> HasModify<S, A> for DefaultedPrism<P, D, S, A>
+where
+    P: Prism<S, A>,
+    D: Fn() -> A,
+{
+}
+
+/// Creates a `Lens<S,A>` that focuses through `prism`, substituting the value produced by
+/// `default_fn` when the prism does not match.
+pub(crate) fn new<S, A, P: Prism<S, A>, D: Fn() -> A>(
+    prism: P,
+    default_fn: D,
+) -> LensImpl<S, A, impl Lens<S, A>> {
+    DefaultedPrism {
+        optic: prism,
+        default_fn,
+        _phantom: PhantomData,
+    }
+    .into()
+}
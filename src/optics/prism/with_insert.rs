@@ -0,0 +1,64 @@
+use crate::{HasGetter, HasInsert, HasModify, HasSetter, Prism, PrismImpl};
+use core::marker::PhantomData;
+
+struct InsertablePrism<P: Prism<S, A>, F: Fn(&mut S, A), S, A> {
+    optic: P,
+    insert_fn: F,
+    _phantom: PhantomData<(S, A)>,
+}
+
+impl<P, F, S, A> HasGetter<S, A> for InsertablePrism<P, F, S, A>
+where
+    P: Prism<S, A>,
+    F: Fn(&mut S, A),
+{
+    type GetterError = P::GetterError;
+
+    fn try_get(&self, source: &S) -> Result<A, Self::GetterError> {
+        self.optic.try_get(source)
+    }
+}
+
+impl<P, F, S, A> HasSetter<S, A> for InsertablePrism<P, F, S, A>
+where
+    P: Prism<S, A>,
+    F: Fn(&mut S, A),
+{
+    fn set(&self, source: &mut S, value: A) {
+        self.optic.set(source, value);
+    }
+}
+
+impl<P, F, S, A> HasModify<S, A> for InsertablePrism<P, F, S, A>
+where
+    P: Prism<S, A>,
+    F: Fn(&mut S, A),
+{
+    fn modify<M: FnOnce(&mut A)>(&self, source: &mut S, f: M) {
+        self.optic.modify(source, f);
+    }
+}
+
+impl<P, F, S, A> HasInsert<S, A> for InsertablePrism<P, F, S, A>
+where
+    P: Prism<S, A>,
+    F: Fn(&mut S, A),
+{
+    fn insert(&self, source: &mut S, value: A) {
+        (self.insert_fn)(source, value);
+    }
+}
+
+/// Creates a `Prism<S,A>` that focuses through `prism`, constructing a missing focus with
+/// `insert_fn`.
+pub(crate) fn new<S, A, P: Prism<S, A>, F: Fn(&mut S, A)>(
+    prism: P,
+    insert_fn: F,
+) -> PrismImpl<S, A, impl Prism<S, A, GetterError = P::GetterError> + HasInsert<S, A>> {
+    InsertablePrism {
+        optic: prism,
+        insert_fn,
+        _phantom: PhantomData,
+    }
+    .into()
+}
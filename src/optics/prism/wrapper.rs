@@ -1,11 +1,49 @@
+#[cfg(feature = "fallible-iso")]
+use crate::FallibleIso;
+#[cfg(feature = "getter")]
+use crate::Getter;
+use crate::HasPath;
+#[cfg(feature = "iso")]
+use crate::Iso;
+use crate::OuterLabels;
+use crate::named::Named;
+use crate::optics::fallible_setter::ComposedFallibleSetter;
+use crate::optics::partial_getter::{ComposedPartialGetter, ReadOnly};
+use crate::optics::prism::ComposedPrism;
 use crate::optics::prism::composed::new as composed_prism;
+use crate::optics::prism::filter::{new as filter, new_filter_map as filter_map};
+use crate::optics::prism::or_else::new as or_else;
+use crate::optics::prism::validated::new as with_validation;
+use crate::optics::prism::with_insert::new as with_insert;
+use crate::optics::setter::{ComposedSetter, WriteOnly};
+use crate::optics::traversal::{ComposedTraversal, SingleTraversal, single_traversal};
+#[cfg(feature = "tracing")]
+use crate::traced::Traced;
 use crate::{
-    FallibleIso, FallibleIsoImpl, Getter, GetterImpl, HasGetter, HasSetter, Iso, IsoImpl, Lens,
-    LensImpl, PartialGetter, PartialGetterImpl, Prism, Setter, SetterImpl, composed_partial_getter,
-    composed_setter, infallible,
+    Either, FallibleSetter, FallibleSetterImpl, HasFallibleSetter, HasGetter, HasInsert, HasModify,
+    HasSetter, PartialGetter, PartialGetterImpl, PredicateFailed, Prism, Setter, SetterImpl,
+    Traversal, TraversalImpl, composed_fallible_setter, composed_partial_getter, composed_setter,
+    composed_traversal, infallible, mapped_partial_getter,
 };
-use core::convert::identity;
+use core::convert::{Infallible, identity};
+use core::fmt::{Debug, Display, Formatter};
 use core::marker::PhantomData;
+#[cfg(feature = "alloc")]
+use {
+    crate::optics::prism::context::new as context,
+    crate::optics::prism::erased::new as erase_err,
+    crate::optics::prism::{BoxedPrism, DynPrism, SharedPrism},
+    crate::{ContextError, ErasedError},
+    alloc::boxed::Box,
+    alloc::string::{String, ToString},
+    alloc::sync::Arc,
+    alloc::vec::Vec,
+};
+#[cfg(feature = "lens")]
+use {
+    crate::optics::prism::with_default::new as with_default,
+    crate::{Lens, LensImpl},
+};
 
 /// A wrapper of the [`Prism`] optic implementations, encapsulating a partial getter and a setter function.
 ///
@@ -29,7 +67,7 @@ use core::marker::PhantomData;
 pub struct PrismImpl<S, A, P: Prism<S, A>>(pub P, PhantomData<(S, A)>);
 
 impl<S, A, P: Prism<S, A>> PrismImpl<S, A, P> {
-    fn new(prism: P) -> Self {
+    pub(crate) const fn new(prism: P) -> Self {
         //TODO: Verify not to nest an Impl inside an Impl - currently seems to be impossible at compile time.
         PrismImpl(prism, PhantomData)
     }
@@ -41,20 +79,172 @@ impl<S, A, P: Prism<S, A>> From<P> for PrismImpl<S, A, P> {
     }
 }
 
+impl<S, A, P: Prism<S, A> + Clone> Clone for PrismImpl<S, A, P> {
+    fn clone(&self) -> Self {
+        Self::new(self.0.clone())
+    }
+}
+
+impl<S, A, P: Prism<S, A> + Copy> Copy for PrismImpl<S, A, P> {}
+
+impl<S, A, P: Prism<S, A> + Debug> Debug for PrismImpl<S, A, P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("PrismImpl").field(&self.0).finish()
+    }
+}
+
+impl<S, A, P: Prism<S, A> + Display> Display for PrismImpl<S, A, P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl<S, A, P: Prism<S, A> + HasPath> HasPath for PrismImpl<S, A, P> {
+    fn for_each_segment<'a>(&'a self, f: &mut dyn FnMut(&'a str)) {
+        self.0.for_each_segment(f);
+    }
+}
+
+impl<S, A, P: Prism<S, A>> PrismImpl<S, A, P> {
+    /// Labels this optic with a static `name`, used by its [`Display`] implementation.
+    ///
+    /// Composing named optics results in an optic displaying the names of its parts joined with a
+    /// `.`, which helps to identify a misbehaving optic in logs and error messages.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The name of the optic.
+    ///
+    /// # Returns
+    ///
+    /// A new `PrismImpl` behaving exactly like `self`, displayed as `name`.
+    #[must_use]
+    pub fn named(self, name: &'static str) -> PrismImpl<S, A, Named<P>> {
+        Named::new(self.0, name).into()
+    }
+
+    /// Wraps this optic to emit a `tracing` event for every operation going through it, with
+    /// `name` identifying the optic, whether the operation succeeded and the time it took.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The name of the optic in the events, such as `"config.port"`.
+    ///
+    /// # Returns
+    ///
+    /// A new `PrismImpl` behaving exactly like `self`, tracing its operations.
+    #[cfg(feature = "tracing")]
+    #[must_use]
+    pub fn traced(self, name: &'static str) -> PrismImpl<S, A, Traced<P>> {
+        Traced::new(self.0, name).into()
+    }
+
+    /// Returns the name of the optic, the names of the optics it is composed of joined with a `.`.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn name(&self) -> String
+    where
+        P: Display,
+    {
+        self.to_string()
+    }
+
+    /// Returns the names of the fields and variants this optic focuses through, from the outermost
+    /// one, such as the field names of the [`field_lens!`](crate::field_lens) lenses it is
+    /// composed of.
+    #[cfg(feature = "alloc")]
+    pub fn path(&self) -> impl Iterator<Item = &str>
+    where
+        P: HasPath,
+    {
+        let mut path = Vec::new();
+        self.0.for_each_segment(&mut |segment| path.push(segment));
+        path.into_iter()
+    }
+
+    /// Boxes this prism, erasing its type, so it can be stored alongside prisms of other types.
+    ///
+    /// # Returns
+    ///
+    /// A [`BoxedPrism`] behaving exactly like `self`.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn boxed(self) -> BoxedPrism<S, A, P::GetterError>
+    where
+        P: Send + Sync + 'static,
+    {
+        let optic: Box<dyn DynPrism<S, A, P::GetterError> + Send + Sync> = Box::new(self.0);
+        optic.into()
+    }
+
+    /// Wraps this prism in an [`Arc`], erasing its type, so it can be cloned cheaply and shared
+    /// between threads.
+    ///
+    /// # Returns
+    ///
+    /// A [`SharedPrism`] behaving exactly like `self`.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn shared(self) -> SharedPrism<S, A, P::GetterError>
+    where
+        P: Send + Sync + 'static,
+    {
+        let optic: Arc<dyn DynPrism<S, A, P::GetterError> + Send + Sync> = Arc::new(self.0);
+        optic.into()
+    }
+}
+
 impl<S, A, P: Prism<S, A>> HasGetter<S, A> for PrismImpl<S, A, P> {
     type GetterError = P::GetterError;
 
+    #[inline]
     fn try_get(&self, source: &S) -> Result<A, Self::GetterError> {
         self.0.try_get(source)
     }
+
+    #[inline]
+    fn try_get_within(&self, source: &S, outer: OuterLabels<'_>) -> Result<A, Self::GetterError> {
+        self.0.try_get_within(source, outer)
+    }
+
+    #[inline]
+    fn for_each_label(&self, f: &mut dyn FnMut(&'static str)) {
+        self.0.for_each_label(f);
+    }
 }
 
 impl<S, A, P: Prism<S, A>> HasSetter<S, A> for PrismImpl<S, A, P> {
+    #[inline]
     fn set(&self, source: &mut S, value: A) {
         self.0.set(source, value);
     }
 }
 
+impl<S, A, P: Prism<S, A>> HasModify<S, A> for PrismImpl<S, A, P> {
+    #[inline]
+    fn modify<F: FnOnce(&mut A)>(&self, source: &mut S, f: F) {
+        self.0.modify(source, f);
+    }
+}
+
+impl<S, A, P: Prism<S, A> + HasInsert<S, A>> HasInsert<S, A> for PrismImpl<S, A, P> {
+    #[inline]
+    fn insert(&self, source: &mut S, value: A) {
+        self.0.insert(source, value);
+    }
+}
+
+impl<S, A, P: Prism<S, A> + HasFallibleSetter<S, A>> HasFallibleSetter<S, A>
+    for PrismImpl<S, A, P>
+{
+    type SetterError = P::SetterError;
+
+    #[inline]
+    fn try_set(&self, source: &mut S, value: A) -> Result<(), Self::SetterError> {
+        self.0.try_set(source, value)
+    }
+}
+
 impl<S, I, P1: Prism<S, I>> PrismImpl<S, I, P1> {
     /// Composes this `PrismImpl<S,I>` with a `PartialGetter<I,A>`, resulting in a new `PartialGetter<S, A>`
     /// that focuses through both optics sequentially.
@@ -84,13 +274,13 @@ impl<S, I, P1: Prism<S, I>> PrismImpl<S, I, P1> {
     /// [`compose_with_partial_getter_with_mappers`](Self::compose_with_partial_getter_with_mappers).
     pub fn compose_with_partial_getter<E, A, PG2: PartialGetter<I, A>>(
         self,
-        other: PartialGetterImpl<I, A, PG2>,
-    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A, GetterError = E>>
+        other: PG2,
+    ) -> PartialGetterImpl<S, A, ComposedPartialGetter<P1, PG2, E, S, I, A>>
     where
         P1::GetterError: Into<E>,
         PG2::GetterError: Into<E>,
     {
-        composed_partial_getter(self.0, other.0, Into::into, Into::into)
+        composed_partial_getter(self.0, other, Into::into, Into::into)
     }
 
     /// Composes this `PrismImpl<S,I>` with a `PartialGetter<I,A>`, resulting in a new `PartialGetter<S, A>`
@@ -120,13 +310,45 @@ impl<S, I, P1: Prism<S, I>> PrismImpl<S, I, P1> {
     /// This method is similar to [`compose_with_partial_getter`](Self::compose_with_partial_getter), but
     /// provides the ability to specify custom functions to map the errors from each
     /// optic into a common error type.
-    pub fn compose_with_partial_getter_with_mappers<E, A, PG2: PartialGetter<I, A>>(
+    #[allow(clippy::type_complexity)]
+    pub fn compose_with_partial_getter_with_mappers<E, A, PG2: PartialGetter<I, A>, F1, F2>(
+        self,
+        other: PG2,
+        error_mapper_1: F1,
+        error_mapper_2: F2,
+    ) -> PartialGetterImpl<S, A, ComposedPartialGetter<P1, PG2, E, S, I, A, F1, F2>>
+    where
+        F1: Fn(P1::GetterError) -> E,
+        F2: Fn(PG2::GetterError) -> E,
+    {
+        ComposedPartialGetter::with_mappers(self.0, other, error_mapper_1, error_mapper_2).into()
+    }
+
+    /// Composes this `PrismImpl<S,I>` with a `PartialGetterImpl<I,A>`, resulting in a new `PartialGetterImpl<S, A>`
+    /// whose error identifies which of the two optics failed.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `A`: The target type of the composed optic.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: The partial getter to compose with.
+    ///
+    /// # Returns
+    ///
+    /// A new `PartialGetterImpl` failing with a [`Either::Left`] if `self` fails, or a
+    /// [`Either::Right`] if `other` fails.
+    #[allow(clippy::type_complexity)]
+    pub fn compose_with_partial_getter_tracking<A, PG2: PartialGetter<I, A>>(
         self,
-        other: PartialGetterImpl<I, A, PG2>,
-        error_mapper_1: fn(P1::GetterError) -> E,
-        error_mapper_2: fn(PG2::GetterError) -> E,
-    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A, GetterError = E>> {
-        composed_partial_getter(self.0, other.0, error_mapper_1, error_mapper_2)
+        other: PG2,
+    ) -> PartialGetterImpl<
+        S,
+        A,
+        ComposedPartialGetter<P1, PG2, Either<P1::GetterError, PG2::GetterError>, S, I, A>,
+    > {
+        composed_partial_getter(self.0, other, Either::Left, Either::Right)
     }
 
     /// Composes this `PrismImpl<S,I>` with a `Getter<I,A>`, resulting in a new `PartialGetter<S, A>`
@@ -148,11 +370,13 @@ impl<S, I, P1: Prism<S, I>> PrismImpl<S, I, P1> {
     ///
     /// A new `PartialGetterImpl` that represents the composition of `self` and `other`.
     ///
+    #[cfg(feature = "getter")]
+    #[allow(clippy::type_complexity)]
     pub fn compose_with_getter<A, G2: Getter<I, A>>(
         self,
-        other: GetterImpl<I, A, G2>,
-    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A, GetterError = P1::GetterError>> {
-        composed_partial_getter(self.0, other.0, identity, infallible)
+        other: G2,
+    ) -> PartialGetterImpl<S, A, ComposedPartialGetter<P1, G2, P1::GetterError, S, I, A>> {
+        composed_partial_getter(self.0, other, identity, infallible)
     }
 
     /// Composes this `PrismImpl<S,I>` with a `Setter<I,A>`, resulting in a new `Setter<S, A>`
@@ -176,9 +400,75 @@ impl<S, I, P1: Prism<S, I>> PrismImpl<S, I, P1> {
     ///
     pub fn compose_with_setter<A, S2: Setter<I, A>>(
         self,
-        other: SetterImpl<I, A, S2>,
-    ) -> SetterImpl<S, A, impl Setter<S, A>> {
-        composed_setter(self.0, other.0)
+        other: S2,
+    ) -> SetterImpl<S, A, ComposedSetter<P1, S2, S, I, A>> {
+        composed_setter(self.0, other)
+    }
+
+    /// Composes this `PrismImpl<S,I>` with a `FallibleSetter<I,A>`, resulting in a new `FallibleSetter<S, A>`
+    /// that focuses through both optics sequentially.
+    ///
+    /// The resulting `FallibleSetterImpl` will set the value by first applying `self` and then
+    /// `other`. Unlike the composition with a `Setter`, the write is not silently dropped when
+    /// `self` fails to focus: the composition fails if either `self` fails to focus, or `other`
+    /// rejects the value.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `E`: The common error type for the composed optic.
+    /// - `A`: The target type of the composed optic.
+    /// - `FS2`: The type of the fallible setter to compose with.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: The fallible setter to compose with.
+    ///
+    /// # Returns
+    ///
+    /// A new `FallibleSetterImpl` that represents the composition of `self` and `other`.
+    ///
+    pub fn compose_with_fallible_setter<E, A, FS2: FallibleSetter<I, A>>(
+        self,
+        other: FS2,
+    ) -> FallibleSetterImpl<S, A, ComposedFallibleSetter<P1, FS2, E, S, I, A>>
+    where
+        P1::GetterError: Into<E>,
+        FS2::SetterError: Into<E>,
+    {
+        composed_fallible_setter(self.0, other, Into::into, Into::into)
+    }
+
+    /// Composes this `PrismImpl<S,I>` with a `FallibleSetter<I,A>`, resulting in a new `FallibleSetter<S, A>`
+    /// that focuses through both optics sequentially, with explicit error mappers.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `E`: The common error type for the composed optic.
+    /// - `A`: The target type of the composed optic.
+    /// - `FS2`: The type of the fallible setter to compose with.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: The fallible setter to compose with.
+    /// - `error_mapper_1`: A function to map `P1::GetterError` into `E`.
+    /// - `error_mapper_2`: A function to map `FS2::SetterError` into `E`.
+    ///
+    /// # Returns
+    ///
+    /// A new `FallibleSetterImpl` that represents the composition of `self` and `other`.
+    ///
+    #[allow(clippy::type_complexity)]
+    pub fn compose_with_fallible_setter_with_mappers<E, A, FS2: FallibleSetter<I, A>, F1, F2>(
+        self,
+        other: FS2,
+        error_mapper_1: F1,
+        error_mapper_2: F2,
+    ) -> FallibleSetterImpl<S, A, ComposedFallibleSetter<P1, FS2, E, S, I, A, F1, F2>>
+    where
+        F1: Fn(P1::GetterError) -> E,
+        F2: Fn(FS2::SetterError) -> E,
+    {
+        ComposedFallibleSetter::with_mappers(self.0, other, error_mapper_1, error_mapper_2).into()
     }
 
     /// Composes this `PrismImpl<S,I>` with another `Prism<I,A>`, resulting in a new `PrismImpl<S, A>`
@@ -209,13 +499,48 @@ impl<S, I, P1: Prism<S, I>> PrismImpl<S, I, P1> {
     /// [`compose_with_prism_with_mappers`](Self::compose_with_prism_with_mappers).
     pub fn compose_with_prism<E, A, P2: Prism<I, A>>(
         self,
-        other: PrismImpl<I, A, P2>,
-    ) -> PrismImpl<S, A, impl Prism<S, A, GetterError = E>>
+        other: P2,
+    ) -> PrismImpl<S, A, ComposedPrism<P1, P2, E, S, I, A>>
     where
         P1::GetterError: Into<E>,
         P2::GetterError: Into<E>,
     {
-        composed_prism(self.0, other.0, Into::into, Into::into)
+        composed_prism(self.0, other, Into::into, Into::into)
+    }
+
+    /// Composes this `PrismImpl<S,I>` with a `PrismImpl<I,A>` like
+    /// [`compose_with_prism`](Self::compose_with_prism), but boxes both prisms and the result.
+    ///
+    /// The resulting [`BoxedPrism`] has the same type whatever prisms it is composed of, so the
+    /// types of deep chains built with `compose_dyn` do not nest into each other. This trades a
+    /// dynamic call per stage for less generated code and shorter compile times.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use optics::{BoxedPrism, HasGetter, OpticsError, option, vec};
+    ///
+    /// let first_some: BoxedPrism<Vec<Option<u16>>, u16, OpticsError> =
+    ///     vec::first().compose_dyn(option::some());
+    ///
+    /// assert_eq!(first_some.try_get(&vec![Some(80)]), Ok(80));
+    /// assert_eq!(first_some.try_get(&vec![None]), Err(OpticsError::NoFocus));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn compose_dyn<E, A, P2>(self, other: P2) -> BoxedPrism<S, A, E>
+    where
+        P1: Send + Sync + 'static,
+        P2: Prism<I, A> + Send + Sync + 'static,
+        P1::GetterError: Into<E> + 'static,
+        P2::GetterError: Into<E> + 'static,
+        S: Send + Sync + 'static,
+        I: Send + Sync + 'static,
+        A: Send + Sync + 'static,
+        E: Send + Sync + 'static,
+    {
+        self.boxed()
+            .compose_with_prism(PrismImpl::<I, A, P2>::from(other).boxed())
+            .boxed()
     }
 
     /// Composes this `PrismImpl<S,I>` with another `PrismImpl<I,A>`, resulting in a new `PrismImpl<S, A>`
@@ -245,13 +570,42 @@ impl<S, I, P1: Prism<S, I>> PrismImpl<S, I, P1> {
     /// This method is similar to [`compose_with_prism`](Self::compose_with_prism), but
     /// provides the ability to specify custom functions to map the errors from each
     /// prism into a common error type.
-    pub fn compose_with_prism_with_mappers<E, A, P2: Prism<I, A>>(
+    #[allow(clippy::type_complexity)]
+    pub fn compose_with_prism_with_mappers<E, A, P2: Prism<I, A>, F1, F2>(
+        self,
+        other: P2,
+        error_mapper_1: F1,
+        error_mapper_2: F2,
+    ) -> PrismImpl<S, A, ComposedPrism<P1, P2, E, S, I, A, F1, F2>>
+    where
+        F1: Fn(P1::GetterError) -> E,
+        F2: Fn(P2::GetterError) -> E,
+    {
+        ComposedPrism::with_mappers(self.0, other, error_mapper_1, error_mapper_2).into()
+    }
+
+    /// Composes this `PrismImpl<S,I>` with a `PrismImpl<I,A>`, resulting in a new `PrismImpl<S, A>`
+    /// whose error identifies which of the two optics failed.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `A`: The target type of the composed optic.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: The prism to compose with.
+    ///
+    /// # Returns
+    ///
+    /// A new `PrismImpl` failing with a [`Either::Left`] if `self` fails, or a
+    /// [`Either::Right`] if `other` fails.
+    #[allow(clippy::type_complexity)]
+    pub fn compose_with_prism_tracking<A, P2: Prism<I, A>>(
         self,
-        other: PrismImpl<I, A, P2>,
-        error_mapper_1: fn(P1::GetterError) -> E,
-        error_mapper_2: fn(P2::GetterError) -> E,
-    ) -> PrismImpl<S, A, impl Prism<S, A, GetterError = E>> {
-        composed_prism(self.0, other.0, error_mapper_1, error_mapper_2)
+        other: P2,
+    ) -> PrismImpl<S, A, ComposedPrism<P1, P2, Either<P1::GetterError, P2::GetterError>, S, I, A>>
+    {
+        composed_prism(self.0, other, Either::Left, Either::Right)
     }
 
     /// Composes this `PrismImpl<S,I>` with a `Lens<I,A>`, resulting in a new `PrismImpl<S, A>`
@@ -272,11 +626,13 @@ impl<S, I, P1: Prism<S, I>> PrismImpl<S, I, P1> {
     /// # Returns
     ///
     /// A new `PrismImpl` that represents the composition of `self` and `other`
+    #[cfg(feature = "lens")]
+    #[allow(clippy::type_complexity)]
     pub fn compose_with_lens<A, L2: Lens<I, A>>(
         self,
-        other: LensImpl<I, A, L2>,
-    ) -> PrismImpl<S, A, impl Prism<S, A, GetterError = P1::GetterError>> {
-        composed_prism(self.0, other.0, identity, infallible)
+        other: L2,
+    ) -> PrismImpl<S, A, ComposedPrism<P1, L2, P1::GetterError, S, I, A>> {
+        composed_prism(self.0, other, identity, infallible)
     }
 
     /// Composes this `PrismImpl<S,I>` with a `FallibleIso<I,A>`, resulting in a new `PrismImpl<S, A>`
@@ -305,15 +661,16 @@ impl<S, I, P1: Prism<S, I>> PrismImpl<S, I, P1> {
     /// This method uses `Into::into` to convert the errors from both prisms into the
     /// common error type `E`. If you need custom error mapping, consider using
     /// [`compose_with_fallible_iso_with_mappers`](Self::compose_with_fallible_iso_with_mappers).
+    #[cfg(feature = "fallible-iso")]
     pub fn compose_with_fallible_iso<E, A, FI2: FallibleIso<I, A>>(
         self,
-        other: FallibleIsoImpl<I, A, FI2>,
-    ) -> PrismImpl<S, A, impl Prism<S, A, GetterError = E>>
+        other: FI2,
+    ) -> PrismImpl<S, A, ComposedPrism<P1, FI2, E, S, I, A>>
     where
         FI2::GetterError: Into<E>,
         P1::GetterError: Into<E>,
     {
-        composed_prism(self.0, other.0, Into::into, Into::into)
+        composed_prism(self.0, other, Into::into, Into::into)
     }
 
     /// Composes this `PrismImpl<S,I>` with a `FallibleIso<I,A>`, resulting in a new `PrismImpl<S, A>`
@@ -344,18 +701,45 @@ impl<S, I, P1: Prism<S, I>> PrismImpl<S, I, P1> {
     /// This method is similar to [`compose_with_fallible_iso`](Self::compose_with_fallible_iso), but
     /// provides the ability to specify custom functions to map the errors from each
     /// prism into a common error type.
-    pub fn compose_with_fallible_iso_with_mappers<E, A, FI2: FallibleIso<I, A>>(
+    #[cfg(feature = "fallible-iso")]
+    #[allow(clippy::type_complexity)]
+    pub fn compose_with_fallible_iso_with_mappers<E, A, FI2: FallibleIso<I, A>, F1, F2>(
+        self,
+        other: FI2,
+        getter_error_mapper_1: F1,
+        getter_error_mapper_2: F2,
+    ) -> PrismImpl<S, A, ComposedPrism<P1, FI2, E, S, I, A, F1, F2>>
+    where
+        F1: Fn(P1::GetterError) -> E,
+        F2: Fn(FI2::GetterError) -> E,
+    {
+        ComposedPrism::with_mappers(self.0, other, getter_error_mapper_1, getter_error_mapper_2)
+            .into()
+    }
+
+    /// Composes this `PrismImpl<S,I>` with a `FallibleIsoImpl<I,A>`, resulting in a new `PrismImpl<S, A>`
+    /// whose error identifies which of the two optics failed.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `A`: The target type of the composed optic.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: The fallible iso to compose with.
+    ///
+    /// # Returns
+    ///
+    /// A new `PrismImpl` failing with a [`Either::Left`] if `self` fails, or a
+    /// [`Either::Right`] if `other` fails.
+    #[cfg(feature = "fallible-iso")]
+    #[allow(clippy::type_complexity)]
+    pub fn compose_with_fallible_iso_tracking<A, FI2: FallibleIso<I, A>>(
         self,
-        other: FallibleIsoImpl<I, A, FI2>,
-        getter_error_mapper_1: fn(P1::GetterError) -> E,
-        getter_error_mapper_2: fn(FI2::GetterError) -> E,
-    ) -> PrismImpl<S, A, impl Prism<S, A, GetterError = E>> {
-        composed_prism(
-            self.0,
-            other.0,
-            getter_error_mapper_1,
-            getter_error_mapper_2,
-        )
+        other: FI2,
+    ) -> PrismImpl<S, A, ComposedPrism<P1, FI2, Either<P1::GetterError, FI2::GetterError>, S, I, A>>
+    {
+        composed_prism(self.0, other, Either::Left, Either::Right)
     }
 
     /// Composes this `PrismImpl<S,I>` with an `Iso<I,A>`, resulting in a new `PrismImpl<S, A>`
@@ -376,10 +760,554 @@ impl<S, I, P1: Prism<S, I>> PrismImpl<S, I, P1> {
     /// # Returns
     ///
     /// A new `PrismImpl` that represents the composition of `self` and `other`
+    #[cfg(feature = "iso")]
+    #[allow(clippy::type_complexity)]
     pub fn compose_with_iso<A, ISO2: Iso<I, A>>(
         self,
-        other: IsoImpl<I, A, ISO2>,
-    ) -> PrismImpl<S, A, impl Prism<S, A, GetterError = P1::GetterError>> {
-        composed_prism(self.0, other.0, identity, infallible)
+        other: ISO2,
+    ) -> PrismImpl<S, A, ComposedPrism<P1, ISO2, P1::GetterError, S, I, A>> {
+        composed_prism(self.0, other, identity, infallible)
+    }
+
+    /// Composes this `PrismImpl<S,I>` with a `Traversal<I,A>`, resulting in a new `TraversalImpl<S, A>`
+    /// that focuses through both optics sequentially.
+    ///
+    /// The resulting `TraversalImpl` will visit every value focused on by `other` in the value
+    /// focused on by `self`. If the prism fails to focus, no
+    /// values are visited.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `A`: The target type of the composed traversal.
+    /// - `T2`: The type of the traversal to compose with.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: The traversal to compose with.
+    ///
+    /// # Returns
+    ///
+    /// A new `TraversalImpl` that represents the composition of `self` and `other`.
+    #[allow(clippy::type_complexity)]
+    pub fn compose_with_traversal<A, T2: Traversal<I, A>>(
+        self,
+        other: T2,
+    ) -> TraversalImpl<S, A, ComposedTraversal<SingleTraversal<S, I, P1>, T2, S, I, A>> {
+        composed_traversal(single_traversal(self.0), other)
+    }
+}
+
+impl<S, A, P1: Prism<S, A>> PrismImpl<S, A, P1> {
+    /// Combines this `PrismImpl<S,A>` with another `PrismImpl<S,A>` focusing on the same type, resulting in
+    /// a new `PrismImpl<S, A>` that falls back to `other` if `self` fails to retrieve its focus.
+    ///
+    /// This is useful for reading a value that may be found in one of several locations.
+    ///
+    /// The value is set through the prism that currently matches, preferring `self`. If neither of
+    /// them matches, the value is set through `self`.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: The fallback prism.
+    ///
+    /// # Returns
+    ///
+    /// A new `PrismImpl` that fails with the error of `other` only if both optics fail.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use optics::{HasGetter, mapped_prism};
+    ///
+    /// struct Config {
+    ///     port: Option<u16>,
+    ///     legacy_port: Option<u16>,
+    /// }
+    ///
+    /// let port = mapped_prism(|c: &Config| c.port.ok_or(()), |c, p| c.port = Some(p));
+    /// let legacy_port =
+    ///     mapped_prism(|c: &Config| c.legacy_port.ok_or(()), |c, p| c.legacy_port = Some(p));
+    ///
+    /// let any_port = port.or_else(legacy_port);
+    ///
+    /// assert_eq!(any_port.try_get(&Config { port: Some(80), legacy_port: Some(8080) }), Ok(80));
+    /// assert_eq!(any_port.try_get(&Config { port: None, legacy_port: Some(8080) }), Ok(8080));
+    /// assert_eq!(any_port.try_get(&Config { port: None, legacy_port: None }), Err(()));
+    /// ```
+    pub fn or_else<P2: Prism<S, A>>(
+        self,
+        other: PrismImpl<S, A, P2>,
+    ) -> PrismImpl<S, A, impl Prism<S, A, GetterError = P2::GetterError>> {
+        or_else(self.0, other)
+    }
+}
+
+impl<S, A, P1: Prism<S, A>> PrismImpl<S, A, P1> {
+    /// Narrows this `PrismImpl<S,A>` to only match when its focus satisfies `predicate`.
+    ///
+    /// Retrieving the focus fails with a [`Either::Left`] if `self` fails, or with a
+    /// [`Either::Right`] holding a [`PredicateFailed`] if the focus does not satisfy the
+    /// predicate. Values that do not satisfy the predicate are ignored when set, so that they can
+    /// always be read back.
+    ///
+    /// # Parameters
+    ///
+    /// - `predicate`: The condition the focus needs to satisfy.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use optics::{Either, HasGetter, HasSetter, PredicateFailed, option};
+    ///
+    /// let unprivileged_port = option::some().filter(|port: &u16| *port >= 1024);
+    /// let mut port = Some(80);
+    ///
+    /// assert_eq!(
+    ///     unprivileged_port.try_get(&port),
+    ///     Err(Either::Right(PredicateFailed))
+    /// );
+    /// unprivileged_port.set(&mut port, 8080);
+    /// assert_eq!(unprivileged_port.try_get(&port), Ok(8080));
+    /// unprivileged_port.set(&mut port, 443);
+    /// assert_eq!(port, Some(8080));
+    /// ```
+    pub fn filter<F: Fn(&A) -> bool>(
+        self,
+        predicate: F,
+    ) -> PrismImpl<S, A, impl Prism<S, A, GetterError = Either<P1::GetterError, PredicateFailed>>>
+    {
+        filter(self.0, predicate)
+    }
+
+    /// Maps the focus of this `PrismImpl<S,A>` with `f`, resulting in a read-only
+    /// `PartialGetterImpl<S,B>` that only matches when `f` returns `Some`.
+    ///
+    /// Retrieving the focus fails with a [`Either::Left`] if `self` fails, or with a
+    /// [`Either::Right`] holding a [`PredicateFailed`] if `f` returns `None`.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: The function mapping the focus, or rejecting it by returning `None`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use optics::{HasGetter, option};
+    ///
+    /// let even_half = option::some().filter_map(|n: u32| (n % 2 == 0).then_some(n / 2));
+    ///
+    /// assert_eq!(even_half.try_get(&Some(8)), Ok(4));
+    /// assert!(even_half.try_get(&Some(7)).is_err());
+    /// ```
+    pub fn filter_map<B, F: Fn(A) -> Option<B>>(
+        self,
+        f: F,
+    ) -> PartialGetterImpl<
+        S,
+        B,
+        impl PartialGetter<S, B, GetterError = Either<P1::GetterError, PredicateFailed>>,
+    > {
+        filter_map(self.0, f)
+    }
+
+    /// Maps the focus of this `PrismImpl<S,A>` with `f`, resulting in a read-only
+    /// `PartialGetterImpl<S,B>` focusing on a value derived from the focus.
+    ///
+    /// Retrieving the focus fails with the error of `self`.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: The function deriving the new focus from the focus of `self`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use optics::{HasGetter, mapped_prism};
+    ///
+    /// struct Server {
+    ///     host: String,
+    /// }
+    ///
+    /// let host = mapped_prism(
+    ///     |s: &Server| s.host.strip_prefix("local").map(str::to_string).ok_or(()),
+    ///     |s, h| s.host = format!("local{h}"),
+    /// );
+    /// let host_len = host.map(String::len);
+    /// let server = Server { host: "localhost".to_string() };
+    ///
+    /// assert_eq!(host_len.try_get(&server), Ok(4));
+    /// ```
+    pub fn map<B, F: Fn(&A) -> B>(
+        self,
+        f: F,
+    ) -> PartialGetterImpl<S, B, impl PartialGetter<S, B, GetterError = P1::GetterError>> {
+        let mapper = mapped_partial_getter(move |a: &A| Ok::<_, Infallible>(f(a)));
+        composed_partial_getter(self.0, mapper.0, identity, infallible)
+    }
+
+    /// Upgrades this `PrismImpl<S,A>` to a `LensImpl<S,A>` by substituting a fallback value
+    /// whenever the prism does not match.
+    ///
+    /// Retrieving the focus returns the focus of `self` if it matches, or the value produced by
+    /// `default_fn` otherwise. Setting the focus writes through `self`.
+    ///
+    /// # Parameters
+    ///
+    /// - `default_fn`: Produces the value to substitute when `self` does not match.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use optics::{HasSetter, HasTotalGetter, option};
+    ///
+    /// let timeout = option::some::<u32>().with_default(|| 30);
+    /// let mut configured = None;
+    ///
+    /// assert_eq!(timeout.get(&configured), 30);
+    /// timeout.set(&mut configured, 60);
+    /// assert_eq!(timeout.get(&configured), 60);
+    /// ```
+    #[cfg(feature = "lens")]
+    pub fn with_default<D: Fn() -> A>(self, default_fn: D) -> LensImpl<S, A, impl Lens<S, A>> {
+        with_default(self.0, default_fn)
+    }
+
+    /// Upgrades this `PrismImpl<S,A>` to a `LensImpl<S,A>` by substituting `A::default()`
+    /// whenever the prism does not match.
+    ///
+    /// This is a shorthand for [`with_default`](Self::with_default) using [`Default::default`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use optics::{HasTotalGetter, option};
+    ///
+    /// let retries = option::some::<u8>().or_default();
+    ///
+    /// assert_eq!(retries.get(&None), 0);
+    /// assert_eq!(retries.get(&Some(3)), 3);
+    /// ```
+    #[cfg(feature = "lens")]
+    pub fn or_default(self) -> LensImpl<S, A, impl Lens<S, A>>
+    where
+        A: Default,
+    {
+        with_default(self.0, A::default)
+    }
+
+    /// Sets the focus of this `PrismImpl<S,A>` only if it is currently present in the source.
+    ///
+    /// Unlike [`set`](HasSetter::set), this never constructs the focus: if the prism does not
+    /// match, the source is left unchanged.
+    ///
+    /// # Parameters
+    ///
+    /// - `source`: A mutable reference to the source in which the focus is set.
+    /// - `value`: The value to set the focus to.
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the focus was present and has been set, `false` otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use optics::option;
+    ///
+    /// let timeout = option::some::<u32>();
+    /// let mut configured = None;
+    ///
+    /// assert!(!timeout.set_if_present(&mut configured, 30));
+    /// assert_eq!(configured, None);
+    ///
+    /// configured = Some(10);
+    /// assert!(timeout.set_if_present(&mut configured, 30));
+    /// assert_eq!(configured, Some(30));
+    /// ```
+    pub fn set_if_present(&self, source: &mut S, value: A) -> bool {
+        let present = self.0.try_get(source).is_ok();
+        if present {
+            self.0.set(source, value);
+        }
+        present
+    }
+
+    /// Attaches `insert_fn` to this `PrismImpl<S,A>` as the way to construct its focus when it is
+    /// not present in the source, resulting in a new `PrismImpl<S,A>` implementing [`HasInsert`].
+    ///
+    /// Reading and setting the focus is left to `self`, `insert_fn` is only called by
+    /// [`insert`](HasInsert::insert) and [`set_or_insert`](Self::set_or_insert).
+    ///
+    /// # Parameters
+    ///
+    /// - `insert_fn`: The function writing the focus into the source, constructing it if needed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use optics::{HasGetter, HasSetter, mapped_prism};
+    ///
+    /// let first_char = mapped_prism(
+    ///     |s: &String| s.chars().next().ok_or(()),
+    ///     |s: &mut String, c| {
+    ///         if !s.is_empty() {
+    ///             s.replace_range(..1, &c.to_string());
+    ///         }
+    ///     },
+    /// )
+    /// .with_insert(|s: &mut String, c| {
+    ///     if s.is_empty() {
+    ///         s.push(c);
+    ///     } else {
+    ///         s.replace_range(..1, &c.to_string());
+    ///     }
+    /// });
+    /// let mut name = String::new();
+    ///
+    /// first_char.set(&mut name, 'x');
+    /// assert_eq!(name, "");
+    /// first_char.set_or_insert(&mut name, 'x');
+    /// assert_eq!(first_char.try_get(&name), Ok('x'));
+    /// ```
+    pub fn with_insert<F: Fn(&mut S, A)>(
+        self,
+        insert_fn: F,
+    ) -> PrismImpl<S, A, impl Prism<S, A, GetterError = P1::GetterError> + HasInsert<S, A>> {
+        with_insert(self.0, insert_fn)
+    }
+
+    /// Sets the focus of this `PrismImpl<S,A>`, constructing it if it is not present in the source.
+    ///
+    /// This is the counterpart of [`set_if_present`](Self::set_if_present), available on prisms
+    /// that know how to construct their focus. Composed prisms construct the focus of the last
+    /// optic only: if an outer optic of the composition does not match, the source is left
+    /// unchanged.
+    ///
+    /// # Parameters
+    ///
+    /// - `source`: A mutable reference to the source in which the focus is set.
+    /// - `value`: The value to set the focus to.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use optics::{HasGetter, HasSetter, KeyMissing, map};
+    /// use std::collections::HashMap;
+    ///
+    /// let timeout = map::value("timeout");
+    /// let mut settings = HashMap::new();
+    ///
+    /// timeout.set(&mut settings, 30);
+    /// assert_eq!(timeout.try_get(&settings), Err(KeyMissing { key: "timeout" }));
+    ///
+    /// timeout.set_or_insert(&mut settings, 30);
+    /// assert_eq!(timeout.try_get(&settings), Ok(30));
+    /// ```
+    pub fn set_or_insert(&self, source: &mut S, value: A)
+    where
+        P1: HasInsert<S, A>,
+    {
+        self.0.insert(source, value);
+    }
+
+    /// Labels the errors of this `PrismImpl<S,A>` with `label`, resulting in a new `PrismImpl<S,A>`
+    /// whose errors are wrapped in a [`ContextError`].
+    ///
+    /// The label is the last segment of the path carried by the error. When this optic is composed
+    /// after other labelled optics, their labels are prepended to the path automatically, so a
+    /// failure deep in a chain of compositions reports where it happened.
+    ///
+    /// # Parameters
+    ///
+    /// - `label`: A human-readable name of the part of the structure this optic focuses on.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use optics::{ContextError, HasGetter, NoneError, option};
+    ///
+    /// let timeout = option::some::<u32>().context("timeout");
+    /// assert_eq!(timeout.try_get(&None).unwrap_err().path(), ["timeout"]);
+    ///
+    /// let http_timeout = option::some::<Option<u32>>()
+    ///     .context("http")
+    ///     .compose_with_prism::<ContextError<NoneError>, _, _>(timeout);
+    /// let error = http_timeout.try_get(&Some(None)).unwrap_err();
+    ///
+    /// assert_eq!(error.to_string(), "http.timeout: the value is None");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn context(
+        self,
+        label: &'static str,
+    ) -> PrismImpl<S, A, impl Prism<S, A, GetterError = ContextError<P1::GetterError>>> {
+        context(self.0, label)
+    }
+
+    /// Boxes the errors of this `PrismImpl<S,A>` into an [`ErasedError`], resulting in a new
+    /// `PrismImpl<S,A>` that can be composed with optics of any other error type without mapping
+    /// the errors into a common type.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use optics::{HasGetter, option};
+    ///
+    /// let timeout = option::some::<u32>().erase_err();
+    /// let error = timeout.try_get(&None).unwrap_err();
+    ///
+    /// assert_eq!(error.to_string(), "the value is None");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn erase_err(self) -> PrismImpl<S, A, impl Prism<S, A, GetterError = ErasedError>>
+    where
+        P1::GetterError: Into<ErasedError>,
+    {
+        erase_err(self.0)
+    }
+
+    /// Restricts the values that can be written through this `PrismImpl<S,A>` to the ones accepted
+    /// by `validate_fn`, resulting in a new `PrismImpl<S,A>` that also implements
+    /// [`HasFallibleSetter`].
+    ///
+    /// Rejected values are reported by [`HasFallibleSetter::try_set`] with the error returned by
+    /// `validate_fn`, while [`HasSetter::set`] ignores them. Unlike [`PrismImpl::filter`], reading
+    /// the focus is not affected. To surface rejections through compositions, compose the optic
+    /// with [`PrismImpl::as_fallible_setter`].
+    ///
+    /// # Parameters
+    ///
+    /// - `validate_fn`: The function checking a value before it is written.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use optics::{HasFallibleSetter, option};
+    ///
+    /// let port = option::some::<u16>()
+    ///     .with_validation(|port| if *port < 1024 { Err("port below 1024") } else { Ok(()) });
+    ///
+    /// let mut configured = None;
+    /// assert_eq!(port.try_set(&mut configured, 80), Err("port below 1024"));
+    /// assert_eq!(configured, None);
+    ///
+    /// assert_eq!(port.try_set(&mut configured, 8080), Ok(()));
+    /// assert_eq!(configured, Some(8080));
+    /// ```
+    pub fn with_validation<E, V: Fn(&A) -> Result<(), E>>(
+        self,
+        validate_fn: V,
+    ) -> PrismImpl<
+        S,
+        A,
+        impl Prism<S, A, GetterError = P1::GetterError> + HasFallibleSetter<S, A, SetterError = E>,
+    > {
+        with_validation(self.0, validate_fn)
+    }
+}
+
+impl<S, A, P: Prism<S, Option<A>>> PrismImpl<S, Option<A>, P> {
+    /// Removes the value this `PrismImpl<S,Option<A>>` focuses on, leaving `None` in its place,
+    /// and returns the removed value.
+    ///
+    /// If the prism does not match, the source is left unchanged and `None` is returned.
+    ///
+    /// # Parameters
+    ///
+    /// - `source`: A mutable reference to the source from which the focus is removed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use optics::{field_lens, vec, HasGetter};
+    ///
+    /// #[derive(Clone)]
+    /// struct Replica {
+    ///     port: Option<u16>,
+    /// }
+    ///
+    /// let first_port = vec::first().compose_with_lens(field_lens!(Replica, port));
+    /// let mut replicas = vec![Replica { port: Some(5432) }];
+    ///
+    /// assert_eq!(first_port.take(&mut replicas), Some(5432));
+    /// assert_eq!(first_port.try_get(&replicas).ok(), Some(None));
+    /// assert_eq!(first_port.take(&mut Vec::new()), None);
+    /// ```
+    pub fn take(&self, source: &mut S) -> Option<A> {
+        let mut taken = None;
+        self.0.modify(source, |value| taken = value.take());
+        taken
+    }
+}
+
+#[allow(clippy::wrong_self_convention)]
+impl<S, A, P: Prism<S, A>> PrismImpl<S, A, P> {
+    /// Downgrades this `PrismImpl<S,A>` to a read-only `PartialGetterImpl<S,A>`, exposing only the
+    /// capabilities of a [`PartialGetter`].
+    ///
+    /// The underlying optic is wrapped so that only its read operations can be reached, not even
+    /// through the `.0` field of the result, so APIs can accept exactly the access level they need.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use optics::{HasGetter, NoneError, option};
+    ///
+    /// let timeout = option::some::<u32>().as_partial_getter();
+    ///
+    /// assert_eq!(timeout.try_get(&Some(30)), Ok(30));
+    /// assert_eq!(timeout.try_get(&None), Err(NoneError));
+    /// ```
+    #[must_use]
+    pub fn as_partial_getter(self) -> PartialGetterImpl<S, A, ReadOnly<P>> {
+        ReadOnly::new(self.0).into()
+    }
+
+    /// Downgrades this `PrismImpl<S,A>` to a write-only `SetterImpl<S,A>`, exposing only the
+    /// capabilities of a [`Setter`].
+    ///
+    /// The underlying optic is wrapped so that only its write operations can be reached, not even
+    /// through the `.0` field of the result, so APIs can accept exactly the access level they need.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use optics::{HasSetter, option};
+    ///
+    /// let timeout = option::some::<u32>().as_setter();
+    /// let mut configured = None;
+    ///
+    /// timeout.set(&mut configured, 30);
+    /// assert_eq!(configured, Some(30));
+    /// ```
+    #[must_use]
+    pub fn as_setter(self) -> SetterImpl<S, A, WriteOnly<P>> {
+        WriteOnly::new(self.0).into()
+    }
+
+    /// Downgrades this `PrismImpl<S,A>` to a write-only `FallibleSetterImpl<S,A>`, exposing only
+    /// the capabilities of a [`FallibleSetter`].
+    ///
+    /// This is only available for optics that can reject the written values, such as the ones
+    /// created by [`PrismImpl::with_validation`]. The resulting optic can be composed with other
+    /// optics to surface the rejections.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use optics::{HasFallibleSetter, option};
+    ///
+    /// let port = option::some::<u16>()
+    ///     .with_validation(|port| if *port < 1024 { Err("port below 1024") } else { Ok(()) })
+    ///     .as_fallible_setter();
+    ///
+    /// assert_eq!(port.try_set(&mut None, 80), Err("port below 1024"));
+    /// ```
+    #[must_use]
+    pub fn as_fallible_setter(self) -> FallibleSetterImpl<S, A, WriteOnly<P>>
+    where
+        P: HasFallibleSetter<S, A>,
+    {
+        WriteOnly::new(self.0).into()
     }
 }
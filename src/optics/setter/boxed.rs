@@ -0,0 +1,23 @@
+use crate::{HasSetter, SetterImpl};
+use alloc::boxed::Box;
+
+/// An object safe version of [`Setter`](crate::Setter), implemented by every setter.
+///
+/// It can be used as a trait object, such as in a [`BoxedSetter`], to store setters of different
+/// types in the same collection.
+pub trait DynSetter<S, A>: HasSetter<S, A> {}
+
+impl<S, A, O: HasSetter<S, A>> DynSetter<S, A> for O {}
+
+/// A boxed setter of any type, focusing on a value of type `A` in a source of type `S`.
+///
+/// Unlike the opaque setters returned by the constructors of this crate, boxed setters of different
+/// types have the same type, so they can be stored in collections or struct fields, and composed
+/// like any other setter. They are created with [`SetterImpl::boxed`].
+pub type BoxedSetter<S, A> = SetterImpl<S, A, Box<dyn DynSetter<S, A> + Send + Sync>>;
+
+impl<S, A> HasSetter<S, A> for Box<dyn DynSetter<S, A> + Send + Sync> {
+    fn set(&self, source: &mut S, value: A) {
+        (**self).set(source, value);
+    }
+}
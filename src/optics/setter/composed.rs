@@ -1,12 +1,77 @@
+use crate::HasPath;
 use crate::Setter;
 use crate::optics::setter::wrapper::SetterImpl;
-use crate::{HasSetter, Prism};
+use crate::{HasGetter, HasSetter};
+use core::fmt::{Debug, Display, Formatter};
 use core::marker::PhantomData;
 
-struct ComposedSetter<SETTER1: Setter<S, I>, SETTER2: Setter<I, A>, S, I, A> {
-    optic1: SETTER1,
-    optic2: SETTER2,
-    _phantom: PhantomData<(S, I, A)>,
+mod private {
+    use crate::Setter;
+    use core::marker::PhantomData;
+
+    pub struct ComposedSetter<SETTER1: Setter<S, I>, SETTER2: Setter<I, A>, S, I, A> {
+        pub(super) optic1: SETTER1,
+        pub(super) optic2: SETTER2,
+        pub(super) _phantom: PhantomData<(S, I, A)>,
+    }
+}
+
+pub type ComposedSetter<SETTER1, SETTER2, S, I, A> =
+    private::ComposedSetter<SETTER1, SETTER2, S, I, A>;
+
+impl<SETTER1, SETTER2, S, I, A> Clone for ComposedSetter<SETTER1, SETTER2, S, I, A>
+where
+    SETTER1: Setter<S, I> + Clone,
+    SETTER2: Setter<I, A> + Clone,
+{
+    fn clone(&self) -> Self {
+        ComposedSetter {
+            optic1: self.optic1.clone(),
+            optic2: self.optic2.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<SETTER1, SETTER2, S, I, A> Copy for ComposedSetter<SETTER1, SETTER2, S, I, A>
+where
+    SETTER1: Setter<S, I> + Copy,
+    SETTER2: Setter<I, A> + Copy,
+{
+}
+
+impl<SETTER1, SETTER2, S, I, A> Debug for ComposedSetter<SETTER1, SETTER2, S, I, A>
+where
+    SETTER1: Setter<S, I> + Debug,
+    SETTER2: Setter<I, A> + Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ComposedSetter")
+            .field("optic1", &self.optic1)
+            .field("optic2", &self.optic2)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<SETTER1, SETTER2, S, I, A> Display for ComposedSetter<SETTER1, SETTER2, S, I, A>
+where
+    SETTER1: Setter<S, I> + Display,
+    SETTER2: Setter<I, A> + Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}.{}", self.optic1, self.optic2)
+    }
+}
+
+impl<SETTER1, SETTER2, S, I, A> HasPath for ComposedSetter<SETTER1, SETTER2, S, I, A>
+where
+    SETTER1: Setter<S, I> + HasPath,
+    SETTER2: Setter<I, A> + HasPath,
+{
+    fn for_each_segment<'a>(&'a self, f: &mut dyn FnMut(&'a str)) {
+        self.optic1.for_each_segment(f);
+        self.optic2.for_each_segment(f);
+    }
 }
 
 impl<SETTER1, SETTER2, S, I, A> ComposedSetter<SETTER1, SETTER2, S, I, A>
@@ -14,7 +79,7 @@ where
     SETTER1: Setter<S, I>,
     SETTER2: Setter<I, A>,
 {
-    pub(self) fn new(optic1: SETTER1, optic2: SETTER2) -> Self {
+    pub(crate) fn new(optic1: SETTER1, optic2: SETTER2) -> Self {
         ComposedSetter {
             optic1,
             optic2,
@@ -25,9 +90,10 @@ where
 
 impl<S, I, A, P, SETTER2> HasSetter<S, A> for ComposedSetter<P, SETTER2, S, I, A>
 where
-    P: Prism<S, I>,
+    P: HasGetter<S, I> + HasSetter<S, I>,
     SETTER2: Setter<I, A>,
 {
+    #[inline]
     fn set(&self, source: &mut S, value: A) {
         if let Ok(mut i) = self.optic1.try_get(source) {
             self.optic2.set(&mut i, value);
@@ -63,9 +129,9 @@ where
 ///
 /// - [`Setter`] — the optic type that `ComposedSetter` is based on
 #[must_use]
-pub fn new<S, A, I, P1: Prism<S, I>, SETTER2: Setter<I, A>>(
+pub fn new<S, A, I, P1: HasGetter<S, I> + HasSetter<S, I>, SETTER2: Setter<I, A>>(
     p1: P1,
     s2: SETTER2,
-) -> SetterImpl<S, A, impl Setter<S, A>> {
+) -> SetterImpl<S, A, ComposedSetter<P1, SETTER2, S, I, A>> {
     ComposedSetter::new(p1, s2).into()
 }
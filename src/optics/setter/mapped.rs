@@ -1,20 +1,50 @@
 use crate::HasSetter;
-use crate::{Setter, SetterImpl};
+use crate::SetterImpl;
+use core::fmt::{Debug, Formatter};
 use core::marker::PhantomData;
 
-struct MappedSetter<S, A, SET = fn(&mut S, A)>
+mod private {
+    use core::marker::PhantomData;
+
+    pub struct MappedSetter<S, A, SET = fn(&mut S, A)>
+    where
+        SET: Fn(&mut S, A),
+    {
+        pub(super) set_fn: SET,
+        pub(super) phantom: PhantomData<(S, A)>,
+    }
+}
+
+pub type MappedSetter<S, A, SET = fn(&mut S, A)> = private::MappedSetter<S, A, SET>;
+
+impl<S, A, SET> Clone for MappedSetter<S, A, SET>
+where
+    SET: Fn(&mut S, A) + Clone,
+{
+    fn clone(&self) -> Self {
+        MappedSetter {
+            set_fn: self.set_fn.clone(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<S, A, SET> Copy for MappedSetter<S, A, SET> where SET: Fn(&mut S, A) + Copy {}
+
+impl<S, A, SET> Debug for MappedSetter<S, A, SET>
 where
     SET: Fn(&mut S, A),
 {
-    set_fn: SET,
-    phantom: PhantomData<(S, A)>,
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("MappedSetter").finish_non_exhaustive()
+    }
 }
 
 impl<S, A, SET> MappedSetter<S, A, SET>
 where
     SET: Fn(&mut S, A),
 {
-    fn new(set_fn: SET) -> Self {
+    const fn new(set_fn: SET) -> Self {
         MappedSetter {
             set_fn,
             phantom: PhantomData,
@@ -59,9 +89,9 @@ where
 /// assert_eq!(s.x, 42);
 /// ```
 #[must_use]
-pub fn new<S, A, SET>(set_fn: SET) -> SetterImpl<S, A, impl Setter<S, A>>
+pub const fn new<S, A, SET>(set_fn: SET) -> SetterImpl<S, A, MappedSetter<S, A, SET>>
 where
     SET: Fn(&mut S, A),
 {
-    MappedSetter::new(set_fn).into()
+    SetterImpl::new(MappedSetter::new(set_fn))
 }
@@ -1,12 +1,44 @@
+#[cfg(all(feature = "alloc", feature = "setter"))]
+mod boxed;
+#[cfg(feature = "setter")]
 mod composed;
+#[cfg(feature = "setter")]
 mod mapped;
+mod nested;
+#[cfg(all(feature = "setter", any(feature = "iso", feature = "fallible-iso")))]
+mod reversed;
+#[cfg(all(feature = "alloc", feature = "setter"))]
+mod shared;
+#[cfg(feature = "setter")]
 mod wrapper;
+#[cfg(all(feature = "setter", any(feature = "lens", feature = "prism")))]
+mod write_only;
 
 use crate::HasSetter;
 
+#[cfg(all(feature = "alloc", feature = "setter"))]
+pub use boxed::{BoxedSetter, DynSetter};
+#[cfg(all(feature = "setter", any(feature = "getter", feature = "prism")))]
+pub(crate) use composed::ComposedSetter;
+#[cfg(feature = "setter")]
 pub use composed::new as composed_setter;
+#[cfg(feature = "setter")]
 pub use mapped::new as mapped_setter;
+pub(crate) use nested::NestedSetter;
+#[cfg(all(feature = "setter", any(feature = "iso", feature = "fallible-iso")))]
+pub(crate) use reversed::ReversedSetter;
+#[cfg(all(feature = "alloc", feature = "setter"))]
+pub use shared::SharedSetter;
+#[cfg(feature = "setter")]
 pub use wrapper::SetterImpl;
+#[cfg(all(feature = "setter", any(feature = "lens", feature = "prism")))]
+pub(crate) use write_only::WriteOnly;
+
+/// A [`SetterImpl`] built by [`mapped_setter`] from plain functions or non-capturing closures.
+///
+/// As [`mapped_setter`] is a `const fn`, this type can be used to declare the optic as a `static`.
+#[cfg(feature = "setter")]
+pub type FnSetter<S, A> = SetterImpl<S, A, mapped::MappedSetter<S, A>>;
 
 /// A `Setter` is an optic that can change its focused value, providing
 /// only a write operation
@@ -75,6 +107,7 @@ impl<S, A, SETTER: HasSetter<S, A>> Setter<S, A> for SETTER {}
 /// # See Also
 ///
 /// - [`mapped_setter`] for constructing custom `Setter`s from arbitrary mapping functions.
+#[cfg(feature = "setter")]
 #[must_use]
 pub fn identity_setter<S>() -> SetterImpl<S, S, impl Setter<S, S>> {
     mapped_setter(|s, v| *s = v)
@@ -0,0 +1,130 @@
+use crate::HasPath;
+use crate::{HasFallibleSetter, HasModify, HasSetter, Setter};
+use core::fmt::{Debug, Display, Formatter};
+use core::marker::PhantomData;
+
+mod private {
+    use crate::Setter;
+    use core::marker::PhantomData;
+
+    pub struct NestedSetter<O1: Setter<S, I>, SETTER2: Setter<I, A>, S, I, A> {
+        pub(super) optic1: O1,
+        pub(super) optic2: SETTER2,
+        pub(super) _phantom: PhantomData<(S, I, A)>,
+    }
+}
+
+/// A `NestedSetter` represents the composition of an optic able to modify its focus of type `I`
+/// in place, such as a setter wrapping a lens or a prism, with a `Setter<I, A>`, resulting in a
+/// setter writing values of type `A` into a source of type `S`.
+///
+/// The value is written by modifying the focus of the first optic with the second one, so the
+/// write is dropped if the first optic has no focus in the source.
+///
+/// It is a [`HasSetter`], and a [`HasFallibleSetter`] when the first optic is one, writing the
+/// modified focus back with [`HasFallibleSetter::try_set`] so the first optic can reject it.
+///
+/// # Type Parameters
+/// - `O1`: The optic modifying `I` in place inside `S`.
+/// - `SETTER2`: The setter writing `A` into `I`.
+/// - `S`: The source type.
+/// - `I`: The intermediate type.
+/// - `A`: The target type.
+pub type NestedSetter<O1, SETTER2, S, I, A> = private::NestedSetter<O1, SETTER2, S, I, A>;
+
+impl<O1, SETTER2, S, I, A> Clone for NestedSetter<O1, SETTER2, S, I, A>
+where
+    O1: Setter<S, I> + Clone,
+    SETTER2: Setter<I, A> + Clone,
+{
+    fn clone(&self) -> Self {
+        NestedSetter {
+            optic1: self.optic1.clone(),
+            optic2: self.optic2.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<O1, SETTER2, S, I, A> Copy for NestedSetter<O1, SETTER2, S, I, A>
+where
+    O1: Setter<S, I> + Copy,
+    SETTER2: Setter<I, A> + Copy,
+{
+}
+
+impl<O1, SETTER2, S, I, A> Debug for NestedSetter<O1, SETTER2, S, I, A>
+where
+    O1: Setter<S, I> + Debug,
+    SETTER2: Setter<I, A> + Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("NestedSetter")
+            .field("optic1", &self.optic1)
+            .field("optic2", &self.optic2)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<O1, SETTER2, S, I, A> Display for NestedSetter<O1, SETTER2, S, I, A>
+where
+    O1: Setter<S, I> + Display,
+    SETTER2: Setter<I, A> + Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}.{}", self.optic1, self.optic2)
+    }
+}
+
+impl<O1, SETTER2, S, I, A> HasPath for NestedSetter<O1, SETTER2, S, I, A>
+where
+    O1: Setter<S, I> + HasPath,
+    SETTER2: Setter<I, A> + HasPath,
+{
+    fn for_each_segment<'a>(&'a self, f: &mut dyn FnMut(&'a str)) {
+        self.optic1.for_each_segment(f);
+        self.optic2.for_each_segment(f);
+    }
+}
+
+impl<O1, SETTER2, S, I, A> NestedSetter<O1, SETTER2, S, I, A>
+where
+    O1: Setter<S, I>,
+    SETTER2: Setter<I, A>,
+{
+    pub(crate) fn new(optic1: O1, optic2: SETTER2) -> Self {
+        NestedSetter {
+            optic1,
+            optic2,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<O1, SETTER2, S, I, A> HasSetter<S, A> for NestedSetter<O1, SETTER2, S, I, A>
+where
+    O1: HasModify<S, I>,
+    SETTER2: Setter<I, A>,
+{
+    #[inline]
+    fn set(&self, source: &mut S, value: A) {
+        self.optic1.modify(source, |i| self.optic2.set(i, value));
+    }
+}
+
+impl<O1, SETTER2, S, I, A> HasFallibleSetter<S, A> for NestedSetter<O1, SETTER2, S, I, A>
+where
+    O1: HasModify<S, I> + HasFallibleSetter<S, I>,
+    SETTER2: Setter<I, A>,
+{
+    type SetterError = O1::SetterError;
+
+    #[inline]
+    fn try_set(&self, source: &mut S, value: A) -> Result<(), Self::SetterError> {
+        let Ok(mut i) = self.optic1.try_get(source) else {
+            return Ok(());
+        };
+        self.optic2.set(&mut i, value);
+        self.optic1.try_set(source, i)
+    }
+}
@@ -0,0 +1,131 @@
+use crate::HasPath;
+use crate::Setter;
+use crate::{HasFallibleSetter, HasReverseGet, HasSetter};
+use core::fmt::{Debug, Display, Formatter};
+use core::marker::PhantomData;
+
+mod private {
+    use crate::{HasReverseGet, Setter};
+    use core::marker::PhantomData;
+
+    pub struct ReversedSetter<SETTER1: Setter<S, I>, R2: HasReverseGet<I, A>, S, I, A> {
+        pub(super) optic1: SETTER1,
+        pub(super) optic2: R2,
+        pub(super) _phantom: PhantomData<(S, I, A)>,
+    }
+}
+
+/// A `ReversedSetter` represents the composition of a `Setter<S, I>` with an optic able to
+/// convert a value of type `A` back into an `I`, such as an `Iso<I, A>` or a `FallibleIso<I, A>`,
+/// resulting in a setter writing values of type `A` into a source of type `S`.
+///
+/// A setter can not read its focus, so the value being written is converted into the focus of the
+/// setter with the reverse direction of the second optic, and written as a whole.
+///
+/// It is a [`HasSetter`] that drops the write if the conversion fails, and a
+/// [`HasFallibleSetter`] reporting the error of the conversion.
+///
+/// # Type Parameters
+/// - `SETTER1`: The setter, writing `I` into `S`.
+/// - `R2`: The optic converting `A` back into `I`.
+/// - `S`: The source type.
+/// - `I`: The intermediate type.
+/// - `A`: The target type.
+pub type ReversedSetter<SETTER1, R2, S, I, A> = private::ReversedSetter<SETTER1, R2, S, I, A>;
+
+impl<SETTER1, R2, S, I, A> Clone for ReversedSetter<SETTER1, R2, S, I, A>
+where
+    SETTER1: Setter<S, I> + Clone,
+    R2: HasReverseGet<I, A> + Clone,
+{
+    fn clone(&self) -> Self {
+        ReversedSetter {
+            optic1: self.optic1.clone(),
+            optic2: self.optic2.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<SETTER1, R2, S, I, A> Copy for ReversedSetter<SETTER1, R2, S, I, A>
+where
+    SETTER1: Setter<S, I> + Copy,
+    R2: HasReverseGet<I, A> + Copy,
+{
+}
+
+impl<SETTER1, R2, S, I, A> Debug for ReversedSetter<SETTER1, R2, S, I, A>
+where
+    SETTER1: Setter<S, I> + Debug,
+    R2: HasReverseGet<I, A> + Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ReversedSetter")
+            .field("optic1", &self.optic1)
+            .field("optic2", &self.optic2)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<SETTER1, R2, S, I, A> Display for ReversedSetter<SETTER1, R2, S, I, A>
+where
+    SETTER1: Setter<S, I> + Display,
+    R2: HasReverseGet<I, A> + Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}.{}", self.optic1, self.optic2)
+    }
+}
+
+impl<SETTER1, R2, S, I, A> HasPath for ReversedSetter<SETTER1, R2, S, I, A>
+where
+    SETTER1: Setter<S, I> + HasPath,
+    R2: HasReverseGet<I, A> + HasPath,
+{
+    fn for_each_segment<'a>(&'a self, f: &mut dyn FnMut(&'a str)) {
+        self.optic1.for_each_segment(f);
+        self.optic2.for_each_segment(f);
+    }
+}
+
+impl<SETTER1, R2, S, I, A> ReversedSetter<SETTER1, R2, S, I, A>
+where
+    SETTER1: Setter<S, I>,
+    R2: HasReverseGet<I, A>,
+{
+    pub(crate) fn new(optic1: SETTER1, optic2: R2) -> Self {
+        ReversedSetter {
+            optic1,
+            optic2,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<SETTER1, R2, S, I, A> HasSetter<S, A> for ReversedSetter<SETTER1, R2, S, I, A>
+where
+    SETTER1: Setter<S, I>,
+    R2: HasReverseGet<I, A>,
+{
+    #[inline]
+    fn set(&self, source: &mut S, value: A) {
+        if let Ok(i) = self.optic2.try_reverse_get(&value) {
+            self.optic1.set(source, i);
+        }
+    }
+}
+
+impl<SETTER1, R2, S, I, A> HasFallibleSetter<S, A> for ReversedSetter<SETTER1, R2, S, I, A>
+where
+    SETTER1: Setter<S, I>,
+    R2: HasReverseGet<I, A>,
+{
+    type SetterError = R2::ReverseError;
+
+    #[inline]
+    fn try_set(&self, source: &mut S, value: A) -> Result<(), Self::SetterError> {
+        let i = self.optic2.try_reverse_get(&value)?;
+        self.optic1.set(source, i);
+        Ok(())
+    }
+}
@@ -0,0 +1,15 @@
+use crate::{DynSetter, HasSetter, SetterImpl};
+use alloc::sync::Arc;
+
+/// A shared setter of any type, focusing on a value of type `A` in a source of type `S`.
+///
+/// Like [boxed setters](crate::BoxedSetter), shared setters of different types have the same type,
+/// but they are reference counted, so they can be cloned cheaply, stored in registries and sent to
+/// other threads. They are created with [`SetterImpl::shared`](crate::SetterImpl::shared).
+pub type SharedSetter<S, A> = SetterImpl<S, A, Arc<dyn DynSetter<S, A> + Send + Sync>>;
+
+impl<S, A> HasSetter<S, A> for Arc<dyn DynSetter<S, A> + Send + Sync> {
+    fn set(&self, source: &mut S, value: A) {
+        (**self).set(source, value);
+    }
+}
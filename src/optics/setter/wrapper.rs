@@ -1,5 +1,34 @@
-use crate::{HasSetter, Setter};
+#[cfg(feature = "fallible-iso")]
+use crate::FallibleIso;
+use crate::HasPath;
+#[cfg(feature = "iso")]
+use crate::Iso;
+#[cfg(feature = "lens")]
+use crate::Lens;
+#[cfg(feature = "prism")]
+use crate::Prism;
+use crate::named::Named;
+use crate::optics::fallible_setter::ComposedFallibleSetter;
+use crate::optics::setter::NestedSetter;
+#[cfg(any(feature = "iso", feature = "fallible-iso"))]
+use crate::optics::setter::ReversedSetter;
+use crate::optics::traversal::{OverAll, over_all};
+#[cfg(feature = "tracing")]
+use crate::traced::Traced;
+use crate::{
+    FallibleSetter, FallibleSetterImpl, HasModify, HasSetter, Setter, Traversal,
+    composed_fallible_setter, mapped_setter,
+};
+use core::fmt::{Debug, Display, Formatter};
 use core::marker::PhantomData;
+#[cfg(feature = "alloc")]
+use {
+    crate::optics::setter::{BoxedSetter, DynSetter, SharedSetter},
+    alloc::boxed::Box,
+    alloc::string::{String, ToString},
+    alloc::sync::Arc,
+    alloc::vec::Vec,
+};
 
 /// A wrapper of the [`Setter`] optic implementations, encapsulating a setter function.
 ///
@@ -25,7 +54,7 @@ use core::marker::PhantomData;
 pub struct SetterImpl<S, A, SETTER: Setter<S, A>>(pub SETTER, PhantomData<(S, A)>);
 
 impl<S, A, SETTER: Setter<S, A>> SetterImpl<S, A, SETTER> {
-    fn new(l: SETTER) -> Self {
+    pub(crate) const fn new(l: SETTER) -> Self {
         //TODO: Verify not to nest an Impl inside an Impl - currently seems to be impossible at compile time.
         SetterImpl(l, PhantomData)
     }
@@ -37,8 +66,433 @@ impl<S, A, SETTER: Setter<S, A>> From<SETTER> for SetterImpl<S, A, SETTER> {
     }
 }
 
+impl<S, A, SETTER: Setter<S, A> + Clone> Clone for SetterImpl<S, A, SETTER> {
+    fn clone(&self) -> Self {
+        Self::new(self.0.clone())
+    }
+}
+
+impl<S, A, SETTER: Setter<S, A> + Copy> Copy for SetterImpl<S, A, SETTER> {}
+
+impl<S, A, SETTER: Setter<S, A> + Debug> Debug for SetterImpl<S, A, SETTER> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("SetterImpl").field(&self.0).finish()
+    }
+}
+
+impl<S, A, SETTER: Setter<S, A> + Display> Display for SetterImpl<S, A, SETTER> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl<S, A, SETTER: Setter<S, A> + HasPath> HasPath for SetterImpl<S, A, SETTER> {
+    fn for_each_segment<'a>(&'a self, f: &mut dyn FnMut(&'a str)) {
+        self.0.for_each_segment(f);
+    }
+}
+
+impl<S, A, SETTER: Setter<S, A>> SetterImpl<S, A, SETTER> {
+    /// Labels this optic with a static `name`, used by its [`Display`] implementation.
+    ///
+    /// Composing named optics results in an optic displaying the names of its parts joined with a
+    /// `.`, which helps to identify a misbehaving optic in logs and error messages.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The name of the optic.
+    ///
+    /// # Returns
+    ///
+    /// A new `SetterImpl` behaving exactly like `self`, displayed as `name`.
+    #[must_use]
+    pub fn named(self, name: &'static str) -> SetterImpl<S, A, Named<SETTER>> {
+        Named::new(self.0, name).into()
+    }
+
+    /// Wraps this optic to emit a `tracing` event for every operation going through it, with
+    /// `name` identifying the optic, whether the operation succeeded and the time it took.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The name of the optic in the events, such as `"config.port"`.
+    ///
+    /// # Returns
+    ///
+    /// A new `SetterImpl` behaving exactly like `self`, tracing its operations.
+    #[cfg(feature = "tracing")]
+    #[must_use]
+    pub fn traced(self, name: &'static str) -> SetterImpl<S, A, Traced<SETTER>> {
+        Traced::new(self.0, name).into()
+    }
+
+    /// Returns the name of the optic, the names of the optics it is composed of joined with a `.`.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn name(&self) -> String
+    where
+        SETTER: Display,
+    {
+        self.to_string()
+    }
+
+    /// Returns the names of the fields and variants this optic focuses through, from the outermost
+    /// one, such as the field names of the [`field_lens!`](crate::field_lens) lenses it is
+    /// composed of.
+    #[cfg(feature = "alloc")]
+    pub fn path(&self) -> impl Iterator<Item = &str>
+    where
+        SETTER: HasPath,
+    {
+        let mut path = Vec::new();
+        self.0.for_each_segment(&mut |segment| path.push(segment));
+        path.into_iter()
+    }
+
+    /// Boxes this setter, erasing its type, so it can be stored alongside setters of other types.
+    ///
+    /// # Returns
+    ///
+    /// A [`BoxedSetter`] behaving exactly like `self`.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn boxed(self) -> BoxedSetter<S, A>
+    where
+        SETTER: Send + Sync + 'static,
+    {
+        let optic: Box<dyn DynSetter<S, A> + Send + Sync> = Box::new(self.0);
+        optic.into()
+    }
+
+    /// Wraps this setter in an [`Arc`], erasing its type, so it can be cloned cheaply and shared
+    /// between threads.
+    ///
+    /// # Returns
+    ///
+    /// A [`SharedSetter`] behaving exactly like `self`.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn shared(self) -> SharedSetter<S, A>
+    where
+        SETTER: Send + Sync + 'static,
+    {
+        let optic: Arc<dyn DynSetter<S, A> + Send + Sync> = Arc::new(self.0);
+        optic.into()
+    }
+}
+
 impl<S, A, SETTER: Setter<S, A>> HasSetter<S, A> for SetterImpl<S, A, SETTER> {
+    #[inline]
     fn set(&self, source: &mut S, value: A) {
         self.0.set(source, value);
     }
 }
+
+impl<S, A, SETTER: Setter<S, A>> SetterImpl<S, A, SETTER> {
+    /// Adapts this `SetterImpl<S,A>` into a `SetterImpl<S,B>` by mapping the incoming value with `f`
+    /// before writing it.
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: The function converting the value being set into the focus of `self`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use optics::{HasSetter, mapped_setter};
+    ///
+    /// let timeout_millis = mapped_setter(|timeout: &mut u64, millis: u64| *timeout = millis);
+    /// let timeout_secs = timeout_millis.premap(|secs: u32| u64::from(secs) * 1_000);
+    ///
+    /// let mut timeout = 0;
+    /// timeout_secs.set(&mut timeout, 30);
+    /// assert_eq!(timeout, 30_000);
+    /// ```
+    pub fn premap<B, F: Fn(B) -> A>(self, f: F) -> SetterImpl<S, B, impl Setter<S, B>> {
+        mapped_setter(move |source: &mut S, value: B| self.0.set(source, f(value)))
+    }
+}
+
+impl<S, I, SETTER: Setter<S, I>> SetterImpl<S, I, SETTER> {
+    /// Composes this `SetterImpl<S,I>` with a `Setter<I,A>`, resulting in a new `SetterImpl<S, A>`
+    /// writing values of type `A`.
+    ///
+    /// The value is written by modifying the focus of `self` in place with `other`, so `self`
+    /// must be able to read its focus, such as a setter wrapping a lens or a prism. If `self`
+    /// has no focus in the source, the write is dropped.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `A`: The target type of the composed optic.
+    /// - `S2`: The type of the setter to compose with.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: The setter to compose with.
+    ///
+    /// # Returns
+    ///
+    /// A new `SetterImpl` that represents the composition of `self` and `other`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use optics::{HasSetter, SetterImpl, mapped_lens, mapped_setter};
+    ///
+    /// let first = SetterImpl::from(mapped_lens(|p: &(u32, u32)| p.0, |p, v| p.0 = v).0);
+    /// let double = mapped_setter(|x: &mut u32, v: u32| *x = v * 2);
+    ///
+    /// let mut pair = (1, 2);
+    /// first.compose_with_setter(double).set(&mut pair, 5);
+    /// assert_eq!(pair, (10, 2));
+    /// ```
+    pub fn compose_with_setter<A, S2: Setter<I, A>>(
+        self,
+        other: S2,
+    ) -> SetterImpl<S, A, NestedSetter<SETTER, S2, S, I, A>>
+    where
+        SETTER: HasModify<S, I>,
+    {
+        NestedSetter::new(self.0, other).into()
+    }
+
+    /// Composes this `SetterImpl<S,I>` with a `FallibleSetter<I,A>`, resulting in a new
+    /// `FallibleSetterImpl<S, A>` writing values of type `A`.
+    ///
+    /// The focus of `self` is read, written through `other` and written back through `self`, so
+    /// `self` must be able to read its focus, such as a setter wrapping a lens or a prism. If
+    /// `self` fails to focus, or `other` rejects the value, the source is left unchanged and the
+    /// error is returned.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `E`: The error type of the composed setter, that both the getter error of `self` and the
+    ///   setter error of `other` can be converted into.
+    /// - `A`: The target type of the composed optic.
+    /// - `FS2`: The type of the fallible setter to compose with.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: The fallible setter to compose with.
+    ///
+    /// # Returns
+    ///
+    /// A new `FallibleSetterImpl` that represents the composition of `self` and `other`.
+    pub fn compose_with_fallible_setter<E, A, FS2: FallibleSetter<I, A>>(
+        self,
+        other: FS2,
+    ) -> FallibleSetterImpl<S, A, ComposedFallibleSetter<SETTER, FS2, E, S, I, A>>
+    where
+        SETTER: HasModify<S, I>,
+        SETTER::GetterError: Into<E>,
+        FS2::SetterError: Into<E>,
+    {
+        composed_fallible_setter(self.0, other, Into::into, Into::into)
+    }
+
+    /// Composes this `SetterImpl<S,I>` with a `FallibleSetter<I,A>`, resulting in a new
+    /// `FallibleSetterImpl<S, A>` writing values of type `A`, using the provided mappers to
+    /// convert the errors of both optics into a common error type.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `E`: The error type of the composed setter.
+    /// - `A`: The target type of the composed optic.
+    /// - `FS2`: The type of the fallible setter to compose with.
+    /// - `F1`: The type of the mapper of the getter error of `self`.
+    /// - `F2`: The type of the mapper of the setter error of `other`.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: The fallible setter to compose with.
+    /// - `error_mapper_1`: Converts the getter error of `self` into `E`.
+    /// - `error_mapper_2`: Converts the setter error of `other` into `E`.
+    ///
+    /// # Returns
+    ///
+    /// A new `FallibleSetterImpl` that represents the composition of `self` and `other`.
+    #[allow(clippy::type_complexity)]
+    pub fn compose_with_fallible_setter_with_mappers<E, A, FS2: FallibleSetter<I, A>, F1, F2>(
+        self,
+        other: FS2,
+        error_mapper_1: F1,
+        error_mapper_2: F2,
+    ) -> FallibleSetterImpl<S, A, ComposedFallibleSetter<SETTER, FS2, E, S, I, A, F1, F2>>
+    where
+        SETTER: HasModify<S, I>,
+        F1: Fn(SETTER::GetterError) -> E,
+        F2: Fn(FS2::SetterError) -> E,
+    {
+        ComposedFallibleSetter::with_mappers(self.0, other, error_mapper_1, error_mapper_2).into()
+    }
+
+    /// Composes this `SetterImpl<S,I>` with a `Prism<I,A>`, resulting in a new `SetterImpl<S, A>`
+    /// writing values of type `A`.
+    ///
+    /// The value is written by modifying the focus of `self` in place with `other`, so `self`
+    /// must be able to read its focus, such as a setter wrapping a lens or a prism. If `self`
+    /// has no focus in the source, the write is dropped.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `A`: The target type of the composed optic.
+    /// - `P2`: The type of the prism to compose with.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: The prism to compose with.
+    ///
+    /// # Returns
+    ///
+    /// A new `SetterImpl` that represents the composition of `self` and `other`.
+    #[cfg(feature = "prism")]
+    pub fn compose_with_prism<A, P2: Prism<I, A>>(
+        self,
+        other: P2,
+    ) -> SetterImpl<S, A, NestedSetter<SETTER, P2, S, I, A>>
+    where
+        SETTER: HasModify<S, I>,
+    {
+        NestedSetter::new(self.0, other).into()
+    }
+
+    /// Composes this `SetterImpl<S,I>` with a `Lens<I,A>`, resulting in a new `SetterImpl<S, A>`
+    /// writing values of type `A`.
+    ///
+    /// The value is written by modifying the focus of `self` in place with `other`, so `self`
+    /// must be able to read its focus, such as a setter wrapping a lens or a prism. If `self`
+    /// has no focus in the source, the write is dropped.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `A`: The target type of the composed optic.
+    /// - `L2`: The type of the lens to compose with.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: The lens to compose with.
+    ///
+    /// # Returns
+    ///
+    /// A new `SetterImpl` that represents the composition of `self` and `other`.
+    #[cfg(feature = "lens")]
+    pub fn compose_with_lens<A, L2: Lens<I, A>>(
+        self,
+        other: L2,
+    ) -> SetterImpl<S, A, NestedSetter<SETTER, L2, S, I, A>>
+    where
+        SETTER: HasModify<S, I>,
+    {
+        NestedSetter::new(self.0, other).into()
+    }
+
+    /// Composes this `SetterImpl<S,I>` with an `Iso<I,A>`, resulting in a new `SetterImpl<S, A>`
+    /// writing values of type `A`.
+    ///
+    /// A setter can not read its focus, so the value being written is converted into the focus of
+    /// `self` with the reverse direction of `other`, and written through `self`.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `A`: The target type of the composed optic.
+    /// - `ISO2`: The type of the iso to compose with.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: The iso to compose with.
+    ///
+    /// # Returns
+    ///
+    /// A new `SetterImpl` that represents the composition of `self` and `other`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use optics::{HasSetter, mapped_iso, mapped_setter};
+    ///
+    /// let timeout_millis = mapped_setter(|timeout: &mut u64, millis: u64| *timeout = millis);
+    /// let secs = mapped_iso(|millis: &u64| millis / 1_000, |secs: &u64| secs * 1_000);
+    ///
+    /// let mut timeout = 0;
+    /// timeout_millis.compose_with_iso(secs).set(&mut timeout, 30);
+    /// assert_eq!(timeout, 30_000);
+    /// ```
+    #[cfg(feature = "iso")]
+    pub fn compose_with_iso<A, ISO2: Iso<I, A>>(
+        self,
+        other: ISO2,
+    ) -> SetterImpl<S, A, ReversedSetter<SETTER, ISO2, S, I, A>> {
+        ReversedSetter::new(self.0, other).into()
+    }
+
+    /// Composes this `SetterImpl<S,I>` with a `FallibleIso<I,A>`, resulting in a new
+    /// `FallibleSetterImpl<S, A>` writing values of type `A`.
+    ///
+    /// A setter can not read its focus, so the value being written is converted into the focus of
+    /// `self` with the reverse direction of `other`, and written through `self`. If the
+    /// conversion fails, the source is left unchanged and the reverse error of `other` is
+    /// returned.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `A`: The target type of the composed optic.
+    /// - `FI2`: The type of the fallible iso to compose with.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: The fallible iso to compose with.
+    ///
+    /// # Returns
+    ///
+    /// A new `FallibleSetterImpl` that represents the composition of `self` and `other`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use optics::{HasFallibleSetter, mapped_setter, parse_iso};
+    ///
+    /// let port = mapped_setter(|port: &mut String, value: String| *port = value);
+    /// let port_number = port.compose_with_fallible_iso(parse_iso::<u16>());
+    ///
+    /// let mut config = String::new();
+    /// assert!(port_number.try_set(&mut config, 8080).is_ok());
+    /// assert_eq!(config, "8080");
+    /// ```
+    #[cfg(feature = "fallible-iso")]
+    pub fn compose_with_fallible_iso<A, FI2: FallibleIso<I, A>>(
+        self,
+        other: FI2,
+    ) -> FallibleSetterImpl<S, A, ReversedSetter<SETTER, FI2, S, I, A>> {
+        ReversedSetter::new(self.0, other).into()
+    }
+
+    /// Composes this `SetterImpl<S,I>` with a `Traversal<I,A>`, resulting in a new
+    /// `SetterImpl<S, A>` writing a value of type `A` into every focus of `other`.
+    ///
+    /// The value is written by modifying the focus of `self` in place with `other`, so `self`
+    /// must be able to read its focus, such as a setter wrapping a lens or a prism. If `self`
+    /// has no focus in the source, the write is dropped.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `A`: The target type of the composed optic.
+    /// - `T2`: The type of the traversal to compose with.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: The traversal to compose with.
+    ///
+    /// # Returns
+    ///
+    /// A new `SetterImpl` that represents the composition of `self` and `other`.
+    #[allow(clippy::type_complexity)]
+    pub fn compose_with_traversal<A: Clone, T2: Traversal<I, A>>(
+        self,
+        other: T2,
+    ) -> SetterImpl<S, A, NestedSetter<SETTER, OverAll<I, A, T2>, S, I, A>>
+    where
+        SETTER: HasModify<S, I>,
+    {
+        NestedSetter::new(self.0, over_all(other)).into()
+    }
+}
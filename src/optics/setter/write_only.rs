@@ -0,0 +1,58 @@
+use crate::{HasFallibleSetter, HasPath, HasSetter};
+use core::fmt::{Debug, Display, Formatter};
+
+mod private {
+    #[derive(Clone, Copy)]
+    pub struct WriteOnly<O>(pub(super) O);
+}
+
+/// A `WriteOnly` wraps an optic, exposing only its [`HasSetter`] and [`HasFallibleSetter`]
+/// operations.
+///
+/// It is the optic handed out by the `as_setter` and `as_fallible_setter` downgrades, so the
+/// read operations of the downgraded optic can not be reached through the resulting `SetterImpl`
+/// or `FallibleSetterImpl`.
+///
+/// # Type Parameters
+/// - `O`: The wrapped optic.
+pub type WriteOnly<O> = private::WriteOnly<O>;
+
+impl<O: Debug> Debug for WriteOnly<O> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("WriteOnly").field(&self.0).finish()
+    }
+}
+
+impl<O: Display> Display for WriteOnly<O> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl<O: HasPath> HasPath for WriteOnly<O> {
+    fn for_each_segment<'a>(&'a self, f: &mut dyn FnMut(&'a str)) {
+        self.0.for_each_segment(f);
+    }
+}
+
+impl<O> WriteOnly<O> {
+    pub(crate) fn new(optic: O) -> Self {
+        private::WriteOnly(optic)
+    }
+}
+
+impl<S, A, O: HasSetter<S, A>> HasSetter<S, A> for WriteOnly<O> {
+    #[inline]
+    fn set(&self, source: &mut S, value: A) {
+        self.0.set(source, value);
+    }
+}
+
+impl<S, A, O: HasFallibleSetter<S, A>> HasFallibleSetter<S, A> for WriteOnly<O> {
+    type SetterError = O::SetterError;
+
+    #[inline]
+    fn try_set(&self, source: &mut S, value: A) -> Result<(), Self::SetterError> {
+        self.0.try_set(source, value)
+    }
+}
@@ -0,0 +1,27 @@
+use crate::{HasTraversal, TraversalImpl};
+use alloc::boxed::Box;
+
+/// An object safe version of [`Traversal`](crate::Traversal), implemented by every traversal.
+///
+/// It can be used as a trait object, such as in a [`BoxedTraversal`], to store traversals of
+/// different types in the same collection.
+pub trait DynTraversal<S, A>: HasTraversal<S, A> {}
+
+impl<S, A, O: HasTraversal<S, A>> DynTraversal<S, A> for O {}
+
+/// A boxed traversal of any type, focusing on a value of type `A` in a source of type `S`.
+///
+/// Unlike the opaque traversals returned by the constructors of this crate, boxed traversals of
+/// different types have the same type, so they can be stored in collections or struct fields, and
+/// composed like any other traversal. They are created with [`TraversalImpl::boxed`].
+pub type BoxedTraversal<S, A> = TraversalImpl<S, A, Box<dyn DynTraversal<S, A> + Send + Sync>>;
+
+impl<S, A> HasTraversal<S, A> for Box<dyn DynTraversal<S, A> + Send + Sync> {
+    fn for_each(&self, source: &S, f: &mut dyn FnMut(A)) {
+        (**self).for_each(source, f);
+    }
+
+    fn over_all(&self, source: &mut S, f: &mut dyn FnMut(A) -> A) {
+        (**self).over_all(source, f);
+    }
+}
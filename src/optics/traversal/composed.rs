@@ -0,0 +1,139 @@
+use crate::HasPath;
+use crate::HasTraversal;
+use crate::optics::traversal::Traversal;
+use crate::optics::traversal::wrapper::TraversalImpl;
+use core::fmt::{Debug, Display, Formatter};
+use core::marker::PhantomData;
+
+mod private {
+    use crate::Traversal;
+    use core::marker::PhantomData;
+
+    pub struct ComposedTraversal<T1: Traversal<S, I>, T2: Traversal<I, A>, S, I, A> {
+        pub(super) optic1: T1,
+        pub(super) optic2: T2,
+        pub(super) _phantom: PhantomData<(S, I, A)>,
+    }
+}
+
+pub type ComposedTraversal<T1, T2, S, I, A> = private::ComposedTraversal<T1, T2, S, I, A>;
+
+impl<T1, T2, S, I, A> Clone for ComposedTraversal<T1, T2, S, I, A>
+where
+    T1: Traversal<S, I> + Clone,
+    T2: Traversal<I, A> + Clone,
+{
+    fn clone(&self) -> Self {
+        ComposedTraversal {
+            optic1: self.optic1.clone(),
+            optic2: self.optic2.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T1, T2, S, I, A> Copy for ComposedTraversal<T1, T2, S, I, A>
+where
+    T1: Traversal<S, I> + Copy,
+    T2: Traversal<I, A> + Copy,
+{
+}
+
+impl<T1, T2, S, I, A> Debug for ComposedTraversal<T1, T2, S, I, A>
+where
+    T1: Traversal<S, I> + Debug,
+    T2: Traversal<I, A> + Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ComposedTraversal")
+            .field("optic1", &self.optic1)
+            .field("optic2", &self.optic2)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T1, T2, S, I, A> Display for ComposedTraversal<T1, T2, S, I, A>
+where
+    T1: Traversal<S, I> + Display,
+    T2: Traversal<I, A> + Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}.{}", self.optic1, self.optic2)
+    }
+}
+
+impl<T1, T2, S, I, A> HasPath for ComposedTraversal<T1, T2, S, I, A>
+where
+    T1: Traversal<S, I> + HasPath,
+    T2: Traversal<I, A> + HasPath,
+{
+    fn for_each_segment<'a>(&'a self, f: &mut dyn FnMut(&'a str)) {
+        self.optic1.for_each_segment(f);
+        self.optic2.for_each_segment(f);
+    }
+}
+
+impl<T1, T2, S, I, A> ComposedTraversal<T1, T2, S, I, A>
+where
+    T1: Traversal<S, I>,
+    T2: Traversal<I, A>,
+{
+    pub(crate) fn new(optic1: T1, optic2: T2) -> Self {
+        ComposedTraversal {
+            optic1,
+            optic2,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<S, I, A, T1, T2> HasTraversal<S, A> for ComposedTraversal<T1, T2, S, I, A>
+where
+    T1: Traversal<S, I>,
+    T2: Traversal<I, A>,
+{
+    #[inline]
+    fn for_each(&self, source: &S, f: &mut dyn FnMut(A)) {
+        self.optic1
+            .for_each(source, &mut |i| self.optic2.for_each(&i, f));
+    }
+
+    #[inline]
+    fn over_all(&self, source: &mut S, f: &mut dyn FnMut(A) -> A) {
+        self.optic1.over_all(source, &mut |mut i| {
+            self.optic2.over_all(&mut i, f);
+            i
+        });
+    }
+}
+
+/// Creates a `Traversal<S,A>` combined from two optics <S, I>, <I, A> applied one after another.
+///
+/// This struct is automatically created by composing two existing optics, and is **not** intended
+/// to be directly constructed outside the crate. Instead, it is generated through composition of
+/// two optics via the corresponding `composable_with_XXX` methods, where the two optics can be of any
+/// valid optic type that results in a `Traversal`.
+///
+/// # Type Parameters
+/// - `S`: The source type of the first optic
+/// - `A`: The target type of the second optic
+/// - `I`: The intermediate type: the target type of the first optic and the source type of the second optic
+///
+/// # Arguments
+/// - `t1`: The first optic of type `Traversal<S, I>`
+/// - `t2`: The second optic of type `Traversal<I, A>`
+///
+/// This struct **should not** be manually constructed by users. Instead, it is created via
+/// composition of two optics using the appropriate `compose_with_XXX` methods on each optic impl.
+/// The `ComposedTraversal` structure is provided internally by the crate after you compose valid optics.
+///
+/// # See Also
+///
+/// - [`Traversal`] — the optic type that `ComposedTraversal` is based on
+#[must_use]
+pub fn new<S, A, I, T1: Traversal<S, I>, T2: Traversal<I, A>>(
+    t1: T1,
+    t2: T2,
+) -> TraversalImpl<S, A, ComposedTraversal<T1, T2, S, I, A>> {
+    ComposedTraversal::new(t1, t2).into()
+}
@@ -0,0 +1,103 @@
+use crate::HasPath;
+use crate::{FallibleSetter, HasFallibleSetter, Traversal};
+use core::fmt::{Debug, Display, Formatter};
+use core::marker::PhantomData;
+
+pub struct EachFallibleSetter<T: Traversal<S, I>, FS2: FallibleSetter<I, A>, S, I, A> {
+    optic1: T,
+    optic2: FS2,
+    _phantom: PhantomData<(S, I, A)>,
+}
+
+impl<T, FS2, S, I, A> Clone for EachFallibleSetter<T, FS2, S, I, A>
+where
+    T: Traversal<S, I> + Clone,
+    FS2: FallibleSetter<I, A> + Clone,
+{
+    fn clone(&self) -> Self {
+        EachFallibleSetter {
+            optic1: self.optic1.clone(),
+            optic2: self.optic2.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, FS2, S, I, A> Copy for EachFallibleSetter<T, FS2, S, I, A>
+where
+    T: Traversal<S, I> + Copy,
+    FS2: FallibleSetter<I, A> + Copy,
+{
+}
+
+impl<T, FS2, S, I, A> Debug for EachFallibleSetter<T, FS2, S, I, A>
+where
+    T: Traversal<S, I> + Debug,
+    FS2: FallibleSetter<I, A> + Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("EachFallibleSetter")
+            .field("optic1", &self.optic1)
+            .field("optic2", &self.optic2)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T, FS2, S, I, A> Display for EachFallibleSetter<T, FS2, S, I, A>
+where
+    T: Traversal<S, I> + Display,
+    FS2: FallibleSetter<I, A> + Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}.{}", self.optic1, self.optic2)
+    }
+}
+
+impl<T, FS2, S, I, A> HasPath for EachFallibleSetter<T, FS2, S, I, A>
+where
+    T: Traversal<S, I> + HasPath,
+    FS2: FallibleSetter<I, A> + HasPath,
+{
+    fn for_each_segment<'a>(&'a self, f: &mut dyn FnMut(&'a str)) {
+        self.optic1.for_each_segment(f);
+        self.optic2.for_each_segment(f);
+    }
+}
+
+impl<T, FS2, S, I, A: Clone> HasFallibleSetter<S, A> for EachFallibleSetter<T, FS2, S, I, A>
+where
+    T: Traversal<S, I>,
+    FS2: FallibleSetter<I, A>,
+{
+    type SetterError = FS2::SetterError;
+
+    fn try_set(&self, source: &mut S, value: A) -> Result<(), Self::SetterError> {
+        let mut result = Ok(());
+        self.optic1.over_all(source, &mut |mut i| {
+            if result.is_ok() {
+                result = self.optic2.try_set(&mut i, value.clone());
+            }
+            i
+        });
+        result
+    }
+}
+
+/// Adapts a `Traversal<S, I>` followed by a `FallibleSetter<I, A>` into a `FallibleSetter<S, A>`
+/// that sets the value on every focus of the traversal.
+///
+/// The first rejection is returned, and the foci following it are left unchanged. The foci
+/// preceding it have already been written to.
+///
+/// This is used internally to compose traversals with fallible setters.
+pub(crate) fn new<T, FS2, S, I, A>(t: T, fs2: FS2) -> EachFallibleSetter<T, FS2, S, I, A>
+where
+    T: Traversal<S, I>,
+    FS2: FallibleSetter<I, A>,
+{
+    EachFallibleSetter {
+        optic1: t,
+        optic2: fs2,
+        _phantom: PhantomData,
+    }
+}
@@ -0,0 +1,94 @@
+use crate::HasPath;
+use crate::{HasSetter, Setter, Traversal};
+use core::fmt::{Debug, Display, Formatter};
+use core::marker::PhantomData;
+
+pub struct EachSetter<T: Traversal<S, I>, SETTER2: Setter<I, A>, S, I, A> {
+    optic1: T,
+    optic2: SETTER2,
+    _phantom: PhantomData<(S, I, A)>,
+}
+
+impl<T, SETTER2, S, I, A> Clone for EachSetter<T, SETTER2, S, I, A>
+where
+    T: Traversal<S, I> + Clone,
+    SETTER2: Setter<I, A> + Clone,
+{
+    fn clone(&self) -> Self {
+        EachSetter {
+            optic1: self.optic1.clone(),
+            optic2: self.optic2.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, SETTER2, S, I, A> Copy for EachSetter<T, SETTER2, S, I, A>
+where
+    T: Traversal<S, I> + Copy,
+    SETTER2: Setter<I, A> + Copy,
+{
+}
+
+impl<T, SETTER2, S, I, A> Debug for EachSetter<T, SETTER2, S, I, A>
+where
+    T: Traversal<S, I> + Debug,
+    SETTER2: Setter<I, A> + Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("EachSetter")
+            .field("optic1", &self.optic1)
+            .field("optic2", &self.optic2)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T, SETTER2, S, I, A> Display for EachSetter<T, SETTER2, S, I, A>
+where
+    T: Traversal<S, I> + Display,
+    SETTER2: Setter<I, A> + Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}.{}", self.optic1, self.optic2)
+    }
+}
+
+impl<T, SETTER2, S, I, A> HasPath for EachSetter<T, SETTER2, S, I, A>
+where
+    T: Traversal<S, I> + HasPath,
+    SETTER2: Setter<I, A> + HasPath,
+{
+    fn for_each_segment<'a>(&'a self, f: &mut dyn FnMut(&'a str)) {
+        self.optic1.for_each_segment(f);
+        self.optic2.for_each_segment(f);
+    }
+}
+
+impl<T, SETTER2, S, I, A: Clone> HasSetter<S, A> for EachSetter<T, SETTER2, S, I, A>
+where
+    T: Traversal<S, I>,
+    SETTER2: Setter<I, A>,
+{
+    fn set(&self, source: &mut S, value: A) {
+        self.optic1.over_all(source, &mut |mut i| {
+            self.optic2.set(&mut i, value.clone());
+            i
+        });
+    }
+}
+
+/// Adapts a `Traversal<S, I>` followed by a `Setter<I, A>` into a `Setter<S, A>` that sets the
+/// value on every focus of the traversal.
+///
+/// This is used internally to compose traversals with setters.
+pub(crate) fn new<T, SETTER2, S, I, A>(t: T, s2: SETTER2) -> EachSetter<T, SETTER2, S, I, A>
+where
+    T: Traversal<S, I>,
+    SETTER2: Setter<I, A>,
+{
+    EachSetter {
+        optic1: t,
+        optic2: s2,
+        _phantom: PhantomData,
+    }
+}
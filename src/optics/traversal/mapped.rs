@@ -0,0 +1,122 @@
+use crate::HasTraversal;
+use crate::optics::traversal::wrapper::TraversalImpl;
+use core::fmt::{Debug, Formatter};
+use core::marker::PhantomData;
+
+mod private {
+    use core::marker::PhantomData;
+
+    pub struct MappedTraversal<S, A, EACH, OVER>
+    where
+        EACH: Fn(&S, &mut dyn FnMut(A)),
+        OVER: Fn(&mut S, &mut dyn FnMut(A) -> A),
+    {
+        pub(super) for_each_fn: EACH,
+        pub(super) over_fn: OVER,
+        pub(super) phantom: PhantomData<(S, A)>,
+    }
+}
+
+pub type MappedTraversal<S, A, EACH, OVER> = private::MappedTraversal<S, A, EACH, OVER>;
+
+impl<S, A, EACH, OVER> Clone for MappedTraversal<S, A, EACH, OVER>
+where
+    EACH: Fn(&S, &mut dyn FnMut(A)) + Clone,
+    OVER: Fn(&mut S, &mut dyn FnMut(A) -> A) + Clone,
+{
+    fn clone(&self) -> Self {
+        MappedTraversal {
+            for_each_fn: self.for_each_fn.clone(),
+            over_fn: self.over_fn.clone(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<S, A, EACH, OVER> Copy for MappedTraversal<S, A, EACH, OVER>
+where
+    EACH: Fn(&S, &mut dyn FnMut(A)) + Copy,
+    OVER: Fn(&mut S, &mut dyn FnMut(A) -> A) + Copy,
+{
+}
+
+impl<S, A, EACH, OVER> Debug for MappedTraversal<S, A, EACH, OVER>
+where
+    EACH: Fn(&S, &mut dyn FnMut(A)),
+    OVER: Fn(&mut S, &mut dyn FnMut(A) -> A),
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("MappedTraversal").finish_non_exhaustive()
+    }
+}
+
+impl<S, A, EACH, OVER> MappedTraversal<S, A, EACH, OVER>
+where
+    EACH: Fn(&S, &mut dyn FnMut(A)),
+    OVER: Fn(&mut S, &mut dyn FnMut(A) -> A),
+{
+    pub(crate) const fn new(for_each_fn: EACH, over_fn: OVER) -> Self {
+        MappedTraversal {
+            for_each_fn,
+            over_fn,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<S, A, EACH, OVER> HasTraversal<S, A> for MappedTraversal<S, A, EACH, OVER>
+where
+    EACH: Fn(&S, &mut dyn FnMut(A)),
+    OVER: Fn(&mut S, &mut dyn FnMut(A) -> A),
+{
+    fn for_each(&self, source: &S, f: &mut dyn FnMut(A)) {
+        (self.for_each_fn)(source, f);
+    }
+
+    fn over_all(&self, source: &mut S, f: &mut dyn FnMut(A) -> A) {
+        (self.over_fn)(source, f);
+    }
+}
+
+/// Creates a new `Traversal` with the provided visiting and updating functions.
+///
+/// # Type Parameters
+/// - `S`: The source type of the optic
+/// - `A`: The target type of the optic
+///
+/// # Arguments
+///
+/// - `for_each_fn` — A function that calls its callback with every focused value `A` in the source `S`.
+/// - `over_fn` — A function that replaces every focused value `A` in the source `S` with the result of its callback.
+///
+/// # Returns
+///
+/// A new `TraversalImpl` instance that can be used as a `Traversal<S, A>`.
+///
+/// # Examples
+///
+/// ```
+/// use optics::{mapped_traversal, HasGetAll, HasTraversal};
+///
+/// let each = mapped_traversal(
+///     |v: &Vec<u32>, f: &mut dyn FnMut(u32)| v.iter().copied().for_each(f),
+///     |v: &mut Vec<u32>, f: &mut dyn FnMut(u32) -> u32| v.iter_mut().for_each(|x| *x = f(*x)),
+/// );
+///
+/// let mut v = vec![1, 2, 3];
+///
+/// assert_eq!(each.get_all(&v), vec![1, 2, 3]);
+/// each.over_all(&mut v, &mut |x| x * 10);
+/// assert_eq!(v, vec![10, 20, 30]);
+/// ```
+#[must_use]
+pub const fn new<S, A, EACH, OVER>(
+    for_each_fn: EACH,
+    over_fn: OVER,
+) -> TraversalImpl<S, A, MappedTraversal<S, A, EACH, OVER>>
+where
+    EACH: Fn(&S, &mut dyn FnMut(A)),
+    OVER: Fn(&mut S, &mut dyn FnMut(A) -> A),
+{
+    TraversalImpl::new(MappedTraversal::new(for_each_fn, over_fn))
+}
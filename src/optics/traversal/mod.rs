@@ -1 +1,98 @@
-//TODO
\ No newline at end of file
+use crate::HasTraversal;
+
+#[cfg(feature = "alloc")]
+mod boxed;
+mod composed;
+mod each_fallible_setter;
+#[cfg(feature = "setter")]
+mod each_setter;
+mod mapped;
+mod over_all;
+#[cfg(feature = "alloc")]
+mod shared;
+#[cfg(any(feature = "lens", feature = "prism"))]
+mod single;
+mod wrapper;
+
+#[cfg(feature = "alloc")]
+pub use boxed::{BoxedTraversal, DynTraversal};
+pub(crate) use composed::ComposedTraversal;
+pub use composed::new as composed_traversal;
+pub(crate) use each_fallible_setter::EachFallibleSetter;
+pub(crate) use each_fallible_setter::new as each_fallible_setter;
+#[cfg(feature = "setter")]
+pub(crate) use each_setter::EachSetter;
+#[cfg(feature = "setter")]
+pub(crate) use each_setter::new as each_setter;
+pub use mapped::new as mapped_traversal;
+pub(crate) use over_all::OverAll;
+pub(crate) use over_all::new as over_all;
+#[cfg(feature = "alloc")]
+pub use shared::SharedTraversal;
+#[cfg(any(feature = "lens", feature = "prism"))]
+pub(crate) use single::SingleTraversal;
+#[cfg(any(feature = "lens", feature = "prism"))]
+pub(crate) use single::new as single_traversal;
+pub use wrapper::TraversalImpl;
+
+/// A `Traversal` is an optic that focuses on zero, one or many values inside a larger structure.
+///
+/// It provides:
+/// - `for_each` to visit every focused value of a larger type
+/// - `over_all` to replace every focused value of a larger type based on its current value
+///
+/// This is useful for working with collections, such as the elements of an array or a `Vec`,
+/// where the number of focused values is not known in advance.
+///
+/// Type Arguments
+///   - `S`: The data type the optic operates on
+///   - `A`: The data type the optic focuses on
+///
+/// # Note
+///
+/// This is a marker trait that is blanket implemented for all structs that satisfy the requirements.
+///
+/// # See Also
+/// - [`Prism`] — an optic that focuses on a potentially missing value, a traversal of at most one value
+/// - [`Lens`] — an optic that focuses on an always-present value, a traversal of exactly one value
+/// - [`Setter`] — an optic that can change its focused value
+pub trait Traversal<S, A>: HasTraversal<S, A> {}
+
+impl<S, A, T: HasTraversal<S, A>> Traversal<S, A> for T {}
+
+/// Creates a `Traversal` that focuses on the entire input.
+///
+/// It can be useful in cases where you need an identity optic within
+/// a composition chain, or as a trivial traversal implementation.
+///
+/// # Type Parameters
+///
+/// - `S`: The type of the input and output value. Must implement `Clone`.
+///
+/// # Returns
+///
+/// A `TraversalImpl` instance that implements `Traversal<S, S>`
+/// and always visits exactly the cloned input value.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{identity_traversal, HasGetAll, HasTraversal};
+///
+/// let traversal = identity_traversal::<u32>();
+/// let mut v = 42;
+/// assert_eq!(traversal.get_all(&v), vec![42]);
+/// traversal.over_all(&mut v, &mut |x| x + 1);
+/// assert_eq!(v, 43);
+/// ```
+///
+/// # See Also
+///
+/// - [`mapped_traversal`] for constructing custom `Traversal`s from arbitrary mapping functions.
+#[must_use]
+pub fn identity_traversal<S: Clone>() -> TraversalImpl<S, S, impl Traversal<S, S>> {
+    mapped_traversal(
+        |s: &S, f: &mut dyn FnMut(S)| f(s.clone()),
+        |s: &mut S, f: &mut dyn FnMut(S) -> S| *s = f(s.clone()),
+    )
+}
@@ -0,0 +1,69 @@
+use crate::HasPath;
+use crate::{HasSetter, Traversal};
+use core::fmt::{Debug, Display, Formatter};
+use core::marker::PhantomData;
+
+pub struct OverAll<S, A, T: Traversal<S, A>> {
+    optic: T,
+    _phantom: PhantomData<(S, A)>,
+}
+
+impl<S, A, T> Clone for OverAll<S, A, T>
+where
+    T: Traversal<S, A> + Clone,
+{
+    fn clone(&self) -> Self {
+        OverAll {
+            optic: self.optic.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<S, A, T> Copy for OverAll<S, A, T> where T: Traversal<S, A> + Copy {}
+
+impl<S, A, T> Debug for OverAll<S, A, T>
+where
+    T: Traversal<S, A> + Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("OverAll")
+            .field("optic", &self.optic)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<S, A, T> Display for OverAll<S, A, T>
+where
+    T: Traversal<S, A> + Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.optic, f)
+    }
+}
+
+impl<S, A, T> HasPath for OverAll<S, A, T>
+where
+    T: Traversal<S, A> + HasPath,
+{
+    fn for_each_segment<'a>(&'a self, f: &mut dyn FnMut(&'a str)) {
+        self.optic.for_each_segment(f);
+    }
+}
+
+impl<S, A: Clone, T: Traversal<S, A>> HasSetter<S, A> for OverAll<S, A, T> {
+    fn set(&self, source: &mut S, value: A) {
+        self.optic.over_all(source, &mut |_| value.clone());
+    }
+}
+
+/// Adapts a `Traversal<S, A>` into a `Setter<S, A>` that writes the value into every focus of the
+/// traversal.
+///
+/// This is used internally to compose setters with traversals.
+pub(crate) fn new<S, A, T: Traversal<S, A>>(optic: T) -> OverAll<S, A, T> {
+    OverAll {
+        optic,
+        _phantom: PhantomData,
+    }
+}
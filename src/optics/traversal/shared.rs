@@ -0,0 +1,20 @@
+use crate::{DynTraversal, HasTraversal, TraversalImpl};
+use alloc::sync::Arc;
+
+/// A shared traversal of any type, focusing on a value of type `A` in a source of type `S`.
+///
+/// Like [boxed traversals](crate::BoxedTraversal), shared traversals of different types have the
+/// same type, but they are reference counted, so they can be cloned cheaply, stored in registries
+/// and sent to other threads. They are created with
+/// [`TraversalImpl::shared`](crate::TraversalImpl::shared).
+pub type SharedTraversal<S, A> = TraversalImpl<S, A, Arc<dyn DynTraversal<S, A> + Send + Sync>>;
+
+impl<S, A> HasTraversal<S, A> for Arc<dyn DynTraversal<S, A> + Send + Sync> {
+    fn for_each(&self, source: &S, f: &mut dyn FnMut(A)) {
+        (**self).for_each(source, f);
+    }
+
+    fn over_all(&self, source: &mut S, f: &mut dyn FnMut(A) -> A) {
+        (**self).over_all(source, f);
+    }
+}
@@ -0,0 +1,76 @@
+use crate::HasPath;
+use crate::{HasTraversal, Prism};
+use core::fmt::{Debug, Display, Formatter};
+use core::marker::PhantomData;
+
+pub struct SingleTraversal<S, A, P: Prism<S, A>> {
+    optic: P,
+    _phantom: PhantomData<(S, A)>,
+}
+
+impl<S, A, P> Clone for SingleTraversal<S, A, P>
+where
+    P: Prism<S, A> + Clone,
+{
+    fn clone(&self) -> Self {
+        SingleTraversal {
+            optic: self.optic.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<S, A, P> Copy for SingleTraversal<S, A, P> where P: Prism<S, A> + Copy {}
+
+impl<S, A, P> Debug for SingleTraversal<S, A, P>
+where
+    P: Prism<S, A> + Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SingleTraversal")
+            .field("optic", &self.optic)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<S, A, P> Display for SingleTraversal<S, A, P>
+where
+    P: Prism<S, A> + Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.optic, f)
+    }
+}
+
+impl<S, A, P> HasPath for SingleTraversal<S, A, P>
+where
+    P: Prism<S, A> + HasPath,
+{
+    fn for_each_segment<'a>(&'a self, f: &mut dyn FnMut(&'a str)) {
+        self.optic.for_each_segment(f);
+    }
+}
+
+impl<S, A, P: Prism<S, A>> HasTraversal<S, A> for SingleTraversal<S, A, P> {
+    fn for_each(&self, source: &S, f: &mut dyn FnMut(A)) {
+        if let Ok(a) = self.optic.try_get(source) {
+            f(a);
+        }
+    }
+
+    fn over_all(&self, source: &mut S, f: &mut dyn FnMut(A) -> A) {
+        if let Ok(a) = self.optic.try_get(source) {
+            self.optic.set(source, f(a));
+        }
+    }
+}
+
+/// Adapts any optic that can both read and write its focus into a `Traversal` of at most one value.
+///
+/// This is used internally to compose prisms, lenses, isos and fallible isos with traversals.
+pub(crate) fn new<S, A, P: Prism<S, A>>(optic: P) -> SingleTraversal<S, A, P> {
+    SingleTraversal {
+        optic,
+        _phantom: PhantomData,
+    }
+}
@@ -0,0 +1,383 @@
+#[cfg(feature = "fallible-iso")]
+use crate::FallibleIso;
+use crate::HasPath;
+#[cfg(feature = "iso")]
+use crate::Iso;
+#[cfg(feature = "lens")]
+use crate::Lens;
+#[cfg(feature = "prism")]
+use crate::Prism;
+use crate::named::Named;
+use crate::optics::traversal::{ComposedTraversal, EachFallibleSetter, each_fallible_setter};
+#[cfg(feature = "setter")]
+use crate::optics::traversal::{EachSetter, each_setter};
+#[cfg(any(feature = "lens", feature = "prism"))]
+use crate::optics::traversal::{SingleTraversal, single_traversal};
+#[cfg(feature = "tracing")]
+use crate::traced::Traced;
+use crate::{FallibleSetter, FallibleSetterImpl, HasTraversal, Traversal, composed_traversal};
+#[cfg(feature = "setter")]
+use crate::{Setter, SetterImpl};
+use core::fmt::{Debug, Display, Formatter};
+use core::marker::PhantomData;
+#[cfg(feature = "alloc")]
+use {
+    crate::optics::traversal::{BoxedTraversal, DynTraversal, SharedTraversal},
+    alloc::boxed::Box,
+    alloc::string::{String, ToString},
+    alloc::sync::Arc,
+    alloc::vec::Vec,
+};
+
+/// A wrapper of the [`Traversal`] optic implementations, encapsulating a visiting and an updating
+/// function.
+///
+/// `TraversalImpl` provides a way to define traversals - optics that can visit and change any number
+/// of values of type `A` inside a source of type `S`.
+/// They are particularly useful in scenarios where you need to focus on the elements of a collection.
+///
+/// # Note
+///
+/// This struct is not intended to be created by users directly, but it implements a From<`Traversal`<S,A>> so
+/// that implementors of new optic types can wrap their concrete implementation of a `Traversal` optic.
+///
+/// # Type Parameters
+///
+/// - `S`: The source type from which the values are to be retrieved.
+/// - `A`: The target type of the values to be retrieved.
+///
+/// # See Also
+///
+/// - [`Traversal`] trait for defining custom traversals.
+/// - [`mapped_traversal`] function for creating `TraversalImpl` instances from mapping functions.
+pub struct TraversalImpl<S, A, T: Traversal<S, A>>(pub T, PhantomData<(S, A)>);
+
+impl<S, A, T: Traversal<S, A>> TraversalImpl<S, A, T> {
+    pub(crate) const fn new(t: T) -> Self {
+        //TODO: Verify not to nest an Impl inside an Impl - currently seems to be impossible at compile time.
+        TraversalImpl(t, PhantomData)
+    }
+}
+
+impl<S, A, T: Traversal<S, A>> From<T> for TraversalImpl<S, A, T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<S, A, T: Traversal<S, A> + Clone> Clone for TraversalImpl<S, A, T> {
+    fn clone(&self) -> Self {
+        Self::new(self.0.clone())
+    }
+}
+
+impl<S, A, T: Traversal<S, A> + Copy> Copy for TraversalImpl<S, A, T> {}
+
+impl<S, A, T: Traversal<S, A> + Debug> Debug for TraversalImpl<S, A, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("TraversalImpl").field(&self.0).finish()
+    }
+}
+
+impl<S, A, T: Traversal<S, A> + Display> Display for TraversalImpl<S, A, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl<S, A, T: Traversal<S, A> + HasPath> HasPath for TraversalImpl<S, A, T> {
+    fn for_each_segment<'a>(&'a self, f: &mut dyn FnMut(&'a str)) {
+        self.0.for_each_segment(f);
+    }
+}
+
+impl<S, A, T: Traversal<S, A>> TraversalImpl<S, A, T> {
+    /// Labels this optic with a static `name`, used by its [`Display`] implementation.
+    ///
+    /// Composing named optics results in an optic displaying the names of its parts joined with a
+    /// `.`, which helps to identify a misbehaving optic in logs and error messages.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The name of the optic.
+    ///
+    /// # Returns
+    ///
+    /// A new `TraversalImpl` behaving exactly like `self`, displayed as `name`.
+    #[must_use]
+    pub fn named(self, name: &'static str) -> TraversalImpl<S, A, Named<T>> {
+        Named::new(self.0, name).into()
+    }
+
+    /// Wraps this optic to emit a `tracing` event for every operation going through it, with
+    /// `name` identifying the optic, whether the operation succeeded and the time it took.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The name of the optic in the events, such as `"config.port"`.
+    ///
+    /// # Returns
+    ///
+    /// A new `TraversalImpl` behaving exactly like `self`, tracing its operations.
+    #[cfg(feature = "tracing")]
+    #[must_use]
+    pub fn traced(self, name: &'static str) -> TraversalImpl<S, A, Traced<T>> {
+        Traced::new(self.0, name).into()
+    }
+
+    /// Returns the name of the optic, the names of the optics it is composed of joined with a `.`.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn name(&self) -> String
+    where
+        T: Display,
+    {
+        self.to_string()
+    }
+
+    /// Returns the names of the fields and variants this optic focuses through, from the outermost
+    /// one, such as the field names of the [`field_lens!`](crate::field_lens) lenses it is
+    /// composed of.
+    #[cfg(feature = "alloc")]
+    pub fn path(&self) -> impl Iterator<Item = &str>
+    where
+        T: HasPath,
+    {
+        let mut path = Vec::new();
+        self.0.for_each_segment(&mut |segment| path.push(segment));
+        path.into_iter()
+    }
+
+    /// Boxes this traversal, erasing its type, so it can be stored alongside traversals of other
+    /// types.
+    ///
+    /// # Returns
+    ///
+    /// A [`BoxedTraversal`] behaving exactly like `self`.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn boxed(self) -> BoxedTraversal<S, A>
+    where
+        T: Send + Sync + 'static,
+    {
+        let optic: Box<dyn DynTraversal<S, A> + Send + Sync> = Box::new(self.0);
+        optic.into()
+    }
+
+    /// Wraps this traversal in an [`Arc`], erasing its type, so it can be cloned cheaply and shared
+    /// between threads.
+    ///
+    /// # Returns
+    ///
+    /// A [`SharedTraversal`] behaving exactly like `self`.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn shared(self) -> SharedTraversal<S, A>
+    where
+        T: Send + Sync + 'static,
+    {
+        let optic: Arc<dyn DynTraversal<S, A> + Send + Sync> = Arc::new(self.0);
+        optic.into()
+    }
+}
+
+impl<S, A, T: Traversal<S, A>> HasTraversal<S, A> for TraversalImpl<S, A, T> {
+    #[inline]
+    fn for_each(&self, source: &S, f: &mut dyn FnMut(A)) {
+        self.0.for_each(source, f);
+    }
+
+    #[inline]
+    fn over_all(&self, source: &mut S, f: &mut dyn FnMut(A) -> A) {
+        self.0.over_all(source, f);
+    }
+}
+
+impl<S, I, T1: Traversal<S, I>> TraversalImpl<S, I, T1> {
+    /// Composes this `TraversalImpl<S,I>` with a `Setter<I,A>`, resulting in a new `Setter<S, A>`
+    /// that focuses through both optics sequentially.
+    ///
+    /// The resulting `SetterImpl` will set the value through `other` on every value focused on by
+    /// `self`.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `A`: The target type of the composed optic. Must implement `Clone`.
+    /// - `S2`: The type of the setter to compose with.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: The setter to compose with.
+    ///
+    /// # Returns
+    ///
+    /// A new `SetterImpl` that represents the composition of `self` and `other`.
+    ///
+    #[cfg(feature = "setter")]
+    pub fn compose_with_setter<A: Clone, S2: Setter<I, A>>(
+        self,
+        other: S2,
+    ) -> SetterImpl<S, A, EachSetter<T1, S2, S, I, A>> {
+        each_setter(self.0, other).into()
+    }
+
+    /// Composes this `TraversalImpl<S,I>` with a `FallibleSetter<I,A>`, resulting in a new `FallibleSetter<S, A>`
+    /// that focuses through both optics sequentially.
+    ///
+    /// The resulting `FallibleSetterImpl` will set the value through `other` on every value focused
+    /// on by `self`, failing with the first rejection. The values following a rejected one are left
+    /// unchanged, but the ones preceding it have already been written to.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `A`: The target type of the composed optic. Must implement `Clone`.
+    /// - `FS2`: The type of the fallible setter to compose with.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: The fallible setter to compose with.
+    ///
+    /// # Returns
+    ///
+    /// A new `FallibleSetterImpl` that represents the composition of `self` and `other`.
+    ///
+    pub fn compose_with_fallible_setter<A: Clone, FS2: FallibleSetter<I, A>>(
+        self,
+        other: FS2,
+    ) -> FallibleSetterImpl<S, A, EachFallibleSetter<T1, FS2, S, I, A>> {
+        each_fallible_setter(self.0, other).into()
+    }
+
+    /// Composes this `TraversalImpl<S,I>` with a `Prism<I,A>`, resulting in a new `TraversalImpl<S, A>`
+    /// that focuses through both optics sequentially.
+    ///
+    /// The resulting `TraversalImpl` will visit every value focused on by `self`, for which the
+    /// prism is able to focus.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `A`: The target type of the composed traversal.
+    /// - `P2`: The type of the prism to compose with.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: The prism to compose with.
+    ///
+    /// # Returns
+    ///
+    /// A new `TraversalImpl` that represents the composition of `self` and `other`.
+    #[cfg(feature = "prism")]
+    #[allow(clippy::type_complexity)]
+    pub fn compose_with_prism<A, P2: Prism<I, A>>(
+        self,
+        other: P2,
+    ) -> TraversalImpl<S, A, ComposedTraversal<T1, SingleTraversal<I, A, P2>, S, I, A>> {
+        composed_traversal(self.0, single_traversal(other))
+    }
+
+    /// Composes this `TraversalImpl<S,I>` with a `Lens<I,A>`, resulting in a new `TraversalImpl<S, A>`
+    /// that focuses through both optics sequentially.
+    ///
+    /// The resulting `TraversalImpl` will visit the value focused on by the lens in every value
+    /// focused on by `self`.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `A`: The target type of the composed traversal.
+    /// - `L2`: The type of the lens to compose with.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: The lens to compose with.
+    ///
+    /// # Returns
+    ///
+    /// A new `TraversalImpl` that represents the composition of `self` and `other`.
+    #[cfg(feature = "lens")]
+    #[allow(clippy::type_complexity)]
+    pub fn compose_with_lens<A, L2: Lens<I, A>>(
+        self,
+        other: L2,
+    ) -> TraversalImpl<S, A, ComposedTraversal<T1, SingleTraversal<I, A, L2>, S, I, A>> {
+        composed_traversal(self.0, single_traversal(other))
+    }
+
+    /// Composes this `TraversalImpl<S,I>` with an `Iso<I,A>`, resulting in a new `TraversalImpl<S, A>`
+    /// that focuses through both optics sequentially.
+    ///
+    /// The resulting `TraversalImpl` will visit every value focused on by `self`, converted
+    /// through the iso.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `A`: The target type of the composed traversal.
+    /// - `ISO2`: The type of the iso to compose with.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: The iso to compose with.
+    ///
+    /// # Returns
+    ///
+    /// A new `TraversalImpl` that represents the composition of `self` and `other`.
+    #[cfg(feature = "iso")]
+    #[allow(clippy::type_complexity)]
+    pub fn compose_with_iso<A, ISO2: Iso<I, A>>(
+        self,
+        other: ISO2,
+    ) -> TraversalImpl<S, A, ComposedTraversal<T1, SingleTraversal<I, A, ISO2>, S, I, A>> {
+        composed_traversal(self.0, single_traversal(other))
+    }
+
+    /// Composes this `TraversalImpl<S,I>` with a `FallibleIso<I,A>`, resulting in a new `TraversalImpl<S, A>`
+    /// that focuses through both optics sequentially.
+    ///
+    /// The resulting `TraversalImpl` will visit every value focused on by `self`, for which the
+    /// fallible iso is able to convert it.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `A`: The target type of the composed traversal.
+    /// - `FI2`: The type of the fallible iso to compose with.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: The fallible iso to compose with.
+    ///
+    /// # Returns
+    ///
+    /// A new `TraversalImpl` that represents the composition of `self` and `other`.
+    #[cfg(feature = "fallible-iso")]
+    #[allow(clippy::type_complexity)]
+    pub fn compose_with_fallible_iso<A, FI2: FallibleIso<I, A>>(
+        self,
+        other: FI2,
+    ) -> TraversalImpl<S, A, ComposedTraversal<T1, SingleTraversal<I, A, FI2>, S, I, A>> {
+        composed_traversal(self.0, single_traversal(other))
+    }
+
+    /// Composes this `TraversalImpl<S,I>` with another `Traversal<I,A>`, resulting in a new
+    /// `TraversalImpl<S, A>` that focuses through both traversals sequentially.
+    ///
+    /// The resulting `TraversalImpl` will visit every value focused on by `other` in every value
+    /// focused on by `self`.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `A`: The target type of the composed traversal.
+    /// - `T2`: The type of the traversal to compose with.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: The traversal to compose with.
+    ///
+    /// # Returns
+    ///
+    /// A new `TraversalImpl` that represents the composition of `self` and `other`.
+    pub fn compose_with_traversal<A, T2: Traversal<I, A>>(
+        self,
+        other: T2,
+    ) -> TraversalImpl<S, A, ComposedTraversal<T1, T2, S, I, A>> {
+        composed_traversal(self.0, other)
+    }
+}
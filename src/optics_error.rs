@@ -0,0 +1,107 @@
+use core::convert::Infallible;
+use core::fmt::{Display, Formatter};
+
+/// A general purpose error for optics, for when a dedicated error type is not worth the trouble.
+///
+/// The prisms created by [`enum_prism!`](crate::enum_prism) fail with
+/// [`OpticsError::VariantMismatch`], and the errors of the optics provided by this crate convert
+/// into the variant describing them best, so compositions of these optics can unify their errors
+/// into an `OpticsError`. Parse errors of the standard library convert into
+/// [`OpticsError::ParseFailed`], and `()` into [`OpticsError::NoFocus`], so hand-written optics
+/// can use it as well.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{HasGetter, OpticsError, mapped_fallible_iso, option};
+///
+/// let parse = mapped_fallible_iso(
+///     |s: &String| s.parse::<u16>(),
+///     |port: &u16| Ok::<_, OpticsError>(port.to_string()),
+/// );
+/// let port = option::some().compose_with_fallible_iso::<OpticsError, _, _>(parse);
+///
+/// assert_eq!(port.try_get(&Some("8080".to_string())), Ok(8080));
+/// assert_eq!(port.try_get(&None), Err(OpticsError::NoFocus));
+/// assert_eq!(port.try_get(&Some("http".to_string())), Err(OpticsError::ParseFailed));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum OpticsError {
+    /// The value the optic focuses on is not present.
+    NoFocus,
+    /// The source is a different variant of an enum than the one the optic focuses on.
+    VariantMismatch,
+    /// The optic focuses on an index that is out of the bounds of a collection.
+    IndexOutOfBounds,
+    /// The value could not be parsed.
+    ParseFailed,
+    /// The value is out of the range the optic accepts.
+    OutOfRange,
+}
+
+impl Display for OpticsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            OpticsError::NoFocus => write!(f, "the focus is not present"),
+            OpticsError::VariantMismatch => write!(f, "the value is of a different variant"),
+            OpticsError::IndexOutOfBounds => write!(f, "the index is out of bounds"),
+            OpticsError::ParseFailed => write!(f, "the value could not be parsed"),
+            OpticsError::OutOfRange => write!(f, "the value is out of range"),
+        }
+    }
+}
+
+impl core::error::Error for OpticsError {}
+
+impl From<Infallible> for OpticsError {
+    fn from(e: Infallible) -> Self {
+        match e {}
+    }
+}
+
+impl From<()> for OpticsError {
+    fn from((): ()) -> Self {
+        OpticsError::NoFocus
+    }
+}
+
+impl From<OpticsError> for () {
+    fn from(_: OpticsError) -> Self {}
+}
+
+impl From<core::num::ParseIntError> for OpticsError {
+    fn from(_: core::num::ParseIntError) -> Self {
+        OpticsError::ParseFailed
+    }
+}
+
+impl From<core::num::ParseFloatError> for OpticsError {
+    fn from(_: core::num::ParseFloatError) -> Self {
+        OpticsError::ParseFailed
+    }
+}
+
+impl From<core::str::ParseBoolError> for OpticsError {
+    fn from(_: core::str::ParseBoolError) -> Self {
+        OpticsError::ParseFailed
+    }
+}
+
+impl From<core::char::ParseCharError> for OpticsError {
+    fn from(_: core::char::ParseCharError) -> Self {
+        OpticsError::ParseFailed
+    }
+}
+
+impl From<core::net::AddrParseError> for OpticsError {
+    fn from(_: core::net::AddrParseError) -> Self {
+        OpticsError::ParseFailed
+    }
+}
+
+impl From<core::num::TryFromIntError> for OpticsError {
+    fn from(_: core::num::TryFromIntError) -> Self {
+        OpticsError::OutOfRange
+    }
+}
@@ -0,0 +1,332 @@
+#[cfg(feature = "fallible-iso")]
+use crate::FallibleIso;
+use crate::{ErasedError, HasFallibleSetter, HasPath, HasSetter};
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::{Debug, Display, Formatter};
+use core::str::FromStr;
+
+/// A list of writes to a value, each identifying the optic to write through by its path, and
+/// carrying the value to write in its string form.
+///
+/// A patch is applied to a value with [`apply`](Patch::apply), resolving the optics of its entries
+/// in a [`PatchRegistry`]. Since entries are plain strings, patches can be built from command line
+/// arguments, environment variables or messages of another process.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{Patch, PatchRegistry, field_lens};
+///
+/// struct Server {
+///     host: String,
+///     port: u16,
+/// }
+///
+/// let mut registry = PatchRegistry::new();
+/// registry
+///     .register(field_lens!(Server, host))
+///     .register(field_lens!(Server, port));
+///
+/// let mut patch = Patch::new();
+/// patch.set("port", "9090").set("host", "example.com");
+///
+/// let mut server = Server { host: "localhost".to_string(), port: 8080 };
+/// patch.apply(&mut server, &registry).unwrap();
+/// assert_eq!((server.host.as_str(), server.port), ("example.com", 9090));
+///
+/// let errors = Patch::new().set("port", "high").set("user", "root").apply(&mut server, &registry);
+/// let errors = errors.unwrap_err();
+/// assert_eq!(errors[0].to_string(), "port: invalid digit found in string");
+/// assert_eq!(errors[1].to_string(), "user: no optic is registered with this path");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Patch {
+    entries: Vec<(String, String)>,
+}
+
+impl Patch {
+    /// Creates a new, empty patch.
+    #[must_use]
+    pub fn new() -> Self {
+        Patch {
+            entries: Vec::new(),
+        }
+    }
+
+    /// Appends an entry writing `value` through the optic registered with the path `id`.
+    ///
+    /// # Parameters
+    ///
+    /// - `id`: The path of the optic to write through.
+    /// - `value`: The value to write, in the string form parsed by the registered optic.
+    pub fn set(&mut self, id: impl Into<String>, value: impl Into<String>) -> &mut Self {
+        self.entries.push((id.into(), value.into()));
+        self
+    }
+
+    /// Returns the entries of this patch as pairs of optic paths and values, in order.
+    pub fn entries(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.entries
+            .iter()
+            .map(|(id, value)| (id.as_str(), value.as_str()))
+    }
+
+    /// Returns the number of entries in this patch.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if this patch has no entries.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Writes the entries of this patch in order to `target`, through the optics registered in
+    /// `registry`.
+    ///
+    /// Entries that fail don't stop the patch from being applied, the remaining entries are still
+    /// written.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PatchError`] for each entry that could not be written, in order.
+    pub fn apply<S>(
+        &self,
+        target: &mut S,
+        registry: &PatchRegistry<'_, S>,
+    ) -> Result<(), Vec<PatchError>> {
+        let errors: Vec<PatchError> = self
+            .entries
+            .iter()
+            .filter_map(|(id, value)| registry.write(target, id, value).err())
+            .collect();
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+impl<I: Into<String>, V: Into<String>> FromIterator<(I, V)> for Patch {
+    fn from_iter<T: IntoIterator<Item = (I, V)>>(iter: T) -> Self {
+        Patch {
+            entries: iter
+                .into_iter()
+                .map(|(id, value)| (id.into(), value.into()))
+                .collect(),
+        }
+    }
+}
+
+/// A set of optics into values of type `S`, identified by their paths, that the entries of a
+/// [`Patch`] are written through.
+///
+/// The optics are registered with [`register`](PatchRegistry::register) and
+/// [`register_fallible`](PatchRegistry::register_fallible), and have to implement [`HasPath`].
+/// Optics created by [`field_lens!`](crate::field_lens) and compositions of them have a path, other
+/// optics can be given one with their `named` method. The values of the entries are converted to
+/// the focus of the optics with [`FromStr`], or through a fallible iso given to
+/// [`register_with`](PatchRegistry::register_with).
+pub struct PatchRegistry<'a, S> {
+    writers: BTreeMap<String, Writer<'a, S>>,
+}
+
+type Writer<'a, S> = Box<dyn Fn(&mut S, &str) -> Result<(), ErasedError> + 'a>;
+
+impl<'a, S> PatchRegistry<'a, S> {
+    /// Creates a new, empty registry.
+    #[must_use]
+    pub fn new() -> Self {
+        PatchRegistry {
+            writers: BTreeMap::new(),
+        }
+    }
+
+    /// Registers `optic` under its path, replacing any optic registered with the same path.
+    ///
+    /// # Parameters
+    ///
+    /// - `optic`: The optic to write the entries with its path through.
+    pub fn register<A, O>(&mut self, optic: O) -> &mut Self
+    where
+        O: HasSetter<S, A> + HasPath + 'a,
+        A: FromStr,
+        A::Err: Into<ErasedError>,
+    {
+        self.register_as(optic.joined_path(), move |target, value| {
+            optic.set(target, value);
+            Ok(())
+        })
+    }
+
+    /// Registers `optic`, which may reject the values written through it, under its path,
+    /// replacing any optic registered with the same path.
+    ///
+    /// # Parameters
+    ///
+    /// - `optic`: The fallible setter, such as a validated optic, to write the entries with its
+    ///   path through.
+    pub fn register_fallible<A, O>(&mut self, optic: O) -> &mut Self
+    where
+        O: HasFallibleSetter<S, A> + HasPath + 'a,
+        O::SetterError: Into<ErasedError>,
+        A: FromStr,
+        A::Err: Into<ErasedError>,
+    {
+        self.register_as(optic.joined_path(), move |target, value| {
+            optic.try_set(target, value).map_err(Into::into)
+        })
+    }
+
+    /// Registers `optic` under its path, converting the values of the entries to its focus through
+    /// `iso` instead of [`FromStr`], replacing any optic registered with the same path.
+    ///
+    /// This allows writing foci that have no `FromStr` implementation, or whose string form in the
+    /// entries differs from the one of `FromStr`, such as durations given in milliseconds.
+    ///
+    /// # Parameters
+    ///
+    /// - `optic`: The optic to write the entries with its path through.
+    /// - `iso`: The fallible iso converting the values of the entries to the focus of `optic`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use optics::{Patch, PatchRegistry, field_lens, mapped_fallible_iso};
+    /// use core::num::ParseIntError;
+    /// use std::time::Duration;
+    ///
+    /// struct Server {
+    ///     timeout: Duration,
+    /// }
+    ///
+    /// let millis = mapped_fallible_iso(
+    ///     |s: &String| s.parse().map(Duration::from_millis),
+    ///     |d: &Duration| Ok::<_, ParseIntError>(d.as_millis().to_string()),
+    /// );
+    ///
+    /// let mut registry = PatchRegistry::new();
+    /// registry.register_with(field_lens!(Server, timeout), millis);
+    ///
+    /// let mut server = Server { timeout: Duration::from_secs(1) };
+    /// Patch::new().set("timeout", "250").apply(&mut server, &registry).unwrap();
+    /// assert_eq!(server.timeout, Duration::from_millis(250));
+    /// ```
+    #[cfg(feature = "fallible-iso")]
+    pub fn register_with<A, O, I>(&mut self, optic: O, iso: I) -> &mut Self
+    where
+        O: HasSetter<S, A> + HasPath + 'a,
+        I: FallibleIso<String, A> + 'a,
+        I::GetterError: Into<ErasedError>,
+    {
+        self.writers.insert(
+            optic.joined_path(),
+            Box::new(move |target, value| {
+                optic.set(target, iso.try_get(&value.to_string()).map_err(Into::into)?);
+                Ok(())
+            }),
+        );
+        self
+    }
+
+    /// Registers `set` under `id`, parsing the values of the entries with that id before writing
+    /// them through it.
+    pub(crate) fn register_as<A>(
+        &mut self,
+        id: String,
+        set: impl Fn(&mut S, A) -> Result<(), ErasedError> + 'a,
+    ) -> &mut Self
+    where
+        A: FromStr,
+        A::Err: Into<ErasedError>,
+    {
+        self.writers.insert(
+            id,
+            Box::new(move |target, value| set(target, value.parse().map_err(Into::into)?)),
+        );
+        self
+    }
+
+    /// Returns the paths of the registered optics, in lexicographic order.
+    pub fn paths(&self) -> impl Iterator<Item = &str> {
+        self.writers.keys().map(String::as_str)
+    }
+
+    fn write(&self, target: &mut S, id: &str, value: &str) -> Result<(), PatchError> {
+        let writer = self
+            .writers
+            .get(id)
+            .ok_or_else(|| PatchError::UnknownPath { id: id.to_string() })?;
+
+        writer(target, value).map_err(|error| PatchError::Rejected {
+            id: id.to_string(),
+            error,
+        })
+    }
+}
+
+impl<S> Default for PatchRegistry<'_, S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S> Debug for PatchRegistry<'_, S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.writers.keys()).finish()
+    }
+}
+
+/// The error of an entry of a [`Patch`] that could not be written.
+#[derive(Debug)]
+pub enum PatchError {
+    /// No optic is registered with the path of the entry.
+    UnknownPath {
+        /// The path of the entry.
+        id: String,
+    },
+    /// The value of the entry could not be parsed, or was rejected by the optic.
+    Rejected {
+        /// The path of the entry.
+        id: String,
+        /// The error of parsing or writing the value.
+        error: ErasedError,
+    },
+}
+
+impl PatchError {
+    /// Returns the path of the entry that could not be written.
+    #[must_use]
+    pub fn id(&self) -> &str {
+        match self {
+            PatchError::UnknownPath { id } | PatchError::Rejected { id, .. } => id,
+        }
+    }
+}
+
+impl Display for PatchError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            PatchError::UnknownPath { id } => {
+                write!(f, "{id}: no optic is registered with this path")
+            }
+            PatchError::Rejected { id, error } => write!(f, "{id}: {error}"),
+        }
+    }
+}
+
+impl core::error::Error for PatchError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            PatchError::UnknownPath { .. } => None,
+            PatchError::Rejected { error, .. } => Some(&**error),
+        }
+    }
+}
@@ -0,0 +1,549 @@
+#[cfg(all(feature = "getter", feature = "lens"))]
+use crate::SharedGetter;
+#[cfg(any(feature = "lens", feature = "prism"))]
+use crate::SharedPartialGetter;
+#[cfg(all(feature = "setter", any(feature = "lens", feature = "prism")))]
+use crate::SharedSetter;
+#[cfg(feature = "iso")]
+use crate::{DynIso, Iso, IsoImpl, SharedIso};
+#[cfg(feature = "lens")]
+use crate::{DynLens, Lens, LensImpl, SharedLens};
+#[cfg(feature = "prism")]
+use crate::{DynPrism, Prism, PrismImpl, SharedPrism};
+use crate::{ErasedError, HasModify};
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+#[cfg(any(feature = "lens", feature = "prism"))]
+use alloc::string::ToString;
+use alloc::sync::Arc;
+use core::any::{Any, TypeId, type_name};
+use core::fmt::{Debug, Display, Formatter};
+use core::marker::PhantomData;
+
+/// A set of optics into values of type `S`, registered under names and looked up at runtime,
+/// with the type of their focus checked on lookup.
+///
+/// Isos, lenses and prisms are registered with [`register_iso`](OpticRegistry::register_iso),
+/// [`register_lens`](OpticRegistry::register_lens) and
+/// [`register_prism`](OpticRegistry::register_prism). The optics are stored as
+/// [shared](crate::SharedLens) optics, so looking them up clones a reference counted pointer, and
+/// the errors of prisms are boxed into an [`ErasedError`], so looking them up only requires naming
+/// the type of their focus.
+///
+/// An optic can be looked up as its own kind, or as any weaker kind it can be downgraded to, the
+/// same way the `as_*` methods of the optics downgrade them:
+///
+/// | registered | [`iso`](OpticRegistry::iso) | [`lens`](OpticRegistry::lens) | [`prism`](OpticRegistry::prism) | [`getter`](OpticRegistry::getter) | [`partial_getter`](OpticRegistry::partial_getter) | [`setter`](OpticRegistry::setter) |
+/// |-------|---|---|---|---|---|---|
+/// | iso   | ✓ | ✓ | ✓ | ✓ | ✓ | ✓ |
+/// | lens  |   | ✓ | ✓ | ✓ | ✓ | ✓ |
+/// | prism |   |   | ✓ |   | ✓ | ✓ |
+///
+/// Looking an optic up as a stronger kind fails with [`OpticRegistryError::KindMismatch`].
+///
+/// This allows tools that only know the names of the parts of a structure at runtime, such as
+/// command line interfaces, scripts or remote procedure calls, to read and write them.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{HasGetter, HasSetter, OpticRegistry, OpticRegistryError, field_lens, option};
+///
+/// struct Server {
+///     host: String,
+///     port: Option<u16>,
+/// }
+///
+/// let mut registry = OpticRegistry::new();
+/// registry
+///     .register_lens("host", field_lens!(Server, host))
+///     .register_prism("port", field_lens!(Server, port).compose_with_prism(option::some()));
+///
+/// let mut server = Server { host: "localhost".to_string(), port: Some(8080) };
+/// let port = registry.prism::<u16>("port").unwrap();
+/// port.set(&mut server, 9090);
+/// assert_eq!(port.try_get(&server).ok(), Some(9090));
+///
+/// assert!(matches!(
+///     registry.lens::<u16>("host"),
+///     Err(OpticRegistryError::TypeMismatch { .. })
+/// ));
+/// ```
+pub struct OpticRegistry<S> {
+    optics: BTreeMap<String, Entry>,
+    _phantom: PhantomData<fn(&mut S)>,
+}
+
+struct Entry {
+    kind: &'static str,
+    focus: &'static str,
+    #[cfg_attr(not(any(feature = "lens", feature = "prism")), allow(dead_code))]
+    optic: Box<dyn Any + Send + Sync>,
+    #[cfg_attr(not(feature = "prism"), allow(dead_code))]
+    erased: ErasedOptic,
+}
+
+/// A registered optic, downcast to its shared form.
+#[cfg(any(feature = "lens", feature = "prism"))]
+enum Found<S, A> {
+    #[cfg(feature = "iso")]
+    Iso(SharedIso<S, A>),
+    #[cfg(feature = "lens")]
+    Lens(SharedLens<S, A>),
+    #[cfg(feature = "prism")]
+    Prism(SharedPrism<S, A, ErasedError>),
+}
+
+#[cfg(any(feature = "lens", feature = "prism"))]
+impl<S: 'static, A: 'static> Found<S, A> {
+    #[cfg(any(feature = "iso", all(feature = "lens", feature = "prism")))]
+    fn kind(&self) -> &'static str {
+        match self {
+            #[cfg(feature = "iso")]
+            Found::Iso(_) => "iso",
+            #[cfg(feature = "lens")]
+            Found::Lens(_) => "lens",
+            #[cfg(feature = "prism")]
+            Found::Prism(_) => "prism",
+        }
+    }
+
+    #[cfg(feature = "prism")]
+    fn into_prism(self) -> SharedPrism<S, A, ErasedError> {
+        match self {
+            #[cfg(feature = "iso")]
+            Found::Iso(iso) => PrismImpl::from(iso.0).erase_err().shared(),
+            #[cfg(feature = "lens")]
+            Found::Lens(lens) => PrismImpl::from(lens.0).erase_err().shared(),
+            Found::Prism(prism) => prism,
+        }
+    }
+}
+
+/// A registered optic with both its source and its focus type erased, so optics can be chained at
+/// runtime, such as by [`KeyPathRegistry`](crate::KeyPathRegistry), without naming their types.
+#[allow(clippy::type_complexity)]
+#[cfg_attr(not(feature = "prism"), allow(dead_code))]
+#[derive(Clone)]
+pub(crate) struct ErasedOptic {
+    pub(crate) focus: TypeId,
+    pub(crate) focus_name: &'static str,
+    pub(crate) get: Arc<dyn Fn(&dyn Any) -> Result<Box<dyn Any>, ErasedError> + Send + Sync>,
+    pub(crate) set: Arc<dyn Fn(&mut dyn Any, &mut dyn Any) + Send + Sync>,
+    pub(crate) modify: Arc<dyn Fn(&mut dyn Any, &mut dyn FnMut(&mut dyn Any)) + Send + Sync>,
+}
+
+/// The error of reading through an [`ErasedOptic`] from a source of another type than the source
+/// of the optic.
+#[derive(Debug)]
+struct SourceMismatch {
+    expected: &'static str,
+}
+
+impl Display for SourceMismatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "expected a source of type {}", self.expected)
+    }
+}
+
+impl core::error::Error for SourceMismatch {}
+
+impl ErasedOptic {
+    /// Erases the source and focus types of `optic`.
+    ///
+    /// The value passed to `set` is an `Option<A>`, taken when it is written, and writes to
+    /// sources of another type than `S` are ignored.
+    pub(crate) fn of<S, A, O>(optic: O) -> Self
+    where
+        S: 'static,
+        A: 'static,
+        O: HasModify<S, A> + Send + Sync + 'static,
+        O::GetterError: Into<ErasedError>,
+    {
+        let optic = Arc::new(optic);
+        let setter = optic.clone();
+        let modifier = optic.clone();
+        ErasedOptic {
+            focus: TypeId::of::<A>(),
+            focus_name: type_name::<A>(),
+            get: Arc::new(move |source| {
+                let source = source.downcast_ref::<S>().ok_or(SourceMismatch {
+                    expected: type_name::<S>(),
+                })?;
+                optic
+                    .try_get(source)
+                    .map(|value| Box::new(value) as Box<dyn Any>)
+                    .map_err(Into::into)
+            }),
+            set: Arc::new(move |source, value| {
+                if let (Some(source), Some(value)) = (
+                    source.downcast_mut::<S>(),
+                    value.downcast_mut::<Option<A>>().and_then(Option::take),
+                ) {
+                    setter.set(source, value);
+                }
+            }),
+            modify: Arc::new(move |source, f| {
+                if let Some(source) = source.downcast_mut::<S>() {
+                    modifier.modify(source, |focus| f(focus));
+                }
+            }),
+        }
+    }
+}
+
+impl<S: 'static> OpticRegistry<S> {
+    /// Creates a new, empty registry.
+    #[must_use]
+    pub fn new() -> Self {
+        OpticRegistry {
+            optics: BTreeMap::new(),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns `true` if an optic is registered under `name`.
+    #[must_use]
+    pub fn contains(&self, name: &str) -> bool {
+        self.optics.contains_key(name)
+    }
+
+    /// Returns the names of the registered optics, in lexicographic order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.optics.keys().map(String::as_str)
+    }
+
+    /// Registers `iso` under `name`, replacing any optic registered under the same name.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The name to look up the iso with.
+    /// - `iso`: The iso to register.
+    #[cfg(feature = "iso")]
+    pub fn register_iso<A, I>(
+        &mut self,
+        name: impl Into<String>,
+        iso: IsoImpl<S, A, I>,
+    ) -> &mut Self
+    where
+        A: 'static,
+        I: Iso<S, A> + Send + Sync + 'static,
+    {
+        self.insert(name.into(), "iso", iso.shared().0);
+        self
+    }
+
+    /// Registers `lens` under `name`, replacing any optic registered under the same name.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The name to look up the lens with.
+    /// - `lens`: The lens to register.
+    #[cfg(feature = "lens")]
+    pub fn register_lens<A, L>(
+        &mut self,
+        name: impl Into<String>,
+        lens: LensImpl<S, A, L>,
+    ) -> &mut Self
+    where
+        A: 'static,
+        L: Lens<S, A> + Send + Sync + 'static,
+    {
+        self.insert(name.into(), "lens", lens.shared().0);
+        self
+    }
+
+    /// Registers `prism` under `name`, replacing any optic registered under the same name.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The name to look up the prism with.
+    /// - `prism`: The prism to register. Its errors are boxed into an [`ErasedError`].
+    #[cfg(feature = "prism")]
+    pub fn register_prism<A, P>(
+        &mut self,
+        name: impl Into<String>,
+        prism: PrismImpl<S, A, P>,
+    ) -> &mut Self
+    where
+        A: 'static,
+        P: Prism<S, A> + Send + Sync + 'static,
+        P::GetterError: Into<ErasedError>,
+    {
+        self.insert(name.into(), "prism", prism.erase_err().shared().0);
+        self
+    }
+
+    /// Looks up the iso registered under `name`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`OpticRegistryError`] if no iso focusing on a value of type `A` is registered
+    /// under `name`.
+    #[cfg(feature = "iso")]
+    pub fn iso<A: 'static>(&self, name: &str) -> Result<SharedIso<S, A>, OpticRegistryError> {
+        match self.find::<A>(name)? {
+            Found::Iso(iso) => Ok(iso),
+            found @ Found::Lens(_) => Err(Self::kind_mismatch(name, "iso", &found)),
+            #[cfg(feature = "prism")]
+            found @ Found::Prism(_) => Err(Self::kind_mismatch(name, "iso", &found)),
+        }
+    }
+
+    /// Looks up the lens registered under `name`, or the iso registered under `name` as a lens.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`OpticRegistryError`] if no lens or iso focusing on a value of type `A` is
+    /// registered under `name`.
+    #[cfg(feature = "lens")]
+    pub fn lens<A: 'static>(&self, name: &str) -> Result<SharedLens<S, A>, OpticRegistryError> {
+        match self.find::<A>(name)? {
+            #[cfg(feature = "iso")]
+            Found::Iso(iso) => Ok(LensImpl::from(iso.0).shared()),
+            Found::Lens(lens) => Ok(lens),
+            #[cfg(feature = "prism")]
+            found @ Found::Prism(_) => Err(Self::kind_mismatch(name, "lens", &found)),
+        }
+    }
+
+    /// Looks up the prism registered under `name`, or the iso or lens registered under `name` as a
+    /// prism.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`OpticRegistryError`] if no optic focusing on a value of type `A` is registered
+    /// under `name`.
+    #[cfg(feature = "prism")]
+    pub fn prism<A: 'static>(
+        &self,
+        name: &str,
+    ) -> Result<SharedPrism<S, A, ErasedError>, OpticRegistryError> {
+        self.find::<A>(name).map(Found::into_prism)
+    }
+
+    /// Looks up the iso or lens registered under `name` as a getter.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`OpticRegistryError`] if no iso or lens focusing on a value of type `A` is
+    /// registered under `name`.
+    #[cfg(all(feature = "getter", feature = "lens"))]
+    pub fn getter<A: 'static>(&self, name: &str) -> Result<SharedGetter<S, A>, OpticRegistryError> {
+        match self.find::<A>(name)? {
+            #[cfg(feature = "iso")]
+            Found::Iso(iso) => Ok(iso.as_getter().shared()),
+            Found::Lens(lens) => Ok(lens.as_getter().shared()),
+            #[cfg(feature = "prism")]
+            found @ Found::Prism(_) => Err(Self::kind_mismatch(name, "getter", &found)),
+        }
+    }
+
+    /// Looks up the optic registered under `name` as a partial getter.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`OpticRegistryError`] if no optic focusing on a value of type `A` is registered
+    /// under `name`.
+    #[cfg(any(feature = "lens", feature = "prism"))]
+    pub fn partial_getter<A: 'static>(
+        &self,
+        name: &str,
+    ) -> Result<SharedPartialGetter<S, A, ErasedError>, OpticRegistryError> {
+        match self.find::<A>(name)? {
+            #[cfg(feature = "iso")]
+            Found::Iso(iso) => Ok(iso.as_partial_getter().erase_err().shared()),
+            #[cfg(feature = "lens")]
+            Found::Lens(lens) => Ok(lens.as_partial_getter().erase_err().shared()),
+            #[cfg(feature = "prism")]
+            Found::Prism(prism) => Ok(prism.as_partial_getter().shared()),
+        }
+    }
+
+    /// Looks up the optic registered under `name` as a setter.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`OpticRegistryError`] if no optic focusing on a value of type `A` is registered
+    /// under `name`.
+    #[cfg(all(feature = "setter", any(feature = "lens", feature = "prism")))]
+    pub fn setter<A: 'static>(&self, name: &str) -> Result<SharedSetter<S, A>, OpticRegistryError> {
+        match self.find::<A>(name)? {
+            #[cfg(feature = "iso")]
+            Found::Iso(iso) => Ok(iso.as_setter().shared()),
+            #[cfg(feature = "lens")]
+            Found::Lens(lens) => Ok(lens.as_setter().shared()),
+            #[cfg(feature = "prism")]
+            Found::Prism(prism) => Ok(prism.as_setter().shared()),
+        }
+    }
+
+    /// Returns the optic registered under `name` with its source and focus types erased.
+    #[cfg(feature = "prism")]
+    pub(crate) fn erased(&self, name: &str) -> Option<&ErasedOptic> {
+        self.optics.get(name).map(|entry| &entry.erased)
+    }
+
+    #[cfg_attr(not(any(feature = "lens", feature = "prism")), allow(dead_code))]
+    fn insert<A, O>(&mut self, name: String, kind: &'static str, optic: O)
+    where
+        A: 'static,
+        O: HasModify<S, A> + Clone + Send + Sync + 'static,
+        O::GetterError: Into<ErasedError>,
+    {
+        self.optics.insert(
+            name,
+            Entry {
+                kind,
+                focus: type_name::<A>(),
+                optic: Box::new(optic.clone()),
+                erased: ErasedOptic::of(optic),
+            },
+        );
+    }
+
+    #[cfg(any(feature = "lens", feature = "prism"))]
+    fn find<A: 'static>(&self, name: &str) -> Result<Found<S, A>, OpticRegistryError> {
+        let entry = self
+            .optics
+            .get(name)
+            .ok_or_else(|| OpticRegistryError::NotFound {
+                name: name.to_string(),
+            })?;
+
+        let found = match entry.kind {
+            #[cfg(feature = "iso")]
+            "iso" => Self::downcast::<Arc<dyn DynIso<S, A> + Send + Sync>>(entry)
+                .map(|iso| Found::Iso(IsoImpl::from(iso))),
+            #[cfg(feature = "lens")]
+            "lens" => Self::downcast::<Arc<dyn DynLens<S, A> + Send + Sync>>(entry)
+                .map(|lens| Found::Lens(LensImpl::from(lens))),
+            #[cfg(feature = "prism")]
+            "prism" => Self::downcast::<Arc<dyn DynPrism<S, A, ErasedError> + Send + Sync>>(entry)
+                .map(|prism| Found::Prism(PrismImpl::from(prism))),
+            _ => None,
+        };
+
+        found.ok_or_else(|| OpticRegistryError::TypeMismatch {
+            name: name.to_string(),
+            expected: type_name::<A>(),
+            found: entry.focus,
+        })
+    }
+
+    #[cfg(any(feature = "lens", feature = "prism"))]
+    fn downcast<O: Clone + 'static>(entry: &Entry) -> Option<O> {
+        entry.optic.downcast_ref::<O>().cloned()
+    }
+
+    #[cfg(any(feature = "iso", all(feature = "lens", feature = "prism")))]
+    fn kind_mismatch<A: 'static>(
+        name: &str,
+        expected: &'static str,
+        found: &Found<S, A>,
+    ) -> OpticRegistryError {
+        OpticRegistryError::KindMismatch {
+            name: name.to_string(),
+            expected,
+            found: found.kind(),
+        }
+    }
+}
+
+impl<S: 'static> Default for OpticRegistry<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S> Debug for OpticRegistry<S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_map()
+            .entries(
+                self.optics
+                    .iter()
+                    .map(|(name, entry)| (name, (entry.kind, entry.focus))),
+            )
+            .finish()
+    }
+}
+
+/// The error of looking up an optic in an [`OpticRegistry`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OpticRegistryError {
+    /// No optic is registered under the name.
+    NotFound {
+        /// The name that was looked up.
+        name: String,
+    },
+    /// The optic registered under the name is of a weaker kind than the one looked up, such as a
+    /// prism looked up as a lens.
+    KindMismatch {
+        /// The name that was looked up.
+        name: String,
+        /// The kind of optic that was looked up.
+        expected: &'static str,
+        /// The kind of the registered optic.
+        found: &'static str,
+    },
+    /// The optic registered under the name focuses on a value of another type.
+    TypeMismatch {
+        /// The name that was looked up.
+        name: String,
+        /// The type of the focus that was looked up.
+        expected: &'static str,
+        /// The type of the focus of the registered optic.
+        found: &'static str,
+    },
+}
+
+impl OpticRegistryError {
+    /// Returns the name that was looked up.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        match self {
+            OpticRegistryError::NotFound { name }
+            | OpticRegistryError::KindMismatch { name, .. }
+            | OpticRegistryError::TypeMismatch { name, .. } => name,
+        }
+    }
+}
+
+fn article(kind: &str) -> &'static str {
+    if kind.starts_with(['a', 'e', 'i', 'o', 'u']) {
+        "an"
+    } else {
+        "a"
+    }
+}
+
+impl Display for OpticRegistryError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            OpticRegistryError::NotFound { name } => {
+                write!(f, "{name}: no optic is registered under this name")
+            }
+            OpticRegistryError::KindMismatch {
+                name,
+                expected,
+                found,
+            } => write!(
+                f,
+                "{name}: expected {} {expected}, found {} {found}",
+                article(expected),
+                article(found)
+            ),
+            OpticRegistryError::TypeMismatch {
+                name,
+                expected,
+                found,
+            } => write!(
+                f,
+                "{name}: expected an optic focusing on {expected}, found one focusing on {found}"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for OpticRegistryError {}
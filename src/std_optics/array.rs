@@ -0,0 +1,97 @@
+//! Optics focusing on the elements of fixed size arrays.
+//!
+//! [`array_lens`] creates a [`Lens`] focusing on a single element of an array, with the index
+//! checked against the length of the array at compile time, while [`array_each`] creates a
+//! [`Traversal`] focusing on every element of it.
+//!
+//! # Example
+//!
+//! ```rust
+//! use optics::{array_each, array_lens, HasGetAll, HasSetter, HasTotalGetter, HasTraversal};
+//!
+//! let mut rgb = [32u8, 64, 128];
+//!
+//! assert_eq!(array_lens::<u8, 3, 1>().get(&rgb), 64);
+//! array_lens::<u8, 3, 1>().set(&mut rgb, 65);
+//!
+//! array_each::<u8, 3>().over_all(&mut rgb, &mut |c| c / 2);
+//! assert_eq!(array_each::<u8, 3>().get_all(&rgb), vec![16, 32, 64]);
+//! ```
+#[cfg(feature = "lens")]
+use crate::{Lens, LensImpl, mapped_lens};
+use crate::{Traversal, TraversalImpl, mapped_traversal};
+
+/// Creates a `Lens` focusing on the element at index `I` of an array of length `N`.
+///
+/// The index is checked at compile time: using an index that is out of bounds for the array is a
+/// compilation error rather than a runtime panic.
+///
+/// # Type Parameters
+///
+/// - `T`: The type of the elements of the array. Must implement `Clone`.
+/// - `N`: The length of the array.
+/// - `I`: The index of the focused element. Must be less than `N`.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{array_lens, HasSetter, HasTotalGetter};
+///
+/// let mut point = [1.0f32, 2.0, 3.0];
+///
+/// let z = array_lens::<f32, 3, 2>();
+/// assert_eq!(z.get(&point), 3.0);
+/// z.set(&mut point, 4.0);
+/// assert_eq!(point, [1.0, 2.0, 4.0]);
+/// ```
+///
+/// An out of bounds index does not compile:
+///
+/// ```compile_fail
+/// use optics::array_lens;
+///
+/// let _ = array_lens::<u8, 3, 3>();
+/// ```
+#[cfg(feature = "lens")]
+#[must_use]
+pub fn array_lens<T: Clone, const N: usize, const I: usize>()
+-> LensImpl<[T; N], T, impl Lens<[T; N], T>> {
+    const { assert!(I < N, "array_lens index out of bounds") };
+
+    mapped_lens(
+        |array: &[T; N]| array[I].clone(),
+        |array: &mut [T; N], value| array[I] = value,
+    )
+}
+
+/// Creates a `Traversal` focusing on every element of an array of length `N`.
+///
+/// The elements are visited in order of their index.
+///
+/// # Type Parameters
+///
+/// - `T`: The type of the elements of the array. Must implement `Clone`.
+/// - `N`: The length of the array.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{array_each, HasGetAll, HasTraversal};
+///
+/// let mut scores = [1u32, 2, 3];
+///
+/// array_each::<u32, 3>().over_all(&mut scores, &mut |s| s * 10);
+/// assert_eq!(array_each::<u32, 3>().get_all(&scores), vec![10, 20, 30]);
+/// ```
+#[must_use]
+pub fn array_each<T: Clone, const N: usize>() -> TraversalImpl<[T; N], T, impl Traversal<[T; N], T>>
+{
+    mapped_traversal(
+        |array: &[T; N], f: &mut dyn FnMut(T)| array.iter().cloned().for_each(f),
+        |array: &mut [T; N], f: &mut dyn FnMut(T) -> T| {
+            for element in array.iter_mut() {
+                *element = f(element.clone());
+            }
+        },
+    )
+}
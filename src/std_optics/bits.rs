@@ -0,0 +1,92 @@
+//! Lenses focusing on individual bits and bit fields of unsigned integers.
+//!
+//! [`bit_lens`] focuses on a single bit of a `u32` as a `bool`, and [`bits_lens`] focuses on a
+//! range of bits of a `u32`, shifted down to start at bit zero. This allows flag words and packed
+//! registers to be manipulated with composable optics. The `_u8`, `_u16` and `_u64` variants
+//! work the same way on integers of other widths.
+//!
+//! # Example
+//!
+//! ```rust
+//! use optics::{bit_lens, bits_lens, HasSetter, HasTotalGetter};
+//!
+//! let mut register = 0b1010_0000u32;
+//!
+//! assert!(bit_lens(7).get(&register));
+//! bit_lens(0).set(&mut register, true);
+//!
+//! assert_eq!(bits_lens(4..8).get(&register), 0b1010);
+//! bits_lens(4..8).set(&mut register, 0b0110);
+//! assert_eq!(register, 0b0110_0001);
+//! ```
+#[cfg(feature = "lens")]
+use crate::{Lens, LensImpl, mapped_lens};
+#[cfg(feature = "lens")]
+use core::ops::Range;
+
+macro_rules! bit_lenses {
+    ($($ty:ident => $bit_lens:ident, $bits_lens:ident;)+) => {
+        $(
+            #[doc = concat!("Creates a `Lens` focusing on bit `n` of a `", stringify!($ty), "`.")]
+            ///
+            /// Bits are numbered from the least significant bit, starting at zero.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `n` is not less than the number of bits of the integer.
+            #[cfg(feature = "lens")]
+            #[must_use]
+            pub fn $bit_lens(n: u32) -> LensImpl<$ty, bool, impl Lens<$ty, bool>> {
+                assert!(n < $ty::BITS, "bit index {n} out of range for {}", stringify!($ty));
+                let mask: $ty = 1 << n;
+
+                mapped_lens(
+                    move |x: &$ty| x & mask != 0,
+                    move |x: &mut $ty, value: bool| {
+                        if value {
+                            *x |= mask;
+                        } else {
+                            *x &= !mask;
+                        }
+                    },
+                )
+            }
+
+            #[doc = concat!("Creates a `Lens` focusing on the bits in `range` of a `", stringify!($ty), "`.")]
+            ///
+            /// The focused bits are shifted down, so that the first bit of the range is bit zero
+            /// of the focus. When setting the focus, bits of the new value that do not fit in the
+            /// range are ignored, the bits outside the range are left unchanged.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the range is empty, or extends beyond the number of bits of the integer.
+            #[cfg(feature = "lens")]
+            #[must_use]
+            pub fn $bits_lens(range: Range<u32>) -> LensImpl<$ty, $ty, impl Lens<$ty, $ty>> {
+                assert!(
+                    range.start < range.end && range.end <= $ty::BITS,
+                    "bit range {range:?} invalid for {}",
+                    stringify!($ty)
+                );
+                let shift = range.start;
+                let width_mask: $ty = <$ty>::MAX >> ($ty::BITS - (range.end - range.start));
+                let mask = width_mask << shift;
+
+                mapped_lens(
+                    move |x: &$ty| (x >> shift) & width_mask,
+                    move |x: &mut $ty, value: $ty| {
+                        *x = (*x & !mask) | ((value & width_mask) << shift);
+                    },
+                )
+            }
+        )+
+    };
+}
+
+bit_lenses! {
+    u8 => bit_lens_u8, bits_lens_u8;
+    u16 => bit_lens_u16, bits_lens_u16;
+    u32 => bit_lens, bits_lens;
+    u64 => bit_lens_u64, bits_lens_u64;
+}
@@ -0,0 +1,284 @@
+//! Optics focusing on the entries of a `BTreeMap`.
+//!
+//! [`at`] and [`value`] mirror their `HashMap` counterparts in the [`map`](crate::map) module.
+//! As the entries of a `BTreeMap` are ordered by their keys, [`first_entry`] and [`last_entry`]
+//! focus on the value of the entry with the smallest and the largest key, and
+//! [`range_traversal`] focuses on the values of all entries with keys within a range.
+//!
+//! Unlike the `HashMap` optics, these do not require the `std` feature.
+//!
+//! # Example
+//!
+//! ```rust
+//! use optics::{btree_map, HasGetAll, HasGetter, HasTraversal};
+//! use std::collections::BTreeMap;
+//!
+//! let mut temperatures = BTreeMap::from([(6, 12.5), (12, 21.0), (18, 17.5), (24, 9.0)]);
+//!
+//! assert_eq!(btree_map::first_entry().try_get(&temperatures), Ok(12.5));
+//! assert_eq!(btree_map::range_traversal(10..20).get_all(&temperatures), vec![21.0, 17.5]);
+//!
+//! btree_map::range_traversal(10..20).over_all(&mut temperatures, &mut |t| t + 1.0);
+//! assert_eq!(temperatures[&12], 22.0);
+//! ```
+#[cfg(feature = "prism")]
+use crate::KeyMissing;
+#[cfg(feature = "prism")]
+use crate::{HasInsert, Prism, PrismImpl, mapped_prism};
+#[cfg(feature = "lens")]
+use crate::{Lens, LensImpl, mapped_lens};
+use crate::{OpticsError, Traversal, TraversalImpl, mapped_traversal};
+use alloc::collections::BTreeMap;
+use core::fmt::{Display, Formatter};
+use core::ops::RangeBounds;
+
+/// The error returned when an optic tries to focus on the first or the last entry of an empty
+/// map.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MapEmpty;
+
+impl Display for MapEmpty {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "the map is empty")
+    }
+}
+
+impl core::error::Error for MapEmpty {}
+
+impl From<MapEmpty> for () {
+    fn from(_: MapEmpty) -> Self {}
+}
+
+impl From<MapEmpty> for OpticsError {
+    fn from(_: MapEmpty) -> Self {
+        OpticsError::NoFocus
+    }
+}
+
+/// Creates a `Lens` focusing on the optional value stored under `key` in a `BTreeMap`.
+///
+/// Retrieving the focus returns `None` if there is no entry for the key. Setting `Some(value)`
+/// inserts or replaces the entry, while setting `None` removes it.
+///
+/// # Type Parameters
+///
+/// - `K`: The type of the keys of the map. Must implement `Clone` and `Ord`.
+/// - `V`: The type of the values of the map. Must implement `Clone`.
+///
+/// # Arguments
+///
+/// - `key` — The key of the focused entry.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{btree_map, HasSetter, HasTotalGetter};
+/// use std::collections::BTreeMap;
+///
+/// let mut versions = BTreeMap::new();
+/// let serde = btree_map::at("serde");
+///
+/// serde.set(&mut versions, Some("1.0"));
+/// assert_eq!(serde.get(&versions), Some("1.0"));
+/// serde.set(&mut versions, None);
+/// assert!(versions.is_empty());
+/// ```
+#[cfg(feature = "lens")]
+#[must_use]
+#[allow(clippy::type_complexity)]
+pub fn at<K, V>(key: K) -> LensImpl<BTreeMap<K, V>, Option<V>, impl Lens<BTreeMap<K, V>, Option<V>>>
+where
+    K: Clone + Ord,
+    V: Clone,
+{
+    let set_key = key.clone();
+
+    mapped_lens(
+        move |map: &BTreeMap<K, V>| map.get(&key).cloned(),
+        move |map: &mut BTreeMap<K, V>, value| match value {
+            Some(value) => {
+                map.insert(set_key.clone(), value);
+            }
+            None => {
+                map.remove(&set_key);
+            }
+        },
+    )
+}
+
+/// Creates a `Prism` focusing on the value stored under `key` in a `BTreeMap`.
+///
+/// Retrieving the focus fails with a [`KeyMissing`] error if there is no entry for the key.
+/// Setting the focus only replaces an existing value, it is a no-op if there is no entry for the
+/// key, while [`insert`](crate::HasInsert::insert) adds the entry.
+///
+/// # Type Parameters
+///
+/// - `K`: The type of the keys of the map. Must implement `Clone` and `Ord`.
+/// - `V`: The type of the values of the map. Must implement `Clone`.
+///
+/// # Arguments
+///
+/// - `key` — The key of the focused entry.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{btree_map, HasGetter, KeyMissing};
+/// use std::collections::BTreeMap;
+///
+/// let versions = BTreeMap::from([("serde", "1.0")]);
+///
+/// assert_eq!(btree_map::value("serde").try_get(&versions), Ok("1.0"));
+/// assert_eq!(btree_map::value("syn").try_get(&versions), Err(KeyMissing { key: "syn" }));
+/// ```
+#[cfg(feature = "prism")]
+#[must_use]
+#[allow(clippy::type_complexity)]
+pub fn value<K, V>(
+    key: K,
+) -> PrismImpl<
+    BTreeMap<K, V>,
+    V,
+    impl Prism<BTreeMap<K, V>, V, GetterError = KeyMissing<K>> + HasInsert<BTreeMap<K, V>, V>,
+>
+where
+    K: Clone + Ord,
+    V: Clone,
+{
+    let set_key = key.clone();
+    let insert_key = key.clone();
+
+    mapped_prism(
+        move |map: &BTreeMap<K, V>| {
+            map.get(&key)
+                .cloned()
+                .ok_or_else(|| KeyMissing { key: key.clone() })
+        },
+        move |map: &mut BTreeMap<K, V>, value| {
+            if let Some(existing) = map.get_mut(&set_key) {
+                *existing = value;
+            }
+        },
+    )
+    .with_insert(move |map: &mut BTreeMap<K, V>, value| {
+        map.insert(insert_key.clone(), value);
+    })
+}
+
+/// Creates a `Prism` focusing on the value of the entry with the smallest key of a `BTreeMap`.
+///
+/// Retrieving the focus fails with a [`MapEmpty`] error if the map is empty. Setting the focus
+/// only replaces the value of an existing entry, it is a no-op on an empty map.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{btree_map, HasGetter, HasSetter};
+/// use std::collections::BTreeMap;
+///
+/// let mut queue = BTreeMap::from([(2, "b"), (1, "a")]);
+///
+/// assert_eq!(btree_map::first_entry().try_get(&queue), Ok("a"));
+/// btree_map::first_entry().set(&mut queue, "z");
+/// assert_eq!(queue[&1], "z");
+/// ```
+#[cfg(feature = "prism")]
+#[must_use]
+pub fn first_entry<K: Ord, V: Clone>()
+-> PrismImpl<BTreeMap<K, V>, V, impl Prism<BTreeMap<K, V>, V, GetterError = MapEmpty>> {
+    mapped_prism(
+        |map: &BTreeMap<K, V>| {
+            map.first_key_value()
+                .map(|(_, v)| v.clone())
+                .ok_or(MapEmpty)
+        },
+        |map: &mut BTreeMap<K, V>, value| {
+            if let Some(mut entry) = map.first_entry() {
+                entry.insert(value);
+            }
+        },
+    )
+}
+
+/// Creates a `Prism` focusing on the value of the entry with the largest key of a `BTreeMap`.
+///
+/// Retrieving the focus fails with a [`MapEmpty`] error if the map is empty. Setting the focus
+/// only replaces the value of an existing entry, it is a no-op on an empty map.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{btree_map, HasGetter, MapEmpty};
+/// use std::collections::BTreeMap;
+///
+/// let queue = BTreeMap::from([(2, "b"), (1, "a")]);
+///
+/// assert_eq!(btree_map::last_entry().try_get(&queue), Ok("b"));
+/// assert_eq!(btree_map::last_entry::<u8, u8>().try_get(&BTreeMap::new()), Err(MapEmpty));
+/// ```
+#[cfg(feature = "prism")]
+#[must_use]
+pub fn last_entry<K: Ord, V: Clone>()
+-> PrismImpl<BTreeMap<K, V>, V, impl Prism<BTreeMap<K, V>, V, GetterError = MapEmpty>> {
+    mapped_prism(
+        |map: &BTreeMap<K, V>| map.last_key_value().map(|(_, v)| v.clone()).ok_or(MapEmpty),
+        |map: &mut BTreeMap<K, V>, value| {
+            if let Some(mut entry) = map.last_entry() {
+                entry.insert(value);
+            }
+        },
+    )
+}
+
+/// Creates a `Traversal` focusing on the values of all entries of a `BTreeMap` whose keys fall
+/// within `range`.
+///
+/// The values are visited in the order of their keys.
+///
+/// # Type Parameters
+///
+/// - `K`: The type of the keys of the map. Must implement `Ord`.
+/// - `V`: The type of the values of the map. Must implement `Clone`.
+/// - `R`: The type of the range. Must implement `RangeBounds<K>` and `Clone`.
+///
+/// # Arguments
+///
+/// - `range` — The range of keys whose values are focused on.
+///
+/// # Panics
+///
+/// Using the traversal panics under the same conditions as [`BTreeMap::range`], such as when the
+/// start of the range is greater than its end.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{btree_map, HasGetAll};
+/// use std::collections::BTreeMap;
+///
+/// let scores = BTreeMap::from([("alice", 3), ("bob", 5), ("carol", 4)]);
+///
+/// assert_eq!(btree_map::range_traversal("b"..).get_all(&scores), vec![5, 4]);
+/// ```
+#[must_use]
+pub fn range_traversal<K, V, R>(
+    range: R,
+) -> TraversalImpl<BTreeMap<K, V>, V, impl Traversal<BTreeMap<K, V>, V>>
+where
+    K: Ord,
+    V: Clone,
+    R: RangeBounds<K> + Clone,
+{
+    let over_range = range.clone();
+
+    mapped_traversal(
+        move |map: &BTreeMap<K, V>, f: &mut dyn FnMut(V)| {
+            map.range(range.clone()).for_each(|(_, v)| f(v.clone()));
+        },
+        move |map: &mut BTreeMap<K, V>, f: &mut dyn FnMut(V) -> V| {
+            map.range_mut(over_range.clone())
+                .for_each(|(_, v)| *v = f(v.clone()));
+        },
+    )
+}
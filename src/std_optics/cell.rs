@@ -0,0 +1,90 @@
+//! Optics focusing on the value inside a `Cell` or a `RefCell`.
+//!
+//! These make it possible for interior mutability fields to participate in compositions. As the
+//! optics receive a mutable reference to the cell when setting the focus, writing never requires
+//! a dynamic borrow. Reading a `RefCell` however may fail if it is currently mutably borrowed
+//! elsewhere, so [`ref_cell_lens`] is a `Prism` reporting this as a [`BorrowError`] instead of
+//! panicking.
+//!
+//! # Example
+//!
+//! ```rust
+//! use optics::{cell_lens, HasSetter, HasTotalGetter};
+//! use std::cell::Cell;
+//!
+//! struct Counter {
+//!     hits: Cell<u32>,
+//! }
+//!
+//! let mut counter = Counter { hits: Cell::new(0) };
+//! counter.hits.set(1);
+//!
+//! cell_lens().set(&mut counter.hits, 2);
+//! assert_eq!(cell_lens().get(&counter.hits), 2);
+//! ```
+#[cfg(feature = "lens")]
+use crate::{Lens, LensImpl, mapped_lens};
+#[cfg(feature = "prism")]
+use crate::{Prism, PrismImpl, mapped_prism};
+#[cfg(feature = "lens")]
+use core::cell::Cell;
+#[cfg(feature = "prism")]
+use core::cell::{BorrowError, RefCell};
+
+/// Creates a `Lens` focusing on the value inside a `Cell`.
+///
+/// # Type Parameters
+///
+/// - `T`: The type of the value inside the cell. Must implement `Copy`.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{cell_lens, HasSetter, HasTotalGetter};
+/// use std::cell::Cell;
+///
+/// let mut cell = Cell::new(1);
+///
+/// cell_lens().set(&mut cell, 2);
+/// assert_eq!(cell_lens().get(&cell), 2);
+/// ```
+#[cfg(feature = "lens")]
+#[must_use]
+pub fn cell_lens<T: Copy>() -> LensImpl<Cell<T>, T, impl Lens<Cell<T>, T>> {
+    mapped_lens(Cell::get, |cell: &mut Cell<T>, value| cell.set(value))
+}
+
+/// Creates a `Prism` focusing on the value inside a `RefCell`.
+///
+/// Retrieving the focus fails with a [`BorrowError`] if the `RefCell` is currently mutably
+/// borrowed, instead of panicking. Setting the focus goes through the mutable reference to the
+/// `RefCell`, so it can never fail.
+///
+/// # Type Parameters
+///
+/// - `T`: The type of the value inside the cell. Must implement `Clone`.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{ref_cell_lens, HasGetter, HasSetter};
+/// use std::cell::RefCell;
+///
+/// let mut cell = RefCell::new(vec![1]);
+///
+/// ref_cell_lens().set(&mut cell, vec![1, 2]);
+/// assert_eq!(ref_cell_lens().try_get(&cell).ok(), Some(vec![1, 2]));
+///
+/// let guard = cell.borrow_mut();
+/// assert!(ref_cell_lens::<Vec<u8>>().try_get(&cell).is_err());
+/// # drop(guard);
+/// ```
+#[cfg(feature = "prism")]
+#[must_use]
+pub fn ref_cell_lens<T: Clone>()
+-> PrismImpl<RefCell<T>, T, impl Prism<RefCell<T>, T, GetterError = BorrowError>> {
+    mapped_prism(
+        |cell: &RefCell<T>| cell.try_borrow().map(|value| value.clone()),
+        |cell: &mut RefCell<T>, value| *cell.get_mut() = value,
+    )
+}
@@ -0,0 +1,238 @@
+//! Optics converting `chrono` date and time values, and focusing on their parts.
+//!
+//! [`rfc3339_iso`] and [`unix_timestamp_iso`] convert a `DateTime<Utc>` to and from the strings
+//! and timestamps it is usually stored as, while [`date`], [`hour`], [`minute`] and [`second`]
+//! focus on its parts. Setting an hour, minute or second out of its range is rejected: the
+//! lenses report it through [`HasFallibleSetter`](crate::HasFallibleSetter) with a
+//! [`NumericRangeError`](crate::NumericRangeError), and leave the value unchanged on
+//! [`HasSetter::set`](crate::HasSetter::set).
+//!
+//! These optics require the `chrono` feature.
+//!
+//! # Example
+//!
+//! ```rust
+//! use optics::{chrono, HasGetter, HasSetter};
+//!
+//! let hour = chrono::rfc3339_iso().compose_with_lens(chrono::hour());
+//! let mut created_at = "2025-05-16T08:30:00Z".to_string();
+//!
+//! assert_eq!(hour.try_get(&created_at), Ok(8));
+//! hour.set(&mut created_at, 17);
+//! assert_eq!(created_at, "2025-05-16T17:30:00Z");
+//! ```
+#[cfg(any(feature = "lens", feature = "fallible-iso"))]
+use crate::NumericRangeError;
+use crate::OpticsError;
+#[cfg(feature = "lens")]
+use crate::optics::lens::checked_lens;
+#[cfg(feature = "fallible-iso")]
+use crate::{FallibleIso, FallibleIsoImpl, mapped_fallible_iso};
+#[cfg(feature = "lens")]
+use crate::{HasFallibleSetter, Lens, LensImpl, mapped_lens};
+#[cfg(any(feature = "lens", feature = "fallible-iso"))]
+use ::chrono::{DateTime, Utc};
+#[cfg(feature = "lens")]
+use ::chrono::{NaiveDate, Timelike};
+#[cfg(feature = "fallible-iso")]
+use {::chrono::SecondsFormat, alloc::string::String, core::convert::Infallible};
+
+impl From<::chrono::ParseError> for OpticsError {
+    fn from(_: ::chrono::ParseError) -> Self {
+        OpticsError::ParseFailed
+    }
+}
+
+/// Creates a `FallibleIso` between an RFC 3339 `String` and the `DateTime<Utc>` it contains.
+///
+/// Retrieving the focus parses the string with [`DateTime::parse_from_rfc3339`], converting its
+/// offset to UTC, and fails with a [`chrono::ParseError`](::chrono::ParseError) if it is not a
+/// valid RFC 3339 date and time. The reverse direction formats the value with a `Z` offset and as
+/// many fractional digits as needed, and can not fail.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{chrono, HasGetter, HasTotalReverseGet};
+///
+/// let at = chrono::rfc3339_iso().try_get(&"2025-05-16T10:00:00+02:00".to_string()).unwrap();
+///
+/// assert_eq!(chrono::rfc3339_iso().reverse_get(&at), "2025-05-16T08:00:00Z");
+/// assert!(chrono::rfc3339_iso().try_get(&"yesterday".to_string()).is_err());
+/// ```
+#[cfg(feature = "fallible-iso")]
+#[must_use]
+#[allow(clippy::type_complexity)]
+pub fn rfc3339_iso() -> FallibleIsoImpl<
+    String,
+    DateTime<Utc>,
+    impl FallibleIso<
+        String,
+        DateTime<Utc>,
+        GetterError = ::chrono::ParseError,
+        ReverseError = Infallible,
+    >,
+> {
+    mapped_fallible_iso(
+        |s: &String| DateTime::parse_from_rfc3339(s).map(|at| at.with_timezone(&Utc)),
+        |at: &DateTime<Utc>| Ok::<_, Infallible>(at.to_rfc3339_opts(SecondsFormat::AutoSi, true)),
+    )
+}
+
+/// Creates a `FallibleIso` between a Unix timestamp in seconds and the `DateTime<Utc>` it stands
+/// for.
+///
+/// Retrieving the focus fails with a [`NumericRangeError`] if the timestamp is out of the range
+/// `chrono` can represent. The reverse direction drops the sub-second part of the value, and can
+/// not fail.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{chrono, HasGetter, HasTotalReverseGet, NumericRangeError};
+///
+/// let epoch = chrono::unix_timestamp_iso().try_get(&0).unwrap();
+///
+/// assert_eq!(chrono::rfc3339_iso().reverse_get(&epoch), "1970-01-01T00:00:00Z");
+/// assert_eq!(chrono::unix_timestamp_iso().reverse_get(&epoch), 0);
+/// assert_eq!(chrono::unix_timestamp_iso().try_get(&i64::MAX), Err(NumericRangeError));
+/// ```
+#[cfg(feature = "fallible-iso")]
+#[must_use]
+#[allow(clippy::type_complexity)]
+pub fn unix_timestamp_iso() -> FallibleIsoImpl<
+    i64,
+    DateTime<Utc>,
+    impl FallibleIso<i64, DateTime<Utc>, GetterError = NumericRangeError, ReverseError = Infallible>,
+> {
+    mapped_fallible_iso(
+        |secs: &i64| DateTime::from_timestamp(*secs, 0).ok_or(NumericRangeError),
+        |at: &DateTime<Utc>| Ok::<_, Infallible>(at.timestamp()),
+    )
+}
+
+/// Creates a `Lens` focusing on the date of a `DateTime<Utc>`.
+///
+/// Setting the focus replaces the date, keeping the time of day.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{chrono, HasGetter, HasSetter, HasTotalGetter};
+/// use ::chrono::NaiveDate;
+///
+/// let mut at = chrono::rfc3339_iso().try_get(&"2025-05-16T08:30:00Z".to_string()).unwrap();
+///
+/// chrono::date().set(&mut at, NaiveDate::from_ymd_opt(2025, 12, 24).unwrap());
+/// assert_eq!(at.to_rfc3339(), "2025-12-24T08:30:00+00:00");
+/// assert_eq!(chrono::date().get(&at).to_string(), "2025-12-24");
+/// ```
+#[cfg(feature = "lens")]
+#[must_use]
+pub fn date() -> LensImpl<DateTime<Utc>, NaiveDate, impl Lens<DateTime<Utc>, NaiveDate>> {
+    mapped_lens(
+        |at: &DateTime<Utc>| at.date_naive(),
+        |at: &mut DateTime<Utc>, date: NaiveDate| *at = date.and_time(at.time()).and_utc(),
+    )
+}
+
+#[cfg(feature = "lens")]
+fn time_component(
+    get_fn: fn(&DateTime<Utc>) -> u32,
+    with_fn: fn(&DateTime<Utc>, u32) -> Option<DateTime<Utc>>,
+) -> LensImpl<
+    DateTime<Utc>,
+    u32,
+    impl Lens<DateTime<Utc>, u32>
+    + HasFallibleSetter<DateTime<Utc>, u32, SetterError = NumericRangeError>,
+> {
+    checked_lens(get_fn, move |at: &mut DateTime<Utc>, value| {
+        *at = with_fn(at, value).ok_or(NumericRangeError)?;
+        Ok(())
+    })
+}
+
+/// Creates a `Lens` focusing on the hour of a `DateTime<Utc>`, from 0 to 23.
+///
+/// Setting an hour above 23 is rejected: [`HasFallibleSetter::try_set`] fails with a
+/// [`NumericRangeError`], and [`HasSetter::set`](crate::HasSetter::set) leaves the value
+/// unchanged.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{chrono, HasFallibleSetter, HasGetter, HasTotalGetter, NumericRangeError};
+///
+/// let mut at = chrono::unix_timestamp_iso().try_get(&0).unwrap();
+///
+/// assert_eq!(chrono::hour().try_set(&mut at, 23), Ok(()));
+/// assert_eq!(chrono::hour().try_set(&mut at, 24), Err(NumericRangeError));
+/// assert_eq!(chrono::hour().get(&at), 23);
+/// ```
+#[cfg(feature = "lens")]
+#[must_use]
+#[allow(clippy::type_complexity)]
+pub fn hour() -> LensImpl<
+    DateTime<Utc>,
+    u32,
+    impl Lens<DateTime<Utc>, u32>
+    + HasFallibleSetter<DateTime<Utc>, u32, SetterError = NumericRangeError>,
+> {
+    time_component(Timelike::hour, Timelike::with_hour)
+}
+
+/// Creates a `Lens` focusing on the minute of a `DateTime<Utc>`, from 0 to 59.
+///
+/// Setting a minute above 59 is rejected: [`HasFallibleSetter::try_set`] fails with a
+/// [`NumericRangeError`], and [`HasSetter::set`](crate::HasSetter::set) leaves the value
+/// unchanged.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{chrono, HasFallibleSetter, HasGetter, HasTotalGetter, NumericRangeError};
+///
+/// let mut at = chrono::unix_timestamp_iso().try_get(&0).unwrap();
+///
+/// assert_eq!(chrono::minute().try_set(&mut at, 60), Err(NumericRangeError));
+/// assert_eq!(chrono::minute().get(&at), 0);
+/// ```
+#[cfg(feature = "lens")]
+#[must_use]
+#[allow(clippy::type_complexity)]
+pub fn minute() -> LensImpl<
+    DateTime<Utc>,
+    u32,
+    impl Lens<DateTime<Utc>, u32>
+    + HasFallibleSetter<DateTime<Utc>, u32, SetterError = NumericRangeError>,
+> {
+    time_component(Timelike::minute, Timelike::with_minute)
+}
+
+/// Creates a `Lens` focusing on the second of a `DateTime<Utc>`, from 0 to 59.
+///
+/// Setting a second above 59 is rejected: [`HasFallibleSetter::try_set`] fails with a
+/// [`NumericRangeError`], and [`HasSetter::set`](crate::HasSetter::set) leaves the value
+/// unchanged.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{chrono, HasFallibleSetter, HasGetter, HasTotalGetter};
+///
+/// let mut at = chrono::unix_timestamp_iso().try_get(&0).unwrap();
+///
+/// assert_eq!(chrono::second().try_set(&mut at, 30), Ok(()));
+/// assert_eq!(chrono::second().get(&at), 30);
+/// ```
+#[cfg(feature = "lens")]
+#[must_use]
+#[allow(clippy::type_complexity)]
+pub fn second() -> LensImpl<
+    DateTime<Utc>,
+    u32,
+    impl Lens<DateTime<Utc>, u32>
+    + HasFallibleSetter<DateTime<Utc>, u32, SetterError = NumericRangeError>,
+> {
+    time_component(Timelike::second, Timelike::with_second)
+}
@@ -0,0 +1,126 @@
+//! Configuration overrides read from `config` sources.
+//!
+//! [`overrides`] looks up the path of every optic registered in a [`PatchRegistry`] in a built
+//! `config::Config`, and collects the values it finds into a [`Patch`], so settings layered from
+//! files, environment variables or overrides can be written into a typed configuration through
+//! the same optics that read and write it. The values are converted to the foci of the optics as
+//! patches convert them, with `FromStr` or a fallible iso given to
+//! [`PatchRegistry::register_with`].
+//!
+//! These overrides require the `config` feature.
+//!
+//! # Example
+//!
+//! ```rust
+//! use optics::{PatchRegistry, config, field_lens};
+//!
+//! struct Server {
+//!     host: String,
+//!     port: u16,
+//! }
+//!
+//! let mut registry = PatchRegistry::new();
+//! registry
+//!     .register(field_lens!(Server, host))
+//!     .register(field_lens!(Server, port));
+//!
+//! let source = ::config::Config::builder()
+//!     .set_override("port", 9090)
+//!     .unwrap()
+//!     .build()
+//!     .unwrap();
+//! let mut server = Server { host: "localhost".to_string(), port: 8080 };
+//!
+//! config::overrides(&source, &registry)
+//!     .unwrap()
+//!     .apply(&mut server, &registry)
+//!     .unwrap();
+//! assert_eq!((server.host.as_str(), server.port), ("localhost", 9090));
+//! ```
+use crate::{Patch, PatchRegistry};
+use ::config::{Config, ConfigError};
+
+/// Creates a [`Patch`] writing the values `config` holds at the paths of the optics registered in
+/// `registry`.
+///
+/// Paths that `config` holds no value at are left out of the patch, so the values they lead to
+/// keep their current value when the patch is applied. Strings, booleans and numbers are written
+/// in their string form.
+///
+/// # Arguments
+///
+/// - `config` — The layered sources to read the overrides from.
+/// - `registry` — The optics to look up the paths of, and to apply the patch through.
+///
+/// # Errors
+///
+/// Returns the `ConfigError` of `config` if a path leads to a table or an array.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{PatchRegistry, config, field_lens, parse_iso};
+/// use std::net::IpAddr;
+///
+/// struct Server {
+///     bind: Bind,
+/// }
+///
+/// #[derive(Clone)]
+/// struct Bind {
+///     address: IpAddr,
+///     backlog: u32,
+/// }
+///
+/// let mut registry = PatchRegistry::new();
+/// registry
+///     .register_with(
+///         field_lens!(Server, bind).compose_with_lens(field_lens!(Bind, address)),
+///         parse_iso::<IpAddr>(),
+///     )
+///     .register(field_lens!(Server, bind).compose_with_lens(field_lens!(Bind, backlog)));
+///
+/// let source = ::config::Config::builder()
+///     .set_override("bind.address", "0.0.0.0")
+///     .unwrap()
+///     .set_override("bind.backlog", 512)
+///     .unwrap()
+///     .build()
+///     .unwrap();
+///
+/// let patch = config::overrides(&source, &registry).unwrap();
+/// assert_eq!(
+///     patch.entries().collect::<Vec<_>>(),
+///     [("bind.address", "0.0.0.0"), ("bind.backlog", "512")]
+/// );
+///
+/// let mut server = Server { bind: Bind { address: [127, 0, 0, 1].into(), backlog: 128 } };
+/// patch.apply(&mut server, &registry).unwrap();
+/// assert_eq!(server.bind.address, IpAddr::from([0, 0, 0, 0]));
+/// assert_eq!(server.bind.backlog, 512);
+///
+/// let nested = ::config::Config::builder()
+///     .set_override("bind.backlog", vec![1, 2])
+///     .unwrap()
+///     .build()
+///     .unwrap();
+/// assert!(config::overrides(&nested, &registry).is_err());
+/// ```
+pub fn overrides<S>(
+    config: &Config,
+    registry: &PatchRegistry<'_, S>,
+) -> Result<Patch, ConfigError> {
+    let mut patch = Patch::new();
+
+    for path in registry.paths() {
+        match config.get_string(path) {
+            Ok(value) => {
+                patch.set(path, value);
+            }
+            Err(ConfigError::NotFound(_)) => {}
+            Err(error) => return Err(error),
+        }
+    }
+
+    Ok(patch)
+}
@@ -0,0 +1,198 @@
+//! Optics built from the standard conversion traits.
+//!
+//! These turn the conversions already implemented for a type into optics, so that they can be
+//! used in compositions without writing the closures by hand.
+//!
+//! # Example
+//!
+//! ```rust
+//! use optics::{parse_iso, HasGetter, HasTotalReverseGet};
+//! use std::net::SocketAddr;
+//!
+//! let bind_address = parse_iso::<SocketAddr>();
+//!
+//! let addr = bind_address.try_get(&"127.0.0.1:8080".to_string()).unwrap();
+//! assert_eq!(addr.port(), 8080);
+//! assert_eq!(bind_address.reverse_get(&addr), "127.0.0.1:8080");
+//! ```
+#[cfg(feature = "fallible-iso")]
+use crate::{FallibleIso, FallibleIsoImpl, mapped_fallible_iso};
+#[cfg(feature = "iso")]
+use crate::{Iso, IsoImpl, mapped_iso};
+#[cfg(feature = "fallible-iso")]
+use core::convert::Infallible;
+#[cfg(all(feature = "alloc", feature = "fallible-iso"))]
+use {
+    alloc::string::{String, ToString},
+    core::fmt::Display,
+    core::str::FromStr,
+};
+
+/// Creates a `FallibleIso` between a `String` and a type that can be parsed from, and displayed
+/// as a string.
+///
+/// Retrieving the focus parses the string using [`FromStr`], failing with `T::Err` if it can not
+/// be parsed. The reverse direction formats the value using [`Display`], and can not fail.
+///
+/// # Type Parameters
+///
+/// - `T`: The parsed type. Must implement `FromStr` and `Display`.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{parse_iso, HasGetter, HasSetter};
+///
+/// let mut port = "8080".to_string();
+///
+/// assert_eq!(parse_iso::<u16>().try_get(&port), Ok(8080));
+/// assert!(parse_iso::<u16>().try_get(&"http".to_string()).is_err());
+///
+/// parse_iso::<u16>().set(&mut port, 8081);
+/// assert_eq!(port, "8081");
+/// ```
+#[cfg(all(feature = "alloc", feature = "fallible-iso"))]
+#[must_use]
+pub fn parse_iso<T>() -> FallibleIsoImpl<
+    String,
+    T,
+    impl FallibleIso<String, T, GetterError = T::Err, ReverseError = Infallible>,
+>
+where
+    T: FromStr + Display,
+{
+    mapped_fallible_iso(
+        |s: &String| s.parse::<T>(),
+        |value: &T| Ok::<_, Infallible>(value.to_string()),
+    )
+}
+
+/// Creates an `Iso` between two types that can be converted into each other.
+///
+/// Both directions use [`From`], and can not fail. This gives isos for wrapper and mirror types
+/// without any closures.
+///
+/// # Type Parameters
+///
+/// - `S`: The source type. Must implement `Clone` and `From<A>`.
+/// - `A`: The focused type. Must implement `Clone` and `From<S>`.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{from_iso, HasTotalGetter, HasTotalReverseGet};
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// struct Celsius(f64);
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// struct Fahrenheit(f64);
+///
+/// impl From<Celsius> for Fahrenheit {
+///     fn from(c: Celsius) -> Self {
+///         Fahrenheit(c.0 * 9.0 / 5.0 + 32.0)
+///     }
+/// }
+///
+/// impl From<Fahrenheit> for Celsius {
+///     fn from(f: Fahrenheit) -> Self {
+///         Celsius((f.0 - 32.0) * 5.0 / 9.0)
+///     }
+/// }
+///
+/// let iso = from_iso::<Celsius, Fahrenheit>();
+///
+/// assert_eq!(iso.get(&Celsius(100.0)), Fahrenheit(212.0));
+/// assert_eq!(iso.reverse_get(&Fahrenheit(32.0)), Celsius(0.0));
+/// ```
+#[cfg(feature = "iso")]
+#[must_use]
+pub fn from_iso<S, A>() -> IsoImpl<S, A, impl Iso<S, A>>
+where
+    S: Clone + From<A>,
+    A: Clone + From<S>,
+{
+    mapped_iso(|s: &S| A::from(s.clone()), |a: &A| S::from(a.clone()))
+}
+
+/// Creates a `FallibleIso` between two types where the conversion from `S` to `A` may fail, but
+/// the conversion back always succeeds.
+///
+/// Retrieving the focus uses [`TryFrom`], failing with its error if the value can not be
+/// converted. The reverse direction uses [`From`], and can not fail.
+///
+/// # Type Parameters
+///
+/// - `S`: The source type. Must implement `Clone` and `From<A>`.
+/// - `A`: The focused type. Must implement `Clone` and `TryFrom<S>`.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{try_from_iso, HasGetter, HasTotalReverseGet};
+///
+/// let narrow = try_from_iso::<u32, u8>();
+///
+/// assert_eq!(narrow.try_get(&200), Ok(200));
+/// assert!(narrow.try_get(&300).is_err());
+/// assert_eq!(narrow.reverse_get(&200), 200);
+/// ```
+#[cfg(feature = "fallible-iso")]
+#[must_use]
+pub fn try_from_iso<S, A>() -> FallibleIsoImpl<
+    S,
+    A,
+    impl FallibleIso<S, A, GetterError = <A as TryFrom<S>>::Error, ReverseError = Infallible>,
+>
+where
+    S: Clone + From<A>,
+    A: Clone + TryFrom<S>,
+{
+    mapped_fallible_iso(
+        |s: &S| A::try_from(s.clone()),
+        |a: &A| Ok::<_, Infallible>(S::from(a.clone())),
+    )
+}
+
+/// Creates a `FallibleIso` between two types where the conversions in both directions may fail.
+///
+/// Retrieving the focus and the reverse direction both use [`TryFrom`], failing with their
+/// respective errors if the value can not be converted.
+///
+/// # Type Parameters
+///
+/// - `S`: The source type. Must implement `Clone` and `TryFrom<A>`.
+/// - `A`: The focused type. Must implement `Clone` and `TryFrom<S>`.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{try_from_both_iso, HasGetter, HasReverseGet};
+///
+/// let signed = try_from_both_iso::<u16, i8>();
+///
+/// assert_eq!(signed.try_get(&100), Ok(100));
+/// assert!(signed.try_get(&200).is_err());
+/// assert!(signed.try_reverse_get(&-1).is_err());
+/// ```
+#[cfg(feature = "fallible-iso")]
+#[must_use]
+pub fn try_from_both_iso<S, A>() -> FallibleIsoImpl<
+    S,
+    A,
+    impl FallibleIso<
+        S,
+        A,
+        GetterError = <A as TryFrom<S>>::Error,
+        ReverseError = <S as TryFrom<A>>::Error,
+    >,
+>
+where
+    S: Clone + TryFrom<A>,
+    A: Clone + TryFrom<S>,
+{
+    mapped_fallible_iso(
+        |s: &S| A::try_from(s.clone()),
+        |a: &A| S::try_from(a.clone()),
+    )
+}
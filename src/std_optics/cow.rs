@@ -0,0 +1,177 @@
+//! Optics focusing on the value inside a `Cow`.
+//!
+//! [`owned`] focuses on the value a `Cow` points to, leaving a borrowed `Cow` borrowed until the
+//! value is written through it, and [`string_iso`] converts between a `Cow<str>` and a `String`.
+//! A composed pipeline that only reads through a `Cow` never promotes the `Cow` itself to an
+//! owned value. As optics hand out their focus by value, reading the focus with
+//! [`HasGetter`](crate::HasGetter) allocates an owned copy of it, but when the `Cow` points to a
+//! sized `Clone` type, [`HasGetterRef`](crate::HasGetterRef) borrows it instead.
+//!
+//! # Example
+//!
+//! ```rust
+//! use optics::{cow, HasGetterRef, HasSetter, HasTotalGetter};
+//! use std::borrow::Cow;
+//!
+//! let mut name: Cow<str> = Cow::Borrowed("main");
+//!
+//! assert_eq!(cow::owned().get(&name), "main".to_string());
+//! assert!(matches!(name, Cow::Borrowed(_)));
+//!
+//! cow::owned().set(&mut name, "aux".to_string());
+//! assert!(matches!(name, Cow::Owned(_)));
+//!
+//! let limits: Cow<[u16; 2]> = Cow::Borrowed(&[80, 443]);
+//! assert_eq!(cow::owned().try_get_ref(&limits), Ok(&[80, 443]));
+//! ```
+#[cfg(feature = "lens")]
+use crate::{HasGetter, HasGetterMut, HasGetterRef, HasModify, HasSetter, LensImpl};
+#[cfg(feature = "iso")]
+use crate::{Iso, IsoImpl, mapped_iso};
+#[cfg(any(feature = "lens", feature = "iso"))]
+use alloc::borrow::Cow;
+#[cfg(feature = "lens")]
+use alloc::borrow::ToOwned;
+#[cfg(feature = "iso")]
+use alloc::string::String;
+#[cfg(feature = "lens")]
+use core::convert::Infallible;
+#[cfg(feature = "lens")]
+use core::fmt::{Debug, Formatter};
+#[cfg(feature = "lens")]
+use core::marker::PhantomData;
+
+#[cfg(feature = "lens")]
+mod private {
+    use core::marker::PhantomData;
+
+    pub struct OwnedLens<B: ?Sized>(pub(super) PhantomData<fn(&B)>);
+}
+
+#[cfg(feature = "lens")]
+impl<B: ?Sized> Clone for private::OwnedLens<B> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+#[cfg(feature = "lens")]
+impl<B: ?Sized> Copy for private::OwnedLens<B> {}
+
+#[cfg(feature = "lens")]
+impl<B: ?Sized> Debug for private::OwnedLens<B> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str("OwnedLens")
+    }
+}
+
+#[cfg(feature = "lens")]
+impl<'a, B: ToOwned + ?Sized> HasGetter<Cow<'a, B>, B::Owned> for private::OwnedLens<B> {
+    type GetterError = Infallible;
+
+    fn try_get(&self, source: &Cow<'a, B>) -> Result<B::Owned, Self::GetterError> {
+        Ok((**source).to_owned())
+    }
+}
+
+#[cfg(feature = "lens")]
+impl<'a, B: Clone> HasGetterRef<Cow<'a, B>, B> for private::OwnedLens<B> {
+    fn try_get_ref<'s>(&self, source: &'s Cow<'a, B>) -> Result<&'s B, Self::GetterError>
+    where
+        Self: 's,
+    {
+        Ok(source)
+    }
+}
+
+#[cfg(feature = "lens")]
+impl<'a, B: ToOwned + ?Sized> HasGetterMut<Cow<'a, B>, B::Owned> for private::OwnedLens<B> {
+    fn try_get_mut<'s>(
+        &self,
+        source: &'s mut Cow<'a, B>,
+    ) -> Result<&'s mut B::Owned, Self::GetterError>
+    where
+        Self: 's,
+    {
+        Ok(source.to_mut())
+    }
+}
+
+#[cfg(feature = "lens")]
+impl<'a, B: ToOwned + ?Sized> HasSetter<Cow<'a, B>, B::Owned> for private::OwnedLens<B> {
+    fn set(&self, source: &mut Cow<'a, B>, value: B::Owned) {
+        *source = Cow::Owned(value);
+    }
+}
+
+#[cfg(feature = "lens")]
+impl<'a, B: ToOwned + ?Sized> HasModify<Cow<'a, B>, B::Owned> for private::OwnedLens<B> {
+    fn modify<F: FnOnce(&mut B::Owned)>(&self, source: &mut Cow<'a, B>, f: F) {
+        f(source.to_mut());
+    }
+}
+
+/// Creates a `Lens` focusing on the owned form of the value a `Cow` points to.
+///
+/// Retrieving the focus with [`HasGetter`] creates an owned copy of the value on every read,
+/// without changing the `Cow`. When the `Cow` points to a sized `Clone` type, whose owned form is
+/// the type itself, [`HasGetterRef`] borrows the value instead, whether the `Cow` is borrowed or
+/// owned.
+///
+/// Setting the focus replaces the `Cow` with an owned one holding the new value, without cloning
+/// the value it previously pointed to. Modifying the focus, and borrowing it with
+/// [`HasGetterMut`], promotes a borrowed `Cow` to an owned one with `Cow::to_mut`, cloning the
+/// value only if it was borrowed, so compositions writing a part of the focus clone it at most
+/// once.
+///
+/// # Type Parameters
+///
+/// - `B`: The borrowed type the `Cow` points to, such as `str` or `[T]`.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{cow, vec, HasGetterRef, HasSetter, HasTotalGetter};
+/// use std::borrow::Cow;
+///
+/// let mut ports: Cow<[u16]> = Cow::Borrowed(&[80, 443]);
+///
+/// assert_eq!(cow::owned().get(&ports), vec![80, 443]);
+/// cow::owned().compose_with_prism(vec::first()).set(&mut ports, 8080);
+/// assert_eq!(ports, Cow::<[u16]>::Owned(vec![8080, 443]));
+///
+/// let timeout: Cow<u64> = Cow::Borrowed(&30);
+/// assert_eq!(cow::owned().try_get_ref(&timeout), Ok(&30));
+/// ```
+#[cfg(feature = "lens")]
+#[must_use]
+pub fn owned<'a, B>() -> LensImpl<Cow<'a, B>, B::Owned, private::OwnedLens<B>>
+where
+    B: ToOwned + ?Sized,
+{
+    private::OwnedLens(PhantomData).into()
+}
+
+/// Creates an `Iso` between a `Cow<str>` and a `String`.
+///
+/// Converting a `String` back into a `Cow` always results in an owned `Cow`.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{cow, HasTotalGetter, HasTotalReverseGet};
+/// use std::borrow::Cow;
+///
+/// let iso = cow::string_iso();
+///
+/// assert_eq!(iso.get(&Cow::Borrowed("host")), "host".to_string());
+/// assert_eq!(iso.reverse_get(&"host".to_string()), Cow::<str>::Owned("host".to_string()));
+/// ```
+#[cfg(feature = "iso")]
+#[must_use]
+pub fn string_iso<'a>() -> IsoImpl<Cow<'a, str>, String, impl Iso<Cow<'a, str>, String>> {
+    mapped_iso(
+        |cow: &Cow<'a, str>| String::from(&**cow),
+        |s: &String| Cow::Owned(s.clone()),
+    )
+}
@@ -0,0 +1,150 @@
+//! Optics focusing on the elements of a `VecDeque`.
+//!
+//! [`front`], [`back`] and [`get`] focus on a single existing element of the deque, so that
+//! ring-buffer-style state can be addressed through optic chains. Setting the focus only replaces
+//! an existing element, it never grows the deque, while [`insert`](crate::HasInsert::insert)
+//! pushes a missing element.
+//!
+//! # Example
+//!
+//! ```rust
+//! use optics::{deque, HasGetter, HasSetter};
+//! use std::collections::VecDeque;
+//!
+//! let mut samples = VecDeque::from([1, 2, 3]);
+//!
+//! samples.push_back(4);
+//! samples.pop_front();
+//!
+//! assert_eq!(deque::front().try_get(&samples), Ok(2));
+//! deque::back().set(&mut samples, 5);
+//! assert_eq!(deque::get(2).try_get(&samples), Ok(5));
+//! ```
+#[cfg(feature = "prism")]
+use crate::IndexError;
+#[cfg(feature = "prism")]
+use crate::{HasInsert, Prism, PrismImpl, mapped_prism};
+#[cfg(feature = "prism")]
+use alloc::collections::VecDeque;
+
+/// Creates a `Prism` focusing on the front element of a `VecDeque`.
+///
+/// Retrieving the focus fails with an [`IndexError`] if the deque is empty. Setting the focus
+/// only replaces an existing element, it is a no-op on an empty deque, while
+/// [`insert`](crate::HasInsert::insert) pushes the value to the front of an empty deque.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{deque, HasGetter, IndexError};
+/// use std::collections::VecDeque;
+///
+/// assert_eq!(deque::front().try_get(&VecDeque::from([1, 2])), Ok(1));
+/// assert_eq!(
+///     deque::front::<u8>().try_get(&VecDeque::new()),
+///     Err(IndexError { index: 0, len: 0 })
+/// );
+/// ```
+#[cfg(feature = "prism")]
+#[must_use]
+pub fn front<T: Clone>() -> PrismImpl<
+    VecDeque<T>,
+    T,
+    impl Prism<VecDeque<T>, T, GetterError = IndexError> + HasInsert<VecDeque<T>, T>,
+> {
+    get(0).with_insert(|d: &mut VecDeque<T>, value| match d.front_mut() {
+        Some(element) => *element = value,
+        None => d.push_front(value),
+    })
+}
+
+/// Creates a `Prism` focusing on the back element of a `VecDeque`.
+///
+/// Retrieving the focus fails with an [`IndexError`] if the deque is empty. Setting the focus
+/// only replaces an existing element, it is a no-op on an empty deque, while
+/// [`insert`](crate::HasInsert::insert) pushes the value to the back of an empty deque.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{deque, HasGetter, HasSetter};
+/// use std::collections::VecDeque;
+///
+/// let mut d = VecDeque::from([1, 2]);
+///
+/// deque::back().set(&mut d, 3);
+/// assert_eq!(deque::back().try_get(&d), Ok(3));
+/// ```
+#[cfg(feature = "prism")]
+#[must_use]
+pub fn back<T: Clone>() -> PrismImpl<
+    VecDeque<T>,
+    T,
+    impl Prism<VecDeque<T>, T, GetterError = IndexError> + HasInsert<VecDeque<T>, T>,
+> {
+    mapped_prism(
+        |d: &VecDeque<T>| d.back().cloned().ok_or(IndexError { index: 0, len: 0 }),
+        |d: &mut VecDeque<T>, value| {
+            if let Some(element) = d.back_mut() {
+                *element = value;
+            }
+        },
+    )
+    .with_insert(|d: &mut VecDeque<T>, value| match d.back_mut() {
+        Some(element) => *element = value,
+        None => d.push_back(value),
+    })
+}
+
+/// Creates a `Prism` focusing on the element at `index` of a `VecDeque`, counted from its front.
+///
+/// Retrieving the focus fails with an [`IndexError`] if the index is out of bounds. Setting the
+/// focus only replaces an existing element, it is a no-op if the index is out of bounds, while
+/// [`insert`](crate::HasInsert::insert) pushes the value to the back of the deque if `index` is
+/// its length.
+///
+/// # Arguments
+///
+/// - `index` — The index of the focused element.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{deque, HasGetter, IndexError};
+/// use std::collections::VecDeque;
+///
+/// let d = VecDeque::from([1, 2, 3]);
+///
+/// assert_eq!(deque::get(1).try_get(&d), Ok(2));
+/// assert_eq!(deque::get::<u8>(3).try_get(&VecDeque::new()), Err(IndexError { index: 3, len: 0 }));
+/// ```
+#[cfg(feature = "prism")]
+#[must_use]
+pub fn get<T: Clone>(
+    index: usize,
+) -> PrismImpl<
+    VecDeque<T>,
+    T,
+    impl Prism<VecDeque<T>, T, GetterError = IndexError> + HasInsert<VecDeque<T>, T>,
+> {
+    mapped_prism(
+        move |d: &VecDeque<T>| {
+            d.get(index).cloned().ok_or(IndexError {
+                index,
+                len: d.len(),
+            })
+        },
+        move |d: &mut VecDeque<T>, value| {
+            if let Some(element) = d.get_mut(index) {
+                *element = value;
+            }
+        },
+    )
+    .with_insert(move |d: &mut VecDeque<T>, value| {
+        if let Some(element) = d.get_mut(index) {
+            *element = value;
+        } else if index == d.len() {
+            d.push_back(value);
+        }
+    })
+}
@@ -0,0 +1,57 @@
+//! The error shared by the optics of semi-structured document values.
+use crate::OpticsError;
+use alloc::string::String;
+use core::fmt::{Display, Formatter};
+
+/// The error returned when an optic can not focus on a part of a semi-structured document value,
+/// such as a JSON, TOML or YAML value.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DocumentError {
+    /// The value is of a different type than the one the optic focuses on, e.g. a string where
+    /// an object is expected.
+    TypeMismatch {
+        /// The type of value the optic expected.
+        expected: &'static str,
+        /// The type of value that was found.
+        found: &'static str,
+    },
+    /// The map has no entry under the key.
+    KeyMissing {
+        /// The key that was looked up.
+        key: String,
+    },
+    /// The index is out of bounds of the sequence.
+    IndexOutOfBounds {
+        /// The index that was requested.
+        index: usize,
+        /// The length of the sequence at the time of the access.
+        len: usize,
+    },
+}
+
+impl Display for DocumentError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DocumentError::TypeMismatch { expected, found } => {
+                write!(f, "expected {expected}, found {found}")
+            }
+            DocumentError::KeyMissing { key } => write!(f, "key {key:?} is missing"),
+            DocumentError::IndexOutOfBounds { index, len } => write!(
+                f,
+                "index {index} is out of bounds for a sequence of length {len}"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for DocumentError {}
+
+impl From<DocumentError> for OpticsError {
+    fn from(e: DocumentError) -> Self {
+        match e {
+            DocumentError::TypeMismatch { .. } => OpticsError::VariantMismatch,
+            DocumentError::KeyMissing { .. } => OpticsError::NoFocus,
+            DocumentError::IndexOutOfBounds { .. } => OpticsError::IndexOutOfBounds,
+        }
+    }
+}
@@ -0,0 +1,146 @@
+//! Fallible isos between strings and the bytes they encode.
+//!
+//! [`hex_iso`] and [`base64_iso`] decode a `String` into the bytes it encodes, so that
+//! credentials and blobs stored as strings can be edited as bytes through a composition. Decoding
+//! fails with a [`DecodeError`] if the string is not validly encoded, encoding can not fail.
+//!
+//! The strings are encoded and decoded with the `hex` and `base64` crates. These optics require
+//! the `encoding` feature.
+//!
+//! # Example
+//!
+//! ```rust
+//! use optics::{hex_iso, HasGetter, HasSetter};
+//!
+//! let mut key = "00ff".to_string();
+//!
+//! assert_eq!(hex_iso().try_get(&key), Ok(vec![0x00, 0xff]));
+//! hex_iso().set(&mut key, vec![0xca, 0xfe]);
+//! assert_eq!(key, "cafe");
+//! ```
+use crate::OpticsError;
+#[cfg(feature = "fallible-iso")]
+use crate::{FallibleIso, FallibleIsoImpl, mapped_fallible_iso};
+#[cfg(feature = "fallible-iso")]
+use alloc::{string::String, vec::Vec};
+#[cfg(feature = "fallible-iso")]
+use base64::{Engine, engine::general_purpose::STANDARD};
+#[cfg(feature = "fallible-iso")]
+use core::convert::Infallible;
+use core::fmt::{Display, Formatter};
+
+/// The error returned when a string can not be decoded into bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DecodeError {
+    /// The string contains a character that is not valid in the encoding, at the given byte index.
+    InvalidCharacter {
+        /// The byte index of the invalid character.
+        index: usize,
+    },
+    /// The length of the string is not valid for the encoding.
+    InvalidLength {
+        /// The length of the string in bytes.
+        len: usize,
+    },
+    /// The string is not padded as the encoding requires.
+    InvalidPadding,
+}
+
+impl Display for DecodeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DecodeError::InvalidCharacter { index } => {
+                write!(f, "invalid character at index {index}")
+            }
+            DecodeError::InvalidLength { len } => write!(f, "invalid encoded length {len}"),
+            DecodeError::InvalidPadding => write!(f, "invalid padding"),
+        }
+    }
+}
+
+impl core::error::Error for DecodeError {}
+
+impl From<DecodeError> for OpticsError {
+    fn from(_: DecodeError) -> Self {
+        OpticsError::ParseFailed
+    }
+}
+
+#[cfg(feature = "fallible-iso")]
+fn hex_decode(s: &str) -> Result<Vec<u8>, DecodeError> {
+    hex::decode(s).map_err(|error| match error {
+        hex::FromHexError::InvalidHexCharacter { index, .. } => {
+            DecodeError::InvalidCharacter { index }
+        }
+        hex::FromHexError::OddLength | hex::FromHexError::InvalidStringLength => {
+            DecodeError::InvalidLength { len: s.len() }
+        }
+    })
+}
+
+#[cfg(feature = "fallible-iso")]
+fn base64_decode(s: &str) -> Result<Vec<u8>, DecodeError> {
+    STANDARD.decode(s).map_err(|error| match error {
+        base64::DecodeError::InvalidByte(index, _)
+        | base64::DecodeError::InvalidLastSymbol(index, _) => {
+            DecodeError::InvalidCharacter { index }
+        }
+        base64::DecodeError::InvalidLength(_) => DecodeError::InvalidLength { len: s.len() },
+        base64::DecodeError::InvalidPadding => DecodeError::InvalidPadding,
+    })
+}
+
+/// Creates a `FallibleIso` between a hexadecimal `String` and the bytes it encodes.
+///
+/// Decoding accepts both lower and upper case digits, and fails with a [`DecodeError`] if the
+/// string has an odd length or contains a non-hexadecimal character. Encoding always produces
+/// lower case digits.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{hex_iso, DecodeError, HasGetter, HasTotalReverseGet};
+///
+/// assert_eq!(hex_iso().try_get(&"DEADbeef".to_string()), Ok(vec![0xde, 0xad, 0xbe, 0xef]));
+/// assert_eq!(hex_iso().try_get(&"abc".to_string()), Err(DecodeError::InvalidLength { len: 3 }));
+/// assert_eq!(hex_iso().reverse_get(&vec![1, 2]), "0102");
+/// ```
+#[cfg(feature = "fallible-iso")]
+#[must_use]
+pub fn hex_iso() -> FallibleIsoImpl<
+    String,
+    Vec<u8>,
+    impl FallibleIso<String, Vec<u8>, GetterError = DecodeError, ReverseError = Infallible>,
+> {
+    mapped_fallible_iso(
+        |s: &String| hex_decode(s),
+        |bytes: &Vec<u8>| Ok::<_, Infallible>(hex::encode(bytes)),
+    )
+}
+
+/// Creates a `FallibleIso` between a base64 `String` and the bytes it encodes.
+///
+/// The standard alphabet with padding is used, as defined in RFC 4648. Decoding fails with a
+/// [`DecodeError`] if the string is not padded to a multiple of four characters, contains a
+/// character outside the alphabet, or ends in a character whose unused bits are not zero.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{base64_iso, HasGetter, HasTotalReverseGet};
+///
+/// assert_eq!(base64_iso().try_get(&"aGVsbG8=".to_string()), Ok(b"hello".to_vec()));
+/// assert_eq!(base64_iso().reverse_get(&b"hi!?".to_vec()), "aGkhPw==");
+/// ```
+#[cfg(feature = "fallible-iso")]
+#[must_use]
+pub fn base64_iso() -> FallibleIsoImpl<
+    String,
+    Vec<u8>,
+    impl FallibleIso<String, Vec<u8>, GetterError = DecodeError, ReverseError = Infallible>,
+> {
+    mapped_fallible_iso(
+        |s: &String| base64_decode(s),
+        |bytes: &Vec<u8>| Ok::<_, Infallible>(STANDARD.encode(bytes)),
+    )
+}
@@ -0,0 +1,138 @@
+//! Configuration overrides read from `figment` providers.
+//!
+//! [`overrides`] looks up the path of every optic registered in a [`PatchRegistry`] in a
+//! `Figment`, and collects the values it finds into a [`Patch`], so settings coming from
+//! environment variables, command line arguments or any other provider can be layered over a
+//! typed configuration through the same optics that read and write it. The values are converted
+//! to the foci of the optics as patches convert them, with `FromStr` or a fallible iso given to
+//! [`PatchRegistry::register_with`].
+//!
+//! These overrides require the `figment` feature.
+//!
+//! # Example
+//!
+//! ```rust
+//! use ::figment::{Figment, providers::Serialized};
+//! use optics::{PatchRegistry, field_lens, figment};
+//!
+//! struct Server {
+//!     host: String,
+//!     port: u16,
+//! }
+//!
+//! let mut registry = PatchRegistry::new();
+//! registry
+//!     .register(field_lens!(Server, host))
+//!     .register(field_lens!(Server, port));
+//!
+//! let provider = Figment::from(Serialized::default("port", 9090));
+//! let mut server = Server { host: "localhost".to_string(), port: 8080 };
+//!
+//! figment::overrides(&provider, &registry)
+//!     .unwrap()
+//!     .apply(&mut server, &registry)
+//!     .unwrap();
+//! assert_eq!((server.host.as_str(), server.port), ("localhost", 9090));
+//! ```
+use crate::{Patch, PatchRegistry};
+use ::figment::value::Value;
+use ::figment::{Error, Figment};
+use alloc::string::{String, ToString};
+
+fn to_string(value: &Value) -> Option<String> {
+    match value {
+        Value::String(_, value) => Some(value.clone()),
+        Value::Char(_, value) => Some(value.to_string()),
+        Value::Bool(_, value) => Some(value.to_string()),
+        Value::Num(_, value) => value
+            .to_u128()
+            .map(|value| value.to_string())
+            .or_else(|| value.to_i128().map(|value| value.to_string()))
+            .or_else(|| value.to_f64().map(|value| value.to_string())),
+        Value::Empty(..) | Value::Dict(..) | Value::Array(..) => None,
+    }
+}
+
+/// Creates a [`Patch`] writing the values `figment` holds at the paths of the optics registered in
+/// `registry`.
+///
+/// Paths that `figment` holds no value at are left out of the patch, so the values they lead to
+/// keep their current value when the patch is applied. Strings, characters, booleans and numbers
+/// are written in their string form.
+///
+/// # Arguments
+///
+/// - `figment` — The layered providers to read the overrides from.
+/// - `registry` — The optics to look up the paths of, and to apply the patch through.
+///
+/// # Errors
+///
+/// Returns the `figment::Error` of the providers if they fail, or an invalid type error if a path
+/// leads to a dictionary, an array or an empty value.
+///
+/// # Example
+///
+/// ```rust
+/// use ::figment::{Figment, providers::Serialized};
+/// use optics::{PatchRegistry, field_lens, figment, parse_iso};
+/// use std::net::IpAddr;
+///
+/// struct Server {
+///     bind: Bind,
+/// }
+///
+/// #[derive(Clone)]
+/// struct Bind {
+///     address: IpAddr,
+///     backlog: u32,
+/// }
+///
+/// let mut registry = PatchRegistry::new();
+/// registry
+///     .register_with(
+///         field_lens!(Server, bind).compose_with_lens(field_lens!(Bind, address)),
+///         parse_iso::<IpAddr>(),
+///     )
+///     .register(field_lens!(Server, bind).compose_with_lens(field_lens!(Bind, backlog)));
+///
+/// let provider = Figment::new()
+///     .merge(Serialized::default("bind.address", "0.0.0.0"))
+///     .merge(Serialized::default("bind.backlog", 512));
+///
+/// let patch = figment::overrides(&provider, &registry).unwrap();
+/// assert_eq!(
+///     patch.entries().collect::<Vec<_>>(),
+///     [("bind.address", "0.0.0.0"), ("bind.backlog", "512")]
+/// );
+///
+/// let mut server = Server { bind: Bind { address: [127, 0, 0, 1].into(), backlog: 128 } };
+/// patch.apply(&mut server, &registry).unwrap();
+/// assert_eq!(server.bind.address, IpAddr::from([0, 0, 0, 0]));
+/// assert_eq!(server.bind.backlog, 512);
+///
+/// let nested = Figment::from(Serialized::default("bind.backlog", [1, 2]));
+/// assert!(figment::overrides(&nested, &registry).is_err());
+/// ```
+#[allow(clippy::result_large_err)]
+pub fn overrides<S>(figment: &Figment, registry: &PatchRegistry<'_, S>) -> Result<Patch, Error> {
+    let mut patch = Patch::new();
+
+    for path in registry.paths() {
+        let value = match figment.find_value(path) {
+            Ok(value) => value,
+            Err(error) if error.missing() => continue,
+            Err(error) => return Err(error),
+        };
+
+        let string = to_string(&value).ok_or_else(|| {
+            Error::from(::figment::error::Kind::InvalidType(
+                value.to_actual(),
+                "a string, a character, a boolean or a number".to_string(),
+            ))
+            .with_path(path)
+        })?;
+        patch.set(path, string);
+    }
+
+    Ok(patch)
+}
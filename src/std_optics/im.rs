@@ -0,0 +1,387 @@
+//! Optics focusing on the elements of the persistent collections of the `im` crate.
+//!
+//! These mirror the optics of the standard collections for `im::Vector`, `im::HashMap` and
+//! `im::OrdMap`: the `*_at` lenses focus on the presence of the value stored under a key as an
+//! `Option`, the `*_value` and [`vector_get`] prisms focus on a single existing element, and the
+//! `*_each` traversals focus on every element. Writes go through the in-place mutators of the
+//! collections, which only copy the nodes on the path to the written element and share the rest
+//! with the other versions of the collection, so a functional update does not clone the whole
+//! collection.
+//!
+//! These optics require the `im` feature.
+//!
+//! # Example
+//!
+//! ```rust
+//! use optics::{im, HasGetter, HasSetter};
+//! use ::im::Vector;
+//!
+//! let before: Vector<u32> = (0..10_000).collect();
+//! let mut after = before.clone();
+//!
+//! im::vector_get(42).set(&mut after, 0);
+//! assert_eq!(im::vector_get(42).try_get(&before), Ok(42));
+//! assert_eq!(im::vector_get(42).try_get(&after), Ok(0));
+//! ```
+#[cfg(feature = "prism")]
+use crate::{HasInsert, IndexError, KeyMissing, Prism, PrismImpl, mapped_prism};
+#[cfg(feature = "lens")]
+use crate::{Lens, LensImpl, mapped_lens};
+use crate::{Traversal, TraversalImpl, mapped_traversal};
+use ::im::{HashMap, OrdMap, Vector};
+use alloc::vec::Vec;
+use core::hash::Hash;
+
+/// Creates a `Prism` focusing on the element at `index` of an `im::Vector`.
+///
+/// Retrieving the focus fails with an [`IndexError`] if the index is out of bounds. Setting the
+/// focus only replaces an existing element, while [`insert`](crate::HasInsert::insert) also
+/// appends the value if `index` is the length of the vector.
+///
+/// # Arguments
+///
+/// - `index` — The index of the focused element.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{im, HasGetter, HasInsert, IndexError};
+/// use ::im::vector;
+///
+/// let mut hosts = vector!["main".to_string()];
+///
+/// im::vector_get(1).insert(&mut hosts, "aux".to_string());
+/// assert_eq!(im::vector_get(1).try_get(&hosts), Ok("aux".to_string()));
+/// assert_eq!(
+///     im::vector_get::<String>(2).try_get(&hosts),
+///     Err(IndexError { index: 2, len: 2 })
+/// );
+/// ```
+#[cfg(feature = "prism")]
+#[must_use]
+pub fn vector_get<T: Clone>(
+    index: usize,
+) -> PrismImpl<
+    Vector<T>,
+    T,
+    impl Prism<Vector<T>, T, GetterError = IndexError> + HasInsert<Vector<T>, T>,
+> {
+    mapped_prism(
+        move |v: &Vector<T>| {
+            v.get(index).cloned().ok_or(IndexError {
+                index,
+                len: v.len(),
+            })
+        },
+        move |v: &mut Vector<T>, value| {
+            if let Some(element) = v.get_mut(index) {
+                *element = value;
+            }
+        },
+    )
+    .with_insert(move |v: &mut Vector<T>, value| {
+        if let Some(element) = v.get_mut(index) {
+            *element = value;
+        } else if index == v.len() {
+            v.push_back(value);
+        }
+    })
+}
+
+/// Creates a `Traversal` focusing on every element of an `im::Vector`, in order.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{im, HasGetAll, HasTraversal};
+/// use ::im::vector;
+///
+/// let mut ports = vector![80u16, 443];
+///
+/// im::vector_each().over_all(&mut ports, &mut |p| p + 8000);
+/// assert_eq!(im::vector_each().get_all(&ports), vec![8080, 8443]);
+/// ```
+#[must_use]
+pub fn vector_each<T: Clone>() -> TraversalImpl<Vector<T>, T, impl Traversal<Vector<T>, T>> {
+    mapped_traversal(
+        |v: &Vector<T>, f: &mut dyn FnMut(T)| v.iter().cloned().for_each(f),
+        |v: &mut Vector<T>, f: &mut dyn FnMut(T) -> T| {
+            for element in v.iter_mut() {
+                *element = f(element.clone());
+            }
+        },
+    )
+}
+
+/// Creates a `Lens` focusing on the optional value stored under `key` in an `im::HashMap`.
+///
+/// Retrieving the focus returns `None` if there is no entry for the key. Setting `Some(value)`
+/// inserts or replaces the entry, while setting `None` removes it.
+///
+/// # Arguments
+///
+/// - `key` — The key of the focused entry.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{im, HasSetter, HasTotalGetter};
+/// use ::im::HashMap;
+///
+/// let mut env = HashMap::new();
+///
+/// im::hash_map_at("HOME").set(&mut env, Some("/root"));
+/// assert_eq!(im::hash_map_at("HOME").get(&env), Some("/root"));
+/// im::hash_map_at("HOME").set(&mut env, None);
+/// assert!(env.is_empty());
+/// ```
+#[cfg(feature = "lens")]
+#[must_use]
+#[allow(clippy::type_complexity)]
+pub fn hash_map_at<K, V>(
+    key: K,
+) -> LensImpl<HashMap<K, V>, Option<V>, impl Lens<HashMap<K, V>, Option<V>>>
+where
+    K: Clone + Eq + Hash,
+    V: Clone,
+{
+    let set_key = key.clone();
+
+    mapped_lens(
+        move |map: &HashMap<K, V>| map.get(&key).cloned(),
+        move |map: &mut HashMap<K, V>, value| match value {
+            Some(value) => {
+                map.insert(set_key.clone(), value);
+            }
+            None => {
+                map.remove(&set_key);
+            }
+        },
+    )
+}
+
+/// Creates a `Prism` focusing on the value stored under `key` in an `im::HashMap`.
+///
+/// Retrieving the focus fails with a [`KeyMissing`] error if there is no entry for the key.
+/// Setting the focus only replaces an existing value, while
+/// [`insert`](crate::HasInsert::insert) adds the entry.
+///
+/// # Arguments
+///
+/// - `key` — The key of the focused entry.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{im, HasGetter, HasSetter, KeyMissing};
+/// use ::im::hashmap;
+///
+/// let mut limits = hashmap! {"cpu" => 2u32};
+///
+/// im::hash_map_value("cpu").set(&mut limits, 4);
+/// im::hash_map_value("memory").set(&mut limits, 512);
+/// assert_eq!(im::hash_map_value("cpu").try_get(&limits), Ok(4));
+/// assert_eq!(
+///     im::hash_map_value("memory").try_get(&limits),
+///     Err(KeyMissing { key: "memory" })
+/// );
+/// ```
+#[cfg(feature = "prism")]
+#[must_use]
+#[allow(clippy::type_complexity)]
+pub fn hash_map_value<K, V>(
+    key: K,
+) -> PrismImpl<
+    HashMap<K, V>,
+    V,
+    impl Prism<HashMap<K, V>, V, GetterError = KeyMissing<K>> + HasInsert<HashMap<K, V>, V>,
+>
+where
+    K: Clone + Eq + Hash,
+    V: Clone,
+{
+    let set_key = key.clone();
+    let insert_key = key.clone();
+
+    mapped_prism(
+        move |map: &HashMap<K, V>| {
+            map.get(&key)
+                .cloned()
+                .ok_or_else(|| KeyMissing { key: key.clone() })
+        },
+        move |map: &mut HashMap<K, V>, value| {
+            if let Some(existing) = map.get_mut(&set_key) {
+                *existing = value;
+            }
+        },
+    )
+    .with_insert(move |map: &mut HashMap<K, V>, value| {
+        map.insert(insert_key.clone(), value);
+    })
+}
+
+/// Creates a `Traversal` focusing on every value of an `im::HashMap`, in the iteration order of
+/// the map.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{im, HasTraversal};
+/// use ::im::hashmap;
+///
+/// let mut limits = hashmap! {"cpu" => 2u32, "memory" => 512};
+///
+/// im::hash_map_each().over_all(&mut limits, &mut |limit| limit * 2);
+/// assert_eq!(limits, hashmap! {"cpu" => 4, "memory" => 1024});
+/// ```
+#[must_use]
+pub fn hash_map_each<K, V>() -> TraversalImpl<HashMap<K, V>, V, impl Traversal<HashMap<K, V>, V>>
+where
+    K: Clone + Eq + Hash,
+    V: Clone,
+{
+    mapped_traversal(
+        |map: &HashMap<K, V>, f: &mut dyn FnMut(V)| map.values().cloned().for_each(f),
+        |map: &mut HashMap<K, V>, f: &mut dyn FnMut(V) -> V| {
+            for (_, value) in map.iter_mut() {
+                *value = f(value.clone());
+            }
+        },
+    )
+}
+
+/// Creates a `Lens` focusing on the optional value stored under `key` in an `im::OrdMap`.
+///
+/// Retrieving the focus returns `None` if there is no entry for the key. Setting `Some(value)`
+/// inserts or replaces the entry, while setting `None` removes it.
+///
+/// # Arguments
+///
+/// - `key` — The key of the focused entry.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{im, HasSetter, HasTotalGetter};
+/// use ::im::OrdMap;
+///
+/// let mut scores = OrdMap::new();
+///
+/// im::ord_map_at("alice").set(&mut scores, Some(3));
+/// assert_eq!(im::ord_map_at("alice").get(&scores), Some(3));
+/// ```
+#[cfg(feature = "lens")]
+#[must_use]
+#[allow(clippy::type_complexity)]
+pub fn ord_map_at<K, V>(
+    key: K,
+) -> LensImpl<OrdMap<K, V>, Option<V>, impl Lens<OrdMap<K, V>, Option<V>>>
+where
+    K: Clone + Ord,
+    V: Clone,
+{
+    let set_key = key.clone();
+
+    mapped_lens(
+        move |map: &OrdMap<K, V>| map.get(&key).cloned(),
+        move |map: &mut OrdMap<K, V>, value| match value {
+            Some(value) => {
+                map.insert(set_key.clone(), value);
+            }
+            None => {
+                map.remove(&set_key);
+            }
+        },
+    )
+}
+
+/// Creates a `Prism` focusing on the value stored under `key` in an `im::OrdMap`.
+///
+/// Retrieving the focus fails with a [`KeyMissing`] error if there is no entry for the key.
+/// Setting the focus only replaces an existing value, while
+/// [`insert`](crate::HasInsert::insert) adds the entry.
+///
+/// # Arguments
+///
+/// - `key` — The key of the focused entry.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{im, HasGetter, HasInsert, KeyMissing};
+/// use ::im::OrdMap;
+///
+/// let mut scores = OrdMap::new();
+///
+/// assert_eq!(im::ord_map_value("bob").try_get(&scores), Err::<u32, _>(KeyMissing { key: "bob" }));
+/// im::ord_map_value("bob").insert(&mut scores, 5);
+/// assert_eq!(im::ord_map_value("bob").try_get(&scores), Ok(5));
+/// ```
+#[cfg(feature = "prism")]
+#[must_use]
+#[allow(clippy::type_complexity)]
+pub fn ord_map_value<K, V>(
+    key: K,
+) -> PrismImpl<
+    OrdMap<K, V>,
+    V,
+    impl Prism<OrdMap<K, V>, V, GetterError = KeyMissing<K>> + HasInsert<OrdMap<K, V>, V>,
+>
+where
+    K: Clone + Ord,
+    V: Clone,
+{
+    let set_key = key.clone();
+    let insert_key = key.clone();
+
+    mapped_prism(
+        move |map: &OrdMap<K, V>| {
+            map.get(&key)
+                .cloned()
+                .ok_or_else(|| KeyMissing { key: key.clone() })
+        },
+        move |map: &mut OrdMap<K, V>, value| {
+            if let Some(existing) = map.get_mut(&set_key) {
+                *existing = value;
+            }
+        },
+    )
+    .with_insert(move |map: &mut OrdMap<K, V>, value| {
+        map.insert(insert_key.clone(), value);
+    })
+}
+
+/// Creates a `Traversal` focusing on every value of an `im::OrdMap`, in the order of their keys.
+///
+/// `OrdMap` has no mutable iterator, so each value is written back through
+/// [`OrdMap::get_mut`], copying only the nodes on the path to it.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{im, HasGetAll, HasTraversal};
+/// use ::im::ordmap;
+///
+/// let mut scores = ordmap! {"alice" => 3u32, "bob" => 5};
+///
+/// im::ord_map_each().over_all(&mut scores, &mut |s| s + 1);
+/// assert_eq!(im::ord_map_each().get_all(&scores), vec![4, 6]);
+/// ```
+#[must_use]
+pub fn ord_map_each<K, V>() -> TraversalImpl<OrdMap<K, V>, V, impl Traversal<OrdMap<K, V>, V>>
+where
+    K: Clone + Ord,
+    V: Clone,
+{
+    mapped_traversal(
+        |map: &OrdMap<K, V>, f: &mut dyn FnMut(V)| map.values().cloned().for_each(f),
+        |map: &mut OrdMap<K, V>, f: &mut dyn FnMut(V) -> V| {
+            let keys: Vec<K> = map.keys().cloned().collect();
+            for key in keys {
+                if let Some(value) = map.get_mut(&key) {
+                    *value = f(value.clone());
+                }
+            }
+        },
+    )
+}
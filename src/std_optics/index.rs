@@ -0,0 +1,135 @@
+//! Optics focusing on an element of a `Vec` or a slice by its index.
+//!
+//! Unlike the closures usually written by hand for this purpose, these optics report a missing
+//! element with an [`IndexError`] carrying both the requested index and the actual length of the
+//! collection.
+//!
+//! # Example
+//!
+//! ```rust
+//! use optics::{index_prism, HasGetter, HasSetter, IndexError};
+//!
+//! let mut hosts = vec!["main".to_string(), "aux".to_string()];
+//!
+//! let second = index_prism(1);
+//! assert_eq!(second.try_get(&hosts), Ok("aux".to_string()));
+//! second.set(&mut hosts, "backup".to_string());
+//! assert_eq!(hosts, vec!["main".to_string(), "backup".to_string()]);
+//!
+//! let third = index_prism::<String>(2);
+//! assert_eq!(third.try_get(&hosts), Err(IndexError { index: 2, len: 2 }));
+//! ```
+use crate::{OpticsError, PartialGetter, PartialGetterImpl, mapped_partial_getter};
+use core::fmt::{Display, Formatter};
+#[cfg(all(feature = "alloc", feature = "prism"))]
+use {
+    crate::{Prism, PrismImpl, mapped_prism},
+    alloc::vec::Vec,
+};
+
+/// The error returned when an optic tries to focus on an element at an index that is out of
+/// bounds of the collection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IndexError {
+    /// The index that was requested.
+    pub index: usize,
+    /// The length of the collection at the time of the access.
+    pub len: usize,
+}
+
+impl Display for IndexError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "index {} is out of bounds for a collection of length {}",
+            self.index, self.len
+        )
+    }
+}
+
+impl core::error::Error for IndexError {}
+
+impl From<IndexError> for OpticsError {
+    fn from(_: IndexError) -> Self {
+        OpticsError::IndexOutOfBounds
+    }
+}
+
+fn checked_get<T: Clone>(slice: &[T], index: usize) -> Result<T, IndexError> {
+    slice.get(index).cloned().ok_or(IndexError {
+        index,
+        len: slice.len(),
+    })
+}
+
+/// Creates a `Prism` focusing on the element at `index` of a `Vec`.
+///
+/// Retrieving the focus fails with an [`IndexError`] if the index is out of bounds. Setting the
+/// focus only replaces an existing element, it is a no-op if the index is out of bounds.
+///
+/// # Type Parameters
+///
+/// - `T`: The type of the elements of the `Vec`. Must implement `Clone`.
+///
+/// # Arguments
+///
+/// - `index` — The index of the focused element.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{index_prism, HasGetter, HasSetter};
+///
+/// let mut ports = vec![80u16, 443];
+///
+/// index_prism(5).set(&mut ports, 8080);
+/// assert_eq!(ports, vec![80, 443]);
+/// assert_eq!(index_prism(0).try_get(&ports), Ok(80));
+/// ```
+#[cfg(all(feature = "alloc", feature = "prism"))]
+#[must_use]
+pub fn index_prism<T: Clone>(
+    index: usize,
+) -> PrismImpl<Vec<T>, T, impl Prism<Vec<T>, T, GetterError = IndexError>> {
+    mapped_prism(
+        move |v: &Vec<T>| checked_get(v, index),
+        move |v: &mut Vec<T>, value| {
+            if let Some(element) = v.get_mut(index) {
+                *element = value;
+            }
+        },
+    )
+}
+
+/// Creates a `PartialGetter` focusing on the element at `index` of a borrowed slice.
+///
+/// As a shared slice can not be written to, this is the read-only counterpart of [`index_prism`].
+/// Retrieving the focus fails with an [`IndexError`] if the index is out of bounds.
+///
+/// # Type Parameters
+///
+/// - `T`: The type of the elements of the slice. Must implement `Clone`.
+///
+/// # Arguments
+///
+/// - `index` — The index of the focused element.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{slice_index_getter, HasGetter, IndexError};
+///
+/// let ports = [80u16, 443];
+///
+/// assert_eq!(slice_index_getter(1).try_get(&&ports[..]), Ok(443));
+/// assert_eq!(
+///     slice_index_getter::<u16>(2).try_get(&&ports[..]),
+///     Err(IndexError { index: 2, len: 2 })
+/// );
+/// ```
+#[must_use]
+pub fn slice_index_getter<'a, T: Clone>(
+    index: usize,
+) -> PartialGetterImpl<&'a [T], T, impl PartialGetter<&'a [T], T, GetterError = IndexError>> {
+    mapped_partial_getter(move |s: &&'a [T]| checked_get(s, index))
+}
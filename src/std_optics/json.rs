@@ -0,0 +1,430 @@
+//! Optics focusing on the parts of a `serde_json::Value`.
+//!
+//! [`key`] and [`index`] focus on a member of an object and an element of an array, while
+//! [`as_str`] and [`as_u64`] convert a value to and from a string and an unsigned integer. They
+//! fail with a [`DocumentError`] if the value is not of the expected type, or the member or
+//! element is missing, so semi-structured JSON can be navigated with the same compositions as
+//! typed structs. [`pointer`] builds a prism from a JSON pointer parsed at runtime.
+//!
+//! These optics require the `json` feature.
+//!
+//! # Example
+//!
+//! ```rust
+//! use optics::{json, DocumentError, HasGetter, HasSetter};
+//! use serde_json::json;
+//!
+//! let mut config = json!({ "http": { "ports": [80, 8080] } });
+//! let port = json::key("http")
+//!     .compose_with_prism::<DocumentError, _, _>(json::key("ports"))
+//!     .compose_with_prism::<DocumentError, _, _>(json::index(1))
+//!     .compose_with_fallible_iso::<DocumentError, _, _>(json::as_u64());
+//!
+//! assert_eq!(port.try_get(&config), Ok(8080));
+//! port.set(&mut config, 8081);
+//! assert_eq!(config, json!({ "http": { "ports": [80, 8081] } }));
+//! ```
+#[cfg(any(feature = "prism", feature = "fallible-iso"))]
+use crate::DocumentError;
+use crate::OpticsError;
+#[cfg(feature = "fallible-iso")]
+use crate::{FallibleIso, FallibleIsoImpl, mapped_fallible_iso};
+#[cfg(feature = "prism")]
+use crate::{HasInsert, Prism, PrismImpl, mapped_prism};
+use alloc::string::String;
+#[cfg(feature = "prism")]
+use alloc::{string::ToString, vec::Vec};
+#[cfg(feature = "fallible-iso")]
+use core::convert::Infallible;
+use core::fmt::{Display, Formatter};
+#[cfg(any(feature = "prism", feature = "fallible-iso"))]
+use serde_json::Value;
+
+#[cfg(any(feature = "prism", feature = "fallible-iso"))]
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+#[cfg(any(feature = "prism", feature = "fallible-iso"))]
+fn mismatch(expected: &'static str, found: &Value) -> DocumentError {
+    DocumentError::TypeMismatch {
+        expected,
+        found: type_name(found),
+    }
+}
+
+/// Creates a `Prism` focusing on the member stored under `key` in a JSON object.
+///
+/// Retrieving the focus fails with a [`DocumentError`] if the value is not an object, or has no
+/// member under the key. Setting the focus only replaces an existing member, while
+/// [`insert`](crate::HasInsert::insert) adds it, turning a `null` value into an object first.
+///
+/// # Arguments
+///
+/// - `key` — The key of the focused member.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{json, DocumentError, HasGetter, HasInsert, HasSetter};
+/// use serde_json::{json, Value};
+///
+/// let mut config = Value::Null;
+///
+/// json::key("debug").set(&mut config, json!(true));
+/// assert_eq!(
+///     json::key("debug").try_get(&config),
+///     Err(DocumentError::TypeMismatch { expected: "object", found: "null" })
+/// );
+///
+/// json::key("debug").insert(&mut config, json!(true));
+/// assert_eq!(config, json!({ "debug": true }));
+/// ```
+#[cfg(feature = "prism")]
+#[must_use]
+pub fn key(
+    key: impl Into<String>,
+) -> PrismImpl<
+    Value,
+    Value,
+    impl Prism<Value, Value, GetterError = DocumentError> + HasInsert<Value, Value>,
+> {
+    let key = key.into();
+    let set_key = key.clone();
+    let insert_key = key.clone();
+
+    mapped_prism(
+        move |value: &Value| match value {
+            Value::Object(members) => members
+                .get(&key)
+                .cloned()
+                .ok_or_else(|| DocumentError::KeyMissing { key: key.clone() }),
+            other => Err(mismatch("object", other)),
+        },
+        move |value: &mut Value, member| {
+            if let Some(existing) = value.get_mut(&set_key) {
+                *existing = member;
+            }
+        },
+    )
+    .with_insert(move |value: &mut Value, member| {
+        if value.is_null() {
+            *value = Value::Object(serde_json::Map::new());
+        }
+        if let Value::Object(members) = value {
+            members.insert(insert_key.clone(), member);
+        }
+    })
+}
+
+/// Creates a `Prism` focusing on the element at `index` of a JSON array.
+///
+/// Retrieving the focus fails with a [`DocumentError`] if the value is not an array, or the index
+/// is out of its bounds. Setting the focus only replaces an existing element.
+///
+/// # Arguments
+///
+/// - `index` — The index of the focused element.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{json, DocumentError, HasGetter, HasSetter};
+/// use serde_json::json;
+///
+/// let mut hosts = json!(["main", "aux"]);
+///
+/// json::index(1).set(&mut hosts, json!("backup"));
+/// assert_eq!(hosts, json!(["main", "backup"]));
+/// assert_eq!(
+///     json::index(2).try_get(&hosts),
+///     Err(DocumentError::IndexOutOfBounds { index: 2, len: 2 })
+/// );
+/// ```
+#[cfg(feature = "prism")]
+#[must_use]
+pub fn index(
+    index: usize,
+) -> PrismImpl<Value, Value, impl Prism<Value, Value, GetterError = DocumentError>> {
+    mapped_prism(
+        move |value: &Value| match value {
+            Value::Array(elements) => {
+                elements
+                    .get(index)
+                    .cloned()
+                    .ok_or(DocumentError::IndexOutOfBounds {
+                        index,
+                        len: elements.len(),
+                    })
+            }
+            other => Err(mismatch("array", other)),
+        },
+        move |value: &mut Value, element| {
+            if let Some(existing) = value.get_mut(index) {
+                *existing = element;
+            }
+        },
+    )
+}
+
+/// Creates a `FallibleIso` between a JSON value and the string it contains.
+///
+/// Retrieving the focus fails with a [`DocumentError`] if the value is not a string. The reverse
+/// direction wraps the string into a value, and can not fail.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{json, HasGetter, HasTotalReverseGet};
+/// use serde_json::json;
+///
+/// assert_eq!(json::as_str().try_get(&json!("localhost")), Ok("localhost".to_string()));
+/// assert!(json::as_str().try_get(&json!(80)).is_err());
+/// assert_eq!(json::as_str().reverse_get(&"localhost".to_string()), json!("localhost"));
+/// ```
+#[cfg(feature = "fallible-iso")]
+#[must_use]
+pub fn as_str() -> FallibleIsoImpl<
+    Value,
+    String,
+    impl FallibleIso<Value, String, GetterError = DocumentError, ReverseError = Infallible>,
+> {
+    mapped_fallible_iso(
+        |value: &Value| match value {
+            Value::String(s) => Ok(s.clone()),
+            other => Err(mismatch("string", other)),
+        },
+        |s: &String| Ok::<_, Infallible>(Value::String(s.clone())),
+    )
+}
+
+/// Creates a `FallibleIso` between a JSON value and the unsigned integer it contains.
+///
+/// Retrieving the focus fails with a [`DocumentError`] if the value is not a number, or is a
+/// negative or fractional one. The reverse direction wraps the integer into a value, and can not
+/// fail.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{json, HasGetter, HasTotalReverseGet};
+/// use serde_json::json;
+///
+/// assert_eq!(json::as_u64().try_get(&json!(8080)), Ok(8080));
+/// assert!(json::as_u64().try_get(&json!(-1)).is_err());
+/// assert_eq!(json::as_u64().reverse_get(&443), json!(443));
+/// ```
+#[cfg(feature = "fallible-iso")]
+#[must_use]
+pub fn as_u64() -> FallibleIsoImpl<
+    Value,
+    u64,
+    impl FallibleIso<Value, u64, GetterError = DocumentError, ReverseError = Infallible>,
+> {
+    mapped_fallible_iso(
+        |value: &Value| match value {
+            Value::Number(n) => n.as_u64().ok_or(DocumentError::TypeMismatch {
+                expected: "unsigned integer",
+                found: "number",
+            }),
+            other => Err(mismatch("unsigned integer", other)),
+        },
+        |n: &u64| Ok::<_, Infallible>(Value::from(*n)),
+    )
+}
+
+/// The error returned when a string is not a valid JSON pointer.
+///
+/// A JSON pointer, as defined in RFC 6901, is either empty or starts with a `/`, and only uses `~`
+/// in the `~0` and `~1` escapes.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct InvalidPointer {
+    /// The string that could not be parsed.
+    pub pointer: String,
+}
+
+impl Display for InvalidPointer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{:?} is not a valid JSON pointer", self.pointer)
+    }
+}
+
+impl core::error::Error for InvalidPointer {}
+
+impl From<InvalidPointer> for OpticsError {
+    fn from(_: InvalidPointer) -> Self {
+        OpticsError::ParseFailed
+    }
+}
+
+#[cfg(feature = "prism")]
+fn parse_pointer(pointer: &str) -> Result<Vec<String>, InvalidPointer> {
+    let invalid = || InvalidPointer {
+        pointer: pointer.to_string(),
+    };
+
+    if pointer.is_empty() {
+        return Ok(Vec::new());
+    }
+    let rest = pointer.strip_prefix('/').ok_or_else(invalid)?;
+
+    rest.split('/')
+        .map(|token| {
+            let mut unescaped = String::with_capacity(token.len());
+            let mut chars = token.chars();
+            while let Some(c) = chars.next() {
+                if c != '~' {
+                    unescaped.push(c);
+                    continue;
+                }
+                match chars.next() {
+                    Some('0') => unescaped.push('~'),
+                    Some('1') => unescaped.push('/'),
+                    _ => return Err(invalid()),
+                }
+            }
+            Ok(unescaped)
+        })
+        .collect()
+}
+
+/// Parses a reference token into an array index, rejecting leading zeros and signs as RFC 6901
+/// requires.
+#[cfg(feature = "prism")]
+fn array_index(token: &str) -> Option<usize> {
+    let is_canonical = token == "0" || !token.starts_with('0');
+    let is_digits = !token.is_empty() && token.bytes().all(|b| b.is_ascii_digit());
+    (is_canonical && is_digits)
+        .then(|| token.parse().ok())
+        .flatten()
+}
+
+#[cfg(feature = "prism")]
+fn step<'a>(value: &'a Value, token: &str) -> Result<&'a Value, DocumentError> {
+    match value {
+        Value::Object(members) => members.get(token).ok_or_else(|| DocumentError::KeyMissing {
+            key: token.to_string(),
+        }),
+        Value::Array(elements) => {
+            let index = array_index(token).ok_or(DocumentError::TypeMismatch {
+                expected: "object",
+                found: "array",
+            })?;
+            elements.get(index).ok_or(DocumentError::IndexOutOfBounds {
+                index,
+                len: elements.len(),
+            })
+        }
+        other => Err(mismatch("object or array", other)),
+    }
+}
+
+#[cfg(feature = "prism")]
+fn step_mut<'a>(value: &'a mut Value, token: &str) -> Option<&'a mut Value> {
+    match value {
+        Value::Object(members) => members.get_mut(token),
+        Value::Array(elements) => elements.get_mut(array_index(token)?),
+        _ => None,
+    }
+}
+
+/// Creates a `Prism` focusing on the value a JSON pointer refers to, parsing the pointer at
+/// runtime.
+///
+/// The pointer follows RFC 6901: each `/`-separated token selects a member of an object or an
+/// element of an array, with `~1` standing for `/` and `~0` for `~` in member names, and the empty
+/// pointer refers to the whole value. This allows paths supplied by users, such as command line
+/// flags or API requests, to drive reads and writes.
+///
+/// Retrieving the focus fails with a [`DocumentError`] describing the first token that could not
+/// be followed. Setting the focus only replaces an existing value, while
+/// [`insert`](crate::HasInsert::insert) also adds the last member to its object, or appends an
+/// element to its array when the last token is `-` or the length of the array.
+///
+/// # Arguments
+///
+/// - `pointer` — The JSON pointer to the focused value.
+///
+/// # Errors
+///
+/// Returns an [`InvalidPointer`] error if `pointer` is not a valid JSON pointer.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{json, HasGetter, HasInsert, HasSetter};
+/// use serde_json::json;
+///
+/// let mut config = json!({ "http": { "bind_address": "127.0.0.1:80", "a/b": [] } });
+///
+/// let bind_address = json::pointer("/http/bind_address").unwrap();
+/// bind_address.set(&mut config, json!("0.0.0.0:80"));
+/// assert_eq!(bind_address.try_get(&config), Ok(json!("0.0.0.0:80")));
+///
+/// json::pointer("/http/a~1b/-").unwrap().insert(&mut config, json!(1));
+/// assert_eq!(config["http"]["a/b"], json!([1]));
+///
+/// assert!(json::pointer("http").is_err());
+/// ```
+#[cfg(feature = "prism")]
+#[allow(clippy::type_complexity)]
+pub fn pointer(
+    pointer: &str,
+) -> Result<
+    PrismImpl<
+        Value,
+        Value,
+        impl Prism<Value, Value, GetterError = DocumentError> + HasInsert<Value, Value>,
+    >,
+    InvalidPointer,
+> {
+    let tokens = parse_pointer(pointer)?;
+    let set_tokens = tokens.clone();
+    let insert_tokens = tokens.clone();
+
+    Ok(mapped_prism(
+        move |value: &Value| {
+            tokens
+                .iter()
+                .try_fold(value, |value, token| step(value, token))
+                .cloned()
+        },
+        move |value: &mut Value, focus| {
+            let target = set_tokens
+                .iter()
+                .try_fold(value, |value, token| step_mut(value, token));
+            if let Some(target) = target {
+                *target = focus;
+            }
+        },
+    )
+    .with_insert(move |value: &mut Value, focus| {
+        let Some((last, parents)) = insert_tokens.split_last() else {
+            *value = focus;
+            return;
+        };
+
+        match parents
+            .iter()
+            .try_fold(value, |value, token| step_mut(value, token))
+        {
+            Some(Value::Object(members)) => {
+                members.insert(last.clone(), focus);
+            }
+            Some(Value::Array(elements)) => match array_index(last) {
+                Some(index) if index < elements.len() => elements[index] = focus,
+                Some(index) if index == elements.len() => elements.push(focus),
+                None if last == "-" => elements.push(focus),
+                _ => {}
+            },
+            _ => {}
+        }
+    }))
+}
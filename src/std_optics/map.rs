@@ -0,0 +1,166 @@
+//! Optics focusing on the value stored under a key of a `HashMap`.
+//!
+//! [`at`] focuses on the presence of the value as an `Option`, so setting `None` removes the
+//! entry, while [`value`] focuses on the value itself and fails with a [`KeyMissing`] error when
+//! there is no entry for the key.
+//!
+//! The `HashMap` optics require the `std` feature, which is enabled by default.
+//!
+//! # Example
+//!
+//! ```rust
+//! use optics::{map, HasGetter, HasSetter, HasTotalGetter, KeyMissing};
+//! use std::collections::HashMap;
+//!
+//! let mut ports = HashMap::from([("http", 80u16)]);
+//!
+//! map::at("https").set(&mut ports, Some(443));
+//! assert_eq!(map::value("https").try_get(&ports), Ok(443));
+//!
+//! map::at("http").set(&mut ports, None);
+//! assert_eq!(map::at("http").get(&ports), None);
+//! assert_eq!(map::value("http").try_get(&ports), Err(KeyMissing { key: "http" }));
+//! ```
+use crate::OpticsError;
+#[cfg(all(feature = "std", feature = "prism"))]
+use crate::{HasInsert, Prism, PrismImpl, mapped_prism};
+#[cfg(all(feature = "std", feature = "lens"))]
+use crate::{Lens, LensImpl, mapped_lens};
+use core::fmt::{Debug, Display, Formatter};
+#[cfg(all(feature = "std", any(feature = "lens", feature = "prism")))]
+use {core::hash::Hash, std::collections::HashMap};
+
+/// The error returned when an optic tries to focus on the value of a key that is not present in
+/// the map.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyMissing<K> {
+    /// The key that was looked up.
+    pub key: K,
+}
+
+impl<K: Debug> Display for KeyMissing<K> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "key {:?} is missing from the map", self.key)
+    }
+}
+
+impl<K: Debug> core::error::Error for KeyMissing<K> {}
+
+impl<K> From<KeyMissing<K>> for OpticsError {
+    fn from(_: KeyMissing<K>) -> Self {
+        OpticsError::NoFocus
+    }
+}
+
+/// Creates a `Lens` focusing on the optional value stored under `key` in a `HashMap`.
+///
+/// Retrieving the focus returns `None` if there is no entry for the key. Setting `Some(value)`
+/// inserts or replaces the entry, while setting `None` removes it.
+///
+/// # Type Parameters
+///
+/// - `K`: The type of the keys of the map. Must implement `Clone`, `Eq` and `Hash`.
+/// - `V`: The type of the values of the map. Must implement `Clone`.
+///
+/// # Arguments
+///
+/// - `key` — The key of the focused entry.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{map, HasSetter, HasTotalGetter};
+/// use std::collections::HashMap;
+///
+/// let mut env = HashMap::new();
+/// let home = map::at("HOME".to_string());
+///
+/// assert_eq!(home.get(&env), None);
+/// home.set(&mut env, Some("/root".to_string()));
+/// assert_eq!(env.get("HOME"), Some(&"/root".to_string()));
+/// ```
+#[cfg(all(feature = "std", feature = "lens"))]
+#[must_use]
+#[allow(clippy::type_complexity)]
+pub fn at<K, V>(key: K) -> LensImpl<HashMap<K, V>, Option<V>, impl Lens<HashMap<K, V>, Option<V>>>
+where
+    K: Clone + Eq + Hash,
+    V: Clone,
+{
+    let set_key = key.clone();
+
+    mapped_lens(
+        move |map: &HashMap<K, V>| map.get(&key).cloned(),
+        move |map: &mut HashMap<K, V>, value| match value {
+            Some(value) => {
+                map.insert(set_key.clone(), value);
+            }
+            None => {
+                map.remove(&set_key);
+            }
+        },
+    )
+}
+
+/// Creates a `Prism` focusing on the value stored under `key` in a `HashMap`.
+///
+/// Retrieving the focus fails with a [`KeyMissing`] error if there is no entry for the key.
+/// Setting the focus only replaces an existing value, it is a no-op if there is no entry for the
+/// key, while [`insert`](crate::HasInsert::insert) adds the entry.
+///
+/// # Type Parameters
+///
+/// - `K`: The type of the keys of the map. Must implement `Clone`, `Eq` and `Hash`.
+/// - `V`: The type of the values of the map. Must implement `Clone`.
+///
+/// # Arguments
+///
+/// - `key` — The key of the focused entry.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{map, HasGetter, HasSetter, KeyMissing};
+/// use std::collections::HashMap;
+///
+/// let mut limits = HashMap::from([("cpu", 2u32)]);
+///
+/// map::value("cpu").set(&mut limits, 4);
+/// map::value("memory").set(&mut limits, 512);
+///
+/// assert_eq!(map::value("cpu").try_get(&limits), Ok(4));
+/// assert_eq!(map::value("memory").try_get(&limits), Err(KeyMissing { key: "memory" }));
+/// ```
+#[cfg(all(feature = "std", feature = "prism"))]
+#[must_use]
+#[allow(clippy::type_complexity)]
+pub fn value<K, V>(
+    key: K,
+) -> PrismImpl<
+    HashMap<K, V>,
+    V,
+    impl Prism<HashMap<K, V>, V, GetterError = KeyMissing<K>> + HasInsert<HashMap<K, V>, V>,
+>
+where
+    K: Clone + Eq + Hash,
+    V: Clone,
+{
+    let set_key = key.clone();
+    let insert_key = key.clone();
+
+    mapped_prism(
+        move |map: &HashMap<K, V>| {
+            map.get(&key)
+                .cloned()
+                .ok_or_else(|| KeyMissing { key: key.clone() })
+        },
+        move |map: &mut HashMap<K, V>, value| {
+            if let Some(existing) = map.get_mut(&set_key) {
+                *existing = value;
+            }
+        },
+    )
+    .with_insert(move |map: &mut HashMap<K, V>, value| {
+        map.insert(insert_key.clone(), value);
+    })
+}
@@ -0,0 +1,54 @@
+pub mod array;
+pub mod bits;
+#[cfg(feature = "alloc")]
+pub mod btree_map;
+pub mod cell;
+#[cfg(feature = "chrono")]
+pub mod chrono;
+#[cfg(feature = "config")]
+pub mod config;
+pub mod convert;
+#[cfg(feature = "alloc")]
+pub mod cow;
+#[cfg(feature = "alloc")]
+pub mod deque;
+#[cfg(any(feature = "json", feature = "toml", feature = "yaml"))]
+pub mod document;
+#[cfg(feature = "encoding")]
+pub mod encoding;
+#[cfg(feature = "figment")]
+pub mod figment;
+#[cfg(feature = "im")]
+pub mod im;
+pub mod index;
+#[cfg(feature = "json")]
+pub mod json;
+pub mod map;
+pub mod num;
+pub mod option;
+#[cfg(feature = "alloc")]
+pub mod pointer;
+#[cfg(feature = "bevy_reflect")]
+pub mod reflect;
+pub mod result;
+pub mod socket_addr;
+pub mod std_isos;
+#[cfg(feature = "alloc")]
+pub mod string;
+pub mod sync;
+pub mod time;
+#[cfg(feature = "time")]
+pub mod time_crate;
+#[cfg(feature = "toml")]
+pub mod toml;
+pub mod tuple;
+#[cfg(feature = "url")]
+pub mod url;
+#[cfg(feature = "uuid")]
+pub mod uuid;
+#[cfg(feature = "validator")]
+pub mod validator;
+#[cfg(feature = "alloc")]
+pub mod vec;
+#[cfg(feature = "yaml")]
+pub mod yaml;
@@ -0,0 +1,263 @@
+//! Fallible isos converting between the primitive integer types.
+//!
+//! Every `x_to_y()` function creates a [`FallibleIso`] converting an integer of type `x` to an
+//! integer of type `y` using [`TryFrom`] in both directions. A conversion fails with a
+//! [`NumericRangeError`] if the value is out of the range of the target type, regardless of
+//! whether it is a narrowing or a widening conversion, and of the direction.
+//!
+//! [`checked`] creates the same optic for any pair of types convertible into each other with
+//! [`TryFrom`].
+//!
+//! Every `nonzero_x()` function creates a [`FallibleIso`] between an integer of type `x` and its
+//! `NonZero` counterpart, failing with a [`ZeroError`] if the value is zero. The reverse direction
+//! can not fail, so values can be carried in their `NonZero` form through a chain of optics.
+//!
+//! # Example
+//!
+//! ```rust
+//! use optics::{num, HasGetter, HasReverseGet, NumericRangeError, ZeroError};
+//!
+//! assert_eq!(num::u32_to_u16().try_get(&3), Ok(3));
+//! assert_eq!(num::u32_to_u16().try_get(&70_000), Err(NumericRangeError));
+//! assert_eq!(num::i64_to_u32().try_get(&-1), Err(NumericRangeError));
+//! assert_eq!(num::u8_to_i8().try_reverse_get(&-1), Err(NumericRangeError));
+//! assert_eq!(num::nonzero_u16().try_get(&0), Err(ZeroError));
+//! ```
+use crate::OpticsError;
+#[cfg(feature = "fallible-iso")]
+use crate::{FallibleIso, FallibleIsoImpl, mapped_fallible_iso};
+#[cfg(feature = "fallible-iso")]
+use core::convert::Infallible;
+use core::fmt::{Display, Formatter};
+#[cfg(feature = "fallible-iso")]
+use core::num::{
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroIsize, NonZeroU8, NonZeroU16, NonZeroU32,
+    NonZeroU64, NonZeroUsize,
+};
+
+/// The error returned when a numeric conversion fails because the value is out of the range of
+/// the target type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NumericRangeError;
+
+impl Display for NumericRangeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "the value is out of the range of the target type")
+    }
+}
+
+impl core::error::Error for NumericRangeError {}
+
+impl From<NumericRangeError> for OpticsError {
+    fn from(_: NumericRangeError) -> Self {
+        OpticsError::OutOfRange
+    }
+}
+
+/// The error returned when converting an integer to its `NonZero` counterpart fails because the
+/// value is zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ZeroError;
+
+impl Display for ZeroError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "the value is zero")
+    }
+}
+
+impl core::error::Error for ZeroError {}
+
+impl From<ZeroError> for () {
+    fn from(_: ZeroError) -> Self {}
+}
+
+impl From<ZeroError> for OpticsError {
+    fn from(_: ZeroError) -> Self {
+        OpticsError::OutOfRange
+    }
+}
+
+/// Creates a `FallibleIso` between two types convertible into each other with [`TryFrom`],
+/// failing with a [`NumericRangeError`] in both directions.
+///
+/// # Type Parameters
+///
+/// - `S`: The source type. Must implement `Copy` and `TryFrom<A>`.
+/// - `A`: The focused type. Must implement `Copy` and `TryFrom<S>`.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{num, HasGetter};
+///
+/// assert_eq!(num::checked::<u128, u8>().try_get(&255), Ok(255));
+/// ```
+#[cfg(feature = "fallible-iso")]
+#[must_use]
+pub fn checked<S, A>() -> FallibleIsoImpl<
+    S,
+    A,
+    impl FallibleIso<S, A, GetterError = NumericRangeError, ReverseError = NumericRangeError>,
+>
+where
+    S: Copy + TryFrom<A>,
+    A: Copy + TryFrom<S>,
+{
+    mapped_fallible_iso(
+        |s: &S| A::try_from(*s).map_err(|_| NumericRangeError),
+        |a: &A| S::try_from(*a).map_err(|_| NumericRangeError),
+    )
+}
+
+macro_rules! numeric_isos {
+    ($($name:ident: $source:ty => $target:ty;)+) => {
+        $(
+            #[doc = concat!("Creates a `FallibleIso` converting a `", stringify!($source), "` to a `", stringify!($target), "`.")]
+            ///
+            /// Fails with a [`NumericRangeError`] if the value is out of the range of the target
+            /// type, in either direction.
+            #[cfg(feature = "fallible-iso")]
+            #[must_use]
+            pub fn $name() -> FallibleIsoImpl<
+                $source,
+                $target,
+                impl FallibleIso<
+                    $source,
+                    $target,
+                    GetterError = NumericRangeError,
+                    ReverseError = NumericRangeError,
+                >,
+            > {
+                checked()
+            }
+        )+
+    };
+}
+
+numeric_isos! {
+    u8_to_u16: u8 => u16;
+    u8_to_u32: u8 => u32;
+    u8_to_u64: u8 => u64;
+    u8_to_usize: u8 => usize;
+    u8_to_i8: u8 => i8;
+    u8_to_i16: u8 => i16;
+    u8_to_i32: u8 => i32;
+    u8_to_i64: u8 => i64;
+    u8_to_isize: u8 => isize;
+    u16_to_u8: u16 => u8;
+    u16_to_u32: u16 => u32;
+    u16_to_u64: u16 => u64;
+    u16_to_usize: u16 => usize;
+    u16_to_i8: u16 => i8;
+    u16_to_i16: u16 => i16;
+    u16_to_i32: u16 => i32;
+    u16_to_i64: u16 => i64;
+    u16_to_isize: u16 => isize;
+    u32_to_u8: u32 => u8;
+    u32_to_u16: u32 => u16;
+    u32_to_u64: u32 => u64;
+    u32_to_usize: u32 => usize;
+    u32_to_i8: u32 => i8;
+    u32_to_i16: u32 => i16;
+    u32_to_i32: u32 => i32;
+    u32_to_i64: u32 => i64;
+    u32_to_isize: u32 => isize;
+    u64_to_u8: u64 => u8;
+    u64_to_u16: u64 => u16;
+    u64_to_u32: u64 => u32;
+    u64_to_usize: u64 => usize;
+    u64_to_i8: u64 => i8;
+    u64_to_i16: u64 => i16;
+    u64_to_i32: u64 => i32;
+    u64_to_i64: u64 => i64;
+    u64_to_isize: u64 => isize;
+    usize_to_u8: usize => u8;
+    usize_to_u16: usize => u16;
+    usize_to_u32: usize => u32;
+    usize_to_u64: usize => u64;
+    usize_to_i8: usize => i8;
+    usize_to_i16: usize => i16;
+    usize_to_i32: usize => i32;
+    usize_to_i64: usize => i64;
+    usize_to_isize: usize => isize;
+    i8_to_u8: i8 => u8;
+    i8_to_u16: i8 => u16;
+    i8_to_u32: i8 => u32;
+    i8_to_u64: i8 => u64;
+    i8_to_usize: i8 => usize;
+    i8_to_i16: i8 => i16;
+    i8_to_i32: i8 => i32;
+    i8_to_i64: i8 => i64;
+    i8_to_isize: i8 => isize;
+    i16_to_u8: i16 => u8;
+    i16_to_u16: i16 => u16;
+    i16_to_u32: i16 => u32;
+    i16_to_u64: i16 => u64;
+    i16_to_usize: i16 => usize;
+    i16_to_i8: i16 => i8;
+    i16_to_i32: i16 => i32;
+    i16_to_i64: i16 => i64;
+    i16_to_isize: i16 => isize;
+    i32_to_u8: i32 => u8;
+    i32_to_u16: i32 => u16;
+    i32_to_u32: i32 => u32;
+    i32_to_u64: i32 => u64;
+    i32_to_usize: i32 => usize;
+    i32_to_i8: i32 => i8;
+    i32_to_i16: i32 => i16;
+    i32_to_i64: i32 => i64;
+    i32_to_isize: i32 => isize;
+    i64_to_u8: i64 => u8;
+    i64_to_u16: i64 => u16;
+    i64_to_u32: i64 => u32;
+    i64_to_u64: i64 => u64;
+    i64_to_usize: i64 => usize;
+    i64_to_i8: i64 => i8;
+    i64_to_i16: i64 => i16;
+    i64_to_i32: i64 => i32;
+    i64_to_isize: i64 => isize;
+    isize_to_u8: isize => u8;
+    isize_to_u16: isize => u16;
+    isize_to_u32: isize => u32;
+    isize_to_u64: isize => u64;
+    isize_to_usize: isize => usize;
+    isize_to_i8: isize => i8;
+    isize_to_i16: isize => i16;
+    isize_to_i32: isize => i32;
+    isize_to_i64: isize => i64;
+}
+
+macro_rules! nonzero_isos {
+    ($($name:ident: $source:ty => $target:ty;)+) => {
+        $(
+            #[doc = concat!("Creates a `FallibleIso` converting a `", stringify!($source), "` to a `", stringify!($target), "`.")]
+            ///
+            /// Fails with a [`ZeroError`] if the value is zero. The reverse direction can not fail.
+            #[cfg(feature = "fallible-iso")]
+            #[must_use]
+            pub fn $name() -> FallibleIsoImpl<
+                $source,
+                $target,
+                impl FallibleIso<$source, $target, GetterError = ZeroError, ReverseError = Infallible>,
+            > {
+                mapped_fallible_iso(
+                    |s: &$source| <$target>::new(*s).ok_or(ZeroError),
+                    |a: &$target| Ok::<_, Infallible>(a.get()),
+                )
+            }
+        )+
+    };
+}
+
+nonzero_isos! {
+    nonzero_u8: u8 => NonZeroU8;
+    nonzero_u16: u16 => NonZeroU16;
+    nonzero_u32: u32 => NonZeroU32;
+    nonzero_u64: u64 => NonZeroU64;
+    nonzero_usize: usize => NonZeroUsize;
+    nonzero_i8: i8 => NonZeroI8;
+    nonzero_i16: i16 => NonZeroI16;
+    nonzero_i32: i32 => NonZeroI32;
+    nonzero_i64: i64 => NonZeroI64;
+    nonzero_isize: isize => NonZeroIsize;
+}
@@ -0,0 +1,138 @@
+//! Prisms focusing on the variants of an `Option`.
+//!
+//! [`some`] focuses on the value of a `Some`, while [`none`] matches a `None`. As their errors
+//! convert into [`OpticsError`](crate::OpticsError), they compose with the prisms created by
+//! [`enum_prism!`](crate::enum_prism), and as they convert into `()`, with hand-written prisms
+//! using `()` as their error.
+//!
+//! # Example
+//!
+//! ```rust
+//! use optics::{field_lens, option, HasGetter, HasSetter, LensImpl, NoneError};
+//!
+//! #[derive(Clone)]
+//! struct DatabaseConfig {
+//!     port: Option<u16>,
+//! }
+//!
+//! let port_lens: LensImpl<DatabaseConfig, Option<u16>, _> = field_lens!(DatabaseConfig, port);
+//! let port = port_lens.compose_with_prism(option::some());
+//!
+//! let mut db = DatabaseConfig { port: None };
+//! assert_eq!(port.try_get(&db), Err(NoneError));
+//!
+//! port.set(&mut db, 5432);
+//! assert_eq!(port.try_get(&db), Ok(5432));
+//! ```
+use crate::OpticsError;
+#[cfg(feature = "prism")]
+use crate::{HasInsert, Prism, PrismImpl, mapped_prism};
+use core::fmt::{Display, Formatter};
+
+/// The error returned when an optic tries to focus on the value of an `Option` that is `None`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NoneError;
+
+impl Display for NoneError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "the value is None")
+    }
+}
+
+impl core::error::Error for NoneError {}
+
+impl From<NoneError> for () {
+    fn from(_: NoneError) -> Self {}
+}
+
+impl From<NoneError> for OpticsError {
+    fn from(_: NoneError) -> Self {
+        OpticsError::NoFocus
+    }
+}
+
+/// The error returned when an optic expects an `Option` to be `None`, but it is a `Some`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SomeError;
+
+impl Display for SomeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "the value is Some")
+    }
+}
+
+impl core::error::Error for SomeError {}
+
+impl From<SomeError> for () {
+    fn from(_: SomeError) -> Self {}
+}
+
+impl From<SomeError> for OpticsError {
+    fn from(_: SomeError) -> Self {
+        OpticsError::VariantMismatch
+    }
+}
+
+/// Creates a `Prism` focusing on the value inside the `Some` variant of an `Option`.
+///
+/// Retrieving the focus fails with a [`NoneError`] if the option is `None`. Setting or
+/// [inserting](crate::HasInsert::insert) the focus always results in a `Some` holding the new
+/// value.
+///
+/// # Type Parameters
+///
+/// - `T`: The type of the value inside the option. Must implement `Clone`.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{option, HasGetter, HasSetter, NoneError};
+///
+/// let mut timeout = None;
+///
+/// assert_eq!(option::some::<u32>().try_get(&timeout), Err(NoneError));
+/// option::some().set(&mut timeout, 30);
+/// assert_eq!(timeout, Some(30));
+/// ```
+#[cfg(feature = "prism")]
+#[must_use]
+pub fn some<T: Clone>() -> PrismImpl<
+    Option<T>,
+    T,
+    impl Prism<Option<T>, T, GetterError = NoneError> + HasInsert<Option<T>, T>,
+> {
+    mapped_prism(
+        |o: &Option<T>| o.clone().ok_or(NoneError),
+        |o: &mut Option<T>, value| *o = Some(value),
+    )
+    .with_insert(|o: &mut Option<T>, value| *o = Some(value))
+}
+
+/// Creates a `Prism` matching the `None` variant of an `Option`.
+///
+/// Retrieving the focus succeeds with `()` if the option is `None`, and fails with a
+/// [`SomeError`] otherwise. Setting the focus always results in a `None`.
+///
+/// # Type Parameters
+///
+/// - `T`: The type of the value inside the option.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{option, HasGetter, HasSetter, SomeError};
+///
+/// let mut timeout = Some(30);
+///
+/// assert_eq!(option::none::<u32>().try_get(&timeout), Err(SomeError));
+/// option::none().set(&mut timeout, ());
+/// assert_eq!(timeout, None);
+/// ```
+#[cfg(feature = "prism")]
+#[must_use]
+pub fn none<T>() -> PrismImpl<Option<T>, (), impl Prism<Option<T>, (), GetterError = SomeError>> {
+    mapped_prism(
+        |o: &Option<T>| if o.is_none() { Ok(()) } else { Err(SomeError) },
+        |o: &mut Option<T>, ()| *o = None,
+    )
+}
@@ -0,0 +1,107 @@
+//! Lenses focusing through smart pointers.
+//!
+//! [`boxed`], [`rc_make_mut`] and [`arc_make_mut`] focus on the value behind a `Box`, an `Rc`
+//! and an `Arc` respectively, so that optic chains can cross pointer boundaries without custom
+//! closures. Setting the value behind a shared pointer uses `make_mut`, cloning the value first
+//! if it is shared with other pointers, so the other owners never observe the change.
+//!
+//! # Example
+//!
+//! ```rust
+//! use optics::{arc_make_mut, HasSetter, HasTotalGetter};
+//! use std::sync::Arc;
+//!
+//! let mut config = Arc::new(8080u16);
+//! let snapshot = Arc::clone(&config);
+//!
+//! arc_make_mut().set(&mut config, 8081);
+//!
+//! assert_eq!(arc_make_mut().get(&config), 8081);
+//! assert_eq!(*snapshot, 8080);
+//! ```
+#[cfg(feature = "lens")]
+use crate::{Lens, LensImpl, mapped_lens};
+#[cfg(feature = "lens")]
+use alloc::{boxed::Box, rc::Rc, sync::Arc};
+
+/// Creates a `Lens` focusing on the value inside a `Box`.
+///
+/// # Type Parameters
+///
+/// - `T`: The type of the boxed value. Must implement `Clone`.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{boxed, HasSetter, HasTotalGetter};
+///
+/// let mut b = Box::new(1);
+///
+/// boxed().set(&mut b, 2);
+/// assert_eq!(boxed().get(&b), 2);
+/// ```
+#[cfg(feature = "lens")]
+#[must_use]
+pub fn boxed<T: Clone>() -> LensImpl<Box<T>, T, impl Lens<Box<T>, T>> {
+    mapped_lens::<Box<T>, T, _, _>(|b| (**b).clone(), |b, value| **b = value)
+}
+
+/// Creates a `Lens` focusing on the value inside an `Rc`.
+///
+/// Setting the focus uses [`Rc::make_mut`], so if the value is shared with other `Rc`s, it is
+/// cloned first, and only the `Rc` passed to the setter is updated.
+///
+/// # Type Parameters
+///
+/// - `T`: The type of the shared value. Must implement `Clone`.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{rc_make_mut, HasSetter, HasTotalGetter};
+/// use std::rc::Rc;
+///
+/// let mut rc = Rc::new(1);
+/// let other = Rc::clone(&rc);
+///
+/// rc_make_mut().set(&mut rc, 2);
+/// assert_eq!(rc_make_mut().get(&rc), 2);
+/// assert_eq!(*other, 1);
+/// ```
+#[cfg(feature = "lens")]
+#[must_use]
+pub fn rc_make_mut<T: Clone>() -> LensImpl<Rc<T>, T, impl Lens<Rc<T>, T>> {
+    mapped_lens(
+        |rc: &Rc<T>| (**rc).clone(),
+        |rc: &mut Rc<T>, value| *Rc::make_mut(rc) = value,
+    )
+}
+
+/// Creates a `Lens` focusing on the value inside an `Arc`.
+///
+/// Setting the focus uses [`Arc::make_mut`], so if the value is shared with other `Arc`s, it is
+/// cloned first, and only the `Arc` passed to the setter is updated.
+///
+/// # Type Parameters
+///
+/// - `T`: The type of the shared value. Must implement `Clone`.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{arc_make_mut, HasSetter, HasTotalGetter};
+/// use std::sync::Arc;
+///
+/// let mut arc = Arc::new("a".to_string());
+///
+/// arc_make_mut().set(&mut arc, "b".to_string());
+/// assert_eq!(arc_make_mut().get(&arc), "b");
+/// ```
+#[cfg(feature = "lens")]
+#[must_use]
+pub fn arc_make_mut<T: Clone>() -> LensImpl<Arc<T>, T, impl Lens<Arc<T>, T>> {
+    mapped_lens(
+        |arc: &Arc<T>| (**arc).clone(),
+        |arc: &mut Arc<T>, value| *Arc::make_mut(arc) = value,
+    )
+}
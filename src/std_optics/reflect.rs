@@ -0,0 +1,179 @@
+//! Optics built from `bevy_reflect` reflection paths at runtime.
+//!
+//! [`reflect_lens`] resolves a path such as `"foo.bar[2]"` against any type implementing
+//! `Reflect`, so editors and inspectors that only know the path of a field at runtime can read
+//! and write it through the same optic interfaces as statically built optics, and compose it with
+//! them.
+//!
+//! These optics require the `bevy_reflect` feature.
+//!
+//! # Example
+//!
+//! ```rust
+//! use bevy_reflect::Reflect;
+//! use optics::{HasGetter, HasSetter, reflect_lens};
+//!
+//! #[derive(Reflect)]
+//! struct Transform {
+//!     translation: [f32; 3],
+//! }
+//!
+//! let mut transform = Transform { translation: [0.0, 1.0, 2.0] };
+//! let y = reflect_lens::<Transform, f32>("translation[1]").unwrap();
+//!
+//! y.set(&mut transform, 5.0);
+//! assert_eq!(y.try_get(&transform), Ok(5.0));
+//! ```
+use crate::OpticsError;
+#[cfg(feature = "prism")]
+use crate::{Prism, PrismImpl, mapped_prism};
+#[cfg(feature = "prism")]
+use ::bevy_reflect::{ParsedPath, Reflect, ReflectPath, ReflectPathError};
+use alloc::string::String;
+#[cfg(feature = "prism")]
+use alloc::string::ToString;
+use core::fmt::{Display, Formatter};
+
+/// The error returned when a reflection path can not be parsed or does not lead to a value of
+/// the focused type.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ReflectError {
+    /// The path could not be parsed.
+    InvalidPath {
+        /// The path that could not be parsed.
+        path: String,
+        /// Why the path could not be parsed.
+        reason: String,
+    },
+    /// The path does not lead to a value in the source, e.g. because a list is too short or an
+    /// enum holds another variant.
+    Inaccessible {
+        /// The path that could not be followed.
+        path: String,
+        /// Why the path could not be followed.
+        reason: String,
+    },
+    /// The path leads to a value of another type than the focused one.
+    TypeMismatch {
+        /// The path of the value.
+        path: String,
+        /// The name of the focused type.
+        expected: &'static str,
+    },
+}
+
+impl Display for ReflectError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ReflectError::InvalidPath { path, reason } => {
+                write!(f, "{path:?} is not a valid reflection path: {reason}")
+            }
+            ReflectError::Inaccessible { path, reason } => {
+                write!(f, "{path:?} can not be accessed: {reason}")
+            }
+            ReflectError::TypeMismatch { path, expected } => {
+                write!(f, "{path:?} does not lead to a value of type {expected}")
+            }
+        }
+    }
+}
+
+impl core::error::Error for ReflectError {}
+
+impl From<ReflectError> for OpticsError {
+    fn from(error: ReflectError) -> Self {
+        match error {
+            ReflectError::InvalidPath { .. } => OpticsError::ParseFailed,
+            ReflectError::Inaccessible { .. } => OpticsError::NoFocus,
+            ReflectError::TypeMismatch { .. } => OpticsError::VariantMismatch,
+        }
+    }
+}
+
+#[cfg(feature = "prism")]
+fn reflect_error<A>(path: &ParsedPath, error: &ReflectPathError<'_>) -> ReflectError {
+    match error {
+        ReflectPathError::InvalidDowncast => ReflectError::TypeMismatch {
+            path: path.to_string(),
+            expected: core::any::type_name::<A>(),
+        },
+        error => ReflectError::Inaccessible {
+            path: path.to_string(),
+            reason: error.to_string(),
+        },
+    }
+}
+
+/// Creates a `Prism` focusing on the value found at the reflection path `path` in a source of
+/// type `S`.
+///
+/// The path uses the syntax of `bevy_reflect` paths: `.field` for named fields, `.0` for tuple
+/// fields, `#0` for fields by index and `[2]` for list elements, as in `"foo.bar[2]"`. It is
+/// parsed once, when the prism is created.
+///
+/// Retrieving the focus fails with a [`ReflectError`] if the path can not be followed in the
+/// source, or leads to a value that is not an `A`. Setting the focus is a no-op in those cases.
+///
+/// # Errors
+///
+/// Returns a [`ReflectError::InvalidPath`] error if `path` is not a valid reflection path.
+///
+/// # Example
+///
+/// ```rust
+/// use bevy_reflect::Reflect;
+/// use optics::{HasGetter, HasSetter, ReflectError, reflect_lens};
+///
+/// #[derive(Reflect)]
+/// struct Scene {
+///     lights: Vec<Light>,
+/// }
+///
+/// #[derive(Reflect)]
+/// struct Light {
+///     intensity: f32,
+/// }
+///
+/// let mut scene = Scene { lights: vec![Light { intensity: 1.0 }] };
+///
+/// let first = reflect_lens::<Scene, f32>("lights[0].intensity").unwrap();
+/// first.set(&mut scene, 0.5);
+/// assert_eq!(first.try_get(&scene), Ok(0.5));
+///
+/// let second = reflect_lens::<Scene, f32>("lights[1].intensity").unwrap();
+/// assert!(matches!(second.try_get(&scene), Err(ReflectError::Inaccessible { .. })));
+///
+/// let as_int = reflect_lens::<Scene, u32>("lights[0].intensity").unwrap();
+/// assert!(matches!(as_int.try_get(&scene), Err(ReflectError::TypeMismatch { .. })));
+///
+/// assert!(reflect_lens::<Scene, f32>("lights[").is_err());
+/// ```
+#[cfg(feature = "prism")]
+#[allow(clippy::type_complexity)]
+pub fn reflect_lens<S, A>(
+    path: &str,
+) -> Result<PrismImpl<S, A, impl Prism<S, A, GetterError = ReflectError>>, ReflectError>
+where
+    S: Reflect,
+    A: Reflect + Clone,
+{
+    let parsed = ParsedPath::parse(path).map_err(|error| ReflectError::InvalidPath {
+        path: path.to_string(),
+        reason: error.to_string(),
+    })?;
+    let set_parsed = parsed.clone();
+
+    Ok(mapped_prism(
+        move |source: &S| {
+            (&parsed)
+                .element::<A>(source.as_partial_reflect())
+                .cloned()
+                .map_err(|error| reflect_error::<A>(&parsed, &error))
+        },
+        move |source: &mut S, value: A| {
+            if let Ok(focus) = (&set_parsed).element_mut::<A>(source.as_partial_reflect_mut()) {
+                *focus = value;
+            }
+        },
+    ))
+}
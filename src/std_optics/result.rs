@@ -0,0 +1,109 @@
+//! Prisms focusing on the variants of a `Result`.
+//!
+//! [`ok`] focuses on the value of an `Ok`, and fails with the error held by the `Err` variant,
+//! while [`err`] focuses on the error of an `Err`, and fails with an [`UnexpectedOk`] holding the
+//! value of the `Ok` variant. This way the value of the other side is never lost when the prism
+//! fails to focus.
+//!
+//! # Example
+//!
+//! ```rust
+//! use optics::{result, HasGetter, HasSetter, UnexpectedOk};
+//!
+//! let mut create_result: Result<String, String> = Err("disk full".to_string());
+//!
+//! assert_eq!(result::ok().try_get(&create_result), Err("disk full".to_string()));
+//! result::ok().set(&mut create_result, "created".to_string());
+//!
+//! assert_eq!(
+//!     result::err::<String, String>().try_get(&create_result),
+//!     Err(UnexpectedOk("created".to_string()))
+//! );
+//! ```
+use crate::OpticsError;
+#[cfg(feature = "prism")]
+use crate::{Prism, PrismImpl, mapped_prism};
+use core::fmt::{Debug, Display, Formatter};
+
+/// The error returned when an optic tries to focus on the error of a `Result` that is `Ok`.
+///
+/// It holds the value of the `Ok` variant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UnexpectedOk<T>(pub T);
+
+impl<T: Debug> Display for UnexpectedOk<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "expected an Err, found Ok({:?})", self.0)
+    }
+}
+
+impl<T: Debug> core::error::Error for UnexpectedOk<T> {}
+
+impl<T> From<UnexpectedOk<T>> for OpticsError {
+    fn from(_: UnexpectedOk<T>) -> Self {
+        OpticsError::VariantMismatch
+    }
+}
+
+/// Creates a `Prism` focusing on the value inside the `Ok` variant of a `Result`.
+///
+/// Retrieving the focus fails with the error held by the `Err` variant. Setting the focus always
+/// results in an `Ok` holding the new value.
+///
+/// # Type Parameters
+///
+/// - `T`: The type of the success value. Must implement `Clone`.
+/// - `E`: The type of the error value. Must implement `Clone`.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{result, HasGetter};
+///
+/// assert_eq!(result::ok().try_get(&Ok::<u8, String>(1)), Ok(1));
+/// assert_eq!(result::ok::<u8, u8>().try_get(&Err(2)), Err(2));
+/// ```
+#[cfg(feature = "prism")]
+#[must_use]
+pub fn ok<T: Clone, E: Clone>()
+-> PrismImpl<Result<T, E>, T, impl Prism<Result<T, E>, T, GetterError = E>> {
+    mapped_prism(
+        |r: &Result<T, E>| r.clone(),
+        |r: &mut Result<T, E>, value| *r = Ok(value),
+    )
+}
+
+/// Creates a `Prism` focusing on the error inside the `Err` variant of a `Result`.
+///
+/// Retrieving the focus fails with an [`UnexpectedOk`] holding the value of the `Ok` variant.
+/// Setting the focus always results in an `Err` holding the new error.
+///
+/// # Type Parameters
+///
+/// - `T`: The type of the success value. Must implement `Clone`.
+/// - `E`: The type of the error value. Must implement `Clone`.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{result, HasGetter, HasSetter, UnexpectedOk};
+///
+/// let mut r = Ok::<u8, String>(1);
+///
+/// assert_eq!(result::err().try_get(&r), Err(UnexpectedOk(1)));
+/// result::err().set(&mut r, "failed".to_string());
+/// assert_eq!(r, Err("failed".to_string()));
+/// ```
+#[cfg(feature = "prism")]
+#[must_use]
+#[allow(clippy::type_complexity)]
+pub fn err<T: Clone, E: Clone>()
+-> PrismImpl<Result<T, E>, E, impl Prism<Result<T, E>, E, GetterError = UnexpectedOk<T>>> {
+    mapped_prism(
+        |r: &Result<T, E>| match r {
+            Ok(value) => Err(UnexpectedOk(value.clone())),
+            Err(error) => Ok(error.clone()),
+        },
+        |r: &mut Result<T, E>, error| *r = Err(error),
+    )
+}
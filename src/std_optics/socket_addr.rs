@@ -0,0 +1,92 @@
+//! Optics focusing on the parts of a `SocketAddr`.
+//!
+//! [`port`] and [`ip`] focus on the port and the IP address of a socket address, while
+//! [`parts_iso`] converts it to and from an `(IpAddr, u16)` pair. Combined with
+//! [`parse_iso`](crate::parse_iso), they allow reaching into socket addresses stored as strings.
+//!
+//! # Example
+//!
+//! ```rust
+//! use optics::{parse_iso, socket_addr, HasGetter, HasSetter};
+//! use std::net::SocketAddr;
+//!
+//! let port = parse_iso::<SocketAddr>().compose_with_lens(socket_addr::port());
+//! let mut bind_address = "127.0.0.1:8080".to_string();
+//!
+//! assert_eq!(port.try_get(&bind_address).ok(), Some(8080));
+//! port.set(&mut bind_address, 8081);
+//! assert_eq!(bind_address, "127.0.0.1:8081");
+//! ```
+#[cfg(feature = "iso")]
+use crate::{Iso, IsoImpl, mapped_iso};
+#[cfg(feature = "lens")]
+use crate::{Lens, LensImpl, mapped_lens};
+#[cfg(any(feature = "lens", feature = "iso"))]
+use core::net::{IpAddr, SocketAddr};
+
+/// Creates a `Lens` focusing on the port of a `SocketAddr`.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{socket_addr, HasSetter, HasTotalGetter};
+/// use std::net::SocketAddr;
+///
+/// let mut addr: SocketAddr = "[::1]:80".parse().unwrap();
+///
+/// socket_addr::port().set(&mut addr, 443);
+/// assert_eq!(socket_addr::port().get(&addr), 443);
+/// ```
+#[cfg(feature = "lens")]
+#[must_use]
+pub fn port() -> LensImpl<SocketAddr, u16, impl Lens<SocketAddr, u16>> {
+    mapped_lens(SocketAddr::port, SocketAddr::set_port)
+}
+
+/// Creates a `Lens` focusing on the IP address of a `SocketAddr`.
+///
+/// Setting an address of a different family than the current one changes the family of the
+/// socket address as well.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{socket_addr, HasSetter, HasTotalGetter};
+/// use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+///
+/// let mut addr: SocketAddr = "127.0.0.1:80".parse().unwrap();
+///
+/// socket_addr::ip().set(&mut addr, IpAddr::V4(Ipv4Addr::UNSPECIFIED));
+/// assert_eq!(addr.to_string(), "0.0.0.0:80");
+/// ```
+#[cfg(feature = "lens")]
+#[must_use]
+pub fn ip() -> LensImpl<SocketAddr, IpAddr, impl Lens<SocketAddr, IpAddr>> {
+    mapped_lens(SocketAddr::ip, SocketAddr::set_ip)
+}
+
+/// Creates an `Iso` between a `SocketAddr` and a pair of its IP address and port.
+///
+/// Note that the flow info and scope id of IPv6 socket addresses are not part of the pair, so
+/// they are reset when converting back.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{socket_addr, HasTotalGetter, HasTotalReverseGet};
+/// use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+///
+/// let localhost = IpAddr::V4(Ipv4Addr::LOCALHOST);
+///
+/// let addr = socket_addr::parts_iso().reverse_get(&(localhost, 8080));
+/// assert_eq!(addr.to_string(), "127.0.0.1:8080");
+/// assert_eq!(socket_addr::parts_iso().get(&addr), (localhost, 8080));
+/// ```
+#[cfg(feature = "iso")]
+#[must_use]
+pub fn parts_iso() -> IsoImpl<SocketAddr, (IpAddr, u16), impl Iso<SocketAddr, (IpAddr, u16)>> {
+    mapped_iso(
+        |addr: &SocketAddr| (addr.ip(), addr.port()),
+        |(ip, port): &(IpAddr, u16)| SocketAddr::new(*ip, *port),
+    )
+}
@@ -0,0 +1,90 @@
+//! Small isomorphisms between common standard library types.
+//!
+//! These cover conversions that come up often enough in optic chains to not warrant writing the
+//! closures by hand every time: negating a `bool`, swapping the elements of a pair, and converting
+//! between a `char` and its Unicode scalar value.
+//!
+//! # Example
+//!
+//! ```rust
+//! use optics::{std_isos, HasSetter, HasTotalGetter};
+//!
+//! let mut pair = (true, 'a');
+//!
+//! assert_eq!(std_isos::swap().get(&pair), ('a', true));
+//! std_isos::not().set(&mut pair.0, true);
+//! assert_eq!(pair, (false, 'a'));
+//! ```
+#[cfg(feature = "fallible-iso")]
+use crate::{FallibleIso, FallibleIsoImpl, mapped_fallible_iso};
+#[cfg(feature = "iso")]
+use crate::{Iso, IsoImpl, mapped_iso};
+#[cfg(feature = "fallible-iso")]
+use core::{char::CharTryFromError, convert::Infallible};
+
+/// Creates an `Iso` negating a `bool`.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{std_isos, HasTotalGetter, HasTotalReverseGet};
+///
+/// assert!(std_isos::not().get(&false));
+/// assert!(!std_isos::not().reverse_get(&true));
+/// ```
+#[cfg(feature = "iso")]
+#[must_use]
+pub fn not() -> IsoImpl<bool, bool, impl Iso<bool, bool>> {
+    mapped_iso(|b: &bool| !*b, |b: &bool| !*b)
+}
+
+/// Creates an `Iso` swapping the elements of a pair.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{std_isos, HasTotalGetter, HasTotalReverseGet};
+///
+/// assert_eq!(std_isos::swap().get(&(1, "one")), ("one", 1));
+/// assert_eq!(std_isos::swap().reverse_get(&("one", 1)), (1, "one"));
+/// ```
+#[cfg(feature = "iso")]
+#[must_use]
+#[allow(clippy::type_complexity)]
+pub fn swap<A: Clone, B: Clone>() -> IsoImpl<(A, B), (B, A), impl Iso<(A, B), (B, A)>> {
+    mapped_iso(
+        |(a, b): &(A, B)| (b.clone(), a.clone()),
+        |(b, a): &(B, A)| (a.clone(), b.clone()),
+    )
+}
+
+/// Creates a `FallibleIso` between a `u32` and the `char` with that Unicode scalar value.
+///
+/// Retrieving the focus fails with a [`CharTryFromError`] if the number is not a valid Unicode
+/// scalar value, such as a surrogate code point. The reverse direction can not fail.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{std_isos, HasGetter, HasSetter};
+///
+/// let mut code_point = 0x41u32;
+///
+/// assert_eq!(std_isos::char_u32().try_get(&code_point), Ok('A'));
+/// std_isos::char_u32().set(&mut code_point, 'é');
+/// assert_eq!(code_point, 0xE9);
+///
+/// assert!(std_isos::char_u32().try_get(&0xD800).is_err());
+/// ```
+#[cfg(feature = "fallible-iso")]
+#[must_use]
+pub fn char_u32() -> FallibleIsoImpl<
+    u32,
+    char,
+    impl FallibleIso<u32, char, GetterError = CharTryFromError, ReverseError = Infallible>,
+> {
+    mapped_fallible_iso(
+        |n: &u32| char::try_from(*n),
+        |c: &char| Ok::<_, Infallible>(u32::from(*c)),
+    )
+}
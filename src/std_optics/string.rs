@@ -0,0 +1,198 @@
+//! Optics for light-weight surgery on `String`s.
+//!
+//! [`strip_prefix`] and [`strip_suffix`] focus on the remainder of a string after removing a
+//! known prefix or suffix, and re-add it when the focus is set. [`char_at`] focuses on a single
+//! character of a string.
+//!
+//! # Example
+//!
+//! ```rust
+//! use optics::{string, HasGetter, HasSetter};
+//!
+//! let mut url = "https://example.com".to_string();
+//! let host = string::strip_prefix("https://");
+//!
+//! assert_eq!(host.try_get(&url), Ok("example.com".to_string()));
+//! host.set(&mut url, "example.org".to_string());
+//! assert_eq!(url, "https://example.org");
+//! ```
+#[cfg(feature = "prism")]
+use crate::IndexError;
+use crate::OpticsError;
+#[cfg(feature = "prism")]
+use crate::{Prism, PrismImpl, mapped_prism};
+#[cfg(feature = "prism")]
+use alloc::string::String;
+use core::fmt::{Display, Formatter};
+
+/// The error returned when an optic tries to strip a prefix from a string that does not start
+/// with it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PrefixMissing;
+
+impl Display for PrefixMissing {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "the string does not start with the expected prefix")
+    }
+}
+
+impl core::error::Error for PrefixMissing {}
+
+impl From<PrefixMissing> for () {
+    fn from(_: PrefixMissing) -> Self {}
+}
+
+impl From<PrefixMissing> for OpticsError {
+    fn from(_: PrefixMissing) -> Self {
+        OpticsError::NoFocus
+    }
+}
+
+/// The error returned when an optic tries to strip a suffix from a string that does not end
+/// with it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SuffixMissing;
+
+impl Display for SuffixMissing {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "the string does not end with the expected suffix")
+    }
+}
+
+impl core::error::Error for SuffixMissing {}
+
+impl From<SuffixMissing> for () {
+    fn from(_: SuffixMissing) -> Self {}
+}
+
+impl From<SuffixMissing> for OpticsError {
+    fn from(_: SuffixMissing) -> Self {
+        OpticsError::NoFocus
+    }
+}
+
+/// Creates a `Prism` focusing on the remainder of a string after `prefix`.
+///
+/// Retrieving the focus fails with a [`PrefixMissing`] error if the string does not start with
+/// `prefix`. Setting the focus replaces the string with `prefix` followed by the new value.
+///
+/// # Arguments
+///
+/// - `prefix` — The prefix to strip.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{string, HasGetter, HasSetter, PrefixMissing};
+///
+/// let mut flag = "--verbose".to_string();
+///
+/// assert_eq!(string::strip_prefix("-").try_get(&"v".to_string()), Err(PrefixMissing));
+/// string::strip_prefix("--").set(&mut flag, "quiet".to_string());
+/// assert_eq!(flag, "--quiet");
+/// ```
+#[cfg(feature = "prism")]
+#[must_use]
+pub fn strip_prefix(
+    prefix: impl Into<String>,
+) -> PrismImpl<String, String, impl Prism<String, String, GetterError = PrefixMissing>> {
+    let prefix = prefix.into();
+    let set_prefix = prefix.clone();
+
+    mapped_prism(
+        move |s: &String| {
+            s.strip_prefix(prefix.as_str())
+                .map(String::from)
+                .ok_or(PrefixMissing)
+        },
+        move |s: &mut String, value: String| {
+            s.clone_from(&set_prefix);
+            s.push_str(&value);
+        },
+    )
+}
+
+/// Creates a `Prism` focusing on the remainder of a string before `suffix`.
+///
+/// Retrieving the focus fails with a [`SuffixMissing`] error if the string does not end with
+/// `suffix`. Setting the focus replaces the string with the new value followed by `suffix`.
+///
+/// # Arguments
+///
+/// - `suffix` — The suffix to strip.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{string, HasGetter, HasSetter};
+///
+/// let mut file = "config.toml".to_string();
+/// let stem = string::strip_suffix(".toml");
+///
+/// assert_eq!(stem.try_get(&file), Ok("config".to_string()));
+/// stem.set(&mut file, "settings".to_string());
+/// assert_eq!(file, "settings.toml");
+/// ```
+#[cfg(feature = "prism")]
+#[must_use]
+pub fn strip_suffix(
+    suffix: impl Into<String>,
+) -> PrismImpl<String, String, impl Prism<String, String, GetterError = SuffixMissing>> {
+    let suffix = suffix.into();
+    let set_suffix = suffix.clone();
+
+    mapped_prism(
+        move |s: &String| {
+            s.strip_suffix(suffix.as_str())
+                .map(String::from)
+                .ok_or(SuffixMissing)
+        },
+        move |s: &mut String, mut value: String| {
+            value.push_str(&set_suffix);
+            *s = value;
+        },
+    )
+}
+
+/// Creates a `Prism` focusing on the character at `index` of a string.
+///
+/// The index counts characters, not bytes. Retrieving the focus fails with an [`IndexError`]
+/// holding the number of characters in the string if the index is out of bounds. Setting the
+/// focus only replaces an existing character, it is a no-op if the index is out of bounds.
+///
+/// # Arguments
+///
+/// - `index` — The index of the focused character.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{string, HasGetter, HasSetter, IndexError};
+///
+/// let mut word = "héllo".to_string();
+///
+/// assert_eq!(string::char_at(1).try_get(&word), Ok('é'));
+/// string::char_at(1).set(&mut word, 'e');
+/// assert_eq!(word, "hello");
+/// assert_eq!(string::char_at(5).try_get(&word), Err(IndexError { index: 5, len: 5 }));
+/// ```
+#[cfg(feature = "prism")]
+#[must_use]
+pub fn char_at(
+    index: usize,
+) -> PrismImpl<String, char, impl Prism<String, char, GetterError = IndexError>> {
+    mapped_prism(
+        move |s: &String| {
+            s.chars().nth(index).ok_or_else(|| IndexError {
+                index,
+                len: s.chars().count(),
+            })
+        },
+        move |s: &mut String, value: char| {
+            if let Some((start, c)) = s.char_indices().nth(index) {
+                let mut buffer = [0; 4];
+                s.replace_range(start..start + c.len_utf8(), value.encode_utf8(&mut buffer));
+            }
+        },
+    )
+}
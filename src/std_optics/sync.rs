@@ -0,0 +1,150 @@
+//! Optics focusing on the value guarded by a `Mutex` or an `RwLock`.
+//!
+//! [`mutex_lens`] and [`rwlock_lens`] lock the guarded value, read a clone of it or write it, and
+//! release the lock again, so that a composed optic can reach into shared state. They work both on
+//! the lock itself, and on any type that can be borrowed as one, such as an `Arc<Mutex<T>>`.
+//!
+//! Reading the value fails with a [`LockPoisoned`] error if a thread panicked while holding the
+//! lock. Setting the value replaces it entirely, so it is done even if the lock is poisoned, and
+//! clears the poisoning.
+//!
+//! These optics require the `std` feature, which is enabled by default.
+//!
+//! # Example
+//!
+//! ```rust
+//! use optics::{mutex_lens, HasGetter, HasSetter};
+//! use std::sync::{Arc, Mutex};
+//!
+//! let mut shared = Arc::new(Mutex::new(8080u16));
+//! let handle = Arc::clone(&shared);
+//!
+//! mutex_lens::<Arc<Mutex<u16>>, u16>().set(&mut shared, 8081);
+//! assert_eq!(*handle.lock().unwrap(), 8081);
+//! ```
+use core::fmt::{Display, Formatter};
+#[cfg(all(feature = "std", feature = "prism"))]
+use {
+    crate::{Prism, PrismImpl, mapped_prism},
+    core::borrow::Borrow,
+    std::sync::{Mutex, RwLock},
+};
+
+/// The error returned when an optic tries to read a value guarded by a lock that is poisoned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LockPoisoned;
+
+impl Display for LockPoisoned {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "the lock is poisoned")
+    }
+}
+
+impl core::error::Error for LockPoisoned {}
+
+/// Creates a `Prism` focusing on the value guarded by a `Mutex`.
+///
+/// Retrieving the focus locks the mutex and returns a clone of the guarded value, or fails with a
+/// [`LockPoisoned`] error if the mutex is poisoned. Setting the focus locks the mutex and replaces
+/// the guarded value, clearing the poisoning if there was any.
+///
+/// Both operations block until the mutex can be locked.
+///
+/// # Type Parameters
+///
+/// - `M`: The type of the source, either the `Mutex` itself or a type that can be borrowed as one,
+///   such as an `Arc<Mutex<T>>`.
+/// - `T`: The type of the guarded value. Must implement `Clone`.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{mutex_lens, HasGetter, HasSetter};
+/// use std::sync::Mutex;
+///
+/// let mut counter = Mutex::new(1);
+///
+/// mutex_lens().set(&mut counter, 2);
+/// assert_eq!(mutex_lens::<Mutex<u32>, u32>().try_get(&counter), Ok(2));
+/// ```
+#[cfg(all(feature = "std", feature = "prism"))]
+#[must_use]
+pub fn mutex_lens<M, T>() -> PrismImpl<M, T, impl Prism<M, T, GetterError = LockPoisoned>>
+where
+    M: Borrow<Mutex<T>>,
+    T: Clone,
+{
+    mapped_prism(
+        |m: &M| {
+            m.borrow()
+                .lock()
+                .map(|guard| guard.clone())
+                .map_err(|_| LockPoisoned)
+        },
+        |m: &mut M, value| {
+            let mutex: &Mutex<T> = (*m).borrow();
+            match mutex.lock() {
+                Ok(mut guard) => *guard = value,
+                Err(poisoned) => {
+                    *poisoned.into_inner() = value;
+                    mutex.clear_poison();
+                }
+            }
+        },
+    )
+}
+
+/// Creates a `Prism` focusing on the value guarded by an `RwLock`.
+///
+/// Retrieving the focus acquires a read lock and returns a clone of the guarded value, or fails
+/// with a [`LockPoisoned`] error if the lock is poisoned. Setting the focus acquires a write lock
+/// and replaces the guarded value, clearing the poisoning if there was any.
+///
+/// Both operations block until the lock can be acquired.
+///
+/// # Type Parameters
+///
+/// - `L`: The type of the source, either the `RwLock` itself or a type that can be borrowed as
+///   one, such as an `Arc<RwLock<T>>`.
+/// - `T`: The type of the guarded value. Must implement `Clone`.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{rwlock_lens, HasGetter, HasSetter};
+/// use std::sync::{Arc, RwLock};
+///
+/// let mut settings = Arc::new(RwLock::new("debug".to_string()));
+///
+/// rwlock_lens().set(&mut settings, "info".to_string());
+/// assert_eq!(
+///     rwlock_lens::<Arc<RwLock<String>>, String>().try_get(&settings),
+///     Ok("info".to_string())
+/// );
+/// ```
+#[cfg(all(feature = "std", feature = "prism"))]
+#[must_use]
+pub fn rwlock_lens<L, T>() -> PrismImpl<L, T, impl Prism<L, T, GetterError = LockPoisoned>>
+where
+    L: Borrow<RwLock<T>>,
+    T: Clone,
+{
+    mapped_prism(
+        |l: &L| {
+            l.borrow()
+                .read()
+                .map(|guard| guard.clone())
+                .map_err(|_| LockPoisoned)
+        },
+        |l: &mut L, value| {
+            let lock: &RwLock<T> = (*l).borrow();
+            match lock.write() {
+                Ok(mut guard) => *guard = value,
+                Err(poisoned) => {
+                    *poisoned.into_inner() = value;
+                    lock.clear_poison();
+                }
+            }
+        },
+    )
+}
@@ -0,0 +1,84 @@
+//! Optics converting a `Duration` to and from plain numbers.
+//!
+//! These mirror the unit conversions one would otherwise write by hand for a `Duration` field.
+//! As a `Duration` has nanosecond precision, converting it to a coarser unit loses information:
+//! [`duration_secs`] is therefore a `Lens` that keeps the sub-second part when the focus is set,
+//! rather than an `Iso`.
+//!
+//! # Example
+//!
+//! ```rust
+//! use core::time::Duration;
+//! use optics::{duration_secs, HasSetter, HasTotalGetter};
+//!
+//! let mut timeout = Duration::from_millis(1_500);
+//!
+//! assert_eq!(duration_secs().get(&timeout), 1);
+//! duration_secs().set(&mut timeout, 3);
+//! assert_eq!(timeout, Duration::from_millis(3_500));
+//! ```
+#[cfg(feature = "fallible-iso")]
+use crate::NumericRangeError;
+#[cfg(feature = "fallible-iso")]
+use crate::{FallibleIso, FallibleIsoImpl, mapped_fallible_iso};
+#[cfg(feature = "lens")]
+use crate::{Lens, LensImpl, mapped_lens};
+#[cfg(feature = "fallible-iso")]
+use core::convert::Infallible;
+#[cfg(any(feature = "lens", feature = "fallible-iso"))]
+use core::time::Duration;
+
+/// Creates a `Lens` focusing on the whole seconds of a `Duration`.
+///
+/// Retrieving the focus truncates the sub-second part. Setting the focus replaces the whole
+/// seconds, keeping the sub-second part of the duration.
+///
+/// # Example
+///
+/// ```rust
+/// use core::time::Duration;
+/// use optics::{duration_secs, HasTotalGetter};
+///
+/// assert_eq!(duration_secs().get(&Duration::from_millis(2_999)), 2);
+/// ```
+#[cfg(feature = "lens")]
+#[must_use]
+pub fn duration_secs() -> LensImpl<Duration, u64, impl Lens<Duration, u64>> {
+    mapped_lens(Duration::as_secs, |d: &mut Duration, secs| {
+        *d = Duration::new(secs, d.subsec_nanos());
+    })
+}
+
+/// Creates a `FallibleIso` between a `Duration` and its length in whole milliseconds as a `u32`.
+///
+/// Retrieving the focus truncates the sub-millisecond part, and fails with a
+/// [`NumericRangeError`] if the number of milliseconds does not fit in a `u32`, which is the case
+/// for durations of about 50 days or more. The reverse direction can not fail.
+///
+/// # Example
+///
+/// ```rust
+/// use core::time::Duration;
+/// use optics::{duration_millis_u32, HasGetter, HasSetter, NumericRangeError};
+///
+/// let mut delay = Duration::from_secs(1);
+///
+/// assert_eq!(duration_millis_u32().try_get(&delay), Ok(1_000));
+/// duration_millis_u32().set(&mut delay, 250);
+/// assert_eq!(delay, Duration::from_millis(250));
+///
+/// let long = Duration::from_secs(100 * 24 * 60 * 60);
+/// assert_eq!(duration_millis_u32().try_get(&long), Err(NumericRangeError));
+/// ```
+#[cfg(feature = "fallible-iso")]
+#[must_use]
+pub fn duration_millis_u32() -> FallibleIsoImpl<
+    Duration,
+    u32,
+    impl FallibleIso<Duration, u32, GetterError = NumericRangeError, ReverseError = Infallible>,
+> {
+    mapped_fallible_iso(
+        |d: &Duration| u32::try_from(d.as_millis()).map_err(|_| NumericRangeError),
+        |millis: &u32| Ok::<_, Infallible>(Duration::from_millis(u64::from(*millis))),
+    )
+}
@@ -0,0 +1,246 @@
+//! Optics converting `time` date and time values, and focusing on their parts.
+//!
+//! [`rfc3339_iso`] and [`unix_timestamp_iso`] convert an `OffsetDateTime` to and from the strings
+//! and timestamps it is usually stored as, while [`date`], [`hour`], [`minute`] and [`second`]
+//! focus on its parts. Setting an hour, minute or second out of its range is rejected: the
+//! lenses report it through [`HasFallibleSetter`](crate::HasFallibleSetter) with the
+//! [`ComponentRange`] error of `time`, and leave the value unchanged on
+//! [`HasSetter::set`](crate::HasSetter::set).
+//!
+//! These optics are available as `optics::time`, and require the `time` feature.
+//!
+//! # Example
+//!
+//! ```rust
+//! use optics::{time, HasGetter, HasSetter};
+//!
+//! let hour = time::rfc3339_iso().compose_with_lens(time::hour());
+//! let mut created_at = "2025-05-16T08:30:00+02:00".to_string();
+//!
+//! assert_eq!(hour.try_get(&created_at), Ok(8));
+//! hour.set(&mut created_at, 17);
+//! assert_eq!(created_at, "2025-05-16T17:30:00+02:00");
+//! ```
+use crate::OpticsError;
+#[cfg(feature = "lens")]
+use crate::optics::lens::checked_lens;
+#[cfg(feature = "fallible-iso")]
+use crate::{FallibleIso, FallibleIsoImpl, mapped_fallible_iso};
+#[cfg(feature = "lens")]
+use crate::{HasFallibleSetter, Lens, LensImpl, mapped_lens};
+#[cfg(feature = "lens")]
+use ::time::Date;
+#[cfg(any(feature = "lens", feature = "fallible-iso"))]
+use ::time::OffsetDateTime;
+use ::time::error::{ComponentRange, Format, Parse};
+#[cfg(feature = "fallible-iso")]
+use {
+    ::time::format_description::well_known::Rfc3339, alloc::string::String,
+    core::convert::Infallible,
+};
+
+impl From<Parse> for OpticsError {
+    fn from(_: Parse) -> Self {
+        OpticsError::ParseFailed
+    }
+}
+
+impl From<Format> for OpticsError {
+    fn from(_: Format) -> Self {
+        OpticsError::OutOfRange
+    }
+}
+
+impl From<ComponentRange> for OpticsError {
+    fn from(_: ComponentRange) -> Self {
+        OpticsError::OutOfRange
+    }
+}
+
+/// Creates a `FallibleIso` between an RFC 3339 `String` and the `OffsetDateTime` it contains.
+///
+/// Retrieving the focus fails with a [`Parse`] error if the string is not a valid RFC 3339 date
+/// and time. The reverse direction keeps the offset of the value, and fails with a [`Format`]
+/// error if it can not be represented in RFC 3339, such as a year above 9999.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{time, HasGetter, HasReverseGet};
+///
+/// let at = time::rfc3339_iso().try_get(&"2025-05-16T10:00:00+02:00".to_string()).unwrap();
+///
+/// assert_eq!(at.hour(), 10);
+/// assert_eq!(time::rfc3339_iso().try_reverse_get(&at).unwrap(), "2025-05-16T10:00:00+02:00");
+/// assert!(time::rfc3339_iso().try_get(&"yesterday".to_string()).is_err());
+/// ```
+#[cfg(feature = "fallible-iso")]
+#[must_use]
+#[allow(clippy::type_complexity)]
+pub fn rfc3339_iso() -> FallibleIsoImpl<
+    String,
+    OffsetDateTime,
+    impl FallibleIso<String, OffsetDateTime, GetterError = Parse, ReverseError = Format>,
+> {
+    mapped_fallible_iso(
+        |s: &String| OffsetDateTime::parse(s, &Rfc3339),
+        |at: &OffsetDateTime| at.format(&Rfc3339),
+    )
+}
+
+/// Creates a `FallibleIso` between a Unix timestamp in seconds and the `OffsetDateTime` it
+/// stands for, in UTC.
+///
+/// Retrieving the focus fails with a [`ComponentRange`] error if the timestamp is out of the
+/// range `time` can represent. The reverse direction drops the sub-second part of the value, and
+/// can not fail.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{time, HasGetter, HasTotalReverseGet};
+///
+/// let epoch = time::unix_timestamp_iso().try_get(&0).unwrap();
+///
+/// assert_eq!(epoch.year(), 1970);
+/// assert_eq!(time::unix_timestamp_iso().reverse_get(&epoch), 0);
+/// assert!(time::unix_timestamp_iso().try_get(&i64::MAX).is_err());
+/// ```
+#[cfg(feature = "fallible-iso")]
+#[must_use]
+#[allow(clippy::type_complexity)]
+pub fn unix_timestamp_iso() -> FallibleIsoImpl<
+    i64,
+    OffsetDateTime,
+    impl FallibleIso<i64, OffsetDateTime, GetterError = ComponentRange, ReverseError = Infallible>,
+> {
+    mapped_fallible_iso(
+        |secs: &i64| OffsetDateTime::from_unix_timestamp(*secs),
+        |at: &OffsetDateTime| Ok::<_, Infallible>(at.unix_timestamp()),
+    )
+}
+
+/// Creates a `Lens` focusing on the date of an `OffsetDateTime`.
+///
+/// Setting the focus replaces the date, keeping the time of day and the offset.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{time, HasGetter, HasSetter, HasTotalGetter};
+/// use ::time::{Date, Month};
+///
+/// let mut at = time::unix_timestamp_iso().try_get(&0).unwrap();
+///
+/// time::date().set(&mut at, Date::from_calendar_date(2025, Month::December, 24).unwrap());
+/// assert_eq!(time::date().get(&at).to_string(), "2025-12-24");
+/// assert_eq!(at.hour(), 0);
+/// ```
+#[cfg(feature = "lens")]
+#[must_use]
+pub fn date() -> LensImpl<OffsetDateTime, Date, impl Lens<OffsetDateTime, Date>> {
+    mapped_lens(
+        |at: &OffsetDateTime| at.date(),
+        |at: &mut OffsetDateTime, date| *at = at.replace_date(date),
+    )
+}
+
+#[cfg(feature = "lens")]
+fn time_component(
+    get_fn: fn(OffsetDateTime) -> u8,
+    replace_fn: fn(OffsetDateTime, u8) -> Result<OffsetDateTime, ComponentRange>,
+) -> LensImpl<
+    OffsetDateTime,
+    u8,
+    impl Lens<OffsetDateTime, u8> + HasFallibleSetter<OffsetDateTime, u8, SetterError = ComponentRange>,
+> {
+    checked_lens(
+        move |at: &OffsetDateTime| get_fn(*at),
+        move |at: &mut OffsetDateTime, value| {
+            *at = replace_fn(*at, value)?;
+            Ok(())
+        },
+    )
+}
+
+/// Creates a `Lens` focusing on the hour of an `OffsetDateTime`, from 0 to 23.
+///
+/// Setting an hour above 23 is rejected: [`HasFallibleSetter::try_set`] fails with a
+/// [`ComponentRange`] error, and [`HasSetter::set`](crate::HasSetter::set) leaves the value
+/// unchanged.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{time, HasFallibleSetter, HasGetter, HasTotalGetter};
+///
+/// let mut at = time::unix_timestamp_iso().try_get(&0).unwrap();
+///
+/// assert_eq!(time::hour().try_set(&mut at, 23), Ok(()));
+/// assert!(time::hour().try_set(&mut at, 24).is_err());
+/// assert_eq!(time::hour().get(&at), 23);
+/// ```
+#[cfg(feature = "lens")]
+#[must_use]
+#[allow(clippy::type_complexity)]
+pub fn hour() -> LensImpl<
+    OffsetDateTime,
+    u8,
+    impl Lens<OffsetDateTime, u8> + HasFallibleSetter<OffsetDateTime, u8, SetterError = ComponentRange>,
+> {
+    time_component(OffsetDateTime::hour, OffsetDateTime::replace_hour)
+}
+
+/// Creates a `Lens` focusing on the minute of an `OffsetDateTime`, from 0 to 59.
+///
+/// Setting a minute above 59 is rejected: [`HasFallibleSetter::try_set`] fails with a
+/// [`ComponentRange`] error, and [`HasSetter::set`](crate::HasSetter::set) leaves the value
+/// unchanged.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{time, HasFallibleSetter, HasGetter, HasTotalGetter};
+///
+/// let mut at = time::unix_timestamp_iso().try_get(&0).unwrap();
+///
+/// assert!(time::minute().try_set(&mut at, 60).is_err());
+/// assert_eq!(time::minute().get(&at), 0);
+/// ```
+#[cfg(feature = "lens")]
+#[must_use]
+#[allow(clippy::type_complexity)]
+pub fn minute() -> LensImpl<
+    OffsetDateTime,
+    u8,
+    impl Lens<OffsetDateTime, u8> + HasFallibleSetter<OffsetDateTime, u8, SetterError = ComponentRange>,
+> {
+    time_component(OffsetDateTime::minute, OffsetDateTime::replace_minute)
+}
+
+/// Creates a `Lens` focusing on the second of an `OffsetDateTime`, from 0 to 59.
+///
+/// Setting a second above 59 is rejected: [`HasFallibleSetter::try_set`] fails with a
+/// [`ComponentRange`] error, and [`HasSetter::set`](crate::HasSetter::set) leaves the value
+/// unchanged.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{time, HasFallibleSetter, HasGetter, HasTotalGetter};
+///
+/// let mut at = time::unix_timestamp_iso().try_get(&0).unwrap();
+///
+/// assert_eq!(time::second().try_set(&mut at, 30), Ok(()));
+/// assert_eq!(time::second().get(&at), 30);
+/// ```
+#[cfg(feature = "lens")]
+#[must_use]
+#[allow(clippy::type_complexity)]
+pub fn second() -> LensImpl<
+    OffsetDateTime,
+    u8,
+    impl Lens<OffsetDateTime, u8> + HasFallibleSetter<OffsetDateTime, u8, SetterError = ComponentRange>,
+> {
+    time_component(OffsetDateTime::second, OffsetDateTime::replace_second)
+}
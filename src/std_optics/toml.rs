@@ -0,0 +1,279 @@
+//! Optics focusing on the parts of a `toml::Value`.
+//!
+//! [`key`] and [`index`] focus on an entry of a table and an element of an array, while
+//! [`as_str`], [`as_integer`], [`as_float`] and [`as_bool`] convert a value to and from the
+//! primitive it contains. They fail with a [`DocumentError`] if the value is not of the expected
+//! type, or the entry or element is missing, so configuration files can be edited in place with
+//! the same compositions as typed structs.
+//!
+//! These optics require the `toml` feature.
+//!
+//! # Example
+//!
+//! ```rust
+//! use optics::{toml, DocumentError, HasGetter, HasSetter};
+//!
+//! let mut manifest = ::toml::Value::Table(::toml::toml! {
+//!     [package]
+//!     name = "optics"
+//!     edition = "2021"
+//! });
+//! let edition = toml::key("package")
+//!     .compose_with_prism::<DocumentError, _, _>(toml::key("edition"))
+//!     .compose_with_fallible_iso::<DocumentError, _, _>(toml::as_str());
+//!
+//! assert_eq!(edition.try_get(&manifest), Ok("2021".to_string()));
+//! edition.set(&mut manifest, "2024".to_string());
+//! assert_eq!(manifest["package"]["edition"].as_str(), Some("2024"));
+//! ```
+#[cfg(any(feature = "prism", feature = "fallible-iso"))]
+use crate::DocumentError;
+#[cfg(feature = "fallible-iso")]
+use crate::{FallibleIso, FallibleIsoImpl, mapped_fallible_iso};
+#[cfg(feature = "prism")]
+use crate::{HasInsert, Prism, PrismImpl, mapped_prism};
+#[cfg(feature = "fallible-iso")]
+use core::convert::Infallible;
+#[cfg(any(feature = "prism", feature = "fallible-iso"))]
+use {::toml::Value, alloc::string::String};
+
+#[cfg(any(feature = "prism", feature = "fallible-iso"))]
+fn mismatch(expected: &'static str, found: &Value) -> DocumentError {
+    DocumentError::TypeMismatch {
+        expected,
+        found: found.type_str(),
+    }
+}
+
+/// Creates a `Prism` focusing on the entry stored under `key` in a TOML table.
+///
+/// Retrieving the focus fails with a [`DocumentError`] if the value is not a table, or has no
+/// entry under the key. Setting the focus only replaces an existing entry, while
+/// [`insert`](crate::HasInsert::insert) adds it to the table.
+///
+/// # Arguments
+///
+/// - `key` — The key of the focused entry.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{toml, DocumentError, HasGetter, HasInsert};
+///
+/// let mut config = ::toml::Value::Table(::toml::Table::new());
+///
+/// assert_eq!(
+///     toml::key("debug").try_get(&config),
+///     Err(DocumentError::KeyMissing { key: "debug".to_string() })
+/// );
+/// toml::key("debug").insert(&mut config, ::toml::Value::Boolean(true));
+/// assert_eq!(config["debug"].as_bool(), Some(true));
+/// ```
+#[cfg(feature = "prism")]
+#[must_use]
+pub fn key(
+    key: impl Into<String>,
+) -> PrismImpl<
+    Value,
+    Value,
+    impl Prism<Value, Value, GetterError = DocumentError> + HasInsert<Value, Value>,
+> {
+    let key = key.into();
+    let set_key = key.clone();
+    let insert_key = key.clone();
+
+    mapped_prism(
+        move |value: &Value| match value {
+            Value::Table(entries) => entries
+                .get(&key)
+                .cloned()
+                .ok_or_else(|| DocumentError::KeyMissing { key: key.clone() }),
+            other => Err(mismatch("table", other)),
+        },
+        move |value: &mut Value, entry| {
+            if let Some(existing) = value.get_mut(&set_key) {
+                *existing = entry;
+            }
+        },
+    )
+    .with_insert(move |value: &mut Value, entry| {
+        if let Value::Table(entries) = value {
+            entries.insert(insert_key.clone(), entry);
+        }
+    })
+}
+
+/// Creates a `Prism` focusing on the element at `index` of a TOML array.
+///
+/// Retrieving the focus fails with a [`DocumentError`] if the value is not an array, or the index
+/// is out of its bounds. Setting the focus only replaces an existing element.
+///
+/// # Arguments
+///
+/// - `index` — The index of the focused element.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{toml, DocumentError, HasGetter, HasSetter};
+/// use ::toml::Value;
+///
+/// let mut members = Value::Array(vec![Value::from("core")]);
+///
+/// toml::index(0).set(&mut members, Value::from("optics"));
+/// assert_eq!(members[0].as_str(), Some("optics"));
+/// assert_eq!(
+///     toml::index(1).try_get(&members),
+///     Err(DocumentError::IndexOutOfBounds { index: 1, len: 1 })
+/// );
+/// ```
+#[cfg(feature = "prism")]
+#[must_use]
+pub fn index(
+    index: usize,
+) -> PrismImpl<Value, Value, impl Prism<Value, Value, GetterError = DocumentError>> {
+    mapped_prism(
+        move |value: &Value| match value {
+            Value::Array(elements) => {
+                elements
+                    .get(index)
+                    .cloned()
+                    .ok_or(DocumentError::IndexOutOfBounds {
+                        index,
+                        len: elements.len(),
+                    })
+            }
+            other => Err(mismatch("array", other)),
+        },
+        move |value: &mut Value, element| {
+            if let Some(existing) = value.get_mut(index) {
+                *existing = element;
+            }
+        },
+    )
+}
+
+/// Creates a `FallibleIso` between a TOML value and the string it contains.
+///
+/// Retrieving the focus fails with a [`DocumentError`] if the value is not a string. The reverse
+/// direction wraps the string into a value, and can not fail.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{toml, HasGetter, HasTotalReverseGet};
+/// use ::toml::Value;
+///
+/// assert_eq!(toml::as_str().try_get(&Value::from("info")), Ok("info".to_string()));
+/// assert!(toml::as_str().try_get(&Value::from(1)).is_err());
+/// assert_eq!(toml::as_str().reverse_get(&"info".to_string()), Value::from("info"));
+/// ```
+#[cfg(feature = "fallible-iso")]
+#[must_use]
+pub fn as_str() -> FallibleIsoImpl<
+    Value,
+    String,
+    impl FallibleIso<Value, String, GetterError = DocumentError, ReverseError = Infallible>,
+> {
+    mapped_fallible_iso(
+        |value: &Value| match value {
+            Value::String(s) => Ok(s.clone()),
+            other => Err(mismatch("string", other)),
+        },
+        |s: &String| Ok::<_, Infallible>(Value::String(s.clone())),
+    )
+}
+
+/// Creates a `FallibleIso` between a TOML value and the integer it contains.
+///
+/// Retrieving the focus fails with a [`DocumentError`] if the value is not an integer. The
+/// reverse direction wraps the integer into a value, and can not fail.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{toml, HasGetter, HasTotalReverseGet};
+/// use ::toml::Value;
+///
+/// assert_eq!(toml::as_integer().try_get(&Value::from(8080)), Ok(8080));
+/// assert!(toml::as_integer().try_get(&Value::from(1.5)).is_err());
+/// assert_eq!(toml::as_integer().reverse_get(&-1), Value::from(-1));
+/// ```
+#[cfg(feature = "fallible-iso")]
+#[must_use]
+pub fn as_integer() -> FallibleIsoImpl<
+    Value,
+    i64,
+    impl FallibleIso<Value, i64, GetterError = DocumentError, ReverseError = Infallible>,
+> {
+    mapped_fallible_iso(
+        |value: &Value| match value {
+            Value::Integer(n) => Ok(*n),
+            other => Err(mismatch("integer", other)),
+        },
+        |n: &i64| Ok::<_, Infallible>(Value::Integer(*n)),
+    )
+}
+
+/// Creates a `FallibleIso` between a TOML value and the float it contains.
+///
+/// Retrieving the focus fails with a [`DocumentError`] if the value is not a float. Integers are
+/// not converted, as writing the float back would change the type of the value in the document.
+/// The reverse direction wraps the float into a value, and can not fail.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{toml, HasGetter, HasTotalReverseGet};
+/// use ::toml::Value;
+///
+/// assert_eq!(toml::as_float().try_get(&Value::from(0.5)), Ok(0.5));
+/// assert!(toml::as_float().try_get(&Value::from(1)).is_err());
+/// assert_eq!(toml::as_float().reverse_get(&2.0), Value::from(2.0));
+/// ```
+#[cfg(feature = "fallible-iso")]
+#[must_use]
+pub fn as_float() -> FallibleIsoImpl<
+    Value,
+    f64,
+    impl FallibleIso<Value, f64, GetterError = DocumentError, ReverseError = Infallible>,
+> {
+    mapped_fallible_iso(
+        |value: &Value| match value {
+            Value::Float(n) => Ok(*n),
+            other => Err(mismatch("float", other)),
+        },
+        |n: &f64| Ok::<_, Infallible>(Value::Float(*n)),
+    )
+}
+
+/// Creates a `FallibleIso` between a TOML value and the boolean it contains.
+///
+/// Retrieving the focus fails with a [`DocumentError`] if the value is not a boolean. The reverse
+/// direction wraps the boolean into a value, and can not fail.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{toml, HasGetter, HasTotalReverseGet};
+/// use ::toml::Value;
+///
+/// assert_eq!(toml::as_bool().try_get(&Value::from(true)), Ok(true));
+/// assert!(toml::as_bool().try_get(&Value::from("true")).is_err());
+/// assert_eq!(toml::as_bool().reverse_get(&false), Value::from(false));
+/// ```
+#[cfg(feature = "fallible-iso")]
+#[must_use]
+pub fn as_bool() -> FallibleIsoImpl<
+    Value,
+    bool,
+    impl FallibleIso<Value, bool, GetterError = DocumentError, ReverseError = Infallible>,
+> {
+    mapped_fallible_iso(
+        |value: &Value| match value {
+            Value::Boolean(b) => Ok(*b),
+            other => Err(mismatch("boolean", other)),
+        },
+        |b: &bool| Ok::<_, Infallible>(Value::Boolean(*b)),
+    )
+}
@@ -0,0 +1,239 @@
+//! Lenses focusing on the elements of tuples.
+//!
+//! The functions `_0()` through `_11()` create a [`Lens`] focusing on the element at the given
+//! index of any tuple of up to 12 elements that has an element at that index. This makes it
+//! possible to work with tuple intermediates, such as the ones returned by
+//! [`enum_prism!`](crate::enum_prism) for multi-field variants, without hand-written lenses.
+//!
+//! # Example
+//!
+//! ```rust
+//! use optics::{tuple, HasSetter, HasTotalGetter};
+//!
+//! let mut pair = ("localhost".to_string(), 8080u16);
+//!
+//! assert_eq!(tuple::_0().get(&pair), "localhost".to_string());
+//! assert_eq!(tuple::_1().get(&pair), 8080);
+//!
+//! tuple::_1().set(&mut pair, 8081);
+//! assert_eq!(pair, ("localhost".to_string(), 8081));
+//! ```
+#[cfg(feature = "lens")]
+use crate::{Lens, LensImpl, mapped_lens};
+
+/// Provides access to the element at index `I` of a tuple.
+///
+/// This trait is implemented for all tuples of up to 12 elements, for every valid index.
+pub trait TupleElement<const I: usize> {
+    /// The type of the element at index `I`.
+    type Element;
+
+    /// Returns a reference to the element at index `I`.
+    fn element(&self) -> &Self::Element;
+
+    /// Returns a mutable reference to the element at index `I`.
+    fn element_mut(&mut self) -> &mut Self::Element;
+}
+
+macro_rules! tuple_elements {
+    ($params:tt $($idx:tt $element:ident)+) => {
+        $(tuple_elements!(@impl $params $idx $element);)+
+    };
+
+    (@impl [$($param:ident)+] $idx:tt $element:ident) => {
+        impl<$($param),+> TupleElement<$idx> for ($($param,)+) {
+            type Element = $element;
+
+            fn element(&self) -> &Self::Element {
+                &self.$idx
+            }
+
+            fn element_mut(&mut self) -> &mut Self::Element {
+                &mut self.$idx
+            }
+        }
+    };
+}
+
+tuple_elements!([A] 0 A);
+tuple_elements!([A B] 0 A 1 B);
+tuple_elements!([A B C] 0 A 1 B 2 C);
+tuple_elements!([A B C D] 0 A 1 B 2 C 3 D);
+tuple_elements!([A B C D E] 0 A 1 B 2 C 3 D 4 E);
+tuple_elements!([A B C D E F] 0 A 1 B 2 C 3 D 4 E 5 F);
+tuple_elements!([A B C D E F G] 0 A 1 B 2 C 3 D 4 E 5 F 6 G);
+tuple_elements!([A B C D E F G H] 0 A 1 B 2 C 3 D 4 E 5 F 6 G 7 H);
+tuple_elements!([A B C D E F G H I] 0 A 1 B 2 C 3 D 4 E 5 F 6 G 7 H 8 I);
+tuple_elements!([A B C D E F G H I J] 0 A 1 B 2 C 3 D 4 E 5 F 6 G 7 H 8 I 9 J);
+tuple_elements!([A B C D E F G H I J K] 0 A 1 B 2 C 3 D 4 E 5 F 6 G 7 H 8 I 9 J 10 K);
+tuple_elements!([A B C D E F G H I J K L] 0 A 1 B 2 C 3 D 4 E 5 F 6 G 7 H 8 I 9 J 10 K 11 L);
+
+/// Creates a `Lens` focusing on the element at index `I` of a tuple.
+///
+/// This is the const generic form of the `_0()` ... `_11()` functions, useful when the index is
+/// itself a generic parameter.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{tuple, HasTotalGetter};
+///
+/// let triple = (1u8, 'b', "c");
+/// assert_eq!(tuple::element::<_, 1>().get(&triple), 'b');
+/// ```
+#[cfg(feature = "lens")]
+#[must_use]
+pub fn element<T: TupleElement<I>, const I: usize>()
+-> LensImpl<T, T::Element, impl Lens<T, T::Element>>
+where
+    T::Element: Clone,
+{
+    mapped_lens(
+        |t: &T| t.element().clone(),
+        |t: &mut T, value| *t.element_mut() = value,
+    )
+}
+
+/// Creates a `Lens` focusing on the element at index 0 of a tuple.
+///
+/// See the [module level documentation](self) for details.
+#[cfg(feature = "lens")]
+#[must_use]
+pub fn _0<T: TupleElement<0>>() -> LensImpl<T, T::Element, impl Lens<T, T::Element>>
+where
+    T::Element: Clone,
+{
+    element::<T, 0>()
+}
+
+/// Creates a `Lens` focusing on the element at index 1 of a tuple.
+///
+/// See the [module level documentation](self) for details.
+#[cfg(feature = "lens")]
+#[must_use]
+pub fn _1<T: TupleElement<1>>() -> LensImpl<T, T::Element, impl Lens<T, T::Element>>
+where
+    T::Element: Clone,
+{
+    element::<T, 1>()
+}
+
+/// Creates a `Lens` focusing on the element at index 2 of a tuple.
+///
+/// See the [module level documentation](self) for details.
+#[cfg(feature = "lens")]
+#[must_use]
+pub fn _2<T: TupleElement<2>>() -> LensImpl<T, T::Element, impl Lens<T, T::Element>>
+where
+    T::Element: Clone,
+{
+    element::<T, 2>()
+}
+
+/// Creates a `Lens` focusing on the element at index 3 of a tuple.
+///
+/// See the [module level documentation](self) for details.
+#[cfg(feature = "lens")]
+#[must_use]
+pub fn _3<T: TupleElement<3>>() -> LensImpl<T, T::Element, impl Lens<T, T::Element>>
+where
+    T::Element: Clone,
+{
+    element::<T, 3>()
+}
+
+/// Creates a `Lens` focusing on the element at index 4 of a tuple.
+///
+/// See the [module level documentation](self) for details.
+#[cfg(feature = "lens")]
+#[must_use]
+pub fn _4<T: TupleElement<4>>() -> LensImpl<T, T::Element, impl Lens<T, T::Element>>
+where
+    T::Element: Clone,
+{
+    element::<T, 4>()
+}
+
+/// Creates a `Lens` focusing on the element at index 5 of a tuple.
+///
+/// See the [module level documentation](self) for details.
+#[cfg(feature = "lens")]
+#[must_use]
+pub fn _5<T: TupleElement<5>>() -> LensImpl<T, T::Element, impl Lens<T, T::Element>>
+where
+    T::Element: Clone,
+{
+    element::<T, 5>()
+}
+
+/// Creates a `Lens` focusing on the element at index 6 of a tuple.
+///
+/// See the [module level documentation](self) for details.
+#[cfg(feature = "lens")]
+#[must_use]
+pub fn _6<T: TupleElement<6>>() -> LensImpl<T, T::Element, impl Lens<T, T::Element>>
+where
+    T::Element: Clone,
+{
+    element::<T, 6>()
+}
+
+/// Creates a `Lens` focusing on the element at index 7 of a tuple.
+///
+/// See the [module level documentation](self) for details.
+#[cfg(feature = "lens")]
+#[must_use]
+pub fn _7<T: TupleElement<7>>() -> LensImpl<T, T::Element, impl Lens<T, T::Element>>
+where
+    T::Element: Clone,
+{
+    element::<T, 7>()
+}
+
+/// Creates a `Lens` focusing on the element at index 8 of a tuple.
+///
+/// See the [module level documentation](self) for details.
+#[cfg(feature = "lens")]
+#[must_use]
+pub fn _8<T: TupleElement<8>>() -> LensImpl<T, T::Element, impl Lens<T, T::Element>>
+where
+    T::Element: Clone,
+{
+    element::<T, 8>()
+}
+
+/// Creates a `Lens` focusing on the element at index 9 of a tuple.
+///
+/// See the [module level documentation](self) for details.
+#[cfg(feature = "lens")]
+#[must_use]
+pub fn _9<T: TupleElement<9>>() -> LensImpl<T, T::Element, impl Lens<T, T::Element>>
+where
+    T::Element: Clone,
+{
+    element::<T, 9>()
+}
+
+/// Creates a `Lens` focusing on the element at index 10 of a tuple.
+///
+/// See the [module level documentation](self) for details.
+#[cfg(feature = "lens")]
+#[must_use]
+pub fn _10<T: TupleElement<10>>() -> LensImpl<T, T::Element, impl Lens<T, T::Element>>
+where
+    T::Element: Clone,
+{
+    element::<T, 10>()
+}
+
+/// Creates a `Lens` focusing on the element at index 11 of a tuple.
+///
+/// See the [module level documentation](self) for details.
+#[cfg(feature = "lens")]
+#[must_use]
+pub fn _11<T: TupleElement<11>>() -> LensImpl<T, T::Element, impl Lens<T, T::Element>>
+where
+    T::Element: Clone,
+{
+    element::<T, 11>()
+}
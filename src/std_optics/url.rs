@@ -0,0 +1,270 @@
+//! Optics focusing on the components of a `url::Url`.
+//!
+//! [`scheme`], [`host`], [`port`], [`path`] and [`query_param`] focus on the components of a URL.
+//! Their setters go through the validated mutators of `Url`, so a URL written through them is
+//! always valid. The components that can not be changed on every URL, such as the port of a
+//! `file:` URL, are written with lenses that also implement
+//! [`HasFallibleSetter`](crate::HasFallibleSetter), reporting the rejected writes with a
+//! [`UrlError`] while [`HasSetter::set`](crate::HasSetter::set) leaves the URL unchanged.
+//!
+//! These optics require the `url` feature.
+//!
+//! # Example
+//!
+//! ```rust
+//! use optics::{url, HasFallibleSetter, HasSetter, HasTotalGetter};
+//! use ::url::Url;
+//!
+//! let mut endpoint = Url::parse("http://localhost:8080/api?debug=1").unwrap();
+//!
+//! url::scheme().set(&mut endpoint, "https".to_string());
+//! url::port().set(&mut endpoint, None);
+//! url::query_param("debug").set(&mut endpoint, None);
+//! assert_eq!(endpoint.as_str(), "https://localhost/api");
+//!
+//! assert!(url::host().try_set(&mut endpoint, Some("exa mple.com".to_string())).is_err());
+//! assert_eq!(url::host().get(&endpoint), Some("localhost".to_string()));
+//! ```
+use crate::OpticsError;
+use core::fmt::{Display, Formatter};
+#[cfg(feature = "lens")]
+use {
+    crate::optics::lens::checked_lens,
+    crate::{HasFallibleSetter, Lens, LensImpl, mapped_lens},
+    ::url::Url,
+    alloc::{
+        string::{String, ToString},
+        vec::Vec,
+    },
+};
+
+/// The error returned when a component of a URL could not be written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UrlError {
+    /// The URL does not allow the component to be changed, or not to the given value, such as a
+    /// port on a `file:` URL, or a scheme turning a special URL into a non-special one.
+    Rejected {
+        /// The name of the component that was written.
+        component: &'static str,
+    },
+    /// The new host is not a valid host.
+    InvalidHost(::url::ParseError),
+}
+
+impl Display for UrlError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            UrlError::Rejected { component } => {
+                write!(f, "the {component} of the URL can not be set to this value")
+            }
+            UrlError::InvalidHost(e) => write!(f, "invalid host: {e}"),
+        }
+    }
+}
+
+impl core::error::Error for UrlError {}
+
+impl From<UrlError> for OpticsError {
+    fn from(e: UrlError) -> Self {
+        match e {
+            UrlError::Rejected { .. } => OpticsError::OutOfRange,
+            UrlError::InvalidHost(_) => OpticsError::ParseFailed,
+        }
+    }
+}
+
+/// Creates a `Lens` focusing on the scheme of a `Url`.
+///
+/// The scheme is written with [`Url::set_scheme`], which rejects invalid schemes and changes
+/// between special schemes, such as `http`, and non-special ones. Rejected writes are reported by
+/// [`HasFallibleSetter::try_set`] with a [`UrlError`], and ignored by [`HasSetter::set`].
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{url, HasFallibleSetter, HasTotalGetter};
+/// use ::url::Url;
+///
+/// let mut endpoint = Url::parse("ws://localhost/").unwrap();
+///
+/// assert_eq!(url::scheme().try_set(&mut endpoint, "wss".to_string()), Ok(()));
+/// assert!(url::scheme().try_set(&mut endpoint, "mailto".to_string()).is_err());
+/// assert_eq!(url::scheme().get(&endpoint), "wss");
+/// ```
+#[cfg(feature = "lens")]
+#[must_use]
+pub fn scheme() -> LensImpl<
+    Url,
+    String,
+    impl Lens<Url, String> + HasFallibleSetter<Url, String, SetterError = UrlError>,
+> {
+    checked_lens(
+        |url: &Url| url.scheme().to_string(),
+        |url: &mut Url, scheme: String| {
+            url.set_scheme(&scheme).map_err(|()| UrlError::Rejected {
+                component: "scheme",
+            })
+        },
+    )
+}
+
+/// Creates a `Lens` focusing on the host of a `Url`, as a string.
+///
+/// The host is `None` for URLs without one, such as `mailto:` URLs. It is written with
+/// [`Url::set_host`], which parses the new host, and rejects removing the host of URLs that
+/// require one. Rejected writes are reported by [`HasFallibleSetter::try_set`] with a
+/// [`UrlError`], and ignored by [`HasSetter::set`].
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{url, HasFallibleSetter, HasTotalGetter};
+/// use ::url::Url;
+///
+/// let mut endpoint = Url::parse("https://localhost/").unwrap();
+///
+/// assert_eq!(url::host().try_set(&mut endpoint, Some("example.com".to_string())), Ok(()));
+/// assert!(url::host().try_set(&mut endpoint, None).is_err());
+/// assert_eq!(url::host().get(&endpoint), Some("example.com".to_string()));
+/// ```
+#[cfg(feature = "lens")]
+#[must_use]
+#[allow(clippy::type_complexity)]
+pub fn host() -> LensImpl<
+    Url,
+    Option<String>,
+    impl Lens<Url, Option<String>> + HasFallibleSetter<Url, Option<String>, SetterError = UrlError>,
+> {
+    checked_lens(
+        |url: &Url| url.host_str().map(ToString::to_string),
+        |url: &mut Url, host: Option<String>| {
+            url.set_host(host.as_deref()).map_err(|e| match e {
+                ::url::ParseError::EmptyHost => UrlError::Rejected { component: "host" },
+                e => UrlError::InvalidHost(e),
+            })
+        },
+    )
+}
+
+/// Creates a `Lens` focusing on the port of a `Url`.
+///
+/// The port is `None` if it is not given, or is the default port of the scheme. It is written
+/// with [`Url::set_port`], which rejects ports on URLs that can not have one, such as `file:`
+/// URLs. Rejected writes are reported by [`HasFallibleSetter::try_set`] with a [`UrlError`], and
+/// ignored by [`HasSetter::set`].
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{url, HasFallibleSetter, HasTotalGetter};
+/// use ::url::Url;
+///
+/// let mut endpoint = Url::parse("https://localhost/").unwrap();
+///
+/// assert_eq!(url::port().try_set(&mut endpoint, Some(8443)), Ok(()));
+/// assert_eq!(endpoint.as_str(), "https://localhost:8443/");
+///
+/// let mut file = Url::parse("file:///etc/hosts").unwrap();
+/// assert!(url::port().try_set(&mut file, Some(80)).is_err());
+/// assert_eq!(url::port().get(&file), None);
+/// ```
+#[cfg(feature = "lens")]
+#[must_use]
+#[allow(clippy::type_complexity)]
+pub fn port() -> LensImpl<
+    Url,
+    Option<u16>,
+    impl Lens<Url, Option<u16>> + HasFallibleSetter<Url, Option<u16>, SetterError = UrlError>,
+> {
+    checked_lens(Url::port, |url: &mut Url, port| {
+        url.set_port(port)
+            .map_err(|()| UrlError::Rejected { component: "port" })
+    })
+}
+
+/// Creates a `Lens` focusing on the path of a `Url`.
+///
+/// The path is written with [`Url::set_path`], which percent-encodes the characters that are not
+/// allowed in a path, and can not fail.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{url, HasSetter, HasTotalGetter};
+/// use ::url::Url;
+///
+/// let mut endpoint = Url::parse("https://localhost/v1").unwrap();
+///
+/// url::path().set(&mut endpoint, "/v2/users list".to_string());
+/// assert_eq!(url::path().get(&endpoint), "/v2/users%20list");
+/// ```
+#[cfg(feature = "lens")]
+#[must_use]
+pub fn path() -> LensImpl<Url, String, impl Lens<Url, String>> {
+    mapped_lens(
+        |url: &Url| url.path().to_string(),
+        |url: &mut Url, path: String| url.set_path(&path),
+    )
+}
+
+/// Creates a `Lens` focusing on the value of the query parameter `name` of a `Url`.
+///
+/// Retrieving the focus decodes the value of the first parameter named `name`, or returns `None`
+/// if there is no such parameter. Setting `Some(value)` replaces the value of the first such
+/// parameter, or appends one, and removes the other parameters with the same name, while setting
+/// `None` removes all of them. The query is re-encoded with `application/x-www-form-urlencoded`
+/// serialization, and removed if no parameters are left.
+///
+/// # Arguments
+///
+/// - `name` — The name of the focused query parameter.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{url, HasSetter, HasTotalGetter};
+/// use ::url::Url;
+///
+/// let mut search = Url::parse("https://example.com/search?q=optics&page=2").unwrap();
+///
+/// assert_eq!(url::query_param("q").get(&search), Some("optics".to_string()));
+/// url::query_param("q").set(&mut search, Some("rust lenses".to_string()));
+/// url::query_param("page").set(&mut search, None);
+/// assert_eq!(search.as_str(), "https://example.com/search?q=rust+lenses");
+/// ```
+#[cfg(feature = "lens")]
+#[must_use]
+pub fn query_param(
+    name: impl Into<String>,
+) -> LensImpl<Url, Option<String>, impl Lens<Url, Option<String>>> {
+    let name = name.into();
+    let set_name = name.clone();
+
+    mapped_lens(
+        move |url: &Url| {
+            url.query_pairs()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| value.into_owned())
+        },
+        move |url: &mut Url, value: Option<String>| {
+            let mut value = value;
+            let mut pairs: Vec<(String, String)> = Vec::new();
+            for (key, existing) in url.query_pairs().into_owned() {
+                if key != set_name {
+                    pairs.push((key, existing));
+                } else if let Some(value) = value.take() {
+                    pairs.push((key, value));
+                }
+            }
+            if let Some(value) = value {
+                pairs.push((set_name.clone(), value));
+            }
+
+            if pairs.is_empty() {
+                url.set_query(None);
+            } else {
+                url.query_pairs_mut().clear().extend_pairs(pairs);
+            }
+        },
+    )
+}
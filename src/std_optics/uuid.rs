@@ -0,0 +1,87 @@
+//! Optics converting a `Uuid` to and from its textual and binary representations.
+//!
+//! [`uuid_str_iso`] parses identifiers stored as strings in configuration files and data transfer
+//! objects, failing with a [`uuid::Error`] if the string is not a valid UUID, so that they can be
+//! validated and edited as a `Uuid` through a composition. [`uuid_bytes_iso`] converts a `Uuid` to
+//! and from its 16 bytes.
+//!
+//! These optics require the `uuid` feature.
+//!
+//! # Example
+//!
+//! ```rust
+//! use optics::{uuid_bytes_iso, uuid_str_iso, HasGetter, HasSetter};
+//!
+//! let id = uuid_str_iso().compose_with_iso(uuid_bytes_iso());
+//! let mut request_id = "67e55044-10b1-426f-9247-bb680e5fe0c8".to_string();
+//!
+//! assert_eq!(id.try_get(&request_id).map(|b| b[0]), Ok(0x67));
+//! id.set(&mut request_id, [0; 16]);
+//! assert_eq!(request_id, "00000000-0000-0000-0000-000000000000");
+//! ```
+use crate::OpticsError;
+#[cfg(all(feature = "alloc", feature = "fallible-iso"))]
+use crate::{FallibleIso, FallibleIsoImpl, mapped_fallible_iso};
+#[cfg(feature = "iso")]
+use crate::{Iso, IsoImpl, mapped_iso};
+#[cfg(all(feature = "alloc", feature = "fallible-iso"))]
+use alloc::string::{String, ToString};
+#[cfg(all(feature = "alloc", feature = "fallible-iso"))]
+use core::convert::Infallible;
+#[cfg(any(feature = "iso", all(feature = "alloc", feature = "fallible-iso")))]
+use uuid::Uuid;
+
+impl From<uuid::Error> for OpticsError {
+    fn from(_: uuid::Error) -> Self {
+        OpticsError::ParseFailed
+    }
+}
+
+/// Creates a `FallibleIso` between a `String` and the `Uuid` it contains.
+///
+/// Retrieving the focus accepts every format [`Uuid::parse_str`] does, such as the hyphenated,
+/// simple, braced and URN forms, and fails with a [`uuid::Error`] if the string is not a valid
+/// UUID. The reverse direction always produces the lower case hyphenated form.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{uuid_str_iso, HasGetter, HasTotalReverseGet};
+///
+/// let id = uuid_str_iso().try_get(&"{67E55044-10B1-426F-9247-BB680E5FE0C8}".to_string()).unwrap();
+///
+/// assert_eq!(uuid_str_iso().reverse_get(&id), "67e55044-10b1-426f-9247-bb680e5fe0c8");
+/// assert!(uuid_str_iso().try_get(&"not-a-uuid".to_string()).is_err());
+/// ```
+#[cfg(all(feature = "alloc", feature = "fallible-iso"))]
+#[must_use]
+pub fn uuid_str_iso() -> FallibleIsoImpl<
+    String,
+    Uuid,
+    impl FallibleIso<String, Uuid, GetterError = uuid::Error, ReverseError = Infallible>,
+> {
+    mapped_fallible_iso(
+        |s: &String| Uuid::parse_str(s),
+        |id: &Uuid| Ok::<_, Infallible>(id.hyphenated().to_string()),
+    )
+}
+
+/// Creates an `Iso` between a `Uuid` and its 16 bytes, in big endian order.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{uuid_bytes_iso, HasTotalGetter, HasTotalReverseGet};
+/// use uuid::Uuid;
+///
+/// assert_eq!(uuid_bytes_iso().get(&Uuid::max()), [0xff; 16]);
+/// assert_eq!(uuid_bytes_iso().reverse_get(&[0; 16]), Uuid::nil());
+/// ```
+#[cfg(feature = "iso")]
+#[must_use]
+pub fn uuid_bytes_iso() -> IsoImpl<Uuid, [u8; 16], impl Iso<Uuid, [u8; 16]>> {
+    mapped_iso(
+        |id: &Uuid| *id.as_bytes(),
+        |bytes: &[u8; 16]| Uuid::from_bytes(*bytes),
+    )
+}
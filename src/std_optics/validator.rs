@@ -0,0 +1,193 @@
+//! Lenses re-running the `validator` constraints of their source on every write.
+//!
+//! [`validated`] and [`validated_field`] wrap a lens into a struct deriving
+//! `validator::Validate`, so a value written through them is checked against the
+//! `#[validate(...)]` attributes of the struct. A write that breaks them is rolled back: it is
+//! reported through [`HasFallibleSetter`](crate::HasFallibleSetter) with the
+//! `ValidationErrors`, and ignored by [`HasSetter::set`](crate::HasSetter::set), so tools
+//! editing a configuration through optics can not leave it invalid.
+//!
+//! These lenses require the `validator` feature.
+//!
+//! # Example
+//!
+//! ```rust
+//! use optics::{field_lens, validator, HasFallibleSetter, HasTotalGetter};
+//! use ::validator::Validate;
+//!
+//! #[derive(Clone, Validate)]
+//! struct Server {
+//!     #[validate(range(min = 1024))]
+//!     port: u16,
+//! }
+//!
+//! let port = validator::validated(field_lens!(Server, port));
+//! let mut server = Server { port: 8080 };
+//!
+//! assert!(port.try_set(&mut server, 80).is_err());
+//! assert_eq!(port.get(&server), 8080);
+//! ```
+use crate::OpticsError;
+use ::validator::ValidationErrors;
+#[cfg(feature = "lens")]
+use {
+    crate::{HasFallibleSetter, HasGetter, HasModify, HasSetter, HasTotalGetter, Lens, LensImpl},
+    ::validator::Validate,
+    core::marker::PhantomData,
+    std::collections::HashMap,
+};
+
+impl From<ValidationErrors> for OpticsError {
+    fn from(_: ValidationErrors) -> Self {
+        OpticsError::OutOfRange
+    }
+}
+
+#[cfg(feature = "lens")]
+struct ValidatedLens<L: Lens<S, A>, S: Validate, A> {
+    optic: L,
+    field: Option<&'static str>,
+    _phantom: PhantomData<(S, A)>,
+}
+
+#[cfg(feature = "lens")]
+impl<L: Lens<S, A>, S: Validate, A> HasGetter<S, A> for ValidatedLens<L, S, A> {
+    type GetterError = L::GetterError;
+
+    fn try_get(&self, source: &S) -> Result<A, Self::GetterError> {
+        self.optic.try_get(source)
+    }
+}
+
+#[cfg(feature = "lens")]
+impl<L: Lens<S, A>, S: Validate, A> HasSetter<S, A> for ValidatedLens<L, S, A> {
+    fn set(&self, source: &mut S, value: A) {
+        let _ = self.try_set(source, value);
+    }
+}
+
+#[cfg(feature = "lens")]
+impl<L: Lens<S, A>, S: Validate, A> HasFallibleSetter<S, A> for ValidatedLens<L, S, A> {
+    type SetterError = ValidationErrors;
+
+    fn try_set(&self, source: &mut S, value: A) -> Result<(), Self::SetterError> {
+        let previous = self.optic.get(source);
+        self.optic.set(source, value);
+
+        let errors = match (source.validate(), self.field) {
+            (Ok(()), _) => return Ok(()),
+            (Err(errors), None) => errors,
+            (Err(errors), Some(field)) => match errors.into_errors().remove(field) {
+                Some(kind) => ValidationErrors(HashMap::from([(field.into(), kind)])),
+                None => return Ok(()),
+            },
+        };
+
+        self.optic.set(source, previous);
+        Err(errors)
+    }
+}
+
+#[cfg(feature = "lens")]
+impl<L: Lens<S, A>, S: Validate, A> HasModify<S, A> for ValidatedLens<L, S, A> {}
+
+/// Creates a `Lens` focusing through `lens`, that validates the whole source after every write
+/// and rolls the write back if the source is no longer valid.
+///
+/// The rejected writes are reported by [`HasFallibleSetter::try_set`] with all the
+/// `ValidationErrors` of the source, so a write is also rejected while another field of the
+/// source is invalid. Use [`validated_field`] to only check the written field.
+///
+/// # Arguments
+///
+/// - `lens` — The lens to write through.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{field_lens, validator, HasFallibleSetter};
+/// use ::validator::Validate;
+///
+/// #[derive(Clone, Validate)]
+/// struct Account {
+///     #[validate(email)]
+///     email: String,
+/// }
+///
+/// let email = validator::validated(field_lens!(Account, email));
+/// let mut account = Account { email: "a@example.com".to_string() };
+///
+/// assert!(email.try_set(&mut account, "b@example.com".to_string()).is_ok());
+/// let errors = email.try_set(&mut account, "nobody".to_string()).unwrap_err();
+/// assert!(errors.field_errors().contains_key("email"));
+/// assert_eq!(account.email, "b@example.com");
+/// ```
+#[cfg(feature = "lens")]
+#[must_use]
+pub fn validated<L, S, A>(
+    lens: L,
+) -> LensImpl<S, A, impl Lens<S, A> + HasFallibleSetter<S, A, SetterError = ValidationErrors>>
+where
+    L: Lens<S, A>,
+    S: Validate,
+{
+    ValidatedLens {
+        optic: lens,
+        field: None,
+        _phantom: PhantomData,
+    }
+    .into()
+}
+
+/// Creates a `Lens` focusing through `lens` on the field `field` of a struct, that validates the
+/// struct after every write and rolls the write back if `field` is no longer valid.
+///
+/// Only the errors of `field` reject a write, and they are the only ones reported by
+/// [`HasFallibleSetter::try_set`], so a field can be corrected while other fields of the source
+/// are still invalid. Constraints spanning several fields, declared with `#[validate(schema(...))]`,
+/// are reported under the `__all__` field by `validator`.
+///
+/// # Arguments
+///
+/// - `lens` — The lens to write through.
+/// - `field` — The name of the field `lens` focuses on, as `validator` reports it.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{field_lens, validator, HasFallibleSetter};
+/// use ::validator::Validate;
+///
+/// #[derive(Clone, Validate)]
+/// struct Server {
+///     #[validate(length(min = 1))]
+///     name: String,
+///     #[validate(range(min = 1024))]
+///     port: u16,
+/// }
+///
+/// let port = validator::validated_field(field_lens!(Server, port), "port");
+/// let mut server = Server { name: String::new(), port: 8080 };
+///
+/// assert!(port.try_set(&mut server, 8081).is_ok());
+/// let errors = port.try_set(&mut server, 80).unwrap_err();
+/// assert_eq!(errors.field_errors().len(), 1);
+/// assert_eq!(server.port, 8081);
+/// ```
+#[cfg(feature = "lens")]
+#[must_use]
+pub fn validated_field<L, S, A>(
+    lens: L,
+    field: &'static str,
+) -> LensImpl<S, A, impl Lens<S, A> + HasFallibleSetter<S, A, SetterError = ValidationErrors>>
+where
+    L: Lens<S, A>,
+    S: Validate,
+{
+    ValidatedLens {
+        optic: lens,
+        field: Some(field),
+        _phantom: PhantomData,
+    }
+    .into()
+}
@@ -0,0 +1,122 @@
+//! Optics focusing on the elements of a `Vec`.
+//!
+//! These cover the most common ways of reaching into a `Vec` without writing the closures by hand:
+//! [`first`], [`last`] and [`get`] focus on a single existing element, while [`push_setter`]
+//! appends new elements to it.
+//!
+//! # Example
+//!
+//! ```rust
+//! use optics::{vec, HasGetter, HasSetter};
+//!
+//! let mut hosts = vec!["main".to_string()];
+//!
+//! vec::push_setter().set(&mut hosts, "aux".to_string());
+//! assert_eq!(vec::first().try_get(&hosts), Ok("main".to_string()));
+//! assert_eq!(vec::last().try_get(&hosts), Ok("aux".to_string()));
+//!
+//! vec::get(1).set(&mut hosts, "backup".to_string());
+//! assert_eq!(hosts, vec!["main".to_string(), "backup".to_string()]);
+//! ```
+#[cfg(feature = "prism")]
+use crate::IndexError;
+#[cfg(feature = "prism")]
+use crate::{HasInsert, Prism, PrismImpl, index_prism, mapped_prism};
+#[cfg(feature = "setter")]
+use crate::{Setter, SetterImpl, mapped_setter};
+#[cfg(any(feature = "prism", feature = "setter"))]
+use alloc::vec::Vec;
+
+/// Creates a `Prism` focusing on the first element of a `Vec`.
+///
+/// Retrieving the focus fails with an [`IndexError`] if the `Vec` is empty. Setting the focus
+/// only replaces an existing element, it is a no-op on an empty `Vec`.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{vec, HasGetter, IndexError};
+///
+/// assert_eq!(vec::first().try_get(&vec![1, 2, 3]), Ok(1));
+/// assert_eq!(vec::first::<u8>().try_get(&vec![]), Err(IndexError { index: 0, len: 0 }));
+/// ```
+#[cfg(feature = "prism")]
+#[must_use]
+pub fn first<T: Clone>() -> PrismImpl<Vec<T>, T, impl Prism<Vec<T>, T, GetterError = IndexError>> {
+    index_prism(0)
+}
+
+/// Creates a `Prism` focusing on the last element of a `Vec`.
+///
+/// Retrieving the focus fails with an [`IndexError`] if the `Vec` is empty. Setting the focus
+/// only replaces an existing element, it is a no-op on an empty `Vec`.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{vec, HasGetter, HasSetter};
+///
+/// let mut v = vec![1, 2, 3];
+///
+/// assert_eq!(vec::last().try_get(&v), Ok(3));
+/// vec::last().set(&mut v, 4);
+/// assert_eq!(v, vec![1, 2, 4]);
+/// ```
+#[cfg(feature = "prism")]
+#[must_use]
+pub fn last<T: Clone>() -> PrismImpl<Vec<T>, T, impl Prism<Vec<T>, T, GetterError = IndexError>> {
+    mapped_prism(
+        |v: &Vec<T>| v.last().cloned().ok_or(IndexError { index: 0, len: 0 }),
+        |v: &mut Vec<T>, value| {
+            if let Some(element) = v.last_mut() {
+                *element = value;
+            }
+        },
+    )
+}
+
+/// Creates a `Prism` focusing on the element at `index` of a `Vec`.
+///
+/// This is the same optic as [`index_prism`], provided here so that all the `Vec` optics can be
+/// reached through the same namespace. In addition, [`insert`](crate::HasInsert::insert) appends
+/// the value if `index` is the length of the `Vec`, and is a no-op if it is further out of bounds.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{vec, HasGetter, IndexError};
+///
+/// assert_eq!(vec::get(1).try_get(&vec![1, 2, 3]), Ok(2));
+/// assert_eq!(vec::get::<u8>(3).try_get(&vec![1, 2, 3]), Err(IndexError { index: 3, len: 3 }));
+/// ```
+#[cfg(feature = "prism")]
+#[must_use]
+pub fn get<T: Clone>(
+    index: usize,
+) -> PrismImpl<Vec<T>, T, impl Prism<Vec<T>, T, GetterError = IndexError> + HasInsert<Vec<T>, T>> {
+    index_prism(index).with_insert(move |v: &mut Vec<T>, value| {
+        if let Some(element) = v.get_mut(index) {
+            *element = value;
+        } else if index == v.len() {
+            v.push(value);
+        }
+    })
+}
+
+/// Creates a `Setter` that appends the value being set to the end of a `Vec`.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{vec, HasSetter};
+///
+/// let mut v = vec![1, 2];
+///
+/// vec::push_setter().set(&mut v, 3);
+/// assert_eq!(v, vec![1, 2, 3]);
+/// ```
+#[cfg(feature = "setter")]
+#[must_use]
+pub fn push_setter<T>() -> SetterImpl<Vec<T>, T, impl Setter<Vec<T>, T>> {
+    mapped_setter(|v: &mut Vec<T>, value| v.push(value))
+}
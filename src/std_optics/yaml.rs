@@ -0,0 +1,360 @@
+//! Optics focusing on the parts of a `serde_norway::Value`.
+//!
+//! [`key`] and [`index`] focus on an entry of a mapping and an element of a sequence, while
+//! [`as_str`], [`as_i64`], [`as_f64`] and [`as_bool`] convert a scalar to and from the primitive
+//! it contains. They fail with a [`DocumentError`] if the value is not of the expected type, or
+//! the entry or element is missing, so manifests can be addressed through optic chains.
+//!
+//! [`merged_key`] reads an entry the way YAML merge keys (`<<`) define it, falling back to the
+//! mappings merged into the mapping when it has no entry of its own.
+//!
+//! These optics require the `yaml` feature.
+//!
+//! # Example
+//!
+//! ```rust
+//! use optics::{yaml, DocumentError, HasGetter, HasSetter};
+//!
+//! let mut deployment: serde_norway::Value = serde_norway::from_str(
+//!     "spec:\n  replicas: 1\n  containers:\n    - image: nginx:1.25\n",
+//! ).unwrap();
+//! let replicas = yaml::key("spec")
+//!     .compose_with_prism::<DocumentError, _, _>(yaml::key("replicas"))
+//!     .compose_with_fallible_iso::<DocumentError, _, _>(yaml::as_i64());
+//! let image = yaml::key("spec")
+//!     .compose_with_prism::<DocumentError, _, _>(yaml::key("containers"))
+//!     .compose_with_prism::<DocumentError, _, _>(yaml::index(0))
+//!     .compose_with_prism::<DocumentError, _, _>(yaml::key("image"))
+//!     .compose_with_fallible_iso::<DocumentError, _, _>(yaml::as_str());
+//!
+//! replicas.set(&mut deployment, 3);
+//! image.set(&mut deployment, "nginx:1.27".to_string());
+//! assert_eq!(replicas.try_get(&deployment), Ok(3));
+//! assert_eq!(deployment["spec"]["containers"][0]["image"].as_str(), Some("nginx:1.27"));
+//! ```
+use crate::DocumentError;
+#[cfg(feature = "fallible-iso")]
+use crate::{FallibleIso, FallibleIsoImpl, mapped_fallible_iso};
+#[cfg(feature = "prism")]
+use crate::{HasInsert, Prism, PrismImpl, mapped_prism};
+use crate::{PartialGetter, PartialGetterImpl, mapped_partial_getter};
+use alloc::string::String;
+#[cfg(feature = "fallible-iso")]
+use core::convert::Infallible;
+use serde_norway::{Mapping, Value};
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Sequence(_) => "sequence",
+        Value::Mapping(_) => "mapping",
+        Value::Tagged(_) => "tagged value",
+    }
+}
+
+fn mismatch(expected: &'static str, found: &Value) -> DocumentError {
+    DocumentError::TypeMismatch {
+        expected,
+        found: type_name(found),
+    }
+}
+
+/// Looks `key` up in `mapping`, then in the mappings merged into it with `<<`, in order.
+fn merged_get<'a>(mapping: &'a Mapping, key: &str) -> Option<&'a Value> {
+    if let Some(value) = mapping.get(key) {
+        return Some(value);
+    }
+
+    match mapping.get("<<")? {
+        Value::Mapping(merged) => merged_get(merged, key),
+        Value::Sequence(merged) => merged
+            .iter()
+            .filter_map(Value::as_mapping)
+            .find_map(|merged| merged_get(merged, key)),
+        _ => None,
+    }
+}
+
+/// Creates a `Prism` focusing on the entry stored under `key` in a YAML mapping.
+///
+/// Retrieving the focus fails with a [`DocumentError`] if the value is not a mapping, or has no
+/// entry under the key. Entries merged in with `<<` are not followed, use [`merged_key`] to read
+/// them. Setting the focus only replaces an existing entry, while
+/// [`insert`](crate::HasInsert::insert) adds it, turning a `null` value into a mapping first.
+///
+/// # Arguments
+///
+/// - `key` — The key of the focused entry.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{yaml, HasGetter, HasInsert};
+/// use serde_norway::Value;
+///
+/// let mut labels = Value::Null;
+///
+/// yaml::key("app").insert(&mut labels, Value::from("web"));
+/// assert_eq!(yaml::key("app").try_get(&labels), Ok(Value::from("web")));
+/// assert!(yaml::key("tier").try_get(&labels).is_err());
+/// ```
+#[cfg(feature = "prism")]
+#[must_use]
+pub fn key(
+    key: impl Into<String>,
+) -> PrismImpl<
+    Value,
+    Value,
+    impl Prism<Value, Value, GetterError = DocumentError> + HasInsert<Value, Value>,
+> {
+    let key = key.into();
+    let set_key = key.clone();
+    let insert_key = key.clone();
+
+    mapped_prism(
+        move |value: &Value| match value {
+            Value::Mapping(entries) => entries
+                .get(&key)
+                .cloned()
+                .ok_or_else(|| DocumentError::KeyMissing { key: key.clone() }),
+            other => Err(mismatch("mapping", other)),
+        },
+        move |value: &mut Value, entry| {
+            if let Some(existing) = value.get_mut(&set_key) {
+                *existing = entry;
+            }
+        },
+    )
+    .with_insert(move |value: &mut Value, entry| {
+        if value.is_null() {
+            *value = Value::Mapping(Mapping::new());
+        }
+        if let Value::Mapping(entries) = value {
+            entries.insert(Value::String(insert_key.clone()), entry);
+        }
+    })
+}
+
+/// Creates a `PartialGetter` reading the entry stored under `key` in a YAML mapping, following
+/// merge keys.
+///
+/// If the mapping has no entry of its own under the key, the mappings merged into it with `<<`
+/// are searched, the earlier ones in a merged sequence taking precedence, as the YAML merge key
+/// type defines. Retrieving the focus fails with a [`DocumentError`] if the value is not a
+/// mapping, or neither the mapping nor the mappings merged into it have an entry under the key.
+///
+/// This optic is read only, as writing an entry that is merged in from an anchor would change
+/// every mapping the anchor is merged into.
+///
+/// # Arguments
+///
+/// - `key` — The key of the focused entry.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{yaml, HasGetter};
+/// use serde_norway::Value;
+///
+/// let doc: Value = serde_norway::from_str(
+///     "base: &base\n  cpu: 1\n  memory: 256\nworker:\n  <<: *base\n  cpu: 4\n",
+/// ).unwrap();
+/// let worker = &doc["worker"];
+///
+/// assert_eq!(yaml::merged_key("cpu").try_get(worker), Ok(Value::from(4)));
+/// assert_eq!(yaml::merged_key("memory").try_get(worker), Ok(Value::from(256)));
+/// assert!(yaml::key("memory").try_get(worker).is_err());
+/// ```
+#[must_use]
+pub fn merged_key(
+    key: impl Into<String>,
+) -> PartialGetterImpl<Value, Value, impl PartialGetter<Value, Value, GetterError = DocumentError>>
+{
+    let key = key.into();
+
+    mapped_partial_getter(move |value: &Value| match value {
+        Value::Mapping(entries) => merged_get(entries, &key)
+            .cloned()
+            .ok_or_else(|| DocumentError::KeyMissing { key: key.clone() }),
+        other => Err(mismatch("mapping", other)),
+    })
+}
+
+/// Creates a `Prism` focusing on the element at `index` of a YAML sequence.
+///
+/// Retrieving the focus fails with a [`DocumentError`] if the value is not a sequence, or the
+/// index is out of its bounds. Setting the focus only replaces an existing element.
+///
+/// # Arguments
+///
+/// - `index` — The index of the focused element.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{yaml, DocumentError, HasGetter, HasSetter};
+/// use serde_norway::Value;
+///
+/// let mut args = Value::Sequence(vec![Value::from("--verbose")]);
+///
+/// yaml::index(0).set(&mut args, Value::from("--quiet"));
+/// assert_eq!(args[0].as_str(), Some("--quiet"));
+/// assert_eq!(
+///     yaml::index(1).try_get(&args),
+///     Err(DocumentError::IndexOutOfBounds { index: 1, len: 1 })
+/// );
+/// ```
+#[cfg(feature = "prism")]
+#[must_use]
+pub fn index(
+    index: usize,
+) -> PrismImpl<Value, Value, impl Prism<Value, Value, GetterError = DocumentError>> {
+    mapped_prism(
+        move |value: &Value| match value {
+            Value::Sequence(elements) => {
+                elements
+                    .get(index)
+                    .cloned()
+                    .ok_or(DocumentError::IndexOutOfBounds {
+                        index,
+                        len: elements.len(),
+                    })
+            }
+            other => Err(mismatch("sequence", other)),
+        },
+        move |value: &mut Value, element| {
+            if let Some(existing) = value.get_mut(index) {
+                *existing = element;
+            }
+        },
+    )
+}
+
+/// Creates a `FallibleIso` between a YAML value and the string it contains.
+///
+/// Retrieving the focus fails with a [`DocumentError`] if the value is not a string. The reverse
+/// direction wraps the string into a value, and can not fail.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{yaml, HasGetter, HasTotalReverseGet};
+/// use serde_norway::Value;
+///
+/// assert_eq!(yaml::as_str().try_get(&Value::from("web")), Ok("web".to_string()));
+/// assert!(yaml::as_str().try_get(&Value::Null).is_err());
+/// assert_eq!(yaml::as_str().reverse_get(&"web".to_string()), Value::from("web"));
+/// ```
+#[cfg(feature = "fallible-iso")]
+#[must_use]
+pub fn as_str() -> FallibleIsoImpl<
+    Value,
+    String,
+    impl FallibleIso<Value, String, GetterError = DocumentError, ReverseError = Infallible>,
+> {
+    mapped_fallible_iso(
+        |value: &Value| match value {
+            Value::String(s) => Ok(s.clone()),
+            other => Err(mismatch("string", other)),
+        },
+        |s: &String| Ok::<_, Infallible>(Value::String(s.clone())),
+    )
+}
+
+/// Creates a `FallibleIso` between a YAML value and the signed integer it contains.
+///
+/// Retrieving the focus fails with a [`DocumentError`] if the value is not a number, or is a
+/// fractional one or out of the range of an `i64`. The reverse direction wraps the integer into a
+/// value, and can not fail.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{yaml, HasGetter, HasTotalReverseGet};
+/// use serde_norway::Value;
+///
+/// assert_eq!(yaml::as_i64().try_get(&Value::from(-3)), Ok(-3));
+/// assert!(yaml::as_i64().try_get(&Value::from(0.5)).is_err());
+/// assert_eq!(yaml::as_i64().reverse_get(&3), Value::from(3));
+/// ```
+#[cfg(feature = "fallible-iso")]
+#[must_use]
+pub fn as_i64() -> FallibleIsoImpl<
+    Value,
+    i64,
+    impl FallibleIso<Value, i64, GetterError = DocumentError, ReverseError = Infallible>,
+> {
+    mapped_fallible_iso(
+        |value: &Value| match value {
+            Value::Number(n) => n.as_i64().ok_or(DocumentError::TypeMismatch {
+                expected: "integer",
+                found: "number",
+            }),
+            other => Err(mismatch("integer", other)),
+        },
+        |n: &i64| Ok::<_, Infallible>(Value::from(*n)),
+    )
+}
+
+/// Creates a `FallibleIso` between a YAML value and the number it contains, as a float.
+///
+/// Retrieving the focus fails with a [`DocumentError`] if the value is not a number. Integers are
+/// converted to the nearest float. The reverse direction wraps the float into a value, and can
+/// not fail.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{yaml, HasGetter, HasTotalReverseGet};
+/// use serde_norway::Value;
+///
+/// assert_eq!(yaml::as_f64().try_get(&Value::from(0.5)), Ok(0.5));
+/// assert_eq!(yaml::as_f64().try_get(&Value::from(2)), Ok(2.0));
+/// assert_eq!(yaml::as_f64().reverse_get(&1.5), Value::from(1.5));
+/// ```
+#[cfg(feature = "fallible-iso")]
+#[must_use]
+pub fn as_f64() -> FallibleIsoImpl<
+    Value,
+    f64,
+    impl FallibleIso<Value, f64, GetterError = DocumentError, ReverseError = Infallible>,
+> {
+    mapped_fallible_iso(
+        |value: &Value| value.as_f64().ok_or_else(|| mismatch("number", value)),
+        |n: &f64| Ok::<_, Infallible>(Value::from(*n)),
+    )
+}
+
+/// Creates a `FallibleIso` between a YAML value and the boolean it contains.
+///
+/// Retrieving the focus fails with a [`DocumentError`] if the value is not a boolean. The reverse
+/// direction wraps the boolean into a value, and can not fail.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{yaml, HasGetter, HasTotalReverseGet};
+/// use serde_norway::Value;
+///
+/// assert_eq!(yaml::as_bool().try_get(&Value::from(true)), Ok(true));
+/// assert!(yaml::as_bool().try_get(&Value::from("yes")).is_err());
+/// assert_eq!(yaml::as_bool().reverse_get(&false), Value::from(false));
+/// ```
+#[cfg(feature = "fallible-iso")]
+#[must_use]
+pub fn as_bool() -> FallibleIsoImpl<
+    Value,
+    bool,
+    impl FallibleIso<Value, bool, GetterError = DocumentError, ReverseError = Infallible>,
+> {
+    mapped_fallible_iso(
+        |value: &Value| match value {
+            Value::Bool(b) => Ok(*b),
+            other => Err(mismatch("boolean", other)),
+        },
+        |b: &bool| Ok::<_, Infallible>(Value::Bool(*b)),
+    )
+}
@@ -0,0 +1,241 @@
+use crate::{HasGetter, HasModify, HasSetter};
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::cell::RefCell;
+use core::fmt::{Debug, Formatter};
+use core::marker::PhantomData;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+static NEXT_STORE_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// A container of application state that is read and written through optics, notifying
+/// subscribers of the parts of the state they focus on when those parts change.
+///
+/// Every write goes through [`update`](Store::update) or [`set`](Store::set), which bump the
+/// version of the state, so [`Selector`]s can skip recomputing their focus while the state is
+/// unchanged, and subscriptions can check whether their focus changed.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{Store, field_lens};
+/// use std::cell::Cell;
+/// use std::rc::Rc;
+///
+/// #[derive(Clone)]
+/// struct State {
+///     count: u32,
+///     label: String,
+/// }
+///
+/// let mut store = Store::new(State { count: 0, label: "clicks".to_string() });
+///
+/// let changes = Rc::new(Cell::new(0));
+/// let seen = changes.clone();
+/// store.subscribe(field_lens!(State, count), move |_: &u32| seen.set(seen.get() + 1));
+///
+/// store.update(&field_lens!(State, count), |count| *count += 1);
+/// store.set(&field_lens!(State, label), "taps".to_string());
+/// assert_eq!(changes.get(), 1);
+///
+/// let count = store.select(field_lens!(State, count));
+/// assert_eq!(count.get(&store), Ok(1));
+/// ```
+pub struct Store<S> {
+    id: usize,
+    state: S,
+    version: u64,
+    subscriptions: Vec<Subscription<S>>,
+    next_id: u64,
+}
+
+/// Identifies a subscription of a [`Store`], so it can be removed with
+/// [`unsubscribe`](Store::unsubscribe).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SubscriptionId(u64);
+
+struct Subscription<S> {
+    id: SubscriptionId,
+    notify: Box<dyn FnMut(&S)>,
+}
+
+impl<S> Store<S> {
+    /// Creates a new store holding `state`.
+    #[must_use]
+    pub fn new(state: S) -> Self {
+        Store {
+            id: NEXT_STORE_ID.fetch_add(1, Ordering::Relaxed),
+            state,
+            version: 0,
+            subscriptions: Vec::new(),
+            next_id: 0,
+        }
+    }
+
+    /// Returns the current state.
+    #[must_use]
+    pub fn state(&self) -> &S {
+        &self.state
+    }
+
+    /// Consumes the store, returning the current state.
+    #[must_use]
+    pub fn into_state(self) -> S {
+        self.state
+    }
+
+    /// Returns the version of the state, incremented on every write through the store.
+    #[must_use]
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    /// Creates a memoized [`Selector`] reading the focus of `getter` from the state of this store.
+    ///
+    /// # Parameters
+    ///
+    /// - `getter`: The optic to read the focus with.
+    #[must_use]
+    pub fn select<A, G: HasGetter<S, A>>(&self, getter: G) -> Selector<S, A, G> {
+        Selector {
+            getter,
+            last: RefCell::new(None),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Modifies the focus of `optic` in the state with `f`, then notifies the subscribers whose
+    /// focus changed.
+    ///
+    /// # Parameters
+    ///
+    /// - `optic`: The optic to modify the focus of.
+    /// - `f`: The function modifying the focus in place.
+    pub fn update<A, O: HasModify<S, A>>(&mut self, optic: &O, f: impl FnOnce(&mut A)) {
+        optic.modify(&mut self.state, f);
+        self.notify();
+    }
+
+    /// Sets the focus of `optic` in the state to `value`, then notifies the subscribers whose focus
+    /// changed.
+    ///
+    /// # Parameters
+    ///
+    /// - `optic`: The optic to set the focus of.
+    /// - `value`: The value to set the focus to.
+    pub fn set<A, O: HasSetter<S, A>>(&mut self, optic: &O, value: A) {
+        optic.set(&mut self.state, value);
+        self.notify();
+    }
+
+    /// Calls `callback` with the focus of `optic` every time a write through the store changes it.
+    ///
+    /// The focus is compared to the one seen after the previous write, so writes to other parts of
+    /// the state don't call `callback`. Writes that leave the optic without a focus don't call it
+    /// either.
+    ///
+    /// # Parameters
+    ///
+    /// - `optic`: The optic to read the focus with.
+    /// - `callback`: The function to call with the changed focus.
+    ///
+    /// # Returns
+    ///
+    /// The id of the subscription, to remove it with [`unsubscribe`](Store::unsubscribe).
+    pub fn subscribe<A, O>(
+        &mut self,
+        optic: O,
+        mut callback: impl FnMut(&A) + 'static,
+    ) -> SubscriptionId
+    where
+        O: HasGetter<S, A> + 'static,
+        A: PartialEq + 'static,
+    {
+        let mut last = optic.try_get(&self.state).ok();
+        let id = SubscriptionId(self.next_id);
+        self.next_id += 1;
+
+        self.subscriptions.push(Subscription {
+            id,
+            notify: Box::new(move |state| {
+                let focus = optic.try_get(state).ok();
+                if let Some(value) = focus.as_ref().filter(|value| last.as_ref() != Some(*value)) {
+                    callback(value);
+                }
+                last = focus;
+            }),
+        });
+        id
+    }
+
+    /// Removes the subscription `id`, so its callback is no longer called.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the subscription existed.
+    pub fn unsubscribe(&mut self, id: SubscriptionId) -> bool {
+        let len = self.subscriptions.len();
+        self.subscriptions
+            .retain(|subscription| subscription.id != id);
+        self.subscriptions.len() != len
+    }
+
+    fn notify(&mut self) {
+        self.version += 1;
+        for subscription in &mut self.subscriptions {
+            (subscription.notify)(&self.state);
+        }
+    }
+}
+
+impl<S: Debug> Debug for Store<S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Store")
+            .field("state", &self.state)
+            .field("id", &self.id)
+            .field("version", &self.version)
+            .field("subscriptions", &self.subscriptions.len())
+            .finish_non_exhaustive()
+    }
+}
+
+/// A memoized read of the focus of an optic from the state of a [`Store`], created by
+/// [`Store::select`].
+///
+/// The focus is only read again through the optic when the state was written since the previous
+/// read from the same store, otherwise a clone of the previously read focus is returned. The
+/// previous read is keyed by both the identity of the store and the version of its state, so a
+/// selector read from several stores never returns the focus read from another one.
+#[allow(clippy::type_complexity)]
+pub struct Selector<S, A, G: HasGetter<S, A>> {
+    getter: G,
+    last: RefCell<Option<(usize, u64, Result<A, G::GetterError>)>>,
+    _phantom: PhantomData<fn(&S)>,
+}
+
+impl<S, A, G> Selector<S, A, G>
+where
+    G: HasGetter<S, A>,
+    A: Clone,
+    G::GetterError: Clone,
+{
+    /// Returns the focus of the selector in the current state of `store`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the optic if the state has no focus.
+    pub fn get(&self, store: &Store<S>) -> Result<A, G::GetterError> {
+        if let Some((_, _, focus)) = self
+            .last
+            .borrow()
+            .as_ref()
+            .filter(|(id, version, _)| *id == store.id && *version == store.version)
+        {
+            return focus.clone();
+        }
+
+        let focus = self.getter.try_get(&store.state);
+        *self.last.borrow_mut() = Some((store.id, store.version, focus.clone()));
+        focus
+    }
+}
@@ -0,0 +1,71 @@
+use std::path::Path;
+use std::process::Command;
+use std::string::String;
+use std::vec::Vec;
+use std::{env, fs};
+
+/// Verifies that the forwarding layers of composed optics are optimized away completely.
+#[test]
+#[ignore = "builds the codegen example in release mode, run with --ignored"]
+fn composed_lens_get_compiles_to_direct_field_access() {
+    let target_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("target/codegen");
+
+    let status = Command::new(env!("CARGO"))
+        .args(["rustc", "--release", "--example", "codegen", "--target-dir"])
+        .arg(&target_dir)
+        .args(["--", "--emit=asm", "-Ccodegen-units=1"])
+        .status()
+        .expect("failed to run cargo");
+    assert!(status.success(), "failed to build the codegen example");
+
+    let asm = fs::read_dir(target_dir.join("release/examples"))
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .find(|path| {
+            path.extension().is_some_and(|ext| ext == "s")
+                && path
+                    .file_name()
+                    .unwrap()
+                    .to_string_lossy()
+                    .starts_with("codegen-")
+        })
+        .map(|path| fs::read_to_string(path).unwrap())
+        .expect("no assembly emitted for the codegen example");
+
+    assert_eq!(
+        instructions(&asm, "composed_lens_get"),
+        instructions(&asm, "direct_get")
+    );
+}
+
+/// Collects the instructions of the function `symbol`, skipping directives, labels and comments.
+///
+/// Functions with identical code may be merged into aliases by the compiler, these are resolved
+/// to the function they alias.
+fn instructions(asm: &str, symbol: &str) -> Vec<String> {
+    if let Some(target) = asm.lines().find_map(|line| {
+        line.trim_start_matches('_')
+            .strip_prefix(symbol)
+            .and_then(|rest| rest.strip_prefix(" = "))
+    }) {
+        return instructions(asm, target.trim_start_matches('_'));
+    }
+
+    let body: Vec<String> = asm
+        .lines()
+        .skip_while(|line| line.trim_start_matches('_') != format!("{symbol}:"))
+        .skip(1)
+        .map(str::trim)
+        .take_while(|line| *line != ".cfi_endproc" && !line.starts_with(".Lfunc_end"))
+        .filter(|line| {
+            !line.is_empty()
+                && !line.starts_with(['.', '#', ';'])
+                && !line.starts_with("//")
+                && !line.ends_with(':')
+        })
+        .map(String::from)
+        .collect();
+
+    assert!(!body.is_empty(), "function {symbol} not found in assembly");
+    body
+}
@@ -1,4 +1,5 @@
 mod code_quality;
+mod codegen;
 mod fixtures;
 pub mod helpers;
 
@@ -8,7 +9,14 @@ use crate::HasTotalGetter;
 use crate::optics::lens::{Lens, mapped_lens};
 use crate::optics::prism::{Prism, mapped_prism};
 use crate::test::fixtures::{Config, DatabaseConfig, Timespan};
-use crate::{FallibleIso, HasReverseGet, Iso, mapped_fallible_iso, mapped_iso};
+use crate::{
+    FallibleIso, HasGetAll, HasReverseGet, HasTraversal, IndexError, Iso, KeyMissing, LockPoisoned,
+    MapEmpty, NoneError, OpticsError, UnexpectedOk, arc_make_mut, array_each, array_lens, boxed,
+    btree_map, cell_lens, cow, deque, duration_millis_u32, duration_secs, enum_prism, enum_prisms,
+    field_lens, from_iso, index_prism, map, mapped_fallible_iso, mapped_iso, mutex_lens,
+    newtype_iso, num, option, parse_iso, rc_make_mut, ref_cell_lens, result, socket_addr, string,
+    try_from_iso, tuple, vec,
+};
 use alloc::string::{String, ToString};
 
 macro_rules! assert_impl {
@@ -192,3 +200,3556 @@ fn test_iso_and_fallible_iso() {
         Err("Too big".to_string())
     );
 }
+
+#[test]
+fn enum_prism_dispatches_on_variant_shape() {
+    let mut config = Config::default();
+
+    let minutes = enum_prism!(Timespan, Minutes(_));
+    let legacy_minutes = enum_prism!(Timespan, Minutes, tuple, (m));
+    let delay_lens = mapped_lens(|c: &Config| c.delay.clone(), |c, v| c.delay = v);
+    let delay_minutes = delay_lens.compose_with_prism(minutes);
+
+    assert_eq!(delay_minutes.try_get(&config), Ok(14));
+    assert_eq!(legacy_minutes.try_get(&config.delay), Ok(14));
+
+    delay_minutes.set(&mut config, 20);
+    assert_eq!(config.delay, Timespan::Minutes(20));
+
+    config.delay = Timespan::Hours(1);
+    assert_eq!(
+        delay_minutes.try_get(&config),
+        Err(OpticsError::VariantMismatch)
+    );
+}
+
+enum_prisms!(mod timespan for Timespan {
+    /// Timespans counted in seconds.
+    Seconds(u32),
+    Minutes(u32),
+    Hours(u32),
+});
+
+#[test]
+fn enum_prisms_generates_prism_for_every_variant() {
+    let mut delay = Timespan::Hours(2);
+
+    assert_eq!(
+        timespan::Seconds().try_get(&delay),
+        Err(OpticsError::VariantMismatch)
+    );
+    assert_eq!(
+        timespan::Minutes().try_get(&delay),
+        Err(OpticsError::VariantMismatch)
+    );
+    assert_eq!(timespan::Hours().try_get(&delay), Ok(2));
+
+    timespan::Seconds().set(&mut delay, 30);
+    assert_eq!(delay, Timespan::Seconds(30));
+}
+
+#[test]
+fn newtype_iso_unwraps_wrapper_types() {
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Port(u16);
+
+    let port_iso = newtype_iso!(Port, u16);
+    let mut port = Port(8080);
+
+    assert_eq!(port_iso.try_get(&port), Ok(8080));
+    port_iso.set(&mut port, 8081);
+    assert_eq!(port, Port(8081));
+}
+
+#[test]
+#[allow(clippy::used_underscore_items)]
+fn tuple_lenses_focus_multi_field_variants() {
+    #[derive(Debug, Clone, PartialEq)]
+    enum Shape {
+        Rect { width: u32, height: u32 },
+        Circle(u32),
+    }
+
+    let height = enum_prism!(Shape, Rect { width, height }).compose_with_lens(tuple::_1());
+    let mut shape = Shape::Rect {
+        width: 3,
+        height: 4,
+    };
+
+    assert_eq!(height.try_get(&shape), Ok(4));
+    height.set(&mut shape, 5);
+    assert_eq!(
+        shape,
+        Shape::Rect {
+            width: 3,
+            height: 5
+        }
+    );
+
+    shape = Shape::Circle(1);
+    assert_eq!(height.try_get(&shape), Err(OpticsError::VariantMismatch));
+}
+
+#[test]
+fn array_optics_focus_elements() {
+    let mut grid = [[1u8, 2], [3, 4]];
+
+    let corner = array_lens::<[u8; 2], 2, 1>().compose_with_lens(array_lens::<u8, 2, 0>());
+    assert_eq!(corner.get(&grid), 3);
+    corner.set(&mut grid, 5);
+    assert_eq!(grid, [[1, 2], [5, 4]]);
+
+    let cells = array_each::<[u8; 2], 2>().compose_with_traversal(array_each::<u8, 2>());
+    assert_eq!(cells.get_all(&grid), vec![1, 2, 5, 4]);
+    cells.over_all(&mut grid, &mut |c| c * 2);
+    assert_eq!(grid, [[2, 4], [10, 8]]);
+
+    let rows = array_each::<[u8; 2], 2>().compose_with_lens(array_lens::<u8, 2, 1>());
+    assert_eq!(rows.get_all(&grid), vec![4, 8]);
+}
+
+#[test]
+fn index_prism_reports_out_of_bounds_index() {
+    let mut config = Config::default();
+    let aux_host = mapped_lens(|c: &Config| c.aux.clone(), |c, v| c.aux = v)
+        .compose_with_prism(index_prism(1))
+        .compose_with_lens(mapped_lens(
+            |db: &DatabaseConfig| db.host.clone(),
+            |db, v| db.host = v,
+        ));
+
+    assert_eq!(aux_host.try_get(&config), Ok("aux2".to_string()));
+    aux_host.set(&mut config, "aux3".to_string());
+    assert_eq!(config.aux[1].host, "aux3");
+
+    config.aux.truncate(1);
+    assert_eq!(
+        aux_host.try_get(&config),
+        Err(IndexError { index: 1, len: 1 })
+    );
+    aux_host.set(&mut config, "aux4".to_string());
+    assert_eq!(config.aux.len(), 1);
+}
+
+#[test]
+fn compose_methods_take_bare_optics_and_wrappers_alike() {
+    let mut config = Config::default();
+    let host = || field_lens!(DatabaseConfig, host);
+
+    let wrapped = field_lens!(Config, aux)
+        .compose_with_prism(vec::first())
+        .compose_with_lens(host());
+    let bare = field_lens!(Config, aux)
+        .compose_with_prism(vec::first().0)
+        .compose_with_lens(host().0);
+    assert_eq!(bare.try_get(&config), wrapped.try_get(&config));
+
+    bare.set(&mut config, "aux0".to_string());
+    assert_eq!(wrapped.try_get(&config), Ok("aux0".to_string()));
+
+    let main_host = field_lens!(Config, main).compose_with_lens(host().0);
+    assert_eq!(main_host.get(&config), "main");
+}
+
+#[test]
+fn vec_optics_focus_aux_databases() {
+    let mut config = Config::default();
+    let aux = mapped_lens(|c: &Config| c.aux.clone(), |c, v| c.aux = v);
+    let host = mapped_lens(|db: &DatabaseConfig| db.host.clone(), |db, v| db.host = v);
+
+    let first_host = aux.compose_with_prism(vec::first()).compose_with_lens(host);
+    assert_eq!(first_host.try_get(&config), Ok("aux1".to_string()));
+
+    let aux = mapped_lens(|c: &Config| c.aux.clone(), |c, v| c.aux = v);
+    let main = config.main.clone();
+    aux.compose_with_setter(vec::push_setter())
+        .set(&mut config, main);
+    assert_eq!(config.aux.len(), 3);
+
+    let aux = mapped_lens(|c: &Config| c.aux.clone(), |c, v| c.aux = v);
+    let host = mapped_lens(|db: &DatabaseConfig| db.host.clone(), |db, v| db.host = v);
+    let last_host = aux.compose_with_prism(vec::last()).compose_with_lens(host);
+    assert_eq!(last_host.try_get(&config), Ok("main".to_string()));
+}
+
+#[test]
+fn map_optics_focus_values_by_key() {
+    let mut databases = std::collections::HashMap::new();
+    databases.insert("main", Config::default().main);
+
+    let main_host = map::value("main").compose_with_lens(mapped_lens(
+        |db: &DatabaseConfig| db.host.clone(),
+        |db, v| db.host = v,
+    ));
+    assert_eq!(main_host.try_get(&databases), Ok("main".to_string()));
+    main_host.set(&mut databases, "primary".to_string());
+    assert_eq!(databases["main"].host, "primary");
+
+    map::at("main").set(&mut databases, None);
+    assert!(databases.is_empty());
+    assert_eq!(
+        main_host.try_get(&databases),
+        Err(KeyMissing { key: "main" })
+    );
+}
+
+#[test]
+fn btree_map_optics_focus_ordered_entries() {
+    let mut delays = alloc::collections::BTreeMap::from([
+        (1, Timespan::Seconds(30)),
+        (2, Timespan::Minutes(5)),
+        (3, Timespan::Hours(1)),
+    ]);
+
+    assert_eq!(
+        btree_map::last_entry().try_get(&delays),
+        Ok(Timespan::Hours(1))
+    );
+    btree_map::value(1).set(&mut delays, Timespan::Seconds(45));
+    btree_map::at(4).set(&mut delays, Some(Timespan::Hours(2)));
+
+    let hours = btree_map::range_traversal(2..).compose_with_prism(enum_prism!(Timespan, Hours(_)));
+    assert_eq!(hours.get_all(&delays), vec![1, 2]);
+    hours.over_all(&mut delays, &mut |h| h * 2);
+    assert_eq!(delays[&4], Timespan::Hours(4));
+    assert_eq!(
+        btree_map::first_entry().try_get(&delays),
+        Ok(Timespan::Seconds(45))
+    );
+
+    delays.clear();
+    assert_eq!(btree_map::first_entry().try_get(&delays), Err(MapEmpty));
+}
+
+#[test]
+fn deque_optics_only_write_existing_elements() {
+    let mut recent = alloc::collections::VecDeque::from([Config::default().main]);
+    let back_host = deque::back().compose_with_lens(mapped_lens(
+        |db: &DatabaseConfig| db.host.clone(),
+        |db, v| db.host = v,
+    ));
+
+    assert_eq!(deque::front().try_get(&recent), Ok(Config::default().main));
+    back_host.set(&mut recent, "replica".to_string());
+    assert_eq!(back_host.try_get(&recent), Ok("replica".to_string()));
+
+    recent.clear();
+    back_host.set(&mut recent, "replica".to_string());
+    deque::get(0).set(&mut recent, Config::default().main);
+    assert!(recent.is_empty());
+    assert_eq!(
+        back_host.try_get(&recent),
+        Err(IndexError { index: 0, len: 0 })
+    );
+}
+
+#[test]
+fn option_prisms_compose_with_field_lenses() {
+    let mut config = Config::default();
+    let main_port = field_lens!(Config, main)
+        .compose_with_lens(field_lens!(DatabaseConfig, port))
+        .compose_with_prism(option::some());
+
+    assert_eq!(main_port.try_get(&config), Err(NoneError));
+    main_port.set(&mut config, 5432);
+    assert_eq!(main_port.try_get(&config), Ok(5432));
+
+    let mut delay = Some(Timespan::Seconds(30));
+    let seconds = option::some().compose_with_prism::<(), _, _>(enum_prism!(Timespan, Seconds(_)));
+    assert_eq!(seconds.try_get(&delay), Ok(30));
+    delay = None;
+    assert_eq!(seconds.try_get(&delay), Err(()));
+}
+
+#[test]
+fn result_prisms_keep_the_other_side() {
+    let mut config = Config::default();
+    let aux_result = field_lens!(Config, aux)
+        .compose_with_prism(vec::first())
+        .compose_with_lens(field_lens!(DatabaseConfig, create_result));
+
+    let created = field_lens!(Config, main)
+        .compose_with_lens(field_lens!(DatabaseConfig, create_result))
+        .compose_with_prism(result::ok());
+    assert_eq!(created.try_get(&config), Ok("ok".to_string()));
+
+    let failure = aux_result.compose_with_prism_with_mappers(
+        result::err(),
+        |_| None,
+        |ok: UnexpectedOk<String>| Some(ok.0),
+    );
+    assert_eq!(failure.try_get(&config), Ok("f1".to_string()));
+    failure.set(&mut config, "f3".to_string());
+    assert_eq!(config.aux[0].create_result, Err("f3".to_string()));
+
+    config.aux[0].create_result = Ok("recovered".to_string());
+    assert_eq!(failure.try_get(&config), Err(Some("recovered".to_string())));
+}
+
+#[test]
+fn pointer_lenses_cross_pointer_boundaries() {
+    let shared = alloc::rc::Rc::new(Config::default());
+    let mut config = alloc::rc::Rc::clone(&shared);
+    let main_host = rc_make_mut()
+        .compose_with_lens(field_lens!(Config, main))
+        .compose_with_lens(field_lens!(DatabaseConfig, host));
+
+    main_host.set(&mut config, "primary".to_string());
+    assert_eq!(main_host.get(&config), "primary");
+    assert_eq!(shared.main.host, "main");
+
+    let mut boxed_config = alloc::boxed::Box::new(Config::default());
+    boxed()
+        .compose_with_lens(field_lens!(Config, filename))
+        .set(&mut boxed_config, "efgh".to_string());
+    assert_eq!(boxed_config.filename, "efgh");
+
+    let mut arc_config = alloc::sync::Arc::new(Config::default());
+    arc_make_mut()
+        .compose_with_lens(field_lens!(Config, delay))
+        .set(&mut arc_config, Timespan::Hours(1));
+    assert_eq!(arc_config.delay, Timespan::Hours(1));
+}
+
+#[test]
+fn cell_optics_participate_in_compositions() {
+    struct Shared {
+        retries: core::cell::Cell<u8>,
+        config: core::cell::RefCell<Config>,
+    }
+
+    let mut shared = Shared {
+        retries: core::cell::Cell::new(3),
+        config: core::cell::RefCell::new(Config::default()),
+    };
+
+    let retries = mapped_lens(|s: &Shared| s.retries.clone(), |s, v| s.retries = v)
+        .compose_with_lens(cell_lens());
+    retries.set(&mut shared, 5);
+    assert_eq!(retries.get(&shared), 5);
+
+    let filename = ref_cell_lens().compose_with_lens(field_lens!(Config, filename));
+    filename.set(&mut shared.config, "efgh".to_string());
+    assert_eq!(
+        filename.try_get(&shared.config).ok(),
+        Some("efgh".to_string())
+    );
+
+    let guard = shared.config.borrow_mut();
+    assert!(filename.try_get(&shared.config).is_err());
+    drop(guard);
+}
+
+#[test]
+fn mutex_lens_reaches_into_shared_state() {
+    use std::sync::{Arc, Mutex};
+
+    let mut shared = Arc::new(Mutex::new(Config::default()));
+    let handle = Arc::clone(&shared);
+    let filename =
+        mutex_lens::<Arc<Mutex<Config>>, Config>().compose_with_lens(field_lens!(Config, filename));
+
+    filename.set(&mut shared, "efgh".to_string());
+    assert_eq!(handle.lock().unwrap().filename, "efgh");
+
+    let poisoner = Arc::clone(&shared);
+    let _ = std::thread::spawn(move || {
+        let _guard = poisoner.lock().unwrap();
+        panic!("poisoning the lock");
+    })
+    .join();
+
+    assert_eq!(filename.try_get(&shared), Err(LockPoisoned));
+    mutex_lens().set(&mut shared, Config::default());
+    assert_eq!(filename.try_get(&shared), Ok("abcd".to_string()));
+}
+
+#[test]
+fn cow_lens_only_promotes_on_write() {
+    use crate::{HasGetterRef, HasModify};
+    use alloc::borrow::Cow;
+
+    let hosts = ["main".to_string(), "aux".to_string()];
+    let mut borrowed: Cow<[String]> = Cow::Borrowed(&hosts);
+    let first_host = cow::owned().compose_with_prism(vec::first());
+
+    assert_eq!(first_host.try_get(&borrowed), Ok("main".to_string()));
+    assert!(matches!(borrowed, Cow::Borrowed(_)));
+
+    first_host.set(&mut borrowed, "primary".to_string());
+    assert!(matches!(borrowed, Cow::Owned(_)));
+    assert_eq!(borrowed[0], "primary");
+    assert_eq!(hosts[0], "main");
+
+    let mut config = Cow::Borrowed(&hosts[1]);
+    let host = cow::owned();
+    assert!(core::ptr::eq(
+        host.try_get_ref(&config).unwrap(),
+        &raw const hosts[1]
+    ));
+    host.modify(&mut config, |host| host.push_str("-1"));
+    assert_eq!(config, Cow::<String>::Owned("aux-1".to_string()));
+    assert_eq!(hosts[1], "aux");
+}
+
+#[test]
+fn string_prisms_perform_string_surgery() {
+    let mut config = Config::default();
+    let rest = field_lens!(Config, filename).compose_with_prism(string::strip_prefix("ab"));
+    let last = field_lens!(Config, filename).compose_with_prism(string::char_at(3));
+
+    assert_eq!(rest.try_get(&config), Ok("cd".to_string()));
+    rest.set(&mut config, "cx".to_string());
+    assert_eq!(last.try_get(&config), Ok('x'));
+    last.set(&mut config, 'y');
+    assert_eq!(config.filename, "abcy");
+
+    let suffix = field_lens!(Config, filename).compose_with_prism(string::strip_suffix(".toml"));
+    assert_eq!(suffix.try_get(&config), Err(crate::SuffixMissing));
+}
+
+#[test]
+fn parse_iso_parses_and_displays_strings() {
+    use std::net::{IpAddr, Ipv4Addr};
+
+    let mut db = Config::default().main;
+    let host_ip =
+        field_lens!(DatabaseConfig, host).compose_with_fallible_iso(parse_iso::<IpAddr>());
+
+    assert!(host_ip.try_get(&db).is_err());
+    host_ip.set(&mut db, IpAddr::V4(Ipv4Addr::LOCALHOST));
+    assert_eq!(db.host, "127.0.0.1");
+    assert_eq!(host_ip.try_get(&db), Ok(IpAddr::V4(Ipv4Addr::LOCALHOST)));
+}
+
+#[test]
+fn try_from_iso_narrows_numbers() {
+    let mut db = Config::default().aux[0].clone();
+    let port_byte = field_lens!(DatabaseConfig, port)
+        .compose_with_prism(option::some())
+        .compose_with_fallible_iso_with_mappers(
+            try_from_iso::<u16, u8>(),
+            |_| None,
+            |e: core::num::TryFromIntError| Some(e),
+        );
+
+    assert!(matches!(port_byte.try_get(&db), Err(Some(_))));
+    port_byte.set(&mut db, 80);
+    assert_eq!(db.port, Some(80));
+    assert_eq!(port_byte.try_get(&db), Ok(80));
+}
+
+#[test]
+fn from_iso_converts_mirror_types() {
+    #[derive(Debug, Clone, PartialEq)]
+    struct Endpoint {
+        host: String,
+        port: Option<u16>,
+    }
+
+    impl From<DatabaseConfig> for Endpoint {
+        fn from(db: DatabaseConfig) -> Self {
+            Endpoint {
+                host: db.host,
+                port: db.port,
+            }
+        }
+    }
+
+    impl From<Endpoint> for DatabaseConfig {
+        fn from(endpoint: Endpoint) -> Self {
+            DatabaseConfig {
+                host: endpoint.host,
+                port: endpoint.port,
+                create_result: Ok(String::new()),
+            }
+        }
+    }
+
+    let mut config = Config::default();
+    let main_endpoint =
+        field_lens!(Config, main).compose_with_iso(from_iso::<DatabaseConfig, Endpoint>());
+
+    assert_eq!(
+        main_endpoint.get(&config),
+        Endpoint {
+            host: "main".to_string(),
+            port: None
+        }
+    );
+    main_endpoint.set(
+        &mut config,
+        Endpoint {
+            host: "primary".to_string(),
+            port: Some(5432),
+        },
+    );
+    assert_eq!(config.main.host, "primary");
+    assert_eq!(config.main.port, Some(5432));
+}
+
+#[test]
+fn num_isos_convert_between_integers() {
+    let mut db = Config::default().aux[0].clone();
+    let port = field_lens!(DatabaseConfig, port)
+        .compose_with_prism(option::some())
+        .compose_with_fallible_iso_with_mappers(num::u16_to_u8(), |_| None, Some);
+
+    assert_eq!(port.try_get(&db), Err(Some(crate::NumericRangeError)));
+    port.set(&mut db, 80);
+    assert_eq!(db.port, Some(80));
+
+    let mut val: u32 = 3;
+    let u16_val = num::u32_to_u16();
+    assert_eq!(u16_val.try_get(&val), Ok(3));
+    u16_val.set(&mut val, 4);
+    assert_eq!(val, 4);
+}
+
+#[test]
+fn duration_optics_mirror_timespan_conversions() {
+    use core::time::Duration;
+
+    let mut delay = Duration::from_millis(840_500);
+    let minutes = duration_secs().compose_with_iso(mapped_iso(|s: &u64| s / 60, |m| m * 60));
+
+    assert_eq!(minutes.get(&delay), 14);
+    minutes.set(&mut delay, 15);
+    assert_eq!(delay, Duration::from_millis(900_500));
+    assert_eq!(duration_millis_u32().try_get(&delay), Ok(900_500));
+}
+
+#[test]
+fn socket_addr_optics_focus_parts() {
+    use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+
+    let mut db = Config::default().main;
+    let host_port = field_lens!(DatabaseConfig, host)
+        .compose_with_fallible_iso(parse_iso::<SocketAddr>())
+        .compose_with_lens(socket_addr::port());
+
+    assert!(host_port.try_get(&db).is_err());
+    db.host = "10.0.0.1:5432".to_string();
+    assert_eq!(host_port.try_get(&db).ok(), Some(5432));
+    host_port.set(&mut db, 5433);
+    assert_eq!(db.host, "10.0.0.1:5433");
+
+    let parts = parse_iso::<SocketAddr>().compose_with_iso(socket_addr::parts_iso());
+    assert_eq!(
+        parts.try_get(&db.host).ok(),
+        Some((IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)), 5433))
+    );
+}
+
+#[test]
+#[cfg(feature = "encoding")]
+fn encoding_isos_round_trip_bytes() {
+    use crate::{DecodeError, HasTotalReverseGet, base64_iso, hex_iso};
+
+    let mut config = Config::default();
+    let filename_bytes = field_lens!(Config, filename).compose_with_fallible_iso(hex_iso());
+
+    assert_eq!(filename_bytes.try_get(&config), Ok(vec![0xab, 0xcd]));
+    filename_bytes.set(&mut config, vec![0x01, 0x23]);
+    assert_eq!(config.filename, "0123");
+
+    config.filename = "0g".to_string();
+    assert_eq!(
+        filename_bytes.try_get(&config),
+        Err(DecodeError::InvalidCharacter { index: 1 })
+    );
+
+    for (bytes, base16, base64) in [
+        (&b""[..], "", ""),
+        (b"f", "66", "Zg=="),
+        (b"fo", "666f", "Zm8="),
+        (b"foo", "666f6f", "Zm9v"),
+        (b"foob", "666f6f62", "Zm9vYg=="),
+        (b"fooba", "666f6f6261", "Zm9vYmE="),
+        (b"foobar", "666f6f626172", "Zm9vYmFy"),
+    ] {
+        assert_eq!(hex_iso().reverse_get(&bytes.to_vec()), base16);
+        assert_eq!(
+            hex_iso().try_get(&base16.to_uppercase()),
+            Ok(bytes.to_vec())
+        );
+        assert_eq!(base64_iso().reverse_get(&bytes.to_vec()), base64);
+        assert_eq!(
+            base64_iso().try_get(&base64.to_string()),
+            Ok(bytes.to_vec())
+        );
+    }
+
+    for (malformed, error) in [
+        ("Zg", DecodeError::InvalidPadding),
+        ("Zg=", DecodeError::InvalidPadding),
+        ("Zg=a", DecodeError::InvalidCharacter { index: 2 }),
+        ("Z===", DecodeError::InvalidCharacter { index: 1 }),
+        ("Zm9v=", DecodeError::InvalidCharacter { index: 4 }),
+        ("Zm9vY", DecodeError::InvalidLength { len: 5 }),
+        ("Zh==", DecodeError::InvalidCharacter { index: 1 }),
+    ] {
+        assert_eq!(
+            base64_iso().try_get(&malformed.to_string()),
+            Err(error),
+            "{malformed}"
+        );
+    }
+    assert_eq!(
+        base64_iso().try_get(&"Zm9v=mFy".to_string()),
+        Err(DecodeError::InvalidCharacter { index: 4 })
+    );
+}
+
+#[test]
+#[cfg(feature = "uuid")]
+fn uuid_isos_validate_identifiers_stored_as_strings() {
+    use crate::{HasTotalReverseGet, uuid_bytes_iso, uuid_str_iso};
+    use uuid::Uuid;
+
+    let mut config = Config::default();
+    let id = field_lens!(Config, filename).compose_with_fallible_iso(uuid_str_iso());
+
+    assert_eq!(
+        id.try_get(&config).map_err(OpticsError::from),
+        Err(OpticsError::ParseFailed)
+    );
+
+    id.set(&mut config, Uuid::max());
+    assert_eq!(config.filename, "ffffffff-ffff-ffff-ffff-ffffffffffff");
+    assert_eq!(id.try_get(&config), Ok(Uuid::max()));
+
+    let bytes = uuid_str_iso().compose_with_iso(uuid_bytes_iso());
+    let mut raw = [0u8; 16];
+    raw[15] = 1;
+    assert_eq!(
+        bytes.reverse_get(&raw),
+        "00000000-0000-0000-0000-000000000001"
+    );
+    assert_eq!(uuid_bytes_iso().reverse_get(&raw).as_u128(), 1);
+}
+
+#[test]
+#[cfg(feature = "json")]
+fn json_optics_navigate_semi_structured_values() {
+    use crate::{DocumentError, HasInsert, json};
+    use serde_json::json;
+    use std::net::SocketAddr;
+
+    let mut config = json!({ "http": { "bind_address": "127.0.0.1:8080", "workers": 4 } });
+    let bind_port = json::key("http")
+        .compose_with_prism::<DocumentError, _, _>(json::key("bind_address"))
+        .compose_with_fallible_iso::<DocumentError, _, _>(json::as_str())
+        .compose_with_fallible_iso::<OpticsError, _, _>(parse_iso::<SocketAddr>())
+        .compose_with_lens(socket_addr::port());
+
+    assert_eq!(bind_port.try_get(&config), Ok(8080));
+    bind_port.set(&mut config, 8081);
+    assert_eq!(config["http"]["bind_address"], "127.0.0.1:8081");
+
+    let workers = json::key("http")
+        .compose_with_prism::<DocumentError, _, _>(json::key("workers"))
+        .compose_with_fallible_iso::<DocumentError, _, _>(json::as_u64());
+    assert_eq!(workers.try_get(&config), Ok(4));
+
+    let tls = json::key("http").compose_with_prism::<DocumentError, _, _>(json::key("tls"));
+    assert_eq!(
+        tls.try_get(&config),
+        Err(DocumentError::KeyMissing {
+            key: "tls".to_string()
+        })
+    );
+    tls.insert(&mut config, json!(true));
+    assert_eq!(tls.try_get(&config), Ok(json!(true)));
+
+    assert_eq!(
+        json::key("http").try_get(&json!([1])),
+        Err(DocumentError::TypeMismatch {
+            expected: "object",
+            found: "array"
+        })
+    );
+    assert_eq!(
+        OpticsError::from(json::index(0).try_get(&json!([])).unwrap_err()),
+        OpticsError::IndexOutOfBounds
+    );
+}
+
+#[test]
+#[cfg(feature = "json")]
+fn json_pointers_follow_rfc_6901() {
+    use crate::{DocumentError, HasInsert, json};
+    use serde_json::json;
+
+    let mut doc = json!({ "foo": ["bar", "baz"], "": 0, "a/b": 1, "m~n": 8, "k\"l": 6 });
+
+    for (pointer, expected) in [
+        ("", doc.clone()),
+        ("/foo", json!(["bar", "baz"])),
+        ("/foo/0", json!("bar")),
+        ("/", json!(0)),
+        ("/a~1b", json!(1)),
+        ("/m~0n", json!(8)),
+        ("/k\"l", json!(6)),
+    ] {
+        assert_eq!(json::pointer(pointer).unwrap().try_get(&doc), Ok(expected));
+    }
+
+    for pointer in ["foo", "/~2", "/m~"] {
+        assert_eq!(
+            json::pointer(pointer).err(),
+            Some(json::InvalidPointer {
+                pointer: pointer.to_string()
+            })
+        );
+    }
+
+    assert_eq!(
+        json::pointer("/foo/01").unwrap().try_get(&doc),
+        Err(DocumentError::TypeMismatch {
+            expected: "object",
+            found: "array"
+        })
+    );
+    assert_eq!(
+        json::pointer("/foo/2").unwrap().try_get(&doc),
+        Err(DocumentError::IndexOutOfBounds { index: 2, len: 2 })
+    );
+
+    let appended = json::pointer("/foo/-").unwrap();
+    appended.set(&mut doc, json!("ignored"));
+    appended.insert(&mut doc, json!("qux"));
+    json::pointer("/new").unwrap().insert(&mut doc, json!({}));
+    assert_eq!(doc["foo"], json!(["bar", "baz", "qux"]));
+    assert_eq!(doc["new"], json!({}));
+}
+
+#[test]
+#[cfg(feature = "toml")]
+fn toml_optics_edit_configuration_in_place() {
+    use crate::{DocumentError, HasInsert, toml};
+    use ::toml::Value;
+
+    let mut config = Value::Table(::toml::toml! {
+        [server]
+        port = 8080
+        ratio = 0.5
+        hosts = ["main", "aux"]
+    });
+
+    let port = toml::key("server")
+        .compose_with_prism::<DocumentError, _, _>(toml::key("port"))
+        .compose_with_fallible_iso::<DocumentError, _, _>(toml::as_integer())
+        .compose_with_fallible_iso::<OpticsError, _, _>(try_from_iso::<i64, u16>());
+    assert_eq!(port.try_get(&config), Ok(8080));
+    port.set(&mut config, 8081);
+    assert_eq!(config["server"]["port"].as_integer(), Some(8081));
+
+    let aux = toml::key("server")
+        .compose_with_prism::<DocumentError, _, _>(toml::key("hosts"))
+        .compose_with_prism::<DocumentError, _, _>(toml::index(1))
+        .compose_with_fallible_iso::<DocumentError, _, _>(toml::as_str());
+    aux.set(&mut config, "backup".to_string());
+    assert_eq!(config["server"]["hosts"][1].as_str(), Some("backup"));
+
+    assert_eq!(
+        toml::key("server")
+            .compose_with_prism::<DocumentError, _, _>(toml::key("ratio"))
+            .compose_with_fallible_iso::<DocumentError, _, _>(toml::as_integer())
+            .try_get(&config),
+        Err(DocumentError::TypeMismatch {
+            expected: "integer",
+            found: "float"
+        })
+    );
+
+    let debug =
+        toml::key("debug").compose_with_fallible_iso::<DocumentError, _, _>(toml::as_bool());
+    debug.set(&mut config, true);
+    assert!(debug.try_get(&config).is_err());
+    toml::key("debug").insert(&mut config, Value::Boolean(true));
+    assert_eq!(debug.try_get(&config), Ok(true));
+}
+
+#[test]
+#[cfg(feature = "yaml")]
+fn yaml_optics_address_manifests_and_follow_merge_keys() {
+    use crate::{DocumentError, yaml};
+    use serde_norway::Value;
+
+    let mut manifest: Value = serde_norway::from_str(
+        "defaults: &defaults\n  replicas: 1\n  debug: false\nlimits: &limits\n  cpu: 2\n\
+         web:\n  <<: [*defaults, *limits]\n  replicas: 3\n",
+    )
+    .unwrap();
+
+    let web = yaml::key("web");
+    let replicas = yaml::key("web")
+        .compose_with_prism::<DocumentError, _, _>(yaml::key("replicas"))
+        .compose_with_fallible_iso::<DocumentError, _, _>(yaml::as_i64());
+    assert_eq!(replicas.try_get(&manifest), Ok(3));
+    replicas.set(&mut manifest, 5);
+    assert_eq!(manifest["web"]["replicas"].as_i64(), Some(5));
+
+    let merged_debug = web
+        .compose_with_partial_getter::<DocumentError, _, _>(yaml::merged_key("debug"))
+        .compose_with_fallible_iso::<DocumentError, _, _>(yaml::as_bool());
+    assert_eq!(merged_debug.try_get(&manifest), Ok(false));
+    assert_eq!(
+        yaml::merged_key("cpu").try_get(&manifest["web"]),
+        Ok(Value::from(2))
+    );
+    assert_eq!(
+        yaml::merged_key("memory").try_get(&manifest["web"]),
+        Err(DocumentError::KeyMissing {
+            key: "memory".to_string()
+        })
+    );
+    assert_eq!(
+        yaml::index(0).try_get(&manifest),
+        Err(DocumentError::TypeMismatch {
+            expected: "sequence",
+            found: "mapping"
+        })
+    );
+}
+
+#[test]
+#[cfg(feature = "url")]
+fn url_lenses_write_through_validated_mutators() {
+    use crate::{HasFallibleSetter, url};
+    use ::url::Url;
+
+    let mut endpoint =
+        Url::parse("postgres://db.internal:5432/app?sslmode=disable&x=1&x=2").unwrap();
+
+    assert_eq!(url::port().get(&endpoint), Some(5432));
+    url::port().set(&mut endpoint, Some(6432));
+    url::path().set(&mut endpoint, "/reporting".to_string());
+    url::query_param("sslmode").set(&mut endpoint, Some("require".to_string()));
+    url::query_param("x").set(&mut endpoint, Some("3".to_string()));
+    assert_eq!(
+        endpoint.as_str(),
+        "postgres://db.internal:6432/reporting?sslmode=require&x=3"
+    );
+
+    url::query_param("sslmode").set(&mut endpoint, None);
+    url::query_param("x").set(&mut endpoint, None);
+    assert_eq!(endpoint.query(), None);
+    url::query_param("application_name").set(&mut endpoint, Some("optics".to_string()));
+    assert_eq!(endpoint.query(), Some("application_name=optics"));
+
+    let mut page = Url::parse("https://example.com/").unwrap();
+    assert_eq!(
+        url::scheme().try_set(&mut page, "data".to_string()),
+        Err(url::UrlError::Rejected {
+            component: "scheme"
+        })
+    );
+    assert_eq!(
+        url::host().try_set(&mut page, None),
+        Err(url::UrlError::Rejected { component: "host" })
+    );
+    assert_eq!(
+        url::host()
+            .try_set(&mut page, Some("[::1".to_string()))
+            .map_err(OpticsError::from),
+        Err(OpticsError::ParseFailed)
+    );
+    url::scheme().set(&mut page, "data".to_string());
+    assert_eq!(page.as_str(), "https://example.com/");
+}
+
+#[test]
+#[cfg(feature = "chrono")]
+fn chrono_optics_edit_timestamps_stored_as_strings() {
+    use crate::{HasFallibleSetter, HasTotalReverseGet, NumericRangeError, chrono};
+    use ::chrono::NaiveDate;
+
+    let mut config = Config {
+        filename: "2025-05-16T23:59:30.250+01:00".to_string(),
+        ..Config::default()
+    };
+    let at = field_lens!(Config, filename).compose_with_fallible_iso(chrono::rfc3339_iso());
+
+    let mut parsed = at.try_get(&config).unwrap();
+    assert_eq!(chrono::hour().get(&parsed), 22);
+    assert_eq!(
+        chrono::hour().try_set(&mut parsed, 24),
+        Err(NumericRangeError)
+    );
+    chrono::minute().set(&mut parsed, 60);
+    assert_eq!(chrono::minute().get(&parsed), 59);
+    chrono::date().set(&mut parsed, NaiveDate::from_ymd_opt(2024, 2, 29).unwrap());
+    at.set(&mut config, parsed);
+    assert_eq!(config.filename, "2024-02-29T22:59:30.250Z");
+
+    let timestamp = chrono::unix_timestamp_iso().compose_with_lens(chrono::second());
+    let mut secs = 90i64;
+    assert_eq!(timestamp.try_get(&secs), Ok(30));
+    timestamp.set(&mut secs, 0);
+    assert_eq!(secs, 60);
+    assert_eq!(
+        chrono::unix_timestamp_iso().reverse_get(&parsed),
+        1_709_247_570
+    );
+    assert_eq!(
+        at.try_get(&Config::default()).map_err(OpticsError::from),
+        Err(OpticsError::ParseFailed)
+    );
+}
+
+#[test]
+#[cfg(feature = "time")]
+fn time_optics_edit_timestamps_stored_as_strings() {
+    use crate::{HasFallibleSetter, time};
+    use ::time::{Date, Month};
+
+    let mut config = Config {
+        filename: "2025-05-16T23:59:30+01:00".to_string(),
+        ..Config::default()
+    };
+    let at = field_lens!(Config, filename).compose_with_fallible_iso(time::rfc3339_iso());
+
+    let mut parsed = at.try_get(&config).unwrap();
+    assert_eq!(time::hour().get(&parsed), 23);
+    assert_eq!(
+        time::hour()
+            .try_set(&mut parsed, 24)
+            .map_err(OpticsError::from),
+        Err(OpticsError::OutOfRange)
+    );
+    time::second().set(&mut parsed, 60);
+    assert_eq!(time::second().get(&parsed), 30);
+    time::date().set(
+        &mut parsed,
+        Date::from_calendar_date(2024, Month::February, 29).unwrap(),
+    );
+    at.set(&mut config, parsed);
+    assert_eq!(config.filename, "2024-02-29T23:59:30+01:00");
+
+    let minute = time::unix_timestamp_iso().compose_with_lens(time::minute());
+    let mut secs = 0i64;
+    minute.set(&mut secs, 2);
+    assert_eq!(secs, 120);
+}
+
+#[test]
+#[cfg(feature = "im")]
+fn im_optics_update_persistent_collections_without_touching_old_versions() {
+    use crate::{HasGetAll, HasInsert, HasTraversal, IndexError, KeyMissing, im};
+    use ::im::{HashMap, OrdMap, Vector};
+
+    let before: Vector<u32> = (0..100).collect();
+    let mut after = before.clone();
+    im::vector_get(7).set(&mut after, 700);
+    im::vector_get(100).insert(&mut after, 100);
+    im::vector_get(500).insert(&mut after, 500);
+    assert_eq!(im::vector_get(7).try_get(&before), Ok(7));
+    assert_eq!(im::vector_get(7).try_get(&after), Ok(700));
+    assert_eq!(
+        im::vector_get(101).try_get(&after),
+        Err(IndexError {
+            index: 101,
+            len: 101
+        })
+    );
+    im::vector_each().over_all(&mut after, &mut |n| n % 10);
+    assert_eq!(im::vector_each().get_all(&after).iter().sum::<u32>(), 443);
+    assert_eq!(before.len(), 100);
+
+    let mut env: HashMap<&str, &str> = HashMap::new();
+    let snapshot = env.clone();
+    im::hash_map_at("HOME").set(&mut env, Some("/root"));
+    im::hash_map_value("SHELL").set(&mut env, "sh");
+    assert_eq!(
+        im::hash_map_value("SHELL").try_get(&env),
+        Err(KeyMissing { key: "SHELL" })
+    );
+    im::hash_map_each().over_all(&mut env, &mut |_| "/");
+    assert_eq!(im::hash_map_at("HOME").get(&env), Some("/"));
+    assert!(snapshot.is_empty());
+
+    let mut scores: OrdMap<&str, u32> = OrdMap::new();
+    im::ord_map_value("bob").insert(&mut scores, 5);
+    im::ord_map_at("alice").set(&mut scores, Some(3));
+    let snapshot = scores.clone();
+    im::ord_map_each().over_all(&mut scores, &mut |s| s * 10);
+    assert_eq!(im::ord_map_each().get_all(&scores), vec![30, 50]);
+    assert_eq!(im::ord_map_each().get_all(&snapshot), vec![3, 5]);
+    im::ord_map_at("bob").set(&mut scores, None);
+    assert_eq!(im::ord_map_value("alice").try_get(&scores), Ok(30));
+    assert_eq!(scores.len(), 1);
+}
+
+#[test]
+#[cfg(feature = "bevy_reflect")]
+fn reflect_lenses_follow_runtime_paths() {
+    use crate::{ReflectError, reflect_lens};
+    use bevy_reflect::Reflect;
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    enum Shape {
+        Circle(f32),
+        Rect(f32, f32),
+    }
+
+    #[derive(Reflect)]
+    struct Scene {
+        shapes: Vec<Shape>,
+        name: String,
+    }
+
+    let mut scene = Scene {
+        shapes: vec![Shape::Circle(1.0), Shape::Rect(2.0, 3.0)],
+        name: "main".to_string(),
+    };
+
+    let radius = reflect_lens::<Scene, f32>("shapes[0].0").unwrap();
+    radius.set(&mut scene, 4.0);
+    assert_eq!(radius.try_get(&scene), Ok(4.0));
+
+    let height = reflect_lens::<Scene, f32>("shapes[1].1").unwrap();
+    assert_eq!(height.try_get(&scene), Ok(3.0));
+
+    let wrong_variant = reflect_lens::<Scene, f32>("shapes[0].1").unwrap();
+    assert!(matches!(
+        wrong_variant.try_get(&scene),
+        Err(ReflectError::Inaccessible { .. })
+    ));
+    wrong_variant.set(&mut scene, 9.0);
+    assert_eq!(scene.shapes[0], Shape::Circle(4.0));
+
+    let first = reflect_lens::<Scene, Shape>("shapes[0]").unwrap();
+    first.set(&mut scene, Shape::Rect(5.0, 6.0));
+    assert_eq!(wrong_variant.try_get(&scene), Ok(6.0));
+
+    let name_len = reflect_lens::<Scene, String>("name")
+        .unwrap()
+        .compose_with_lens(mapped_lens(String::len, |s: &mut String, len| {
+            s.truncate(len);
+        }));
+    assert_eq!(name_len.try_get(&scene), Ok(4));
+
+    assert_eq!(
+        reflect_lens::<Scene, u8>("name")
+            .unwrap()
+            .try_get(&scene)
+            .map_err(OpticsError::from),
+        Err(OpticsError::VariantMismatch)
+    );
+    assert!(matches!(
+        reflect_lens::<Scene, f32>("shapes[0"),
+        Err(ReflectError::InvalidPath { .. })
+    ));
+}
+
+#[test]
+#[cfg(feature = "validator")]
+fn validated_lenses_keep_sources_valid() {
+    use crate::{HasFallibleSetter, validator};
+    use ::validator::Validate;
+
+    #[derive(Clone, Validate)]
+    struct Limits {
+        #[validate(range(min = 1, max = 64))]
+        workers: u8,
+        #[validate(length(min = 1))]
+        queue: String,
+    }
+
+    let mut limits = Limits {
+        workers: 4,
+        queue: String::new(),
+    };
+
+    let workers = validator::validated(field_lens!(Limits, workers));
+    assert!(workers.try_set(&mut limits, 8).is_err());
+    assert_eq!(limits.workers, 4);
+
+    let workers = validator::validated_field(field_lens!(Limits, workers), "workers");
+    assert_eq!(workers.try_set(&mut limits, 8), Ok(()));
+    workers.set(&mut limits, 0);
+    assert_eq!(workers.get(&limits), 8);
+    assert_eq!(
+        workers.try_set(&mut limits, 65).map_err(OpticsError::from),
+        Err(OpticsError::OutOfRange)
+    );
+
+    let queue = validator::validated_field(field_lens!(Limits, queue), "queue");
+    let queue_len = queue.compose_with_lens(mapped_lens(String::len, |s: &mut String, len| {
+        *s = "q".repeat(len);
+    }));
+    queue_len.set(&mut limits, 3);
+    assert_eq!(limits.queue, "qqq");
+    queue_len.set(&mut limits, 0);
+    assert_eq!(limits.queue, "qqq");
+    assert!(limits.validate().is_ok());
+}
+
+#[test]
+#[cfg(feature = "proptest")]
+fn provided_optics_obey_the_laws() {
+    use crate::laws::proptest::{iso_laws, lens_laws, prism_laws};
+    use crate::{btree_map, map, option};
+    use proptest::prelude::*;
+    use std::collections::{BTreeMap, HashMap};
+
+    lens_laws(
+        any::<BTreeMap<u8, u8>>(),
+        any::<Option<u8>>(),
+        &btree_map::at(3),
+    )
+    .unwrap();
+    prism_laws(any::<Option<u8>>(), any::<u8>(), &option::some()).unwrap();
+    prism_laws(any::<HashMap<u8, u8>>(), any::<u8>(), &map::value(3)).unwrap();
+    iso_laws(
+        any::<(u8, bool)>(),
+        any::<(bool, u8)>(),
+        &crate::std_isos::swap(),
+    )
+    .unwrap();
+
+    let clamped = mapped_lens(|n: &u8| *n, |n, v: u8| *n = v.min(100));
+    assert!(lens_laws(any::<u8>(), any::<u8>(), &clamped).is_err());
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn serde_focus_reads_and_writes_partial_payloads() {
+    use crate::serde_focus;
+    use serde::de::DeserializeSeed;
+
+    let mut config = Config::default();
+    let main = serde_focus(
+        field_lens!(Config, main)
+            .compose_with_lens(field_lens!(DatabaseConfig, host))
+            .zip(field_lens!(Config, main).compose_with_lens(field_lens!(DatabaseConfig, port))),
+    );
+
+    assert_eq!(
+        serde_json::to_value(main.serialize(&config)).unwrap(),
+        serde_json::json!([config.main.host, config.main.port])
+    );
+
+    let mut body = serde_json::Deserializer::from_str(r#"["db.internal", null]"#);
+    main.deserialize_into(&mut config)
+        .deserialize(&mut body)
+        .unwrap();
+    assert_eq!(config.main.host, "db.internal");
+    assert_eq!(config.main.port, None);
+    assert_eq!(config.aux, Config::default().aux);
+
+    let mut malformed = serde_json::Deserializer::from_str(r#"["db.internal"]"#);
+    assert!(
+        main.deserialize_into(&mut config)
+            .deserialize(&mut malformed)
+            .is_err()
+    );
+
+    let seconds = serde_focus(
+        field_lens!(Config, delay).compose_with_prism(enum_prism!(Timespan, Seconds(_))),
+    );
+    let error = serde_json::to_string(&seconds.serialize(&config)).unwrap_err();
+    assert!(error.to_string().contains("no focus"));
+}
+
+#[cfg(all(feature = "fallible-iso", any(feature = "figment", feature = "config")))]
+fn override_registry<'a>() -> crate::PatchRegistry<'a, Config> {
+    use crate::{PatchRegistry, mapped_fallible_iso};
+    use core::num::ParseIntError;
+
+    let minutes = mapped_fallible_iso(
+        |s: &String| s.trim_end_matches('m').parse().map(Timespan::Minutes),
+        |delay: &Timespan| Ok::<_, ParseIntError>(format!("{delay:?}")),
+    );
+
+    let mut registry = PatchRegistry::new();
+    registry
+        .register(field_lens!(Config, filename))
+        .register(field_lens!(Config, main).compose_with_lens(field_lens!(DatabaseConfig, host)))
+        .register_with(field_lens!(Config, delay), minutes);
+    registry
+}
+
+#[test]
+#[cfg(all(feature = "figment", feature = "fallible-iso"))]
+fn figment_overrides_are_written_through_registered_optics() {
+    use ::figment::{Figment, providers::Serialized};
+
+    let registry = override_registry();
+    let provider = Figment::new()
+        .merge(Serialized::default("delay", "90m"))
+        .merge(Serialized::default("main.host", "db.internal"))
+        .merge(Serialized::default("main.port", 5432));
+
+    let mut config = Config::default();
+    crate::figment::overrides(&provider, &registry)
+        .unwrap()
+        .apply(&mut config, &registry)
+        .unwrap();
+
+    assert_eq!(config.delay, Timespan::Minutes(90));
+    assert_eq!(config.main.host, "db.internal");
+    assert_eq!(config.main.port, None);
+    assert_eq!(config.filename, Config::default().filename);
+
+    let invalid = Figment::from(Serialized::default("delay", "soon"));
+    let errors = crate::figment::overrides(&invalid, &registry)
+        .unwrap()
+        .apply(&mut config, &registry)
+        .unwrap_err();
+    assert_eq!(errors[0].id(), "delay");
+    assert_eq!(config.delay, Timespan::Minutes(90));
+}
+
+#[test]
+#[cfg(all(feature = "config", feature = "fallible-iso"))]
+fn config_overrides_are_written_through_registered_optics() {
+    let registry = override_registry();
+    let source = ::config::Config::builder()
+        .set_override("delay", "90m")
+        .unwrap()
+        .set_override("filename", "app.toml")
+        .unwrap()
+        .build()
+        .unwrap();
+
+    let patch = crate::config::overrides(&source, &registry).unwrap();
+    assert_eq!(
+        patch.entries().collect::<Vec<_>>(),
+        [("delay", "90m"), ("filename", "app.toml")]
+    );
+
+    let mut config = Config::default();
+    patch.apply(&mut config, &registry).unwrap();
+    assert_eq!(config.delay, Timespan::Minutes(90));
+    assert_eq!(config.filename, "app.toml");
+    assert_eq!(config.main.host, Config::default().main.host);
+}
+
+#[test]
+fn setters_compose_with_isos_through_their_reverse_direction() {
+    use crate::{
+        ComposeWith, Either, HasFallibleSetter, mapped_fallible_iso, mapped_fallible_setter,
+        mapped_setter,
+    };
+    use core::num::ParseIntError;
+
+    let delay = mapped_setter(|config: &mut Config, delay: Timespan| config.delay = delay);
+    let minutes = mapped_iso(
+        |delay: &Timespan| match delay {
+            Timespan::Seconds(s) => *s / 60,
+            Timespan::Minutes(m) => *m,
+            Timespan::Hours(h) => *h * 60,
+        },
+        |minutes: &u32| Timespan::Minutes(*minutes),
+    );
+    let formatted = mapped_fallible_iso(
+        |minutes: &u32| Ok::<_, ParseIntError>(minutes.to_string()),
+        |minutes: &String| minutes.parse::<u32>(),
+    );
+    let mut config = Config::default();
+
+    delay.then(minutes).set(&mut config, 30);
+    assert_eq!(config.delay, Timespan::Minutes(30));
+
+    let parsed = delay.then(minutes).then(formatted);
+    assert!(parsed.try_set(&mut config, "soon".to_string()).is_err());
+    assert_eq!(config.delay, Timespan::Minutes(30));
+    assert!(parsed.try_set(&mut config, "45".to_string()).is_ok());
+    assert_eq!(config.delay, Timespan::Minutes(45));
+
+    let filename = mapped_fallible_setter(|config: &mut Config, name: String| {
+        if name.len() > 3 {
+            return Err("filename too long");
+        }
+        config.filename = name;
+        Ok(())
+    });
+    let numbered = filename >> parse_iso::<u32>();
+    assert!(numbered.try_set(&mut config, 42).is_ok());
+    assert_eq!(config.filename, "42");
+    assert!(matches!(
+        numbered.try_set(&mut config, 1234),
+        Err(Either::Left("filename too long"))
+    ));
+    assert_eq!(config.filename, "42");
+}
+
+#[test]
+fn setters_reading_their_focus_compose_with_lenses_prisms_and_traversals() {
+    use crate::{
+        ComposeWith, Either, FallibleSetterImpl, HasFallibleSetter, SetterImpl,
+        mapped_fallible_setter, mapped_traversal,
+    };
+
+    let aux = || SetterImpl::from(field_lens!(Config, aux).0);
+    let hosts = || {
+        mapped_traversal(
+            |dbs: &Vec<DatabaseConfig>, f: &mut dyn FnMut(String)| {
+                for db in dbs {
+                    f(db.host.clone());
+                }
+            },
+            |dbs: &mut Vec<DatabaseConfig>, f: &mut dyn FnMut(String) -> String| {
+                for db in dbs {
+                    db.host = f(db.host.clone());
+                }
+            },
+        )
+    };
+    let mut config = Config::default();
+
+    SetterImpl::from(field_lens!(Config, main).0)
+        .compose_with_lens(field_lens!(DatabaseConfig, host))
+        .set(&mut config, "primary".to_string());
+    assert_eq!(config.main.host, "primary");
+
+    let primary = config.main.clone();
+    let first = aux().compose_with_prism(vec::first());
+    first.set(&mut config, primary.clone());
+    assert_eq!(config.aux[0].host, "primary");
+    assert_eq!(config.aux.len(), 2);
+
+    (aux() >> hosts()).set(&mut config, "replica".to_string());
+    assert!(config.aux.iter().all(|db| db.host == "replica"));
+
+    config.aux.clear();
+    first.set(&mut config, primary);
+    assert!(config.aux.is_empty());
+
+    let main = FallibleSetterImpl::from(
+        field_lens!(Config, main)
+            .with_validation(|db: &DatabaseConfig| {
+                if db.host.is_empty() {
+                    return Err("empty host");
+                }
+                Ok(())
+            })
+            .0,
+    );
+    let host = main.compose_with_lens(field_lens!(DatabaseConfig, host));
+    assert_eq!(host.try_set(&mut config, String::new()), Err("empty host"));
+    assert_eq!(config.main.host, "primary");
+    assert_eq!(host.try_set(&mut config, "backup".to_string()), Ok(()));
+    assert_eq!(config.main.host, "backup");
+
+    let short_host = mapped_fallible_setter(|host: &mut String, value: String| {
+        if value.len() > 8 {
+            return Err("host too long");
+        }
+        *host = value;
+        Ok(())
+    });
+    let main_host = FallibleSetterImpl::from(
+        field_lens!(Config, main)
+            .compose_with_lens(field_lens!(DatabaseConfig, host))
+            .with_validation(|host: &String| {
+                if host.is_empty() {
+                    return Err("empty host");
+                }
+                Ok(())
+            })
+            .0,
+    );
+    let checked = main_host.then(short_host);
+    assert!(matches!(
+        checked.try_set(&mut config, "a-very-long-host".to_string()),
+        Err(Either::Right("host too long"))
+    ));
+    assert!(matches!(
+        checked.try_set(&mut config, String::new()),
+        Err(Either::Left("empty host"))
+    ));
+    assert_eq!(config.main.host, "backup");
+}
+
+#[test]
+fn bit_lenses_manipulate_packed_registers() {
+    use crate::{bit_lens_u8, bits_lens_u64};
+
+    let mut flags = 0u8;
+    let enabled = bit_lens_u8(7);
+    enabled.set(&mut flags, true);
+    assert_eq!(flags, 0x80);
+    assert!(enabled.get(&flags));
+
+    let mut word = u64::MAX;
+    let high = bits_lens_u64(32..64);
+    high.set(&mut word, 0x1_0000_0001);
+    assert_eq!(word, 0x0000_0001_FFFF_FFFF);
+    assert_eq!(high.get(&word), 1);
+    assert_eq!(bits_lens_u64(0..64).get(&word), word);
+}
+
+#[test]
+fn std_isos_compose_without_closures() {
+    use crate::std_isos;
+
+    let code_point = mapped_lens(|e: &(u32, bool)| e.0, |e: &mut (u32, bool), v| e.0 = v);
+    let flag = mapped_lens(|e: &(u32, bool)| e.1, |e: &mut (u32, bool), v| e.1 = v);
+
+    let mut entry = (0x263Au32, false);
+    let flipped = std_isos::swap().compose_with_lens(mapped_lens(
+        |e: &(bool, u32)| e.0,
+        |e: &mut (bool, u32), v| e.0 = v,
+    ));
+    flipped.set(&mut entry, true);
+    assert_eq!(entry, (0x263A, true));
+
+    let disabled = flag.compose_with_iso(std_isos::not());
+    assert!(!disabled.get(&entry));
+
+    let symbol = code_point.compose_with_fallible_iso(std_isos::char_u32());
+    assert_eq!(symbol.try_get(&entry), Ok('\u{263A}'));
+    symbol.set(&mut entry, 'x');
+    assert_eq!(entry, (0x78, true));
+}
+
+#[test]
+fn nonzero_isos_carry_parsed_ports() {
+    use crate::{ZeroError, num, parse_iso};
+    use core::num::NonZeroU16;
+
+    let port = parse_iso::<u16>().compose_with_fallible_iso_with_mappers(
+        num::nonzero_u16(),
+        |_| (),
+        |_| (),
+        |e| match e {},
+        |e| match e {},
+    );
+    assert_eq!(
+        port.try_get(&"8080".to_string()),
+        Ok(NonZeroU16::new(8080).unwrap())
+    );
+    assert_eq!(port.try_get(&"0".to_string()), Err(()));
+    assert_eq!(num::nonzero_u16().try_get(&0), Err(ZeroError));
+
+    let mut raw = 0u16;
+    num::nonzero_u16().set(&mut raw, NonZeroU16::MIN);
+    assert_eq!(raw, 1);
+}
+
+#[test]
+fn then_composes_optics_of_any_kind() {
+    use crate::ComposeWith;
+
+    let mut config = Config::default();
+    let main_port = field_lens!(Config, main)
+        .then(field_lens!(DatabaseConfig, port))
+        .then(option::some());
+
+    assert_eq!(main_port.try_get(&config), Err(NoneError));
+    main_port.set(&mut config, 5432);
+    assert_eq!(config.main.port, Some(5432));
+
+    let first_aux_host = field_lens!(Config, aux)
+        .then(vec::first())
+        .then(field_lens!(DatabaseConfig, host));
+    assert_eq!(first_aux_host.try_get(&config), Ok("aux1".to_string()));
+
+    let mut ports = [Some(1u16), None, Some(3)];
+    let each_port = array_each().then(option::some());
+    assert_eq!(each_port.get_all(&ports), vec![1, 3]);
+    each_port.over_all(&mut ports, &mut |port| port + 1);
+    assert_eq!(ports, [Some(2), None, Some(4)]);
+
+    let mut queues = [vec![1], vec![]];
+    array_each().then(vec::push_setter()).set(&mut queues, 7);
+    assert_eq!(queues, [vec![1, 7], vec![7]]);
+}
+
+#[test]
+fn shr_composes_optics_like_then() {
+    let mut config = Config::default();
+    let first_aux_port =
+        field_lens!(Config, aux) >> vec::first() >> field_lens!(DatabaseConfig, port);
+    let main_port =
+        field_lens!(Config, main) >> field_lens!(DatabaseConfig, port) >> option::some();
+
+    assert_eq!(first_aux_port.try_get(&config), Ok(Some(2345)));
+    first_aux_port.set(&mut config, None);
+    assert_eq!(config.aux[0].port, None);
+
+    main_port.set(&mut config, 5432);
+    assert_eq!(main_port.try_get(&config), Ok(5432));
+}
+
+#[test]
+fn compose_functions_infer_the_result_kind() {
+    use crate::{compose, compose3, compose4};
+
+    let mut config = Config::default();
+    let main = field_lens!(Config, main);
+    let main_host = compose(main, field_lens!(DatabaseConfig, host));
+    assert_eq!(main_host.get(&config), "main".to_string());
+
+    let main_port = compose3(
+        field_lens!(Config, main),
+        field_lens!(DatabaseConfig, port),
+        option::some(),
+    );
+    main_port.set(&mut config, 5432);
+    assert_eq!(main_port.try_get(&config), Ok(5432));
+
+    let first_aux_host = compose4(
+        field_lens!(Config, aux),
+        vec::first(),
+        field_lens!(DatabaseConfig, host),
+        mapped_iso(
+            |host: &String| host.to_uppercase(),
+            |host: &String| host.to_lowercase(),
+        ),
+    );
+    assert_eq!(first_aux_host.try_get(&config), Ok("AUX1".to_string()));
+    first_aux_host.set(&mut config, "REPLICA".to_string());
+    assert_eq!(config.aux[0].host, "replica");
+}
+
+#[test]
+fn then_keeps_both_errors_of_fallible_optics() {
+    use crate::{ComposeWith, Either, PrefixMissing};
+
+    let mut config = Config::default();
+    let first_aux_port = field_lens!(Config, aux)
+        .then(vec::first())
+        .then(field_lens!(DatabaseConfig, port))
+        .then(option::some());
+    assert_eq!(first_aux_port.try_get(&config), Ok(2345));
+
+    config.aux[0].port = None;
+    assert_eq!(
+        first_aux_port.try_get(&config),
+        Err(Either::Right(NoneError))
+    );
+    config.aux.clear();
+    assert_eq!(
+        first_aux_port.try_get(&config),
+        Err(Either::Left(IndexError { index: 0, len: 0 }))
+    );
+
+    let stem = field_lens!(Config, filename)
+        .then(string::strip_prefix("ab"))
+        .then(string::strip_suffix("d"));
+    assert_eq!(stem.try_get(&config), Ok("c".to_string()));
+    config.filename = "xcd".to_string();
+    assert_eq!(stem.try_get(&config), Err(Either::Left(PrefixMissing)));
+
+    let unified = field_lens!(Config, main)
+        .then(field_lens!(DatabaseConfig, port))
+        .then(option::some())
+        .then(mapped_prism(
+            |port: &u16| port.checked_sub(1000).ok_or(NoneError),
+            |port, p| *port = p + 1000,
+        ));
+    assert_eq!(
+        unified.try_get(&config).map_err(Either::into_inner),
+        Err(NoneError)
+    );
+}
+
+#[test]
+fn tracking_compositions_report_the_failing_stage() {
+    use crate::ComposedError;
+    use core::net::SocketAddr;
+
+    let mut config = Config::default();
+    let main_addr = field_lens!(Config, main)
+        .compose_with_lens(field_lens!(DatabaseConfig, create_result))
+        .compose_with_prism(result::ok())
+        .compose_with_fallible_iso_tracking(parse_iso::<SocketAddr>());
+
+    assert!(matches!(
+        main_addr.try_get(&config),
+        Err(ComposedError::Right(_))
+    ));
+
+    config.main.create_result = Err("failed".to_string());
+    assert_eq!(
+        main_addr.try_get(&config),
+        Err(ComposedError::Left("failed".to_string()))
+    );
+
+    main_addr.set(&mut config, "127.0.0.1:5432".parse().unwrap());
+    assert_eq!(config.main.create_result, Err("failed".to_string()));
+
+    config.main.create_result = Ok("pending".to_string());
+    main_addr.set(&mut config, "127.0.0.1:5432".parse().unwrap());
+    assert_eq!(config.main.create_result, Ok("127.0.0.1:5432".to_string()));
+    assert_eq!(main_addr.try_get(&config).map(|addr| addr.port()), Ok(5432));
+}
+
+#[test]
+fn or_else_falls_back_to_another_location() {
+    let mut config = Config::default();
+    let main_port = field_lens!(Config, main)
+        .compose_with_lens(field_lens!(DatabaseConfig, port))
+        .compose_with_prism(option::some());
+    let aux_port = field_lens!(Config, aux)
+        .compose_with_prism(vec::first())
+        .compose_with_lens(field_lens!(DatabaseConfig, port))
+        .compose_with_prism_with_mappers(option::some(), |_| NoneError, |e| e);
+    let port = main_port.or_else(aux_port);
+
+    assert_eq!(port.try_get(&config), Ok(2345));
+    port.set(&mut config, 2346);
+    assert_eq!(config.aux[0].port, Some(2346));
+    assert_eq!(config.main.port, None);
+
+    config.aux.clear();
+    assert_eq!(port.try_get(&config), Err(NoneError));
+    port.set(&mut config, 5432);
+    assert_eq!(config.main.port, Some(5432));
+    assert_eq!(port.try_get(&config), Ok(5432));
+}
+
+#[test]
+fn zip_updates_host_and_port_atomically() {
+    let mut config = Config::default();
+    let endpoint = field_lens!(Config, main).compose_with_lens(
+        field_lens!(DatabaseConfig, host).zip(field_lens!(DatabaseConfig, port)),
+    );
+
+    assert_eq!(endpoint.get(&config), ("main".to_string(), None));
+    endpoint.set(&mut config, ("primary".to_string(), Some(5432)));
+    assert_eq!(config.main.host, "primary");
+    assert_eq!(config.main.port, Some(5432));
+
+    let summary = crate::mapped_getter(|c: &Config| c.filename.clone())
+        .zip(crate::mapped_getter(|c: &Config| c.aux.len()));
+    assert_eq!(summary.get(&config), ("abcd".to_string(), 2));
+}
+
+#[test]
+fn filter_narrows_prisms_to_matching_values() {
+    use crate::{Either, PredicateFailed};
+
+    let mut config = Config::default();
+    config.main.port = Some(80);
+    let unprivileged_port = field_lens!(Config, main)
+        .compose_with_lens(field_lens!(DatabaseConfig, port))
+        .compose_with_prism(option::some())
+        .filter(|port| *port >= 1024);
+
+    assert_eq!(
+        unprivileged_port.try_get(&config),
+        Err(Either::Right(PredicateFailed))
+    );
+    unprivileged_port.set(&mut config, 22);
+    assert_eq!(config.main.port, Some(80));
+    unprivileged_port.set(&mut config, 5432);
+    assert_eq!(unprivileged_port.try_get(&config), Ok(5432));
+
+    config.main.port = None;
+    assert_eq!(
+        unprivileged_port.try_get(&config),
+        Err(Either::Left(NoneError))
+    );
+
+    let seconds = field_lens!(Config, delay)
+        .compose_with_prism(enum_prism!(Timespan, Minutes(_)))
+        .filter_map(|minutes: u32| minutes.checked_mul(60));
+    assert_eq!(seconds.try_get(&config), Ok(840));
+}
+
+#[test]
+fn map_derives_read_only_views() {
+    let config = Config::default();
+    let host_len = field_lens!(Config, main)
+        .compose_with_lens(field_lens!(DatabaseConfig, host))
+        .map(String::len);
+    assert_eq!(host_len.get(&config), 4);
+
+    let first_aux_host_upper = field_lens!(Config, aux)
+        .compose_with_prism(vec::first())
+        .compose_with_lens(field_lens!(DatabaseConfig, host))
+        .map(|host| host.to_uppercase());
+    assert_eq!(
+        first_aux_host_upper.try_get(&config),
+        Ok("AUX1".to_string())
+    );
+}
+
+#[test]
+fn premap_adapts_values_before_setting() {
+    let mut config = Config::default();
+    let main_port = field_lens!(Config, main).compose_with_setter(crate::mapped_setter(
+        |db: &mut DatabaseConfig, port: Option<u16>| db.port = port,
+    ));
+    let main_port_str = main_port.premap(|port: &str| port.parse().ok());
+
+    main_port_str.set(&mut config, "5432");
+    assert_eq!(config.main.port, Some(5432));
+    main_port_str.set(&mut config, "invalid");
+    assert_eq!(config.main.port, None);
+}
+
+#[test]
+fn dimap_adapts_both_sides_of_isos() {
+    let bytes = crate::mapped_iso(
+        |v: &u16| v.to_be_bytes(),
+        |b: &[u8; 2]| u16::from_be_bytes(*b),
+    );
+    let widened = bytes.dimap(
+        |v: &u32| u16::try_from(*v).unwrap_or(u16::MAX),
+        |v: &u16| u32::from(*v),
+        |b: &[u8; 2]| (b[0], b[1]),
+        |(hi, lo): &(u8, u8)| [*hi, *lo],
+    );
+    assert_eq!(widened.get(&0x1234), (0x12, 0x34));
+    assert_eq!(
+        crate::HasTotalReverseGet::reverse_get(&widened, &(0xab, 0xcd)),
+        0xabcd
+    );
+
+    let parse = crate::mapped_fallible_iso(
+        |s: &String| s.parse::<u8>(),
+        |v: &u8| Ok::<_, core::num::ParseIntError>(v.to_string()),
+    );
+    let parse_doubled = parse.dimap(
+        |s: &&str| (*s).to_string(),
+        |s: &String| if s == "42" { "answer" } else { "other" },
+        |v: &u8| u16::from(*v) * 2,
+        |v: &u16| u8::try_from(v / 2).unwrap_or(u8::MAX),
+    );
+    assert_eq!(parse_doubled.try_get(&"21"), Ok(42));
+    assert!(parse_doubled.try_get(&"x").is_err());
+    assert_eq!(
+        crate::HasReverseGet::try_reverse_get(&parse_doubled, &84),
+        Ok("answer")
+    );
+}
+
+#[test]
+fn invert_swaps_the_direction_of_isos() {
+    let bytes = crate::mapped_iso(
+        |v: &u16| v.to_be_bytes(),
+        |b: &[u8; 2]| u16::from_be_bytes(*b),
+    );
+    let from_bytes = bytes.invert();
+
+    assert_eq!(from_bytes.get(&[0x12, 0x34]), 0x1234);
+    assert_eq!(
+        crate::HasTotalReverseGet::reverse_get(&from_bytes, &0xabcd),
+        [0xab, 0xcd]
+    );
+
+    let mut raw = [0u8; 2];
+    from_bytes.set(&mut raw, 0x0102);
+    assert_eq!(raw, [0x01, 0x02]);
+}
+
+#[test]
+fn invert_swaps_the_errors_of_fallible_isos() {
+    let parse = crate::mapped_fallible_iso(
+        |s: &String| s.parse::<u8>().map_err(|_| "not a number"),
+        |v: &u8| if *v < 100 { Ok(v.to_string()) } else { Err(*v) },
+    );
+    let format = parse.invert();
+
+    assert_eq!(format.try_get(&42), Ok("42".to_string()));
+    assert_eq!(format.try_get(&200), Err(200));
+    assert_eq!(
+        crate::HasReverseGet::try_reverse_get(&format, &"x".to_string()),
+        Err("not a number")
+    );
+
+    let mut value = 1;
+    format.set(&mut value, "7".to_string());
+    assert_eq!(value, 7);
+    format.set(&mut value, "x".to_string());
+    assert_eq!(value, 7);
+}
+
+#[test]
+fn with_default_upgrades_prisms_to_lenses() {
+    let mut config = Config::default();
+    let main_port = field_lens!(Config, main)
+        .compose_with_lens(field_lens!(DatabaseConfig, port))
+        .compose_with_prism(option::some())
+        .with_default(|| 5432);
+    assert_eq!(main_port.get(&config), 5432);
+    main_port.set(&mut config, 6543);
+    assert_eq!(config.main.port, Some(6543));
+    assert_eq!(main_port.get(&config), 6543);
+
+    let main_port_or_zero = field_lens!(Config, main)
+        .compose_with_lens(field_lens!(DatabaseConfig, port))
+        .compose_with_prism(option::some())
+        .or_default();
+    assert_eq!(main_port_or_zero.get(&config), 6543);
+    config.main.port = None;
+    assert_eq!(main_port_or_zero.get(&config), 0);
+}
+
+#[test]
+fn downgrade_methods_hand_out_weaker_capabilities() {
+    fn read_host<G: crate::Getter<Config, String>>(getter: &G, config: &Config) -> String {
+        getter.get(config)
+    }
+    fn write_port<SETTER: crate::Setter<Config, Option<u16>>>(
+        setter: &SETTER,
+        config: &mut Config,
+    ) {
+        setter.set(config, Some(1234));
+    }
+
+    let mut config = Config::default();
+    let main_host = field_lens!(Config, main).compose_with_lens(field_lens!(DatabaseConfig, host));
+    assert_eq!(
+        read_host(&main_host.as_getter().0, &config),
+        config.main.host
+    );
+
+    let main_port = field_lens!(Config, main).compose_with_lens(field_lens!(DatabaseConfig, port));
+    write_port(&main_port.as_setter().0, &mut config);
+    assert_eq!(config.main.port, Some(1234));
+
+    let first_aux = field_lens!(Config, aux).compose_with_prism(vec::first());
+    assert_eq!(
+        first_aux.as_partial_getter().try_get(&config),
+        Ok(config.aux[0].clone())
+    );
+}
+
+#[test]
+fn with_setter_upgrades_getters_to_writable_optics() {
+    use crate::{mapped_getter, mapped_partial_getter};
+
+    let mut config = Config::default();
+
+    let main_host = mapped_getter(|c: &Config| c.main.host.clone())
+        .with_setter(|c, host| c.main.host = host)
+        .compose_with_lens(mapped_lens(|h: &String| h.len(), |h, len| h.truncate(len)));
+    main_host.set(&mut config, 2);
+    assert_eq!(config.main.host, "ma");
+    assert_eq!(main_host.get(&config), 2);
+
+    let first_aux_port =
+        mapped_partial_getter(|c: &Config| c.aux.first().and_then(|aux| aux.port).ok_or("no port"))
+            .with_setter(|c, port| {
+                if let Some(aux) = c.aux.first_mut() {
+                    aux.port = Some(port);
+                }
+            });
+    first_aux_port.set(&mut config, 6543);
+    assert_eq!(first_aux_port.try_get(&config), Ok(6543));
+
+    config.aux.clear();
+    assert_eq!(first_aux_port.try_get(&config), Err("no port"));
+}
+
+#[test]
+fn context_labels_errors_with_their_path() {
+    use crate::{ContextError, NoneError};
+
+    let main_port = field_lens!(Config, main)
+        .compose_with_lens(field_lens!(DatabaseConfig, port))
+        .compose_with_prism(option::some().context("port"));
+    let config = Config::default();
+    let error = main_port.try_get(&config).unwrap_err();
+    assert_eq!(error.path(), ["port"]);
+    assert_eq!(error.error(), &NoneError);
+
+    let nested = option::some::<Option<u16>>()
+        .context("outer")
+        .compose_with_prism::<ContextError<NoneError>, _, _>(
+            option::some::<u16>().context("inner"),
+        );
+    assert_eq!(nested.try_get(&Some(Some(80))), Ok(80));
+    assert_eq!(nested.try_get(&None).unwrap_err().path(), ["outer"]);
+    let error = nested.try_get(&Some(None)).unwrap_err();
+    assert_eq!(error.path(), ["outer", "inner"]);
+    assert_eq!(error.to_string(), "outer.inner: the value is None");
+
+    let deeper = option::some::<Option<Option<u16>>>()
+        .context("a")
+        .compose_with_lens(crate::identity_lens())
+        .compose_with_prism::<ContextError<NoneError>, _, _>(nested);
+    assert_eq!(
+        deeper.try_get(&Some(Some(None))).unwrap_err().path(),
+        ["a", "outer", "inner"]
+    );
+}
+
+#[test]
+fn get_ref_borrows_the_focus_through_compositions() {
+    use crate::{ComposeWith, HasGetterRef};
+
+    fn main_host(config: &Config) -> &String {
+        match field_lens!(Config, main)
+            .compose_with_lens(field_lens!(DatabaseConfig, host))
+            .try_get_ref(config)
+        {
+            Ok(host) => host,
+        }
+    }
+
+    let config = Config::default();
+    assert_eq!(main_host(&config), &config.main.host);
+
+    let aux = crate::borrowed_lens(|c: &Config| &c.aux, |c, aux| c.aux = aux);
+    let host_len = field_lens!(Config, main)
+        .then(field_lens!(DatabaseConfig, host))
+        .try_get_ref(&config)
+        .map(String::len);
+    assert_eq!(aux.try_get_ref(&config), Ok(&config.aux));
+    assert_eq!(host_len, Ok(config.main.host.len()));
+}
+
+#[test]
+fn get_mut_modifies_the_focus_in_place() {
+    use crate::HasGetterMut;
+
+    let mut config = Config::default();
+    let main_host = field_lens!(Config, main).compose_with_lens(field_lens!(DatabaseConfig, host));
+    let Ok(host) = main_host.try_get_mut(&mut config);
+    host.push_str(".local");
+    assert_eq!(config.main.host, "main.local");
+
+    let aux = crate::mapped_ref_lens(|c: &Config| &c.aux, |c: &mut Config| &mut c.aux);
+    let Ok(aux) = aux.try_get_mut(&mut config);
+    aux.clear();
+    assert!(config.aux.is_empty());
+}
+
+#[test]
+fn modify_updates_the_focus_in_place() {
+    use crate::HasModify;
+
+    let mut config = Config::default();
+    let main_host = field_lens!(Config, main).compose_with_lens(field_lens!(DatabaseConfig, host));
+    main_host.modify(&mut config, |host| host.push_str(".local"));
+    assert_eq!(config.main.host, "main.local");
+
+    let first_aux_port = field_lens!(Config, aux)
+        .compose_with_prism(vec::first())
+        .compose_with_lens(field_lens!(DatabaseConfig, port));
+    first_aux_port.modify(&mut config, |port| *port = port.map(|p| p + 1));
+    assert_eq!(config.aux[0].port, Some(2346));
+
+    config.aux.clear();
+    first_aux_port.modify(&mut config, |_| unreachable!());
+    assert!(config.aux.is_empty());
+
+    let doubled = mapped_iso(
+        |v: &u32| u64::from(*v) * 2,
+        |v: &u64| u32::try_from(v / 2).unwrap(),
+    );
+    let mut value = 21u32;
+    doubled.modify(&mut value, |v| *v += 2);
+    assert_eq!(value, 22);
+}
+
+#[test]
+fn take_removes_optional_foci() {
+    let mut config = Config::default();
+    let first_aux_port = field_lens!(Config, aux)
+        .compose_with_prism(vec::first())
+        .compose_with_lens(field_lens!(DatabaseConfig, port));
+    assert_eq!(first_aux_port.take(&mut config), Some(2345));
+    assert_eq!(config.aux[0].port, None);
+    assert_eq!(first_aux_port.take(&mut config), None);
+
+    let main_port = field_lens!(Config, main).compose_with_lens(field_lens!(DatabaseConfig, port));
+    config.main.port = Some(5432);
+    assert_eq!(main_port.take(&mut config), Some(5432));
+    assert_eq!(config.main.port, None);
+}
+
+#[test]
+fn prisms_distinguish_set_if_present_from_set_or_insert() {
+    let mut config = Config::default();
+    let main_port = field_lens!(Config, main)
+        .compose_with_lens(field_lens!(DatabaseConfig, port))
+        .compose_with_prism(option::some());
+
+    assert!(!main_port.set_if_present(&mut config, 5432));
+    assert_eq!(config.main.port, None);
+
+    main_port.set_or_insert(&mut config, 5432);
+    assert_eq!(config.main.port, Some(5432));
+
+    assert!(main_port.set_if_present(&mut config, 6543));
+    assert_eq!(config.main.port, Some(6543));
+
+    let third_aux = field_lens!(Config, aux).compose_with_prism(vec::get(2));
+    let backup = DatabaseConfig {
+        host: "backup".to_string(),
+        ..config.main.clone()
+    };
+    third_aux.set(&mut config, backup.clone());
+    assert_eq!(config.aux.len(), 2);
+    third_aux.set_or_insert(&mut config, backup.clone());
+    assert_eq!(config.aux.get(2), Some(&backup));
+
+    let mut ports = alloc::collections::BTreeMap::new();
+    btree_map::value("http").set_or_insert(&mut ports, 80);
+    assert_eq!(ports.get("http"), Some(&80));
+
+    let mut samples = alloc::collections::VecDeque::new();
+    deque::front().set_or_insert(&mut samples, 2);
+    deque::front().set_or_insert(&mut samples, 1);
+    assert_eq!(samples, [1]);
+}
+
+#[test]
+fn update_produces_new_sources() {
+    use crate::HasUpdate;
+
+    let main_port = field_lens!(Config, main).compose_with_lens(field_lens!(DatabaseConfig, port));
+    let config = main_port.update(Config::default(), Some(5432));
+    assert_eq!(config.main.port, Some(5432));
+
+    let first_aux_host = field_lens!(Config, aux)
+        .compose_with_prism(vec::first())
+        .compose_with_lens(field_lens!(DatabaseConfig, host));
+    let config = first_aux_host.with(config, |host| host.make_ascii_lowercase());
+    assert_eq!(config.aux[0].host, "aux1");
+    assert_eq!(config.main.port, Some(5432));
+}
+
+#[test]
+fn fallible_setters_surface_rejected_writes() {
+    use crate::{ComposeWith, Either, HasFallibleSetter, mapped_fallible_setter};
+
+    let unprivileged = || {
+        mapped_fallible_setter(|port: &mut u16, value: u16| {
+            if value < 1024 {
+                return Err("port below 1024");
+            }
+            *port = value;
+            Ok(())
+        })
+    };
+
+    let mut config = Config::default();
+    let first_aux_port = field_lens!(Config, aux)
+        .compose_with_prism(vec::first())
+        .compose_with_lens(field_lens!(DatabaseConfig, port))
+        .then(option::some())
+        .then(unprivileged());
+
+    assert_eq!(
+        first_aux_port.try_set(&mut config, 80),
+        Err(Either::Right("port below 1024"))
+    );
+    assert_eq!(config.aux[0].port, Some(2345));
+    assert_eq!(first_aux_port.try_set(&mut config, 8080), Ok(()));
+    assert_eq!(config.aux[0].port, Some(8080));
+
+    config.aux.clear();
+    assert!(matches!(
+        first_aux_port.try_set(&mut config, 8080),
+        Err(Either::Left(_))
+    ));
+
+    let mut ports = [8080u16, 8081];
+    let all_ports = array_each::<u16, 2>().compose_with_fallible_setter(unprivileged());
+    assert_eq!(all_ports.try_set(&mut ports, 9090), Ok(()));
+    assert_eq!(ports, [9090, 9090]);
+    assert_eq!(all_ports.try_set(&mut ports, 22), Err("port below 1024"));
+}
+
+#[test]
+fn validated_optics_reject_invalid_writes() {
+    use crate::{ComposeWith, HasFallibleSetter, HasModify, HasTotalGetter};
+
+    let unprivileged = |port: &u16| {
+        if *port < 1024 {
+            Err("port below 1024")
+        } else {
+            Ok(())
+        }
+    };
+
+    let mut config = Config::default();
+    config.main.port = Some(5432);
+    let main_port = field_lens!(Config, main).compose_with_lens(
+        field_lens!(DatabaseConfig, port)
+            .with_validation(|port: &Option<u16>| port.as_ref().map_or(Ok(()), unprivileged)),
+    );
+
+    assert_eq!(
+        main_port.try_set(&mut config, Some(80)),
+        Err("port below 1024")
+    );
+    main_port.set(&mut config, Some(80));
+    assert_eq!(main_port.get(&config), Some(5432));
+    assert_eq!(main_port.try_set(&mut config, None), Ok(()));
+    assert_eq!(main_port.get(&config), None);
+
+    let first_aux_port = field_lens!(Config, aux)
+        .compose_with_prism(vec::first())
+        .compose_with_lens(field_lens!(DatabaseConfig, port))
+        .then(option::some())
+        .with_validation(unprivileged);
+    assert_eq!(
+        first_aux_port.try_set(&mut config, 22),
+        Err("port below 1024")
+    );
+    assert_eq!(first_aux_port.try_get(&config).ok(), Some(2345));
+    first_aux_port.modify(&mut config, |port| *port -= 2000);
+    assert_eq!(first_aux_port.try_get(&config).ok(), Some(2345));
+    assert_eq!(first_aux_port.try_set(&mut config, 2346), Ok(()));
+    assert_eq!(config.aux[0].port, Some(2346));
+}
+
+#[test]
+fn composed_optics_can_be_copied_and_debugged() {
+    use alloc::format;
+
+    let main_port = field_lens!(Config, main).compose_with_lens(field_lens!(DatabaseConfig, port));
+    let also_main_port = main_port;
+
+    let mut config = Config::default();
+    main_port.set(&mut config, Some(5432));
+    assert_eq!(also_main_port.get(&config), Some(5432));
+
+    let missing = String::from("no port");
+    let port = mapped_prism(
+        move |p: &Option<u16>| p.ok_or(missing.clone()),
+        |p: &mut Option<u16>, v| *p = Some(v),
+    );
+    let aux_port = main_port.compose_with_prism(port);
+    let cloned = aux_port.clone();
+    assert_eq!(cloned.try_get(&config), Ok(5432));
+    assert_eq!(
+        cloned.try_get(&Config::default()),
+        Err("no port".to_string())
+    );
+    assert!(format!("{aux_port:?}").starts_with("PrismImpl(ComposedPrism {"));
+}
+
+#[test]
+fn shared_optics_can_be_composed_repeatedly() {
+    use crate::{ComposeWith, HasGetter};
+
+    let main = field_lens!(Config, main);
+    let main_port = main.compose_ref(&field_lens!(DatabaseConfig, port));
+    let main_host = main.compose_ref(&field_lens!(DatabaseConfig, host));
+    let port_value = mapped_prism(
+        |p: &Option<u16>| p.ok_or(()),
+        |p: &mut Option<u16>, v| *p = Some(v),
+    );
+    let main_port_value = main_port.compose_ref(&port_value);
+    let aux_port_value = field_lens!(Config, aux)
+        .compose_with_prism(vec::first())
+        .compose_with_lens(field_lens!(DatabaseConfig, port))
+        .then(port_value);
+
+    let mut config = Config::default();
+    main_port.set(&mut config, Some(5432));
+    assert_eq!(main.get(&config).port, Some(5432));
+    assert_eq!(main_host.get(&config), config.main.host);
+    assert_eq!(main_port_value.try_get(&config), Ok(5432));
+    assert_eq!(aux_port_value.try_get(&config).ok(), Some(2345));
+}
+
+#[test]
+fn named_optics_display_their_path() {
+    use crate::{ComposeWith, HasGetter};
+    use alloc::format;
+
+    let main = field_lens!(Config, main).named("main");
+    let port = field_lens!(DatabaseConfig, port).named("port");
+    let some = mapped_prism(
+        |p: &Option<u16>| p.ok_or(()),
+        |p: &mut Option<u16>, v| *p = Some(v),
+    )
+    .named("some");
+
+    let main_port = main.compose_ref(&port);
+    assert_eq!(main.name(), "main");
+    assert_eq!(main_port.name(), "main.port");
+
+    let main_port_value = main_port.then(some);
+    assert_eq!(format!("{main_port_value}"), "main.port.some");
+    assert_eq!(main_port_value.try_get(&Config::default()), Err(()));
+
+    let renamed = main_port_value.named("main_port");
+    assert_eq!(renamed.to_string(), "main_port");
+}
+
+#[test]
+#[cfg(feature = "tracing")]
+fn traced_optics_emit_events_for_each_operation() {
+    use crate::{ComposeWith, HasGetter};
+    use std::fmt::Debug;
+    use std::sync::{Arc, Mutex};
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    #[derive(Default)]
+    struct Fields(Vec<(String, String)>);
+
+    impl Visit for Fields {
+        fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+            self.0
+                .push((field.name().to_string(), format!("{value:?}")));
+        }
+    }
+
+    #[derive(Clone, Default)]
+    struct Collector(Arc<Mutex<Vec<Fields>>>);
+
+    impl Subscriber for Collector {
+        fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+            metadata.target() == "optics"
+        }
+        fn new_span(&self, _: &Attributes<'_>) -> Id {
+            Id::from_u64(1)
+        }
+        fn record(&self, _: &Id, _: &Record<'_>) {}
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+        fn event(&self, event: &Event<'_>) {
+            let mut fields = Fields::default();
+            event.record(&mut fields);
+            self.0.lock().unwrap().push(fields);
+        }
+        fn enter(&self, _: &Id) {}
+        fn exit(&self, _: &Id) {}
+    }
+
+    let collector = Collector::default();
+    let events = collector.0.clone();
+
+    tracing::subscriber::with_default(collector, || {
+        let mut config = Config::default();
+        let port = field_lens!(Config, main)
+            .then(field_lens!(DatabaseConfig, port))
+            .traced("main.port");
+        let delay = field_lens!(Config, delay)
+            .then(enum_prism!(Timespan, Seconds(_)))
+            .traced("delay.seconds");
+
+        port.set(&mut config, Some(80));
+        assert_eq!(port.get(&config), Some(80));
+        assert!(delay.try_get(&config).is_err());
+    });
+
+    let events = events.lock().unwrap();
+    let summary: Vec<Vec<String>> = events
+        .iter()
+        .map(|event| {
+            event
+                .0
+                .iter()
+                .filter(|(name, _)| name != "elapsed")
+                .map(|(name, value)| format!("{name}={value}"))
+                .collect()
+        })
+        .collect();
+    assert_eq!(
+        summary,
+        [
+            ["optic=\"main.port\"", "operation=\"set\"", "success=true"],
+            [
+                "optic=\"main.port\"",
+                "operation=\"try_get\"",
+                "success=true"
+            ],
+            [
+                "optic=\"delay.seconds\"",
+                "operation=\"try_get\"",
+                "success=false"
+            ],
+        ]
+    );
+    assert!(
+        events
+            .iter()
+            .all(|event| event.0.iter().any(|(name, _)| name == "elapsed"))
+    );
+}
+
+#[test]
+fn generated_optics_track_their_path() {
+    use crate::{ComposeWith, HasGetter, HasPath};
+    use alloc::vec::Vec;
+
+    let main_port = field_lens!(Config, main).compose_with_lens(field_lens!(DatabaseConfig, port));
+    assert_eq!(main_port.path().collect::<Vec<_>>(), ["main", "port"]);
+
+    let delay_minutes = field_lens!(Config, delay).then(enum_prism!(Timespan, Minutes(_)));
+    assert_eq!(
+        delay_minutes.path().collect::<Vec<_>>(),
+        ["delay", "Minutes"]
+    );
+    assert_eq!(delay_minutes.try_get(&Config::default()), Ok(14));
+
+    let aux_port = field_lens!(Config, aux)
+        .then(vec::first().named("first"))
+        .then(field_lens!(DatabaseConfig, port));
+    assert_eq!(
+        aux_port.path().collect::<Vec<_>>().join(" → "),
+        "aux → first → port"
+    );
+    assert_eq!(aux_port.joined_path(), "aux.first.port");
+}
+
+#[test]
+fn boxed_optics_can_be_stored_together() {
+    use crate::{BoxedLens, BoxedPrism, ComposeWith, HasGetter};
+    use std::collections::HashMap;
+
+    let mut strings: HashMap<&str, BoxedLens<Config, String>> = HashMap::new();
+    strings.insert("filename", field_lens!(Config, filename).boxed());
+    strings.insert(
+        "main.host",
+        field_lens!(Config, main)
+            .compose_with_lens(field_lens!(DatabaseConfig, host))
+            .boxed(),
+    );
+
+    let mut config = Config::default();
+    strings["main.host"].set(&mut config, "db".to_string());
+    assert_eq!(config.main.host, "db");
+    assert_eq!(strings["filename"].get(&config), config.filename);
+
+    let ports: Vec<BoxedPrism<Config, u16, ()>> = vec![
+        field_lens!(Config, main)
+            .then(field_lens!(DatabaseConfig, port))
+            .then(mapped_prism(
+                |p: &Option<u16>| p.ok_or(()),
+                |p: &mut Option<u16>, v| *p = Some(v),
+            ))
+            .boxed(),
+        mapped_prism(
+            |c: &Config| c.aux.first().and_then(|db| db.port).ok_or(()),
+            |c: &mut Config, v| c.aux[0].port = Some(v),
+        )
+        .boxed(),
+    ];
+    assert_eq!(ports[0].try_get(&config), Err(()));
+    assert_eq!(ports[1].try_get(&config), Ok(2345));
+
+    let main = field_lens!(Config, main).boxed();
+    let main_port = main.compose_with_lens(field_lens!(DatabaseConfig, port));
+    main_port.set(&mut config, Some(5432));
+    assert_eq!(config.main.port, Some(5432));
+}
+
+#[test]
+fn erased_errors_unify_deep_compositions() {
+    use crate::{ErasedError, HasGetter, mapped_fallible_iso};
+    use core::num::TryFromIntError;
+
+    let low_byte = mapped_fallible_iso(
+        |port: &u16| u8::try_from(*port),
+        |byte: &u8| Ok::<_, TryFromIntError>(u16::from(*byte)),
+    );
+    let first_aux_port_byte = field_lens!(Config, aux)
+        .compose_with_prism(vec::first().erase_err())
+        .compose_with_lens(field_lens!(DatabaseConfig, port))
+        .compose_with_prism::<ErasedError, _, _>(option::some().erase_err())
+        .compose_with_fallible_iso::<ErasedError, _, _>(low_byte.erase_err());
+
+    let mut config = Config::default();
+    assert_eq!(
+        first_aux_port_byte
+            .try_get(&config)
+            .unwrap_err()
+            .to_string(),
+        "out of range integral type conversion attempted"
+    );
+    config.aux[0].port = Some(80);
+    assert_eq!(first_aux_port_byte.try_get(&config).ok(), Some(80));
+    config.aux[0].port = None;
+    assert_eq!(
+        first_aux_port_byte
+            .try_get(&config)
+            .unwrap_err()
+            .to_string(),
+        "the value is None"
+    );
+    config.aux.clear();
+    assert!(first_aux_port_byte.try_get(&config).is_err());
+}
+
+#[test]
+fn optics_errors_unify_crate_errors() {
+    use crate::{ComposeWith, HasGetter};
+
+    let aux_port_byte = field_lens!(Config, aux)
+        .compose_with_prism(vec::first())
+        .compose_with_lens(field_lens!(DatabaseConfig, port))
+        .compose_with_prism::<OpticsError, _, _>(option::some())
+        .compose_with_fallible_iso::<OpticsError, _, _>(try_from_iso::<u16, u8>());
+
+    let mut config = Config::default();
+    assert_eq!(aux_port_byte.try_get(&config), Err(OpticsError::OutOfRange));
+    config.aux[0].port = None;
+    assert_eq!(aux_port_byte.try_get(&config), Err(OpticsError::NoFocus));
+    config.aux.clear();
+    assert_eq!(
+        aux_port_byte.try_get(&config),
+        Err(OpticsError::IndexOutOfBounds)
+    );
+
+    let hours = field_lens!(Config, delay).then(enum_prism!(Timespan, Hours(_)));
+    assert_eq!(
+        hours.try_get(&config).unwrap_err().to_string(),
+        "the value is of a different variant"
+    );
+    assert_eq!(OpticsError::from(()), OpticsError::NoFocus);
+}
+
+#[test]
+fn mapped_optics_can_be_declared_static() {
+    use crate::{FnLens, FnPrism, NoneError};
+
+    static MAIN: FnLens<Config, DatabaseConfig> =
+        mapped_lens(|c: &Config| c.main.clone(), |c, main| c.main = main);
+    static PORT: FnPrism<DatabaseConfig, u16, NoneError> = mapped_prism(
+        |c: &DatabaseConfig| c.port.ok_or(NoneError),
+        |c, port| c.port = Some(port),
+    );
+
+    let mut config = Config::default();
+    let main_port = MAIN.compose_with_prism(PORT);
+    assert_eq!(main_port.try_get(&config), Err(NoneError));
+
+    main_port.set(&mut config, 5432);
+    assert_eq!(PORT.try_get(&MAIN.get(&config)), Ok(5432));
+}
+
+#[test]
+fn composed_set_reads_each_intermediate_once() {
+    use core::cell::Cell;
+
+    type Deep = ((((u8, u8), u8), u8), u8);
+
+    fn read<T: Copy>(reads: &Cell<usize>, v: T) -> T {
+        reads.set(reads.get() + 1);
+        v
+    }
+
+    let reads = Cell::new(0);
+    let deep = mapped_lens(|s: &Deep| read(&reads, s.0), |s, v| s.0 = v)
+        .compose_with_lens(mapped_lens(
+            |s: &(((u8, u8), u8), u8)| read(&reads, s.0),
+            |s, v| s.0 = v,
+        ))
+        .compose_with_lens(mapped_lens(
+            |s: &((u8, u8), u8)| read(&reads, s.0),
+            |s, v| s.0 = v,
+        ))
+        .compose_with_lens(mapped_lens(
+            |s: &(u8, u8)| read(&reads, s.0),
+            |s, v| s.0 = v,
+        ));
+
+    let mut source: Deep = ((((1, 2), 3), 4), 5);
+    deep.set(&mut source, 42);
+
+    assert_eq!(source, ((((42, 2), 3), 4), 5));
+    assert_eq!(reads.get(), 3);
+}
+
+#[test]
+fn composed_field_lenses_write_in_place() {
+    use crate::{HasGetterMut, HasModify};
+
+    struct Server {
+        database: Database,
+    }
+    struct Database {
+        endpoint: Endpoint,
+    }
+    struct Endpoint {
+        port: u16,
+    }
+    impl Clone for Database {
+        fn clone(&self) -> Self {
+            panic!("intermediate Database cloned")
+        }
+    }
+    impl Clone for Endpoint {
+        fn clone(&self) -> Self {
+            panic!("intermediate Endpoint cloned")
+        }
+    }
+
+    let port = field_lens!(Server, database)
+        .compose_with_lens(field_lens!(Database, endpoint))
+        .compose_with_lens(field_lens!(Endpoint, port));
+
+    let mut server = Server {
+        database: Database {
+            endpoint: Endpoint { port: 5432 },
+        },
+    };
+    port.set(&mut server, 5433);
+    port.modify(&mut server, |p| *p += 1);
+    *port.try_get_mut(&mut server).unwrap() += 1;
+
+    assert_eq!(server.database.endpoint.port, 5435);
+}
+
+#[test]
+fn cached_getter_evaluates_once_per_source() {
+    use crate::mapped_getter;
+    use core::cell::Cell;
+
+    let evaluations = Cell::new(0);
+    let port = mapped_getter(|c: &DatabaseConfig| {
+        evaluations.set(evaluations.get() + 1);
+        c.port
+    });
+    let cached_port = port.cached();
+
+    let mut config = Config::default().main;
+    assert_eq!(cached_port.get(&config), None);
+    assert_eq!(cached_port.get(&config), None);
+    assert_eq!(evaluations.get(), 1);
+
+    config.port = Some(5432);
+    assert_eq!(cached_port.get(&config), Some(5432));
+    assert_eq!(evaluations.get(), 2);
+
+    let evaluations = Cell::new(0);
+    let host_len = mapped_getter(|c: &DatabaseConfig| {
+        evaluations.set(evaluations.get() + 1);
+        c.host.len()
+    })
+    .cached_by(|c: &DatabaseConfig| c.port);
+
+    assert_eq!(host_len.get(&config), config.host.len());
+    config.host.push('x');
+    assert_eq!(host_len.get(&config), config.host.len() - 1);
+    assert_eq!(evaluations.get(), 1);
+}
+
+#[test]
+fn cached_getter_clones_the_source_only_when_evaluating() {
+    use crate::mapped_getter;
+    use core::cell::Cell;
+
+    #[derive(PartialEq)]
+    struct Counted<'a>(u16, &'a Cell<usize>);
+
+    impl Clone for Counted<'_> {
+        fn clone(&self) -> Self {
+            self.1.set(self.1.get() + 1);
+            Counted(self.0, self.1)
+        }
+    }
+
+    let clones = Cell::new(0);
+    let doubled = mapped_getter(|c: &Counted| c.0 * 2).cached();
+
+    let mut source = Counted(21, &clones);
+    assert_eq!(doubled.get(&source), 42);
+    assert_eq!(doubled.get(&source), 42);
+    assert_eq!(doubled.get(&source), 42);
+    assert_eq!(clones.get(), 1);
+
+    source.0 = 5;
+    assert_eq!(doubled.get(&source), 10);
+    assert_eq!(clones.get(), 2);
+}
+
+#[test]
+fn compose_dyn_erases_the_types_of_the_stages() {
+    use crate::{BoxedLens, BoxedPrism};
+    use core::convert::Infallible;
+
+    let main_port: BoxedLens<Config, Option<u16>> =
+        field_lens!(Config, main).compose_dyn(field_lens!(DatabaseConfig, port));
+    let aux_port: BoxedLens<Config, Option<u16>> = mapped_lens(
+        |c: &Config| c.aux[0].clone(),
+        |c: &mut Config, db| c.aux[0] = db,
+    )
+    .compose_dyn(field_lens!(DatabaseConfig, port));
+
+    let mut config = Config::default();
+    main_port.set(&mut config, Some(5432));
+    let ports = [main_port, aux_port];
+    assert_eq!(
+        ports.map(|port| port.get(&config)),
+        [Some(5432), Some(2345)]
+    );
+
+    let first_aux_port: BoxedPrism<Config, Option<u16>, OpticsError> = field_lens!(Config, aux)
+        .compose_with_prism(vec::first())
+        .compose_dyn(mapped_prism(
+            |db: &DatabaseConfig| Ok::<_, Infallible>(db.port),
+            |db, port| db.port = port,
+        ));
+    assert_eq!(first_aux_port.try_get(&config), Ok(Some(2345)));
+    config.aux.clear();
+    assert_eq!(
+        first_aux_port.try_get(&config),
+        Err(OpticsError::IndexOutOfBounds)
+    );
+}
+
+#[test]
+fn shared_optics_are_cloned_across_threads() {
+    use crate::{SharedLens, SharedPrism};
+
+    let main_port: SharedLens<Config, Option<u16>> = field_lens!(Config, main)
+        .compose_with_lens(field_lens!(DatabaseConfig, port))
+        .shared();
+    let first_aux: SharedPrism<Config, DatabaseConfig, _> = field_lens!(Config, aux)
+        .compose_with_prism(vec::first())
+        .shared();
+
+    let worker = {
+        let main_port = main_port.clone();
+        std::thread::spawn(move || {
+            let mut config = Config::default();
+            main_port.set(&mut config, Some(5432));
+            config
+        })
+    };
+    let config = worker.join().unwrap();
+
+    assert_eq!(main_port.get(&config), Some(5432));
+    assert_eq!(
+        first_aux.clone().try_get(&config).map(|db| db.port),
+        Ok(Some(2345))
+    );
+}
+
+#[test]
+fn prism_composes_with_read_and_write_only_optics() {
+    use crate::{mapped_getter, mapped_partial_getter, mapped_setter};
+
+    let mut config = Config::default();
+    let first_aux = || field_lens!(Config, aux).compose_with_prism(vec::first());
+
+    let first_aux_port = first_aux().compose_with_partial_getter::<OpticsError, _, _>(
+        mapped_partial_getter(|db: &DatabaseConfig| db.port.ok_or(NoneError)),
+    );
+    assert_eq!(first_aux_port.try_get(&config), Ok(2345));
+
+    let first_aux_port = first_aux().compose_with_partial_getter_with_mappers(
+        mapped_partial_getter(|db: &DatabaseConfig| db.port.ok_or(NoneError)),
+        |_| "no first database",
+        |_| "no port",
+    );
+    config.aux[0].port = None;
+    assert_eq!(first_aux_port.try_get(&config), Err("no port"));
+
+    let first_aux_host_len =
+        first_aux().compose_with_getter(mapped_getter(|db: &DatabaseConfig| db.host.len()));
+    assert_eq!(first_aux_host_len.try_get(&config), Ok(4));
+
+    first_aux()
+        .compose_with_setter(mapped_setter(|db: &mut DatabaseConfig, port| {
+            db.port = Some(port);
+        }))
+        .set(&mut config, 5432);
+    assert_eq!(config.aux[0].port, Some(5432));
+
+    config.aux.clear();
+    assert_eq!(
+        first_aux_host_len.try_get(&config),
+        Err(IndexError { index: 0, len: 0 })
+    );
+}
+
+#[test]
+fn error_mappers_can_capture_their_environment() {
+    let mut config = Config::default();
+    let context = String::from("aux[0]");
+
+    let first_aux_port = field_lens!(Config, aux)
+        .compose_with_prism(vec::first())
+        .compose_with_lens(field_lens!(DatabaseConfig, port))
+        .compose_with_prism_with_mappers(
+            option::some(),
+            |e: IndexError| format!("{context}: {e}"),
+            |_: NoneError| format!("{context}: no port"),
+        );
+    assert_eq!(first_aux_port.try_get(&config), Ok(2345));
+
+    config.aux[0].port = None;
+    assert_eq!(
+        first_aux_port.try_get(&config),
+        Err(String::from("aux[0]: no port"))
+    );
+
+    config.aux.clear();
+    assert!(
+        first_aux_port
+            .try_get(&config)
+            .unwrap_err()
+            .starts_with("aux[0]: ")
+    );
+}
+
+#[test]
+fn lens_composed_with_fallible_iso_reports_reverse_errors() {
+    use crate::{ComposeWith, HasFallibleSetter, NumericRangeError};
+
+    let mut pair = (7u8, "level");
+    let level = tuple::element::<(u8, &str), 0>().compose_with_fallible_iso(num::u8_to_u16());
+
+    assert_eq!(level.try_get(&pair), Ok(7));
+    assert_eq!(level.try_set(&mut pair, 42), Ok(()));
+    assert_eq!(pair, (42, "level"));
+
+    assert_eq!(level.try_set(&mut pair, 300), Err(NumericRangeError));
+    assert_eq!(pair, (42, "level"));
+    level.set(&mut pair, 300);
+    assert_eq!(pair, (42, "level"));
+
+    let then_level = tuple::element::<(u8, &str), 0>().then(num::u8_to_u16());
+    assert_eq!(then_level.try_set(&mut pair, 43), Ok(()));
+    assert_eq!(then_level.try_set(&mut pair, 300), Err(NumericRangeError));
+
+    let shr_level = tuple::element::<(u8, &str), 0>() >> num::u8_to_u16();
+    assert_eq!(shr_level.try_set(&mut pair, 44), Ok(()));
+    assert_eq!(shr_level.try_set(&mut pair, 300), Err(NumericRangeError));
+    assert_eq!(pair, (44, "level"));
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn async_optics_compose_with_lifted_lenses() {
+    use crate::{AsyncGetter, AsyncSetter, Either, lift_async};
+    use core::pin::pin;
+    use core::task::{Context, Poll, Waker};
+
+    struct Row(usize);
+
+    impl AsyncGetter<Vec<DatabaseConfig>, DatabaseConfig> for Row {
+        type GetterError = IndexError;
+
+        async fn try_get(&self, table: &Vec<DatabaseConfig>) -> Result<DatabaseConfig, IndexError> {
+            table.get(self.0).cloned().ok_or(IndexError {
+                index: self.0,
+                len: table.len(),
+            })
+        }
+    }
+
+    impl AsyncSetter<Vec<DatabaseConfig>, DatabaseConfig> for Row {
+        type SetterError = IndexError;
+
+        async fn try_set(
+            &self,
+            table: &mut Vec<DatabaseConfig>,
+            row: DatabaseConfig,
+        ) -> Result<(), IndexError> {
+            let len = table.len();
+            let slot = table
+                .get_mut(self.0)
+                .ok_or(IndexError { index: self.0, len })?;
+            *slot = row;
+            Ok(())
+        }
+    }
+
+    fn block_on<F: Future + Send>(future: F) -> F::Output {
+        match pin!(future).poll(&mut Context::from_waker(Waker::noop())) {
+            Poll::Ready(output) => output,
+            Poll::Pending => panic!("the future was expected to complete immediately"),
+        }
+    }
+
+    let mut table = Config::default().aux;
+    let port = Row(0).compose_with_async(lift_async(field_lens!(DatabaseConfig, port)));
+
+    assert_eq!(block_on(port.try_get(&table)), Ok(Some(2345)));
+    assert_eq!(block_on(port.try_set(&mut table, Some(5432))), Ok(()));
+    assert_eq!(table[0].port, Some(5432));
+
+    let missing = Row(9).compose_with_async(lift_async(field_lens!(DatabaseConfig, port)));
+    assert_eq!(
+        block_on(missing.try_get(&table)),
+        Err(Either::Left(IndexError { index: 9, len: 2 }))
+    );
+    assert_eq!(
+        block_on(missing.try_set(&mut table, None)),
+        Err(Either::Left(Either::Left(IndexError { index: 9, len: 2 })))
+    );
+}
+
+#[cfg(feature = "tokio")]
+#[test]
+fn watch_map_only_wakes_on_changes_of_the_focus() {
+    use crate::{send_through, watch_map};
+    use core::pin::pin;
+    use core::task::{Context, Poll, Waker};
+    use tokio::sync::watch;
+
+    fn poll_once<F: Future>(future: F) -> Poll<F::Output> {
+        pin!(future).poll(&mut Context::from_waker(Waker::noop()))
+    }
+
+    let (tx, rx) = watch::channel(Config::default());
+    let main_port = field_lens!(Config, main).compose_with_lens(field_lens!(DatabaseConfig, port));
+    let mut port = watch_map(rx, main_port);
+
+    assert!(send_through(
+        &tx,
+        &field_lens!(Config, filename),
+        "other".to_string()
+    ));
+    assert!(poll_once(port.changed()).is_pending());
+    assert!(!port.has_changed().unwrap());
+
+    let main_port = field_lens!(Config, main).compose_with_lens(field_lens!(DatabaseConfig, port));
+    let current = port.get();
+    assert!(!send_through(&tx, &main_port, current));
+    assert!(send_through(&tx, &main_port, Some(1)));
+    assert!(matches!(poll_once(port.changed()), Poll::Ready(Ok(()))));
+    assert_eq!(port.get(), Some(1));
+    assert!(!port.has_changed().unwrap());
+
+    tx.send_modify(|config| config.main.port = Some(2));
+    drop(tx);
+    assert!(matches!(poll_once(port.changed()), Poll::Ready(Ok(()))));
+    assert_eq!(port.get(), Some(2));
+    assert!(port.has_changed().is_err());
+    assert!(matches!(poll_once(port.changed()), Poll::Ready(Err(_))));
+}
+
+#[cfg(feature = "store")]
+#[test]
+fn store_notifies_subscribers_of_changed_focus_only() {
+    use crate::Store;
+    use core::cell::{Cell, RefCell};
+    use std::rc::Rc;
+
+    let mut store = Store::new(Config::default());
+    let main_port = || {
+        field_lens!(Config, main)
+            .compose_with_lens(field_lens!(DatabaseConfig, port))
+            .compose_with_prism(option::some())
+    };
+
+    let seen = Rc::new(RefCell::new(Vec::new()));
+    let subscription = store.subscribe(main_port(), {
+        let seen = seen.clone();
+        move |port: &u16| seen.borrow_mut().push(*port)
+    });
+
+    let reads = Rc::new(Cell::new(0));
+    let host_len = store.select(crate::mapped_getter({
+        let reads = reads.clone();
+        move |config: &Config| {
+            reads.set(reads.get() + 1);
+            config.main.host.len()
+        }
+    }));
+    assert_eq!(host_len.get(&store), Ok(4));
+    assert_eq!(host_len.get(&store), Ok(4));
+    assert_eq!(reads.get(), 1);
+
+    store.set(
+        &field_lens!(Config, main).compose_with_lens(field_lens!(DatabaseConfig, port)),
+        Some(5432),
+    );
+    store.update(&field_lens!(Config, filename), |name| name.push_str(".bak"));
+    store.set(&main_port(), 5432);
+    store.set(&main_port(), 6543);
+    assert_eq!(*seen.borrow(), [5432, 6543]);
+
+    assert_eq!(host_len.get(&store), Ok(4));
+    assert_eq!(reads.get(), 2);
+
+    assert!(store.unsubscribe(subscription));
+    store.set(&main_port(), 7654);
+    assert_eq!(*seen.borrow(), [5432, 6543]);
+    assert_eq!(store.into_state().main.port, Some(7654));
+}
+
+#[cfg(feature = "store")]
+#[test]
+fn selectors_read_from_several_stores_keep_their_focus_apart() {
+    use crate::Store;
+
+    let mut renamed = Config::default();
+    renamed.main.host = "replica".to_string();
+    let primary = Store::new(Config::default());
+    let replica = Store::new(renamed);
+    assert_eq!(primary.version(), replica.version());
+
+    let host = primary
+        .select(field_lens!(Config, main).compose_with_lens(field_lens!(DatabaseConfig, host)));
+    assert_eq!(host.get(&primary), Ok("main".to_string()));
+    assert_eq!(host.get(&replica), Ok("replica".to_string()));
+    assert_eq!(host.get(&primary), Ok("main".to_string()));
+}
+
+#[test]
+fn transaction_rolls_back_when_a_write_is_rejected() {
+    use crate::Transaction;
+
+    let mut config = Config::default();
+    let main_port = || {
+        field_lens!(Config, main)
+            .compose_with_lens(field_lens!(DatabaseConfig, port))
+            .compose_with_prism(option::some())
+    };
+    let validated_port = || {
+        main_port()
+            .with_validation(|port: &u16| {
+                if *port < 1024 {
+                    Err("port below 1024")
+                } else {
+                    Ok(())
+                }
+            })
+            .named("port")
+    };
+    let main_port_option =
+        field_lens!(Config, main).compose_with_lens(field_lens!(DatabaseConfig, port));
+
+    let mut transaction = Transaction::new();
+    transaction
+        .set(main_port_option, Some(5432))
+        .set(field_lens!(Config, filename), "other.json".to_string())
+        .try_set(validated_port(), 80);
+    assert_eq!(transaction.len(), 3);
+
+    let error = transaction.commit(&mut config).unwrap_err();
+    assert_eq!(error.index(), 2);
+    assert_eq!(error.path(), "port");
+    assert_eq!(config, Config::default());
+
+    let mut transaction = Transaction::new();
+    transaction
+        .set(main_port_option, Some(5432))
+        .try_set(validated_port(), 6543);
+    assert!(transaction.commit(&mut config).is_ok());
+    assert_eq!(config.main.port, Some(6543));
+}
+
+#[test]
+fn patch_reports_the_entries_it_could_not_write() {
+    use crate::{Patch, PatchError, PatchRegistry};
+
+    let mut config = Config::default();
+    config.main.port = Some(5432);
+
+    let mut registry = PatchRegistry::new();
+    registry
+        .register(field_lens!(Config, filename))
+        .register(field_lens!(Config, main).compose_with_lens(field_lens!(DatabaseConfig, host)))
+        .register_fallible(
+            field_lens!(Config, main)
+                .compose_with_lens(field_lens!(DatabaseConfig, port))
+                .compose_with_prism(option::some())
+                .with_validation(|port: &u16| {
+                    if *port < 1024 {
+                        Err("port below 1024")
+                    } else {
+                        Ok(())
+                    }
+                })
+                .named("main.port"),
+        );
+    assert_eq!(
+        registry.paths().collect::<Vec<_>>(),
+        ["filename", "main.host", "main.port"]
+    );
+
+    let patch: Patch = [
+        ("main.host", "db.local"),
+        ("main.port", "80"),
+        ("main.user", "root"),
+        ("main.port", "high"),
+        ("filename", "other.json"),
+    ]
+    .into_iter()
+    .collect();
+
+    let errors = patch.apply(&mut config, &registry).unwrap_err();
+    assert_eq!(errors.len(), 3);
+    assert!(matches!(&errors[0], PatchError::Rejected { id, .. } if id == "main.port"));
+    assert!(matches!(&errors[1], PatchError::UnknownPath { id } if id == "main.user"));
+    assert_eq!(
+        errors[2].to_string(),
+        "main.port: invalid digit found in string"
+    );
+
+    assert_eq!(config.main.host, "db.local");
+    assert_eq!(config.main.port, Some(5432));
+    assert_eq!(config.filename, "other.json");
+
+    let mut patch = Patch::new();
+    patch.set("main.port", "6543");
+    assert!(patch.apply(&mut config, &registry).is_ok());
+    assert_eq!(config.main.port, Some(6543));
+}
+
+#[test]
+fn commands_restore_the_focus_they_overwrote() {
+    use crate::{Command, OverCommand, SetCommand};
+
+    let mut config = Config::default();
+    let main_port = || {
+        field_lens!(Config, main)
+            .compose_with_lens(field_lens!(DatabaseConfig, port))
+            .compose_with_prism(option::some())
+    };
+
+    let mut history: Vec<Box<dyn Command<Config>>> = vec![
+        Box::new(SetCommand::new(
+            field_lens!(Config, filename),
+            "other.json".to_string(),
+        )),
+        Box::new(OverCommand::new(main_port(), |port: u16| port + 1)),
+        Box::new(SetCommand::new(
+            field_lens!(Config, main).compose_with_lens(field_lens!(DatabaseConfig, port)),
+            Some(5432),
+        )),
+        Box::new(OverCommand::new(main_port(), |port: u16| port + 1)),
+    ];
+
+    let executed: Vec<bool> = history
+        .iter_mut()
+        .map(|command| command.execute(&mut config))
+        .collect();
+    assert_eq!(executed, [true, false, true, true]);
+    assert_eq!(config.filename, "other.json");
+    assert_eq!(config.main.port, Some(5433));
+
+    history[3].undo(&mut config);
+    assert_eq!(config.main.port, Some(5432));
+    history[3].undo(&mut config);
+    assert_eq!(config.main.port, Some(5432));
+
+    for command in history.iter_mut().rev() {
+        command.undo(&mut config);
+    }
+    assert_eq!(config, Config::default());
+
+    let mut missed = SetCommand::new(main_port(), 80);
+    assert!(!missed.execute(&mut config));
+    missed.undo(&mut config);
+    assert_eq!(config, Config::default());
+
+    config.main.port = Some(5432);
+    let mut increment = OverCommand::new(main_port(), |port: u16| port + 1);
+    assert!(increment.execute(&mut config));
+    increment.undo(&mut config);
+    assert_eq!(config.main.port, Some(5432));
+    config.main.port = None;
+    assert!(!increment.execute(&mut config));
+    increment.undo(&mut config);
+    assert_eq!(config.main.port, None);
+
+    let mut reset = SetCommand::new(main_port(), 80);
+    config.main.port = Some(5432);
+    assert!(reset.execute(&mut config));
+    config.main.port = None;
+    assert!(!reset.execute(&mut config));
+    reset.undo(&mut config);
+    assert_eq!(config.main.port, None);
+}
+
+#[test]
+fn registry_checks_the_kind_and_focus_of_looked_up_optics() {
+    use crate::{OpticRegistry, OpticRegistryError};
+
+    let mut registry = OpticRegistry::<Config>::new();
+    registry
+        .register_lens("filename", field_lens!(Config, filename))
+        .register_lens(
+            "main.host",
+            field_lens!(Config, main).compose_with_lens(field_lens!(DatabaseConfig, host)),
+        )
+        .register_prism(
+            "main.port",
+            field_lens!(Config, main)
+                .compose_with_lens(field_lens!(DatabaseConfig, port))
+                .compose_with_prism(option::some()),
+        );
+    assert_eq!(
+        registry.names().collect::<Vec<_>>(),
+        ["filename", "main.host", "main.port"]
+    );
+
+    let mut config = Config::default();
+    let host = registry.lens::<String>("main.host").unwrap();
+    host.set(&mut config, "db.local".to_string());
+    assert_eq!(config.main.host, "db.local");
+
+    let port = registry.prism::<u16>("main.port").unwrap();
+    assert_eq!(
+        port.try_get(&config).unwrap_err().to_string(),
+        "the value is None"
+    );
+    config.main.port = Some(5432);
+    assert_eq!(port.try_get(&config).ok(), Some(5432));
+
+    assert_eq!(
+        registry.lens::<u16>("main.port").err(),
+        Some(OpticRegistryError::KindMismatch {
+            name: "main.port".to_string(),
+            expected: "lens",
+            found: "prism",
+        })
+    );
+    assert_eq!(
+        registry.lens::<u16>("filename").err().unwrap().to_string(),
+        "filename: expected an optic focusing on u16, found one focusing on alloc::string::String"
+    );
+    assert_eq!(
+        registry.prism::<u16>("aux.port").err().unwrap().name(),
+        "aux.port"
+    );
+}
+
+#[test]
+fn registry_looks_up_optics_as_weaker_kinds() {
+    use crate::{OpticRegistry, OpticRegistryError, mapped_iso};
+
+    let mut registry = OpticRegistry::<Config>::new();
+    registry
+        .register_lens("filename", field_lens!(Config, filename))
+        .register_prism(
+            "main.port",
+            field_lens!(Config, main)
+                .compose_with_lens(field_lens!(DatabaseConfig, port))
+                .compose_with_prism(option::some()),
+        );
+
+    let mut config = Config::default();
+    let filename = registry.prism::<String>("filename").unwrap();
+    filename.set(&mut config, "backup.conf".to_string());
+    assert_eq!(
+        registry.getter::<String>("filename").unwrap().get(&config),
+        "backup.conf"
+    );
+    assert_eq!(
+        registry
+            .partial_getter::<String>("filename")
+            .unwrap()
+            .try_get(&config)
+            .ok(),
+        Some("backup.conf".to_string())
+    );
+
+    registry
+        .setter::<u16>("main.port")
+        .unwrap()
+        .set(&mut config, 5432);
+    assert_eq!(
+        registry
+            .partial_getter::<u16>("main.port")
+            .unwrap()
+            .try_get(&config)
+            .ok(),
+        Some(5432)
+    );
+    assert_eq!(
+        registry.getter::<u16>("main.port").err(),
+        Some(OpticRegistryError::KindMismatch {
+            name: "main.port".to_string(),
+            expected: "getter",
+            found: "prism",
+        })
+    );
+    assert!(matches!(
+        registry.setter::<u32>("main.port"),
+        Err(OpticRegistryError::TypeMismatch { .. })
+    ));
+
+    let mut numbers = OpticRegistry::<i32>::new();
+    numbers
+        .register_iso("negated", mapped_iso(|x: &i32| -x, |x: &i32| -x))
+        .register_lens("itself", mapped_lens(|x: &i32| *x, |x, v| *x = v));
+    assert_eq!(numbers.iso::<i32>("negated").unwrap().get(&3), -3);
+    assert_eq!(numbers.lens::<i32>("negated").unwrap().get(&3), -3);
+    let mut number = 3;
+    numbers
+        .setter::<i32>("negated")
+        .unwrap()
+        .set(&mut number, 7);
+    assert_eq!(number, -7);
+    assert_eq!(
+        numbers.iso::<i32>("itself").err().unwrap().to_string(),
+        "itself: expected an iso, found a lens"
+    );
+}
+
+#[test]
+fn keypaths_are_resolved_through_registered_optics() {
+    use crate::{HasModify, KeyPathError, KeyPathRegistry};
+
+    fn assert_send_sync<T: Send + Sync>(_: &T) {}
+
+    let mut registry = KeyPathRegistry::new();
+    registry
+        .field("filename", field_lens!(Config, filename))
+        .field("main", field_lens!(Config, main))
+        .field("aux", field_lens!(Config, aux))
+        .field("host", field_lens!(DatabaseConfig, host))
+        .field("port", field_lens!(DatabaseConfig, port))
+        .index(vec::get::<DatabaseConfig>)
+        .optional(option::some::<u16>());
+
+    assert_send_sync(&registry);
+    assert!(format!("{registry:?}").contains(r#""aux", "filename", "main""#));
+
+    let mut config = Config::default();
+
+    let aux_port = registry.parse::<Config, u16>("aux[0].port?").unwrap();
+    assert_eq!(aux_port.try_get(&config).ok(), Some(2345));
+    aux_port.set(&mut config, 3456);
+    assert_eq!(config.aux[0].port, Some(3456));
+    aux_port.modify(&mut config, |port| *port += 1);
+    assert_eq!(config.aux[0].port, Some(3457));
+
+    let second_aux_port = registry.parse::<Config, u16>("aux[1].port?").unwrap();
+    assert_eq!(
+        second_aux_port.try_get(&config).unwrap_err().to_string(),
+        "the value is None"
+    );
+    second_aux_port.set(&mut config, 80);
+    assert_eq!(config.aux[1].port, Some(80));
+
+    let main_host = registry.parse::<Config, String>("main.host").unwrap();
+    main_host.set(&mut config, "db.local".to_string());
+    assert_eq!(config.main.host, "db.local");
+
+    let out_of_bounds = registry.parse::<Config, String>("aux[5].host").unwrap();
+    assert!(out_of_bounds.try_get(&config).is_err());
+    out_of_bounds.set(&mut config, "aux6".to_string());
+    assert_eq!(config.aux.len(), 2);
+
+    let error = |path| registry.parse::<Config, u16>(path).err().unwrap();
+    assert_eq!(
+        error("main.port"),
+        KeyPathError::TypeMismatch {
+            expected: "u16",
+            found: "core::option::Option<u16>",
+        }
+    );
+    assert!(
+        matches!(error("main.user"), KeyPathError::UnknownField { field, .. } if field == "user")
+    );
+    assert!(matches!(
+        error("filename?"),
+        KeyPathError::NotOptional { .. }
+    ));
+    assert!(matches!(
+        error("main[0]"),
+        KeyPathError::NotIndexable { .. }
+    ));
+    assert_eq!(
+        error("aux[x].port?"),
+        KeyPathError::Syntax {
+            position: 4,
+            expected: "an index",
+        }
+    );
+    assert_eq!(
+        error("aux[0]port").to_string(),
+        "expected `.`, `[` or `?` at position 6"
+    );
+    assert!(matches!(
+        error(""),
+        KeyPathError::Syntax { position: 0, .. }
+    ));
+}
+
+#[test]
+fn form_binder_commits_the_edited_bindings() {
+    use crate::FormBinder;
+
+    let mut config = Config::default();
+    config.main.port = Some(5432);
+
+    let mut form = FormBinder::new();
+    form.bind("filename", field_lens!(Config, filename))
+        .bind(
+            "main.host",
+            field_lens!(Config, main).compose_with_lens(field_lens!(DatabaseConfig, host)),
+        )
+        .bind_fallible(
+            "main.port",
+            field_lens!(Config, main)
+                .compose_with_lens(field_lens!(DatabaseConfig, port))
+                .compose_with_prism(option::some())
+                .with_validation(|port: &u16| {
+                    if *port < 1024 {
+                        Err("port below 1024")
+                    } else {
+                        Ok(())
+                    }
+                }),
+        );
+    form.load(&config);
+    assert_eq!(form.binding("main.port").unwrap().get(), "5432");
+    assert!(!form.is_dirty());
+
+    form.binding_mut("main.host")
+        .unwrap()
+        .set("db.local".to_string());
+    form.binding_mut("main.port").unwrap().set("80".to_string());
+    let errors = form.commit(&mut config).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].to_string(), "main.port: port below 1024");
+    assert_eq!(config.main.host, "db.local");
+    assert_eq!(config.main.port, Some(5432));
+    assert!(!form.binding("main.host").unwrap().is_dirty());
+    assert!(form.binding("main.port").unwrap().is_dirty());
+
+    form.binding_mut("main.port")
+        .unwrap()
+        .set("6543".to_string());
+    assert!(form.commit(&mut config).is_ok());
+    assert_eq!(config.main.port, Some(6543));
+    assert!(!form.is_dirty());
+
+    config.main.port = None;
+    form.load(&config);
+    assert_eq!(form.binding("main.port").unwrap().get(), "");
+}
+
+#[test]
+fn bindings_keep_writes_dropped_by_a_prism_dirty() {
+    use crate::{FormBinder, OpticBinding};
+
+    let first_host = || {
+        field_lens!(Config, aux)
+            .compose_with_prism(vec::first())
+            .compose_with_lens(field_lens!(DatabaseConfig, host))
+    };
+    let mut config = Config::default();
+
+    let mut form = FormBinder::new();
+    form.bind("aux[0].host", first_host());
+    form.load(&config);
+    assert_eq!(form.binding("aux[0].host").unwrap().get(), "aux1");
+
+    form.binding_mut("aux[0].host")
+        .unwrap()
+        .set("replica".to_string());
+    let aux = core::mem::take(&mut config.aux);
+    let errors = form.commit(&mut config).unwrap_err();
+    assert_eq!(
+        errors[0].to_string(),
+        "aux[0].host: the focus is not present"
+    );
+    assert!(config.aux.is_empty());
+    assert!(form.is_dirty());
+
+    let mut binding = OpticBinding::new(first_host(), &Config::default()).unwrap();
+    binding.set("replica".to_string());
+    assert!(binding.commit(&mut config).is_err());
+    assert!(binding.is_dirty());
+
+    config.aux = aux;
+    assert!(binding.commit(&mut config).is_ok());
+    assert_eq!(config.aux[0].host, "replica");
+    assert!(!binding.is_dirty());
+
+    config.aux.clear();
+    assert!(binding.load(&config).is_err());
+    assert_eq!(binding.get(), "replica");
+}
+
+#[test]
+#[should_panic(expected = "is already a version of the migration chain")]
+fn migration_chain_rejects_repeated_versions() {
+    use crate::{MigrationChain, mapped_fallible_iso};
+    use core::convert::Infallible;
+
+    let _ = MigrationChain::<String, String>::new()
+        .then(mapped_fallible_iso(
+            |s: &String| s.parse::<u16>(),
+            |port: &u16| Ok::<_, Infallible>(port.to_string()),
+        ))
+        .then(mapped_fallible_iso(
+            |port: &u16| Ok::<_, Infallible>(port.to_string()),
+            |s: &String| s.parse::<u16>(),
+        ));
+}
+
+#[test]
+fn migration_chain_reports_the_failed_step() {
+    use crate::{ErasedError, MigrationChain, MigrationError, mapped_fallible_iso};
+    use core::convert::Infallible;
+
+    #[derive(Debug, PartialEq)]
+    struct ConfigV1 {
+        host: String,
+        port: String,
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct ConfigV2 {
+        host: String,
+        port: u16,
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct ConfigV3 {
+        address: String,
+    }
+
+    let chain = MigrationChain::<ConfigV1, ConfigV1>::new()
+        .then(mapped_fallible_iso(
+            |v1: &ConfigV1| {
+                v1.port.parse().map(|port| ConfigV2 {
+                    host: v1.host.clone(),
+                    port,
+                })
+            },
+            |v2: &ConfigV2| {
+                Ok::<_, Infallible>(ConfigV1 {
+                    host: v2.host.clone(),
+                    port: v2.port.to_string(),
+                })
+            },
+        ))
+        .then(mapped_fallible_iso(
+            |v2: &ConfigV2| {
+                Ok::<_, Infallible>(ConfigV3 {
+                    address: format!("{}:{}", v2.host, v2.port),
+                })
+            },
+            |v3: &ConfigV3| {
+                let (host, port) = v3.address.split_once(':').ok_or("missing port")?;
+                Ok::<_, ErasedError>(ConfigV2 {
+                    host: host.to_string(),
+                    port: port.parse()?,
+                })
+            },
+        ));
+    assert_eq!(chain.len(), 2);
+
+    let v3 = chain
+        .migrate(ConfigV1 {
+            host: "main".to_string(),
+            port: "5432".to_string(),
+        })
+        .unwrap();
+    assert_eq!(v3.address, "main:5432");
+
+    let v3 = chain
+        .migrate_from(ConfigV2 {
+            host: "aux".to_string(),
+            port: 2345,
+        })
+        .unwrap();
+    assert_eq!(v3.address, "aux:2345");
+
+    let error = chain
+        .migrate(ConfigV1 {
+            host: "main".to_string(),
+            port: "none".to_string(),
+        })
+        .unwrap_err();
+    assert!(matches!(error, MigrationError::Failed { step: 0, .. }));
+
+    assert_eq!(
+        chain
+            .downgrade(ConfigV3 {
+                address: "main:5432".to_string(),
+            })
+            .unwrap(),
+        ConfigV1 {
+            host: "main".to_string(),
+            port: "5432".to_string(),
+        }
+    );
+    let error = chain
+        .downgrade(ConfigV3 {
+            address: "main".to_string(),
+        })
+        .unwrap_err();
+    assert!(matches!(error, MigrationError::Failed { step: 1, .. }));
+    assert!(error.to_string().ends_with("failed: missing port"));
+
+    assert!(matches!(
+        chain.migrate_from(Config::default()),
+        Err(MigrationError::UnknownVersion { .. })
+    ));
+}
+
+#[test]
+fn diff_lists_the_changed_foci() {
+    use crate::diff;
+
+    let old = Config::default();
+    let mut new = old.clone();
+    new.main.port = Some(5432);
+    new.aux.clear();
+
+    let main_port = field_lens!(Config, main).compose_with_lens(field_lens!(DatabaseConfig, port));
+    let main_host = field_lens!(Config, main).compose_with_lens(field_lens!(DatabaseConfig, host));
+    let first_aux_host = field_lens!(Config, aux)
+        .compose_with_prism(vec::first().named("first"))
+        .compose_with_lens(field_lens!(DatabaseConfig, host));
+
+    let changes = diff(&old, &new, &[&main_port, &main_host, &first_aux_host]);
+
+    assert_eq!(changes.len(), 2);
+    assert_eq!(changes[0].path(), "main.port");
+    assert_eq!(changes[0].before(), Some("None"));
+    assert_eq!(changes[0].after(), Some("Some(5432)"));
+    assert_eq!(
+        changes[1].to_string(),
+        "aux.first.host: \"aux1\" -> (absent)"
+    );
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn par_over_and_par_fold_match_their_sequential_counterparts() {
+    use crate::{HasGetAll, HasParOver, mapped_traversal};
+
+    let ports = mapped_traversal(
+        |v: &Vec<DatabaseConfig>, f: &mut dyn FnMut(DatabaseConfig)| v.iter().cloned().for_each(f),
+        |v: &mut Vec<DatabaseConfig>, f: &mut dyn FnMut(DatabaseConfig) -> DatabaseConfig| {
+            for c in v.iter_mut() {
+                *c = f(c.clone());
+            }
+        },
+    )
+    .compose_with_lens(field_lens!(DatabaseConfig, port))
+    .compose_with_prism(option::some());
+
+    let mut configs: Vec<DatabaseConfig> = (0..20_000)
+        .map(|i| DatabaseConfig {
+            port: (i % 3 != 0).then_some(i % 1000),
+            ..Config::default().main
+        })
+        .collect();
+    let mut expected = configs.clone();
+
+    ports.par_over(&mut configs, |p| p + 1);
+    ports.over_all(&mut expected, &mut |p| p + 1);
+    assert_eq!(configs, expected);
+
+    let sum = ports.par_fold(&configs, || 0u64, |acc, p| acc + u64::from(p), |a, b| a + b);
+    assert_eq!(
+        sum,
+        ports
+            .get_all(&configs)
+            .into_iter()
+            .map(u64::from)
+            .sum::<u64>()
+    );
+
+    let in_order = ports.par_fold(
+        &configs,
+        Vec::new,
+        |mut acc, p| {
+            acc.push(p);
+            acc
+        },
+        |mut a, b| {
+            a.extend(b);
+            a
+        },
+    );
+    assert_eq!(in_order, ports.get_all(&configs));
+    assert_eq!(
+        ports.par_fold(&Vec::new(), || 7, |acc, p| acc + p, |a, b| a + b),
+        7
+    );
+}
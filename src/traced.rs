@@ -0,0 +1,142 @@
+use crate::{
+    HasFallibleSetter, HasGetter, HasGetterMut, HasGetterRef, HasInsert, HasModify, HasPath,
+    HasReverseGet, HasSetter, HasTraversal,
+};
+use core::fmt::{Display, Formatter};
+use std::time::Instant;
+
+/// An optic emitting a `tracing` event for every operation going through it, created by the
+/// `traced` method of the optic wrappers.
+///
+/// It behaves exactly like the optic it wraps, implementing the same base traits. Each event is
+/// emitted at the `TRACE` level by the `optics` target, with the name given to `traced` in the
+/// `optic` field, the name of the operation, such as `try_get` or `set`, in the `operation` field,
+/// whether the operation succeeded in the `success` field, and the time it took in the `elapsed`
+/// field.
+#[derive(Debug, Clone, Copy)]
+pub struct Traced<O> {
+    optic: O,
+    name: &'static str,
+}
+
+impl<O> Traced<O> {
+    pub(crate) fn new(optic: O, name: &'static str) -> Self {
+        Traced { optic, name }
+    }
+
+    fn trace<R>(
+        &self,
+        operation: &'static str,
+        run: impl FnOnce() -> R,
+        success: fn(&R) -> bool,
+    ) -> R {
+        let start = Instant::now();
+        let result = run();
+        tracing::trace!(
+            target: "optics",
+            optic = self.name,
+            operation,
+            success = success(&result),
+            elapsed = ?start.elapsed(),
+        );
+        result
+    }
+}
+
+impl<O: Display> Display for Traced<O> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.optic, f)
+    }
+}
+
+impl<O: HasPath> HasPath for Traced<O> {
+    fn for_each_segment<'a>(&'a self, f: &mut dyn FnMut(&'a str)) {
+        self.optic.for_each_segment(f);
+    }
+}
+
+impl<S, A, O: HasGetter<S, A>> HasGetter<S, A> for Traced<O> {
+    type GetterError = O::GetterError;
+
+    fn try_get(&self, source: &S) -> Result<A, Self::GetterError> {
+        self.trace("try_get", || self.optic.try_get(source), Result::is_ok)
+    }
+}
+
+impl<S, A, O: HasGetterRef<S, A>> HasGetterRef<S, A> for Traced<O> {
+    fn try_get_ref<'a>(&self, source: &'a S) -> Result<&'a A, Self::GetterError>
+    where
+        Self: 'a,
+    {
+        self.trace(
+            "try_get_ref",
+            || self.optic.try_get_ref(source),
+            Result::is_ok,
+        )
+    }
+}
+
+impl<S, A, O: HasGetterMut<S, A>> HasGetterMut<S, A> for Traced<O> {
+    fn try_get_mut<'a>(&self, source: &'a mut S) -> Result<&'a mut A, Self::GetterError>
+    where
+        Self: 'a,
+    {
+        self.trace(
+            "try_get_mut",
+            || self.optic.try_get_mut(source),
+            Result::is_ok,
+        )
+    }
+}
+
+impl<S, A, O: HasSetter<S, A>> HasSetter<S, A> for Traced<O> {
+    fn set(&self, source: &mut S, value: A) {
+        self.trace("set", || self.optic.set(source, value), |()| true);
+    }
+}
+
+impl<S, A, O: HasInsert<S, A>> HasInsert<S, A> for Traced<O> {
+    fn insert(&self, source: &mut S, value: A) {
+        self.trace("insert", || self.optic.insert(source, value), |()| true);
+    }
+}
+
+impl<S, A, O: HasModify<S, A>> HasModify<S, A> for Traced<O> {
+    fn modify<F: FnOnce(&mut A)>(&self, source: &mut S, f: F) {
+        self.trace("modify", || self.optic.modify(source, f), |()| true);
+    }
+}
+
+impl<S, A, O: HasFallibleSetter<S, A>> HasFallibleSetter<S, A> for Traced<O> {
+    type SetterError = O::SetterError;
+
+    fn try_set(&self, source: &mut S, value: A) -> Result<(), Self::SetterError> {
+        self.trace(
+            "try_set",
+            || self.optic.try_set(source, value),
+            Result::is_ok,
+        )
+    }
+}
+
+impl<S, A, O: HasReverseGet<S, A>> HasReverseGet<S, A> for Traced<O> {
+    type ReverseError = O::ReverseError;
+
+    fn try_reverse_get(&self, value: &A) -> Result<S, Self::ReverseError> {
+        self.trace(
+            "try_reverse_get",
+            || self.optic.try_reverse_get(value),
+            Result::is_ok,
+        )
+    }
+}
+
+impl<S, A, O: HasTraversal<S, A>> HasTraversal<S, A> for Traced<O> {
+    fn for_each(&self, source: &S, f: &mut dyn FnMut(A)) {
+        self.trace("for_each", || self.optic.for_each(source, f), |()| true);
+    }
+
+    fn over_all(&self, source: &mut S, f: &mut dyn FnMut(A) -> A) {
+        self.trace("over_all", || self.optic.over_all(source, f), |()| true);
+    }
+}
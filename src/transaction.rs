@@ -0,0 +1,198 @@
+use crate::{ErasedError, HasFallibleSetter, HasPath, HasSetter};
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{Debug, Display, Formatter};
+
+/// A batch of writes through optics, applied to a source all at once or not at all.
+///
+/// Writes are queued with [`set`](Transaction::set) and [`try_set`](Transaction::try_set), and
+/// applied in order to a working copy of the source by [`commit`](Transaction::commit). The source
+/// is only replaced by the working copy if every write succeeded, so a value rejected by a
+/// validated setter rolls back the writes queued before it.
+///
+/// The optics have to implement [`HasPath`], so a failed commit can report the path of the optic
+/// that failed. Optics created by [`field_lens!`](crate::field_lens) and compositions of them have
+/// a path, other optics, such as validated ones, can be given one with their `named` method.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{Transaction, field_lens};
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// struct Server {
+///     host: String,
+///     port: u16,
+/// }
+///
+/// let mut server = Server { host: "localhost".to_string(), port: 8080 };
+/// let port = field_lens!(Server, port)
+///     .with_validation(|port| if *port < 1024 { Err("port below 1024") } else { Ok(()) })
+///     .named("port");
+///
+/// let mut transaction = Transaction::new();
+/// transaction
+///     .set(field_lens!(Server, host), "example.com".to_string())
+///     .try_set(port, 80);
+///
+/// let error = transaction.commit(&mut server).unwrap_err();
+/// assert_eq!(error.path(), "port");
+/// assert_eq!(error.to_string(), "port: port below 1024");
+/// assert_eq!(server, Server { host: "localhost".to_string(), port: 8080 });
+/// ```
+pub struct Transaction<'a, S> {
+    writes: Vec<Write<'a, S>>,
+}
+
+#[allow(clippy::type_complexity)]
+struct Write<'a, S> {
+    path: String,
+    apply: Box<dyn FnOnce(&mut S) -> Result<(), ErasedError> + 'a>,
+}
+
+impl<'a, S> Transaction<'a, S> {
+    /// Creates a new, empty transaction.
+    #[must_use]
+    pub fn new() -> Self {
+        Transaction { writes: Vec::new() }
+    }
+
+    /// Returns the number of writes queued in this transaction.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.writes.len()
+    }
+
+    /// Returns `true` if no writes are queued in this transaction.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.writes.is_empty()
+    }
+
+    /// Queues setting the focus of `optic` to `value`.
+    ///
+    /// # Parameters
+    ///
+    /// - `optic`: The optic to set the focus of.
+    /// - `value`: The value to set the focus to.
+    pub fn set<A, O>(&mut self, optic: O, value: A) -> &mut Self
+    where
+        O: HasSetter<S, A> + HasPath + 'a,
+        A: 'a,
+    {
+        self.writes.push(Write {
+            path: optic.joined_path(),
+            apply: Box::new(move |source| {
+                optic.set(source, value);
+                Ok(())
+            }),
+        });
+        self
+    }
+
+    /// Queues setting the focus of `optic` to `value`, failing the transaction if `optic` rejects
+    /// the value.
+    ///
+    /// # Parameters
+    ///
+    /// - `optic`: The fallible setter to set the focus of, such as a validated optic.
+    /// - `value`: The value to set the focus to.
+    pub fn try_set<A, O>(&mut self, optic: O, value: A) -> &mut Self
+    where
+        O: HasFallibleSetter<S, A> + HasPath + 'a,
+        O::SetterError: Into<ErasedError>,
+        A: 'a,
+    {
+        self.writes.push(Write {
+            path: optic.joined_path(),
+            apply: Box::new(move |source| optic.try_set(source, value).map_err(Into::into)),
+        });
+        self
+    }
+
+    /// Applies the queued writes in order to a copy of `source`, replacing `source` with the copy if
+    /// all of them succeeded.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`TransactionError`] identifying the first write that failed. In that case
+    /// `source` is left unchanged.
+    pub fn commit(self, source: &mut S) -> Result<(), TransactionError>
+    where
+        S: Clone,
+    {
+        let mut working = source.clone();
+
+        for (index, write) in self.writes.into_iter().enumerate() {
+            (write.apply)(&mut working).map_err(|error| TransactionError {
+                index,
+                path: write.path,
+                error,
+            })?;
+        }
+
+        *source = working;
+        Ok(())
+    }
+}
+
+impl<S> Default for Transaction<'_, S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S> Debug for Transaction<'_, S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_list()
+            .entries(self.writes.iter().map(|write| &write.path))
+            .finish()
+    }
+}
+
+/// The error of a [`Transaction`] that was rolled back, identifying the write that failed.
+#[derive(Debug)]
+pub struct TransactionError {
+    index: usize,
+    path: String,
+    error: ErasedError,
+}
+
+impl TransactionError {
+    /// Returns the position of the failed write among the writes of the transaction.
+    #[must_use]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the path of the optic that failed, its segments joined with a `.`.
+    #[must_use]
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Returns a reference to the error of the optic that failed.
+    #[must_use]
+    pub fn error(&self) -> &ErasedError {
+        &self.error
+    }
+
+    /// Consumes this `TransactionError`, returning the error of the optic that failed.
+    #[must_use]
+    pub fn into_error(self) -> ErasedError {
+        self.error
+    }
+}
+
+impl Display for TransactionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}: {}", self.path, self.error)
+    }
+}
+
+impl core::error::Error for TransactionError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(&*self.error)
+    }
+}